@@ -1071,25 +1071,11 @@ class AppState: ObservableObject {
     }
 
     private func launchClaudeResume(projectPath: String, sessionId: String, creationId: String) async throws {
-        let process = Process()
-        process.executableURL = URL(fileURLWithPath: "/bin/bash")
-        process.arguments = ["-c", """
-            PROJECT_PATH="\(projectPath)"
-            SESSION_ID="\(sessionId)"
-            CLAUDE_CMD="/opt/homebrew/bin/claude --resume $SESSION_ID"
-
-            if [ -d "/Applications/Ghostty.app" ]; then
-                open -na "Ghostty.app" --args --working-directory="$PROJECT_PATH" -e bash -c "$CLAUDE_CMD"
-            elif [ -d "/Applications/iTerm.app" ]; then
-                osascript -e "tell application \\"iTerm\\" to create window with default profile command \\"cd '$PROJECT_PATH' && $CLAUDE_CMD\\""
-                osascript -e 'tell application "iTerm" to activate'
-            else
-                osascript -e "tell application \\"Terminal\\" to do script \\"cd '$PROJECT_PATH' && $CLAUDE_CMD\\""
-                osascript -e 'tell application "Terminal" to activate'
-            fi
-        """]
-
-        try process.run()
+        let engine = try self.engine ?? HudEngine()
+        // The configured terminal app (HudConfig.terminal_app), running the
+        // project's launch command with --resume
+        let launch = try engine.terminalLaunch(projectPath: projectPath, runClaude: true, resumeSessionId: sessionId)
+        try TerminalLauncher.run(launch)
 
         startCompletionMonitor(projectPath: projectPath, creationId: creationId, sessionId: sessionId)
     }
@@ -53,7 +53,7 @@ final class SessionStateManager {
             DispatchQueue.main.asyncAfter(deadline: .now() + Constants.flashDurationSeconds) { [weak self] in
                 self?.flashingProjects.removeValue(forKey: path)
             }
-        case .working, .idle:
+        case .working, .thinking, .idle:
             break
         }
     }
@@ -855,12 +855,44 @@ final class TerminalLauncher {
 
     private func launchNewTerminal(forPath path: String, name: String) {
         _Concurrency.Task {
+            guard isTmuxInstalled() else {
+                launchConfiguredTerminal(atPath: path)
+                return
+            }
             let claudePath = await getClaudePath()
             runBashScript(TerminalScripts.launch(projectPath: path, projectName: name, claudePath: claudePath))
             scheduleTerminalActivation()
         }
     }
 
+    private func isTmuxInstalled() -> Bool {
+        let searchPath = Constants.homebrewPaths + ":" + (ProcessInfo.processInfo.environment["PATH"] ?? "")
+        return searchPath.split(separator: ":").contains {
+            FileManager.default.isExecutableFile(atPath: "\($0)/tmux")
+        }
+    }
+
+    /// Without tmux, opens the configured terminal app (HudConfig.terminal_app) at the path.
+    private func launchConfiguredTerminal(atPath path: String) {
+        do {
+            let launch = try HudEngine().terminalLaunch(projectPath: path, runClaude: false, resumeSessionId: nil)
+            try Self.run(launch)
+        } catch {
+            logger.warning("Terminal launch failed: \(error.localizedDescription)")
+        }
+    }
+
+    /// Spawns a terminal as described by the engine's `terminalLaunch`.
+    static func run(_ launch: TerminalLaunch) throws {
+        let process = Process()
+        process.executableURL = URL(fileURLWithPath: "/usr/bin/env")
+        process.arguments = [launch.program] + launch.args
+        if let cwd = launch.cwd {
+            process.currentDirectoryURL = URL(fileURLWithPath: cwd)
+        }
+        try process.run()
+    }
+
     private func getClaudePath() async -> String {
         await CapacitorConfig.shared.getClaudePath() ?? "/opt/homebrew/bin/claude"
     }
@@ -1015,8 +1047,6 @@ private enum TerminalScripts {
             printf '%s' "$1" | sed "s/'/'\\\\''/g"
         }
 
-        \(tmuxCheckAndFallback)
-
         \(findOrCreateSession)
 
         HAS_ATTACHED_CLIENT=$(tmux list-clients 2>/dev/null | head -1)
@@ -1034,31 +1064,6 @@ private enum TerminalScripts {
         """
     }
 
-    private static var tmuxCheckAndFallback: String {
-        """
-        if ! command -v tmux &> /dev/null; then
-            # Escape path for single-quoted arguments in osascript commands
-            PATH_ESC=$(shell_escape_single "$PROJECT_PATH")
-            if [ -d "/Applications/Ghostty.app" ]; then
-                open -na "Ghostty.app" --args --working-directory="$PROJECT_PATH"
-            elif [ -d "/Applications/iTerm.app" ]; then
-                osascript -e "tell application \\"iTerm\\" to create window with default profile command \\"cd '$PATH_ESC' && exec \\$SHELL\\""
-                osascript -e 'tell application "iTerm" to activate'
-            elif [ -d "/Applications/Alacritty.app" ]; then
-                open -na "Alacritty.app" --args --working-directory "$PROJECT_PATH"
-            elif command -v kitty &>/dev/null; then
-                kitty --directory "$PROJECT_PATH" &
-            elif [ -d "/Applications/Warp.app" ]; then
-                open -a "Warp" "$PROJECT_PATH"
-            else
-                osascript -e "tell application \\"Terminal\\" to do script \\"cd '$PATH_ESC'\\""
-                osascript -e 'tell application "Terminal" to activate'
-            fi
-            exit 0
-        fi
-        """
-    }
-
     private static var findOrCreateSession: String {
         """
         EXISTING_SESSION=$(tmux list-windows -a -F '#{session_name}:#{pane_current_path}' 2>/dev/null | \\
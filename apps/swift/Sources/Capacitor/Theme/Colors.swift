@@ -60,7 +60,7 @@ extension Color {
     static func statusColor(for state: SessionState) -> Color {
         switch state {
         case .ready: return statusReady
-        case .working: return statusWorking
+        case .working, .thinking: return statusWorking
         case .waiting: return statusWaiting
         case .compacting: return statusCompacting
         case .idle: return statusIdle
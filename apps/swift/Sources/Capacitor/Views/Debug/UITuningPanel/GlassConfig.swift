@@ -32,6 +32,7 @@ enum PreviewState: String, CaseIterable {
     case none = "None"
     case ready = "Ready"
     case working = "Working"
+    case thinking = "Thinking"
     case waiting = "Waiting"
     case compacting = "Compacting"
     case idle = "Idle"
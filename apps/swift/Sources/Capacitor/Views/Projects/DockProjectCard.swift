@@ -214,6 +214,7 @@ struct DockProjectCard: View {
         switch state {
         case .ready: return "Ready for input"
         case .working: return "Working"
+        case .thinking: return "Thinking"
         case .waiting: return "Waiting for user action"
         case .compacting: return "Compacting history"
         case .idle: return "Idle"
@@ -16,6 +16,7 @@ struct StatusIndicator: View {
         switch state {
         case .ready: return "Ready"
         case .working: return "Working"
+        case .thinking: return "Thinking"
         case .waiting: return "Waiting"
         case .compacting: return "Compacting"
         case .idle: return "Idle"
@@ -38,7 +39,7 @@ struct StatusIndicator: View {
                     .contentTransition(reduceMotion ? .identity : .numericText())
             }
 
-            if state == .working {
+            if state == .working || state == .thinking {
                 AnimatedEllipsis(color: statusColor)
             }
         }
@@ -51,6 +52,7 @@ struct StatusIndicator: View {
         switch state {
         case .ready: return "Ready for input"
         case .working: return "Currently working on a task"
+        case .thinking: return "Reasoning before the next step"
         case .waiting: return "Waiting for user action"
         case .compacting: return "Compacting conversation history"
         case .idle: return "Session is idle"
@@ -36,6 +36,7 @@ struct ProjectCardView: View {
         case .none: return sessionState?.state
         case .ready: return .ready
         case .working: return .working
+        case .thinking: return .thinking
         case .waiting: return .waiting
         case .compacting: return .compacting
         case .idle: return .idle
@@ -147,6 +148,7 @@ struct ProjectCardView: View {
         switch state {
         case .ready: return "Ready for input"
         case .working: return "Working"
+        case .thinking: return "Thinking"
         case .waiting: return "Waiting for user action"
         case .compacting: return "Compacting history"
         case .idle: return "Idle"
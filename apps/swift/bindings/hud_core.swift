@@ -8,10 +8,10 @@ import Foundation
 // might be in a separate module, or it might be compiled inline into
 // this module. This is a bit of light hackery to work with both.
 #if canImport(hud_coreFFI)
-    import hud_coreFFI
+import hud_coreFFI
 #endif
 
-private extension RustBuffer {
+fileprivate extension RustBuffer {
     // Allocate a new buffer, copying the contents of a `UInt8` array.
     init(bytes: [UInt8]) {
         let rbuf = bytes.withUnsafeBufferPointer { ptr in
@@ -21,7 +21,7 @@ private extension RustBuffer {
     }
 
     static func empty() -> RustBuffer {
-        RustBuffer(capacity: 0, len: 0, data: nil)
+        RustBuffer(capacity: 0, len:0, data: nil)
     }
 
     static func from(_ ptr: UnsafeBufferPointer<UInt8>) -> RustBuffer {
@@ -35,7 +35,7 @@ private extension RustBuffer {
     }
 }
 
-private extension ForeignBytes {
+fileprivate extension ForeignBytes {
     init(bufferPointer: UnsafeBufferPointer<UInt8>) {
         self.init(len: Int32(bufferPointer.count), data: bufferPointer.baseAddress)
     }
@@ -48,7 +48,7 @@ private extension ForeignBytes {
 // Helper classes/extensions that don't change.
 // Someday, this will be in a library of its own.
 
-private extension Data {
+fileprivate extension Data {
     init(rustBuffer: RustBuffer) {
         self.init(
             bytesNoCopy: rustBuffer.data!,
@@ -72,15 +72,15 @@ private extension Data {
 //
 // Instead, the read() method and these helper functions input a tuple of data
 
-private func createReader(data: Data) -> (data: Data, offset: Data.Index) {
+fileprivate func createReader(data: Data) -> (data: Data, offset: Data.Index) {
     (data: data, offset: 0)
 }
 
 // Reads an integer at the current offset, in big-endian order, and advances
 // the offset on success. Throws if reading the integer would move the
 // offset past the end of the buffer.
-private func readInt<T: FixedWidthInteger>(_ reader: inout (data: Data, offset: Data.Index)) throws -> T {
-    let range = reader.offset ..< reader.offset + MemoryLayout<T>.size
+fileprivate func readInt<T: FixedWidthInteger>(_ reader: inout (data: Data, offset: Data.Index)) throws -> T {
+    let range = reader.offset..<reader.offset + MemoryLayout<T>.size
     guard reader.data.count >= range.upperBound else {
         throw UniffiInternalError.bufferOverflow
     }
@@ -90,38 +90,38 @@ private func readInt<T: FixedWidthInteger>(_ reader: inout (data: Data, offset:
         return value as! T
     }
     var value: T = 0
-    let _ = withUnsafeMutableBytes(of: &value) { reader.data.copyBytes(to: $0, from: range) }
+    let _ = withUnsafeMutableBytes(of: &value, { reader.data.copyBytes(to: $0, from: range)})
     reader.offset = range.upperBound
     return value.bigEndian
 }
 
 // Reads an arbitrary number of bytes, to be used to read
 // raw bytes, this is useful when lifting strings
-private func readBytes(_ reader: inout (data: Data, offset: Data.Index), count: Int) throws -> [UInt8] {
-    let range = reader.offset ..< (reader.offset + count)
+fileprivate func readBytes(_ reader: inout (data: Data, offset: Data.Index), count: Int) throws -> Array<UInt8> {
+    let range = reader.offset..<(reader.offset+count)
     guard reader.data.count >= range.upperBound else {
         throw UniffiInternalError.bufferOverflow
     }
     var value = [UInt8](repeating: 0, count: count)
-    value.withUnsafeMutableBufferPointer { buffer in
+    value.withUnsafeMutableBufferPointer({ buffer in
         reader.data.copyBytes(to: buffer, from: range)
-    }
+    })
     reader.offset = range.upperBound
     return value
 }
 
 // Reads a float at the current offset.
-private func readFloat(_ reader: inout (data: Data, offset: Data.Index)) throws -> Float {
-    return try Float(bitPattern: readInt(&reader))
+fileprivate func readFloat(_ reader: inout (data: Data, offset: Data.Index)) throws -> Float {
+    return Float(bitPattern: try readInt(&reader))
 }
 
 // Reads a float at the current offset.
-private func readDouble(_ reader: inout (data: Data, offset: Data.Index)) throws -> Double {
-    return try Double(bitPattern: readInt(&reader))
+fileprivate func readDouble(_ reader: inout (data: Data, offset: Data.Index)) throws -> Double {
+    return Double(bitPattern: try readInt(&reader))
 }
 
 // Indicates if the offset has reached the end of the buffer.
-private func hasRemaining(_ reader: (data: Data, offset: Data.Index)) -> Bool {
+fileprivate func hasRemaining(_ reader: (data: Data, offset: Data.Index)) -> Bool {
     return reader.offset < reader.data.count
 }
 
@@ -129,11 +129,11 @@ private func hasRemaining(_ reader: (data: Data, offset: Data.Index)) -> Bool {
 // struct, but we use standalone functions instead in order to make external
 // types work.  See the above discussion on Readers for details.
 
-private func createWriter() -> [UInt8] {
+fileprivate func createWriter() -> [UInt8] {
     return []
 }
 
-private func writeBytes<S>(_ writer: inout [UInt8], _ byteArr: S) where S: Sequence, S.Element == UInt8 {
+fileprivate func writeBytes<S>(_ writer: inout [UInt8], _ byteArr: S) where S: Sequence, S.Element == UInt8 {
     writer.append(contentsOf: byteArr)
 }
 
@@ -141,22 +141,22 @@ private func writeBytes<S>(_ writer: inout [UInt8], _ byteArr: S) where S: Seque
 //
 // Warning: make sure what you are trying to write
 // is in the correct type!
-private func writeInt<T: FixedWidthInteger>(_ writer: inout [UInt8], _ value: T) {
+fileprivate func writeInt<T: FixedWidthInteger>(_ writer: inout [UInt8], _ value: T) {
     var value = value.bigEndian
     withUnsafeBytes(of: &value) { writer.append(contentsOf: $0) }
 }
 
-private func writeFloat(_ writer: inout [UInt8], _ value: Float) {
+fileprivate func writeFloat(_ writer: inout [UInt8], _ value: Float) {
     writeInt(&writer, value.bitPattern)
 }
 
-private func writeDouble(_ writer: inout [UInt8], _ value: Double) {
+fileprivate func writeDouble(_ writer: inout [UInt8], _ value: Double) {
     writeInt(&writer, value.bitPattern)
 }
 
 // Protocol for types that transfer other types across the FFI. This is
 // analogous to the Rust trait of the same name.
-private protocol FfiConverter {
+fileprivate protocol FfiConverter {
     associatedtype FfiType
     associatedtype SwiftType
 
@@ -167,19 +167,19 @@ private protocol FfiConverter {
 }
 
 // Types conforming to `Primitive` pass themselves directly over the FFI.
-private protocol FfiConverterPrimitive: FfiConverter where FfiType == SwiftType {}
+fileprivate protocol FfiConverterPrimitive: FfiConverter where FfiType == SwiftType { }
 
 extension FfiConverterPrimitive {
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
     public static func lift(_ value: FfiType) throws -> SwiftType {
         return value
     }
 
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
     public static func lower(_ value: SwiftType) -> FfiType {
         return value
     }
@@ -187,12 +187,12 @@ extension FfiConverterPrimitive {
 
 // Types conforming to `FfiConverterRustBuffer` lift and lower into a `RustBuffer`.
 // Used for complex types where it's hard to write a custom lift/lower.
-private protocol FfiConverterRustBuffer: FfiConverter where FfiType == RustBuffer {}
+fileprivate protocol FfiConverterRustBuffer: FfiConverter where FfiType == RustBuffer {}
 
 extension FfiConverterRustBuffer {
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
     public static func lift(_ buf: RustBuffer) throws -> SwiftType {
         var reader = createReader(data: Data(rustBuffer: buf))
         let value = try read(from: &reader)
@@ -203,19 +203,18 @@ extension FfiConverterRustBuffer {
         return value
     }
 
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
     public static func lower(_ value: SwiftType) -> RustBuffer {
-        var writer = createWriter()
-        write(value, into: &writer)
-        return RustBuffer(bytes: writer)
+          var writer = createWriter()
+          write(value, into: &writer)
+          return RustBuffer(bytes: writer)
     }
 }
-
 // An error type for FFI errors. These errors occur at the UniFFI level, not
 // the library level.
-private enum UniffiInternalError: LocalizedError {
+fileprivate enum UniffiInternalError: LocalizedError {
     case bufferOverflow
     case incompleteData
     case unexpectedOptionalTag
@@ -241,24 +240,24 @@ private enum UniffiInternalError: LocalizedError {
     }
 }
 
-private extension NSLock {
+fileprivate extension NSLock {
     func withLock<T>(f: () throws -> T) rethrows -> T {
-        lock()
+        self.lock()
         defer { self.unlock() }
         return try f()
     }
 }
 
-private let CALL_SUCCESS: Int8 = 0
-private let CALL_ERROR: Int8 = 1
-private let CALL_UNEXPECTED_ERROR: Int8 = 2
-private let CALL_CANCELLED: Int8 = 3
+fileprivate let CALL_SUCCESS: Int8 = 0
+fileprivate let CALL_ERROR: Int8 = 1
+fileprivate let CALL_UNEXPECTED_ERROR: Int8 = 2
+fileprivate let CALL_CANCELLED: Int8 = 3
 
-private extension RustCallStatus {
+fileprivate extension RustCallStatus {
     init() {
         self.init(
             code: CALL_SUCCESS,
-            errorBuf: RustBuffer(
+            errorBuf: RustBuffer.init(
                 capacity: 0,
                 len: 0,
                 data: nil
@@ -274,8 +273,7 @@ private func rustCall<T>(_ callback: (UnsafeMutablePointer<RustCallStatus>) -> T
 
 private func rustCallWithError<T, E: Swift.Error>(
     _ errorHandler: @escaping (RustBuffer) throws -> E,
-    _ callback: (UnsafeMutablePointer<RustCallStatus>) -> T
-) throws -> T {
+    _ callback: (UnsafeMutablePointer<RustCallStatus>) -> T) throws -> T {
     try makeRustCall(callback, errorHandler: errorHandler)
 }
 
@@ -284,7 +282,7 @@ private func makeRustCall<T, E: Swift.Error>(
     errorHandler: ((RustBuffer) throws -> E)?
 ) throws -> T {
     uniffiEnsureInitialized()
-    var callStatus = RustCallStatus()
+    var callStatus = RustCallStatus.init()
     let returnedVal = callback(&callStatus)
     try uniffiCheckCallStatus(callStatus: callStatus, errorHandler: errorHandler)
     return returnedVal
@@ -295,44 +293,44 @@ private func uniffiCheckCallStatus<E: Swift.Error>(
     errorHandler: ((RustBuffer) throws -> E)?
 ) throws {
     switch callStatus.code {
-    case CALL_SUCCESS:
-        return
+        case CALL_SUCCESS:
+            return
 
-    case CALL_ERROR:
-        if let errorHandler = errorHandler {
-            throw try errorHandler(callStatus.errorBuf)
-        } else {
-            callStatus.errorBuf.deallocate()
-            throw UniffiInternalError.unexpectedRustCallError
-        }
+        case CALL_ERROR:
+            if let errorHandler = errorHandler {
+                throw try errorHandler(callStatus.errorBuf)
+            } else {
+                callStatus.errorBuf.deallocate()
+                throw UniffiInternalError.unexpectedRustCallError
+            }
 
-    case CALL_UNEXPECTED_ERROR:
-        // When the rust code sees a panic, it tries to construct a RustBuffer
-        // with the message.  But if that code panics, then it just sends back
-        // an empty buffer.
-        if callStatus.errorBuf.len > 0 {
-            throw try UniffiInternalError.rustPanic(FfiConverterString.lift(callStatus.errorBuf))
-        } else {
-            callStatus.errorBuf.deallocate()
-            throw UniffiInternalError.rustPanic("Rust panic")
-        }
+        case CALL_UNEXPECTED_ERROR:
+            // When the rust code sees a panic, it tries to construct a RustBuffer
+            // with the message.  But if that code panics, then it just sends back
+            // an empty buffer.
+            if callStatus.errorBuf.len > 0 {
+                throw UniffiInternalError.rustPanic(try FfiConverterString.lift(callStatus.errorBuf))
+            } else {
+                callStatus.errorBuf.deallocate()
+                throw UniffiInternalError.rustPanic("Rust panic")
+            }
 
-    case CALL_CANCELLED:
-        fatalError("Cancellation not supported yet")
+        case CALL_CANCELLED:
+            fatalError("Cancellation not supported yet")
 
-    default:
-        throw UniffiInternalError.unexpectedRustCallStatusCode
+        default:
+            throw UniffiInternalError.unexpectedRustCallStatusCode
     }
 }
 
 private func uniffiTraitInterfaceCall<T>(
     callStatus: UnsafeMutablePointer<RustCallStatus>,
     makeCall: () throws -> T,
-    writeReturn: (T) -> Void
+    writeReturn: (T) -> ()
 ) {
     do {
         try writeReturn(makeCall())
-    } catch {
+    } catch let error {
         callStatus.pointee.code = CALL_UNEXPECTED_ERROR
         callStatus.pointee.errorBuf = FfiConverterString.lower(String(describing: error))
     }
@@ -341,7 +339,7 @@ private func uniffiTraitInterfaceCall<T>(
 private func uniffiTraitInterfaceCallWithError<T, E>(
     callStatus: UnsafeMutablePointer<RustCallStatus>,
     makeCall: () throws -> T,
-    writeReturn: (T) -> Void,
+    writeReturn: (T) -> (),
     lowerError: (E) -> RustBuffer
 ) {
     do {
@@ -354,8 +352,7 @@ private func uniffiTraitInterfaceCallWithError<T, E>(
         callStatus.pointee.errorBuf = FfiConverterString.lower(String(describing: error))
     }
 }
-
-private class UniffiHandleMap<T> {
+fileprivate class UniffiHandleMap<T> {
     private var map: [UInt64: T] = [:]
     private let lock = NSLock()
     private var currentHandle: UInt64 = 1
@@ -369,7 +366,7 @@ private class UniffiHandleMap<T> {
         }
     }
 
-    func get(handle: UInt64) throws -> T {
+     func get(handle: UInt64) throws -> T {
         try lock.withLock {
             guard let obj = map[handle] else {
                 throw UniffiInternalError.unexpectedStaleHandle
@@ -389,16 +386,20 @@ private class UniffiHandleMap<T> {
     }
 
     var count: Int {
-        map.count
+        get {
+            map.count
+        }
     }
 }
 
+
 // Public interface members begin here.
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterUInt8: FfiConverterPrimitive {
+fileprivate struct FfiConverterUInt8: FfiConverterPrimitive {
     typealias FfiType = UInt8
     typealias SwiftType = UInt8
 
@@ -412,9 +413,9 @@ private struct FfiConverterUInt8: FfiConverterPrimitive {
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterUInt32: FfiConverterPrimitive {
+fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
     typealias FfiType = UInt32
     typealias SwiftType = UInt32
 
@@ -428,9 +429,9 @@ private struct FfiConverterUInt32: FfiConverterPrimitive {
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterUInt64: FfiConverterPrimitive {
+fileprivate struct FfiConverterUInt64: FfiConverterPrimitive {
     typealias FfiType = UInt64
     typealias SwiftType = UInt64
 
@@ -444,9 +445,41 @@ private struct FfiConverterUInt64: FfiConverterPrimitive {
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterInt64: FfiConverterPrimitive {
+    typealias FfiType = Int64
+    typealias SwiftType = Int64
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Int64 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Int64, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDouble: FfiConverterPrimitive {
+    typealias FfiType = Double
+    typealias SwiftType = Double
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Double {
+        return try lift(readDouble(&buf))
+    }
+
+    public static func write(_ value: Double, into buf: inout [UInt8]) {
+        writeDouble(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterBool: FfiConverter {
+fileprivate struct FfiConverterBool : FfiConverter {
     typealias FfiType = Int8
     typealias SwiftType = Bool
 
@@ -468,9 +501,9 @@ private struct FfiConverterBool: FfiConverter {
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterString: FfiConverter {
+fileprivate struct FfiConverterString: FfiConverter {
     typealias SwiftType = String
     typealias FfiType = RustBuffer
 
@@ -498,7 +531,7 @@ private struct FfiConverterString: FfiConverter {
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> String {
         let len: Int32 = try readInt(&buf)
-        return try String(bytes: readBytes(&buf, count: Int(len)), encoding: String.Encoding.utf8)!
+        return String(bytes: try readBytes(&buf, count: Int(len)), encoding: String.Encoding.utf8)!
     }
 
     public static func write(_ value: String, into buf: inout [UInt8]) {
@@ -508,26 +541,97 @@ private struct FfiConverterString: FfiConverter {
     }
 }
 
+
+
+
 /**
  * The main engine for Claude HUD operations.
  *
  * Provides a unified API for all HUD functionality, suitable for any client type.
  * This is the primary FFI interface for Swift/Kotlin/Python clients.
  */
-public protocol HudEngineProtocol: AnyObject {
+public protocol HudEngineProtocol : AnyObject {
+    
+    /**
+     * Returns the most recently updated Working or Compacting session across all
+     * projects, for the global "jump to active work" shortcut.
+     *
+     * # Arguments
+     * * `shell_state` - Current contents of shell-cwd.json (may be None if file missing)
+     */
+    func activeWorkingSession(shellState: ShellCwdStateFfi?)  -> ActiveWorkingSession?
+    
+    /**
+     * File edits over the last 24 hours in `buckets` equal buckets, oldest first,
+     * for the inline activity chart on a project card.
+     */
+    func activitySparkline(projectPath: String, buckets: UInt32)  -> [UInt32]
+    
+    /**
+     * Current and longest runs of consecutive active days for a project.
+     */
+    func activityStreak(projectPath: String)  -> ActivityStreak
+    
     /**
      * Adds a project to the pinned projects list.
      *
      * Also reconciles any orphaned locks for this path to ensure correct state display.
      */
-    func addProject(path: String) throws
-
+    func addProject(path: String) throws 
+    
+    /**
+     * Returns a unified diff for an agent-edited file.
+     *
+     * Untracked files render their full content as an addition.
+     */
+    func agentEditDiff(projectPath: String, filePath: String, includeUntracked: Bool) throws  -> String
+    
+    /**
+     * Resolves which plugin (or "Global"/"Project") provides an artifact.
+     *
+     * Paths are compared after canonicalization so artifacts reached through
+     * symlinked global dirs (e.g. `~/.claude/skills -> ~/dotfiles/skills`) still
+     * resolve. Returns None when the path isn't under any known artifact root.
+     *
+     * The global Claude dir is checked before project dirs: with `~` pinned as a
+     * project, `~/.claude` looks like a project's `.claude` folder.
+     */
+    func artifactOrigin(artifactPath: String)  -> ArtifactOrigin?
+    
+    /**
+     * Lists hook commands in ~/.claude/settings.json whose script or binary is
+     * missing or not executable, including hooks added by other tools.
+     */
+    func auditHooks()  -> [BrokenHook]
+    
+    /**
+     * Reports which pinned projects no longer exist, with a guessed new location.
+     */
+    func auditPinnedProjects()  -> [PinnedProjectAudit]
+    
+    /**
+     * Characters the user typed versus characters Claude wrote in a project's
+     * sessions, ignoring tool traffic.
+     */
+    func authorshipRatio(projectPath: String)  -> AuthorshipRatio
+    
+    /**
+     * Archives `~/.capacitor` (minus lock directories) to a `.tar.gz` at `dest`,
+     * for bug reports and moving to a new machine. Returns the file count.
+     */
+    func backupCapacitorState(dest: String) throws  -> UInt32
+    
+    /**
+     * This month's spend against `HudConfig.monthly_budget_usd`.
+     */
+    func budgetStatus() throws  -> BudgetStatus
+    
     /**
      * Returns the path to the Capacitor data directory as a string.
      * This is where Capacitor stores its own data (~/.capacitor by default).
      */
-    func capacitorDir() -> String
-
+    func capacitorDir()  -> String
+    
     /**
      * Captures a new idea for a project.
      *
@@ -536,15 +640,33 @@ public protocol HudEngineProtocol: AnyObject {
      *
      * Returns the generated ULID for the idea.
      */
-    func captureIdea(projectPath: String, ideaText: String) throws -> String
-
+    func captureIdea(projectPath: String, ideaText: String) throws  -> String
+    
+    /**
+     * Returns the budget status when spend has crossed the 80% or 100% mark
+     * since the previous check, and None otherwise.
+     *
+     * This is the `budget-threshold` event: clients call it from their refresh
+     * loop and notify once per crossing. A drop (e.g. a new month) resets it quietly.
+     */
+    func checkBudgetThreshold() throws  -> BudgetStatus?
+    
     /**
      * Checks the status of a specific dependency.
      *
      * Supported dependencies: "tmux", "claude"
      */
-    func checkDependency(name: String) -> DependencyStatus
-
+    func checkDependency(name: String)  -> DependencyStatus
+    
+    /**
+     * Returns the global `~/.claude/CLAUDE.md` when its content has changed
+     * (including being created or deleted) since the previous check, and None otherwise.
+     *
+     * This is the `global-instructions-changed` event: clients call it from their
+     * refresh loop to keep an instructions editor or preview current.
+     */
+    func checkGlobalInstructionsChanged()  -> GlobalInstructions?
+    
     /**
      * Checks the health of the hook binary by examining its heartbeat file.
      *
@@ -558,8 +680,8 @@ public protocol HudEngineProtocol: AnyObject {
      * - `Stale`: Heartbeat is old (hooks stopped firing)
      * - `Unreadable`: Can't read heartbeat file
      */
-    func checkHookHealth() -> HookHealthReport
-
+    func checkHookHealth()  -> HookHealthReport
+    
     /**
      * Checks the overall setup status including dependencies and hooks.
      *
@@ -569,51 +691,168 @@ public protocol HudEngineProtocol: AnyObject {
      * - Whether storage is ready
      * - Any blocking issues preventing operation
      */
-    func checkSetupStatus() -> SetupStatus
-
+    func checkSetupStatus()  -> SetupStatus
+    
+    /**
+     * Scores how project-like a directory is, with the signals found.
+     */
+    func classifyDirectory(path: String)  -> DirectoryClassification
+    
     /**
      * Returns the path to the Claude directory as a string.
      * This is the Claude Code data directory (~/.claude by default).
      */
-    func claudeDir() -> String
-
+    func claudeDir()  -> String
+    
+    /**
+     * Removes lock directories left by crashed sessions (dead PIDs) and returns
+     * how many were removed. Also runs as part of `run_startup_cleanup`.
+     */
+    func cleanDeadLocks()  -> UInt32
+    
+    /**
+     * Removes expired session-end tombstones.
+     *
+     * With `dry_run`, returns what would be removed without deleting anything.
+     */
+    func clearTombstones(dryRun: Bool)  -> MaintenanceReport
+    
+    /**
+     * Compares the newest session record timestamp to now and warns when the
+     * difference suggests a skewed clock (e.g. a VM with the wrong time).
+     */
+    func clockSkewCheck()  -> ClockSkewReport
+    
+    /**
+     * Drops session records that are stale and hold no live lock, for installs
+     * where ended sessions were never cleaned up (e.g. SessionEnd never fired).
+     *
+     * Fresh and locked sessions are kept. Returns how many records were removed.
+     */
+    func compactStateStore() throws  -> UInt32
+    
+    /**
+     * Lists live sessions that are compacting their context right now, so the
+     * HUD can warn against closing them. Empty when none are.
+     */
+    func compactingSessions()  -> [CompactingSession]
+    
+    /**
+     * Estimates how quickly a session is filling its context window.
+     *
+     * Returns None when the transcript has too few usage blocks to tell.
+     */
+    func contextBurnRate(sessionId: String, projectPath: String)  -> ContextBurnRate?
+    
     /**
      * Creates a CLAUDE.md file for a project.
      *
      * Returns Ok(()) if successful, or an error if the file couldn't be created.
      * Does NOT overwrite existing CLAUDE.md files.
      */
-    func createProjectClaudeMd(projectPath: String) throws
-
+    func createProjectClaudeMd(projectPath: String) throws 
+    
+    /**
+     * Removes orphaned legacy (path-hash) lock directories.
+     *
+     * With `dry_run`, returns what would be removed without deleting anything.
+     */
+    func deleteOrphanedDirs(dryRun: Bool)  -> MaintenanceReport
+    
+    /**
+     * Permanently deletes a project's Claude conversation history.
+     *
+     * `confirm_token` must be the project name. Refuses while a session is
+     * running in the project. Returns bytes reclaimed.
+     */
+    func deleteProjectHistory(projectPath: String, confirmToken: String) throws  -> UInt64
+    
+    /**
+     * Lists session ids recorded under unrelated project paths, a sign that
+     * separate Claude profiles are writing colliding ids into shared state.
+     */
+    func detectSessionIdCollisions()  -> [SessionIdCollision]
+    
+    /**
+     * Lists the known terminal apps that are installed on this system.
+     *
+     * Drives the terminal picker in settings.
+     */
+    func detectTerminals()  -> [DetectedTerminal]
+    
+    /**
+     * Output tokens per uncached input token for each of the last `days` days,
+     * plus whether that ratio is trending up, down, or flat.
+     */
+    func efficiencyTrend(projectPath: String, days: UInt32)  -> EfficiencyTrend
+    
+    /**
+     * Writes everything `load_dashboard` returns, plus each project's session
+     * state, to `path` as pretty-printed JSON. Returns the number of projects exported.
+     */
+    func exportDashboardJson(path: String) throws  -> UInt32
+    
+    /**
+     * Exports a session's state transitions and tool uses as a JSON trace.
+     *
+     * See [`crate::trace`] for the schema.
+     */
+    func exportSessionTrace(sessionId: String, projectPath: String) throws  -> String
+    
+    /**
+     * Writes per-project token and cost stats for the pinned projects to `path`
+     * as CSV, one row per project, from the stats cache. Returns the row count.
+     */
+    func exportStatsCsv(path: String) throws  -> UInt32
+    
+    /**
+     * Pinned projects nested inside other pinned projects, so the UI can
+     * suggest consolidating them.
+     */
+    func findOverlappingPins()  -> [OverlappingPins]
+    
+    /**
+     * Lists session records whose working directory has been deleted and that
+     * hold no live lock. These can never become active again.
+     */
+    func findZombieSessions() throws  -> [ZombieSession]
+    
+    /**
+     * Releases the locks of a session listed by `orphaned_live_sessions`.
+     *
+     * Refuses sessions whose folder still exists. Returns the number of locks released.
+     */
+    func forceReleaseLock(sessionId: String) throws  -> UInt32
+    
     /**
      * Detects all agent sessions for a project path.
      *
      * Returns sessions from all installed agents (Claude, Codex, Aider, etc.)
      * that have active sessions at the given path or its children.
      */
-    func getAgentSessions(projectPath: String) -> [AgentSession]
-
+    func getAgentSessions(projectPath: String)  -> [AgentSession]
+    
     /**
      * Gets all agent sessions across all projects (cached).
      *
      * Uses mtime-based caching for efficient repeated calls.
      * Call `invalidate_agent_cache()` to force a refresh.
      */
-    func getAllAgentSessions() -> [AgentSession]
-
+    func getAllAgentSessions()  -> [AgentSession]
+    
     /**
      * Gets session states for multiple projects.
      * Uses session-ID keyed state and lock detection for reliable state.
      *
      * Takes a Vec instead of slice for FFI compatibility.
      */
-    func getAllSessionStates(projects: [Project]) -> [String: ProjectSessionState]
-
+    func getAllSessionStates(projects: [Project])  -> [String: ProjectSessionState]
+    
     /**
      * Gets the HUD configuration (pinned projects, terminal app, etc.)
      */
-    func getConfig() -> HudConfig
-
+    func getConfig()  -> HudConfig
+    
     /**
      * Returns a unified diagnostic report for hook status.
      *
@@ -626,47 +865,103 @@ public protocol HudEngineProtocol: AnyObject {
      * - `can_auto_fix`: True if "Fix All" can resolve the issue
      * - Individual status flags for checklist display
      */
-    func getHookDiagnostic() -> HookDiagnosticReport
-
+    func getHookDiagnostic()  -> HookDiagnosticReport
+    
     /**
      * Returns the current hook status without full setup check.
      *
      * Useful for quick hook status checks in the UI.
      */
-    func getHookStatus() -> HookStatus
-
+    func getHookStatus()  -> HookStatus
+    
     /**
      * Returns the file path where ideas are stored for a project.
      *
      * This is useful for mtime-based change detection in the UI.
      * Path: `~/.capacitor/projects/{encoded-path}/ideas.md`
      */
-    func getIdeasFilePath(projectPath: String) -> String
-
+    func getIdeasFilePath(projectPath: String)  -> String
+    
     /**
      * Detects the primary agent session for a project path.
      *
      * Returns the first session found based on user preference order.
      * Use this when you only need to display one agent's state.
      */
-    func getPrimaryAgentSession(projectPath: String) -> AgentSession?
-
+    func getPrimaryAgentSession(projectPath: String)  -> AgentSession?
+    
+    /**
+     * A project's card plus its instructions content, tasks, and git state.
+     */
+    func getProjectDetails(path: String) throws  -> ProjectDetails
+    
     /**
      * Gets project status from .claude/hud-status.json.
      */
-    func getProjectStatus(projectPath: String) -> ProjectStatus?
-
+    func getProjectStatus(projectPath: String)  -> ProjectStatus?
+    
+    /**
+     * How full a session's context window is, so the HUD can warn before an
+     * auto-compact. None when the session has no transcript usage yet.
+     */
+    func getSessionContext(sessionId: String)  -> ContextInfo?
+    
     /**
      * Gets the session state for a single project.
      * Uses session-ID keyed state and lock detection for reliable state.
      */
-    func getSessionState(projectPath: String) -> ProjectSessionState
-
+    func getSessionState(projectPath: String)  -> ProjectSessionState
+    
+    /**
+     * The last `limit` user/assistant messages of a session as plain text,
+     * oldest first, for peeking at what it's doing. Empty when not found.
+     */
+    func getSessionTranscript(sessionId: String, limit: UInt32)  -> [TranscriptMessage]
+    
     /**
      * Discovers suggested projects based on activity in ~/.claude/projects.
      */
-    func getSuggestedProjects() throws -> [SuggestedProject]
-
+    func getSuggestedProjects() throws  -> [SuggestedProject]
+    
+    /**
+     * The checked-out branch, or `detached @ <short sha>` for a detached HEAD.
+     * None if the path isn't a git repository.
+     */
+    func gitBranch(path: String)  -> String?
+    
+    /**
+     * Number of git stash entries for a project, None if it isn't a git repository.
+     */
+    func gitStashCount(path: String)  -> UInt32?
+    
+    /**
+     * Total estimated prompt-cache savings in dollars across pinned projects.
+     */
+    func globalCacheSavingsUsd()  -> Double
+    
+    /**
+     * A project's sessions with resumed continuations folded into one entry
+     * each, newest first.
+     */
+    func groupContinuedSessions(projectPath: String)  -> [SessionGroup]
+    
+    /**
+     * Groups pinned projects by their common ancestor `depth` levels below home.
+     */
+    func groupProjectsByRoot(depth: UInt32)  -> [ProjectGroup]
+    
+    /**
+     * Min/median/p95 run times of recent hook invocations, per subcommand,
+     * from the hook's debug log. Empty until the hook has logged timed runs.
+     */
+    func hookLatencyStats()  -> [HookLatencyStats]
+    
+    /**
+     * Returns the most-edited files in a project, aggregated across all sessions
+     * in the file-activity feed.
+     */
+    func hotFiles(projectPath: String, limit: UInt32)  -> [HotFile]
+    
     /**
      * Installs the hook binary from a source path to ~/.local/bin/hud-hook.
      *
@@ -682,8 +977,8 @@ public protocol HudEngineProtocol: AnyObject {
      * - Cannot create ~/.local/bin
      * - Cannot copy or set permissions
      */
-    func installHookBinaryFromPath(sourcePath: String) throws -> InstallResult
-
+    func installHookBinaryFromPath(sourcePath: String) throws  -> InstallResult
+    
     /**
      * Installs the session tracking hooks.
      *
@@ -695,64 +990,255 @@ public protocol HudEngineProtocol: AnyObject {
      * - Hook binary is missing or broken
      * - Hooks are disabled by policy (disableAllHooks or allowManagedHooksOnly)
      * - File system operations fail
+     *
+     * Deprecated: writes settings.json without showing what changes first, which
+     * can clobber existing Stop hooks. Use `install_hooks_with_confirm`.
      */
-    func installHooks() throws -> InstallResult
-
+    func installHooks() throws  -> InstallResult
+    
+    /**
+     * Two-phase hook install.
+     *
+     * With `confirm` false, returns the current and proposed `hooks.Stop` from
+     * ~/.claude/settings.json and writes nothing. Call again with `confirm` true
+     * to perform the install; the outcome then also carries the install result.
+     */
+    func installHooksWithConfirm(confirm: Bool) throws  -> HookInstallOutcome
+    
     /**
      * Invalidates the agent session cache.
      *
      * Call this when you know the underlying state has changed
      * and want to force a fresh read on the next call.
      */
-    func invalidateAgentCache()
-
+    func invalidateAgentCache() 
+    
+    /**
+     * Whether a path is inside a git repository, checked without spawning git.
+     */
+    func isGitRepo(path: String)  -> GitRepoCheck
+    
+    /**
+     * The shell command a new terminal runs to start (or resume) Claude in a
+     * project, honoring the project's custom launch command if configured.
+     */
+    func launchCommand(projectPath: String, claudePath: String, resumeSessionId: String?)  -> String
+    
     /**
      * Lists all artifacts (skills, commands, agents) from global and plugin sources.
+     *
+     * Plugin artifacts switched off with `toggle_plugin_artifact` are left out.
      */
-    func listArtifacts() -> [Artifact]
-
+    func listArtifacts()  -> [Artifact]
+    
     /**
      * Returns the list of installed agent IDs.
      *
      * Useful for debugging and UI display of which agents are available.
      */
-    func listInstalledAgents() -> [String]
-
+    func listInstalledAgents()  -> [String]
+    
+    /**
+     * Lists one plugin's artifacts, including ones switched off, with `enabled`
+     * set so the UI can render a toggle next to each.
+     */
+    func listPluginArtifacts(pluginId: String)  -> [Artifact]
+    
     /**
      * Lists all installed plugins.
      */
-    func listPlugins() throws -> [Plugin]
-
+    func listPlugins() throws  -> [Plugin]
+    
     /**
      * Lists all pinned projects, sorted by most recent activity.
      */
-    func listProjects() throws -> [Project]
-
+    func listProjects() throws  -> [Project]
+    
     /**
      * Loads all dashboard data in one call.
+     *
+     * Succeeds with partial data when plugins, settings, or projects fail to load;
+     * each non-fatal failure is reported in `DashboardData.warnings`.
      */
-    func loadDashboard() throws -> DashboardData
-
+    func loadDashboard() throws  -> DashboardData
+    
     /**
      * Loads all ideas for a project.
      *
      * Returns an empty vector if the ideas file doesn't exist.
      */
-    func loadIdeas(projectPath: String) throws -> [Idea]
-
+    func loadIdeas(projectPath: String) throws  -> [Idea]
+    
     /**
      * Loads the display order of ideas for a project.
      *
      * Returns an empty vector if no order file exists (graceful degradation).
      * The caller should merge this with loaded ideas: ordered first, unordered appended.
      */
-    func loadIdeasOrder(projectPath: String) throws -> [String]
-
+    func loadIdeasOrder(projectPath: String) throws  -> [String]
+    
+    /**
+     * Lists MCP servers from global settings and, if given, the project's `.mcp.json`.
+     */
+    func loadMcpServers(projectPath: String?)  -> [McpServer]
+    
+    /**
+     * Token usage per day or week for a project, split by model family.
+     *
+     * Suited to a stacked area chart; gaps between active buckets are zero-filled.
+     */
+    func modelUsageTimeseries(projectPath: String, bucket: UsageBucket)  -> [ModelUsagePoint]
+    
+    /**
+     * Collects the non-empty `next_step` of every pinned project, newest first,
+     * as a cross-project agenda.
+     */
+    func nextStepsDigest()  -> [NextStep]
+    
+    /**
+     * Returns the project's `.claude/settings.local.json` for editing, creating it as `{}`
+     * if absent. `Project.has_local_settings` tells the UI whether to label it create or edit.
+     */
+    func openLocalSettings(path: String) throws  -> String
+    
+    /**
+     * Returns the MCP config file to edit for a scope.
+     *
+     * Creates an empty project `.mcp.json` if needed. The client opens the returned path.
+     */
+    func openMcpConfig(scope: McpScope, projectPath: String?) throws  -> String
+    
+    /**
+     * The file behind a project's "edit config" button: `CLAUDE.md`, else
+     * `.claude/settings.local.json`, else global settings. With `create_local_settings`,
+     * a missing local settings file is created rather than falling back to global.
+     */
+    func openProjectConfig(path: String, createLocalSettings: Bool) throws  -> ProjectConfigFile
+    
+    /**
+     * Returns the IDE command that reopens a session's task: the project plus
+     * the files the session most recently edited (capped). Swift runs it.
+     */
+    func openTaskContext(sessionId: String, projectPath: String, editor: ParentApp) throws  -> EditorLaunch
+    
+    /**
+     * Lists running sessions whose working directory has been deleted.
+     */
+    func orphanedLiveSessions()  -> [OrphanedLiveSession]
+    
+    /**
+     * The `limit` sessions across all projects that raised the most permission
+     * prompts, with the tools involved.
+     */
+    func permissionHeavySessions(limit: UInt32)  -> [PermissionHeavySession]
+    
+    /**
+     * Lists recent permission prompts and denials for a session, oldest first.
+     */
+    func permissionHistory(sessionId: String, projectPath: String)  -> [PermissionEntry]
+    
+    /**
+     * Returns the sessions that started waiting on the user (usually for a
+     * permission prompt) since this subscription's previous poll.
+     *
+     * This is the `session-waiting` event: a Waiting-only view of
+     * [`Self::poll_state_feed`] for the same subscription. Clients call it from
+     * their refresh loop and notify once per event.
+     */
+    func pollSessionWaiting(subscriptionId: UInt64) throws  -> [SessionWaitingEvent]
+    
+    /**
+     * Returns the state changes since this subscription's previous poll, one
+     * event per session that appeared, changed state, or ended.
+     */
+    func pollStateFeed(subscriptionId: UInt64) throws  -> [StateFeedEvent]
+    
+    /**
+     * Recomputed stats for each of `project_paths` whose session transcripts
+     * changed since the previous call.
+     *
+     * This is the `stats-changed` event: clients call it from their refresh loop
+     * to keep dashboard stats live. The first call for a project only records a
+     * baseline, and changes are held until transcripts have been quiet for
+     * [`crate::stats::STATS_CHANGE_DEBOUNCE`], so one burst yields one event.
+     */
+    func pollStatsChanges(projectPaths: [String]) throws  -> [StatsChangedEvent]
+    
+    /**
+     * Sessions and file edits per day for a project over the last `days` days,
+     * normalized both per calendar day and per active day.
+     */
+    func projectVelocity(projectPath: String, days: UInt32)  -> ProjectVelocity
+    
+    /**
+     * Projects whose live session is running subagents, with the count, so the
+     * HUD can show parallel agent work. Empty when none are.
+     */
+    func projectsWithSubagents()  -> [ProjectSubagents]
+    
+    /**
+     * Removes old entries from the file activity feed.
+     *
+     * With `dry_run`, returns what would be removed without modifying the feed.
+     */
+    func pruneActivity(dryRun: Bool)  -> MaintenanceReport
+    
+    /**
+     * Unpins every project whose folder is missing. Returns the removed paths.
+     */
+    func pruneMissingProjects() throws  -> [String]
+    
+    /**
+     * Prunes stale `sessions.json` records whose session holds no live lock,
+     * returning how many were removed. Also runs on each dashboard load.
+     */
+    func pruneSessions() throws  -> UInt32
+    
+    /**
+     * Content of the global `~/.claude/CLAUDE.md`, None if it doesn't exist.
+     */
+    func readGlobalInstructions()  -> String?
+    
+    /**
+     * Reads the tail of the status generation log for debugging.
+     *
+     * Empty unless `HudConfig.status_gen_logging` is on and the hook has run.
+     */
+    func readStatusGenLog()  -> [String]
+    
+    /**
+     * Removes lock directories whose process has exited.
+     *
+     * With `dry_run`, returns what would be removed without deleting anything.
+     */
+    func reapStaleLocks(dryRun: Bool)  -> MaintenanceReport
+    
     /**
      * Removes a project from the pinned projects list.
      */
-    func removeProject(path: String) throws
-
+    func removeProject(path: String) throws 
+    
+    /**
+     * Removes the records listed by `find_zombie_sessions`, re-checking each first.
+     *
+     * With `dry_run`, returns what would be removed without modifying the store.
+     */
+    func removeZombieSessions(dryRun: Bool)  -> MaintenanceReport
+    
+    /**
+     * Sets the pinned projects' order and switches the list to manual order.
+     *
+     * `paths` must be exactly the currently pinned projects.
+     */
+    func reorderProjects(paths: [String]) throws 
+    
+    /**
+     * Salvages valid project entries from a partially-corrupt stats cache.
+     *
+     * Returns how many entries were kept vs dropped.
+     */
+    func repairStatsCache() throws  -> StatsCacheRepair
+    
     /**
      * Resolves what activation action to take for a project.
      *
@@ -778,20 +1264,45 @@ public protocol HudEngineProtocol: AnyObject {
      * await executeAction(decision.primary)
      * ```
      */
-    func resolveActivation(projectPath: String, shellState: ShellCwdStateFfi?, tmuxContext: TmuxContextFfi) -> ActivationDecision
-
+    func resolveActivation(projectPath: String, shellState: ShellCwdStateFfi?, tmuxContext: TmuxContextFfi)  -> ActivationDecision
+    
     /**
-     * Runs a comprehensive hook system test.
+     * Lists every artifact a project can see and whether it is actually active there.
      *
-     * This verifies:
-     * 1. Heartbeat file exists and is recent (< 60s old)
-     * 2. State file (sessions.json) can be written and read back
+     * Combines global, plugin, and project (`<project>/.claude/`) artifacts, applies
+     * the project's `enabledPlugins` overrides, and marks same-named artifacts
+     * shadowed by a higher-precedence source (project, then plugin, then global).
+     */
+    func resolveEffectiveArtifacts(projectPath: String)  -> [EffectiveArtifact]
+    
+    /**
+     * Resolves the Claude session for any path, pinned or not.
+     *
+     * Reads the state store and locks directly on each call, bypassing the
+     * registry cache. Suits one-off lookups such as hovering a suggested project.
+     */
+    func resolvePathState(path: String)  -> AgentSession?
+    
+    /**
+     * Replaces `~/.capacitor` with the contents of a backup and returns the
+     * path of the safety backup taken of the previous state.
+     *
+     * Watchers are paused for the duration so clients don't read half-restored files.
+     */
+    func restoreCapacitorState(src: String) throws  -> String
+    
+    /**
+     * Runs a comprehensive hook system test.
+     *
+     * This verifies:
+     * 1. Heartbeat file exists and is recent (< 60s old)
+     * 2. State file (sessions.json) can be written and read back
      *
      * Used by the "Test Hooks" button in SetupStatusCard to give users
      * confidence that the hook system is functioning correctly.
      */
-    func runHookTest() -> HookTestResult
-
+    func runHookTest()  -> HookTestResult
+    
     /**
      * Performs startup cleanup of stale artifacts.
      *
@@ -801,53 +1312,180 @@ public protocol HudEngineProtocol: AnyObject {
      *
      * Returns stats about what was cleaned up.
      */
-    func runStartupCleanup() -> CleanupStats
-
+    func runStartupCleanup()  -> CleanupStats
+    
     /**
      * Saves the display order of ideas for a project.
      *
      * The order is stored separately from idea content in `~/.capacitor/projects/{encoded}/ideas-order.json`.
      * This prevents churning the ideas markdown file on every drag-reorder.
      */
-    func saveIdeasOrder(projectPath: String, ideaIds: [String]) throws
-
+    func saveIdeasOrder(projectPath: String, ideaIds: [String]) throws 
+    
+    /**
+     * Average, median, and longest session length for a project, to tell quick
+     * tweaks from long deep-work sessions.
+     */
+    func sessionDurationStats(projectPath: String)  -> SessionDurationStats
+    
+    /**
+     * Measures time-to-first-response across a session's prompts.
+     *
+     * Returns None when the transcript has no timed prompt/response pairs.
+     */
+    func sessionLatency(sessionId: String, projectPath: String)  -> SessionLatency?
+    
+    /**
+     * One-glance summary of a session for sharing: first prompt, final reply,
+     * duration, tokens and cost, edited files, and models.
+     *
+     * Returns None when the transcript is missing.
+     */
+    func sessionReceipt(sessionId: String, projectPath: String)  -> SessionReceipt?
+    
+    /**
+     * Groups live sessions by the terminal or IDE hosting them.
+     *
+     * # Arguments
+     * * `shell_state` - Current contents of shell-cwd.json (may be None if file missing)
+     */
+    func sessionsByHostApp(shellState: ShellCwdStateFfi?)  -> [HostAppSessions]
+    
+    /**
+     * Switches the project list between manual order and most-recent-activity order.
+     */
+    func setManualOrder(enabled: Bool) throws 
+    
+    /**
+     * Sets a project's display name; an empty alias restores the folder name.
+     */
+    func setProjectAlias(path: String, alias: String) throws 
+    
+    /**
+     * Pauses or resumes background refresh while the HUD isn't visible.
+     *
+     * While paused, the poll and check methods clients call from their refresh
+     * loop (`poll_stats_changes`, `check_budget_threshold`,
+     * `check_global_instructions_changed`, `poll_state_feed`,
+     * `poll_session_waiting`) return nothing without touching disk. Their
+     * baselines are kept, so the first poll after resuming reports whatever
+     * changed in the meantime. Pausing on window blur saves CPU and battery.
+     */
+    func setWatchersPaused(paused: Bool) 
+    
+    /**
+     * Live sessions across all projects counted by state, for a dashboard chart.
+     */
+    func stateDistribution()  -> StateDistribution
+    
+    /**
+     * Each session file's token and message contribution to a project's stats,
+     * largest first, for tracking down where usage comes from.
+     */
+    func statsBreakdown(projectPath: String)  -> [FileStatsContribution]
+    
+    /**
+     * Starts a feed of session state changes across all projects and returns
+     * its subscription id.
+     *
+     * The feed begins at the current state: the first poll reports only what
+     * changed after subscribing. Call [`Self::unsubscribe_state_feed`] when done.
+     */
+    func subscribeStateFeed()  -> UInt64
+    
+    /**
+     * Assembles the app and session context for feedback reports and the debug panel.
+     */
+    func telemetryContext()  -> TelemetryContext
+    
+    /**
+     * The terminal app and shell cwd for a tty, or None if no tracked shell uses it.
+     *
+     * # Arguments
+     * * `shell_state` - Current contents of shell-cwd.json (may be None if file missing)
+     */
+    func terminalForTty(tty: String, shellState: ShellCwdStateFfi?)  -> TtyTerminal?
+    
+    /**
+     * How to open a terminal at a project, running its launch command when
+     * `run_claude` is set, with `--resume <id>` when `resume_session_id` is given.
+     *
+     * On macOS this is the configured `HudConfig.terminal_app`, each with its own
+     * strategy (`open -a` with arguments, or AppleScript that types the command).
+     * On Linux it is `$TERMINAL` or the first installed common emulator, and on
+     * Windows, Windows Terminal or `cmd`. The app spawns the returned process.
+     *
+     * Returns an error listing the probed terminals when none is installed.
+     */
+    func terminalLaunch(projectPath: String, runClaude: Bool, resumeSessionId: String?) throws  -> TerminalLaunch
+    
+    /**
+     * Switches a single skill, command, or agent of a plugin on or off.
+     *
+     * `artifact_type` is "skill", "command", or "agent".
+     */
+    func togglePluginArtifact(pluginId: String, artifactType: String, artifactName: String, enabled: Bool) throws 
+    
+    /**
+     * The `limit` most expensive sessions across all projects, most expensive first.
+     */
+    func topSessionsByCost(limit: UInt32)  -> [SessionCost]
+    
+    /**
+     * Lists uncommitted files in a project that agent sessions edited.
+     *
+     * Newly-created untracked files are included unless `include_untracked` is false.
+     */
+    func uncommittedAgentChanges(projectPath: String, includeUntracked: Bool) throws  -> [AgentChange]
+    
+    /**
+     * Ends a state feed subscription. Returns false if it was already gone.
+     */
+    func unsubscribeStateFeed(subscriptionId: UInt64)  -> Bool
+    
     /**
      * Updates the description of an idea.
      *
      * Used for sensemaking - the idea is initially saved with raw user input,
      * then this is called with an AI-generated expansion.
      */
-    func updateIdeaDescription(projectPath: String, ideaId: String, newDescription: String) throws
-
+    func updateIdeaDescription(projectPath: String, ideaId: String, newDescription: String) throws 
+    
     /**
      * Updates the effort estimate of an idea.
      *
      * Valid efforts: unknown, small, medium, large, xl
      */
-    func updateIdeaEffort(projectPath: String, ideaId: String, newEffort: String) throws
-
+    func updateIdeaEffort(projectPath: String, ideaId: String, newEffort: String) throws 
+    
     /**
      * Updates the status of an idea.
      *
      * Valid statuses: open, in-progress, done
      */
-    func updateIdeaStatus(projectPath: String, ideaId: String, newStatus: String) throws
-
+    func updateIdeaStatus(projectPath: String, ideaId: String, newStatus: String) throws 
+    
     /**
      * Updates the title of an idea.
      *
      * Used for async title generation - the idea is initially saved with a placeholder,
      * then this is called once the AI-generated title is ready.
      */
-    func updateIdeaTitle(projectPath: String, ideaId: String, newTitle: String) throws
-
+    func updateIdeaTitle(projectPath: String, ideaId: String, newTitle: String) throws 
+    
     /**
      * Updates the triage status of an idea.
      *
      * Valid triage statuses: pending, validated
      */
-    func updateIdeaTriage(projectPath: String, ideaId: String, newTriage: String) throws
-
+    func updateIdeaTriage(projectPath: String, ideaId: String, newTriage: String) throws 
+    
+    /**
+     * Token totals and estimated cost across all projects for days from `start`
+     * to `end` inclusive (`YYYY-MM-DD`), for week and billing-month views.
+     */
+    func usageForPeriod(start: String, end: String) throws  -> PeriodUsage
+    
     /**
      * Validates a project path before adding it.
      *
@@ -860,7 +1498,20 @@ public protocol HudEngineProtocol: AnyObject {
      * - Offering to create CLAUDE.md when missing
      * - Detecting if the project is already tracked
      */
-    func validateProject(path: String) -> ValidationResultFfi
+    func validateProject(path: String)  -> ValidationResultFfi
+    
+    /**
+     * Audits how often Claude project folder names decode to the wrong path.
+     *
+     * Compares each folder's decoded path with the cwd recorded in its transcripts.
+     */
+    func verifyProjectEncoding()  -> ProjectEncodingReport
+    
+    /**
+     * Returns true while background refresh is paused.
+     */
+    func watchersPaused()  -> Bool
+    
 }
 
 /**
@@ -870,14 +1521,13 @@ public protocol HudEngineProtocol: AnyObject {
  * This is the primary FFI interface for Swift/Kotlin/Python clients.
  */
 open class HudEngine:
-    HudEngineProtocol
-{
+    HudEngineProtocol {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
     public struct NoPointer {
         public init() {}
     }
@@ -885,7 +1535,7 @@ open class HudEngine:
     // TODO: We'd like this to be `private` but for Swifty reasons,
     // we can't implement `FfiConverter` without making this `required` and we can't
     // make it `required` without making it `public`.
-    public required init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
         self.pointer = pointer
     }
 
@@ -894,33 +1544,32 @@ open class HudEngine:
     //
     // - Warning:
     //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
-    public init(noPointer _: NoPointer) {
-        pointer = nil
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
     }
 
-    #if swift(>=5.8)
-        @_documentation(visibility: private)
-    #endif
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
         return try! rustCall { uniffi_hud_core_fn_clone_hudengine(self.pointer, $0) }
     }
-
     /**
      * Creates a new HudEngine instance with default storage configuration.
      *
      * Uses `~/.capacitor/` for Capacitor data and `~/.claude/` for Claude data.
      */
-    public convenience init() throws {
-        let pointer =
-            try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-                uniffi_hud_core_fn_constructor_hudengine_new($0
-                )
-            }
-        self.init(unsafeFromRawPointer: pointer)
-    }
+public convenience init()throws  {
+    let pointer =
+        try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_constructor_hudengine_new($0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
 
     deinit {
         guard let pointer = pointer else {
@@ -930,27 +1579,159 @@ open class HudEngine:
         try! rustCall { uniffi_hud_core_fn_free_hudengine(pointer, $0) }
     }
 
+    
+
+    
+    /**
+     * Returns the most recently updated Working or Compacting session across all
+     * projects, for the global "jump to active work" shortcut.
+     *
+     * # Arguments
+     * * `shell_state` - Current contents of shell-cwd.json (may be None if file missing)
+     */
+open func activeWorkingSession(shellState: ShellCwdStateFfi?) -> ActiveWorkingSession? {
+    return try!  FfiConverterOptionTypeActiveWorkingSession.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_active_working_session(self.uniffiClonePointer(),
+        FfiConverterOptionTypeShellCwdStateFfi.lower(shellState),$0
+    )
+})
+}
+    
+    /**
+     * File edits over the last 24 hours in `buckets` equal buckets, oldest first,
+     * for the inline activity chart on a project card.
+     */
+open func activitySparkline(projectPath: String, buckets: UInt32) -> [UInt32] {
+    return try!  FfiConverterSequenceUInt32.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_activity_sparkline(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterUInt32.lower(buckets),$0
+    )
+})
+}
+    
+    /**
+     * Current and longest runs of consecutive active days for a project.
+     */
+open func activityStreak(projectPath: String) -> ActivityStreak {
+    return try!  FfiConverterTypeActivityStreak.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_activity_streak(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
     /**
      * Adds a project to the pinned projects list.
      *
      * Also reconciles any orphaned locks for this path to ensure correct state display.
      */
-    open func addProject(path: String) throws { try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-        uniffi_hud_core_fn_method_hudengine_add_project(self.uniffiClonePointer(),
-                                                        FfiConverterString.lower(path), $0)
-    }
-    }
-
+open func addProject(path: String)throws  {try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_add_project(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+}
+}
+    
+    /**
+     * Returns a unified diff for an agent-edited file.
+     *
+     * Untracked files render their full content as an addition.
+     */
+open func agentEditDiff(projectPath: String, filePath: String, includeUntracked: Bool)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_agent_edit_diff(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterString.lower(filePath),
+        FfiConverterBool.lower(includeUntracked),$0
+    )
+})
+}
+    
+    /**
+     * Resolves which plugin (or "Global"/"Project") provides an artifact.
+     *
+     * Paths are compared after canonicalization so artifacts reached through
+     * symlinked global dirs (e.g. `~/.claude/skills -> ~/dotfiles/skills`) still
+     * resolve. Returns None when the path isn't under any known artifact root.
+     *
+     * The global Claude dir is checked before project dirs: with `~` pinned as a
+     * project, `~/.claude` looks like a project's `.claude` folder.
+     */
+open func artifactOrigin(artifactPath: String) -> ArtifactOrigin? {
+    return try!  FfiConverterOptionTypeArtifactOrigin.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_artifact_origin(self.uniffiClonePointer(),
+        FfiConverterString.lower(artifactPath),$0
+    )
+})
+}
+    
+    /**
+     * Lists hook commands in ~/.claude/settings.json whose script or binary is
+     * missing or not executable, including hooks added by other tools.
+     */
+open func auditHooks() -> [BrokenHook] {
+    return try!  FfiConverterSequenceTypeBrokenHook.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_audit_hooks(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Reports which pinned projects no longer exist, with a guessed new location.
+     */
+open func auditPinnedProjects() -> [PinnedProjectAudit] {
+    return try!  FfiConverterSequenceTypePinnedProjectAudit.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_audit_pinned_projects(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Characters the user typed versus characters Claude wrote in a project's
+     * sessions, ignoring tool traffic.
+     */
+open func authorshipRatio(projectPath: String) -> AuthorshipRatio {
+    return try!  FfiConverterTypeAuthorshipRatio.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_authorship_ratio(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
+    /**
+     * Archives `~/.capacitor` (minus lock directories) to a `.tar.gz` at `dest`,
+     * for bug reports and moving to a new machine. Returns the file count.
+     */
+open func backupCapacitorState(dest: String)throws  -> UInt32 {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_backup_capacitor_state(self.uniffiClonePointer(),
+        FfiConverterString.lower(dest),$0
+    )
+})
+}
+    
+    /**
+     * This month's spend against `HudConfig.monthly_budget_usd`.
+     */
+open func budgetStatus()throws  -> BudgetStatus {
+    return try  FfiConverterTypeBudgetStatus.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_budget_status(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
      * Returns the path to the Capacitor data directory as a string.
      * This is where Capacitor stores its own data (~/.capacitor by default).
      */
-    open func capacitorDir() -> String {
-        return try! FfiConverterString.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_capacitor_dir(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func capacitorDir() -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_capacitor_dir(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
      * Captures a new idea for a project.
      *
@@ -959,26 +1740,56 @@ open class HudEngine:
      *
      * Returns the generated ULID for the idea.
      */
-    open func captureIdea(projectPath: String, ideaText: String) throws -> String {
-        return try FfiConverterString.lift(rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-            uniffi_hud_core_fn_method_hudengine_capture_idea(self.uniffiClonePointer(),
-                                                             FfiConverterString.lower(projectPath),
-                                                             FfiConverterString.lower(ideaText), $0)
-        })
-    }
-
+open func captureIdea(projectPath: String, ideaText: String)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_capture_idea(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterString.lower(ideaText),$0
+    )
+})
+}
+    
+    /**
+     * Returns the budget status when spend has crossed the 80% or 100% mark
+     * since the previous check, and None otherwise.
+     *
+     * This is the `budget-threshold` event: clients call it from their refresh
+     * loop and notify once per crossing. A drop (e.g. a new month) resets it quietly.
+     */
+open func checkBudgetThreshold()throws  -> BudgetStatus? {
+    return try  FfiConverterOptionTypeBudgetStatus.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_check_budget_threshold(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
      * Checks the status of a specific dependency.
      *
      * Supported dependencies: "tmux", "claude"
      */
-    open func checkDependency(name: String) -> DependencyStatus {
-        return try! FfiConverterTypeDependencyStatus.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_check_dependency(self.uniffiClonePointer(),
-                                                                 FfiConverterString.lower(name), $0)
-        })
-    }
-
+open func checkDependency(name: String) -> DependencyStatus {
+    return try!  FfiConverterTypeDependencyStatus.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_check_dependency(self.uniffiClonePointer(),
+        FfiConverterString.lower(name),$0
+    )
+})
+}
+    
+    /**
+     * Returns the global `~/.claude/CLAUDE.md` when its content has changed
+     * (including being created or deleted) since the previous check, and None otherwise.
+     *
+     * This is the `global-instructions-changed` event: clients call it from their
+     * refresh loop to keep an instructions editor or preview current.
+     */
+open func checkGlobalInstructionsChanged() -> GlobalInstructions? {
+    return try!  FfiConverterOptionTypeGlobalInstructions.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_check_global_instructions_changed(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
      * Checks the health of the hook binary by examining its heartbeat file.
      *
@@ -992,12 +1803,13 @@ open class HudEngine:
      * - `Stale`: Heartbeat is old (hooks stopped firing)
      * - `Unreadable`: Can't read heartbeat file
      */
-    open func checkHookHealth() -> HookHealthReport {
-        return try! FfiConverterTypeHookHealthReport.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_check_hook_health(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func checkHookHealth() -> HookHealthReport {
+    return try!  FfiConverterTypeHookHealthReport.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_check_hook_health(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
      * Checks the overall setup status including dependencies and hooks.
      *
@@ -1007,166 +1819,517 @@ open class HudEngine:
      * - Whether storage is ready
      * - Any blocking issues preventing operation
      */
-    open func checkSetupStatus() -> SetupStatus {
-        return try! FfiConverterTypeSetupStatus.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_check_setup_status(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func checkSetupStatus() -> SetupStatus {
+    return try!  FfiConverterTypeSetupStatus.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_check_setup_status(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Scores how project-like a directory is, with the signals found.
+     */
+open func classifyDirectory(path: String) -> DirectoryClassification {
+    return try!  FfiConverterTypeDirectoryClassification.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_classify_directory(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
     /**
      * Returns the path to the Claude directory as a string.
      * This is the Claude Code data directory (~/.claude by default).
      */
-    open func claudeDir() -> String {
-        return try! FfiConverterString.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_claude_dir(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func claudeDir() -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_claude_dir(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
-     * Creates a CLAUDE.md file for a project.
-     *
-     * Returns Ok(()) if successful, or an error if the file couldn't be created.
-     * Does NOT overwrite existing CLAUDE.md files.
+     * Removes lock directories left by crashed sessions (dead PIDs) and returns
+     * how many were removed. Also runs as part of `run_startup_cleanup`.
      */
-    open func createProjectClaudeMd(projectPath: String) throws { try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-        uniffi_hud_core_fn_method_hudengine_create_project_claude_md(self.uniffiClonePointer(),
-                                                                     FfiConverterString.lower(projectPath), $0)
-    }
-    }
-
+open func cleanDeadLocks() -> UInt32 {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_clean_dead_locks(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
-     * Detects all agent sessions for a project path.
+     * Removes expired session-end tombstones.
      *
-     * Returns sessions from all installed agents (Claude, Codex, Aider, etc.)
-     * that have active sessions at the given path or its children.
+     * With `dry_run`, returns what would be removed without deleting anything.
      */
-    open func getAgentSessions(projectPath: String) -> [AgentSession] {
-        return try! FfiConverterSequenceTypeAgentSession.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_get_agent_sessions(self.uniffiClonePointer(),
-                                                                   FfiConverterString.lower(projectPath), $0)
-        })
-    }
-
+open func clearTombstones(dryRun: Bool) -> MaintenanceReport {
+    return try!  FfiConverterTypeMaintenanceReport.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_clear_tombstones(self.uniffiClonePointer(),
+        FfiConverterBool.lower(dryRun),$0
+    )
+})
+}
+    
     /**
-     * Gets all agent sessions across all projects (cached).
-     *
-     * Uses mtime-based caching for efficient repeated calls.
-     * Call `invalidate_agent_cache()` to force a refresh.
+     * Compares the newest session record timestamp to now and warns when the
+     * difference suggests a skewed clock (e.g. a VM with the wrong time).
      */
-    open func getAllAgentSessions() -> [AgentSession] {
-        return try! FfiConverterSequenceTypeAgentSession.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_get_all_agent_sessions(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func clockSkewCheck() -> ClockSkewReport {
+    return try!  FfiConverterTypeClockSkewReport.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_clock_skew_check(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
-     * Gets session states for multiple projects.
-     * Uses session-ID keyed state and lock detection for reliable state.
+     * Drops session records that are stale and hold no live lock, for installs
+     * where ended sessions were never cleaned up (e.g. SessionEnd never fired).
      *
-     * Takes a Vec instead of slice for FFI compatibility.
+     * Fresh and locked sessions are kept. Returns how many records were removed.
      */
-    open func getAllSessionStates(projects: [Project]) -> [String: ProjectSessionState] {
-        return try! FfiConverterDictionaryStringTypeProjectSessionState.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_get_all_session_states(self.uniffiClonePointer(),
-                                                                       FfiConverterSequenceTypeProject.lower(projects), $0)
-        })
-    }
-
+open func compactStateStore()throws  -> UInt32 {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_compact_state_store(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
-     * Gets the HUD configuration (pinned projects, terminal app, etc.)
+     * Lists live sessions that are compacting their context right now, so the
+     * HUD can warn against closing them. Empty when none are.
      */
-    open func getConfig() -> HudConfig {
-        return try! FfiConverterTypeHudConfig.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_get_config(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func compactingSessions() -> [CompactingSession] {
+    return try!  FfiConverterSequenceTypeCompactingSession.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_compacting_sessions(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
-     * Returns a unified diagnostic report for hook status.
-     *
-     * This combines setup status (is everything installed?) with health status
-     * (are hooks actually firing?) into a single report for the UI.
+     * Estimates how quickly a session is filling its context window.
      *
-     * The report provides:
-     * - `is_healthy`: True if everything is working
-     * - `primary_issue`: The most critical issue to display (prioritized)
-     * - `can_auto_fix`: True if "Fix All" can resolve the issue
-     * - Individual status flags for checklist display
+     * Returns None when the transcript has too few usage blocks to tell.
      */
-    open func getHookDiagnostic() -> HookDiagnosticReport {
-        return try! FfiConverterTypeHookDiagnosticReport.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_get_hook_diagnostic(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func contextBurnRate(sessionId: String, projectPath: String) -> ContextBurnRate? {
+    return try!  FfiConverterOptionTypeContextBurnRate.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_context_burn_rate(self.uniffiClonePointer(),
+        FfiConverterString.lower(sessionId),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
     /**
-     * Returns the current hook status without full setup check.
+     * Creates a CLAUDE.md file for a project.
      *
-     * Useful for quick hook status checks in the UI.
+     * Returns Ok(()) if successful, or an error if the file couldn't be created.
+     * Does NOT overwrite existing CLAUDE.md files.
      */
-    open func getHookStatus() -> HookStatus {
-        return try! FfiConverterTypeHookStatus.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_get_hook_status(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func createProjectClaudeMd(projectPath: String)throws  {try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_create_project_claude_md(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+}
+}
+    
     /**
-     * Returns the file path where ideas are stored for a project.
+     * Removes orphaned legacy (path-hash) lock directories.
      *
-     * This is useful for mtime-based change detection in the UI.
-     * Path: `~/.capacitor/projects/{encoded-path}/ideas.md`
+     * With `dry_run`, returns what would be removed without deleting anything.
      */
-    open func getIdeasFilePath(projectPath: String) -> String {
-        return try! FfiConverterString.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_get_ideas_file_path(self.uniffiClonePointer(),
-                                                                    FfiConverterString.lower(projectPath), $0)
-        })
-    }
-
+open func deleteOrphanedDirs(dryRun: Bool) -> MaintenanceReport {
+    return try!  FfiConverterTypeMaintenanceReport.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_delete_orphaned_dirs(self.uniffiClonePointer(),
+        FfiConverterBool.lower(dryRun),$0
+    )
+})
+}
+    
     /**
-     * Detects the primary agent session for a project path.
+     * Permanently deletes a project's Claude conversation history.
      *
-     * Returns the first session found based on user preference order.
-     * Use this when you only need to display one agent's state.
+     * `confirm_token` must be the project name. Refuses while a session is
+     * running in the project. Returns bytes reclaimed.
      */
-    open func getPrimaryAgentSession(projectPath: String) -> AgentSession? {
-        return try! FfiConverterOptionTypeAgentSession.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_get_primary_agent_session(self.uniffiClonePointer(),
-                                                                          FfiConverterString.lower(projectPath), $0)
-        })
-    }
-
+open func deleteProjectHistory(projectPath: String, confirmToken: String)throws  -> UInt64 {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_delete_project_history(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterString.lower(confirmToken),$0
+    )
+})
+}
+    
     /**
-     * Gets project status from .claude/hud-status.json.
+     * Lists session ids recorded under unrelated project paths, a sign that
+     * separate Claude profiles are writing colliding ids into shared state.
      */
-    open func getProjectStatus(projectPath: String) -> ProjectStatus? {
-        return try! FfiConverterOptionTypeProjectStatus.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_get_project_status(self.uniffiClonePointer(),
-                                                                   FfiConverterString.lower(projectPath), $0)
-        })
-    }
-
+open func detectSessionIdCollisions() -> [SessionIdCollision] {
+    return try!  FfiConverterSequenceTypeSessionIdCollision.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_detect_session_id_collisions(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Lists the known terminal apps that are installed on this system.
+     *
+     * Drives the terminal picker in settings.
+     */
+open func detectTerminals() -> [DetectedTerminal] {
+    return try!  FfiConverterSequenceTypeDetectedTerminal.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_detect_terminals(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Output tokens per uncached input token for each of the last `days` days,
+     * plus whether that ratio is trending up, down, or flat.
+     */
+open func efficiencyTrend(projectPath: String, days: UInt32) -> EfficiencyTrend {
+    return try!  FfiConverterTypeEfficiencyTrend.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_efficiency_trend(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterUInt32.lower(days),$0
+    )
+})
+}
+    
+    /**
+     * Writes everything `load_dashboard` returns, plus each project's session
+     * state, to `path` as pretty-printed JSON. Returns the number of projects exported.
+     */
+open func exportDashboardJson(path: String)throws  -> UInt32 {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_export_dashboard_json(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * Exports a session's state transitions and tool uses as a JSON trace.
+     *
+     * See [`crate::trace`] for the schema.
+     */
+open func exportSessionTrace(sessionId: String, projectPath: String)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_export_session_trace(self.uniffiClonePointer(),
+        FfiConverterString.lower(sessionId),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
+    /**
+     * Writes per-project token and cost stats for the pinned projects to `path`
+     * as CSV, one row per project, from the stats cache. Returns the row count.
+     */
+open func exportStatsCsv(path: String)throws  -> UInt32 {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_export_stats_csv(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * Pinned projects nested inside other pinned projects, so the UI can
+     * suggest consolidating them.
+     */
+open func findOverlappingPins() -> [OverlappingPins] {
+    return try!  FfiConverterSequenceTypeOverlappingPins.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_find_overlapping_pins(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Lists session records whose working directory has been deleted and that
+     * hold no live lock. These can never become active again.
+     */
+open func findZombieSessions()throws  -> [ZombieSession] {
+    return try  FfiConverterSequenceTypeZombieSession.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_find_zombie_sessions(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Releases the locks of a session listed by `orphaned_live_sessions`.
+     *
+     * Refuses sessions whose folder still exists. Returns the number of locks released.
+     */
+open func forceReleaseLock(sessionId: String)throws  -> UInt32 {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_force_release_lock(self.uniffiClonePointer(),
+        FfiConverterString.lower(sessionId),$0
+    )
+})
+}
+    
+    /**
+     * Detects all agent sessions for a project path.
+     *
+     * Returns sessions from all installed agents (Claude, Codex, Aider, etc.)
+     * that have active sessions at the given path or its children.
+     */
+open func getAgentSessions(projectPath: String) -> [AgentSession] {
+    return try!  FfiConverterSequenceTypeAgentSession.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_get_agent_sessions(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
+    /**
+     * Gets all agent sessions across all projects (cached).
+     *
+     * Uses mtime-based caching for efficient repeated calls.
+     * Call `invalidate_agent_cache()` to force a refresh.
+     */
+open func getAllAgentSessions() -> [AgentSession] {
+    return try!  FfiConverterSequenceTypeAgentSession.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_get_all_agent_sessions(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Gets session states for multiple projects.
+     * Uses session-ID keyed state and lock detection for reliable state.
+     *
+     * Takes a Vec instead of slice for FFI compatibility.
+     */
+open func getAllSessionStates(projects: [Project]) -> [String: ProjectSessionState] {
+    return try!  FfiConverterDictionaryStringTypeProjectSessionState.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_get_all_session_states(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeProject.lower(projects),$0
+    )
+})
+}
+    
+    /**
+     * Gets the HUD configuration (pinned projects, terminal app, etc.)
+     */
+open func getConfig() -> HudConfig {
+    return try!  FfiConverterTypeHudConfig.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_get_config(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns a unified diagnostic report for hook status.
+     *
+     * This combines setup status (is everything installed?) with health status
+     * (are hooks actually firing?) into a single report for the UI.
+     *
+     * The report provides:
+     * - `is_healthy`: True if everything is working
+     * - `primary_issue`: The most critical issue to display (prioritized)
+     * - `can_auto_fix`: True if "Fix All" can resolve the issue
+     * - Individual status flags for checklist display
+     */
+open func getHookDiagnostic() -> HookDiagnosticReport {
+    return try!  FfiConverterTypeHookDiagnosticReport.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_get_hook_diagnostic(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the current hook status without full setup check.
+     *
+     * Useful for quick hook status checks in the UI.
+     */
+open func getHookStatus() -> HookStatus {
+    return try!  FfiConverterTypeHookStatus.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_get_hook_status(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the file path where ideas are stored for a project.
+     *
+     * This is useful for mtime-based change detection in the UI.
+     * Path: `~/.capacitor/projects/{encoded-path}/ideas.md`
+     */
+open func getIdeasFilePath(projectPath: String) -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_get_ideas_file_path(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
+    /**
+     * Detects the primary agent session for a project path.
+     *
+     * Returns the first session found based on user preference order.
+     * Use this when you only need to display one agent's state.
+     */
+open func getPrimaryAgentSession(projectPath: String) -> AgentSession? {
+    return try!  FfiConverterOptionTypeAgentSession.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_get_primary_agent_session(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
+    /**
+     * A project's card plus its instructions content, tasks, and git state.
+     */
+open func getProjectDetails(path: String)throws  -> ProjectDetails {
+    return try  FfiConverterTypeProjectDetails.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_get_project_details(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * Gets project status from .claude/hud-status.json.
+     */
+open func getProjectStatus(projectPath: String) -> ProjectStatus? {
+    return try!  FfiConverterOptionTypeProjectStatus.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_get_project_status(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
+    /**
+     * How full a session's context window is, so the HUD can warn before an
+     * auto-compact. None when the session has no transcript usage yet.
+     */
+open func getSessionContext(sessionId: String) -> ContextInfo? {
+    return try!  FfiConverterOptionTypeContextInfo.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_get_session_context(self.uniffiClonePointer(),
+        FfiConverterString.lower(sessionId),$0
+    )
+})
+}
+    
     /**
      * Gets the session state for a single project.
      * Uses session-ID keyed state and lock detection for reliable state.
      */
-    open func getSessionState(projectPath: String) -> ProjectSessionState {
-        return try! FfiConverterTypeProjectSessionState.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_get_session_state(self.uniffiClonePointer(),
-                                                                  FfiConverterString.lower(projectPath), $0)
-        })
-    }
-
+open func getSessionState(projectPath: String) -> ProjectSessionState {
+    return try!  FfiConverterTypeProjectSessionState.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_get_session_state(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
+    /**
+     * The last `limit` user/assistant messages of a session as plain text,
+     * oldest first, for peeking at what it's doing. Empty when not found.
+     */
+open func getSessionTranscript(sessionId: String, limit: UInt32) -> [TranscriptMessage] {
+    return try!  FfiConverterSequenceTypeTranscriptMessage.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_get_session_transcript(self.uniffiClonePointer(),
+        FfiConverterString.lower(sessionId),
+        FfiConverterUInt32.lower(limit),$0
+    )
+})
+}
+    
     /**
      * Discovers suggested projects based on activity in ~/.claude/projects.
      */
-    open func getSuggestedProjects() throws -> [SuggestedProject] {
-        return try FfiConverterSequenceTypeSuggestedProject.lift(rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-            uniffi_hud_core_fn_method_hudengine_get_suggested_projects(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func getSuggestedProjects()throws  -> [SuggestedProject] {
+    return try  FfiConverterSequenceTypeSuggestedProject.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_get_suggested_projects(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * The checked-out branch, or `detached @ <short sha>` for a detached HEAD.
+     * None if the path isn't a git repository.
+     */
+open func gitBranch(path: String) -> String? {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_git_branch(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * Number of git stash entries for a project, None if it isn't a git repository.
+     */
+open func gitStashCount(path: String) -> UInt32? {
+    return try!  FfiConverterOptionUInt32.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_git_stash_count(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * Total estimated prompt-cache savings in dollars across pinned projects.
+     */
+open func globalCacheSavingsUsd() -> Double {
+    return try!  FfiConverterDouble.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_global_cache_savings_usd(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * A project's sessions with resumed continuations folded into one entry
+     * each, newest first.
+     */
+open func groupContinuedSessions(projectPath: String) -> [SessionGroup] {
+    return try!  FfiConverterSequenceTypeSessionGroup.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_group_continued_sessions(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
+    /**
+     * Groups pinned projects by their common ancestor `depth` levels below home.
+     */
+open func groupProjectsByRoot(depth: UInt32) -> [ProjectGroup] {
+    return try!  FfiConverterSequenceTypeProjectGroup.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_group_projects_by_root(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(depth),$0
+    )
+})
+}
+    
+    /**
+     * Min/median/p95 run times of recent hook invocations, per subcommand,
+     * from the hook's debug log. Empty until the hook has logged timed runs.
+     */
+open func hookLatencyStats() -> [HookLatencyStats] {
+    return try!  FfiConverterSequenceTypeHookLatencyStats.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_hook_latency_stats(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the most-edited files in a project, aggregated across all sessions
+     * in the file-activity feed.
+     */
+open func hotFiles(projectPath: String, limit: UInt32) -> [HotFile] {
+    return try!  FfiConverterSequenceTypeHotFile.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_hot_files(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterUInt32.lower(limit),$0
+    )
+})
+}
+    
     /**
      * Installs the hook binary from a source path to ~/.local/bin/hud-hook.
      *
@@ -1182,13 +2345,14 @@ open class HudEngine:
      * - Cannot create ~/.local/bin
      * - Cannot copy or set permissions
      */
-    open func installHookBinaryFromPath(sourcePath: String) throws -> InstallResult {
-        return try FfiConverterTypeInstallResult.lift(rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-            uniffi_hud_core_fn_method_hudengine_install_hook_binary_from_path(self.uniffiClonePointer(),
-                                                                              FfiConverterString.lower(sourcePath), $0)
-        })
-    }
-
+open func installHookBinaryFromPath(sourcePath: String)throws  -> InstallResult {
+    return try  FfiConverterTypeInstallResult.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_install_hook_binary_from_path(self.uniffiClonePointer(),
+        FfiConverterString.lower(sourcePath),$0
+    )
+})
+}
+    
     /**
      * Installs the session tracking hooks.
      *
@@ -1200,155 +2364,569 @@ open class HudEngine:
      * - Hook binary is missing or broken
      * - Hooks are disabled by policy (disableAllHooks or allowManagedHooksOnly)
      * - File system operations fail
+     *
+     * Deprecated: writes settings.json without showing what changes first, which
+     * can clobber existing Stop hooks. Use `install_hooks_with_confirm`.
      */
-    open func installHooks() throws -> InstallResult {
-        return try FfiConverterTypeInstallResult.lift(rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-            uniffi_hud_core_fn_method_hudengine_install_hooks(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func installHooks()throws  -> InstallResult {
+    return try  FfiConverterTypeInstallResult.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_install_hooks(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Two-phase hook install.
+     *
+     * With `confirm` false, returns the current and proposed `hooks.Stop` from
+     * ~/.claude/settings.json and writes nothing. Call again with `confirm` true
+     * to perform the install; the outcome then also carries the install result.
+     */
+open func installHooksWithConfirm(confirm: Bool)throws  -> HookInstallOutcome {
+    return try  FfiConverterTypeHookInstallOutcome.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_install_hooks_with_confirm(self.uniffiClonePointer(),
+        FfiConverterBool.lower(confirm),$0
+    )
+})
+}
+    
     /**
      * Invalidates the agent session cache.
      *
      * Call this when you know the underlying state has changed
      * and want to force a fresh read on the next call.
      */
-    open func invalidateAgentCache() { try! rustCall {
-        uniffi_hud_core_fn_method_hudengine_invalidate_agent_cache(self.uniffiClonePointer(), $0)
-    }
-    }
-
+open func invalidateAgentCache() {try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_invalidate_agent_cache(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+    /**
+     * Whether a path is inside a git repository, checked without spawning git.
+     */
+open func isGitRepo(path: String) -> GitRepoCheck {
+    return try!  FfiConverterTypeGitRepoCheck.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_is_git_repo(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * The shell command a new terminal runs to start (or resume) Claude in a
+     * project, honoring the project's custom launch command if configured.
+     */
+open func launchCommand(projectPath: String, claudePath: String, resumeSessionId: String?) -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_launch_command(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterString.lower(claudePath),
+        FfiConverterOptionString.lower(resumeSessionId),$0
+    )
+})
+}
+    
     /**
      * Lists all artifacts (skills, commands, agents) from global and plugin sources.
+     *
+     * Plugin artifacts switched off with `toggle_plugin_artifact` are left out.
      */
-    open func listArtifacts() -> [Artifact] {
-        return try! FfiConverterSequenceTypeArtifact.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_list_artifacts(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func listArtifacts() -> [Artifact] {
+    return try!  FfiConverterSequenceTypeArtifact.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_list_artifacts(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
      * Returns the list of installed agent IDs.
      *
      * Useful for debugging and UI display of which agents are available.
      */
-    open func listInstalledAgents() -> [String] {
-        return try! FfiConverterSequenceString.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_list_installed_agents(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func listInstalledAgents() -> [String] {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_list_installed_agents(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Lists one plugin's artifacts, including ones switched off, with `enabled`
+     * set so the UI can render a toggle next to each.
+     */
+open func listPluginArtifacts(pluginId: String) -> [Artifact] {
+    return try!  FfiConverterSequenceTypeArtifact.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_list_plugin_artifacts(self.uniffiClonePointer(),
+        FfiConverterString.lower(pluginId),$0
+    )
+})
+}
+    
     /**
      * Lists all installed plugins.
      */
-    open func listPlugins() throws -> [Plugin] {
-        return try FfiConverterSequenceTypePlugin.lift(rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-            uniffi_hud_core_fn_method_hudengine_list_plugins(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func listPlugins()throws  -> [Plugin] {
+    return try  FfiConverterSequenceTypePlugin.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_list_plugins(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
      * Lists all pinned projects, sorted by most recent activity.
      */
-    open func listProjects() throws -> [Project] {
-        return try FfiConverterSequenceTypeProject.lift(rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-            uniffi_hud_core_fn_method_hudengine_list_projects(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func listProjects()throws  -> [Project] {
+    return try  FfiConverterSequenceTypeProject.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_list_projects(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
      * Loads all dashboard data in one call.
+     *
+     * Succeeds with partial data when plugins, settings, or projects fail to load;
+     * each non-fatal failure is reported in `DashboardData.warnings`.
      */
-    open func loadDashboard() throws -> DashboardData {
-        return try FfiConverterTypeDashboardData.lift(rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-            uniffi_hud_core_fn_method_hudengine_load_dashboard(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func loadDashboard()throws  -> DashboardData {
+    return try  FfiConverterTypeDashboardData.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_load_dashboard(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
      * Loads all ideas for a project.
      *
      * Returns an empty vector if the ideas file doesn't exist.
      */
-    open func loadIdeas(projectPath: String) throws -> [Idea] {
-        return try FfiConverterSequenceTypeIdea.lift(rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-            uniffi_hud_core_fn_method_hudengine_load_ideas(self.uniffiClonePointer(),
-                                                           FfiConverterString.lower(projectPath), $0)
-        })
-    }
-
+open func loadIdeas(projectPath: String)throws  -> [Idea] {
+    return try  FfiConverterSequenceTypeIdea.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_load_ideas(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
     /**
      * Loads the display order of ideas for a project.
      *
      * Returns an empty vector if no order file exists (graceful degradation).
      * The caller should merge this with loaded ideas: ordered first, unordered appended.
      */
-    open func loadIdeasOrder(projectPath: String) throws -> [String] {
-        return try FfiConverterSequenceString.lift(rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-            uniffi_hud_core_fn_method_hudengine_load_ideas_order(self.uniffiClonePointer(),
-                                                                 FfiConverterString.lower(projectPath), $0)
-        })
-    }
-
+open func loadIdeasOrder(projectPath: String)throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_load_ideas_order(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
     /**
-     * Removes a project from the pinned projects list.
+     * Lists MCP servers from global settings and, if given, the project's `.mcp.json`.
      */
-    open func removeProject(path: String) throws { try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-        uniffi_hud_core_fn_method_hudengine_remove_project(self.uniffiClonePointer(),
-                                                           FfiConverterString.lower(path), $0)
-    }
-    }
-
+open func loadMcpServers(projectPath: String?) -> [McpServer] {
+    return try!  FfiConverterSequenceTypeMcpServer.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_load_mcp_servers(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(projectPath),$0
+    )
+})
+}
+    
     /**
-     * Resolves what activation action to take for a project.
-     *
-     * This is the main entry point for terminal activation. Given the current
-     * shell state and tmux context, it determines what action(s) Swift should
-     * take to activate the correct terminal.
-     *
-     * # Arguments
-     * * `project_path` - The absolute path to the project
-     * * `shell_state` - Current contents of shell-cwd.json (may be None if file missing)
-     * * `tmux_context` - Tmux state queried by Swift
-     *
-     * # Returns
-     * An `ActivationDecision` with primary action and optional fallback.
+     * Token usage per day or week for a project, split by model family.
      *
-     * # Example Usage (Swift)
-     * ```swift
-     * let decision = engine.resolveActivation(
-     * projectPath: project.path,
-     * shellState: shellStore.state?.toFfi(),
-     * tmuxContext: await queryTmuxContext()
-     * )
-     * await executeAction(decision.primary)
-     * ```
+     * Suited to a stacked area chart; gaps between active buckets are zero-filled.
      */
-    open func resolveActivation(projectPath: String, shellState: ShellCwdStateFfi?, tmuxContext: TmuxContextFfi) -> ActivationDecision {
-        return try! FfiConverterTypeActivationDecision.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_resolve_activation(self.uniffiClonePointer(),
-                                                                   FfiConverterString.lower(projectPath),
-                                                                   FfiConverterOptionTypeShellCwdStateFfi.lower(shellState),
-                                                                   FfiConverterTypeTmuxContextFfi.lower(tmuxContext), $0)
-        })
-    }
-
+open func modelUsageTimeseries(projectPath: String, bucket: UsageBucket) -> [ModelUsagePoint] {
+    return try!  FfiConverterSequenceTypeModelUsagePoint.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_model_usage_timeseries(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterTypeUsageBucket.lower(bucket),$0
+    )
+})
+}
+    
     /**
-     * Runs a comprehensive hook system test.
-     *
-     * This verifies:
-     * 1. Heartbeat file exists and is recent (< 60s old)
-     * 2. State file (sessions.json) can be written and read back
-     *
+     * Collects the non-empty `next_step` of every pinned project, newest first,
+     * as a cross-project agenda.
+     */
+open func nextStepsDigest() -> [NextStep] {
+    return try!  FfiConverterSequenceTypeNextStep.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_next_steps_digest(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns the project's `.claude/settings.local.json` for editing, creating it as `{}`
+     * if absent. `Project.has_local_settings` tells the UI whether to label it create or edit.
+     */
+open func openLocalSettings(path: String)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_open_local_settings(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * Returns the MCP config file to edit for a scope.
+     *
+     * Creates an empty project `.mcp.json` if needed. The client opens the returned path.
+     */
+open func openMcpConfig(scope: McpScope, projectPath: String?)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_open_mcp_config(self.uniffiClonePointer(),
+        FfiConverterTypeMcpScope.lower(scope),
+        FfiConverterOptionString.lower(projectPath),$0
+    )
+})
+}
+    
+    /**
+     * The file behind a project's "edit config" button: `CLAUDE.md`, else
+     * `.claude/settings.local.json`, else global settings. With `create_local_settings`,
+     * a missing local settings file is created rather than falling back to global.
+     */
+open func openProjectConfig(path: String, createLocalSettings: Bool)throws  -> ProjectConfigFile {
+    return try  FfiConverterTypeProjectConfigFile.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_open_project_config(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),
+        FfiConverterBool.lower(createLocalSettings),$0
+    )
+})
+}
+    
+    /**
+     * Returns the IDE command that reopens a session's task: the project plus
+     * the files the session most recently edited (capped). Swift runs it.
+     */
+open func openTaskContext(sessionId: String, projectPath: String, editor: ParentApp)throws  -> EditorLaunch {
+    return try  FfiConverterTypeEditorLaunch.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_open_task_context(self.uniffiClonePointer(),
+        FfiConverterString.lower(sessionId),
+        FfiConverterString.lower(projectPath),
+        FfiConverterTypeParentApp.lower(editor),$0
+    )
+})
+}
+    
+    /**
+     * Lists running sessions whose working directory has been deleted.
+     */
+open func orphanedLiveSessions() -> [OrphanedLiveSession] {
+    return try!  FfiConverterSequenceTypeOrphanedLiveSession.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_orphaned_live_sessions(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * The `limit` sessions across all projects that raised the most permission
+     * prompts, with the tools involved.
+     */
+open func permissionHeavySessions(limit: UInt32) -> [PermissionHeavySession] {
+    return try!  FfiConverterSequenceTypePermissionHeavySession.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_permission_heavy_sessions(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(limit),$0
+    )
+})
+}
+    
+    /**
+     * Lists recent permission prompts and denials for a session, oldest first.
+     */
+open func permissionHistory(sessionId: String, projectPath: String) -> [PermissionEntry] {
+    return try!  FfiConverterSequenceTypePermissionEntry.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_permission_history(self.uniffiClonePointer(),
+        FfiConverterString.lower(sessionId),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
+    /**
+     * Returns the sessions that started waiting on the user (usually for a
+     * permission prompt) since this subscription's previous poll.
+     *
+     * This is the `session-waiting` event: a Waiting-only view of
+     * [`Self::poll_state_feed`] for the same subscription. Clients call it from
+     * their refresh loop and notify once per event.
+     */
+open func pollSessionWaiting(subscriptionId: UInt64)throws  -> [SessionWaitingEvent] {
+    return try  FfiConverterSequenceTypeSessionWaitingEvent.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_poll_session_waiting(self.uniffiClonePointer(),
+        FfiConverterUInt64.lower(subscriptionId),$0
+    )
+})
+}
+    
+    /**
+     * Returns the state changes since this subscription's previous poll, one
+     * event per session that appeared, changed state, or ended.
+     */
+open func pollStateFeed(subscriptionId: UInt64)throws  -> [StateFeedEvent] {
+    return try  FfiConverterSequenceTypeStateFeedEvent.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_poll_state_feed(self.uniffiClonePointer(),
+        FfiConverterUInt64.lower(subscriptionId),$0
+    )
+})
+}
+    
+    /**
+     * Recomputed stats for each of `project_paths` whose session transcripts
+     * changed since the previous call.
+     *
+     * This is the `stats-changed` event: clients call it from their refresh loop
+     * to keep dashboard stats live. The first call for a project only records a
+     * baseline, and changes are held until transcripts have been quiet for
+     * [`crate::stats::STATS_CHANGE_DEBOUNCE`], so one burst yields one event.
+     */
+open func pollStatsChanges(projectPaths: [String])throws  -> [StatsChangedEvent] {
+    return try  FfiConverterSequenceTypeStatsChangedEvent.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_poll_stats_changes(self.uniffiClonePointer(),
+        FfiConverterSequenceString.lower(projectPaths),$0
+    )
+})
+}
+    
+    /**
+     * Sessions and file edits per day for a project over the last `days` days,
+     * normalized both per calendar day and per active day.
+     */
+open func projectVelocity(projectPath: String, days: UInt32) -> ProjectVelocity {
+    return try!  FfiConverterTypeProjectVelocity.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_project_velocity(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterUInt32.lower(days),$0
+    )
+})
+}
+    
+    /**
+     * Projects whose live session is running subagents, with the count, so the
+     * HUD can show parallel agent work. Empty when none are.
+     */
+open func projectsWithSubagents() -> [ProjectSubagents] {
+    return try!  FfiConverterSequenceTypeProjectSubagents.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_projects_with_subagents(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Removes old entries from the file activity feed.
+     *
+     * With `dry_run`, returns what would be removed without modifying the feed.
+     */
+open func pruneActivity(dryRun: Bool) -> MaintenanceReport {
+    return try!  FfiConverterTypeMaintenanceReport.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_prune_activity(self.uniffiClonePointer(),
+        FfiConverterBool.lower(dryRun),$0
+    )
+})
+}
+    
+    /**
+     * Unpins every project whose folder is missing. Returns the removed paths.
+     */
+open func pruneMissingProjects()throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_prune_missing_projects(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Prunes stale `sessions.json` records whose session holds no live lock,
+     * returning how many were removed. Also runs on each dashboard load.
+     */
+open func pruneSessions()throws  -> UInt32 {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_prune_sessions(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Content of the global `~/.claude/CLAUDE.md`, None if it doesn't exist.
+     */
+open func readGlobalInstructions() -> String? {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_read_global_instructions(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Reads the tail of the status generation log for debugging.
+     *
+     * Empty unless `HudConfig.status_gen_logging` is on and the hook has run.
+     */
+open func readStatusGenLog() -> [String] {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_read_status_gen_log(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Removes lock directories whose process has exited.
+     *
+     * With `dry_run`, returns what would be removed without deleting anything.
+     */
+open func reapStaleLocks(dryRun: Bool) -> MaintenanceReport {
+    return try!  FfiConverterTypeMaintenanceReport.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_reap_stale_locks(self.uniffiClonePointer(),
+        FfiConverterBool.lower(dryRun),$0
+    )
+})
+}
+    
+    /**
+     * Removes a project from the pinned projects list.
+     */
+open func removeProject(path: String)throws  {try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_remove_project(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+}
+}
+    
+    /**
+     * Removes the records listed by `find_zombie_sessions`, re-checking each first.
+     *
+     * With `dry_run`, returns what would be removed without modifying the store.
+     */
+open func removeZombieSessions(dryRun: Bool) -> MaintenanceReport {
+    return try!  FfiConverterTypeMaintenanceReport.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_remove_zombie_sessions(self.uniffiClonePointer(),
+        FfiConverterBool.lower(dryRun),$0
+    )
+})
+}
+    
+    /**
+     * Sets the pinned projects' order and switches the list to manual order.
+     *
+     * `paths` must be exactly the currently pinned projects.
+     */
+open func reorderProjects(paths: [String])throws  {try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_reorder_projects(self.uniffiClonePointer(),
+        FfiConverterSequenceString.lower(paths),$0
+    )
+}
+}
+    
+    /**
+     * Salvages valid project entries from a partially-corrupt stats cache.
+     *
+     * Returns how many entries were kept vs dropped.
+     */
+open func repairStatsCache()throws  -> StatsCacheRepair {
+    return try  FfiConverterTypeStatsCacheRepair.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_repair_stats_cache(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Resolves what activation action to take for a project.
+     *
+     * This is the main entry point for terminal activation. Given the current
+     * shell state and tmux context, it determines what action(s) Swift should
+     * take to activate the correct terminal.
+     *
+     * # Arguments
+     * * `project_path` - The absolute path to the project
+     * * `shell_state` - Current contents of shell-cwd.json (may be None if file missing)
+     * * `tmux_context` - Tmux state queried by Swift
+     *
+     * # Returns
+     * An `ActivationDecision` with primary action and optional fallback.
+     *
+     * # Example Usage (Swift)
+     * ```swift
+     * let decision = engine.resolveActivation(
+     * projectPath: project.path,
+     * shellState: shellStore.state?.toFfi(),
+     * tmuxContext: await queryTmuxContext()
+     * )
+     * await executeAction(decision.primary)
+     * ```
+     */
+open func resolveActivation(projectPath: String, shellState: ShellCwdStateFfi?, tmuxContext: TmuxContextFfi) -> ActivationDecision {
+    return try!  FfiConverterTypeActivationDecision.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_resolve_activation(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterOptionTypeShellCwdStateFfi.lower(shellState),
+        FfiConverterTypeTmuxContextFfi.lower(tmuxContext),$0
+    )
+})
+}
+    
+    /**
+     * Lists every artifact a project can see and whether it is actually active there.
+     *
+     * Combines global, plugin, and project (`<project>/.claude/`) artifacts, applies
+     * the project's `enabledPlugins` overrides, and marks same-named artifacts
+     * shadowed by a higher-precedence source (project, then plugin, then global).
+     */
+open func resolveEffectiveArtifacts(projectPath: String) -> [EffectiveArtifact] {
+    return try!  FfiConverterSequenceTypeEffectiveArtifact.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_resolve_effective_artifacts(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
+    /**
+     * Resolves the Claude session for any path, pinned or not.
+     *
+     * Reads the state store and locks directly on each call, bypassing the
+     * registry cache. Suits one-off lookups such as hovering a suggested project.
+     */
+open func resolvePathState(path: String) -> AgentSession? {
+    return try!  FfiConverterOptionTypeAgentSession.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_resolve_path_state(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * Replaces `~/.capacitor` with the contents of a backup and returns the
+     * path of the safety backup taken of the previous state.
+     *
+     * Watchers are paused for the duration so clients don't read half-restored files.
+     */
+open func restoreCapacitorState(src: String)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_restore_capacitor_state(self.uniffiClonePointer(),
+        FfiConverterString.lower(src),$0
+    )
+})
+}
+    
+    /**
+     * Runs a comprehensive hook system test.
+     *
+     * This verifies:
+     * 1. Heartbeat file exists and is recent (< 60s old)
+     * 2. State file (sessions.json) can be written and read back
+     *
      * Used by the "Test Hooks" button in SetupStatusCard to give users
      * confidence that the hook system is functioning correctly.
      */
-    open func runHookTest() -> HookTestResult {
-        return try! FfiConverterTypeHookTestResult.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_run_hook_test(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func runHookTest() -> HookTestResult {
+    return try!  FfiConverterTypeHookTestResult.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_run_hook_test(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
      * Performs startup cleanup of stale artifacts.
      *
@@ -1358,118 +2936,390 @@ open class HudEngine:
      *
      * Returns stats about what was cleaned up.
      */
-    open func runStartupCleanup() -> CleanupStats {
-        return try! FfiConverterTypeCleanupStats.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_run_startup_cleanup(self.uniffiClonePointer(), $0)
-        })
-    }
-
+open func runStartupCleanup() -> CleanupStats {
+    return try!  FfiConverterTypeCleanupStats.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_run_startup_cleanup(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
      * Saves the display order of ideas for a project.
      *
      * The order is stored separately from idea content in `~/.capacitor/projects/{encoded}/ideas-order.json`.
      * This prevents churning the ideas markdown file on every drag-reorder.
      */
-    open func saveIdeasOrder(projectPath: String, ideaIds: [String]) throws { try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-        uniffi_hud_core_fn_method_hudengine_save_ideas_order(self.uniffiClonePointer(),
-                                                             FfiConverterString.lower(projectPath),
-                                                             FfiConverterSequenceString.lower(ideaIds), $0)
-    }
-    }
-
+open func saveIdeasOrder(projectPath: String, ideaIds: [String])throws  {try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_save_ideas_order(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterSequenceString.lower(ideaIds),$0
+    )
+}
+}
+    
     /**
-     * Updates the description of an idea.
+     * Average, median, and longest session length for a project, to tell quick
+     * tweaks from long deep-work sessions.
+     */
+open func sessionDurationStats(projectPath: String) -> SessionDurationStats {
+    return try!  FfiConverterTypeSessionDurationStats.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_session_duration_stats(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
+    /**
+     * Measures time-to-first-response across a session's prompts.
      *
-     * Used for sensemaking - the idea is initially saved with raw user input,
-     * then this is called with an AI-generated expansion.
+     * Returns None when the transcript has no timed prompt/response pairs.
      */
-    open func updateIdeaDescription(projectPath: String, ideaId: String, newDescription: String) throws { try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-        uniffi_hud_core_fn_method_hudengine_update_idea_description(self.uniffiClonePointer(),
-                                                                    FfiConverterString.lower(projectPath),
-                                                                    FfiConverterString.lower(ideaId),
-                                                                    FfiConverterString.lower(newDescription), $0)
-    }
-    }
-
+open func sessionLatency(sessionId: String, projectPath: String) -> SessionLatency? {
+    return try!  FfiConverterOptionTypeSessionLatency.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_session_latency(self.uniffiClonePointer(),
+        FfiConverterString.lower(sessionId),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
     /**
-     * Updates the effort estimate of an idea.
+     * One-glance summary of a session for sharing: first prompt, final reply,
+     * duration, tokens and cost, edited files, and models.
      *
-     * Valid efforts: unknown, small, medium, large, xl
+     * Returns None when the transcript is missing.
      */
-    open func updateIdeaEffort(projectPath: String, ideaId: String, newEffort: String) throws { try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-        uniffi_hud_core_fn_method_hudengine_update_idea_effort(self.uniffiClonePointer(),
-                                                               FfiConverterString.lower(projectPath),
-                                                               FfiConverterString.lower(ideaId),
-                                                               FfiConverterString.lower(newEffort), $0)
-    }
-    }
-
+open func sessionReceipt(sessionId: String, projectPath: String) -> SessionReceipt? {
+    return try!  FfiConverterOptionTypeSessionReceipt.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_session_receipt(self.uniffiClonePointer(),
+        FfiConverterString.lower(sessionId),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
     /**
-     * Updates the status of an idea.
+     * Groups live sessions by the terminal or IDE hosting them.
      *
-     * Valid statuses: open, in-progress, done
+     * # Arguments
+     * * `shell_state` - Current contents of shell-cwd.json (may be None if file missing)
      */
-    open func updateIdeaStatus(projectPath: String, ideaId: String, newStatus: String) throws { try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-        uniffi_hud_core_fn_method_hudengine_update_idea_status(self.uniffiClonePointer(),
-                                                               FfiConverterString.lower(projectPath),
-                                                               FfiConverterString.lower(ideaId),
-                                                               FfiConverterString.lower(newStatus), $0)
-    }
-    }
-
+open func sessionsByHostApp(shellState: ShellCwdStateFfi?) -> [HostAppSessions] {
+    return try!  FfiConverterSequenceTypeHostAppSessions.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_sessions_by_host_app(self.uniffiClonePointer(),
+        FfiConverterOptionTypeShellCwdStateFfi.lower(shellState),$0
+    )
+})
+}
+    
     /**
-     * Updates the title of an idea.
+     * Switches the project list between manual order and most-recent-activity order.
+     */
+open func setManualOrder(enabled: Bool)throws  {try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_set_manual_order(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+    /**
+     * Sets a project's display name; an empty alias restores the folder name.
+     */
+open func setProjectAlias(path: String, alias: String)throws  {try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_set_project_alias(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),
+        FfiConverterString.lower(alias),$0
+    )
+}
+}
+    
+    /**
+     * Pauses or resumes background refresh while the HUD isn't visible.
      *
-     * Used for async title generation - the idea is initially saved with a placeholder,
-     * then this is called once the AI-generated title is ready.
+     * While paused, the poll and check methods clients call from their refresh
+     * loop (`poll_stats_changes`, `check_budget_threshold`,
+     * `check_global_instructions_changed`, `poll_state_feed`,
+     * `poll_session_waiting`) return nothing without touching disk. Their
+     * baselines are kept, so the first poll after resuming reports whatever
+     * changed in the meantime. Pausing on window blur saves CPU and battery.
      */
-    open func updateIdeaTitle(projectPath: String, ideaId: String, newTitle: String) throws { try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-        uniffi_hud_core_fn_method_hudengine_update_idea_title(self.uniffiClonePointer(),
-                                                              FfiConverterString.lower(projectPath),
-                                                              FfiConverterString.lower(ideaId),
-                                                              FfiConverterString.lower(newTitle), $0)
-    }
-    }
-
+open func setWatchersPaused(paused: Bool) {try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_set_watchers_paused(self.uniffiClonePointer(),
+        FfiConverterBool.lower(paused),$0
+    )
+}
+}
+    
     /**
-     * Updates the triage status of an idea.
+     * Live sessions across all projects counted by state, for a dashboard chart.
+     */
+open func stateDistribution() -> StateDistribution {
+    return try!  FfiConverterTypeStateDistribution.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_state_distribution(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Each session file's token and message contribution to a project's stats,
+     * largest first, for tracking down where usage comes from.
+     */
+open func statsBreakdown(projectPath: String) -> [FileStatsContribution] {
+    return try!  FfiConverterSequenceTypeFileStatsContribution.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_stats_breakdown(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),$0
+    )
+})
+}
+    
+    /**
+     * Starts a feed of session state changes across all projects and returns
+     * its subscription id.
      *
-     * Valid triage statuses: pending, validated
+     * The feed begins at the current state: the first poll reports only what
+     * changed after subscribing. Call [`Self::unsubscribe_state_feed`] when done.
      */
-    open func updateIdeaTriage(projectPath: String, ideaId: String, newTriage: String) throws { try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
-        uniffi_hud_core_fn_method_hudengine_update_idea_triage(self.uniffiClonePointer(),
-                                                               FfiConverterString.lower(projectPath),
-                                                               FfiConverterString.lower(ideaId),
-                                                               FfiConverterString.lower(newTriage), $0)
-    }
-    }
-
+open func subscribeStateFeed() -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_subscribe_state_feed(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
     /**
-     * Validates a project path before adding it.
+     * Assembles the app and session context for feedback reports and the debug panel.
+     */
+open func telemetryContext() -> TelemetryContext {
+    return try!  FfiConverterTypeTelemetryContext.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_telemetry_context(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * The terminal app and shell cwd for a tty, or None if no tracked shell uses it.
      *
-     * Returns validation result indicating whether the path is valid,
-     * if there's a better path to use, or if the project is missing CLAUDE.md.
+     * # Arguments
+     * * `shell_state` - Current contents of shell-cwd.json (may be None if file missing)
+     */
+open func terminalForTty(tty: String, shellState: ShellCwdStateFfi?) -> TtyTerminal? {
+    return try!  FfiConverterOptionTypeTtyTerminal.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_terminal_for_tty(self.uniffiClonePointer(),
+        FfiConverterString.lower(tty),
+        FfiConverterOptionTypeShellCwdStateFfi.lower(shellState),$0
+    )
+})
+}
+    
+    /**
+     * How to open a terminal at a project, running its launch command when
+     * `run_claude` is set, with `--resume <id>` when `resume_session_id` is given.
      *
-     * This enables smart UI flows like:
-     * - Suggesting parent directory when user picks a subdirectory
-     * - Warning about dangerous paths (/, ~, etc.)
-     * - Offering to create CLAUDE.md when missing
-     * - Detecting if the project is already tracked
+     * On macOS this is the configured `HudConfig.terminal_app`, each with its own
+     * strategy (`open -a` with arguments, or AppleScript that types the command).
+     * On Linux it is `$TERMINAL` or the first installed common emulator, and on
+     * Windows, Windows Terminal or `cmd`. The app spawns the returned process.
+     *
+     * Returns an error listing the probed terminals when none is installed.
      */
-    open func validateProject(path: String) -> ValidationResultFfi {
-        return try! FfiConverterTypeValidationResultFfi.lift(try! rustCall {
-            uniffi_hud_core_fn_method_hudengine_validate_project(self.uniffiClonePointer(),
-                                                                 FfiConverterString.lower(path), $0)
-        })
-    }
+open func terminalLaunch(projectPath: String, runClaude: Bool, resumeSessionId: String?)throws  -> TerminalLaunch {
+    return try  FfiConverterTypeTerminalLaunch.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_terminal_launch(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterBool.lower(runClaude),
+        FfiConverterOptionString.lower(resumeSessionId),$0
+    )
+})
 }
-
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeHudEngine: FfiConverter {
-    typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = HudEngine
+    
+    /**
+     * Switches a single skill, command, or agent of a plugin on or off.
+     *
+     * `artifact_type` is "skill", "command", or "agent".
+     */
+open func togglePluginArtifact(pluginId: String, artifactType: String, artifactName: String, enabled: Bool)throws  {try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_toggle_plugin_artifact(self.uniffiClonePointer(),
+        FfiConverterString.lower(pluginId),
+        FfiConverterString.lower(artifactType),
+        FfiConverterString.lower(artifactName),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+    /**
+     * The `limit` most expensive sessions across all projects, most expensive first.
+     */
+open func topSessionsByCost(limit: UInt32) -> [SessionCost] {
+    return try!  FfiConverterSequenceTypeSessionCost.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_top_sessions_by_cost(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(limit),$0
+    )
+})
+}
+    
+    /**
+     * Lists uncommitted files in a project that agent sessions edited.
+     *
+     * Newly-created untracked files are included unless `include_untracked` is false.
+     */
+open func uncommittedAgentChanges(projectPath: String, includeUntracked: Bool)throws  -> [AgentChange] {
+    return try  FfiConverterSequenceTypeAgentChange.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_uncommitted_agent_changes(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterBool.lower(includeUntracked),$0
+    )
+})
+}
+    
+    /**
+     * Ends a state feed subscription. Returns false if it was already gone.
+     */
+open func unsubscribeStateFeed(subscriptionId: UInt64) -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_unsubscribe_state_feed(self.uniffiClonePointer(),
+        FfiConverterUInt64.lower(subscriptionId),$0
+    )
+})
+}
+    
+    /**
+     * Updates the description of an idea.
+     *
+     * Used for sensemaking - the idea is initially saved with raw user input,
+     * then this is called with an AI-generated expansion.
+     */
+open func updateIdeaDescription(projectPath: String, ideaId: String, newDescription: String)throws  {try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_update_idea_description(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterString.lower(ideaId),
+        FfiConverterString.lower(newDescription),$0
+    )
+}
+}
+    
+    /**
+     * Updates the effort estimate of an idea.
+     *
+     * Valid efforts: unknown, small, medium, large, xl
+     */
+open func updateIdeaEffort(projectPath: String, ideaId: String, newEffort: String)throws  {try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_update_idea_effort(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterString.lower(ideaId),
+        FfiConverterString.lower(newEffort),$0
+    )
+}
+}
+    
+    /**
+     * Updates the status of an idea.
+     *
+     * Valid statuses: open, in-progress, done
+     */
+open func updateIdeaStatus(projectPath: String, ideaId: String, newStatus: String)throws  {try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_update_idea_status(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterString.lower(ideaId),
+        FfiConverterString.lower(newStatus),$0
+    )
+}
+}
+    
+    /**
+     * Updates the title of an idea.
+     *
+     * Used for async title generation - the idea is initially saved with a placeholder,
+     * then this is called once the AI-generated title is ready.
+     */
+open func updateIdeaTitle(projectPath: String, ideaId: String, newTitle: String)throws  {try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_update_idea_title(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterString.lower(ideaId),
+        FfiConverterString.lower(newTitle),$0
+    )
+}
+}
+    
+    /**
+     * Updates the triage status of an idea.
+     *
+     * Valid triage statuses: pending, validated
+     */
+open func updateIdeaTriage(projectPath: String, ideaId: String, newTriage: String)throws  {try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_update_idea_triage(self.uniffiClonePointer(),
+        FfiConverterString.lower(projectPath),
+        FfiConverterString.lower(ideaId),
+        FfiConverterString.lower(newTriage),$0
+    )
+}
+}
+    
+    /**
+     * Token totals and estimated cost across all projects for days from `start`
+     * to `end` inclusive (`YYYY-MM-DD`), for week and billing-month views.
+     */
+open func usageForPeriod(start: String, end: String)throws  -> PeriodUsage {
+    return try  FfiConverterTypePeriodUsage.lift(try rustCallWithError(FfiConverterTypeHudFfiError.lift) {
+    uniffi_hud_core_fn_method_hudengine_usage_for_period(self.uniffiClonePointer(),
+        FfiConverterString.lower(start),
+        FfiConverterString.lower(end),$0
+    )
+})
+}
+    
+    /**
+     * Validates a project path before adding it.
+     *
+     * Returns validation result indicating whether the path is valid,
+     * if there's a better path to use, or if the project is missing CLAUDE.md.
+     *
+     * This enables smart UI flows like:
+     * - Suggesting parent directory when user picks a subdirectory
+     * - Warning about dangerous paths (/, ~, etc.)
+     * - Offering to create CLAUDE.md when missing
+     * - Detecting if the project is already tracked
+     */
+open func validateProject(path: String) -> ValidationResultFfi {
+    return try!  FfiConverterTypeValidationResultFfi.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_validate_project(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+    /**
+     * Audits how often Claude project folder names decode to the wrong path.
+     *
+     * Compares each folder's decoded path with the cwd recorded in its transcripts.
+     */
+open func verifyProjectEncoding() -> ProjectEncodingReport {
+    return try!  FfiConverterTypeProjectEncodingReport.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_verify_project_encoding(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Returns true while background refresh is paused.
+     */
+open func watchersPaused() -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_hud_core_fn_method_hudengine_watchers_paused(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHudEngine: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = HudEngine
 
     public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> HudEngine {
         return HudEngine(unsafeFromRawPointer: pointer)
@@ -1484,7 +3334,7 @@ public struct FfiConverterTypeHudEngine: FfiConverter {
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
         let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
-        if ptr == nil {
+        if (ptr == nil) {
             throw UniffiInternalError.unexpectedNullPointer
         }
         return try lift(ptr!)
@@ -1497,20 +3347,24 @@ public struct FfiConverterTypeHudEngine: FfiConverter {
     }
 }
 
+
+
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
 public func FfiConverterTypeHudEngine_lift(_ pointer: UnsafeMutableRawPointer) throws -> HudEngine {
     return try FfiConverterTypeHudEngine.lift(pointer)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
 public func FfiConverterTypeHudEngine_lower(_ value: HudEngine) -> UnsafeMutableRawPointer {
     return FfiConverterTypeHudEngine.lower(value)
 }
 
+
 /**
  * The resolved activation decision.
  */
@@ -1533,22 +3387,23 @@ public struct ActivationDecision {
     public init(
         /**
          * Primary action to attempt
-         */ primary: ActivationAction,
+         */primary: ActivationAction, 
         /**
-            * Fallback action if primary fails
-            */ fallback: ActivationAction?,
+         * Fallback action if primary fails
+         */fallback: ActivationAction?, 
         /**
-            * Debug context explaining why this decision was made
-            */ reason: String
-    ) {
+         * Debug context explaining why this decision was made
+         */reason: String) {
         self.primary = primary
         self.fallback = fallback
         self.reason = reason
     }
 }
 
+
+
 extension ActivationDecision: Equatable, Hashable {
-    public static func == (lhs: ActivationDecision, rhs: ActivationDecision) -> Bool {
+    public static func ==(lhs: ActivationDecision, rhs: ActivationDecision) -> Bool {
         if lhs.primary != rhs.primary {
             return false
         }
@@ -1568,17 +3423,18 @@ extension ActivationDecision: Equatable, Hashable {
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
 public struct FfiConverterTypeActivationDecision: FfiConverterRustBuffer {
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ActivationDecision {
         return
             try ActivationDecision(
-                primary: FfiConverterTypeActivationAction.read(from: &buf),
-                fallback: FfiConverterOptionTypeActivationAction.read(from: &buf),
+                primary: FfiConverterTypeActivationAction.read(from: &buf), 
+                fallback: FfiConverterOptionTypeActivationAction.read(from: &buf), 
                 reason: FfiConverterString.read(from: &buf)
-            )
+        )
     }
 
     public static func write(_ value: ActivationDecision, into buf: inout [UInt8]) {
@@ -1588,1654 +3444,1702 @@ public struct FfiConverterTypeActivationDecision: FfiConverterRustBuffer {
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
 public func FfiConverterTypeActivationDecision_lift(_ buf: RustBuffer) throws -> ActivationDecision {
     return try FfiConverterTypeActivationDecision.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
 public func FfiConverterTypeActivationDecision_lower(_ value: ActivationDecision) -> RustBuffer {
     return FfiConverterTypeActivationDecision.lower(value)
 }
 
+
 /**
- * A detected agent session
- *
- * NOTE: The composite key is (agent_type, session_id). Session IDs are only
- * unique within an agent type, not globally.
+ * The session Claude is actively working in, for "jump to active work".
  */
-public struct AgentSession {
-    public var agentType: AgentType
-    public var agentName: String
-    public var state: AgentState
-    public var sessionId: String?
-    public var cwd: String
-    public var detail: String?
+public struct ActiveWorkingSession {
+    /**
+     * `project_dir` when the hook recorded one, otherwise the session's cwd
+     */
+    public var projectPath: String
+    public var sessionId: String
     public var workingOn: String?
-    public var updatedAt: String?
+    public var state: SessionState
+    public var parentApp: ParentApp
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(agentType: AgentType, agentName: String, state: AgentState, sessionId: String?, cwd: String, detail: String?, workingOn: String?, updatedAt: String?) {
-        self.agentType = agentType
-        self.agentName = agentName
-        self.state = state
+    public init(
+        /**
+         * `project_dir` when the hook recorded one, otherwise the session's cwd
+         */projectPath: String, sessionId: String, workingOn: String?, state: SessionState, parentApp: ParentApp) {
+        self.projectPath = projectPath
         self.sessionId = sessionId
-        self.cwd = cwd
-        self.detail = detail
         self.workingOn = workingOn
-        self.updatedAt = updatedAt
+        self.state = state
+        self.parentApp = parentApp
     }
 }
 
-extension AgentSession: Equatable, Hashable {
-    public static func == (lhs: AgentSession, rhs: AgentSession) -> Bool {
-        if lhs.agentType != rhs.agentType {
-            return false
-        }
-        if lhs.agentName != rhs.agentName {
-            return false
-        }
-        if lhs.state != rhs.state {
+
+
+extension ActiveWorkingSession: Equatable, Hashable {
+    public static func ==(lhs: ActiveWorkingSession, rhs: ActiveWorkingSession) -> Bool {
+        if lhs.projectPath != rhs.projectPath {
             return false
         }
         if lhs.sessionId != rhs.sessionId {
             return false
         }
-        if lhs.cwd != rhs.cwd {
-            return false
-        }
-        if lhs.detail != rhs.detail {
+        if lhs.workingOn != rhs.workingOn {
             return false
         }
-        if lhs.workingOn != rhs.workingOn {
+        if lhs.state != rhs.state {
             return false
         }
-        if lhs.updatedAt != rhs.updatedAt {
+        if lhs.parentApp != rhs.parentApp {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(agentType)
-        hasher.combine(agentName)
-        hasher.combine(state)
+        hasher.combine(projectPath)
         hasher.combine(sessionId)
-        hasher.combine(cwd)
-        hasher.combine(detail)
         hasher.combine(workingOn)
-        hasher.combine(updatedAt)
+        hasher.combine(state)
+        hasher.combine(parentApp)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeAgentSession: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AgentSession {
+public struct FfiConverterTypeActiveWorkingSession: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ActiveWorkingSession {
         return
-            try AgentSession(
-                agentType: FfiConverterTypeAgentType.read(from: &buf),
-                agentName: FfiConverterString.read(from: &buf),
-                state: FfiConverterTypeAgentState.read(from: &buf),
-                sessionId: FfiConverterOptionString.read(from: &buf),
-                cwd: FfiConverterString.read(from: &buf),
-                detail: FfiConverterOptionString.read(from: &buf),
-                workingOn: FfiConverterOptionString.read(from: &buf),
-                updatedAt: FfiConverterOptionString.read(from: &buf)
-            )
+            try ActiveWorkingSession(
+                projectPath: FfiConverterString.read(from: &buf), 
+                sessionId: FfiConverterString.read(from: &buf), 
+                workingOn: FfiConverterOptionString.read(from: &buf), 
+                state: FfiConverterTypeSessionState.read(from: &buf), 
+                parentApp: FfiConverterTypeParentApp.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: AgentSession, into buf: inout [UInt8]) {
-        FfiConverterTypeAgentType.write(value.agentType, into: &buf)
-        FfiConverterString.write(value.agentName, into: &buf)
-        FfiConverterTypeAgentState.write(value.state, into: &buf)
-        FfiConverterOptionString.write(value.sessionId, into: &buf)
-        FfiConverterString.write(value.cwd, into: &buf)
-        FfiConverterOptionString.write(value.detail, into: &buf)
+    public static func write(_ value: ActiveWorkingSession, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.projectPath, into: &buf)
+        FfiConverterString.write(value.sessionId, into: &buf)
         FfiConverterOptionString.write(value.workingOn, into: &buf)
-        FfiConverterOptionString.write(value.updatedAt, into: &buf)
+        FfiConverterTypeSessionState.write(value.state, into: &buf)
+        FfiConverterTypeParentApp.write(value.parentApp, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeAgentSession_lift(_ buf: RustBuffer) throws -> AgentSession {
-    return try FfiConverterTypeAgentSession.lift(buf)
+public func FfiConverterTypeActiveWorkingSession_lift(_ buf: RustBuffer) throws -> ActiveWorkingSession {
+    return try FfiConverterTypeActiveWorkingSession.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeAgentSession_lower(_ value: AgentSession) -> RustBuffer {
-    return FfiConverterTypeAgentSession.lower(value)
+public func FfiConverterTypeActiveWorkingSession_lower(_ value: ActiveWorkingSession) -> RustBuffer {
+    return FfiConverterTypeActiveWorkingSession.lower(value)
 }
 
+
 /**
- * A skill, command, or agent definition.
+ * Consecutive days with transcript activity for a project.
  */
-public struct Artifact {
-    public var artifactType: String
-    public var name: String
-    public var description: String
-    public var source: String
-    public var path: String
+public struct ActivityStreak {
+    /**
+     * Run of active days ending today, or yesterday if today has no activity yet
+     */
+    public var currentDays: UInt32
+    public var longestDays: UInt32
+    /**
+     * Most recent active day as YYYY-MM-DD in local time
+     */
+    public var lastActiveDate: String?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(artifactType: String, name: String, description: String, source: String, path: String) {
-        self.artifactType = artifactType
-        self.name = name
-        self.description = description
-        self.source = source
-        self.path = path
+    public init(
+        /**
+         * Run of active days ending today, or yesterday if today has no activity yet
+         */currentDays: UInt32, longestDays: UInt32, 
+        /**
+         * Most recent active day as YYYY-MM-DD in local time
+         */lastActiveDate: String?) {
+        self.currentDays = currentDays
+        self.longestDays = longestDays
+        self.lastActiveDate = lastActiveDate
     }
 }
 
-extension Artifact: Equatable, Hashable {
-    public static func == (lhs: Artifact, rhs: Artifact) -> Bool {
-        if lhs.artifactType != rhs.artifactType {
-            return false
-        }
-        if lhs.name != rhs.name {
-            return false
-        }
-        if lhs.description != rhs.description {
+
+
+extension ActivityStreak: Equatable, Hashable {
+    public static func ==(lhs: ActivityStreak, rhs: ActivityStreak) -> Bool {
+        if lhs.currentDays != rhs.currentDays {
             return false
         }
-        if lhs.source != rhs.source {
+        if lhs.longestDays != rhs.longestDays {
             return false
         }
-        if lhs.path != rhs.path {
+        if lhs.lastActiveDate != rhs.lastActiveDate {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(artifactType)
-        hasher.combine(name)
-        hasher.combine(description)
-        hasher.combine(source)
-        hasher.combine(path)
+        hasher.combine(currentDays)
+        hasher.combine(longestDays)
+        hasher.combine(lastActiveDate)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeArtifact: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Artifact {
+public struct FfiConverterTypeActivityStreak: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ActivityStreak {
         return
-            try Artifact(
-                artifactType: FfiConverterString.read(from: &buf),
-                name: FfiConverterString.read(from: &buf),
-                description: FfiConverterString.read(from: &buf),
-                source: FfiConverterString.read(from: &buf),
-                path: FfiConverterString.read(from: &buf)
-            )
+            try ActivityStreak(
+                currentDays: FfiConverterUInt32.read(from: &buf), 
+                longestDays: FfiConverterUInt32.read(from: &buf), 
+                lastActiveDate: FfiConverterOptionString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: Artifact, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.artifactType, into: &buf)
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterString.write(value.description, into: &buf)
-        FfiConverterString.write(value.source, into: &buf)
-        FfiConverterString.write(value.path, into: &buf)
+    public static func write(_ value: ActivityStreak, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.currentDays, into: &buf)
+        FfiConverterUInt32.write(value.longestDays, into: &buf)
+        FfiConverterOptionString.write(value.lastActiveDate, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeArtifact_lift(_ buf: RustBuffer) throws -> Artifact {
-    return try FfiConverterTypeArtifact.lift(buf)
+public func FfiConverterTypeActivityStreak_lift(_ buf: RustBuffer) throws -> ActivityStreak {
+    return try FfiConverterTypeActivityStreak.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeArtifact_lower(_ value: Artifact) -> RustBuffer {
-    return FfiConverterTypeArtifact.lower(value)
+public func FfiConverterTypeActivityStreak_lower(_ value: ActivityStreak) -> RustBuffer {
+    return FfiConverterTypeActivityStreak.lower(value)
 }
 
+
 /**
- * Cached file metadata for cache invalidation.
+ * A file with uncommitted changes that an agent session edited.
  */
-public struct CachedFileInfo {
-    public var size: UInt64
-    public var mtime: UInt64
+public struct AgentChange {
+    /**
+     * Absolute path to the changed file
+     */
+    public var filePath: String
+    /**
+     * Path relative to the project root
+     */
+    public var relativePath: String
+    public var kind: AgentChangeKind
+    /**
+     * True when git does not track the file yet (newly created)
+     */
+    public var untracked: Bool
+    /**
+     * The last tool that wrote the file (Edit, Write, NotebookEdit)
+     */
+    public var lastTool: String
+    /**
+     * ISO 8601 timestamp of the last agent edit
+     */
+    public var lastEditedAt: String
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(size: UInt64, mtime: UInt64) {
-        self.size = size
-        self.mtime = mtime
+    public init(
+        /**
+         * Absolute path to the changed file
+         */filePath: String, 
+        /**
+         * Path relative to the project root
+         */relativePath: String, kind: AgentChangeKind, 
+        /**
+         * True when git does not track the file yet (newly created)
+         */untracked: Bool, 
+        /**
+         * The last tool that wrote the file (Edit, Write, NotebookEdit)
+         */lastTool: String, 
+        /**
+         * ISO 8601 timestamp of the last agent edit
+         */lastEditedAt: String) {
+        self.filePath = filePath
+        self.relativePath = relativePath
+        self.kind = kind
+        self.untracked = untracked
+        self.lastTool = lastTool
+        self.lastEditedAt = lastEditedAt
     }
 }
 
-extension CachedFileInfo: Equatable, Hashable {
-    public static func == (lhs: CachedFileInfo, rhs: CachedFileInfo) -> Bool {
-        if lhs.size != rhs.size {
+
+
+extension AgentChange: Equatable, Hashable {
+    public static func ==(lhs: AgentChange, rhs: AgentChange) -> Bool {
+        if lhs.filePath != rhs.filePath {
             return false
         }
-        if lhs.mtime != rhs.mtime {
+        if lhs.relativePath != rhs.relativePath {
+            return false
+        }
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.untracked != rhs.untracked {
+            return false
+        }
+        if lhs.lastTool != rhs.lastTool {
+            return false
+        }
+        if lhs.lastEditedAt != rhs.lastEditedAt {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(size)
-        hasher.combine(mtime)
+        hasher.combine(filePath)
+        hasher.combine(relativePath)
+        hasher.combine(kind)
+        hasher.combine(untracked)
+        hasher.combine(lastTool)
+        hasher.combine(lastEditedAt)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCachedFileInfo: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CachedFileInfo {
+public struct FfiConverterTypeAgentChange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AgentChange {
         return
-            try CachedFileInfo(
-                size: FfiConverterUInt64.read(from: &buf),
-                mtime: FfiConverterUInt64.read(from: &buf)
-            )
+            try AgentChange(
+                filePath: FfiConverterString.read(from: &buf), 
+                relativePath: FfiConverterString.read(from: &buf), 
+                kind: FfiConverterTypeAgentChangeKind.read(from: &buf), 
+                untracked: FfiConverterBool.read(from: &buf), 
+                lastTool: FfiConverterString.read(from: &buf), 
+                lastEditedAt: FfiConverterString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: CachedFileInfo, into buf: inout [UInt8]) {
-        FfiConverterUInt64.write(value.size, into: &buf)
-        FfiConverterUInt64.write(value.mtime, into: &buf)
+    public static func write(_ value: AgentChange, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.filePath, into: &buf)
+        FfiConverterString.write(value.relativePath, into: &buf)
+        FfiConverterTypeAgentChangeKind.write(value.kind, into: &buf)
+        FfiConverterBool.write(value.untracked, into: &buf)
+        FfiConverterString.write(value.lastTool, into: &buf)
+        FfiConverterString.write(value.lastEditedAt, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCachedFileInfo_lift(_ buf: RustBuffer) throws -> CachedFileInfo {
-    return try FfiConverterTypeCachedFileInfo.lift(buf)
+public func FfiConverterTypeAgentChange_lift(_ buf: RustBuffer) throws -> AgentChange {
+    return try FfiConverterTypeAgentChange.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCachedFileInfo_lower(_ value: CachedFileInfo) -> RustBuffer {
-    return FfiConverterTypeCachedFileInfo.lower(value)
+public func FfiConverterTypeAgentChange_lower(_ value: AgentChange) -> RustBuffer {
+    return FfiConverterTypeAgentChange.lower(value)
 }
 
+
 /**
- * Cached statistics for a single project.
+ * A detected agent session
+ *
+ * NOTE: The composite key is (agent_type, session_id). Session IDs are only
+ * unique within an agent type, not globally.
  */
-public struct CachedProjectStats {
-    public var files: [String: CachedFileInfo]
-    public var stats: ProjectStats
+public struct AgentSession {
+    public var agentType: AgentType
+    public var agentName: String
+    public var state: AgentState
+    public var sessionId: String?
+    public var cwd: String
+    /**
+     * Project root enclosing `cwd` (nearest boundary such as a git root or
+     * manifest), so a session started in `repo/src` groups under `repo`.
+     * None when no boundary is found.
+     */
+    public var projectPath: String?
+    public var detail: String?
+    public var workingOn: String?
+    public var updatedAt: String?
+    /**
+     * Subagents (Task tool) currently running under this session
+     */
+    public var activeSubagentCount: UInt32
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(files: [String: CachedFileInfo], stats: ProjectStats) {
-        self.files = files
-        self.stats = stats
+    public init(agentType: AgentType, agentName: String, state: AgentState, sessionId: String?, cwd: String, 
+        /**
+         * Project root enclosing `cwd` (nearest boundary such as a git root or
+         * manifest), so a session started in `repo/src` groups under `repo`.
+         * None when no boundary is found.
+         */projectPath: String?, detail: String?, workingOn: String?, updatedAt: String?, 
+        /**
+         * Subagents (Task tool) currently running under this session
+         */activeSubagentCount: UInt32) {
+        self.agentType = agentType
+        self.agentName = agentName
+        self.state = state
+        self.sessionId = sessionId
+        self.cwd = cwd
+        self.projectPath = projectPath
+        self.detail = detail
+        self.workingOn = workingOn
+        self.updatedAt = updatedAt
+        self.activeSubagentCount = activeSubagentCount
     }
 }
 
-extension CachedProjectStats: Equatable, Hashable {
-    public static func == (lhs: CachedProjectStats, rhs: CachedProjectStats) -> Bool {
-        if lhs.files != rhs.files {
+
+
+extension AgentSession: Equatable, Hashable {
+    public static func ==(lhs: AgentSession, rhs: AgentSession) -> Bool {
+        if lhs.agentType != rhs.agentType {
             return false
         }
-        if lhs.stats != rhs.stats {
+        if lhs.agentName != rhs.agentName {
+            return false
+        }
+        if lhs.state != rhs.state {
+            return false
+        }
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        if lhs.cwd != rhs.cwd {
+            return false
+        }
+        if lhs.projectPath != rhs.projectPath {
+            return false
+        }
+        if lhs.detail != rhs.detail {
+            return false
+        }
+        if lhs.workingOn != rhs.workingOn {
+            return false
+        }
+        if lhs.updatedAt != rhs.updatedAt {
+            return false
+        }
+        if lhs.activeSubagentCount != rhs.activeSubagentCount {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(files)
-        hasher.combine(stats)
+        hasher.combine(agentType)
+        hasher.combine(agentName)
+        hasher.combine(state)
+        hasher.combine(sessionId)
+        hasher.combine(cwd)
+        hasher.combine(projectPath)
+        hasher.combine(detail)
+        hasher.combine(workingOn)
+        hasher.combine(updatedAt)
+        hasher.combine(activeSubagentCount)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCachedProjectStats: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CachedProjectStats {
+public struct FfiConverterTypeAgentSession: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AgentSession {
         return
-            try CachedProjectStats(
-                files: FfiConverterDictionaryStringTypeCachedFileInfo.read(from: &buf),
-                stats: FfiConverterTypeProjectStats.read(from: &buf)
-            )
+            try AgentSession(
+                agentType: FfiConverterTypeAgentType.read(from: &buf), 
+                agentName: FfiConverterString.read(from: &buf), 
+                state: FfiConverterTypeAgentState.read(from: &buf), 
+                sessionId: FfiConverterOptionString.read(from: &buf), 
+                cwd: FfiConverterString.read(from: &buf), 
+                projectPath: FfiConverterOptionString.read(from: &buf), 
+                detail: FfiConverterOptionString.read(from: &buf), 
+                workingOn: FfiConverterOptionString.read(from: &buf), 
+                updatedAt: FfiConverterOptionString.read(from: &buf), 
+                activeSubagentCount: FfiConverterUInt32.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: CachedProjectStats, into buf: inout [UInt8]) {
-        FfiConverterDictionaryStringTypeCachedFileInfo.write(value.files, into: &buf)
-        FfiConverterTypeProjectStats.write(value.stats, into: &buf)
+    public static func write(_ value: AgentSession, into buf: inout [UInt8]) {
+        FfiConverterTypeAgentType.write(value.agentType, into: &buf)
+        FfiConverterString.write(value.agentName, into: &buf)
+        FfiConverterTypeAgentState.write(value.state, into: &buf)
+        FfiConverterOptionString.write(value.sessionId, into: &buf)
+        FfiConverterString.write(value.cwd, into: &buf)
+        FfiConverterOptionString.write(value.projectPath, into: &buf)
+        FfiConverterOptionString.write(value.detail, into: &buf)
+        FfiConverterOptionString.write(value.workingOn, into: &buf)
+        FfiConverterOptionString.write(value.updatedAt, into: &buf)
+        FfiConverterUInt32.write(value.activeSubagentCount, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCachedProjectStats_lift(_ buf: RustBuffer) throws -> CachedProjectStats {
-    return try FfiConverterTypeCachedProjectStats.lift(buf)
+public func FfiConverterTypeAgentSession_lift(_ buf: RustBuffer) throws -> AgentSession {
+    return try FfiConverterTypeAgentSession.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCachedProjectStats_lower(_ value: CachedProjectStats) -> RustBuffer {
-    return FfiConverterTypeCachedProjectStats.lower(value)
+public func FfiConverterTypeAgentSession_lower(_ value: AgentSession) -> RustBuffer {
+    return FfiConverterTypeAgentSession.lower(value)
 }
 
+
 /**
- * Results from a cleanup operation.
+ * A skill, command, or agent definition.
  */
-public struct CleanupStats {
-    /**
-     * Number of orphaned lock directories removed (dead PIDs).
-     */
-    public var locksRemoved: UInt32
-    /**
-     * Number of legacy MD5-hash locks removed (dead PIDs).
-     */
-    public var legacyLocksRemoved: UInt32
-    /**
-     * Number of orphaned lock-holder processes killed (monitoring dead PIDs).
-     */
-    public var orphanedProcessesKilled: UInt32
-    /**
-     * Number of orphaned session records removed (stale + no active lock).
-     */
-    public var orphanedSessionsRemoved: UInt32
-    /**
-     * Number of expired session records removed (> 24 hours old).
-     */
-    public var sessionsRemoved: UInt32
-    /**
-     * Number of old tombstone files removed (> 1 minute old).
-     */
-    public var tombstonesRemoved: UInt32
-    /**
-     * Number of old file activity entries cleaned up (> 24 hours old).
-     */
-    public var activityEntriesRemoved: UInt32
+public struct Artifact {
+    public var artifactType: String
+    public var name: String
+    public var description: String
+    public var source: String
+    public var path: String
     /**
-     * Errors encountered during cleanup.
+     * False when the user switched this plugin artifact off with
+     * `toggle_plugin_artifact`
      */
-    public var errors: [String]
+    public var enabled: Bool
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(
-        /**
-         * Number of orphaned lock directories removed (dead PIDs).
-         */ locksRemoved: UInt32,
-        /**
-            * Number of legacy MD5-hash locks removed (dead PIDs).
-            */ legacyLocksRemoved: UInt32,
-        /**
-            * Number of orphaned lock-holder processes killed (monitoring dead PIDs).
-            */ orphanedProcessesKilled: UInt32,
-        /**
-            * Number of orphaned session records removed (stale + no active lock).
-            */ orphanedSessionsRemoved: UInt32,
+    public init(artifactType: String, name: String, description: String, source: String, path: String, 
         /**
-            * Number of expired session records removed (> 24 hours old).
-            */ sessionsRemoved: UInt32,
-        /**
-            * Number of old tombstone files removed (> 1 minute old).
-            */ tombstonesRemoved: UInt32,
-        /**
-            * Number of old file activity entries cleaned up (> 24 hours old).
-            */ activityEntriesRemoved: UInt32,
-        /**
-            * Errors encountered during cleanup.
-            */ errors: [String]
-    ) {
-        self.locksRemoved = locksRemoved
-        self.legacyLocksRemoved = legacyLocksRemoved
-        self.orphanedProcessesKilled = orphanedProcessesKilled
-        self.orphanedSessionsRemoved = orphanedSessionsRemoved
-        self.sessionsRemoved = sessionsRemoved
-        self.tombstonesRemoved = tombstonesRemoved
-        self.activityEntriesRemoved = activityEntriesRemoved
-        self.errors = errors
+         * False when the user switched this plugin artifact off with
+         * `toggle_plugin_artifact`
+         */enabled: Bool) {
+        self.artifactType = artifactType
+        self.name = name
+        self.description = description
+        self.source = source
+        self.path = path
+        self.enabled = enabled
     }
 }
 
-extension CleanupStats: Equatable, Hashable {
-    public static func == (lhs: CleanupStats, rhs: CleanupStats) -> Bool {
-        if lhs.locksRemoved != rhs.locksRemoved {
-            return false
-        }
-        if lhs.legacyLocksRemoved != rhs.legacyLocksRemoved {
-            return false
-        }
-        if lhs.orphanedProcessesKilled != rhs.orphanedProcessesKilled {
+
+
+extension Artifact: Equatable, Hashable {
+    public static func ==(lhs: Artifact, rhs: Artifact) -> Bool {
+        if lhs.artifactType != rhs.artifactType {
             return false
         }
-        if lhs.orphanedSessionsRemoved != rhs.orphanedSessionsRemoved {
+        if lhs.name != rhs.name {
             return false
         }
-        if lhs.sessionsRemoved != rhs.sessionsRemoved {
+        if lhs.description != rhs.description {
             return false
         }
-        if lhs.tombstonesRemoved != rhs.tombstonesRemoved {
+        if lhs.source != rhs.source {
             return false
         }
-        if lhs.activityEntriesRemoved != rhs.activityEntriesRemoved {
+        if lhs.path != rhs.path {
             return false
         }
-        if lhs.errors != rhs.errors {
+        if lhs.enabled != rhs.enabled {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(locksRemoved)
-        hasher.combine(legacyLocksRemoved)
-        hasher.combine(orphanedProcessesKilled)
-        hasher.combine(orphanedSessionsRemoved)
-        hasher.combine(sessionsRemoved)
-        hasher.combine(tombstonesRemoved)
-        hasher.combine(activityEntriesRemoved)
-        hasher.combine(errors)
+        hasher.combine(artifactType)
+        hasher.combine(name)
+        hasher.combine(description)
+        hasher.combine(source)
+        hasher.combine(path)
+        hasher.combine(enabled)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCleanupStats: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CleanupStats {
+public struct FfiConverterTypeArtifact: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Artifact {
         return
-            try CleanupStats(
-                locksRemoved: FfiConverterUInt32.read(from: &buf),
-                legacyLocksRemoved: FfiConverterUInt32.read(from: &buf),
-                orphanedProcessesKilled: FfiConverterUInt32.read(from: &buf),
-                orphanedSessionsRemoved: FfiConverterUInt32.read(from: &buf),
-                sessionsRemoved: FfiConverterUInt32.read(from: &buf),
-                tombstonesRemoved: FfiConverterUInt32.read(from: &buf),
-                activityEntriesRemoved: FfiConverterUInt32.read(from: &buf),
-                errors: FfiConverterSequenceString.read(from: &buf)
-            )
+            try Artifact(
+                artifactType: FfiConverterString.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                description: FfiConverterString.read(from: &buf), 
+                source: FfiConverterString.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf), 
+                enabled: FfiConverterBool.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: CleanupStats, into buf: inout [UInt8]) {
-        FfiConverterUInt32.write(value.locksRemoved, into: &buf)
-        FfiConverterUInt32.write(value.legacyLocksRemoved, into: &buf)
-        FfiConverterUInt32.write(value.orphanedProcessesKilled, into: &buf)
-        FfiConverterUInt32.write(value.orphanedSessionsRemoved, into: &buf)
-        FfiConverterUInt32.write(value.sessionsRemoved, into: &buf)
-        FfiConverterUInt32.write(value.tombstonesRemoved, into: &buf)
-        FfiConverterUInt32.write(value.activityEntriesRemoved, into: &buf)
-        FfiConverterSequenceString.write(value.errors, into: &buf)
+    public static func write(_ value: Artifact, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.artifactType, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.description, into: &buf)
+        FfiConverterString.write(value.source, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterBool.write(value.enabled, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCleanupStats_lift(_ buf: RustBuffer) throws -> CleanupStats {
-    return try FfiConverterTypeCleanupStats.lift(buf)
+public func FfiConverterTypeArtifact_lift(_ buf: RustBuffer) throws -> Artifact {
+    return try FfiConverterTypeArtifact.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCleanupStats_lower(_ value: CleanupStats) -> RustBuffer {
-    return FfiConverterTypeCleanupStats.lower(value)
+public func FfiConverterTypeArtifact_lower(_ value: Artifact) -> RustBuffer {
+    return FfiConverterTypeArtifact.lower(value)
 }
 
+
 /**
- * Context window usage information.
+ * Where an artifact comes from, for navigating from an artifact to its provider.
  */
-public struct ContextInfo {
-    public var percentUsed: UInt32
-    public var tokensUsed: UInt64
-    public var contextSize: UInt64
-    public var updatedAt: String?
+public struct ArtifactOrigin {
+    /**
+     * "Global", "Project", or the providing plugin's name (matches `Artifact.source`)
+     */
+    public var source: String
+    /**
+     * Plugin id when the artifact lives under a plugin's install path
+     */
+    public var pluginId: String?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(percentUsed: UInt32, tokensUsed: UInt64, contextSize: UInt64, updatedAt: String?) {
-        self.percentUsed = percentUsed
-        self.tokensUsed = tokensUsed
-        self.contextSize = contextSize
-        self.updatedAt = updatedAt
+    public init(
+        /**
+         * "Global", "Project", or the providing plugin's name (matches `Artifact.source`)
+         */source: String, 
+        /**
+         * Plugin id when the artifact lives under a plugin's install path
+         */pluginId: String?) {
+        self.source = source
+        self.pluginId = pluginId
     }
 }
 
-extension ContextInfo: Equatable, Hashable {
-    public static func == (lhs: ContextInfo, rhs: ContextInfo) -> Bool {
-        if lhs.percentUsed != rhs.percentUsed {
-            return false
-        }
-        if lhs.tokensUsed != rhs.tokensUsed {
-            return false
-        }
-        if lhs.contextSize != rhs.contextSize {
+
+
+extension ArtifactOrigin: Equatable, Hashable {
+    public static func ==(lhs: ArtifactOrigin, rhs: ArtifactOrigin) -> Bool {
+        if lhs.source != rhs.source {
             return false
         }
-        if lhs.updatedAt != rhs.updatedAt {
+        if lhs.pluginId != rhs.pluginId {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(percentUsed)
-        hasher.combine(tokensUsed)
-        hasher.combine(contextSize)
-        hasher.combine(updatedAt)
+        hasher.combine(source)
+        hasher.combine(pluginId)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeContextInfo: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ContextInfo {
+public struct FfiConverterTypeArtifactOrigin: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ArtifactOrigin {
         return
-            try ContextInfo(
-                percentUsed: FfiConverterUInt32.read(from: &buf),
-                tokensUsed: FfiConverterUInt64.read(from: &buf),
-                contextSize: FfiConverterUInt64.read(from: &buf),
-                updatedAt: FfiConverterOptionString.read(from: &buf)
-            )
+            try ArtifactOrigin(
+                source: FfiConverterString.read(from: &buf), 
+                pluginId: FfiConverterOptionString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: ContextInfo, into buf: inout [UInt8]) {
-        FfiConverterUInt32.write(value.percentUsed, into: &buf)
-        FfiConverterUInt64.write(value.tokensUsed, into: &buf)
-        FfiConverterUInt64.write(value.contextSize, into: &buf)
-        FfiConverterOptionString.write(value.updatedAt, into: &buf)
+    public static func write(_ value: ArtifactOrigin, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.source, into: &buf)
+        FfiConverterOptionString.write(value.pluginId, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeContextInfo_lift(_ buf: RustBuffer) throws -> ContextInfo {
-    return try FfiConverterTypeContextInfo.lift(buf)
+public func FfiConverterTypeArtifactOrigin_lift(_ buf: RustBuffer) throws -> ArtifactOrigin {
+    return try FfiConverterTypeArtifactOrigin.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeContextInfo_lower(_ value: ContextInfo) -> RustBuffer {
-    return FfiConverterTypeContextInfo.lower(value)
+public func FfiConverterTypeArtifactOrigin_lower(_ value: ArtifactOrigin) -> RustBuffer {
+    return FfiConverterTypeArtifactOrigin.lower(value)
 }
 
+
 /**
- * Result of starting a project creation.
+ * How much text the user wrote versus Claude across a project's sessions.
  */
-public struct CreateProjectResult {
-    public var success: Bool
-    public var projectPath: String
-    public var sessionId: String?
-    public var error: String?
+public struct AuthorshipRatio {
+    /**
+     * Characters in typed prompts
+     */
+    public var humanChars: UInt64
+    /**
+     * Characters in Claude's text replies
+     */
+    public var assistantChars: UInt64
+    /**
+     * Human characters per assistant character (0.0 when Claude wrote nothing)
+     */
+    public var ratio: Double
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(success: Bool, projectPath: String, sessionId: String?, error: String?) {
-        self.success = success
-        self.projectPath = projectPath
-        self.sessionId = sessionId
-        self.error = error
+    public init(
+        /**
+         * Characters in typed prompts
+         */humanChars: UInt64, 
+        /**
+         * Characters in Claude's text replies
+         */assistantChars: UInt64, 
+        /**
+         * Human characters per assistant character (0.0 when Claude wrote nothing)
+         */ratio: Double) {
+        self.humanChars = humanChars
+        self.assistantChars = assistantChars
+        self.ratio = ratio
     }
 }
 
-extension CreateProjectResult: Equatable, Hashable {
-    public static func == (lhs: CreateProjectResult, rhs: CreateProjectResult) -> Bool {
-        if lhs.success != rhs.success {
-            return false
-        }
-        if lhs.projectPath != rhs.projectPath {
+
+
+extension AuthorshipRatio: Equatable, Hashable {
+    public static func ==(lhs: AuthorshipRatio, rhs: AuthorshipRatio) -> Bool {
+        if lhs.humanChars != rhs.humanChars {
             return false
         }
-        if lhs.sessionId != rhs.sessionId {
+        if lhs.assistantChars != rhs.assistantChars {
             return false
         }
-        if lhs.error != rhs.error {
+        if lhs.ratio != rhs.ratio {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(success)
-        hasher.combine(projectPath)
-        hasher.combine(sessionId)
-        hasher.combine(error)
+        hasher.combine(humanChars)
+        hasher.combine(assistantChars)
+        hasher.combine(ratio)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCreateProjectResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CreateProjectResult {
+public struct FfiConverterTypeAuthorshipRatio: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AuthorshipRatio {
         return
-            try CreateProjectResult(
-                success: FfiConverterBool.read(from: &buf),
-                projectPath: FfiConverterString.read(from: &buf),
-                sessionId: FfiConverterOptionString.read(from: &buf),
-                error: FfiConverterOptionString.read(from: &buf)
-            )
+            try AuthorshipRatio(
+                humanChars: FfiConverterUInt64.read(from: &buf), 
+                assistantChars: FfiConverterUInt64.read(from: &buf), 
+                ratio: FfiConverterDouble.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: CreateProjectResult, into buf: inout [UInt8]) {
-        FfiConverterBool.write(value.success, into: &buf)
-        FfiConverterString.write(value.projectPath, into: &buf)
-        FfiConverterOptionString.write(value.sessionId, into: &buf)
-        FfiConverterOptionString.write(value.error, into: &buf)
+    public static func write(_ value: AuthorshipRatio, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.humanChars, into: &buf)
+        FfiConverterUInt64.write(value.assistantChars, into: &buf)
+        FfiConverterDouble.write(value.ratio, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCreateProjectResult_lift(_ buf: RustBuffer) throws -> CreateProjectResult {
-    return try FfiConverterTypeCreateProjectResult.lift(buf)
+public func FfiConverterTypeAuthorshipRatio_lift(_ buf: RustBuffer) throws -> AuthorshipRatio {
+    return try FfiConverterTypeAuthorshipRatio.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCreateProjectResult_lower(_ value: CreateProjectResult) -> RustBuffer {
-    return FfiConverterTypeCreateProjectResult.lower(value)
+public func FfiConverterTypeAuthorshipRatio_lower(_ value: AuthorshipRatio) -> RustBuffer {
+    return FfiConverterTypeAuthorshipRatio.lower(value)
 }
 
+
 /**
- * Progress information for a project creation.
+ * A hook entry in settings.json whose command can't run.
  */
-public struct CreationProgress {
-    public var phase: String
-    public var message: String
-    public var percentComplete: UInt8?
+public struct BrokenHook {
+    public var event: String
+    public var matcher: String?
+    public var command: String
+    /**
+     * The script or binary the command refers to, after `~` and env expansion
+     */
+    public var target: String
+    public var reason: String
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(phase: String, message: String, percentComplete: UInt8?) {
-        self.phase = phase
-        self.message = message
-        self.percentComplete = percentComplete
+    public init(event: String, matcher: String?, command: String, 
+        /**
+         * The script or binary the command refers to, after `~` and env expansion
+         */target: String, reason: String) {
+        self.event = event
+        self.matcher = matcher
+        self.command = command
+        self.target = target
+        self.reason = reason
     }
 }
 
-extension CreationProgress: Equatable, Hashable {
-    public static func == (lhs: CreationProgress, rhs: CreationProgress) -> Bool {
-        if lhs.phase != rhs.phase {
+
+
+extension BrokenHook: Equatable, Hashable {
+    public static func ==(lhs: BrokenHook, rhs: BrokenHook) -> Bool {
+        if lhs.event != rhs.event {
             return false
         }
-        if lhs.message != rhs.message {
+        if lhs.matcher != rhs.matcher {
             return false
         }
-        if lhs.percentComplete != rhs.percentComplete {
+        if lhs.command != rhs.command {
+            return false
+        }
+        if lhs.target != rhs.target {
+            return false
+        }
+        if lhs.reason != rhs.reason {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(phase)
-        hasher.combine(message)
-        hasher.combine(percentComplete)
+        hasher.combine(event)
+        hasher.combine(matcher)
+        hasher.combine(command)
+        hasher.combine(target)
+        hasher.combine(reason)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCreationProgress: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CreationProgress {
+public struct FfiConverterTypeBrokenHook: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BrokenHook {
         return
-            try CreationProgress(
-                phase: FfiConverterString.read(from: &buf),
-                message: FfiConverterString.read(from: &buf),
-                percentComplete: FfiConverterOptionUInt8.read(from: &buf)
-            )
+            try BrokenHook(
+                event: FfiConverterString.read(from: &buf), 
+                matcher: FfiConverterOptionString.read(from: &buf), 
+                command: FfiConverterString.read(from: &buf), 
+                target: FfiConverterString.read(from: &buf), 
+                reason: FfiConverterString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: CreationProgress, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.phase, into: &buf)
-        FfiConverterString.write(value.message, into: &buf)
-        FfiConverterOptionUInt8.write(value.percentComplete, into: &buf)
+    public static func write(_ value: BrokenHook, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.event, into: &buf)
+        FfiConverterOptionString.write(value.matcher, into: &buf)
+        FfiConverterString.write(value.command, into: &buf)
+        FfiConverterString.write(value.target, into: &buf)
+        FfiConverterString.write(value.reason, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCreationProgress_lift(_ buf: RustBuffer) throws -> CreationProgress {
-    return try FfiConverterTypeCreationProgress.lift(buf)
+public func FfiConverterTypeBrokenHook_lift(_ buf: RustBuffer) throws -> BrokenHook {
+    return try FfiConverterTypeBrokenHook.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCreationProgress_lower(_ value: CreationProgress) -> RustBuffer {
-    return FfiConverterTypeCreationProgress.lower(value)
+public func FfiConverterTypeBrokenHook_lower(_ value: BrokenHook) -> RustBuffer {
+    return FfiConverterTypeBrokenHook.lower(value)
 }
 
+
 /**
- * Aggregate data for the dashboard view.
+ * This month's estimated spend compared with the configured budget.
  */
-public struct DashboardData {
-    public var global: GlobalConfig
-    public var plugins: [Plugin]
-    public var projects: [Project]
+public struct BudgetStatus {
+    /**
+     * Month covered (YYYY-MM, UTC), from the 1st through today
+     */
+    public var month: String
+    public var spentUsd: Double
+    public var budgetUsd: Double?
+    /**
+     * Spend as a percentage of the budget (100.0 = fully used), None without a budget
+     */
+    public var percentUsed: Double?
+    public var level: BudgetLevel
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(global: GlobalConfig, plugins: [Plugin], projects: [Project]) {
-        self.global = global
-        self.plugins = plugins
-        self.projects = projects
+    public init(
+        /**
+         * Month covered (YYYY-MM, UTC), from the 1st through today
+         */month: String, spentUsd: Double, budgetUsd: Double?, 
+        /**
+         * Spend as a percentage of the budget (100.0 = fully used), None without a budget
+         */percentUsed: Double?, level: BudgetLevel) {
+        self.month = month
+        self.spentUsd = spentUsd
+        self.budgetUsd = budgetUsd
+        self.percentUsed = percentUsed
+        self.level = level
     }
 }
 
-extension DashboardData: Equatable, Hashable {
-    public static func == (lhs: DashboardData, rhs: DashboardData) -> Bool {
-        if lhs.global != rhs.global {
+
+
+extension BudgetStatus: Equatable, Hashable {
+    public static func ==(lhs: BudgetStatus, rhs: BudgetStatus) -> Bool {
+        if lhs.month != rhs.month {
             return false
         }
-        if lhs.plugins != rhs.plugins {
+        if lhs.spentUsd != rhs.spentUsd {
             return false
         }
-        if lhs.projects != rhs.projects {
+        if lhs.budgetUsd != rhs.budgetUsd {
+            return false
+        }
+        if lhs.percentUsed != rhs.percentUsed {
+            return false
+        }
+        if lhs.level != rhs.level {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(global)
-        hasher.combine(plugins)
-        hasher.combine(projects)
+        hasher.combine(month)
+        hasher.combine(spentUsd)
+        hasher.combine(budgetUsd)
+        hasher.combine(percentUsed)
+        hasher.combine(level)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDashboardData: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DashboardData {
+public struct FfiConverterTypeBudgetStatus: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BudgetStatus {
         return
-            try DashboardData(
-                global: FfiConverterTypeGlobalConfig.read(from: &buf),
-                plugins: FfiConverterSequenceTypePlugin.read(from: &buf),
-                projects: FfiConverterSequenceTypeProject.read(from: &buf)
-            )
+            try BudgetStatus(
+                month: FfiConverterString.read(from: &buf), 
+                spentUsd: FfiConverterDouble.read(from: &buf), 
+                budgetUsd: FfiConverterOptionDouble.read(from: &buf), 
+                percentUsed: FfiConverterOptionDouble.read(from: &buf), 
+                level: FfiConverterTypeBudgetLevel.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: DashboardData, into buf: inout [UInt8]) {
-        FfiConverterTypeGlobalConfig.write(value.global, into: &buf)
-        FfiConverterSequenceTypePlugin.write(value.plugins, into: &buf)
-        FfiConverterSequenceTypeProject.write(value.projects, into: &buf)
+    public static func write(_ value: BudgetStatus, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.month, into: &buf)
+        FfiConverterDouble.write(value.spentUsd, into: &buf)
+        FfiConverterOptionDouble.write(value.budgetUsd, into: &buf)
+        FfiConverterOptionDouble.write(value.percentUsed, into: &buf)
+        FfiConverterTypeBudgetLevel.write(value.level, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDashboardData_lift(_ buf: RustBuffer) throws -> DashboardData {
-    return try FfiConverterTypeDashboardData.lift(buf)
+public func FfiConverterTypeBudgetStatus_lift(_ buf: RustBuffer) throws -> BudgetStatus {
+    return try FfiConverterTypeBudgetStatus.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDashboardData_lower(_ value: DashboardData) -> RustBuffer {
-    return FfiConverterTypeDashboardData.lower(value)
+public func FfiConverterTypeBudgetStatus_lower(_ value: BudgetStatus) -> RustBuffer {
+    return FfiConverterTypeBudgetStatus.lower(value)
 }
 
-public struct DependencyStatus {
-    public var name: String
-    public var required: Bool
-    public var found: Bool
-    public var path: String?
-    public var installHint: String?
+
+/**
+ * Cached file metadata for cache invalidation.
+ */
+public struct CachedFileInfo {
+    public var size: UInt64
+    public var mtime: UInt64
+    /**
+     * Stats parsed from this file alone. None for skipped files and for caches
+     * written before per-file subtotals, which forces a re-parse.
+     */
+    public var subtotal: ProjectStats?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(name: String, required: Bool, found: Bool, path: String?, installHint: String?) {
-        self.name = name
-        self.required = required
-        self.found = found
-        self.path = path
-        self.installHint = installHint
+    public init(size: UInt64, mtime: UInt64, 
+        /**
+         * Stats parsed from this file alone. None for skipped files and for caches
+         * written before per-file subtotals, which forces a re-parse.
+         */subtotal: ProjectStats?) {
+        self.size = size
+        self.mtime = mtime
+        self.subtotal = subtotal
     }
 }
 
-extension DependencyStatus: Equatable, Hashable {
-    public static func == (lhs: DependencyStatus, rhs: DependencyStatus) -> Bool {
-        if lhs.name != rhs.name {
-            return false
-        }
-        if lhs.required != rhs.required {
-            return false
-        }
-        if lhs.found != rhs.found {
+
+
+extension CachedFileInfo: Equatable, Hashable {
+    public static func ==(lhs: CachedFileInfo, rhs: CachedFileInfo) -> Bool {
+        if lhs.size != rhs.size {
             return false
         }
-        if lhs.path != rhs.path {
+        if lhs.mtime != rhs.mtime {
             return false
         }
-        if lhs.installHint != rhs.installHint {
+        if lhs.subtotal != rhs.subtotal {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(name)
-        hasher.combine(required)
-        hasher.combine(found)
-        hasher.combine(path)
-        hasher.combine(installHint)
+        hasher.combine(size)
+        hasher.combine(mtime)
+        hasher.combine(subtotal)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDependencyStatus: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DependencyStatus {
+public struct FfiConverterTypeCachedFileInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CachedFileInfo {
         return
-            try DependencyStatus(
-                name: FfiConverterString.read(from: &buf),
-                required: FfiConverterBool.read(from: &buf),
-                found: FfiConverterBool.read(from: &buf),
-                path: FfiConverterOptionString.read(from: &buf),
-                installHint: FfiConverterOptionString.read(from: &buf)
-            )
+            try CachedFileInfo(
+                size: FfiConverterUInt64.read(from: &buf), 
+                mtime: FfiConverterUInt64.read(from: &buf), 
+                subtotal: FfiConverterOptionTypeProjectStats.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: DependencyStatus, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterBool.write(value.required, into: &buf)
-        FfiConverterBool.write(value.found, into: &buf)
-        FfiConverterOptionString.write(value.path, into: &buf)
-        FfiConverterOptionString.write(value.installHint, into: &buf)
+    public static func write(_ value: CachedFileInfo, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.size, into: &buf)
+        FfiConverterUInt64.write(value.mtime, into: &buf)
+        FfiConverterOptionTypeProjectStats.write(value.subtotal, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDependencyStatus_lift(_ buf: RustBuffer) throws -> DependencyStatus {
-    return try FfiConverterTypeDependencyStatus.lift(buf)
+public func FfiConverterTypeCachedFileInfo_lift(_ buf: RustBuffer) throws -> CachedFileInfo {
+    return try FfiConverterTypeCachedFileInfo.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDependencyStatus_lower(_ value: DependencyStatus) -> RustBuffer {
-    return FfiConverterTypeDependencyStatus.lower(value)
+public func FfiConverterTypeCachedFileInfo_lower(_ value: CachedFileInfo) -> RustBuffer {
+    return FfiConverterTypeCachedFileInfo.lower(value)
 }
 
+
 /**
- * Global Claude Code configuration and artifact counts.
+ * Cached statistics for a single project.
  */
-public struct GlobalConfig {
-    public var settingsPath: String
-    public var settingsExists: Bool
-    public var instructionsPath: String?
-    public var skillsDir: String?
-    public var commandsDir: String?
-    public var agentsDir: String?
-    public var skillCount: UInt32
-    public var commandCount: UInt32
-    public var agentCount: UInt32
+public struct CachedProjectStats {
+    public var files: [String: CachedFileInfo]
+    public var stats: ProjectStats
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(settingsPath: String, settingsExists: Bool, instructionsPath: String?, skillsDir: String?, commandsDir: String?, agentsDir: String?, skillCount: UInt32, commandCount: UInt32, agentCount: UInt32) {
-        self.settingsPath = settingsPath
-        self.settingsExists = settingsExists
-        self.instructionsPath = instructionsPath
-        self.skillsDir = skillsDir
-        self.commandsDir = commandsDir
-        self.agentsDir = agentsDir
-        self.skillCount = skillCount
-        self.commandCount = commandCount
-        self.agentCount = agentCount
+    public init(files: [String: CachedFileInfo], stats: ProjectStats) {
+        self.files = files
+        self.stats = stats
     }
 }
 
-extension GlobalConfig: Equatable, Hashable {
-    public static func == (lhs: GlobalConfig, rhs: GlobalConfig) -> Bool {
-        if lhs.settingsPath != rhs.settingsPath {
-            return false
-        }
-        if lhs.settingsExists != rhs.settingsExists {
-            return false
-        }
-        if lhs.instructionsPath != rhs.instructionsPath {
-            return false
-        }
-        if lhs.skillsDir != rhs.skillsDir {
-            return false
-        }
-        if lhs.commandsDir != rhs.commandsDir {
-            return false
-        }
-        if lhs.agentsDir != rhs.agentsDir {
-            return false
-        }
-        if lhs.skillCount != rhs.skillCount {
-            return false
-        }
-        if lhs.commandCount != rhs.commandCount {
+
+
+extension CachedProjectStats: Equatable, Hashable {
+    public static func ==(lhs: CachedProjectStats, rhs: CachedProjectStats) -> Bool {
+        if lhs.files != rhs.files {
             return false
         }
-        if lhs.agentCount != rhs.agentCount {
+        if lhs.stats != rhs.stats {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(settingsPath)
-        hasher.combine(settingsExists)
-        hasher.combine(instructionsPath)
-        hasher.combine(skillsDir)
-        hasher.combine(commandsDir)
-        hasher.combine(agentsDir)
-        hasher.combine(skillCount)
-        hasher.combine(commandCount)
-        hasher.combine(agentCount)
+        hasher.combine(files)
+        hasher.combine(stats)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeGlobalConfig: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GlobalConfig {
+public struct FfiConverterTypeCachedProjectStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CachedProjectStats {
         return
-            try GlobalConfig(
-                settingsPath: FfiConverterString.read(from: &buf),
-                settingsExists: FfiConverterBool.read(from: &buf),
-                instructionsPath: FfiConverterOptionString.read(from: &buf),
-                skillsDir: FfiConverterOptionString.read(from: &buf),
-                commandsDir: FfiConverterOptionString.read(from: &buf),
-                agentsDir: FfiConverterOptionString.read(from: &buf),
-                skillCount: FfiConverterUInt32.read(from: &buf),
-                commandCount: FfiConverterUInt32.read(from: &buf),
-                agentCount: FfiConverterUInt32.read(from: &buf)
-            )
+            try CachedProjectStats(
+                files: FfiConverterDictionaryStringTypeCachedFileInfo.read(from: &buf), 
+                stats: FfiConverterTypeProjectStats.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: GlobalConfig, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.settingsPath, into: &buf)
-        FfiConverterBool.write(value.settingsExists, into: &buf)
-        FfiConverterOptionString.write(value.instructionsPath, into: &buf)
-        FfiConverterOptionString.write(value.skillsDir, into: &buf)
-        FfiConverterOptionString.write(value.commandsDir, into: &buf)
-        FfiConverterOptionString.write(value.agentsDir, into: &buf)
-        FfiConverterUInt32.write(value.skillCount, into: &buf)
-        FfiConverterUInt32.write(value.commandCount, into: &buf)
-        FfiConverterUInt32.write(value.agentCount, into: &buf)
+    public static func write(_ value: CachedProjectStats, into buf: inout [UInt8]) {
+        FfiConverterDictionaryStringTypeCachedFileInfo.write(value.files, into: &buf)
+        FfiConverterTypeProjectStats.write(value.stats, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeGlobalConfig_lift(_ buf: RustBuffer) throws -> GlobalConfig {
-    return try FfiConverterTypeGlobalConfig.lift(buf)
+public func FfiConverterTypeCachedProjectStats_lift(_ buf: RustBuffer) throws -> CachedProjectStats {
+    return try FfiConverterTypeCachedProjectStats.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeGlobalConfig_lower(_ value: GlobalConfig) -> RustBuffer {
-    return FfiConverterTypeGlobalConfig.lower(value)
+public func FfiConverterTypeCachedProjectStats_lower(_ value: CachedProjectStats) -> RustBuffer {
+    return FfiConverterTypeCachedProjectStats.lower(value)
 }
 
+
 /**
- * Unified diagnostic report combining installation status and runtime health.
- *
- * This provides a single source of truth for the UI to determine what to show
- * and whether auto-fix is available.
+ * Results from a cleanup operation.
  */
-public struct HookDiagnosticReport {
+public struct CleanupStats {
     /**
-     * True if everything is working correctly
+     * Number of orphaned lock directories removed (dead PIDs).
      */
-    public var isHealthy: Bool
+    public var locksRemoved: UInt32
     /**
-     * The most critical issue to display (if any)
+     * Number of legacy MD5-hash locks removed (dead PIDs).
      */
-    public var primaryIssue: HookIssue?
+    public var legacyLocksRemoved: UInt32
     /**
-     * True if "Fix All" can resolve the issue
+     * Number of orphaned lock-holder processes killed (monitoring dead PIDs).
      */
-    public var canAutoFix: Bool
+    public var orphanedProcessesKilled: UInt32
     /**
-     * Whether this appears to be a first-time setup (no heartbeat ever seen)
+     * Number of orphaned session records removed (stale + no active lock).
      */
-    public var isFirstRun: Bool
+    public var orphanedSessionsRemoved: UInt32
     /**
-     * Detailed status for checklist display
+     * Number of expired session records removed (> 24 hours old).
      */
-    public var binaryOk: Bool
-    public var configOk: Bool
-    public var firingOk: Bool
+    public var sessionsRemoved: UInt32
     /**
-     * Path to the hook binary/symlink (e.g., ~/.local/bin/hud-hook)
+     * Number of old tombstone files removed (> 1 minute old).
      */
-    public var symlinkPath: String
+    public var tombstonesRemoved: UInt32
     /**
-     * Target of the symlink if it is one, None if regular file or doesn't exist
+     * Number of old file activity entries cleaned up (> 24 hours old).
      */
-    public var symlinkTarget: String?
+    public var activityEntriesRemoved: UInt32
     /**
-     * Age of last heartbeat in seconds (for "last seen X ago" display)
+     * Errors encountered during cleanup.
      */
-    public var lastHeartbeatAgeSecs: UInt64?
+    public var errors: [String]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * True if everything is working correctly
-         */ isHealthy: Bool,
+         * Number of orphaned lock directories removed (dead PIDs).
+         */locksRemoved: UInt32, 
         /**
-            * The most critical issue to display (if any)
-            */ primaryIssue: HookIssue?,
+         * Number of legacy MD5-hash locks removed (dead PIDs).
+         */legacyLocksRemoved: UInt32, 
         /**
-            * True if "Fix All" can resolve the issue
-            */ canAutoFix: Bool,
+         * Number of orphaned lock-holder processes killed (monitoring dead PIDs).
+         */orphanedProcessesKilled: UInt32, 
         /**
-            * Whether this appears to be a first-time setup (no heartbeat ever seen)
-            */ isFirstRun: Bool,
+         * Number of orphaned session records removed (stale + no active lock).
+         */orphanedSessionsRemoved: UInt32, 
         /**
-            * Detailed status for checklist display
-            */ binaryOk: Bool, configOk: Bool, firingOk: Bool,
+         * Number of expired session records removed (> 24 hours old).
+         */sessionsRemoved: UInt32, 
         /**
-            * Path to the hook binary/symlink (e.g., ~/.local/bin/hud-hook)
-            */ symlinkPath: String,
+         * Number of old tombstone files removed (> 1 minute old).
+         */tombstonesRemoved: UInt32, 
         /**
-            * Target of the symlink if it is one, None if regular file or doesn't exist
-            */ symlinkTarget: String?,
+         * Number of old file activity entries cleaned up (> 24 hours old).
+         */activityEntriesRemoved: UInt32, 
         /**
-            * Age of last heartbeat in seconds (for "last seen X ago" display)
-            */ lastHeartbeatAgeSecs: UInt64?
-    ) {
-        self.isHealthy = isHealthy
-        self.primaryIssue = primaryIssue
-        self.canAutoFix = canAutoFix
-        self.isFirstRun = isFirstRun
-        self.binaryOk = binaryOk
-        self.configOk = configOk
-        self.firingOk = firingOk
-        self.symlinkPath = symlinkPath
-        self.symlinkTarget = symlinkTarget
-        self.lastHeartbeatAgeSecs = lastHeartbeatAgeSecs
+         * Errors encountered during cleanup.
+         */errors: [String]) {
+        self.locksRemoved = locksRemoved
+        self.legacyLocksRemoved = legacyLocksRemoved
+        self.orphanedProcessesKilled = orphanedProcessesKilled
+        self.orphanedSessionsRemoved = orphanedSessionsRemoved
+        self.sessionsRemoved = sessionsRemoved
+        self.tombstonesRemoved = tombstonesRemoved
+        self.activityEntriesRemoved = activityEntriesRemoved
+        self.errors = errors
     }
 }
 
-extension HookDiagnosticReport: Equatable, Hashable {
-    public static func == (lhs: HookDiagnosticReport, rhs: HookDiagnosticReport) -> Bool {
-        if lhs.isHealthy != rhs.isHealthy {
+
+
+extension CleanupStats: Equatable, Hashable {
+    public static func ==(lhs: CleanupStats, rhs: CleanupStats) -> Bool {
+        if lhs.locksRemoved != rhs.locksRemoved {
             return false
         }
-        if lhs.primaryIssue != rhs.primaryIssue {
-            return false
-        }
-        if lhs.canAutoFix != rhs.canAutoFix {
-            return false
-        }
-        if lhs.isFirstRun != rhs.isFirstRun {
+        if lhs.legacyLocksRemoved != rhs.legacyLocksRemoved {
             return false
         }
-        if lhs.binaryOk != rhs.binaryOk {
+        if lhs.orphanedProcessesKilled != rhs.orphanedProcessesKilled {
             return false
         }
-        if lhs.configOk != rhs.configOk {
+        if lhs.orphanedSessionsRemoved != rhs.orphanedSessionsRemoved {
             return false
         }
-        if lhs.firingOk != rhs.firingOk {
+        if lhs.sessionsRemoved != rhs.sessionsRemoved {
             return false
         }
-        if lhs.symlinkPath != rhs.symlinkPath {
+        if lhs.tombstonesRemoved != rhs.tombstonesRemoved {
             return false
         }
-        if lhs.symlinkTarget != rhs.symlinkTarget {
+        if lhs.activityEntriesRemoved != rhs.activityEntriesRemoved {
             return false
         }
-        if lhs.lastHeartbeatAgeSecs != rhs.lastHeartbeatAgeSecs {
+        if lhs.errors != rhs.errors {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(isHealthy)
-        hasher.combine(primaryIssue)
-        hasher.combine(canAutoFix)
-        hasher.combine(isFirstRun)
-        hasher.combine(binaryOk)
-        hasher.combine(configOk)
-        hasher.combine(firingOk)
-        hasher.combine(symlinkPath)
-        hasher.combine(symlinkTarget)
-        hasher.combine(lastHeartbeatAgeSecs)
+        hasher.combine(locksRemoved)
+        hasher.combine(legacyLocksRemoved)
+        hasher.combine(orphanedProcessesKilled)
+        hasher.combine(orphanedSessionsRemoved)
+        hasher.combine(sessionsRemoved)
+        hasher.combine(tombstonesRemoved)
+        hasher.combine(activityEntriesRemoved)
+        hasher.combine(errors)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHookDiagnosticReport: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookDiagnosticReport {
+public struct FfiConverterTypeCleanupStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CleanupStats {
         return
-            try HookDiagnosticReport(
-                isHealthy: FfiConverterBool.read(from: &buf),
-                primaryIssue: FfiConverterOptionTypeHookIssue.read(from: &buf),
-                canAutoFix: FfiConverterBool.read(from: &buf),
-                isFirstRun: FfiConverterBool.read(from: &buf),
-                binaryOk: FfiConverterBool.read(from: &buf),
-                configOk: FfiConverterBool.read(from: &buf),
-                firingOk: FfiConverterBool.read(from: &buf),
-                symlinkPath: FfiConverterString.read(from: &buf),
-                symlinkTarget: FfiConverterOptionString.read(from: &buf),
-                lastHeartbeatAgeSecs: FfiConverterOptionUInt64.read(from: &buf)
-            )
+            try CleanupStats(
+                locksRemoved: FfiConverterUInt32.read(from: &buf), 
+                legacyLocksRemoved: FfiConverterUInt32.read(from: &buf), 
+                orphanedProcessesKilled: FfiConverterUInt32.read(from: &buf), 
+                orphanedSessionsRemoved: FfiConverterUInt32.read(from: &buf), 
+                sessionsRemoved: FfiConverterUInt32.read(from: &buf), 
+                tombstonesRemoved: FfiConverterUInt32.read(from: &buf), 
+                activityEntriesRemoved: FfiConverterUInt32.read(from: &buf), 
+                errors: FfiConverterSequenceString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: HookDiagnosticReport, into buf: inout [UInt8]) {
-        FfiConverterBool.write(value.isHealthy, into: &buf)
-        FfiConverterOptionTypeHookIssue.write(value.primaryIssue, into: &buf)
-        FfiConverterBool.write(value.canAutoFix, into: &buf)
-        FfiConverterBool.write(value.isFirstRun, into: &buf)
-        FfiConverterBool.write(value.binaryOk, into: &buf)
-        FfiConverterBool.write(value.configOk, into: &buf)
-        FfiConverterBool.write(value.firingOk, into: &buf)
-        FfiConverterString.write(value.symlinkPath, into: &buf)
-        FfiConverterOptionString.write(value.symlinkTarget, into: &buf)
-        FfiConverterOptionUInt64.write(value.lastHeartbeatAgeSecs, into: &buf)
+    public static func write(_ value: CleanupStats, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.locksRemoved, into: &buf)
+        FfiConverterUInt32.write(value.legacyLocksRemoved, into: &buf)
+        FfiConverterUInt32.write(value.orphanedProcessesKilled, into: &buf)
+        FfiConverterUInt32.write(value.orphanedSessionsRemoved, into: &buf)
+        FfiConverterUInt32.write(value.sessionsRemoved, into: &buf)
+        FfiConverterUInt32.write(value.tombstonesRemoved, into: &buf)
+        FfiConverterUInt32.write(value.activityEntriesRemoved, into: &buf)
+        FfiConverterSequenceString.write(value.errors, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHookDiagnosticReport_lift(_ buf: RustBuffer) throws -> HookDiagnosticReport {
-    return try FfiConverterTypeHookDiagnosticReport.lift(buf)
+public func FfiConverterTypeCleanupStats_lift(_ buf: RustBuffer) throws -> CleanupStats {
+    return try FfiConverterTypeCleanupStats.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHookDiagnosticReport_lower(_ value: HookDiagnosticReport) -> RustBuffer {
-    return FfiConverterTypeHookDiagnosticReport.lower(value)
+public func FfiConverterTypeCleanupStats_lower(_ value: CleanupStats) -> RustBuffer {
+    return FfiConverterTypeCleanupStats.lower(value)
 }
 
+
 /**
- * Full health report for the hook binary.
+ * Result of comparing session record timestamps to the current time.
  */
-public struct HookHealthReport {
-    public var status: HookHealthStatus
-    public var heartbeatPath: String
-    public var thresholdSecs: UInt64
-    public var lastHeartbeatAgeSecs: UInt64?
+public struct ClockSkewReport {
+    /**
+     * `updated_at` of the newest record (RFC 3339), None if the store is empty
+     */
+    public var newestRecordAt: String?
+    /**
+     * Newest record minus now, in seconds (positive = record is in the future)
+     */
+    public var skewSecs: Int64
+    public var isSkewed: Bool
+    public var warning: String?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(status: HookHealthStatus, heartbeatPath: String, thresholdSecs: UInt64, lastHeartbeatAgeSecs: UInt64?) {
-        self.status = status
-        self.heartbeatPath = heartbeatPath
-        self.thresholdSecs = thresholdSecs
-        self.lastHeartbeatAgeSecs = lastHeartbeatAgeSecs
+    public init(
+        /**
+         * `updated_at` of the newest record (RFC 3339), None if the store is empty
+         */newestRecordAt: String?, 
+        /**
+         * Newest record minus now, in seconds (positive = record is in the future)
+         */skewSecs: Int64, isSkewed: Bool, warning: String?) {
+        self.newestRecordAt = newestRecordAt
+        self.skewSecs = skewSecs
+        self.isSkewed = isSkewed
+        self.warning = warning
     }
 }
 
-extension HookHealthReport: Equatable, Hashable {
-    public static func == (lhs: HookHealthReport, rhs: HookHealthReport) -> Bool {
-        if lhs.status != rhs.status {
+
+
+extension ClockSkewReport: Equatable, Hashable {
+    public static func ==(lhs: ClockSkewReport, rhs: ClockSkewReport) -> Bool {
+        if lhs.newestRecordAt != rhs.newestRecordAt {
             return false
         }
-        if lhs.heartbeatPath != rhs.heartbeatPath {
+        if lhs.skewSecs != rhs.skewSecs {
             return false
         }
-        if lhs.thresholdSecs != rhs.thresholdSecs {
+        if lhs.isSkewed != rhs.isSkewed {
             return false
         }
-        if lhs.lastHeartbeatAgeSecs != rhs.lastHeartbeatAgeSecs {
+        if lhs.warning != rhs.warning {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(status)
-        hasher.combine(heartbeatPath)
-        hasher.combine(thresholdSecs)
-        hasher.combine(lastHeartbeatAgeSecs)
+        hasher.combine(newestRecordAt)
+        hasher.combine(skewSecs)
+        hasher.combine(isSkewed)
+        hasher.combine(warning)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHookHealthReport: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookHealthReport {
+public struct FfiConverterTypeClockSkewReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ClockSkewReport {
         return
-            try HookHealthReport(
-                status: FfiConverterTypeHookHealthStatus.read(from: &buf),
-                heartbeatPath: FfiConverterString.read(from: &buf),
-                thresholdSecs: FfiConverterUInt64.read(from: &buf),
-                lastHeartbeatAgeSecs: FfiConverterOptionUInt64.read(from: &buf)
-            )
+            try ClockSkewReport(
+                newestRecordAt: FfiConverterOptionString.read(from: &buf), 
+                skewSecs: FfiConverterInt64.read(from: &buf), 
+                isSkewed: FfiConverterBool.read(from: &buf), 
+                warning: FfiConverterOptionString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: HookHealthReport, into buf: inout [UInt8]) {
-        FfiConverterTypeHookHealthStatus.write(value.status, into: &buf)
-        FfiConverterString.write(value.heartbeatPath, into: &buf)
-        FfiConverterUInt64.write(value.thresholdSecs, into: &buf)
-        FfiConverterOptionUInt64.write(value.lastHeartbeatAgeSecs, into: &buf)
+    public static func write(_ value: ClockSkewReport, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.newestRecordAt, into: &buf)
+        FfiConverterInt64.write(value.skewSecs, into: &buf)
+        FfiConverterBool.write(value.isSkewed, into: &buf)
+        FfiConverterOptionString.write(value.warning, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHookHealthReport_lift(_ buf: RustBuffer) throws -> HookHealthReport {
-    return try FfiConverterTypeHookHealthReport.lift(buf)
+public func FfiConverterTypeClockSkewReport_lift(_ buf: RustBuffer) throws -> ClockSkewReport {
+    return try FfiConverterTypeClockSkewReport.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHookHealthReport_lower(_ value: HookHealthReport) -> RustBuffer {
-    return FfiConverterTypeHookHealthReport.lower(value)
+public func FfiConverterTypeClockSkewReport_lower(_ value: ClockSkewReport) -> RustBuffer {
+    return FfiConverterTypeClockSkewReport.lower(value)
 }
 
+
 /**
- * Result of running a comprehensive hook system test.
- *
- * This verifies both the heartbeat (hooks are firing) and state file I/O
- * (persistence layer is working). Used by the "Test Hooks" button in the UI.
+ * A session compacting its context right now, which the user shouldn't interrupt.
  */
-public struct HookTestResult {
-    /**
-     * True if all tests passed
-     */
-    public var success: Bool
-    /**
-     * True if heartbeat file is recent (hooks are firing)
-     */
-    public var heartbeatOk: Bool
-    /**
-     * Age of the heartbeat file in seconds (None if file doesn't exist)
-     */
-    public var heartbeatAgeSecs: UInt64?
+public struct CompactingSession {
     /**
-     * True if state file I/O test passed
+     * `project_dir` when the hook recorded one, otherwise the session's cwd
      */
-    public var stateFileOk: Bool
+    public var projectPath: String
+    public var sessionId: String
     /**
-     * Human-readable summary message for display
+     * Seconds since the session entered Compacting
      */
-    public var message: String
+    public var timeInStateSecs: UInt64
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * True if all tests passed
-         */ success: Bool,
-        /**
-            * True if heartbeat file is recent (hooks are firing)
-            */ heartbeatOk: Bool,
+         * `project_dir` when the hook recorded one, otherwise the session's cwd
+         */projectPath: String, sessionId: String, 
         /**
-            * Age of the heartbeat file in seconds (None if file doesn't exist)
-            */ heartbeatAgeSecs: UInt64?,
-        /**
-            * True if state file I/O test passed
-            */ stateFileOk: Bool,
-        /**
-            * Human-readable summary message for display
-            */ message: String
-    ) {
-        self.success = success
-        self.heartbeatOk = heartbeatOk
-        self.heartbeatAgeSecs = heartbeatAgeSecs
-        self.stateFileOk = stateFileOk
-        self.message = message
+         * Seconds since the session entered Compacting
+         */timeInStateSecs: UInt64) {
+        self.projectPath = projectPath
+        self.sessionId = sessionId
+        self.timeInStateSecs = timeInStateSecs
     }
 }
 
-extension HookTestResult: Equatable, Hashable {
-    public static func == (lhs: HookTestResult, rhs: HookTestResult) -> Bool {
-        if lhs.success != rhs.success {
-            return false
-        }
-        if lhs.heartbeatOk != rhs.heartbeatOk {
-            return false
-        }
-        if lhs.heartbeatAgeSecs != rhs.heartbeatAgeSecs {
+
+
+extension CompactingSession: Equatable, Hashable {
+    public static func ==(lhs: CompactingSession, rhs: CompactingSession) -> Bool {
+        if lhs.projectPath != rhs.projectPath {
             return false
         }
-        if lhs.stateFileOk != rhs.stateFileOk {
+        if lhs.sessionId != rhs.sessionId {
             return false
         }
-        if lhs.message != rhs.message {
+        if lhs.timeInStateSecs != rhs.timeInStateSecs {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(success)
-        hasher.combine(heartbeatOk)
-        hasher.combine(heartbeatAgeSecs)
-        hasher.combine(stateFileOk)
-        hasher.combine(message)
+        hasher.combine(projectPath)
+        hasher.combine(sessionId)
+        hasher.combine(timeInStateSecs)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHookTestResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookTestResult {
+public struct FfiConverterTypeCompactingSession: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CompactingSession {
         return
-            try HookTestResult(
-                success: FfiConverterBool.read(from: &buf),
-                heartbeatOk: FfiConverterBool.read(from: &buf),
-                heartbeatAgeSecs: FfiConverterOptionUInt64.read(from: &buf),
-                stateFileOk: FfiConverterBool.read(from: &buf),
-                message: FfiConverterString.read(from: &buf)
-            )
+            try CompactingSession(
+                projectPath: FfiConverterString.read(from: &buf), 
+                sessionId: FfiConverterString.read(from: &buf), 
+                timeInStateSecs: FfiConverterUInt64.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: HookTestResult, into buf: inout [UInt8]) {
-        FfiConverterBool.write(value.success, into: &buf)
-        FfiConverterBool.write(value.heartbeatOk, into: &buf)
-        FfiConverterOptionUInt64.write(value.heartbeatAgeSecs, into: &buf)
-        FfiConverterBool.write(value.stateFileOk, into: &buf)
-        FfiConverterString.write(value.message, into: &buf)
+    public static func write(_ value: CompactingSession, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.projectPath, into: &buf)
+        FfiConverterString.write(value.sessionId, into: &buf)
+        FfiConverterUInt64.write(value.timeInStateSecs, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHookTestResult_lift(_ buf: RustBuffer) throws -> HookTestResult {
-    return try FfiConverterTypeHookTestResult.lift(buf)
+public func FfiConverterTypeCompactingSession_lift(_ buf: RustBuffer) throws -> CompactingSession {
+    return try FfiConverterTypeCompactingSession.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHookTestResult_lower(_ value: HookTestResult) -> RustBuffer {
-    return FfiConverterTypeHookTestResult.lower(value)
+public func FfiConverterTypeCompactingSession_lower(_ value: CompactingSession) -> RustBuffer {
+    return FfiConverterTypeCompactingSession.lower(value)
 }
 
+
 /**
- * HUD configuration (pinned projects, etc.)
+ * Estimated rate at which a session is filling its context window.
  */
-public struct HudConfig {
-    public var pinnedProjects: [String]
-    public var terminalApp: String
+public struct ContextBurnRate {
+    public var tokensPerMinute: Double
+    /**
+     * Context tokens in use as of the latest usage block
+     */
+    public var tokensUsed: UInt64
+    public var contextSize: UInt64
+    /**
+     * Minutes until the context window fills, None when usage isn't growing
+     */
+    public var minutesRemaining: Double?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(pinnedProjects: [String], terminalApp: String) {
-        self.pinnedProjects = pinnedProjects
-        self.terminalApp = terminalApp
+    public init(tokensPerMinute: Double, 
+        /**
+         * Context tokens in use as of the latest usage block
+         */tokensUsed: UInt64, contextSize: UInt64, 
+        /**
+         * Minutes until the context window fills, None when usage isn't growing
+         */minutesRemaining: Double?) {
+        self.tokensPerMinute = tokensPerMinute
+        self.tokensUsed = tokensUsed
+        self.contextSize = contextSize
+        self.minutesRemaining = minutesRemaining
     }
 }
 
-extension HudConfig: Equatable, Hashable {
-    public static func == (lhs: HudConfig, rhs: HudConfig) -> Bool {
-        if lhs.pinnedProjects != rhs.pinnedProjects {
+
+
+extension ContextBurnRate: Equatable, Hashable {
+    public static func ==(lhs: ContextBurnRate, rhs: ContextBurnRate) -> Bool {
+        if lhs.tokensPerMinute != rhs.tokensPerMinute {
             return false
         }
-        if lhs.terminalApp != rhs.terminalApp {
+        if lhs.tokensUsed != rhs.tokensUsed {
+            return false
+        }
+        if lhs.contextSize != rhs.contextSize {
+            return false
+        }
+        if lhs.minutesRemaining != rhs.minutesRemaining {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(pinnedProjects)
-        hasher.combine(terminalApp)
+        hasher.combine(tokensPerMinute)
+        hasher.combine(tokensUsed)
+        hasher.combine(contextSize)
+        hasher.combine(minutesRemaining)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHudConfig: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HudConfig {
+public struct FfiConverterTypeContextBurnRate: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ContextBurnRate {
         return
-            try HudConfig(
-                pinnedProjects: FfiConverterSequenceString.read(from: &buf),
-                terminalApp: FfiConverterString.read(from: &buf)
-            )
+            try ContextBurnRate(
+                tokensPerMinute: FfiConverterDouble.read(from: &buf), 
+                tokensUsed: FfiConverterUInt64.read(from: &buf), 
+                contextSize: FfiConverterUInt64.read(from: &buf), 
+                minutesRemaining: FfiConverterOptionDouble.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: HudConfig, into buf: inout [UInt8]) {
-        FfiConverterSequenceString.write(value.pinnedProjects, into: &buf)
-        FfiConverterString.write(value.terminalApp, into: &buf)
+    public static func write(_ value: ContextBurnRate, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.tokensPerMinute, into: &buf)
+        FfiConverterUInt64.write(value.tokensUsed, into: &buf)
+        FfiConverterUInt64.write(value.contextSize, into: &buf)
+        FfiConverterOptionDouble.write(value.minutesRemaining, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHudConfig_lift(_ buf: RustBuffer) throws -> HudConfig {
-    return try FfiConverterTypeHudConfig.lift(buf)
+public func FfiConverterTypeContextBurnRate_lift(_ buf: RustBuffer) throws -> ContextBurnRate {
+    return try FfiConverterTypeContextBurnRate.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHudConfig_lower(_ value: HudConfig) -> RustBuffer {
-    return FfiConverterTypeHudConfig.lower(value)
+public func FfiConverterTypeContextBurnRate_lower(_ value: ContextBurnRate) -> RustBuffer {
+    return FfiConverterTypeContextBurnRate.lower(value)
 }
 
+
 /**
- * A captured idea stored in `~/.capacitor/projects/{encoded}/ideas.md`.
- *
- * Ideas are stored in markdown format with ULID identifiers for stable references.
- * They can be in various states (open, in-progress, done) and have triage status.
+ * Context window usage information.
  */
-public struct Idea {
-    /**
-     * ULID identifier (26 chars, uppercase, sortable)
-     */
-    public var id: String
-    /**
-     * Short title extracted from first line
-     */
-    public var title: String
-    /**
-     * Full description text
-     */
-    public var description: String
-    /**
-     * ISO8601 timestamp when added
-     */
-    public var added: String
-    /**
-     * Effort estimate: unknown, small, medium, large, xl
-     */
-    public var effort: String
-    /**
-     * Status: open, in-progress, done
-     */
-    public var status: String
-    /**
-     * Triage status: pending, validated
-     */
-    public var triage: String
-    /**
-     * Related project name (if associated with a specific project)
-     */
-    public var related: String?
+public struct ContextInfo {
+    public var percentUsed: UInt32
+    public var tokensUsed: UInt64
+    public var contextSize: UInt64
+    public var updatedAt: String?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(
-        /**
-         * ULID identifier (26 chars, uppercase, sortable)
-         */ id: String,
-        /**
-            * Short title extracted from first line
-            */ title: String,
-        /**
-            * Full description text
-            */ description: String,
-        /**
-            * ISO8601 timestamp when added
-            */ added: String,
-        /**
-            * Effort estimate: unknown, small, medium, large, xl
-            */ effort: String,
-        /**
-            * Status: open, in-progress, done
-            */ status: String,
-        /**
-            * Triage status: pending, validated
-            */ triage: String,
-        /**
-            * Related project name (if associated with a specific project)
-            */ related: String?
-    ) {
-        self.id = id
-        self.title = title
-        self.description = description
-        self.added = added
-        self.effort = effort
-        self.status = status
-        self.triage = triage
-        self.related = related
+    public init(percentUsed: UInt32, tokensUsed: UInt64, contextSize: UInt64, updatedAt: String?) {
+        self.percentUsed = percentUsed
+        self.tokensUsed = tokensUsed
+        self.contextSize = contextSize
+        self.updatedAt = updatedAt
     }
 }
 
-extension Idea: Equatable, Hashable {
-    public static func == (lhs: Idea, rhs: Idea) -> Bool {
-        if lhs.id != rhs.id {
-            return false
-        }
-        if lhs.title != rhs.title {
-            return false
-        }
-        if lhs.description != rhs.description {
-            return false
-        }
-        if lhs.added != rhs.added {
-            return false
-        }
-        if lhs.effort != rhs.effort {
+
+
+extension ContextInfo: Equatable, Hashable {
+    public static func ==(lhs: ContextInfo, rhs: ContextInfo) -> Bool {
+        if lhs.percentUsed != rhs.percentUsed {
             return false
         }
-        if lhs.status != rhs.status {
+        if lhs.tokensUsed != rhs.tokensUsed {
             return false
         }
-        if lhs.triage != rhs.triage {
+        if lhs.contextSize != rhs.contextSize {
             return false
         }
-        if lhs.related != rhs.related {
+        if lhs.updatedAt != rhs.updatedAt {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(id)
-        hasher.combine(title)
-        hasher.combine(description)
-        hasher.combine(added)
-        hasher.combine(effort)
-        hasher.combine(status)
-        hasher.combine(triage)
-        hasher.combine(related)
+        hasher.combine(percentUsed)
+        hasher.combine(tokensUsed)
+        hasher.combine(contextSize)
+        hasher.combine(updatedAt)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeIdea: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Idea {
+public struct FfiConverterTypeContextInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ContextInfo {
         return
-            try Idea(
-                id: FfiConverterString.read(from: &buf),
-                title: FfiConverterString.read(from: &buf),
-                description: FfiConverterString.read(from: &buf),
-                added: FfiConverterString.read(from: &buf),
-                effort: FfiConverterString.read(from: &buf),
-                status: FfiConverterString.read(from: &buf),
-                triage: FfiConverterString.read(from: &buf),
-                related: FfiConverterOptionString.read(from: &buf)
-            )
+            try ContextInfo(
+                percentUsed: FfiConverterUInt32.read(from: &buf), 
+                tokensUsed: FfiConverterUInt64.read(from: &buf), 
+                contextSize: FfiConverterUInt64.read(from: &buf), 
+                updatedAt: FfiConverterOptionString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: Idea, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.id, into: &buf)
-        FfiConverterString.write(value.title, into: &buf)
-        FfiConverterString.write(value.description, into: &buf)
-        FfiConverterString.write(value.added, into: &buf)
-        FfiConverterString.write(value.effort, into: &buf)
-        FfiConverterString.write(value.status, into: &buf)
-        FfiConverterString.write(value.triage, into: &buf)
-        FfiConverterOptionString.write(value.related, into: &buf)
+    public static func write(_ value: ContextInfo, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.percentUsed, into: &buf)
+        FfiConverterUInt64.write(value.tokensUsed, into: &buf)
+        FfiConverterUInt64.write(value.contextSize, into: &buf)
+        FfiConverterOptionString.write(value.updatedAt, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeIdea_lift(_ buf: RustBuffer) throws -> Idea {
-    return try FfiConverterTypeIdea.lift(buf)
+public func FfiConverterTypeContextInfo_lift(_ buf: RustBuffer) throws -> ContextInfo {
+    return try FfiConverterTypeContextInfo.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeIdea_lower(_ value: Idea) -> RustBuffer {
-    return FfiConverterTypeIdea.lower(value)
+public func FfiConverterTypeContextInfo_lower(_ value: ContextInfo) -> RustBuffer {
+    return FfiConverterTypeContextInfo.lower(value)
 }
 
-public struct InstallResult {
+
+/**
+ * Result of starting a project creation.
+ */
+public struct CreateProjectResult {
     public var success: Bool
-    public var message: String
-    public var scriptPath: String?
+    public var projectPath: String
+    public var sessionId: String?
+    public var error: String?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(success: Bool, message: String, scriptPath: String?) {
+    public init(success: Bool, projectPath: String, sessionId: String?, error: String?) {
         self.success = success
-        self.message = message
-        self.scriptPath = scriptPath
+        self.projectPath = projectPath
+        self.sessionId = sessionId
+        self.error = error
     }
 }
 
-extension InstallResult: Equatable, Hashable {
-    public static func == (lhs: InstallResult, rhs: InstallResult) -> Bool {
+
+
+extension CreateProjectResult: Equatable, Hashable {
+    public static func ==(lhs: CreateProjectResult, rhs: CreateProjectResult) -> Bool {
         if lhs.success != rhs.success {
             return false
         }
-        if lhs.message != rhs.message {
+        if lhs.projectPath != rhs.projectPath {
             return false
         }
-        if lhs.scriptPath != rhs.scriptPath {
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        if lhs.error != rhs.error {
             return false
         }
         return true
@@ -3243,274 +5147,344 @@ extension InstallResult: Equatable, Hashable {
 
     public func hash(into hasher: inout Hasher) {
         hasher.combine(success)
-        hasher.combine(message)
-        hasher.combine(scriptPath)
+        hasher.combine(projectPath)
+        hasher.combine(sessionId)
+        hasher.combine(error)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeInstallResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InstallResult {
+public struct FfiConverterTypeCreateProjectResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CreateProjectResult {
         return
-            try InstallResult(
-                success: FfiConverterBool.read(from: &buf),
-                message: FfiConverterString.read(from: &buf),
-                scriptPath: FfiConverterOptionString.read(from: &buf)
-            )
+            try CreateProjectResult(
+                success: FfiConverterBool.read(from: &buf), 
+                projectPath: FfiConverterString.read(from: &buf), 
+                sessionId: FfiConverterOptionString.read(from: &buf), 
+                error: FfiConverterOptionString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: InstallResult, into buf: inout [UInt8]) {
+    public static func write(_ value: CreateProjectResult, into buf: inout [UInt8]) {
         FfiConverterBool.write(value.success, into: &buf)
-        FfiConverterString.write(value.message, into: &buf)
-        FfiConverterOptionString.write(value.scriptPath, into: &buf)
+        FfiConverterString.write(value.projectPath, into: &buf)
+        FfiConverterOptionString.write(value.sessionId, into: &buf)
+        FfiConverterOptionString.write(value.error, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeInstallResult_lift(_ buf: RustBuffer) throws -> InstallResult {
-    return try FfiConverterTypeInstallResult.lift(buf)
+public func FfiConverterTypeCreateProjectResult_lift(_ buf: RustBuffer) throws -> CreateProjectResult {
+    return try FfiConverterTypeCreateProjectResult.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeInstallResult_lower(_ value: InstallResult) -> RustBuffer {
-    return FfiConverterTypeInstallResult.lower(value)
+public func FfiConverterTypeCreateProjectResult_lower(_ value: CreateProjectResult) -> RustBuffer {
+    return FfiConverterTypeCreateProjectResult.lower(value)
 }
 
+
 /**
- * Request to create a new project from an idea.
+ * Progress information for a project creation.
  */
-public struct NewProjectRequest {
-    public var name: String
-    public var description: String
-    public var location: String
-    public var language: String?
-    public var framework: String?
+public struct CreationProgress {
+    public var phase: String
+    public var message: String
+    public var percentComplete: UInt8?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(name: String, description: String, location: String, language: String?, framework: String?) {
-        self.name = name
-        self.description = description
-        self.location = location
-        self.language = language
-        self.framework = framework
+    public init(phase: String, message: String, percentComplete: UInt8?) {
+        self.phase = phase
+        self.message = message
+        self.percentComplete = percentComplete
     }
 }
 
-extension NewProjectRequest: Equatable, Hashable {
-    public static func == (lhs: NewProjectRequest, rhs: NewProjectRequest) -> Bool {
-        if lhs.name != rhs.name {
-            return false
-        }
-        if lhs.description != rhs.description {
-            return false
-        }
-        if lhs.location != rhs.location {
+
+
+extension CreationProgress: Equatable, Hashable {
+    public static func ==(lhs: CreationProgress, rhs: CreationProgress) -> Bool {
+        if lhs.phase != rhs.phase {
             return false
         }
-        if lhs.language != rhs.language {
+        if lhs.message != rhs.message {
             return false
         }
-        if lhs.framework != rhs.framework {
+        if lhs.percentComplete != rhs.percentComplete {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(name)
-        hasher.combine(description)
-        hasher.combine(location)
-        hasher.combine(language)
-        hasher.combine(framework)
+        hasher.combine(phase)
+        hasher.combine(message)
+        hasher.combine(percentComplete)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeNewProjectRequest: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NewProjectRequest {
+public struct FfiConverterTypeCreationProgress: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CreationProgress {
         return
-            try NewProjectRequest(
-                name: FfiConverterString.read(from: &buf),
-                description: FfiConverterString.read(from: &buf),
-                location: FfiConverterString.read(from: &buf),
-                language: FfiConverterOptionString.read(from: &buf),
-                framework: FfiConverterOptionString.read(from: &buf)
-            )
+            try CreationProgress(
+                phase: FfiConverterString.read(from: &buf), 
+                message: FfiConverterString.read(from: &buf), 
+                percentComplete: FfiConverterOptionUInt8.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: NewProjectRequest, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterString.write(value.description, into: &buf)
-        FfiConverterString.write(value.location, into: &buf)
-        FfiConverterOptionString.write(value.language, into: &buf)
-        FfiConverterOptionString.write(value.framework, into: &buf)
+    public static func write(_ value: CreationProgress, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.phase, into: &buf)
+        FfiConverterString.write(value.message, into: &buf)
+        FfiConverterOptionUInt8.write(value.percentComplete, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNewProjectRequest_lift(_ buf: RustBuffer) throws -> NewProjectRequest {
-    return try FfiConverterTypeNewProjectRequest.lift(buf)
+public func FfiConverterTypeCreationProgress_lift(_ buf: RustBuffer) throws -> CreationProgress {
+    return try FfiConverterTypeCreationProgress.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNewProjectRequest_lower(_ value: NewProjectRequest) -> RustBuffer {
-    return FfiConverterTypeNewProjectRequest.lower(value)
+public func FfiConverterTypeCreationProgress_lower(_ value: CreationProgress) -> RustBuffer {
+    return FfiConverterTypeCreationProgress.lower(value)
 }
 
+
 /**
- * An installed Claude Code plugin.
+ * Aggregate data for the dashboard view.
  */
-public struct Plugin {
-    public var id: String
-    public var name: String
-    public var description: String
-    public var enabled: Bool
-    public var path: String
-    public var skillCount: UInt32
-    public var commandCount: UInt32
-    public var agentCount: UInt32
-    public var hookCount: UInt32
+public struct DashboardData {
+    public var global: GlobalConfig
+    public var plugins: [Plugin]
+    public var projects: [Project]
+    /**
+     * Non-fatal load failures (e.g. corrupt plugin registry) for the UI to surface
+     */
+    public var warnings: [String]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(id: String, name: String, description: String, enabled: Bool, path: String, skillCount: UInt32, commandCount: UInt32, agentCount: UInt32, hookCount: UInt32) {
-        self.id = id
-        self.name = name
-        self.description = description
-        self.enabled = enabled
-        self.path = path
-        self.skillCount = skillCount
-        self.commandCount = commandCount
-        self.agentCount = agentCount
-        self.hookCount = hookCount
+    public init(global: GlobalConfig, plugins: [Plugin], projects: [Project], 
+        /**
+         * Non-fatal load failures (e.g. corrupt plugin registry) for the UI to surface
+         */warnings: [String]) {
+        self.global = global
+        self.plugins = plugins
+        self.projects = projects
+        self.warnings = warnings
     }
 }
 
-extension Plugin: Equatable, Hashable {
-    public static func == (lhs: Plugin, rhs: Plugin) -> Bool {
-        if lhs.id != rhs.id {
+
+
+extension DashboardData: Equatable, Hashable {
+    public static func ==(lhs: DashboardData, rhs: DashboardData) -> Bool {
+        if lhs.global != rhs.global {
             return false
         }
-        if lhs.name != rhs.name {
+        if lhs.plugins != rhs.plugins {
             return false
         }
-        if lhs.description != rhs.description {
+        if lhs.projects != rhs.projects {
             return false
         }
-        if lhs.enabled != rhs.enabled {
+        if lhs.warnings != rhs.warnings {
             return false
         }
-        if lhs.path != rhs.path {
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(global)
+        hasher.combine(plugins)
+        hasher.combine(projects)
+        hasher.combine(warnings)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDashboardData: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DashboardData {
+        return
+            try DashboardData(
+                global: FfiConverterTypeGlobalConfig.read(from: &buf), 
+                plugins: FfiConverterSequenceTypePlugin.read(from: &buf), 
+                projects: FfiConverterSequenceTypeProject.read(from: &buf), 
+                warnings: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: DashboardData, into buf: inout [UInt8]) {
+        FfiConverterTypeGlobalConfig.write(value.global, into: &buf)
+        FfiConverterSequenceTypePlugin.write(value.plugins, into: &buf)
+        FfiConverterSequenceTypeProject.write(value.projects, into: &buf)
+        FfiConverterSequenceString.write(value.warnings, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDashboardData_lift(_ buf: RustBuffer) throws -> DashboardData {
+    return try FfiConverterTypeDashboardData.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDashboardData_lower(_ value: DashboardData) -> RustBuffer {
+    return FfiConverterTypeDashboardData.lower(value)
+}
+
+
+public struct DependencyStatus {
+    public var name: String
+    public var required: Bool
+    public var found: Bool
+    public var path: String?
+    public var installHint: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, required: Bool, found: Bool, path: String?, installHint: String?) {
+        self.name = name
+        self.required = required
+        self.found = found
+        self.path = path
+        self.installHint = installHint
+    }
+}
+
+
+
+extension DependencyStatus: Equatable, Hashable {
+    public static func ==(lhs: DependencyStatus, rhs: DependencyStatus) -> Bool {
+        if lhs.name != rhs.name {
             return false
         }
-        if lhs.skillCount != rhs.skillCount {
+        if lhs.required != rhs.required {
             return false
         }
-        if lhs.commandCount != rhs.commandCount {
+        if lhs.found != rhs.found {
             return false
         }
-        if lhs.agentCount != rhs.agentCount {
+        if lhs.path != rhs.path {
             return false
         }
-        if lhs.hookCount != rhs.hookCount {
+        if lhs.installHint != rhs.installHint {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(id)
         hasher.combine(name)
-        hasher.combine(description)
-        hasher.combine(enabled)
+        hasher.combine(required)
+        hasher.combine(found)
         hasher.combine(path)
-        hasher.combine(skillCount)
-        hasher.combine(commandCount)
-        hasher.combine(agentCount)
-        hasher.combine(hookCount)
+        hasher.combine(installHint)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypePlugin: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Plugin {
+public struct FfiConverterTypeDependencyStatus: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DependencyStatus {
         return
-            try Plugin(
-                id: FfiConverterString.read(from: &buf),
-                name: FfiConverterString.read(from: &buf),
-                description: FfiConverterString.read(from: &buf),
-                enabled: FfiConverterBool.read(from: &buf),
-                path: FfiConverterString.read(from: &buf),
-                skillCount: FfiConverterUInt32.read(from: &buf),
-                commandCount: FfiConverterUInt32.read(from: &buf),
-                agentCount: FfiConverterUInt32.read(from: &buf),
-                hookCount: FfiConverterUInt32.read(from: &buf)
-            )
+            try DependencyStatus(
+                name: FfiConverterString.read(from: &buf), 
+                required: FfiConverterBool.read(from: &buf), 
+                found: FfiConverterBool.read(from: &buf), 
+                path: FfiConverterOptionString.read(from: &buf), 
+                installHint: FfiConverterOptionString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: Plugin, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.id, into: &buf)
+    public static func write(_ value: DependencyStatus, into buf: inout [UInt8]) {
         FfiConverterString.write(value.name, into: &buf)
-        FfiConverterString.write(value.description, into: &buf)
-        FfiConverterBool.write(value.enabled, into: &buf)
-        FfiConverterString.write(value.path, into: &buf)
-        FfiConverterUInt32.write(value.skillCount, into: &buf)
-        FfiConverterUInt32.write(value.commandCount, into: &buf)
-        FfiConverterUInt32.write(value.agentCount, into: &buf)
-        FfiConverterUInt32.write(value.hookCount, into: &buf)
+        FfiConverterBool.write(value.required, into: &buf)
+        FfiConverterBool.write(value.found, into: &buf)
+        FfiConverterOptionString.write(value.path, into: &buf)
+        FfiConverterOptionString.write(value.installHint, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypePlugin_lift(_ buf: RustBuffer) throws -> Plugin {
-    return try FfiConverterTypePlugin.lift(buf)
+public func FfiConverterTypeDependencyStatus_lift(_ buf: RustBuffer) throws -> DependencyStatus {
+    return try FfiConverterTypeDependencyStatus.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypePlugin_lower(_ value: Plugin) -> RustBuffer {
-    return FfiConverterTypePlugin.lower(value)
+public func FfiConverterTypeDependencyStatus_lower(_ value: DependencyStatus) -> RustBuffer {
+    return FfiConverterTypeDependencyStatus.lower(value)
 }
 
+
 /**
- * Plugin manifest from plugin.json.
+ * An installed terminal app.
  */
-public struct PluginManifest {
+public struct DetectedTerminal {
+    /**
+     * Display name from `KNOWN_TERMINALS`
+     */
     public var name: String
-    public var description: String?
+    /**
+     * Location of the app bundle (macOS) or executable (Linux)
+     */
+    public var path: String
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(name: String, description: String?) {
+    public init(
+        /**
+         * Display name from `KNOWN_TERMINALS`
+         */name: String, 
+        /**
+         * Location of the app bundle (macOS) or executable (Linux)
+         */path: String) {
         self.name = name
-        self.description = description
+        self.path = path
     }
 }
 
-extension PluginManifest: Equatable, Hashable {
-    public static func == (lhs: PluginManifest, rhs: PluginManifest) -> Bool {
+
+
+extension DetectedTerminal: Equatable, Hashable {
+    public static func ==(lhs: DetectedTerminal, rhs: DetectedTerminal) -> Bool {
         if lhs.name != rhs.name {
             return false
         }
-        if lhs.description != rhs.description {
+        if lhs.path != rhs.path {
             return false
         }
         return true
@@ -3518,3057 +5492,11288 @@ extension PluginManifest: Equatable, Hashable {
 
     public func hash(into hasher: inout Hasher) {
         hasher.combine(name)
-        hasher.combine(description)
+        hasher.combine(path)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypePluginManifest: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PluginManifest {
+public struct FfiConverterTypeDetectedTerminal: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DetectedTerminal {
         return
-            try PluginManifest(
-                name: FfiConverterString.read(from: &buf),
-                description: FfiConverterOptionString.read(from: &buf)
-            )
+            try DetectedTerminal(
+                name: FfiConverterString.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: PluginManifest, into buf: inout [UInt8]) {
+    public static func write(_ value: DetectedTerminal, into buf: inout [UInt8]) {
         FfiConverterString.write(value.name, into: &buf)
-        FfiConverterOptionString.write(value.description, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypePluginManifest_lift(_ buf: RustBuffer) throws -> PluginManifest {
-    return try FfiConverterTypePluginManifest.lift(buf)
+public func FfiConverterTypeDetectedTerminal_lift(_ buf: RustBuffer) throws -> DetectedTerminal {
+    return try FfiConverterTypeDetectedTerminal.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypePluginManifest_lower(_ value: PluginManifest) -> RustBuffer {
-    return FfiConverterTypePluginManifest.lower(value)
+public func FfiConverterTypeDetectedTerminal_lower(_ value: DetectedTerminal) -> RustBuffer {
+    return FfiConverterTypeDetectedTerminal.lower(value)
 }
 
+
 /**
- * A pinned project in the HUD.
+ * How project-like a directory looks.
  */
-public struct Project {
-    public var name: String
-    public var path: String
-    public var displayPath: String
-    public var lastActive: String?
-    public var claudeMdPath: String?
-    public var claudeMdPreview: String?
-    public var hasLocalSettings: Bool
-    public var taskCount: UInt32
-    public var stats: ProjectStats?
+public struct DirectoryClassification {
     /**
-     * True if the project directory no longer exists on disk.
+     * 0–100; higher means more confidently a project
      */
-    public var isMissing: Bool
+    public var score: UInt32
+    /**
+     * Signals found, strongest first (file names, or "recent activity")
+     */
+    public var indicators: [String]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(name: String, path: String, displayPath: String, lastActive: String?, claudeMdPath: String?, claudeMdPreview: String?, hasLocalSettings: Bool, taskCount: UInt32, stats: ProjectStats?,
-                /**
-                    * True if the project directory no longer exists on disk.
-                    */ isMissing: Bool)
-    {
-        self.name = name
-        self.path = path
-        self.displayPath = displayPath
-        self.lastActive = lastActive
-        self.claudeMdPath = claudeMdPath
-        self.claudeMdPreview = claudeMdPreview
-        self.hasLocalSettings = hasLocalSettings
-        self.taskCount = taskCount
-        self.stats = stats
-        self.isMissing = isMissing
+    public init(
+        /**
+         * 0–100; higher means more confidently a project
+         */score: UInt32, 
+        /**
+         * Signals found, strongest first (file names, or "recent activity")
+         */indicators: [String]) {
+        self.score = score
+        self.indicators = indicators
     }
 }
 
-extension Project: Equatable, Hashable {
-    public static func == (lhs: Project, rhs: Project) -> Bool {
-        if lhs.name != rhs.name {
-            return false
-        }
-        if lhs.path != rhs.path {
-            return false
-        }
-        if lhs.displayPath != rhs.displayPath {
-            return false
-        }
-        if lhs.lastActive != rhs.lastActive {
-            return false
-        }
-        if lhs.claudeMdPath != rhs.claudeMdPath {
-            return false
-        }
-        if lhs.claudeMdPreview != rhs.claudeMdPreview {
-            return false
-        }
-        if lhs.hasLocalSettings != rhs.hasLocalSettings {
-            return false
-        }
-        if lhs.taskCount != rhs.taskCount {
-            return false
-        }
-        if lhs.stats != rhs.stats {
+
+
+extension DirectoryClassification: Equatable, Hashable {
+    public static func ==(lhs: DirectoryClassification, rhs: DirectoryClassification) -> Bool {
+        if lhs.score != rhs.score {
             return false
         }
-        if lhs.isMissing != rhs.isMissing {
+        if lhs.indicators != rhs.indicators {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(name)
-        hasher.combine(path)
-        hasher.combine(displayPath)
-        hasher.combine(lastActive)
-        hasher.combine(claudeMdPath)
-        hasher.combine(claudeMdPreview)
-        hasher.combine(hasLocalSettings)
-        hasher.combine(taskCount)
-        hasher.combine(stats)
-        hasher.combine(isMissing)
+        hasher.combine(score)
+        hasher.combine(indicators)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeProject: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Project {
+public struct FfiConverterTypeDirectoryClassification: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DirectoryClassification {
         return
-            try Project(
-                name: FfiConverterString.read(from: &buf),
-                path: FfiConverterString.read(from: &buf),
-                displayPath: FfiConverterString.read(from: &buf),
-                lastActive: FfiConverterOptionString.read(from: &buf),
-                claudeMdPath: FfiConverterOptionString.read(from: &buf),
-                claudeMdPreview: FfiConverterOptionString.read(from: &buf),
-                hasLocalSettings: FfiConverterBool.read(from: &buf),
-                taskCount: FfiConverterUInt32.read(from: &buf),
-                stats: FfiConverterOptionTypeProjectStats.read(from: &buf),
-                isMissing: FfiConverterBool.read(from: &buf)
-            )
+            try DirectoryClassification(
+                score: FfiConverterUInt32.read(from: &buf), 
+                indicators: FfiConverterSequenceString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: Project, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterString.write(value.path, into: &buf)
-        FfiConverterString.write(value.displayPath, into: &buf)
-        FfiConverterOptionString.write(value.lastActive, into: &buf)
-        FfiConverterOptionString.write(value.claudeMdPath, into: &buf)
-        FfiConverterOptionString.write(value.claudeMdPreview, into: &buf)
-        FfiConverterBool.write(value.hasLocalSettings, into: &buf)
-        FfiConverterUInt32.write(value.taskCount, into: &buf)
-        FfiConverterOptionTypeProjectStats.write(value.stats, into: &buf)
-        FfiConverterBool.write(value.isMissing, into: &buf)
+    public static func write(_ value: DirectoryClassification, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.score, into: &buf)
+        FfiConverterSequenceString.write(value.indicators, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProject_lift(_ buf: RustBuffer) throws -> Project {
-    return try FfiConverterTypeProject.lift(buf)
+public func FfiConverterTypeDirectoryClassification_lift(_ buf: RustBuffer) throws -> DirectoryClassification {
+    return try FfiConverterTypeDirectoryClassification.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProject_lower(_ value: Project) -> RustBuffer {
-    return FfiConverterTypeProject.lower(value)
+public func FfiConverterTypeDirectoryClassification_lower(_ value: DirectoryClassification) -> RustBuffer {
+    return FfiConverterTypeDirectoryClassification.lower(value)
 }
 
+
 /**
- * A project being created via the Idea → V1 flow.
+ * A command line that opens the project and files in an IDE.
  */
-public struct ProjectCreation {
-    public var id: String
-    public var name: String
-    public var path: String
-    public var description: String
-    public var status: CreationStatus
-    public var sessionId: String?
-    public var progress: CreationProgress?
-    public var error: String?
-    public var createdAt: String
-    public var completedAt: String?
+public struct EditorLaunch {
+    /**
+     * IDE command-line launcher (e.g. `cursor`, `code`)
+     */
+    public var command: String
+    /**
+     * Project folder first, then the files to open
+     */
+    public var args: [String]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(id: String, name: String, path: String, description: String, status: CreationStatus, sessionId: String?, progress: CreationProgress?, error: String?, createdAt: String, completedAt: String?) {
-        self.id = id
-        self.name = name
-        self.path = path
-        self.description = description
-        self.status = status
-        self.sessionId = sessionId
-        self.progress = progress
-        self.error = error
-        self.createdAt = createdAt
-        self.completedAt = completedAt
+    public init(
+        /**
+         * IDE command-line launcher (e.g. `cursor`, `code`)
+         */command: String, 
+        /**
+         * Project folder first, then the files to open
+         */args: [String]) {
+        self.command = command
+        self.args = args
     }
 }
 
-extension ProjectCreation: Equatable, Hashable {
-    public static func == (lhs: ProjectCreation, rhs: ProjectCreation) -> Bool {
-        if lhs.id != rhs.id {
-            return false
-        }
-        if lhs.name != rhs.name {
-            return false
-        }
-        if lhs.path != rhs.path {
-            return false
-        }
-        if lhs.description != rhs.description {
-            return false
-        }
-        if lhs.status != rhs.status {
-            return false
-        }
-        if lhs.sessionId != rhs.sessionId {
-            return false
-        }
-        if lhs.progress != rhs.progress {
-            return false
-        }
-        if lhs.error != rhs.error {
-            return false
-        }
-        if lhs.createdAt != rhs.createdAt {
+
+
+extension EditorLaunch: Equatable, Hashable {
+    public static func ==(lhs: EditorLaunch, rhs: EditorLaunch) -> Bool {
+        if lhs.command != rhs.command {
             return false
         }
-        if lhs.completedAt != rhs.completedAt {
+        if lhs.args != rhs.args {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(id)
-        hasher.combine(name)
-        hasher.combine(path)
-        hasher.combine(description)
-        hasher.combine(status)
-        hasher.combine(sessionId)
-        hasher.combine(progress)
-        hasher.combine(error)
-        hasher.combine(createdAt)
-        hasher.combine(completedAt)
+        hasher.combine(command)
+        hasher.combine(args)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeProjectCreation: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectCreation {
+public struct FfiConverterTypeEditorLaunch: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EditorLaunch {
         return
-            try ProjectCreation(
-                id: FfiConverterString.read(from: &buf),
-                name: FfiConverterString.read(from: &buf),
-                path: FfiConverterString.read(from: &buf),
-                description: FfiConverterString.read(from: &buf),
-                status: FfiConverterTypeCreationStatus.read(from: &buf),
-                sessionId: FfiConverterOptionString.read(from: &buf),
-                progress: FfiConverterOptionTypeCreationProgress.read(from: &buf),
-                error: FfiConverterOptionString.read(from: &buf),
-                createdAt: FfiConverterString.read(from: &buf),
-                completedAt: FfiConverterOptionString.read(from: &buf)
-            )
+            try EditorLaunch(
+                command: FfiConverterString.read(from: &buf), 
+                args: FfiConverterSequenceString.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: ProjectCreation, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.id, into: &buf)
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterString.write(value.path, into: &buf)
-        FfiConverterString.write(value.description, into: &buf)
-        FfiConverterTypeCreationStatus.write(value.status, into: &buf)
-        FfiConverterOptionString.write(value.sessionId, into: &buf)
-        FfiConverterOptionTypeCreationProgress.write(value.progress, into: &buf)
-        FfiConverterOptionString.write(value.error, into: &buf)
-        FfiConverterString.write(value.createdAt, into: &buf)
-        FfiConverterOptionString.write(value.completedAt, into: &buf)
+    public static func write(_ value: EditorLaunch, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.command, into: &buf)
+        FfiConverterSequenceString.write(value.args, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProjectCreation_lift(_ buf: RustBuffer) throws -> ProjectCreation {
-    return try FfiConverterTypeProjectCreation.lift(buf)
+public func FfiConverterTypeEditorLaunch_lift(_ buf: RustBuffer) throws -> EditorLaunch {
+    return try FfiConverterTypeEditorLaunch.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProjectCreation_lower(_ value: ProjectCreation) -> RustBuffer {
-    return FfiConverterTypeProjectCreation.lower(value)
+public func FfiConverterTypeEditorLaunch_lower(_ value: EditorLaunch) -> RustBuffer {
+    return FfiConverterTypeEditorLaunch.lower(value)
 }
 
+
 /**
- * Detailed project information including tasks and git status.
+ * An artifact as a specific project sees it, after shadowing and plugin overrides.
  */
-public struct ProjectDetails {
-    public var project: Project
-    public var claudeMdContent: String?
-    public var tasks: [Task]
-    public var gitBranch: String?
-    public var gitDirty: Bool
+public struct EffectiveArtifact {
+    public var artifact: Artifact
+    /**
+     * True when the project actually gets this artifact
+     */
+    public var active: Bool
+    /**
+     * Source of the same-named artifact that takes precedence, when shadowed
+     */
+    public var shadowedBy: String?
+    /**
+     * True when the providing plugin is disabled for this project
+     */
+    public var disabled: Bool
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(project: Project, claudeMdContent: String?, tasks: [Task], gitBranch: String?, gitDirty: Bool) {
-        self.project = project
-        self.claudeMdContent = claudeMdContent
-        self.tasks = tasks
-        self.gitBranch = gitBranch
-        self.gitDirty = gitDirty
+    public init(artifact: Artifact, 
+        /**
+         * True when the project actually gets this artifact
+         */active: Bool, 
+        /**
+         * Source of the same-named artifact that takes precedence, when shadowed
+         */shadowedBy: String?, 
+        /**
+         * True when the providing plugin is disabled for this project
+         */disabled: Bool) {
+        self.artifact = artifact
+        self.active = active
+        self.shadowedBy = shadowedBy
+        self.disabled = disabled
     }
 }
 
-extension ProjectDetails: Equatable, Hashable {
-    public static func == (lhs: ProjectDetails, rhs: ProjectDetails) -> Bool {
-        if lhs.project != rhs.project {
-            return false
-        }
-        if lhs.claudeMdContent != rhs.claudeMdContent {
+
+
+extension EffectiveArtifact: Equatable, Hashable {
+    public static func ==(lhs: EffectiveArtifact, rhs: EffectiveArtifact) -> Bool {
+        if lhs.artifact != rhs.artifact {
             return false
         }
-        if lhs.tasks != rhs.tasks {
+        if lhs.active != rhs.active {
             return false
         }
-        if lhs.gitBranch != rhs.gitBranch {
+        if lhs.shadowedBy != rhs.shadowedBy {
             return false
         }
-        if lhs.gitDirty != rhs.gitDirty {
+        if lhs.disabled != rhs.disabled {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(project)
-        hasher.combine(claudeMdContent)
-        hasher.combine(tasks)
-        hasher.combine(gitBranch)
-        hasher.combine(gitDirty)
+        hasher.combine(artifact)
+        hasher.combine(active)
+        hasher.combine(shadowedBy)
+        hasher.combine(disabled)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeProjectDetails: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectDetails {
+public struct FfiConverterTypeEffectiveArtifact: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EffectiveArtifact {
         return
-            try ProjectDetails(
-                project: FfiConverterTypeProject.read(from: &buf),
-                claudeMdContent: FfiConverterOptionString.read(from: &buf),
-                tasks: FfiConverterSequenceTypeTask.read(from: &buf),
-                gitBranch: FfiConverterOptionString.read(from: &buf),
-                gitDirty: FfiConverterBool.read(from: &buf)
-            )
+            try EffectiveArtifact(
+                artifact: FfiConverterTypeArtifact.read(from: &buf), 
+                active: FfiConverterBool.read(from: &buf), 
+                shadowedBy: FfiConverterOptionString.read(from: &buf), 
+                disabled: FfiConverterBool.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: ProjectDetails, into buf: inout [UInt8]) {
-        FfiConverterTypeProject.write(value.project, into: &buf)
-        FfiConverterOptionString.write(value.claudeMdContent, into: &buf)
-        FfiConverterSequenceTypeTask.write(value.tasks, into: &buf)
-        FfiConverterOptionString.write(value.gitBranch, into: &buf)
-        FfiConverterBool.write(value.gitDirty, into: &buf)
+    public static func write(_ value: EffectiveArtifact, into buf: inout [UInt8]) {
+        FfiConverterTypeArtifact.write(value.artifact, into: &buf)
+        FfiConverterBool.write(value.active, into: &buf)
+        FfiConverterOptionString.write(value.shadowedBy, into: &buf)
+        FfiConverterBool.write(value.disabled, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProjectDetails_lift(_ buf: RustBuffer) throws -> ProjectDetails {
-    return try FfiConverterTypeProjectDetails.lift(buf)
+public func FfiConverterTypeEffectiveArtifact_lift(_ buf: RustBuffer) throws -> EffectiveArtifact {
+    return try FfiConverterTypeEffectiveArtifact.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProjectDetails_lower(_ value: ProjectDetails) -> RustBuffer {
-    return FfiConverterTypeProjectDetails.lower(value)
+public func FfiConverterTypeEffectiveArtifact_lower(_ value: EffectiveArtifact) -> RustBuffer {
+    return FfiConverterTypeEffectiveArtifact.lower(value)
 }
 
+
 /**
- * Full session state with context information.
+ * Output produced per input token on one day.
  */
-public struct ProjectSessionState {
-    public var state: SessionState
-    public var stateChangedAt: String?
+public struct EfficiencyPoint {
     /**
-     * Timestamp of last hook event (more recent than state_changed_at).
-     * Use this for activity comparison between sessions.
+     * YYYY-MM-DD, UTC
      */
-    public var updatedAt: String?
-    public var sessionId: String?
-    public var workingOn: String?
-    public var context: ContextInfo?
+    public var day: String
     /**
-     * Whether Claude is currently "thinking" (API call in flight).
-     * This provides real-time status when using the fetch-intercepting launcher.
+     * Uncached input tokens; cache reads are excluded so better caching shows up
      */
-    public var thinking: Bool?
+    public var inputTokens: UInt64
+    public var outputTokens: UInt64
     /**
-     * Whether a lock file is held for this project (indicates Claude is running).
-     * This is checked via advisory file locks and is more reliable than state file alone.
+     * None on days without input
      */
-    public var isLocked: Bool
+    public var outputPerInput: Double?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(state: SessionState, stateChangedAt: String?,
-                /**
-                    * Timestamp of last hook event (more recent than state_changed_at).
-                    * Use this for activity comparison between sessions.
-                    */ updatedAt: String?, sessionId: String?, workingOn: String?, context: ContextInfo?,
-                /**
-                    * Whether Claude is currently "thinking" (API call in flight).
-                    * This provides real-time status when using the fetch-intercepting launcher.
-                    */ thinking: Bool?,
-                /**
-                    * Whether a lock file is held for this project (indicates Claude is running).
-                    * This is checked via advisory file locks and is more reliable than state file alone.
-                    */ isLocked: Bool)
-    {
-        self.state = state
-        self.stateChangedAt = stateChangedAt
-        self.updatedAt = updatedAt
-        self.sessionId = sessionId
-        self.workingOn = workingOn
-        self.context = context
-        self.thinking = thinking
-        self.isLocked = isLocked
+    public init(
+        /**
+         * YYYY-MM-DD, UTC
+         */day: String, 
+        /**
+         * Uncached input tokens; cache reads are excluded so better caching shows up
+         */inputTokens: UInt64, outputTokens: UInt64, 
+        /**
+         * None on days without input
+         */outputPerInput: Double?) {
+        self.day = day
+        self.inputTokens = inputTokens
+        self.outputTokens = outputTokens
+        self.outputPerInput = outputPerInput
     }
 }
 
-extension ProjectSessionState: Equatable, Hashable {
-    public static func == (lhs: ProjectSessionState, rhs: ProjectSessionState) -> Bool {
-        if lhs.state != rhs.state {
-            return false
-        }
-        if lhs.stateChangedAt != rhs.stateChangedAt {
-            return false
-        }
-        if lhs.updatedAt != rhs.updatedAt {
+
+
+extension EfficiencyPoint: Equatable, Hashable {
+    public static func ==(lhs: EfficiencyPoint, rhs: EfficiencyPoint) -> Bool {
+        if lhs.day != rhs.day {
             return false
         }
-        if lhs.sessionId != rhs.sessionId {
+        if lhs.inputTokens != rhs.inputTokens {
             return false
         }
-        if lhs.workingOn != rhs.workingOn {
+        if lhs.outputTokens != rhs.outputTokens {
             return false
         }
-        if lhs.context != rhs.context {
+        if lhs.outputPerInput != rhs.outputPerInput {
             return false
         }
-        if lhs.thinking != rhs.thinking {
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(day)
+        hasher.combine(inputTokens)
+        hasher.combine(outputTokens)
+        hasher.combine(outputPerInput)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeEfficiencyPoint: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EfficiencyPoint {
+        return
+            try EfficiencyPoint(
+                day: FfiConverterString.read(from: &buf), 
+                inputTokens: FfiConverterUInt64.read(from: &buf), 
+                outputTokens: FfiConverterUInt64.read(from: &buf), 
+                outputPerInput: FfiConverterOptionDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: EfficiencyPoint, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.day, into: &buf)
+        FfiConverterUInt64.write(value.inputTokens, into: &buf)
+        FfiConverterUInt64.write(value.outputTokens, into: &buf)
+        FfiConverterOptionDouble.write(value.outputPerInput, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEfficiencyPoint_lift(_ buf: RustBuffer) throws -> EfficiencyPoint {
+    return try FfiConverterTypeEfficiencyPoint.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEfficiencyPoint_lower(_ value: EfficiencyPoint) -> RustBuffer {
+    return FfiConverterTypeEfficiencyPoint.lower(value)
+}
+
+
+/**
+ * Daily token efficiency for a project and the direction it is heading.
+ */
+public struct EfficiencyTrend {
+    /**
+     * One point per calendar day in the window, oldest first
+     */
+    public var points: [EfficiencyPoint]
+    public var direction: TrendDirection
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * One point per calendar day in the window, oldest first
+         */points: [EfficiencyPoint], direction: TrendDirection) {
+        self.points = points
+        self.direction = direction
+    }
+}
+
+
+
+extension EfficiencyTrend: Equatable, Hashable {
+    public static func ==(lhs: EfficiencyTrend, rhs: EfficiencyTrend) -> Bool {
+        if lhs.points != rhs.points {
             return false
         }
-        if lhs.isLocked != rhs.isLocked {
+        if lhs.direction != rhs.direction {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(state)
-        hasher.combine(stateChangedAt)
-        hasher.combine(updatedAt)
-        hasher.combine(sessionId)
-        hasher.combine(workingOn)
-        hasher.combine(context)
-        hasher.combine(thinking)
-        hasher.combine(isLocked)
+        hasher.combine(points)
+        hasher.combine(direction)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeProjectSessionState: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectSessionState {
+public struct FfiConverterTypeEfficiencyTrend: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EfficiencyTrend {
         return
-            try ProjectSessionState(
-                state: FfiConverterTypeSessionState.read(from: &buf),
-                stateChangedAt: FfiConverterOptionString.read(from: &buf),
-                updatedAt: FfiConverterOptionString.read(from: &buf),
-                sessionId: FfiConverterOptionString.read(from: &buf),
-                workingOn: FfiConverterOptionString.read(from: &buf),
-                context: FfiConverterOptionTypeContextInfo.read(from: &buf),
-                thinking: FfiConverterOptionBool.read(from: &buf),
-                isLocked: FfiConverterBool.read(from: &buf)
-            )
+            try EfficiencyTrend(
+                points: FfiConverterSequenceTypeEfficiencyPoint.read(from: &buf), 
+                direction: FfiConverterTypeTrendDirection.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: ProjectSessionState, into buf: inout [UInt8]) {
-        FfiConverterTypeSessionState.write(value.state, into: &buf)
-        FfiConverterOptionString.write(value.stateChangedAt, into: &buf)
-        FfiConverterOptionString.write(value.updatedAt, into: &buf)
-        FfiConverterOptionString.write(value.sessionId, into: &buf)
-        FfiConverterOptionString.write(value.workingOn, into: &buf)
-        FfiConverterOptionTypeContextInfo.write(value.context, into: &buf)
-        FfiConverterOptionBool.write(value.thinking, into: &buf)
-        FfiConverterBool.write(value.isLocked, into: &buf)
+    public static func write(_ value: EfficiencyTrend, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeEfficiencyPoint.write(value.points, into: &buf)
+        FfiConverterTypeTrendDirection.write(value.direction, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProjectSessionState_lift(_ buf: RustBuffer) throws -> ProjectSessionState {
-    return try FfiConverterTypeProjectSessionState.lift(buf)
+public func FfiConverterTypeEfficiencyTrend_lift(_ buf: RustBuffer) throws -> EfficiencyTrend {
+    return try FfiConverterTypeEfficiencyTrend.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProjectSessionState_lower(_ value: ProjectSessionState) -> RustBuffer {
-    return FfiConverterTypeProjectSessionState.lower(value)
+public func FfiConverterTypeEfficiencyTrend_lower(_ value: EfficiencyTrend) -> RustBuffer {
+    return FfiConverterTypeEfficiencyTrend.lower(value)
 }
 
+
 /**
- * Aggregated token usage statistics for a project.
+ * A Claude projects folder whose decoded path disagrees with its transcripts.
  */
-public struct ProjectStats {
-    public var totalInputTokens: UInt64
-    public var totalOutputTokens: UInt64
-    public var totalCacheReadTokens: UInt64
-    public var totalCacheCreationTokens: UInt64
-    public var opusMessages: UInt32
-    public var sonnetMessages: UInt32
-    public var haikuMessages: UInt32
-    public var sessionCount: UInt32
-    public var latestSummary: String?
-    public var firstActivity: String?
-    public var lastActivity: String?
+public struct EncodingMismatch {
+    /**
+     * Folder name under `~/.claude/projects/`
+     */
+    public var folder: String
+    /**
+     * Working directory recorded in the folder's transcripts
+     */
+    public var transcriptCwd: String
+    /**
+     * What `try_resolve_encoded_path` produced, None if it found nothing
+     */
+    public var decodedPath: String?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(totalInputTokens: UInt64, totalOutputTokens: UInt64, totalCacheReadTokens: UInt64, totalCacheCreationTokens: UInt64, opusMessages: UInt32, sonnetMessages: UInt32, haikuMessages: UInt32, sessionCount: UInt32, latestSummary: String?, firstActivity: String?, lastActivity: String?) {
-        self.totalInputTokens = totalInputTokens
-        self.totalOutputTokens = totalOutputTokens
-        self.totalCacheReadTokens = totalCacheReadTokens
-        self.totalCacheCreationTokens = totalCacheCreationTokens
-        self.opusMessages = opusMessages
-        self.sonnetMessages = sonnetMessages
-        self.haikuMessages = haikuMessages
-        self.sessionCount = sessionCount
-        self.latestSummary = latestSummary
-        self.firstActivity = firstActivity
-        self.lastActivity = lastActivity
+    public init(
+        /**
+         * Folder name under `~/.claude/projects/`
+         */folder: String, 
+        /**
+         * Working directory recorded in the folder's transcripts
+         */transcriptCwd: String, 
+        /**
+         * What `try_resolve_encoded_path` produced, None if it found nothing
+         */decodedPath: String?) {
+        self.folder = folder
+        self.transcriptCwd = transcriptCwd
+        self.decodedPath = decodedPath
     }
 }
 
-extension ProjectStats: Equatable, Hashable {
-    public static func == (lhs: ProjectStats, rhs: ProjectStats) -> Bool {
-        if lhs.totalInputTokens != rhs.totalInputTokens {
+
+
+extension EncodingMismatch: Equatable, Hashable {
+    public static func ==(lhs: EncodingMismatch, rhs: EncodingMismatch) -> Bool {
+        if lhs.folder != rhs.folder {
             return false
         }
-        if lhs.totalOutputTokens != rhs.totalOutputTokens {
+        if lhs.transcriptCwd != rhs.transcriptCwd {
             return false
         }
-        if lhs.totalCacheReadTokens != rhs.totalCacheReadTokens {
+        if lhs.decodedPath != rhs.decodedPath {
             return false
         }
-        if lhs.totalCacheCreationTokens != rhs.totalCacheCreationTokens {
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(folder)
+        hasher.combine(transcriptCwd)
+        hasher.combine(decodedPath)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeEncodingMismatch: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EncodingMismatch {
+        return
+            try EncodingMismatch(
+                folder: FfiConverterString.read(from: &buf), 
+                transcriptCwd: FfiConverterString.read(from: &buf), 
+                decodedPath: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: EncodingMismatch, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.folder, into: &buf)
+        FfiConverterString.write(value.transcriptCwd, into: &buf)
+        FfiConverterOptionString.write(value.decodedPath, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEncodingMismatch_lift(_ buf: RustBuffer) throws -> EncodingMismatch {
+    return try FfiConverterTypeEncodingMismatch.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEncodingMismatch_lower(_ value: EncodingMismatch) -> RustBuffer {
+    return FfiConverterTypeEncodingMismatch.lower(value)
+}
+
+
+/**
+ * One session file's share of a project's stats.
+ */
+public struct FileStatsContribution {
+    public var fileName: String
+    public var inputTokens: UInt64
+    public var outputTokens: UInt64
+    public var cacheReadTokens: UInt64
+    public var cacheCreationTokens: UInt64
+    /**
+     * Assistant messages from a known model family
+     */
+    public var messageCount: UInt32
+    /**
+     * Fraction of the project's input + output tokens (0.0–1.0)
+     */
+    public var tokenShare: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(fileName: String, inputTokens: UInt64, outputTokens: UInt64, cacheReadTokens: UInt64, cacheCreationTokens: UInt64, 
+        /**
+         * Assistant messages from a known model family
+         */messageCount: UInt32, 
+        /**
+         * Fraction of the project's input + output tokens (0.0–1.0)
+         */tokenShare: Double) {
+        self.fileName = fileName
+        self.inputTokens = inputTokens
+        self.outputTokens = outputTokens
+        self.cacheReadTokens = cacheReadTokens
+        self.cacheCreationTokens = cacheCreationTokens
+        self.messageCount = messageCount
+        self.tokenShare = tokenShare
+    }
+}
+
+
+
+extension FileStatsContribution: Equatable, Hashable {
+    public static func ==(lhs: FileStatsContribution, rhs: FileStatsContribution) -> Bool {
+        if lhs.fileName != rhs.fileName {
             return false
         }
-        if lhs.opusMessages != rhs.opusMessages {
+        if lhs.inputTokens != rhs.inputTokens {
             return false
         }
-        if lhs.sonnetMessages != rhs.sonnetMessages {
+        if lhs.outputTokens != rhs.outputTokens {
             return false
         }
-        if lhs.haikuMessages != rhs.haikuMessages {
+        if lhs.cacheReadTokens != rhs.cacheReadTokens {
             return false
         }
-        if lhs.sessionCount != rhs.sessionCount {
+        if lhs.cacheCreationTokens != rhs.cacheCreationTokens {
             return false
         }
-        if lhs.latestSummary != rhs.latestSummary {
+        if lhs.messageCount != rhs.messageCount {
             return false
         }
-        if lhs.firstActivity != rhs.firstActivity {
+        if lhs.tokenShare != rhs.tokenShare {
             return false
         }
-        if lhs.lastActivity != rhs.lastActivity {
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(fileName)
+        hasher.combine(inputTokens)
+        hasher.combine(outputTokens)
+        hasher.combine(cacheReadTokens)
+        hasher.combine(cacheCreationTokens)
+        hasher.combine(messageCount)
+        hasher.combine(tokenShare)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFileStatsContribution: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileStatsContribution {
+        return
+            try FileStatsContribution(
+                fileName: FfiConverterString.read(from: &buf), 
+                inputTokens: FfiConverterUInt64.read(from: &buf), 
+                outputTokens: FfiConverterUInt64.read(from: &buf), 
+                cacheReadTokens: FfiConverterUInt64.read(from: &buf), 
+                cacheCreationTokens: FfiConverterUInt64.read(from: &buf), 
+                messageCount: FfiConverterUInt32.read(from: &buf), 
+                tokenShare: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FileStatsContribution, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.fileName, into: &buf)
+        FfiConverterUInt64.write(value.inputTokens, into: &buf)
+        FfiConverterUInt64.write(value.outputTokens, into: &buf)
+        FfiConverterUInt64.write(value.cacheReadTokens, into: &buf)
+        FfiConverterUInt64.write(value.cacheCreationTokens, into: &buf)
+        FfiConverterUInt32.write(value.messageCount, into: &buf)
+        FfiConverterDouble.write(value.tokenShare, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileStatsContribution_lift(_ buf: RustBuffer) throws -> FileStatsContribution {
+    return try FfiConverterTypeFileStatsContribution.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileStatsContribution_lower(_ value: FileStatsContribution) -> RustBuffer {
+    return FfiConverterTypeFileStatsContribution.lower(value)
+}
+
+
+/**
+ * Whether a directory is inside a git repository, and where its git dir is.
+ */
+public struct GitRepoCheck {
+    public var isRepo: Bool
+    /**
+     * `.git` itself, or the directory a worktree/submodule `.git` file points to
+     */
+    public var gitDir: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isRepo: Bool, 
+        /**
+         * `.git` itself, or the directory a worktree/submodule `.git` file points to
+         */gitDir: String?) {
+        self.isRepo = isRepo
+        self.gitDir = gitDir
+    }
+}
+
+
+
+extension GitRepoCheck: Equatable, Hashable {
+    public static func ==(lhs: GitRepoCheck, rhs: GitRepoCheck) -> Bool {
+        if lhs.isRepo != rhs.isRepo {
+            return false
+        }
+        if lhs.gitDir != rhs.gitDir {
             return false
         }
         return true
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(totalInputTokens)
-        hasher.combine(totalOutputTokens)
-        hasher.combine(totalCacheReadTokens)
-        hasher.combine(totalCacheCreationTokens)
-        hasher.combine(opusMessages)
-        hasher.combine(sonnetMessages)
-        hasher.combine(haikuMessages)
-        hasher.combine(sessionCount)
-        hasher.combine(latestSummary)
-        hasher.combine(firstActivity)
-        hasher.combine(lastActivity)
-    }
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isRepo)
+        hasher.combine(gitDir)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeGitRepoCheck: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GitRepoCheck {
+        return
+            try GitRepoCheck(
+                isRepo: FfiConverterBool.read(from: &buf), 
+                gitDir: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: GitRepoCheck, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isRepo, into: &buf)
+        FfiConverterOptionString.write(value.gitDir, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGitRepoCheck_lift(_ buf: RustBuffer) throws -> GitRepoCheck {
+    return try FfiConverterTypeGitRepoCheck.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGitRepoCheck_lower(_ value: GitRepoCheck) -> RustBuffer {
+    return FfiConverterTypeGitRepoCheck.lower(value)
+}
+
+
+/**
+ * Global Claude Code configuration and artifact counts.
+ */
+public struct GlobalConfig {
+    public var settingsPath: String
+    public var settingsExists: Bool
+    public var instructionsPath: String?
+    public var skillsDir: String?
+    public var commandsDir: String?
+    public var agentsDir: String?
+    public var skillCount: UInt32
+    public var commandCount: UInt32
+    public var agentCount: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(settingsPath: String, settingsExists: Bool, instructionsPath: String?, skillsDir: String?, commandsDir: String?, agentsDir: String?, skillCount: UInt32, commandCount: UInt32, agentCount: UInt32) {
+        self.settingsPath = settingsPath
+        self.settingsExists = settingsExists
+        self.instructionsPath = instructionsPath
+        self.skillsDir = skillsDir
+        self.commandsDir = commandsDir
+        self.agentsDir = agentsDir
+        self.skillCount = skillCount
+        self.commandCount = commandCount
+        self.agentCount = agentCount
+    }
+}
+
+
+
+extension GlobalConfig: Equatable, Hashable {
+    public static func ==(lhs: GlobalConfig, rhs: GlobalConfig) -> Bool {
+        if lhs.settingsPath != rhs.settingsPath {
+            return false
+        }
+        if lhs.settingsExists != rhs.settingsExists {
+            return false
+        }
+        if lhs.instructionsPath != rhs.instructionsPath {
+            return false
+        }
+        if lhs.skillsDir != rhs.skillsDir {
+            return false
+        }
+        if lhs.commandsDir != rhs.commandsDir {
+            return false
+        }
+        if lhs.agentsDir != rhs.agentsDir {
+            return false
+        }
+        if lhs.skillCount != rhs.skillCount {
+            return false
+        }
+        if lhs.commandCount != rhs.commandCount {
+            return false
+        }
+        if lhs.agentCount != rhs.agentCount {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(settingsPath)
+        hasher.combine(settingsExists)
+        hasher.combine(instructionsPath)
+        hasher.combine(skillsDir)
+        hasher.combine(commandsDir)
+        hasher.combine(agentsDir)
+        hasher.combine(skillCount)
+        hasher.combine(commandCount)
+        hasher.combine(agentCount)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeGlobalConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GlobalConfig {
+        return
+            try GlobalConfig(
+                settingsPath: FfiConverterString.read(from: &buf), 
+                settingsExists: FfiConverterBool.read(from: &buf), 
+                instructionsPath: FfiConverterOptionString.read(from: &buf), 
+                skillsDir: FfiConverterOptionString.read(from: &buf), 
+                commandsDir: FfiConverterOptionString.read(from: &buf), 
+                agentsDir: FfiConverterOptionString.read(from: &buf), 
+                skillCount: FfiConverterUInt32.read(from: &buf), 
+                commandCount: FfiConverterUInt32.read(from: &buf), 
+                agentCount: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: GlobalConfig, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.settingsPath, into: &buf)
+        FfiConverterBool.write(value.settingsExists, into: &buf)
+        FfiConverterOptionString.write(value.instructionsPath, into: &buf)
+        FfiConverterOptionString.write(value.skillsDir, into: &buf)
+        FfiConverterOptionString.write(value.commandsDir, into: &buf)
+        FfiConverterOptionString.write(value.agentsDir, into: &buf)
+        FfiConverterUInt32.write(value.skillCount, into: &buf)
+        FfiConverterUInt32.write(value.commandCount, into: &buf)
+        FfiConverterUInt32.write(value.agentCount, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGlobalConfig_lift(_ buf: RustBuffer) throws -> GlobalConfig {
+    return try FfiConverterTypeGlobalConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGlobalConfig_lower(_ value: GlobalConfig) -> RustBuffer {
+    return FfiConverterTypeGlobalConfig.lower(value)
+}
+
+
+/**
+ * The global `~/.claude/CLAUDE.md` as of one read.
+ */
+public struct GlobalInstructions {
+    public var path: String
+    /**
+     * None when the file doesn't exist
+     */
+    public var content: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(path: String, 
+        /**
+         * None when the file doesn't exist
+         */content: String?) {
+        self.path = path
+        self.content = content
+    }
+}
+
+
+
+extension GlobalInstructions: Equatable, Hashable {
+    public static func ==(lhs: GlobalInstructions, rhs: GlobalInstructions) -> Bool {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.content != rhs.content {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(path)
+        hasher.combine(content)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeGlobalInstructions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GlobalInstructions {
+        return
+            try GlobalInstructions(
+                path: FfiConverterString.read(from: &buf), 
+                content: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: GlobalInstructions, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterOptionString.write(value.content, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGlobalInstructions_lift(_ buf: RustBuffer) throws -> GlobalInstructions {
+    return try FfiConverterTypeGlobalInstructions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGlobalInstructions_lower(_ value: GlobalInstructions) -> RustBuffer {
+    return FfiConverterTypeGlobalInstructions.lower(value)
+}
+
+
+/**
+ * Unified diagnostic report combining installation status and runtime health.
+ *
+ * This provides a single source of truth for the UI to determine what to show
+ * and whether auto-fix is available.
+ */
+public struct HookDiagnosticReport {
+    /**
+     * True if everything is working correctly
+     */
+    public var isHealthy: Bool
+    /**
+     * The most critical issue to display (if any)
+     */
+    public var primaryIssue: HookIssue?
+    /**
+     * True if "Fix All" can resolve the issue
+     */
+    public var canAutoFix: Bool
+    /**
+     * Whether this appears to be a first-time setup (no heartbeat ever seen)
+     */
+    public var isFirstRun: Bool
+    /**
+     * Detailed status for checklist display
+     */
+    public var binaryOk: Bool
+    public var configOk: Bool
+    public var firingOk: Bool
+    /**
+     * Path to the hook binary/symlink (e.g., ~/.local/bin/hud-hook)
+     */
+    public var symlinkPath: String
+    /**
+     * Target of the symlink if it is one, None if regular file or doesn't exist
+     */
+    public var symlinkTarget: String?
+    /**
+     * Age of last heartbeat in seconds (for "last seen X ago" display)
+     */
+    public var lastHeartbeatAgeSecs: UInt64?
+    /**
+     * Set when session timestamps indicate a skewed clock
+     */
+    public var clockSkewWarning: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * True if everything is working correctly
+         */isHealthy: Bool, 
+        /**
+         * The most critical issue to display (if any)
+         */primaryIssue: HookIssue?, 
+        /**
+         * True if "Fix All" can resolve the issue
+         */canAutoFix: Bool, 
+        /**
+         * Whether this appears to be a first-time setup (no heartbeat ever seen)
+         */isFirstRun: Bool, 
+        /**
+         * Detailed status for checklist display
+         */binaryOk: Bool, configOk: Bool, firingOk: Bool, 
+        /**
+         * Path to the hook binary/symlink (e.g., ~/.local/bin/hud-hook)
+         */symlinkPath: String, 
+        /**
+         * Target of the symlink if it is one, None if regular file or doesn't exist
+         */symlinkTarget: String?, 
+        /**
+         * Age of last heartbeat in seconds (for "last seen X ago" display)
+         */lastHeartbeatAgeSecs: UInt64?, 
+        /**
+         * Set when session timestamps indicate a skewed clock
+         */clockSkewWarning: String?) {
+        self.isHealthy = isHealthy
+        self.primaryIssue = primaryIssue
+        self.canAutoFix = canAutoFix
+        self.isFirstRun = isFirstRun
+        self.binaryOk = binaryOk
+        self.configOk = configOk
+        self.firingOk = firingOk
+        self.symlinkPath = symlinkPath
+        self.symlinkTarget = symlinkTarget
+        self.lastHeartbeatAgeSecs = lastHeartbeatAgeSecs
+        self.clockSkewWarning = clockSkewWarning
+    }
+}
+
+
+
+extension HookDiagnosticReport: Equatable, Hashable {
+    public static func ==(lhs: HookDiagnosticReport, rhs: HookDiagnosticReport) -> Bool {
+        if lhs.isHealthy != rhs.isHealthy {
+            return false
+        }
+        if lhs.primaryIssue != rhs.primaryIssue {
+            return false
+        }
+        if lhs.canAutoFix != rhs.canAutoFix {
+            return false
+        }
+        if lhs.isFirstRun != rhs.isFirstRun {
+            return false
+        }
+        if lhs.binaryOk != rhs.binaryOk {
+            return false
+        }
+        if lhs.configOk != rhs.configOk {
+            return false
+        }
+        if lhs.firingOk != rhs.firingOk {
+            return false
+        }
+        if lhs.symlinkPath != rhs.symlinkPath {
+            return false
+        }
+        if lhs.symlinkTarget != rhs.symlinkTarget {
+            return false
+        }
+        if lhs.lastHeartbeatAgeSecs != rhs.lastHeartbeatAgeSecs {
+            return false
+        }
+        if lhs.clockSkewWarning != rhs.clockSkewWarning {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isHealthy)
+        hasher.combine(primaryIssue)
+        hasher.combine(canAutoFix)
+        hasher.combine(isFirstRun)
+        hasher.combine(binaryOk)
+        hasher.combine(configOk)
+        hasher.combine(firingOk)
+        hasher.combine(symlinkPath)
+        hasher.combine(symlinkTarget)
+        hasher.combine(lastHeartbeatAgeSecs)
+        hasher.combine(clockSkewWarning)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHookDiagnosticReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookDiagnosticReport {
+        return
+            try HookDiagnosticReport(
+                isHealthy: FfiConverterBool.read(from: &buf), 
+                primaryIssue: FfiConverterOptionTypeHookIssue.read(from: &buf), 
+                canAutoFix: FfiConverterBool.read(from: &buf), 
+                isFirstRun: FfiConverterBool.read(from: &buf), 
+                binaryOk: FfiConverterBool.read(from: &buf), 
+                configOk: FfiConverterBool.read(from: &buf), 
+                firingOk: FfiConverterBool.read(from: &buf), 
+                symlinkPath: FfiConverterString.read(from: &buf), 
+                symlinkTarget: FfiConverterOptionString.read(from: &buf), 
+                lastHeartbeatAgeSecs: FfiConverterOptionUInt64.read(from: &buf), 
+                clockSkewWarning: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HookDiagnosticReport, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isHealthy, into: &buf)
+        FfiConverterOptionTypeHookIssue.write(value.primaryIssue, into: &buf)
+        FfiConverterBool.write(value.canAutoFix, into: &buf)
+        FfiConverterBool.write(value.isFirstRun, into: &buf)
+        FfiConverterBool.write(value.binaryOk, into: &buf)
+        FfiConverterBool.write(value.configOk, into: &buf)
+        FfiConverterBool.write(value.firingOk, into: &buf)
+        FfiConverterString.write(value.symlinkPath, into: &buf)
+        FfiConverterOptionString.write(value.symlinkTarget, into: &buf)
+        FfiConverterOptionUInt64.write(value.lastHeartbeatAgeSecs, into: &buf)
+        FfiConverterOptionString.write(value.clockSkewWarning, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHookDiagnosticReport_lift(_ buf: RustBuffer) throws -> HookDiagnosticReport {
+    return try FfiConverterTypeHookDiagnosticReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHookDiagnosticReport_lower(_ value: HookDiagnosticReport) -> RustBuffer {
+    return FfiConverterTypeHookDiagnosticReport.lower(value)
+}
+
+
+/**
+ * Full health report for the hook binary.
+ */
+public struct HookHealthReport {
+    public var status: HookHealthStatus
+    public var heartbeatPath: String
+    public var thresholdSecs: UInt64
+    public var lastHeartbeatAgeSecs: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(status: HookHealthStatus, heartbeatPath: String, thresholdSecs: UInt64, lastHeartbeatAgeSecs: UInt64?) {
+        self.status = status
+        self.heartbeatPath = heartbeatPath
+        self.thresholdSecs = thresholdSecs
+        self.lastHeartbeatAgeSecs = lastHeartbeatAgeSecs
+    }
+}
+
+
+
+extension HookHealthReport: Equatable, Hashable {
+    public static func ==(lhs: HookHealthReport, rhs: HookHealthReport) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.heartbeatPath != rhs.heartbeatPath {
+            return false
+        }
+        if lhs.thresholdSecs != rhs.thresholdSecs {
+            return false
+        }
+        if lhs.lastHeartbeatAgeSecs != rhs.lastHeartbeatAgeSecs {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(heartbeatPath)
+        hasher.combine(thresholdSecs)
+        hasher.combine(lastHeartbeatAgeSecs)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHookHealthReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookHealthReport {
+        return
+            try HookHealthReport(
+                status: FfiConverterTypeHookHealthStatus.read(from: &buf), 
+                heartbeatPath: FfiConverterString.read(from: &buf), 
+                thresholdSecs: FfiConverterUInt64.read(from: &buf), 
+                lastHeartbeatAgeSecs: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HookHealthReport, into buf: inout [UInt8]) {
+        FfiConverterTypeHookHealthStatus.write(value.status, into: &buf)
+        FfiConverterString.write(value.heartbeatPath, into: &buf)
+        FfiConverterUInt64.write(value.thresholdSecs, into: &buf)
+        FfiConverterOptionUInt64.write(value.lastHeartbeatAgeSecs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHookHealthReport_lift(_ buf: RustBuffer) throws -> HookHealthReport {
+    return try FfiConverterTypeHookHealthReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHookHealthReport_lower(_ value: HookHealthReport) -> RustBuffer {
+    return FfiConverterTypeHookHealthReport.lower(value)
+}
+
+
+/**
+ * Outcome of [`SetupChecker::install_hooks_with_confirm`].
+ */
+public struct HookInstallOutcome {
+    public var preview: HookInstallPreview
+    /**
+     * `None` when the call was a preview and nothing was written
+     */
+    public var result: InstallResult?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(preview: HookInstallPreview, 
+        /**
+         * `None` when the call was a preview and nothing was written
+         */result: InstallResult?) {
+        self.preview = preview
+        self.result = result
+    }
+}
+
+
+
+extension HookInstallOutcome: Equatable, Hashable {
+    public static func ==(lhs: HookInstallOutcome, rhs: HookInstallOutcome) -> Bool {
+        if lhs.preview != rhs.preview {
+            return false
+        }
+        if lhs.result != rhs.result {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(preview)
+        hasher.combine(result)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHookInstallOutcome: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookInstallOutcome {
+        return
+            try HookInstallOutcome(
+                preview: FfiConverterTypeHookInstallPreview.read(from: &buf), 
+                result: FfiConverterOptionTypeInstallResult.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HookInstallOutcome, into buf: inout [UInt8]) {
+        FfiConverterTypeHookInstallPreview.write(value.preview, into: &buf)
+        FfiConverterOptionTypeInstallResult.write(value.result, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHookInstallOutcome_lift(_ buf: RustBuffer) throws -> HookInstallOutcome {
+    return try FfiConverterTypeHookInstallOutcome.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHookInstallOutcome_lower(_ value: HookInstallOutcome) -> RustBuffer {
+    return FfiConverterTypeHookInstallOutcome.lower(value)
+}
+
+
+/**
+ * `hooks.Stop` in settings.json before and after a hook install.
+ */
+public struct HookInstallPreview {
+    /**
+     * Current entries as pretty-printed JSON (`null` when there are none)
+     */
+    public var currentStopHooks: String
+    /**
+     * Entries the install would write, as pretty-printed JSON
+     */
+    public var proposedStopHooks: String
+    public var changed: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Current entries as pretty-printed JSON (`null` when there are none)
+         */currentStopHooks: String, 
+        /**
+         * Entries the install would write, as pretty-printed JSON
+         */proposedStopHooks: String, changed: Bool) {
+        self.currentStopHooks = currentStopHooks
+        self.proposedStopHooks = proposedStopHooks
+        self.changed = changed
+    }
+}
+
+
+
+extension HookInstallPreview: Equatable, Hashable {
+    public static func ==(lhs: HookInstallPreview, rhs: HookInstallPreview) -> Bool {
+        if lhs.currentStopHooks != rhs.currentStopHooks {
+            return false
+        }
+        if lhs.proposedStopHooks != rhs.proposedStopHooks {
+            return false
+        }
+        if lhs.changed != rhs.changed {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(currentStopHooks)
+        hasher.combine(proposedStopHooks)
+        hasher.combine(changed)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHookInstallPreview: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookInstallPreview {
+        return
+            try HookInstallPreview(
+                currentStopHooks: FfiConverterString.read(from: &buf), 
+                proposedStopHooks: FfiConverterString.read(from: &buf), 
+                changed: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HookInstallPreview, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.currentStopHooks, into: &buf)
+        FfiConverterString.write(value.proposedStopHooks, into: &buf)
+        FfiConverterBool.write(value.changed, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHookInstallPreview_lift(_ buf: RustBuffer) throws -> HookInstallPreview {
+    return try FfiConverterTypeHookInstallPreview.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHookInstallPreview_lower(_ value: HookInstallPreview) -> RustBuffer {
+    return FfiConverterTypeHookInstallPreview.lower(value)
+}
+
+
+/**
+ * Run-time distribution for one hook subcommand.
+ */
+public struct HookLatencyStats {
+    /**
+     * Hook subcommand ("handle" or "cwd")
+     */
+    public var command: String
+    public var sampleCount: UInt32
+    public var minMs: Double
+    public var medianMs: Double
+    public var p95Ms: Double
+    public var maxMs: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Hook subcommand ("handle" or "cwd")
+         */command: String, sampleCount: UInt32, minMs: Double, medianMs: Double, p95Ms: Double, maxMs: Double) {
+        self.command = command
+        self.sampleCount = sampleCount
+        self.minMs = minMs
+        self.medianMs = medianMs
+        self.p95Ms = p95Ms
+        self.maxMs = maxMs
+    }
+}
+
+
+
+extension HookLatencyStats: Equatable, Hashable {
+    public static func ==(lhs: HookLatencyStats, rhs: HookLatencyStats) -> Bool {
+        if lhs.command != rhs.command {
+            return false
+        }
+        if lhs.sampleCount != rhs.sampleCount {
+            return false
+        }
+        if lhs.minMs != rhs.minMs {
+            return false
+        }
+        if lhs.medianMs != rhs.medianMs {
+            return false
+        }
+        if lhs.p95Ms != rhs.p95Ms {
+            return false
+        }
+        if lhs.maxMs != rhs.maxMs {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(command)
+        hasher.combine(sampleCount)
+        hasher.combine(minMs)
+        hasher.combine(medianMs)
+        hasher.combine(p95Ms)
+        hasher.combine(maxMs)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHookLatencyStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookLatencyStats {
+        return
+            try HookLatencyStats(
+                command: FfiConverterString.read(from: &buf), 
+                sampleCount: FfiConverterUInt32.read(from: &buf), 
+                minMs: FfiConverterDouble.read(from: &buf), 
+                medianMs: FfiConverterDouble.read(from: &buf), 
+                p95Ms: FfiConverterDouble.read(from: &buf), 
+                maxMs: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HookLatencyStats, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.command, into: &buf)
+        FfiConverterUInt32.write(value.sampleCount, into: &buf)
+        FfiConverterDouble.write(value.minMs, into: &buf)
+        FfiConverterDouble.write(value.medianMs, into: &buf)
+        FfiConverterDouble.write(value.p95Ms, into: &buf)
+        FfiConverterDouble.write(value.maxMs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHookLatencyStats_lift(_ buf: RustBuffer) throws -> HookLatencyStats {
+    return try FfiConverterTypeHookLatencyStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHookLatencyStats_lower(_ value: HookLatencyStats) -> RustBuffer {
+    return FfiConverterTypeHookLatencyStats.lower(value)
+}
+
+
+/**
+ * Result of running a comprehensive hook system test.
+ *
+ * This verifies both the heartbeat (hooks are firing) and state file I/O
+ * (persistence layer is working). Used by the "Test Hooks" button in the UI.
+ */
+public struct HookTestResult {
+    /**
+     * True if all tests passed
+     */
+    public var success: Bool
+    /**
+     * True if heartbeat file is recent (hooks are firing)
+     */
+    public var heartbeatOk: Bool
+    /**
+     * Age of the heartbeat file in seconds (None if file doesn't exist)
+     */
+    public var heartbeatAgeSecs: UInt64?
+    /**
+     * True if state file I/O test passed
+     */
+    public var stateFileOk: Bool
+    /**
+     * Human-readable summary message for display
+     */
+    public var message: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * True if all tests passed
+         */success: Bool, 
+        /**
+         * True if heartbeat file is recent (hooks are firing)
+         */heartbeatOk: Bool, 
+        /**
+         * Age of the heartbeat file in seconds (None if file doesn't exist)
+         */heartbeatAgeSecs: UInt64?, 
+        /**
+         * True if state file I/O test passed
+         */stateFileOk: Bool, 
+        /**
+         * Human-readable summary message for display
+         */message: String) {
+        self.success = success
+        self.heartbeatOk = heartbeatOk
+        self.heartbeatAgeSecs = heartbeatAgeSecs
+        self.stateFileOk = stateFileOk
+        self.message = message
+    }
+}
+
+
+
+extension HookTestResult: Equatable, Hashable {
+    public static func ==(lhs: HookTestResult, rhs: HookTestResult) -> Bool {
+        if lhs.success != rhs.success {
+            return false
+        }
+        if lhs.heartbeatOk != rhs.heartbeatOk {
+            return false
+        }
+        if lhs.heartbeatAgeSecs != rhs.heartbeatAgeSecs {
+            return false
+        }
+        if lhs.stateFileOk != rhs.stateFileOk {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(success)
+        hasher.combine(heartbeatOk)
+        hasher.combine(heartbeatAgeSecs)
+        hasher.combine(stateFileOk)
+        hasher.combine(message)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHookTestResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookTestResult {
+        return
+            try HookTestResult(
+                success: FfiConverterBool.read(from: &buf), 
+                heartbeatOk: FfiConverterBool.read(from: &buf), 
+                heartbeatAgeSecs: FfiConverterOptionUInt64.read(from: &buf), 
+                stateFileOk: FfiConverterBool.read(from: &buf), 
+                message: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HookTestResult, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.success, into: &buf)
+        FfiConverterBool.write(value.heartbeatOk, into: &buf)
+        FfiConverterOptionUInt64.write(value.heartbeatAgeSecs, into: &buf)
+        FfiConverterBool.write(value.stateFileOk, into: &buf)
+        FfiConverterString.write(value.message, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHookTestResult_lift(_ buf: RustBuffer) throws -> HookTestResult {
+    return try FfiConverterTypeHookTestResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHookTestResult_lower(_ value: HookTestResult) -> RustBuffer {
+    return FfiConverterTypeHookTestResult.lower(value)
+}
+
+
+/**
+ * Live sessions running inside one host app (terminal or IDE).
+ */
+public struct HostAppSessions {
+    public var parentApp: ParentApp
+    /**
+     * Most recently updated session first
+     */
+    public var sessionIds: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(parentApp: ParentApp, 
+        /**
+         * Most recently updated session first
+         */sessionIds: [String]) {
+        self.parentApp = parentApp
+        self.sessionIds = sessionIds
+    }
+}
+
+
+
+extension HostAppSessions: Equatable, Hashable {
+    public static func ==(lhs: HostAppSessions, rhs: HostAppSessions) -> Bool {
+        if lhs.parentApp != rhs.parentApp {
+            return false
+        }
+        if lhs.sessionIds != rhs.sessionIds {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(parentApp)
+        hasher.combine(sessionIds)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHostAppSessions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HostAppSessions {
+        return
+            try HostAppSessions(
+                parentApp: FfiConverterTypeParentApp.read(from: &buf), 
+                sessionIds: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HostAppSessions, into buf: inout [UInt8]) {
+        FfiConverterTypeParentApp.write(value.parentApp, into: &buf)
+        FfiConverterSequenceString.write(value.sessionIds, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHostAppSessions_lift(_ buf: RustBuffer) throws -> HostAppSessions {
+    return try FfiConverterTypeHostAppSessions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHostAppSessions_lower(_ value: HostAppSessions) -> RustBuffer {
+    return FfiConverterTypeHostAppSessions.lower(value)
+}
+
+
+/**
+ * A frequently edited file, aggregated across sessions.
+ */
+public struct HotFile {
+    /**
+     * Absolute path to the file
+     */
+    public var filePath: String
+    /**
+     * Number of write-type tool uses (Edit, Write, NotebookEdit)
+     */
+    public var editCount: UInt32
+    /**
+     * ISO 8601 timestamp of the most recent edit
+     */
+    public var lastEditedAt: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Absolute path to the file
+         */filePath: String, 
+        /**
+         * Number of write-type tool uses (Edit, Write, NotebookEdit)
+         */editCount: UInt32, 
+        /**
+         * ISO 8601 timestamp of the most recent edit
+         */lastEditedAt: String) {
+        self.filePath = filePath
+        self.editCount = editCount
+        self.lastEditedAt = lastEditedAt
+    }
+}
+
+
+
+extension HotFile: Equatable, Hashable {
+    public static func ==(lhs: HotFile, rhs: HotFile) -> Bool {
+        if lhs.filePath != rhs.filePath {
+            return false
+        }
+        if lhs.editCount != rhs.editCount {
+            return false
+        }
+        if lhs.lastEditedAt != rhs.lastEditedAt {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(filePath)
+        hasher.combine(editCount)
+        hasher.combine(lastEditedAt)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHotFile: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HotFile {
+        return
+            try HotFile(
+                filePath: FfiConverterString.read(from: &buf), 
+                editCount: FfiConverterUInt32.read(from: &buf), 
+                lastEditedAt: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HotFile, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.filePath, into: &buf)
+        FfiConverterUInt32.write(value.editCount, into: &buf)
+        FfiConverterString.write(value.lastEditedAt, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHotFile_lift(_ buf: RustBuffer) throws -> HotFile {
+    return try FfiConverterTypeHotFile.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHotFile_lower(_ value: HotFile) -> RustBuffer {
+    return FfiConverterTypeHotFile.lower(value)
+}
+
+
+/**
+ * HUD configuration (pinned projects, etc.)
+ */
+public struct HudConfig {
+    public var pinnedProjects: [String]
+    public var terminalApp: String
+    /**
+     * When true, `hud-hook status-gen-log` appends the status generation hook's
+     * `claude -p` output to `~/.capacitor/status-gen.log`. Off by default.
+     */
+    public var statusGenLogging: Bool
+    /**
+     * Session files larger than this are skipped when computing stats, so huge
+     * transcripts can't stall the dashboard. None (the default) means no limit.
+     */
+    public var maxParseBytes: UInt64?
+    /**
+     * When set, only the N most recently modified `~/.claude/projects` folders
+     * are scanned for suggestions. None (the default) scans all of them.
+     */
+    public var maxScanFolders: UInt32?
+    /**
+     * Store the stats cache as MessagePack (`stats-cache.bin`) instead of JSON.
+     * Faster to load for users with hundreds of cached projects.
+     */
+    public var binaryStatsCache: Bool
+    /**
+     * Seconds a locked session may sit in Ready before it shows as Idle.
+     * None (the default) keeps locked sessions Ready until they end.
+     */
+    public var idleThresholdSecs: UInt64?
+    /**
+     * Seconds without a hook update before a session record is considered stale.
+     * None uses the default of 300.
+     */
+    public var staleThresholdSecs: UInt64?
+    /**
+     * Seconds a lockless Working/Waiting record is trusted before it falls back
+     * to Ready. None uses the default of 30.
+     */
+    public var activeStateStaleSecs: UInt64?
+    /**
+     * Monthly spend limit in USD for `budget_status`. None disables budget warnings.
+     */
+    public var monthlyBudgetUsd: Double?
+    /**
+     * Per-project shell command used instead of `cd <path> && claude` when
+     * launching, keyed by project path. `{path}` is replaced with the quoted path.
+     */
+    public var launchCommands: [String: String]
+    /**
+     * Keep pinned projects in `pinned_projects` order instead of sorting them
+     * by most recent activity. Off by default.
+     */
+    public var manualOrder: Bool
+    /**
+     * User-chosen display names keyed by project path, used in place of the
+     * folder name (e.g. to tell apart several `web` directories).
+     */
+    public var projectAliases: [String: String]
+    /**
+     * Plugin artifacts the user switched off, keyed by plugin id. Entries are
+     * `<type>:<name>`, e.g. `skill:deploy`.
+     */
+    public var disabledArtifacts: [String: [String]]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(pinnedProjects: [String], terminalApp: String, 
+        /**
+         * When true, `hud-hook status-gen-log` appends the status generation hook's
+         * `claude -p` output to `~/.capacitor/status-gen.log`. Off by default.
+         */statusGenLogging: Bool, 
+        /**
+         * Session files larger than this are skipped when computing stats, so huge
+         * transcripts can't stall the dashboard. None (the default) means no limit.
+         */maxParseBytes: UInt64?, 
+        /**
+         * When set, only the N most recently modified `~/.claude/projects` folders
+         * are scanned for suggestions. None (the default) scans all of them.
+         */maxScanFolders: UInt32?, 
+        /**
+         * Store the stats cache as MessagePack (`stats-cache.bin`) instead of JSON.
+         * Faster to load for users with hundreds of cached projects.
+         */binaryStatsCache: Bool, 
+        /**
+         * Seconds a locked session may sit in Ready before it shows as Idle.
+         * None (the default) keeps locked sessions Ready until they end.
+         */idleThresholdSecs: UInt64?, 
+        /**
+         * Seconds without a hook update before a session record is considered stale.
+         * None uses the default of 300.
+         */staleThresholdSecs: UInt64?, 
+        /**
+         * Seconds a lockless Working/Waiting record is trusted before it falls back
+         * to Ready. None uses the default of 30.
+         */activeStateStaleSecs: UInt64?, 
+        /**
+         * Monthly spend limit in USD for `budget_status`. None disables budget warnings.
+         */monthlyBudgetUsd: Double?, 
+        /**
+         * Per-project shell command used instead of `cd <path> && claude` when
+         * launching, keyed by project path. `{path}` is replaced with the quoted path.
+         */launchCommands: [String: String], 
+        /**
+         * Keep pinned projects in `pinned_projects` order instead of sorting them
+         * by most recent activity. Off by default.
+         */manualOrder: Bool, 
+        /**
+         * User-chosen display names keyed by project path, used in place of the
+         * folder name (e.g. to tell apart several `web` directories).
+         */projectAliases: [String: String], 
+        /**
+         * Plugin artifacts the user switched off, keyed by plugin id. Entries are
+         * `<type>:<name>`, e.g. `skill:deploy`.
+         */disabledArtifacts: [String: [String]]) {
+        self.pinnedProjects = pinnedProjects
+        self.terminalApp = terminalApp
+        self.statusGenLogging = statusGenLogging
+        self.maxParseBytes = maxParseBytes
+        self.maxScanFolders = maxScanFolders
+        self.binaryStatsCache = binaryStatsCache
+        self.idleThresholdSecs = idleThresholdSecs
+        self.staleThresholdSecs = staleThresholdSecs
+        self.activeStateStaleSecs = activeStateStaleSecs
+        self.monthlyBudgetUsd = monthlyBudgetUsd
+        self.launchCommands = launchCommands
+        self.manualOrder = manualOrder
+        self.projectAliases = projectAliases
+        self.disabledArtifacts = disabledArtifacts
+    }
+}
+
+
+
+extension HudConfig: Equatable, Hashable {
+    public static func ==(lhs: HudConfig, rhs: HudConfig) -> Bool {
+        if lhs.pinnedProjects != rhs.pinnedProjects {
+            return false
+        }
+        if lhs.terminalApp != rhs.terminalApp {
+            return false
+        }
+        if lhs.statusGenLogging != rhs.statusGenLogging {
+            return false
+        }
+        if lhs.maxParseBytes != rhs.maxParseBytes {
+            return false
+        }
+        if lhs.maxScanFolders != rhs.maxScanFolders {
+            return false
+        }
+        if lhs.binaryStatsCache != rhs.binaryStatsCache {
+            return false
+        }
+        if lhs.idleThresholdSecs != rhs.idleThresholdSecs {
+            return false
+        }
+        if lhs.staleThresholdSecs != rhs.staleThresholdSecs {
+            return false
+        }
+        if lhs.activeStateStaleSecs != rhs.activeStateStaleSecs {
+            return false
+        }
+        if lhs.monthlyBudgetUsd != rhs.monthlyBudgetUsd {
+            return false
+        }
+        if lhs.launchCommands != rhs.launchCommands {
+            return false
+        }
+        if lhs.manualOrder != rhs.manualOrder {
+            return false
+        }
+        if lhs.projectAliases != rhs.projectAliases {
+            return false
+        }
+        if lhs.disabledArtifacts != rhs.disabledArtifacts {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(pinnedProjects)
+        hasher.combine(terminalApp)
+        hasher.combine(statusGenLogging)
+        hasher.combine(maxParseBytes)
+        hasher.combine(maxScanFolders)
+        hasher.combine(binaryStatsCache)
+        hasher.combine(idleThresholdSecs)
+        hasher.combine(staleThresholdSecs)
+        hasher.combine(activeStateStaleSecs)
+        hasher.combine(monthlyBudgetUsd)
+        hasher.combine(launchCommands)
+        hasher.combine(manualOrder)
+        hasher.combine(projectAliases)
+        hasher.combine(disabledArtifacts)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHudConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HudConfig {
+        return
+            try HudConfig(
+                pinnedProjects: FfiConverterSequenceString.read(from: &buf), 
+                terminalApp: FfiConverterString.read(from: &buf), 
+                statusGenLogging: FfiConverterBool.read(from: &buf), 
+                maxParseBytes: FfiConverterOptionUInt64.read(from: &buf), 
+                maxScanFolders: FfiConverterOptionUInt32.read(from: &buf), 
+                binaryStatsCache: FfiConverterBool.read(from: &buf), 
+                idleThresholdSecs: FfiConverterOptionUInt64.read(from: &buf), 
+                staleThresholdSecs: FfiConverterOptionUInt64.read(from: &buf), 
+                activeStateStaleSecs: FfiConverterOptionUInt64.read(from: &buf), 
+                monthlyBudgetUsd: FfiConverterOptionDouble.read(from: &buf), 
+                launchCommands: FfiConverterDictionaryStringString.read(from: &buf), 
+                manualOrder: FfiConverterBool.read(from: &buf), 
+                projectAliases: FfiConverterDictionaryStringString.read(from: &buf), 
+                disabledArtifacts: FfiConverterDictionaryStringSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HudConfig, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.pinnedProjects, into: &buf)
+        FfiConverterString.write(value.terminalApp, into: &buf)
+        FfiConverterBool.write(value.statusGenLogging, into: &buf)
+        FfiConverterOptionUInt64.write(value.maxParseBytes, into: &buf)
+        FfiConverterOptionUInt32.write(value.maxScanFolders, into: &buf)
+        FfiConverterBool.write(value.binaryStatsCache, into: &buf)
+        FfiConverterOptionUInt64.write(value.idleThresholdSecs, into: &buf)
+        FfiConverterOptionUInt64.write(value.staleThresholdSecs, into: &buf)
+        FfiConverterOptionUInt64.write(value.activeStateStaleSecs, into: &buf)
+        FfiConverterOptionDouble.write(value.monthlyBudgetUsd, into: &buf)
+        FfiConverterDictionaryStringString.write(value.launchCommands, into: &buf)
+        FfiConverterBool.write(value.manualOrder, into: &buf)
+        FfiConverterDictionaryStringString.write(value.projectAliases, into: &buf)
+        FfiConverterDictionaryStringSequenceString.write(value.disabledArtifacts, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHudConfig_lift(_ buf: RustBuffer) throws -> HudConfig {
+    return try FfiConverterTypeHudConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHudConfig_lower(_ value: HudConfig) -> RustBuffer {
+    return FfiConverterTypeHudConfig.lower(value)
+}
+
+
+/**
+ * A captured idea stored in `~/.capacitor/projects/{encoded}/ideas.md`.
+ *
+ * Ideas are stored in markdown format with ULID identifiers for stable references.
+ * They can be in various states (open, in-progress, done) and have triage status.
+ */
+public struct Idea {
+    /**
+     * ULID identifier (26 chars, uppercase, sortable)
+     */
+    public var id: String
+    /**
+     * Short title extracted from first line
+     */
+    public var title: String
+    /**
+     * Full description text
+     */
+    public var description: String
+    /**
+     * ISO8601 timestamp when added
+     */
+    public var added: String
+    /**
+     * Effort estimate: unknown, small, medium, large, xl
+     */
+    public var effort: String
+    /**
+     * Status: open, in-progress, done
+     */
+    public var status: String
+    /**
+     * Triage status: pending, validated
+     */
+    public var triage: String
+    /**
+     * Related project name (if associated with a specific project)
+     */
+    public var related: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * ULID identifier (26 chars, uppercase, sortable)
+         */id: String, 
+        /**
+         * Short title extracted from first line
+         */title: String, 
+        /**
+         * Full description text
+         */description: String, 
+        /**
+         * ISO8601 timestamp when added
+         */added: String, 
+        /**
+         * Effort estimate: unknown, small, medium, large, xl
+         */effort: String, 
+        /**
+         * Status: open, in-progress, done
+         */status: String, 
+        /**
+         * Triage status: pending, validated
+         */triage: String, 
+        /**
+         * Related project name (if associated with a specific project)
+         */related: String?) {
+        self.id = id
+        self.title = title
+        self.description = description
+        self.added = added
+        self.effort = effort
+        self.status = status
+        self.triage = triage
+        self.related = related
+    }
+}
+
+
+
+extension Idea: Equatable, Hashable {
+    public static func ==(lhs: Idea, rhs: Idea) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.description != rhs.description {
+            return false
+        }
+        if lhs.added != rhs.added {
+            return false
+        }
+        if lhs.effort != rhs.effort {
+            return false
+        }
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.triage != rhs.triage {
+            return false
+        }
+        if lhs.related != rhs.related {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(title)
+        hasher.combine(description)
+        hasher.combine(added)
+        hasher.combine(effort)
+        hasher.combine(status)
+        hasher.combine(triage)
+        hasher.combine(related)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeIdea: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Idea {
+        return
+            try Idea(
+                id: FfiConverterString.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf), 
+                description: FfiConverterString.read(from: &buf), 
+                added: FfiConverterString.read(from: &buf), 
+                effort: FfiConverterString.read(from: &buf), 
+                status: FfiConverterString.read(from: &buf), 
+                triage: FfiConverterString.read(from: &buf), 
+                related: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Idea, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterString.write(value.description, into: &buf)
+        FfiConverterString.write(value.added, into: &buf)
+        FfiConverterString.write(value.effort, into: &buf)
+        FfiConverterString.write(value.status, into: &buf)
+        FfiConverterString.write(value.triage, into: &buf)
+        FfiConverterOptionString.write(value.related, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIdea_lift(_ buf: RustBuffer) throws -> Idea {
+    return try FfiConverterTypeIdea.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIdea_lower(_ value: Idea) -> RustBuffer {
+    return FfiConverterTypeIdea.lower(value)
+}
+
+
+public struct InstallResult {
+    public var success: Bool
+    public var message: String
+    public var scriptPath: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(success: Bool, message: String, scriptPath: String?) {
+        self.success = success
+        self.message = message
+        self.scriptPath = scriptPath
+    }
+}
+
+
+
+extension InstallResult: Equatable, Hashable {
+    public static func ==(lhs: InstallResult, rhs: InstallResult) -> Bool {
+        if lhs.success != rhs.success {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        if lhs.scriptPath != rhs.scriptPath {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(success)
+        hasher.combine(message)
+        hasher.combine(scriptPath)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeInstallResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InstallResult {
+        return
+            try InstallResult(
+                success: FfiConverterBool.read(from: &buf), 
+                message: FfiConverterString.read(from: &buf), 
+                scriptPath: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: InstallResult, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.success, into: &buf)
+        FfiConverterString.write(value.message, into: &buf)
+        FfiConverterOptionString.write(value.scriptPath, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInstallResult_lift(_ buf: RustBuffer) throws -> InstallResult {
+    return try FfiConverterTypeInstallResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInstallResult_lower(_ value: InstallResult) -> RustBuffer {
+    return FfiConverterTypeInstallResult.lower(value)
+}
+
+
+/**
+ * Something a maintenance command removed, or would remove in a dry run.
+ */
+public struct MaintenanceItem {
+    /**
+     * File or directory path; for activity entries, the edited file's path
+     */
+    public var path: String
+    /**
+     * Bytes on disk, 0 for entries inside a shared file
+     */
+    public var sizeBytes: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * File or directory path; for activity entries, the edited file's path
+         */path: String, 
+        /**
+         * Bytes on disk, 0 for entries inside a shared file
+         */sizeBytes: UInt64) {
+        self.path = path
+        self.sizeBytes = sizeBytes
+    }
+}
+
+
+
+extension MaintenanceItem: Equatable, Hashable {
+    public static func ==(lhs: MaintenanceItem, rhs: MaintenanceItem) -> Bool {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.sizeBytes != rhs.sizeBytes {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(path)
+        hasher.combine(sizeBytes)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMaintenanceItem: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MaintenanceItem {
+        return
+            try MaintenanceItem(
+                path: FfiConverterString.read(from: &buf), 
+                sizeBytes: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: MaintenanceItem, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterUInt64.write(value.sizeBytes, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMaintenanceItem_lift(_ buf: RustBuffer) throws -> MaintenanceItem {
+    return try FfiConverterTypeMaintenanceItem.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMaintenanceItem_lower(_ value: MaintenanceItem) -> RustBuffer {
+    return FfiConverterTypeMaintenanceItem.lower(value)
+}
+
+
+/**
+ * Result of a maintenance command, shared by every command that takes `dry_run`.
+ */
+public struct MaintenanceReport {
+    public var dryRun: Bool
+    /**
+     * Items removed, or that would be removed when `dry_run` is true
+     */
+    public var items: [MaintenanceItem]
+    public var errors: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(dryRun: Bool, 
+        /**
+         * Items removed, or that would be removed when `dry_run` is true
+         */items: [MaintenanceItem], errors: [String]) {
+        self.dryRun = dryRun
+        self.items = items
+        self.errors = errors
+    }
+}
+
+
+
+extension MaintenanceReport: Equatable, Hashable {
+    public static func ==(lhs: MaintenanceReport, rhs: MaintenanceReport) -> Bool {
+        if lhs.dryRun != rhs.dryRun {
+            return false
+        }
+        if lhs.items != rhs.items {
+            return false
+        }
+        if lhs.errors != rhs.errors {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(dryRun)
+        hasher.combine(items)
+        hasher.combine(errors)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMaintenanceReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MaintenanceReport {
+        return
+            try MaintenanceReport(
+                dryRun: FfiConverterBool.read(from: &buf), 
+                items: FfiConverterSequenceTypeMaintenanceItem.read(from: &buf), 
+                errors: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: MaintenanceReport, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.dryRun, into: &buf)
+        FfiConverterSequenceTypeMaintenanceItem.write(value.items, into: &buf)
+        FfiConverterSequenceString.write(value.errors, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMaintenanceReport_lift(_ buf: RustBuffer) throws -> MaintenanceReport {
+    return try FfiConverterTypeMaintenanceReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMaintenanceReport_lower(_ value: MaintenanceReport) -> RustBuffer {
+    return FfiConverterTypeMaintenanceReport.lower(value)
+}
+
+
+/**
+ * An MCP server definition.
+ */
+public struct McpServer {
+    public var name: String
+    /**
+     * Executable for stdio servers, None for remote (url) servers
+     */
+    public var command: String?
+    public var args: [String]
+    /**
+     * Endpoint for http/sse servers
+     */
+    public var url: String?
+    public var scope: McpScope
+    /**
+     * File the server is defined in
+     */
+    public var configPath: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, 
+        /**
+         * Executable for stdio servers, None for remote (url) servers
+         */command: String?, args: [String], 
+        /**
+         * Endpoint for http/sse servers
+         */url: String?, scope: McpScope, 
+        /**
+         * File the server is defined in
+         */configPath: String) {
+        self.name = name
+        self.command = command
+        self.args = args
+        self.url = url
+        self.scope = scope
+        self.configPath = configPath
+    }
+}
+
+
+
+extension McpServer: Equatable, Hashable {
+    public static func ==(lhs: McpServer, rhs: McpServer) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.command != rhs.command {
+            return false
+        }
+        if lhs.args != rhs.args {
+            return false
+        }
+        if lhs.url != rhs.url {
+            return false
+        }
+        if lhs.scope != rhs.scope {
+            return false
+        }
+        if lhs.configPath != rhs.configPath {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(command)
+        hasher.combine(args)
+        hasher.combine(url)
+        hasher.combine(scope)
+        hasher.combine(configPath)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMcpServer: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> McpServer {
+        return
+            try McpServer(
+                name: FfiConverterString.read(from: &buf), 
+                command: FfiConverterOptionString.read(from: &buf), 
+                args: FfiConverterSequenceString.read(from: &buf), 
+                url: FfiConverterOptionString.read(from: &buf), 
+                scope: FfiConverterTypeMcpScope.read(from: &buf), 
+                configPath: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: McpServer, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterOptionString.write(value.command, into: &buf)
+        FfiConverterSequenceString.write(value.args, into: &buf)
+        FfiConverterOptionString.write(value.url, into: &buf)
+        FfiConverterTypeMcpScope.write(value.scope, into: &buf)
+        FfiConverterString.write(value.configPath, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMcpServer_lift(_ buf: RustBuffer) throws -> McpServer {
+    return try FfiConverterTypeMcpServer.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMcpServer_lower(_ value: McpServer) -> RustBuffer {
+    return FfiConverterTypeMcpServer.lower(value)
+}
+
+
+/**
+ * Token usage and estimated cost for one model family within a period.
+ */
+public struct ModelPeriodUsage {
+    public var family: ModelFamily
+    public var inputTokens: UInt64
+    public var outputTokens: UInt64
+    public var cacheReadTokens: UInt64
+    public var cacheCreationTokens: UInt64
+    public var costUsd: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(family: ModelFamily, inputTokens: UInt64, outputTokens: UInt64, cacheReadTokens: UInt64, cacheCreationTokens: UInt64, costUsd: Double) {
+        self.family = family
+        self.inputTokens = inputTokens
+        self.outputTokens = outputTokens
+        self.cacheReadTokens = cacheReadTokens
+        self.cacheCreationTokens = cacheCreationTokens
+        self.costUsd = costUsd
+    }
+}
+
+
+
+extension ModelPeriodUsage: Equatable, Hashable {
+    public static func ==(lhs: ModelPeriodUsage, rhs: ModelPeriodUsage) -> Bool {
+        if lhs.family != rhs.family {
+            return false
+        }
+        if lhs.inputTokens != rhs.inputTokens {
+            return false
+        }
+        if lhs.outputTokens != rhs.outputTokens {
+            return false
+        }
+        if lhs.cacheReadTokens != rhs.cacheReadTokens {
+            return false
+        }
+        if lhs.cacheCreationTokens != rhs.cacheCreationTokens {
+            return false
+        }
+        if lhs.costUsd != rhs.costUsd {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(family)
+        hasher.combine(inputTokens)
+        hasher.combine(outputTokens)
+        hasher.combine(cacheReadTokens)
+        hasher.combine(cacheCreationTokens)
+        hasher.combine(costUsd)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeModelPeriodUsage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ModelPeriodUsage {
+        return
+            try ModelPeriodUsage(
+                family: FfiConverterTypeModelFamily.read(from: &buf), 
+                inputTokens: FfiConverterUInt64.read(from: &buf), 
+                outputTokens: FfiConverterUInt64.read(from: &buf), 
+                cacheReadTokens: FfiConverterUInt64.read(from: &buf), 
+                cacheCreationTokens: FfiConverterUInt64.read(from: &buf), 
+                costUsd: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ModelPeriodUsage, into buf: inout [UInt8]) {
+        FfiConverterTypeModelFamily.write(value.family, into: &buf)
+        FfiConverterUInt64.write(value.inputTokens, into: &buf)
+        FfiConverterUInt64.write(value.outputTokens, into: &buf)
+        FfiConverterUInt64.write(value.cacheReadTokens, into: &buf)
+        FfiConverterUInt64.write(value.cacheCreationTokens, into: &buf)
+        FfiConverterDouble.write(value.costUsd, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeModelPeriodUsage_lift(_ buf: RustBuffer) throws -> ModelPeriodUsage {
+    return try FfiConverterTypeModelPeriodUsage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeModelPeriodUsage_lower(_ value: ModelPeriodUsage) -> RustBuffer {
+    return FfiConverterTypeModelPeriodUsage.lower(value)
+}
+
+
+/**
+ * Token usage (input + output) in one time bucket, split by model family.
+ */
+public struct ModelUsagePoint {
+    /**
+     * First day of the bucket (YYYY-MM-DD, UTC)
+     */
+    public var bucketStart: String
+    public var opusTokens: UInt64
+    public var sonnetTokens: UInt64
+    public var haikuTokens: UInt64
+    /**
+     * Models outside the known families
+     */
+    public var otherTokens: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * First day of the bucket (YYYY-MM-DD, UTC)
+         */bucketStart: String, opusTokens: UInt64, sonnetTokens: UInt64, haikuTokens: UInt64, 
+        /**
+         * Models outside the known families
+         */otherTokens: UInt64) {
+        self.bucketStart = bucketStart
+        self.opusTokens = opusTokens
+        self.sonnetTokens = sonnetTokens
+        self.haikuTokens = haikuTokens
+        self.otherTokens = otherTokens
+    }
+}
+
+
+
+extension ModelUsagePoint: Equatable, Hashable {
+    public static func ==(lhs: ModelUsagePoint, rhs: ModelUsagePoint) -> Bool {
+        if lhs.bucketStart != rhs.bucketStart {
+            return false
+        }
+        if lhs.opusTokens != rhs.opusTokens {
+            return false
+        }
+        if lhs.sonnetTokens != rhs.sonnetTokens {
+            return false
+        }
+        if lhs.haikuTokens != rhs.haikuTokens {
+            return false
+        }
+        if lhs.otherTokens != rhs.otherTokens {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(bucketStart)
+        hasher.combine(opusTokens)
+        hasher.combine(sonnetTokens)
+        hasher.combine(haikuTokens)
+        hasher.combine(otherTokens)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeModelUsagePoint: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ModelUsagePoint {
+        return
+            try ModelUsagePoint(
+                bucketStart: FfiConverterString.read(from: &buf), 
+                opusTokens: FfiConverterUInt64.read(from: &buf), 
+                sonnetTokens: FfiConverterUInt64.read(from: &buf), 
+                haikuTokens: FfiConverterUInt64.read(from: &buf), 
+                otherTokens: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ModelUsagePoint, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.bucketStart, into: &buf)
+        FfiConverterUInt64.write(value.opusTokens, into: &buf)
+        FfiConverterUInt64.write(value.sonnetTokens, into: &buf)
+        FfiConverterUInt64.write(value.haikuTokens, into: &buf)
+        FfiConverterUInt64.write(value.otherTokens, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeModelUsagePoint_lift(_ buf: RustBuffer) throws -> ModelUsagePoint {
+    return try FfiConverterTypeModelUsagePoint.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeModelUsagePoint_lower(_ value: ModelUsagePoint) -> RustBuffer {
+    return FfiConverterTypeModelUsagePoint.lower(value)
+}
+
+
+/**
+ * Request to create a new project from an idea.
+ */
+public struct NewProjectRequest {
+    public var name: String
+    public var description: String
+    public var location: String
+    public var language: String?
+    public var framework: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, description: String, location: String, language: String?, framework: String?) {
+        self.name = name
+        self.description = description
+        self.location = location
+        self.language = language
+        self.framework = framework
+    }
+}
+
+
+
+extension NewProjectRequest: Equatable, Hashable {
+    public static func ==(lhs: NewProjectRequest, rhs: NewProjectRequest) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.description != rhs.description {
+            return false
+        }
+        if lhs.location != rhs.location {
+            return false
+        }
+        if lhs.language != rhs.language {
+            return false
+        }
+        if lhs.framework != rhs.framework {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(description)
+        hasher.combine(location)
+        hasher.combine(language)
+        hasher.combine(framework)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNewProjectRequest: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NewProjectRequest {
+        return
+            try NewProjectRequest(
+                name: FfiConverterString.read(from: &buf), 
+                description: FfiConverterString.read(from: &buf), 
+                location: FfiConverterString.read(from: &buf), 
+                language: FfiConverterOptionString.read(from: &buf), 
+                framework: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: NewProjectRequest, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.description, into: &buf)
+        FfiConverterString.write(value.location, into: &buf)
+        FfiConverterOptionString.write(value.language, into: &buf)
+        FfiConverterOptionString.write(value.framework, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNewProjectRequest_lift(_ buf: RustBuffer) throws -> NewProjectRequest {
+    return try FfiConverterTypeNewProjectRequest.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNewProjectRequest_lower(_ value: NewProjectRequest) -> RustBuffer {
+    return FfiConverterTypeNewProjectRequest.lower(value)
+}
+
+
+/**
+ * A project's pending next step, from its `hud-status.json`.
+ */
+public struct NextStep {
+    public var projectPath: String
+    public var nextStep: String
+    public var updatedAt: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(projectPath: String, nextStep: String, updatedAt: String?) {
+        self.projectPath = projectPath
+        self.nextStep = nextStep
+        self.updatedAt = updatedAt
+    }
+}
+
+
+
+extension NextStep: Equatable, Hashable {
+    public static func ==(lhs: NextStep, rhs: NextStep) -> Bool {
+        if lhs.projectPath != rhs.projectPath {
+            return false
+        }
+        if lhs.nextStep != rhs.nextStep {
+            return false
+        }
+        if lhs.updatedAt != rhs.updatedAt {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(projectPath)
+        hasher.combine(nextStep)
+        hasher.combine(updatedAt)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNextStep: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NextStep {
+        return
+            try NextStep(
+                projectPath: FfiConverterString.read(from: &buf), 
+                nextStep: FfiConverterString.read(from: &buf), 
+                updatedAt: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: NextStep, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.projectPath, into: &buf)
+        FfiConverterString.write(value.nextStep, into: &buf)
+        FfiConverterOptionString.write(value.updatedAt, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNextStep_lift(_ buf: RustBuffer) throws -> NextStep {
+    return try FfiConverterTypeNextStep.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNextStep_lower(_ value: NextStep) -> RustBuffer {
+    return FfiConverterTypeNextStep.lower(value)
+}
+
+
+/**
+ * A running session whose working directory has been deleted.
+ */
+public struct OrphanedLiveSession {
+    /**
+     * The session's cwd, which no longer exists
+     */
+    public var path: String
+    public var pid: UInt32
+    /**
+     * None for legacy path-based locks
+     */
+    public var sessionId: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The session's cwd, which no longer exists
+         */path: String, pid: UInt32, 
+        /**
+         * None for legacy path-based locks
+         */sessionId: String?) {
+        self.path = path
+        self.pid = pid
+        self.sessionId = sessionId
+    }
+}
+
+
+
+extension OrphanedLiveSession: Equatable, Hashable {
+    public static func ==(lhs: OrphanedLiveSession, rhs: OrphanedLiveSession) -> Bool {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.pid != rhs.pid {
+            return false
+        }
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(path)
+        hasher.combine(pid)
+        hasher.combine(sessionId)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOrphanedLiveSession: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OrphanedLiveSession {
+        return
+            try OrphanedLiveSession(
+                path: FfiConverterString.read(from: &buf), 
+                pid: FfiConverterUInt32.read(from: &buf), 
+                sessionId: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OrphanedLiveSession, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterUInt32.write(value.pid, into: &buf)
+        FfiConverterOptionString.write(value.sessionId, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrphanedLiveSession_lift(_ buf: RustBuffer) throws -> OrphanedLiveSession {
+    return try FfiConverterTypeOrphanedLiveSession.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrphanedLiveSession_lower(_ value: OrphanedLiveSession) -> RustBuffer {
+    return FfiConverterTypeOrphanedLiveSession.lower(value)
+}
+
+
+/**
+ * Two pinned projects where one contains the other, e.g. `repo` and `repo/app`.
+ */
+public struct OverlappingPins {
+    public var ancestor: String
+    public var descendant: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(ancestor: String, descendant: String) {
+        self.ancestor = ancestor
+        self.descendant = descendant
+    }
+}
+
+
+
+extension OverlappingPins: Equatable, Hashable {
+    public static func ==(lhs: OverlappingPins, rhs: OverlappingPins) -> Bool {
+        if lhs.ancestor != rhs.ancestor {
+            return false
+        }
+        if lhs.descendant != rhs.descendant {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(ancestor)
+        hasher.combine(descendant)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOverlappingPins: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OverlappingPins {
+        return
+            try OverlappingPins(
+                ancestor: FfiConverterString.read(from: &buf), 
+                descendant: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OverlappingPins, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.ancestor, into: &buf)
+        FfiConverterString.write(value.descendant, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOverlappingPins_lift(_ buf: RustBuffer) throws -> OverlappingPins {
+    return try FfiConverterTypeOverlappingPins.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOverlappingPins_lower(_ value: OverlappingPins) -> RustBuffer {
+    return FfiConverterTypeOverlappingPins.lower(value)
+}
+
+
+/**
+ * Usage across all projects between two dates, for billing-period views.
+ */
+public struct PeriodUsage {
+    /**
+     * First day included (YYYY-MM-DD, UTC)
+     */
+    public var start: String
+    /**
+     * Last day included (YYYY-MM-DD, UTC)
+     */
+    public var end: String
+    public var inputTokens: UInt64
+    public var outputTokens: UInt64
+    public var cacheReadTokens: UInt64
+    public var cacheCreationTokens: UInt64
+    public var costUsd: Double
+    /**
+     * Families with any usage in the period, largest cost first
+     */
+    public var models: [ModelPeriodUsage]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * First day included (YYYY-MM-DD, UTC)
+         */start: String, 
+        /**
+         * Last day included (YYYY-MM-DD, UTC)
+         */end: String, inputTokens: UInt64, outputTokens: UInt64, cacheReadTokens: UInt64, cacheCreationTokens: UInt64, costUsd: Double, 
+        /**
+         * Families with any usage in the period, largest cost first
+         */models: [ModelPeriodUsage]) {
+        self.start = start
+        self.end = end
+        self.inputTokens = inputTokens
+        self.outputTokens = outputTokens
+        self.cacheReadTokens = cacheReadTokens
+        self.cacheCreationTokens = cacheCreationTokens
+        self.costUsd = costUsd
+        self.models = models
+    }
+}
+
+
+
+extension PeriodUsage: Equatable, Hashable {
+    public static func ==(lhs: PeriodUsage, rhs: PeriodUsage) -> Bool {
+        if lhs.start != rhs.start {
+            return false
+        }
+        if lhs.end != rhs.end {
+            return false
+        }
+        if lhs.inputTokens != rhs.inputTokens {
+            return false
+        }
+        if lhs.outputTokens != rhs.outputTokens {
+            return false
+        }
+        if lhs.cacheReadTokens != rhs.cacheReadTokens {
+            return false
+        }
+        if lhs.cacheCreationTokens != rhs.cacheCreationTokens {
+            return false
+        }
+        if lhs.costUsd != rhs.costUsd {
+            return false
+        }
+        if lhs.models != rhs.models {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(start)
+        hasher.combine(end)
+        hasher.combine(inputTokens)
+        hasher.combine(outputTokens)
+        hasher.combine(cacheReadTokens)
+        hasher.combine(cacheCreationTokens)
+        hasher.combine(costUsd)
+        hasher.combine(models)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePeriodUsage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PeriodUsage {
+        return
+            try PeriodUsage(
+                start: FfiConverterString.read(from: &buf), 
+                end: FfiConverterString.read(from: &buf), 
+                inputTokens: FfiConverterUInt64.read(from: &buf), 
+                outputTokens: FfiConverterUInt64.read(from: &buf), 
+                cacheReadTokens: FfiConverterUInt64.read(from: &buf), 
+                cacheCreationTokens: FfiConverterUInt64.read(from: &buf), 
+                costUsd: FfiConverterDouble.read(from: &buf), 
+                models: FfiConverterSequenceTypeModelPeriodUsage.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PeriodUsage, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.start, into: &buf)
+        FfiConverterString.write(value.end, into: &buf)
+        FfiConverterUInt64.write(value.inputTokens, into: &buf)
+        FfiConverterUInt64.write(value.outputTokens, into: &buf)
+        FfiConverterUInt64.write(value.cacheReadTokens, into: &buf)
+        FfiConverterUInt64.write(value.cacheCreationTokens, into: &buf)
+        FfiConverterDouble.write(value.costUsd, into: &buf)
+        FfiConverterSequenceTypeModelPeriodUsage.write(value.models, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePeriodUsage_lift(_ buf: RustBuffer) throws -> PeriodUsage {
+    return try FfiConverterTypePeriodUsage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePeriodUsage_lower(_ value: PeriodUsage) -> RustBuffer {
+    return FfiConverterTypePeriodUsage.lower(value)
+}
+
+
+/**
+ * One permission prompt (or refusal) in a session.
+ */
+public struct PermissionEntry {
+    /**
+     * ISO 8601 timestamp of the tool use
+     */
+    public var timestamp: String
+    public var toolName: String
+    /**
+     * Short description of the target (command, file path, or URL)
+     */
+    public var detail: String?
+    public var outcome: PermissionOutcome
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * ISO 8601 timestamp of the tool use
+         */timestamp: String, toolName: String, 
+        /**
+         * Short description of the target (command, file path, or URL)
+         */detail: String?, outcome: PermissionOutcome) {
+        self.timestamp = timestamp
+        self.toolName = toolName
+        self.detail = detail
+        self.outcome = outcome
+    }
+}
+
+
+
+extension PermissionEntry: Equatable, Hashable {
+    public static func ==(lhs: PermissionEntry, rhs: PermissionEntry) -> Bool {
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        if lhs.toolName != rhs.toolName {
+            return false
+        }
+        if lhs.detail != rhs.detail {
+            return false
+        }
+        if lhs.outcome != rhs.outcome {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(timestamp)
+        hasher.combine(toolName)
+        hasher.combine(detail)
+        hasher.combine(outcome)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePermissionEntry: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PermissionEntry {
+        return
+            try PermissionEntry(
+                timestamp: FfiConverterString.read(from: &buf), 
+                toolName: FfiConverterString.read(from: &buf), 
+                detail: FfiConverterOptionString.read(from: &buf), 
+                outcome: FfiConverterTypePermissionOutcome.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PermissionEntry, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.timestamp, into: &buf)
+        FfiConverterString.write(value.toolName, into: &buf)
+        FfiConverterOptionString.write(value.detail, into: &buf)
+        FfiConverterTypePermissionOutcome.write(value.outcome, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePermissionEntry_lift(_ buf: RustBuffer) throws -> PermissionEntry {
+    return try FfiConverterTypePermissionEntry.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePermissionEntry_lower(_ value: PermissionEntry) -> RustBuffer {
+    return FfiConverterTypePermissionEntry.lower(value)
+}
+
+
+/**
+ * A session and how many permission prompts it raised.
+ */
+public struct PermissionHeavySession {
+    public var sessionId: String
+    /**
+     * Working directory the session ran in
+     */
+    public var projectPath: String
+    public var promptCount: UInt32
+    /**
+     * Tools behind the prompts and denials, most frequent first
+     */
+    public var topTools: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(sessionId: String, 
+        /**
+         * Working directory the session ran in
+         */projectPath: String, promptCount: UInt32, 
+        /**
+         * Tools behind the prompts and denials, most frequent first
+         */topTools: [String]) {
+        self.sessionId = sessionId
+        self.projectPath = projectPath
+        self.promptCount = promptCount
+        self.topTools = topTools
+    }
+}
+
+
+
+extension PermissionHeavySession: Equatable, Hashable {
+    public static func ==(lhs: PermissionHeavySession, rhs: PermissionHeavySession) -> Bool {
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        if lhs.projectPath != rhs.projectPath {
+            return false
+        }
+        if lhs.promptCount != rhs.promptCount {
+            return false
+        }
+        if lhs.topTools != rhs.topTools {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(sessionId)
+        hasher.combine(projectPath)
+        hasher.combine(promptCount)
+        hasher.combine(topTools)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePermissionHeavySession: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PermissionHeavySession {
+        return
+            try PermissionHeavySession(
+                sessionId: FfiConverterString.read(from: &buf), 
+                projectPath: FfiConverterString.read(from: &buf), 
+                promptCount: FfiConverterUInt32.read(from: &buf), 
+                topTools: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PermissionHeavySession, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.sessionId, into: &buf)
+        FfiConverterString.write(value.projectPath, into: &buf)
+        FfiConverterUInt32.write(value.promptCount, into: &buf)
+        FfiConverterSequenceString.write(value.topTools, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePermissionHeavySession_lift(_ buf: RustBuffer) throws -> PermissionHeavySession {
+    return try FfiConverterTypePermissionHeavySession.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePermissionHeavySession_lower(_ value: PermissionHeavySession) -> RustBuffer {
+    return FfiConverterTypePermissionHeavySession.lower(value)
+}
+
+
+/**
+ * Whether a pinned project still exists, and where it may have moved.
+ */
+public struct PinnedProjectAudit {
+    public var path: String
+    public var exists: Bool
+    /**
+     * Best guess at the project's new location when it is missing
+     */
+    public var relocatedTo: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(path: String, exists: Bool, 
+        /**
+         * Best guess at the project's new location when it is missing
+         */relocatedTo: String?) {
+        self.path = path
+        self.exists = exists
+        self.relocatedTo = relocatedTo
+    }
+}
+
+
+
+extension PinnedProjectAudit: Equatable, Hashable {
+    public static func ==(lhs: PinnedProjectAudit, rhs: PinnedProjectAudit) -> Bool {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.exists != rhs.exists {
+            return false
+        }
+        if lhs.relocatedTo != rhs.relocatedTo {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(path)
+        hasher.combine(exists)
+        hasher.combine(relocatedTo)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePinnedProjectAudit: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PinnedProjectAudit {
+        return
+            try PinnedProjectAudit(
+                path: FfiConverterString.read(from: &buf), 
+                exists: FfiConverterBool.read(from: &buf), 
+                relocatedTo: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PinnedProjectAudit, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterBool.write(value.exists, into: &buf)
+        FfiConverterOptionString.write(value.relocatedTo, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePinnedProjectAudit_lift(_ buf: RustBuffer) throws -> PinnedProjectAudit {
+    return try FfiConverterTypePinnedProjectAudit.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePinnedProjectAudit_lower(_ value: PinnedProjectAudit) -> RustBuffer {
+    return FfiConverterTypePinnedProjectAudit.lower(value)
+}
+
+
+/**
+ * An installed Claude Code plugin.
+ */
+public struct Plugin {
+    public var id: String
+    public var name: String
+    public var description: String
+    public var enabled: Bool
+    public var path: String
+    public var skillCount: UInt32
+    public var commandCount: UInt32
+    public var agentCount: UInt32
+    public var hookCount: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, name: String, description: String, enabled: Bool, path: String, skillCount: UInt32, commandCount: UInt32, agentCount: UInt32, hookCount: UInt32) {
+        self.id = id
+        self.name = name
+        self.description = description
+        self.enabled = enabled
+        self.path = path
+        self.skillCount = skillCount
+        self.commandCount = commandCount
+        self.agentCount = agentCount
+        self.hookCount = hookCount
+    }
+}
+
+
+
+extension Plugin: Equatable, Hashable {
+    public static func ==(lhs: Plugin, rhs: Plugin) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.description != rhs.description {
+            return false
+        }
+        if lhs.enabled != rhs.enabled {
+            return false
+        }
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.skillCount != rhs.skillCount {
+            return false
+        }
+        if lhs.commandCount != rhs.commandCount {
+            return false
+        }
+        if lhs.agentCount != rhs.agentCount {
+            return false
+        }
+        if lhs.hookCount != rhs.hookCount {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(name)
+        hasher.combine(description)
+        hasher.combine(enabled)
+        hasher.combine(path)
+        hasher.combine(skillCount)
+        hasher.combine(commandCount)
+        hasher.combine(agentCount)
+        hasher.combine(hookCount)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePlugin: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Plugin {
+        return
+            try Plugin(
+                id: FfiConverterString.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                description: FfiConverterString.read(from: &buf), 
+                enabled: FfiConverterBool.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf), 
+                skillCount: FfiConverterUInt32.read(from: &buf), 
+                commandCount: FfiConverterUInt32.read(from: &buf), 
+                agentCount: FfiConverterUInt32.read(from: &buf), 
+                hookCount: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Plugin, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.description, into: &buf)
+        FfiConverterBool.write(value.enabled, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterUInt32.write(value.skillCount, into: &buf)
+        FfiConverterUInt32.write(value.commandCount, into: &buf)
+        FfiConverterUInt32.write(value.agentCount, into: &buf)
+        FfiConverterUInt32.write(value.hookCount, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePlugin_lift(_ buf: RustBuffer) throws -> Plugin {
+    return try FfiConverterTypePlugin.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePlugin_lower(_ value: Plugin) -> RustBuffer {
+    return FfiConverterTypePlugin.lower(value)
+}
+
+
+/**
+ * Plugin manifest from plugin.json.
+ */
+public struct PluginManifest {
+    public var name: String
+    public var description: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, description: String?) {
+        self.name = name
+        self.description = description
+    }
+}
+
+
+
+extension PluginManifest: Equatable, Hashable {
+    public static func ==(lhs: PluginManifest, rhs: PluginManifest) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.description != rhs.description {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(description)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePluginManifest: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PluginManifest {
+        return
+            try PluginManifest(
+                name: FfiConverterString.read(from: &buf), 
+                description: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PluginManifest, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterOptionString.write(value.description, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePluginManifest_lift(_ buf: RustBuffer) throws -> PluginManifest {
+    return try FfiConverterTypePluginManifest.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePluginManifest_lower(_ value: PluginManifest) -> RustBuffer {
+    return FfiConverterTypePluginManifest.lower(value)
+}
+
+
+/**
+ * A pinned project in the HUD.
+ */
+public struct Project {
+    public var name: String
+    public var path: String
+    public var displayPath: String
+    public var lastActive: String?
+    /**
+     * Path to the instructions file in use (CLAUDE.md, or AGENTS.md as a fallback).
+     */
+    public var claudeMdPath: String?
+    public var claudeMdPreview: String?
+    public var hasLocalSettings: Bool
+    public var taskCount: UInt32
+    public var stats: ProjectStats?
+    /**
+     * True if the project directory no longer exists on disk.
+     */
+    public var isMissing: Bool
+    /**
+     * File name of the instructions file in use ("CLAUDE.md" or "AGENTS.md").
+     */
+    public var instructionsFile: String?
+    /**
+     * True if the project has an AGENTS.md, even when CLAUDE.md takes precedence.
+     */
+    public var hasAgentsMd: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, path: String, displayPath: String, lastActive: String?, 
+        /**
+         * Path to the instructions file in use (CLAUDE.md, or AGENTS.md as a fallback).
+         */claudeMdPath: String?, claudeMdPreview: String?, hasLocalSettings: Bool, taskCount: UInt32, stats: ProjectStats?, 
+        /**
+         * True if the project directory no longer exists on disk.
+         */isMissing: Bool, 
+        /**
+         * File name of the instructions file in use ("CLAUDE.md" or "AGENTS.md").
+         */instructionsFile: String?, 
+        /**
+         * True if the project has an AGENTS.md, even when CLAUDE.md takes precedence.
+         */hasAgentsMd: Bool) {
+        self.name = name
+        self.path = path
+        self.displayPath = displayPath
+        self.lastActive = lastActive
+        self.claudeMdPath = claudeMdPath
+        self.claudeMdPreview = claudeMdPreview
+        self.hasLocalSettings = hasLocalSettings
+        self.taskCount = taskCount
+        self.stats = stats
+        self.isMissing = isMissing
+        self.instructionsFile = instructionsFile
+        self.hasAgentsMd = hasAgentsMd
+    }
+}
+
+
+
+extension Project: Equatable, Hashable {
+    public static func ==(lhs: Project, rhs: Project) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.displayPath != rhs.displayPath {
+            return false
+        }
+        if lhs.lastActive != rhs.lastActive {
+            return false
+        }
+        if lhs.claudeMdPath != rhs.claudeMdPath {
+            return false
+        }
+        if lhs.claudeMdPreview != rhs.claudeMdPreview {
+            return false
+        }
+        if lhs.hasLocalSettings != rhs.hasLocalSettings {
+            return false
+        }
+        if lhs.taskCount != rhs.taskCount {
+            return false
+        }
+        if lhs.stats != rhs.stats {
+            return false
+        }
+        if lhs.isMissing != rhs.isMissing {
+            return false
+        }
+        if lhs.instructionsFile != rhs.instructionsFile {
+            return false
+        }
+        if lhs.hasAgentsMd != rhs.hasAgentsMd {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(path)
+        hasher.combine(displayPath)
+        hasher.combine(lastActive)
+        hasher.combine(claudeMdPath)
+        hasher.combine(claudeMdPreview)
+        hasher.combine(hasLocalSettings)
+        hasher.combine(taskCount)
+        hasher.combine(stats)
+        hasher.combine(isMissing)
+        hasher.combine(instructionsFile)
+        hasher.combine(hasAgentsMd)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProject: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Project {
+        return
+            try Project(
+                name: FfiConverterString.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf), 
+                displayPath: FfiConverterString.read(from: &buf), 
+                lastActive: FfiConverterOptionString.read(from: &buf), 
+                claudeMdPath: FfiConverterOptionString.read(from: &buf), 
+                claudeMdPreview: FfiConverterOptionString.read(from: &buf), 
+                hasLocalSettings: FfiConverterBool.read(from: &buf), 
+                taskCount: FfiConverterUInt32.read(from: &buf), 
+                stats: FfiConverterOptionTypeProjectStats.read(from: &buf), 
+                isMissing: FfiConverterBool.read(from: &buf), 
+                instructionsFile: FfiConverterOptionString.read(from: &buf), 
+                hasAgentsMd: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Project, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterString.write(value.displayPath, into: &buf)
+        FfiConverterOptionString.write(value.lastActive, into: &buf)
+        FfiConverterOptionString.write(value.claudeMdPath, into: &buf)
+        FfiConverterOptionString.write(value.claudeMdPreview, into: &buf)
+        FfiConverterBool.write(value.hasLocalSettings, into: &buf)
+        FfiConverterUInt32.write(value.taskCount, into: &buf)
+        FfiConverterOptionTypeProjectStats.write(value.stats, into: &buf)
+        FfiConverterBool.write(value.isMissing, into: &buf)
+        FfiConverterOptionString.write(value.instructionsFile, into: &buf)
+        FfiConverterBool.write(value.hasAgentsMd, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProject_lift(_ buf: RustBuffer) throws -> Project {
+    return try FfiConverterTypeProject.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProject_lower(_ value: Project) -> RustBuffer {
+    return FfiConverterTypeProject.lower(value)
+}
+
+
+/**
+ * The config file to open for a project's "edit config" action.
+ */
+public struct ProjectConfigFile {
+    public var path: String
+    public var kind: ProjectConfigKind
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(path: String, kind: ProjectConfigKind) {
+        self.path = path
+        self.kind = kind
+    }
+}
+
+
+
+extension ProjectConfigFile: Equatable, Hashable {
+    public static func ==(lhs: ProjectConfigFile, rhs: ProjectConfigFile) -> Bool {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(path)
+        hasher.combine(kind)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProjectConfigFile: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectConfigFile {
+        return
+            try ProjectConfigFile(
+                path: FfiConverterString.read(from: &buf), 
+                kind: FfiConverterTypeProjectConfigKind.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ProjectConfigFile, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterTypeProjectConfigKind.write(value.kind, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectConfigFile_lift(_ buf: RustBuffer) throws -> ProjectConfigFile {
+    return try FfiConverterTypeProjectConfigFile.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectConfigFile_lower(_ value: ProjectConfigFile) -> RustBuffer {
+    return FfiConverterTypeProjectConfigFile.lower(value)
+}
+
+
+/**
+ * A project being created via the Idea → V1 flow.
+ */
+public struct ProjectCreation {
+    public var id: String
+    public var name: String
+    public var path: String
+    public var description: String
+    public var status: CreationStatus
+    public var sessionId: String?
+    public var progress: CreationProgress?
+    public var error: String?
+    public var createdAt: String
+    public var completedAt: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, name: String, path: String, description: String, status: CreationStatus, sessionId: String?, progress: CreationProgress?, error: String?, createdAt: String, completedAt: String?) {
+        self.id = id
+        self.name = name
+        self.path = path
+        self.description = description
+        self.status = status
+        self.sessionId = sessionId
+        self.progress = progress
+        self.error = error
+        self.createdAt = createdAt
+        self.completedAt = completedAt
+    }
+}
+
+
+
+extension ProjectCreation: Equatable, Hashable {
+    public static func ==(lhs: ProjectCreation, rhs: ProjectCreation) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.description != rhs.description {
+            return false
+        }
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        if lhs.progress != rhs.progress {
+            return false
+        }
+        if lhs.error != rhs.error {
+            return false
+        }
+        if lhs.createdAt != rhs.createdAt {
+            return false
+        }
+        if lhs.completedAt != rhs.completedAt {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(name)
+        hasher.combine(path)
+        hasher.combine(description)
+        hasher.combine(status)
+        hasher.combine(sessionId)
+        hasher.combine(progress)
+        hasher.combine(error)
+        hasher.combine(createdAt)
+        hasher.combine(completedAt)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProjectCreation: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectCreation {
+        return
+            try ProjectCreation(
+                id: FfiConverterString.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf), 
+                description: FfiConverterString.read(from: &buf), 
+                status: FfiConverterTypeCreationStatus.read(from: &buf), 
+                sessionId: FfiConverterOptionString.read(from: &buf), 
+                progress: FfiConverterOptionTypeCreationProgress.read(from: &buf), 
+                error: FfiConverterOptionString.read(from: &buf), 
+                createdAt: FfiConverterString.read(from: &buf), 
+                completedAt: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ProjectCreation, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterString.write(value.description, into: &buf)
+        FfiConverterTypeCreationStatus.write(value.status, into: &buf)
+        FfiConverterOptionString.write(value.sessionId, into: &buf)
+        FfiConverterOptionTypeCreationProgress.write(value.progress, into: &buf)
+        FfiConverterOptionString.write(value.error, into: &buf)
+        FfiConverterString.write(value.createdAt, into: &buf)
+        FfiConverterOptionString.write(value.completedAt, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectCreation_lift(_ buf: RustBuffer) throws -> ProjectCreation {
+    return try FfiConverterTypeProjectCreation.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectCreation_lower(_ value: ProjectCreation) -> RustBuffer {
+    return FfiConverterTypeProjectCreation.lower(value)
+}
+
+
+/**
+ * Detailed project information including tasks and git status.
+ */
+public struct ProjectDetails {
+    public var project: Project
+    public var claudeMdContent: String?
+    public var tasks: [Task]
+    public var gitBranch: String?
+    /**
+     * Full SHA HEAD points at, None for unborn branches and non-git projects
+     */
+    public var gitHeadCommit: String?
+    public var gitDirty: Bool
+    /**
+     * Number of stash entries, None for non-git projects
+     */
+    public var gitStashCount: UInt32?
+    /**
+     * Commits on HEAD not on its upstream; 0 without an upstream
+     */
+    public var gitAhead: UInt32
+    /**
+     * Commits on the upstream not on HEAD; 0 without an upstream
+     */
+    public var gitBehind: UInt32
+    /**
+     * Whether the branch tracks an upstream, so 0/0 means "in sync"
+     */
+    public var gitHasUpstream: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(project: Project, claudeMdContent: String?, tasks: [Task], gitBranch: String?, 
+        /**
+         * Full SHA HEAD points at, None for unborn branches and non-git projects
+         */gitHeadCommit: String?, gitDirty: Bool, 
+        /**
+         * Number of stash entries, None for non-git projects
+         */gitStashCount: UInt32?, 
+        /**
+         * Commits on HEAD not on its upstream; 0 without an upstream
+         */gitAhead: UInt32, 
+        /**
+         * Commits on the upstream not on HEAD; 0 without an upstream
+         */gitBehind: UInt32, 
+        /**
+         * Whether the branch tracks an upstream, so 0/0 means "in sync"
+         */gitHasUpstream: Bool) {
+        self.project = project
+        self.claudeMdContent = claudeMdContent
+        self.tasks = tasks
+        self.gitBranch = gitBranch
+        self.gitHeadCommit = gitHeadCommit
+        self.gitDirty = gitDirty
+        self.gitStashCount = gitStashCount
+        self.gitAhead = gitAhead
+        self.gitBehind = gitBehind
+        self.gitHasUpstream = gitHasUpstream
+    }
+}
+
+
+
+extension ProjectDetails: Equatable, Hashable {
+    public static func ==(lhs: ProjectDetails, rhs: ProjectDetails) -> Bool {
+        if lhs.project != rhs.project {
+            return false
+        }
+        if lhs.claudeMdContent != rhs.claudeMdContent {
+            return false
+        }
+        if lhs.tasks != rhs.tasks {
+            return false
+        }
+        if lhs.gitBranch != rhs.gitBranch {
+            return false
+        }
+        if lhs.gitHeadCommit != rhs.gitHeadCommit {
+            return false
+        }
+        if lhs.gitDirty != rhs.gitDirty {
+            return false
+        }
+        if lhs.gitStashCount != rhs.gitStashCount {
+            return false
+        }
+        if lhs.gitAhead != rhs.gitAhead {
+            return false
+        }
+        if lhs.gitBehind != rhs.gitBehind {
+            return false
+        }
+        if lhs.gitHasUpstream != rhs.gitHasUpstream {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(project)
+        hasher.combine(claudeMdContent)
+        hasher.combine(tasks)
+        hasher.combine(gitBranch)
+        hasher.combine(gitHeadCommit)
+        hasher.combine(gitDirty)
+        hasher.combine(gitStashCount)
+        hasher.combine(gitAhead)
+        hasher.combine(gitBehind)
+        hasher.combine(gitHasUpstream)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProjectDetails: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectDetails {
+        return
+            try ProjectDetails(
+                project: FfiConverterTypeProject.read(from: &buf), 
+                claudeMdContent: FfiConverterOptionString.read(from: &buf), 
+                tasks: FfiConverterSequenceTypeTask.read(from: &buf), 
+                gitBranch: FfiConverterOptionString.read(from: &buf), 
+                gitHeadCommit: FfiConverterOptionString.read(from: &buf), 
+                gitDirty: FfiConverterBool.read(from: &buf), 
+                gitStashCount: FfiConverterOptionUInt32.read(from: &buf), 
+                gitAhead: FfiConverterUInt32.read(from: &buf), 
+                gitBehind: FfiConverterUInt32.read(from: &buf), 
+                gitHasUpstream: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ProjectDetails, into buf: inout [UInt8]) {
+        FfiConverterTypeProject.write(value.project, into: &buf)
+        FfiConverterOptionString.write(value.claudeMdContent, into: &buf)
+        FfiConverterSequenceTypeTask.write(value.tasks, into: &buf)
+        FfiConverterOptionString.write(value.gitBranch, into: &buf)
+        FfiConverterOptionString.write(value.gitHeadCommit, into: &buf)
+        FfiConverterBool.write(value.gitDirty, into: &buf)
+        FfiConverterOptionUInt32.write(value.gitStashCount, into: &buf)
+        FfiConverterUInt32.write(value.gitAhead, into: &buf)
+        FfiConverterUInt32.write(value.gitBehind, into: &buf)
+        FfiConverterBool.write(value.gitHasUpstream, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectDetails_lift(_ buf: RustBuffer) throws -> ProjectDetails {
+    return try FfiConverterTypeProjectDetails.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectDetails_lower(_ value: ProjectDetails) -> RustBuffer {
+    return FfiConverterTypeProjectDetails.lower(value)
+}
+
+
+/**
+ * Result of auditing `try_resolve_encoded_path` against transcript data.
+ */
+public struct ProjectEncodingReport {
+    /**
+     * Folders with a transcript cwd to compare against
+     */
+    public var checked: UInt32
+    /**
+     * Folders skipped because no transcript recorded a cwd
+     */
+    public var withoutCwd: UInt32
+    public var mismatches: [EncodingMismatch]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Folders with a transcript cwd to compare against
+         */checked: UInt32, 
+        /**
+         * Folders skipped because no transcript recorded a cwd
+         */withoutCwd: UInt32, mismatches: [EncodingMismatch]) {
+        self.checked = checked
+        self.withoutCwd = withoutCwd
+        self.mismatches = mismatches
+    }
+}
+
+
+
+extension ProjectEncodingReport: Equatable, Hashable {
+    public static func ==(lhs: ProjectEncodingReport, rhs: ProjectEncodingReport) -> Bool {
+        if lhs.checked != rhs.checked {
+            return false
+        }
+        if lhs.withoutCwd != rhs.withoutCwd {
+            return false
+        }
+        if lhs.mismatches != rhs.mismatches {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(checked)
+        hasher.combine(withoutCwd)
+        hasher.combine(mismatches)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProjectEncodingReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectEncodingReport {
+        return
+            try ProjectEncodingReport(
+                checked: FfiConverterUInt32.read(from: &buf), 
+                withoutCwd: FfiConverterUInt32.read(from: &buf), 
+                mismatches: FfiConverterSequenceTypeEncodingMismatch.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ProjectEncodingReport, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.checked, into: &buf)
+        FfiConverterUInt32.write(value.withoutCwd, into: &buf)
+        FfiConverterSequenceTypeEncodingMismatch.write(value.mismatches, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectEncodingReport_lift(_ buf: RustBuffer) throws -> ProjectEncodingReport {
+    return try FfiConverterTypeProjectEncodingReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectEncodingReport_lower(_ value: ProjectEncodingReport) -> RustBuffer {
+    return FfiConverterTypeProjectEncodingReport.lower(value)
+}
+
+
+/**
+ * Pinned projects that share an ancestor directory.
+ */
+public struct ProjectGroup {
+    /**
+     * Shared ancestor directory
+     */
+    public var root: String
+    /**
+     * Member project paths, in pinned order
+     */
+    public var projectPaths: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Shared ancestor directory
+         */root: String, 
+        /**
+         * Member project paths, in pinned order
+         */projectPaths: [String]) {
+        self.root = root
+        self.projectPaths = projectPaths
+    }
+}
+
+
+
+extension ProjectGroup: Equatable, Hashable {
+    public static func ==(lhs: ProjectGroup, rhs: ProjectGroup) -> Bool {
+        if lhs.root != rhs.root {
+            return false
+        }
+        if lhs.projectPaths != rhs.projectPaths {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(root)
+        hasher.combine(projectPaths)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProjectGroup: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectGroup {
+        return
+            try ProjectGroup(
+                root: FfiConverterString.read(from: &buf), 
+                projectPaths: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ProjectGroup, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.root, into: &buf)
+        FfiConverterSequenceString.write(value.projectPaths, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectGroup_lift(_ buf: RustBuffer) throws -> ProjectGroup {
+    return try FfiConverterTypeProjectGroup.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectGroup_lower(_ value: ProjectGroup) -> RustBuffer {
+    return FfiConverterTypeProjectGroup.lower(value)
+}
+
+
+/**
+ * Full session state with context information.
+ */
+public struct ProjectSessionState {
+    public var state: SessionState
+    public var stateChangedAt: String?
+    /**
+     * Timestamp of last hook event (more recent than state_changed_at).
+     * Use this for activity comparison between sessions.
+     */
+    public var updatedAt: String?
+    public var sessionId: String?
+    public var workingOn: String?
+    public var context: ContextInfo?
+    /**
+     * Whether Claude is currently "thinking" (API call in flight).
+     * This provides real-time status when using the fetch-intercepting launcher.
+     */
+    public var thinking: Bool?
+    /**
+     * Whether a lock file is held for this project (indicates Claude is running).
+     * This is checked via advisory file locks and is more reliable than state file alone.
+     */
+    public var isLocked: Bool
+    /**
+     * Whether a locked session's record is just past staleness, within the grace band.
+     * The state is still reported; clients can show it as "maybe idle".
+     */
+    public var possiblyStale: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(state: SessionState, stateChangedAt: String?, 
+        /**
+         * Timestamp of last hook event (more recent than state_changed_at).
+         * Use this for activity comparison between sessions.
+         */updatedAt: String?, sessionId: String?, workingOn: String?, context: ContextInfo?, 
+        /**
+         * Whether Claude is currently "thinking" (API call in flight).
+         * This provides real-time status when using the fetch-intercepting launcher.
+         */thinking: Bool?, 
+        /**
+         * Whether a lock file is held for this project (indicates Claude is running).
+         * This is checked via advisory file locks and is more reliable than state file alone.
+         */isLocked: Bool, 
+        /**
+         * Whether a locked session's record is just past staleness, within the grace band.
+         * The state is still reported; clients can show it as "maybe idle".
+         */possiblyStale: Bool) {
+        self.state = state
+        self.stateChangedAt = stateChangedAt
+        self.updatedAt = updatedAt
+        self.sessionId = sessionId
+        self.workingOn = workingOn
+        self.context = context
+        self.thinking = thinking
+        self.isLocked = isLocked
+        self.possiblyStale = possiblyStale
+    }
+}
+
+
+
+extension ProjectSessionState: Equatable, Hashable {
+    public static func ==(lhs: ProjectSessionState, rhs: ProjectSessionState) -> Bool {
+        if lhs.state != rhs.state {
+            return false
+        }
+        if lhs.stateChangedAt != rhs.stateChangedAt {
+            return false
+        }
+        if lhs.updatedAt != rhs.updatedAt {
+            return false
+        }
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        if lhs.workingOn != rhs.workingOn {
+            return false
+        }
+        if lhs.context != rhs.context {
+            return false
+        }
+        if lhs.thinking != rhs.thinking {
+            return false
+        }
+        if lhs.isLocked != rhs.isLocked {
+            return false
+        }
+        if lhs.possiblyStale != rhs.possiblyStale {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(state)
+        hasher.combine(stateChangedAt)
+        hasher.combine(updatedAt)
+        hasher.combine(sessionId)
+        hasher.combine(workingOn)
+        hasher.combine(context)
+        hasher.combine(thinking)
+        hasher.combine(isLocked)
+        hasher.combine(possiblyStale)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProjectSessionState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectSessionState {
+        return
+            try ProjectSessionState(
+                state: FfiConverterTypeSessionState.read(from: &buf), 
+                stateChangedAt: FfiConverterOptionString.read(from: &buf), 
+                updatedAt: FfiConverterOptionString.read(from: &buf), 
+                sessionId: FfiConverterOptionString.read(from: &buf), 
+                workingOn: FfiConverterOptionString.read(from: &buf), 
+                context: FfiConverterOptionTypeContextInfo.read(from: &buf), 
+                thinking: FfiConverterOptionBool.read(from: &buf), 
+                isLocked: FfiConverterBool.read(from: &buf), 
+                possiblyStale: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ProjectSessionState, into buf: inout [UInt8]) {
+        FfiConverterTypeSessionState.write(value.state, into: &buf)
+        FfiConverterOptionString.write(value.stateChangedAt, into: &buf)
+        FfiConverterOptionString.write(value.updatedAt, into: &buf)
+        FfiConverterOptionString.write(value.sessionId, into: &buf)
+        FfiConverterOptionString.write(value.workingOn, into: &buf)
+        FfiConverterOptionTypeContextInfo.write(value.context, into: &buf)
+        FfiConverterOptionBool.write(value.thinking, into: &buf)
+        FfiConverterBool.write(value.isLocked, into: &buf)
+        FfiConverterBool.write(value.possiblyStale, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectSessionState_lift(_ buf: RustBuffer) throws -> ProjectSessionState {
+    return try FfiConverterTypeProjectSessionState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectSessionState_lower(_ value: ProjectSessionState) -> RustBuffer {
+    return FfiConverterTypeProjectSessionState.lower(value)
+}
+
+
+/**
+ * Aggregated token usage statistics for a project.
+ */
+public struct ProjectStats {
+    public var totalInputTokens: UInt64
+    public var totalOutputTokens: UInt64
+    public var totalCacheReadTokens: UInt64
+    public var totalCacheCreationTokens: UInt64
+    public var opusMessages: UInt32
+    public var sonnetMessages: UInt32
+    public var haikuMessages: UInt32
+    public var sessionCount: UInt32
+    public var latestSummary: String?
+    public var firstActivity: String?
+    public var lastActivity: String?
+    /**
+     * Messages per day, keyed by `YYYY-MM-DD`, for activity heatmaps
+     */
+    public var dailyActivity: [String: UInt32]
+    /**
+     * Messages per UTC hour, keyed by `YYYY-MM-DDTHH`, so activity can be
+     * regrouped into local days without re-reading transcripts
+     */
+    public var hourlyActivity: [String: UInt32]
+    /**
+     * Token usage from Opus messages, so each family is priced at its own rates
+     */
+    public var opusUsage: TokenCounts
+    public var sonnetUsage: TokenCounts
+    public var haikuUsage: TokenCounts
+    /**
+     * Usage from other models, or from lines with no model id; priced as Sonnet
+     */
+    public var otherUsage: TokenCounts
+    /**
+     * Estimated dollars saved by serving input from the prompt cache.
+     * Derived from the per-family usage; see `stats::cache_savings_usd`.
+     */
+    public var cacheSavingsUsd: Double
+    /**
+     * Estimated dollar cost of the token usage, rounded to cents.
+     * Derived from the per-family usage; see `stats::estimate_cost`.
+     */
+    public var estimatedCostUsd: Double
+    /**
+     * True when some session files were skipped for exceeding `HudConfig.max_parse_bytes`
+     */
+    public var partial: Bool
+    /**
+     * Names of the skipped session files, sorted
+     */
+    public var skippedFiles: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(totalInputTokens: UInt64, totalOutputTokens: UInt64, totalCacheReadTokens: UInt64, totalCacheCreationTokens: UInt64, opusMessages: UInt32, sonnetMessages: UInt32, haikuMessages: UInt32, sessionCount: UInt32, latestSummary: String?, firstActivity: String?, lastActivity: String?, 
+        /**
+         * Messages per day, keyed by `YYYY-MM-DD`, for activity heatmaps
+         */dailyActivity: [String: UInt32], 
+        /**
+         * Messages per UTC hour, keyed by `YYYY-MM-DDTHH`, so activity can be
+         * regrouped into local days without re-reading transcripts
+         */hourlyActivity: [String: UInt32], 
+        /**
+         * Token usage from Opus messages, so each family is priced at its own rates
+         */opusUsage: TokenCounts, sonnetUsage: TokenCounts, haikuUsage: TokenCounts, 
+        /**
+         * Usage from other models, or from lines with no model id; priced as Sonnet
+         */otherUsage: TokenCounts, 
+        /**
+         * Estimated dollars saved by serving input from the prompt cache.
+         * Derived from the per-family usage; see `stats::cache_savings_usd`.
+         */cacheSavingsUsd: Double, 
+        /**
+         * Estimated dollar cost of the token usage, rounded to cents.
+         * Derived from the per-family usage; see `stats::estimate_cost`.
+         */estimatedCostUsd: Double, 
+        /**
+         * True when some session files were skipped for exceeding `HudConfig.max_parse_bytes`
+         */partial: Bool, 
+        /**
+         * Names of the skipped session files, sorted
+         */skippedFiles: [String]) {
+        self.totalInputTokens = totalInputTokens
+        self.totalOutputTokens = totalOutputTokens
+        self.totalCacheReadTokens = totalCacheReadTokens
+        self.totalCacheCreationTokens = totalCacheCreationTokens
+        self.opusMessages = opusMessages
+        self.sonnetMessages = sonnetMessages
+        self.haikuMessages = haikuMessages
+        self.sessionCount = sessionCount
+        self.latestSummary = latestSummary
+        self.firstActivity = firstActivity
+        self.lastActivity = lastActivity
+        self.dailyActivity = dailyActivity
+        self.hourlyActivity = hourlyActivity
+        self.opusUsage = opusUsage
+        self.sonnetUsage = sonnetUsage
+        self.haikuUsage = haikuUsage
+        self.otherUsage = otherUsage
+        self.cacheSavingsUsd = cacheSavingsUsd
+        self.estimatedCostUsd = estimatedCostUsd
+        self.partial = partial
+        self.skippedFiles = skippedFiles
+    }
+}
+
+
+
+extension ProjectStats: Equatable, Hashable {
+    public static func ==(lhs: ProjectStats, rhs: ProjectStats) -> Bool {
+        if lhs.totalInputTokens != rhs.totalInputTokens {
+            return false
+        }
+        if lhs.totalOutputTokens != rhs.totalOutputTokens {
+            return false
+        }
+        if lhs.totalCacheReadTokens != rhs.totalCacheReadTokens {
+            return false
+        }
+        if lhs.totalCacheCreationTokens != rhs.totalCacheCreationTokens {
+            return false
+        }
+        if lhs.opusMessages != rhs.opusMessages {
+            return false
+        }
+        if lhs.sonnetMessages != rhs.sonnetMessages {
+            return false
+        }
+        if lhs.haikuMessages != rhs.haikuMessages {
+            return false
+        }
+        if lhs.sessionCount != rhs.sessionCount {
+            return false
+        }
+        if lhs.latestSummary != rhs.latestSummary {
+            return false
+        }
+        if lhs.firstActivity != rhs.firstActivity {
+            return false
+        }
+        if lhs.lastActivity != rhs.lastActivity {
+            return false
+        }
+        if lhs.dailyActivity != rhs.dailyActivity {
+            return false
+        }
+        if lhs.hourlyActivity != rhs.hourlyActivity {
+            return false
+        }
+        if lhs.opusUsage != rhs.opusUsage {
+            return false
+        }
+        if lhs.sonnetUsage != rhs.sonnetUsage {
+            return false
+        }
+        if lhs.haikuUsage != rhs.haikuUsage {
+            return false
+        }
+        if lhs.otherUsage != rhs.otherUsage {
+            return false
+        }
+        if lhs.cacheSavingsUsd != rhs.cacheSavingsUsd {
+            return false
+        }
+        if lhs.estimatedCostUsd != rhs.estimatedCostUsd {
+            return false
+        }
+        if lhs.partial != rhs.partial {
+            return false
+        }
+        if lhs.skippedFiles != rhs.skippedFiles {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(totalInputTokens)
+        hasher.combine(totalOutputTokens)
+        hasher.combine(totalCacheReadTokens)
+        hasher.combine(totalCacheCreationTokens)
+        hasher.combine(opusMessages)
+        hasher.combine(sonnetMessages)
+        hasher.combine(haikuMessages)
+        hasher.combine(sessionCount)
+        hasher.combine(latestSummary)
+        hasher.combine(firstActivity)
+        hasher.combine(lastActivity)
+        hasher.combine(dailyActivity)
+        hasher.combine(hourlyActivity)
+        hasher.combine(opusUsage)
+        hasher.combine(sonnetUsage)
+        hasher.combine(haikuUsage)
+        hasher.combine(otherUsage)
+        hasher.combine(cacheSavingsUsd)
+        hasher.combine(estimatedCostUsd)
+        hasher.combine(partial)
+        hasher.combine(skippedFiles)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProjectStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectStats {
+        return
+            try ProjectStats(
+                totalInputTokens: FfiConverterUInt64.read(from: &buf), 
+                totalOutputTokens: FfiConverterUInt64.read(from: &buf), 
+                totalCacheReadTokens: FfiConverterUInt64.read(from: &buf), 
+                totalCacheCreationTokens: FfiConverterUInt64.read(from: &buf), 
+                opusMessages: FfiConverterUInt32.read(from: &buf), 
+                sonnetMessages: FfiConverterUInt32.read(from: &buf), 
+                haikuMessages: FfiConverterUInt32.read(from: &buf), 
+                sessionCount: FfiConverterUInt32.read(from: &buf), 
+                latestSummary: FfiConverterOptionString.read(from: &buf), 
+                firstActivity: FfiConverterOptionString.read(from: &buf), 
+                lastActivity: FfiConverterOptionString.read(from: &buf), 
+                dailyActivity: FfiConverterDictionaryStringUInt32.read(from: &buf), 
+                hourlyActivity: FfiConverterDictionaryStringUInt32.read(from: &buf), 
+                opusUsage: FfiConverterTypeTokenCounts.read(from: &buf), 
+                sonnetUsage: FfiConverterTypeTokenCounts.read(from: &buf), 
+                haikuUsage: FfiConverterTypeTokenCounts.read(from: &buf), 
+                otherUsage: FfiConverterTypeTokenCounts.read(from: &buf), 
+                cacheSavingsUsd: FfiConverterDouble.read(from: &buf), 
+                estimatedCostUsd: FfiConverterDouble.read(from: &buf), 
+                partial: FfiConverterBool.read(from: &buf), 
+                skippedFiles: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ProjectStats, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.totalInputTokens, into: &buf)
+        FfiConverterUInt64.write(value.totalOutputTokens, into: &buf)
+        FfiConverterUInt64.write(value.totalCacheReadTokens, into: &buf)
+        FfiConverterUInt64.write(value.totalCacheCreationTokens, into: &buf)
+        FfiConverterUInt32.write(value.opusMessages, into: &buf)
+        FfiConverterUInt32.write(value.sonnetMessages, into: &buf)
+        FfiConverterUInt32.write(value.haikuMessages, into: &buf)
+        FfiConverterUInt32.write(value.sessionCount, into: &buf)
+        FfiConverterOptionString.write(value.latestSummary, into: &buf)
+        FfiConverterOptionString.write(value.firstActivity, into: &buf)
+        FfiConverterOptionString.write(value.lastActivity, into: &buf)
+        FfiConverterDictionaryStringUInt32.write(value.dailyActivity, into: &buf)
+        FfiConverterDictionaryStringUInt32.write(value.hourlyActivity, into: &buf)
+        FfiConverterTypeTokenCounts.write(value.opusUsage, into: &buf)
+        FfiConverterTypeTokenCounts.write(value.sonnetUsage, into: &buf)
+        FfiConverterTypeTokenCounts.write(value.haikuUsage, into: &buf)
+        FfiConverterTypeTokenCounts.write(value.otherUsage, into: &buf)
+        FfiConverterDouble.write(value.cacheSavingsUsd, into: &buf)
+        FfiConverterDouble.write(value.estimatedCostUsd, into: &buf)
+        FfiConverterBool.write(value.partial, into: &buf)
+        FfiConverterSequenceString.write(value.skippedFiles, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectStats_lift(_ buf: RustBuffer) throws -> ProjectStats {
+    return try FfiConverterTypeProjectStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectStats_lower(_ value: ProjectStats) -> RustBuffer {
+    return FfiConverterTypeProjectStats.lower(value)
+}
+
+
+/**
+ * Project status as stored in .claude/hud-status.json within each project.
+ */
+public struct ProjectStatus {
+    public var workingOn: String?
+    public var nextStep: String?
+    public var status: String?
+    public var blocker: String?
+    public var updatedAt: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(workingOn: String?, nextStep: String?, status: String?, blocker: String?, updatedAt: String?) {
+        self.workingOn = workingOn
+        self.nextStep = nextStep
+        self.status = status
+        self.blocker = blocker
+        self.updatedAt = updatedAt
+    }
+}
+
+
+
+extension ProjectStatus: Equatable, Hashable {
+    public static func ==(lhs: ProjectStatus, rhs: ProjectStatus) -> Bool {
+        if lhs.workingOn != rhs.workingOn {
+            return false
+        }
+        if lhs.nextStep != rhs.nextStep {
+            return false
+        }
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.blocker != rhs.blocker {
+            return false
+        }
+        if lhs.updatedAt != rhs.updatedAt {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(workingOn)
+        hasher.combine(nextStep)
+        hasher.combine(status)
+        hasher.combine(blocker)
+        hasher.combine(updatedAt)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProjectStatus: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectStatus {
+        return
+            try ProjectStatus(
+                workingOn: FfiConverterOptionString.read(from: &buf), 
+                nextStep: FfiConverterOptionString.read(from: &buf), 
+                status: FfiConverterOptionString.read(from: &buf), 
+                blocker: FfiConverterOptionString.read(from: &buf), 
+                updatedAt: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ProjectStatus, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.workingOn, into: &buf)
+        FfiConverterOptionString.write(value.nextStep, into: &buf)
+        FfiConverterOptionString.write(value.status, into: &buf)
+        FfiConverterOptionString.write(value.blocker, into: &buf)
+        FfiConverterOptionString.write(value.updatedAt, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectStatus_lift(_ buf: RustBuffer) throws -> ProjectStatus {
+    return try FfiConverterTypeProjectStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectStatus_lower(_ value: ProjectStatus) -> RustBuffer {
+    return FfiConverterTypeProjectStatus.lower(value)
+}
+
+
+/**
+ * A project whose live session has subagents running in parallel.
+ */
+public struct ProjectSubagents {
+    /**
+     * `project_dir` when the hook recorded one, otherwise the session's cwd
+     */
+    public var projectPath: String
+    public var sessionId: String
+    public var activeSubagentCount: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * `project_dir` when the hook recorded one, otherwise the session's cwd
+         */projectPath: String, sessionId: String, activeSubagentCount: UInt32) {
+        self.projectPath = projectPath
+        self.sessionId = sessionId
+        self.activeSubagentCount = activeSubagentCount
+    }
+}
+
+
+
+extension ProjectSubagents: Equatable, Hashable {
+    public static func ==(lhs: ProjectSubagents, rhs: ProjectSubagents) -> Bool {
+        if lhs.projectPath != rhs.projectPath {
+            return false
+        }
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        if lhs.activeSubagentCount != rhs.activeSubagentCount {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(projectPath)
+        hasher.combine(sessionId)
+        hasher.combine(activeSubagentCount)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProjectSubagents: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectSubagents {
+        return
+            try ProjectSubagents(
+                projectPath: FfiConverterString.read(from: &buf), 
+                sessionId: FfiConverterString.read(from: &buf), 
+                activeSubagentCount: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ProjectSubagents, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.projectPath, into: &buf)
+        FfiConverterString.write(value.sessionId, into: &buf)
+        FfiConverterUInt32.write(value.activeSubagentCount, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectSubagents_lift(_ buf: RustBuffer) throws -> ProjectSubagents {
+    return try FfiConverterTypeProjectSubagents.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectSubagents_lower(_ value: ProjectSubagents) -> RustBuffer {
+    return FfiConverterTypeProjectSubagents.lower(value)
+}
+
+
+/**
+ * Session and edit rates for a project over a trailing window.
+ *
+ * Rates are given both per calendar day and per active day (a day with any
+ * session or edit), so the client can choose which to show.
+ */
+public struct ProjectVelocity {
+    public var windowDays: UInt32
+    public var activeDays: UInt32
+    /**
+     * Sessions with any transcript activity in the window
+     */
+    public var sessionCount: UInt32
+    /**
+     * File writes from the activity feed in the window
+     */
+    public var editCount: UInt32
+    public var sessionsPerCalendarDay: Double
+    public var sessionsPerActiveDay: Double
+    public var editsPerCalendarDay: Double
+    public var editsPerActiveDay: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(windowDays: UInt32, activeDays: UInt32, 
+        /**
+         * Sessions with any transcript activity in the window
+         */sessionCount: UInt32, 
+        /**
+         * File writes from the activity feed in the window
+         */editCount: UInt32, sessionsPerCalendarDay: Double, sessionsPerActiveDay: Double, editsPerCalendarDay: Double, editsPerActiveDay: Double) {
+        self.windowDays = windowDays
+        self.activeDays = activeDays
+        self.sessionCount = sessionCount
+        self.editCount = editCount
+        self.sessionsPerCalendarDay = sessionsPerCalendarDay
+        self.sessionsPerActiveDay = sessionsPerActiveDay
+        self.editsPerCalendarDay = editsPerCalendarDay
+        self.editsPerActiveDay = editsPerActiveDay
+    }
+}
+
+
+
+extension ProjectVelocity: Equatable, Hashable {
+    public static func ==(lhs: ProjectVelocity, rhs: ProjectVelocity) -> Bool {
+        if lhs.windowDays != rhs.windowDays {
+            return false
+        }
+        if lhs.activeDays != rhs.activeDays {
+            return false
+        }
+        if lhs.sessionCount != rhs.sessionCount {
+            return false
+        }
+        if lhs.editCount != rhs.editCount {
+            return false
+        }
+        if lhs.sessionsPerCalendarDay != rhs.sessionsPerCalendarDay {
+            return false
+        }
+        if lhs.sessionsPerActiveDay != rhs.sessionsPerActiveDay {
+            return false
+        }
+        if lhs.editsPerCalendarDay != rhs.editsPerCalendarDay {
+            return false
+        }
+        if lhs.editsPerActiveDay != rhs.editsPerActiveDay {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(windowDays)
+        hasher.combine(activeDays)
+        hasher.combine(sessionCount)
+        hasher.combine(editCount)
+        hasher.combine(sessionsPerCalendarDay)
+        hasher.combine(sessionsPerActiveDay)
+        hasher.combine(editsPerCalendarDay)
+        hasher.combine(editsPerActiveDay)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProjectVelocity: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectVelocity {
+        return
+            try ProjectVelocity(
+                windowDays: FfiConverterUInt32.read(from: &buf), 
+                activeDays: FfiConverterUInt32.read(from: &buf), 
+                sessionCount: FfiConverterUInt32.read(from: &buf), 
+                editCount: FfiConverterUInt32.read(from: &buf), 
+                sessionsPerCalendarDay: FfiConverterDouble.read(from: &buf), 
+                sessionsPerActiveDay: FfiConverterDouble.read(from: &buf), 
+                editsPerCalendarDay: FfiConverterDouble.read(from: &buf), 
+                editsPerActiveDay: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ProjectVelocity, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.windowDays, into: &buf)
+        FfiConverterUInt32.write(value.activeDays, into: &buf)
+        FfiConverterUInt32.write(value.sessionCount, into: &buf)
+        FfiConverterUInt32.write(value.editCount, into: &buf)
+        FfiConverterDouble.write(value.sessionsPerCalendarDay, into: &buf)
+        FfiConverterDouble.write(value.sessionsPerActiveDay, into: &buf)
+        FfiConverterDouble.write(value.editsPerCalendarDay, into: &buf)
+        FfiConverterDouble.write(value.editsPerActiveDay, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectVelocity_lift(_ buf: RustBuffer) throws -> ProjectVelocity {
+    return try FfiConverterTypeProjectVelocity.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProjectVelocity_lower(_ value: ProjectVelocity) -> RustBuffer {
+    return FfiConverterTypeProjectVelocity.lower(value)
+}
+
+
+/**
+ * One session's token usage and estimated cost.
+ */
+public struct SessionCost {
+    public var projectPath: String
+    public var sessionId: String
+    /**
+     * Latest summary line in the transcript, if Claude wrote one
+     */
+    public var summary: String?
+    public var inputTokens: UInt64
+    public var outputTokens: UInt64
+    public var cacheReadTokens: UInt64
+    public var cacheCreationTokens: UInt64
+    /**
+     * Estimated cost at list prices
+     */
+    public var costUsd: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(projectPath: String, sessionId: String, 
+        /**
+         * Latest summary line in the transcript, if Claude wrote one
+         */summary: String?, inputTokens: UInt64, outputTokens: UInt64, cacheReadTokens: UInt64, cacheCreationTokens: UInt64, 
+        /**
+         * Estimated cost at list prices
+         */costUsd: Double) {
+        self.projectPath = projectPath
+        self.sessionId = sessionId
+        self.summary = summary
+        self.inputTokens = inputTokens
+        self.outputTokens = outputTokens
+        self.cacheReadTokens = cacheReadTokens
+        self.cacheCreationTokens = cacheCreationTokens
+        self.costUsd = costUsd
+    }
+}
+
+
+
+extension SessionCost: Equatable, Hashable {
+    public static func ==(lhs: SessionCost, rhs: SessionCost) -> Bool {
+        if lhs.projectPath != rhs.projectPath {
+            return false
+        }
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        if lhs.summary != rhs.summary {
+            return false
+        }
+        if lhs.inputTokens != rhs.inputTokens {
+            return false
+        }
+        if lhs.outputTokens != rhs.outputTokens {
+            return false
+        }
+        if lhs.cacheReadTokens != rhs.cacheReadTokens {
+            return false
+        }
+        if lhs.cacheCreationTokens != rhs.cacheCreationTokens {
+            return false
+        }
+        if lhs.costUsd != rhs.costUsd {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(projectPath)
+        hasher.combine(sessionId)
+        hasher.combine(summary)
+        hasher.combine(inputTokens)
+        hasher.combine(outputTokens)
+        hasher.combine(cacheReadTokens)
+        hasher.combine(cacheCreationTokens)
+        hasher.combine(costUsd)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSessionCost: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SessionCost {
+        return
+            try SessionCost(
+                projectPath: FfiConverterString.read(from: &buf), 
+                sessionId: FfiConverterString.read(from: &buf), 
+                summary: FfiConverterOptionString.read(from: &buf), 
+                inputTokens: FfiConverterUInt64.read(from: &buf), 
+                outputTokens: FfiConverterUInt64.read(from: &buf), 
+                cacheReadTokens: FfiConverterUInt64.read(from: &buf), 
+                cacheCreationTokens: FfiConverterUInt64.read(from: &buf), 
+                costUsd: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SessionCost, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.projectPath, into: &buf)
+        FfiConverterString.write(value.sessionId, into: &buf)
+        FfiConverterOptionString.write(value.summary, into: &buf)
+        FfiConverterUInt64.write(value.inputTokens, into: &buf)
+        FfiConverterUInt64.write(value.outputTokens, into: &buf)
+        FfiConverterUInt64.write(value.cacheReadTokens, into: &buf)
+        FfiConverterUInt64.write(value.cacheCreationTokens, into: &buf)
+        FfiConverterDouble.write(value.costUsd, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionCost_lift(_ buf: RustBuffer) throws -> SessionCost {
+    return try FfiConverterTypeSessionCost.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionCost_lower(_ value: SessionCost) -> RustBuffer {
+    return FfiConverterTypeSessionCost.lower(value)
+}
+
+
+/**
+ * How long a project's sessions run, from first to last transcript timestamp.
+ */
+public struct SessionDurationStats {
+    /**
+     * Sessions with at least two timestamps
+     */
+    public var sessionCount: UInt32
+    public var averageSecs: Double
+    public var medianSecs: Double
+    public var longestSecs: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Sessions with at least two timestamps
+         */sessionCount: UInt32, averageSecs: Double, medianSecs: Double, longestSecs: Double) {
+        self.sessionCount = sessionCount
+        self.averageSecs = averageSecs
+        self.medianSecs = medianSecs
+        self.longestSecs = longestSecs
+    }
+}
+
+
+
+extension SessionDurationStats: Equatable, Hashable {
+    public static func ==(lhs: SessionDurationStats, rhs: SessionDurationStats) -> Bool {
+        if lhs.sessionCount != rhs.sessionCount {
+            return false
+        }
+        if lhs.averageSecs != rhs.averageSecs {
+            return false
+        }
+        if lhs.medianSecs != rhs.medianSecs {
+            return false
+        }
+        if lhs.longestSecs != rhs.longestSecs {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(sessionCount)
+        hasher.combine(averageSecs)
+        hasher.combine(medianSecs)
+        hasher.combine(longestSecs)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSessionDurationStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SessionDurationStats {
+        return
+            try SessionDurationStats(
+                sessionCount: FfiConverterUInt32.read(from: &buf), 
+                averageSecs: FfiConverterDouble.read(from: &buf), 
+                medianSecs: FfiConverterDouble.read(from: &buf), 
+                longestSecs: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SessionDurationStats, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.sessionCount, into: &buf)
+        FfiConverterDouble.write(value.averageSecs, into: &buf)
+        FfiConverterDouble.write(value.medianSecs, into: &buf)
+        FfiConverterDouble.write(value.longestSecs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionDurationStats_lift(_ buf: RustBuffer) throws -> SessionDurationStats {
+    return try FfiConverterTypeSessionDurationStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionDurationStats_lower(_ value: SessionDurationStats) -> RustBuffer {
+    return FfiConverterTypeSessionDurationStats.lower(value)
+}
+
+
+/**
+ * One conversation, possibly spread across several resumed transcript files.
+ */
+public struct SessionGroup {
+    /**
+     * Named after the first session; path and timestamp are the latest file's
+     */
+    public var task: Task
+    /**
+     * Session ids (transcript file stems) in the chain, oldest first
+     */
+    public var sessionIds: [String]
+    /**
+     * Stats summed across every file in the chain
+     */
+    public var stats: ProjectStats
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Named after the first session; path and timestamp are the latest file's
+         */task: Task, 
+        /**
+         * Session ids (transcript file stems) in the chain, oldest first
+         */sessionIds: [String], 
+        /**
+         * Stats summed across every file in the chain
+         */stats: ProjectStats) {
+        self.task = task
+        self.sessionIds = sessionIds
+        self.stats = stats
+    }
+}
+
+
+
+extension SessionGroup: Equatable, Hashable {
+    public static func ==(lhs: SessionGroup, rhs: SessionGroup) -> Bool {
+        if lhs.task != rhs.task {
+            return false
+        }
+        if lhs.sessionIds != rhs.sessionIds {
+            return false
+        }
+        if lhs.stats != rhs.stats {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(task)
+        hasher.combine(sessionIds)
+        hasher.combine(stats)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSessionGroup: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SessionGroup {
+        return
+            try SessionGroup(
+                task: FfiConverterTypeTask.read(from: &buf), 
+                sessionIds: FfiConverterSequenceString.read(from: &buf), 
+                stats: FfiConverterTypeProjectStats.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SessionGroup, into buf: inout [UInt8]) {
+        FfiConverterTypeTask.write(value.task, into: &buf)
+        FfiConverterSequenceString.write(value.sessionIds, into: &buf)
+        FfiConverterTypeProjectStats.write(value.stats, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionGroup_lift(_ buf: RustBuffer) throws -> SessionGroup {
+    return try FfiConverterTypeSessionGroup.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionGroup_lower(_ value: SessionGroup) -> RustBuffer {
+    return FfiConverterTypeSessionGroup.lower(value)
+}
+
+
+/**
+ * A session id seen under project paths that can't belong to one session.
+ */
+public struct SessionIdCollision {
+    public var sessionId: String
+    /**
+     * Distinct paths the id was recorded under, sorted
+     */
+    public var paths: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(sessionId: String, 
+        /**
+         * Distinct paths the id was recorded under, sorted
+         */paths: [String]) {
+        self.sessionId = sessionId
+        self.paths = paths
+    }
+}
+
+
+
+extension SessionIdCollision: Equatable, Hashable {
+    public static func ==(lhs: SessionIdCollision, rhs: SessionIdCollision) -> Bool {
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        if lhs.paths != rhs.paths {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(sessionId)
+        hasher.combine(paths)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSessionIdCollision: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SessionIdCollision {
+        return
+            try SessionIdCollision(
+                sessionId: FfiConverterString.read(from: &buf), 
+                paths: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SessionIdCollision, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.sessionId, into: &buf)
+        FfiConverterSequenceString.write(value.paths, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionIdCollision_lift(_ buf: RustBuffer) throws -> SessionIdCollision {
+    return try FfiConverterTypeSessionIdCollision.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionIdCollision_lower(_ value: SessionIdCollision) -> RustBuffer {
+    return FfiConverterTypeSessionIdCollision.lower(value)
+}
+
+
+/**
+ * Response timing for a session, from prompt/response timestamps in its transcript.
+ */
+public struct SessionLatency {
+    /**
+     * Prompts that had a timed response
+     */
+    public var promptCount: UInt32
+    public var minResponseSecs: Double
+    public var medianResponseSecs: Double
+    public var maxResponseSecs: Double
+    /**
+     * Sum of time from each prompt to the last assistant message of its turn
+     */
+    public var totalAssistantSecs: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Prompts that had a timed response
+         */promptCount: UInt32, minResponseSecs: Double, medianResponseSecs: Double, maxResponseSecs: Double, 
+        /**
+         * Sum of time from each prompt to the last assistant message of its turn
+         */totalAssistantSecs: Double) {
+        self.promptCount = promptCount
+        self.minResponseSecs = minResponseSecs
+        self.medianResponseSecs = medianResponseSecs
+        self.maxResponseSecs = maxResponseSecs
+        self.totalAssistantSecs = totalAssistantSecs
+    }
+}
+
+
+
+extension SessionLatency: Equatable, Hashable {
+    public static func ==(lhs: SessionLatency, rhs: SessionLatency) -> Bool {
+        if lhs.promptCount != rhs.promptCount {
+            return false
+        }
+        if lhs.minResponseSecs != rhs.minResponseSecs {
+            return false
+        }
+        if lhs.medianResponseSecs != rhs.medianResponseSecs {
+            return false
+        }
+        if lhs.maxResponseSecs != rhs.maxResponseSecs {
+            return false
+        }
+        if lhs.totalAssistantSecs != rhs.totalAssistantSecs {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(promptCount)
+        hasher.combine(minResponseSecs)
+        hasher.combine(medianResponseSecs)
+        hasher.combine(maxResponseSecs)
+        hasher.combine(totalAssistantSecs)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSessionLatency: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SessionLatency {
+        return
+            try SessionLatency(
+                promptCount: FfiConverterUInt32.read(from: &buf), 
+                minResponseSecs: FfiConverterDouble.read(from: &buf), 
+                medianResponseSecs: FfiConverterDouble.read(from: &buf), 
+                maxResponseSecs: FfiConverterDouble.read(from: &buf), 
+                totalAssistantSecs: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SessionLatency, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.promptCount, into: &buf)
+        FfiConverterDouble.write(value.minResponseSecs, into: &buf)
+        FfiConverterDouble.write(value.medianResponseSecs, into: &buf)
+        FfiConverterDouble.write(value.maxResponseSecs, into: &buf)
+        FfiConverterDouble.write(value.totalAssistantSecs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionLatency_lift(_ buf: RustBuffer) throws -> SessionLatency {
+    return try FfiConverterTypeSessionLatency.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionLatency_lower(_ value: SessionLatency) -> RustBuffer {
+    return FfiConverterTypeSessionLatency.lower(value)
+}
+
+
+/**
+ * Compact summary of a finished session.
+ */
+public struct SessionReceipt {
+    public var sessionId: String
+    public var projectPath: String
+    public var firstPrompt: String?
+    /**
+     * Text of the last assistant message that had any
+     */
+    public var finalResponse: String?
+    /**
+     * Seconds between the first and last transcript timestamps
+     */
+    public var durationSecs: Double?
+    public var inputTokens: UInt64
+    public var outputTokens: UInt64
+    public var cacheReadTokens: UInt64
+    public var cacheCreationTokens: UInt64
+    /**
+     * Estimated cost at list prices
+     */
+    public var costUsd: Double
+    /**
+     * Files edited or written, in the order first touched
+     */
+    public var filesTouched: [String]
+    /**
+     * Model ids used, in the order first seen
+     */
+    public var models: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(sessionId: String, projectPath: String, firstPrompt: String?, 
+        /**
+         * Text of the last assistant message that had any
+         */finalResponse: String?, 
+        /**
+         * Seconds between the first and last transcript timestamps
+         */durationSecs: Double?, inputTokens: UInt64, outputTokens: UInt64, cacheReadTokens: UInt64, cacheCreationTokens: UInt64, 
+        /**
+         * Estimated cost at list prices
+         */costUsd: Double, 
+        /**
+         * Files edited or written, in the order first touched
+         */filesTouched: [String], 
+        /**
+         * Model ids used, in the order first seen
+         */models: [String]) {
+        self.sessionId = sessionId
+        self.projectPath = projectPath
+        self.firstPrompt = firstPrompt
+        self.finalResponse = finalResponse
+        self.durationSecs = durationSecs
+        self.inputTokens = inputTokens
+        self.outputTokens = outputTokens
+        self.cacheReadTokens = cacheReadTokens
+        self.cacheCreationTokens = cacheCreationTokens
+        self.costUsd = costUsd
+        self.filesTouched = filesTouched
+        self.models = models
+    }
+}
+
+
+
+extension SessionReceipt: Equatable, Hashable {
+    public static func ==(lhs: SessionReceipt, rhs: SessionReceipt) -> Bool {
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        if lhs.projectPath != rhs.projectPath {
+            return false
+        }
+        if lhs.firstPrompt != rhs.firstPrompt {
+            return false
+        }
+        if lhs.finalResponse != rhs.finalResponse {
+            return false
+        }
+        if lhs.durationSecs != rhs.durationSecs {
+            return false
+        }
+        if lhs.inputTokens != rhs.inputTokens {
+            return false
+        }
+        if lhs.outputTokens != rhs.outputTokens {
+            return false
+        }
+        if lhs.cacheReadTokens != rhs.cacheReadTokens {
+            return false
+        }
+        if lhs.cacheCreationTokens != rhs.cacheCreationTokens {
+            return false
+        }
+        if lhs.costUsd != rhs.costUsd {
+            return false
+        }
+        if lhs.filesTouched != rhs.filesTouched {
+            return false
+        }
+        if lhs.models != rhs.models {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(sessionId)
+        hasher.combine(projectPath)
+        hasher.combine(firstPrompt)
+        hasher.combine(finalResponse)
+        hasher.combine(durationSecs)
+        hasher.combine(inputTokens)
+        hasher.combine(outputTokens)
+        hasher.combine(cacheReadTokens)
+        hasher.combine(cacheCreationTokens)
+        hasher.combine(costUsd)
+        hasher.combine(filesTouched)
+        hasher.combine(models)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSessionReceipt: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SessionReceipt {
+        return
+            try SessionReceipt(
+                sessionId: FfiConverterString.read(from: &buf), 
+                projectPath: FfiConverterString.read(from: &buf), 
+                firstPrompt: FfiConverterOptionString.read(from: &buf), 
+                finalResponse: FfiConverterOptionString.read(from: &buf), 
+                durationSecs: FfiConverterOptionDouble.read(from: &buf), 
+                inputTokens: FfiConverterUInt64.read(from: &buf), 
+                outputTokens: FfiConverterUInt64.read(from: &buf), 
+                cacheReadTokens: FfiConverterUInt64.read(from: &buf), 
+                cacheCreationTokens: FfiConverterUInt64.read(from: &buf), 
+                costUsd: FfiConverterDouble.read(from: &buf), 
+                filesTouched: FfiConverterSequenceString.read(from: &buf), 
+                models: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SessionReceipt, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.sessionId, into: &buf)
+        FfiConverterString.write(value.projectPath, into: &buf)
+        FfiConverterOptionString.write(value.firstPrompt, into: &buf)
+        FfiConverterOptionString.write(value.finalResponse, into: &buf)
+        FfiConverterOptionDouble.write(value.durationSecs, into: &buf)
+        FfiConverterUInt64.write(value.inputTokens, into: &buf)
+        FfiConverterUInt64.write(value.outputTokens, into: &buf)
+        FfiConverterUInt64.write(value.cacheReadTokens, into: &buf)
+        FfiConverterUInt64.write(value.cacheCreationTokens, into: &buf)
+        FfiConverterDouble.write(value.costUsd, into: &buf)
+        FfiConverterSequenceString.write(value.filesTouched, into: &buf)
+        FfiConverterSequenceString.write(value.models, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionReceipt_lift(_ buf: RustBuffer) throws -> SessionReceipt {
+    return try FfiConverterTypeSessionReceipt.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionReceipt_lower(_ value: SessionReceipt) -> RustBuffer {
+    return FfiConverterTypeSessionReceipt.lower(value)
+}
+
+
+/**
+ * Count of session records by state.
+ */
+public struct SessionSummary {
+    public var working: UInt32
+    public var waiting: UInt32
+    public var ready: UInt32
+    public var idle: UInt32
+    public var compacting: UInt32
+    public var thinking: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(working: UInt32, waiting: UInt32, ready: UInt32, idle: UInt32, compacting: UInt32, thinking: UInt32) {
+        self.working = working
+        self.waiting = waiting
+        self.ready = ready
+        self.idle = idle
+        self.compacting = compacting
+        self.thinking = thinking
+    }
+}
+
+
+
+extension SessionSummary: Equatable, Hashable {
+    public static func ==(lhs: SessionSummary, rhs: SessionSummary) -> Bool {
+        if lhs.working != rhs.working {
+            return false
+        }
+        if lhs.waiting != rhs.waiting {
+            return false
+        }
+        if lhs.ready != rhs.ready {
+            return false
+        }
+        if lhs.idle != rhs.idle {
+            return false
+        }
+        if lhs.compacting != rhs.compacting {
+            return false
+        }
+        if lhs.thinking != rhs.thinking {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(working)
+        hasher.combine(waiting)
+        hasher.combine(ready)
+        hasher.combine(idle)
+        hasher.combine(compacting)
+        hasher.combine(thinking)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSessionSummary: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SessionSummary {
+        return
+            try SessionSummary(
+                working: FfiConverterUInt32.read(from: &buf), 
+                waiting: FfiConverterUInt32.read(from: &buf), 
+                ready: FfiConverterUInt32.read(from: &buf), 
+                idle: FfiConverterUInt32.read(from: &buf), 
+                compacting: FfiConverterUInt32.read(from: &buf), 
+                thinking: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SessionSummary, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.working, into: &buf)
+        FfiConverterUInt32.write(value.waiting, into: &buf)
+        FfiConverterUInt32.write(value.ready, into: &buf)
+        FfiConverterUInt32.write(value.idle, into: &buf)
+        FfiConverterUInt32.write(value.compacting, into: &buf)
+        FfiConverterUInt32.write(value.thinking, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionSummary_lift(_ buf: RustBuffer) throws -> SessionSummary {
+    return try FfiConverterTypeSessionSummary.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionSummary_lower(_ value: SessionSummary) -> RustBuffer {
+    return FfiConverterTypeSessionSummary.lower(value)
+}
+
+
+/**
+ * A session starting to wait on the user, typically for a permission prompt.
+ */
+public struct SessionWaitingEvent {
+    public var sessionId: String
+    public var cwd: String
+    /**
+     * When the change was observed (RFC 3339)
+     */
+    public var observedAt: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(sessionId: String, cwd: String, 
+        /**
+         * When the change was observed (RFC 3339)
+         */observedAt: String) {
+        self.sessionId = sessionId
+        self.cwd = cwd
+        self.observedAt = observedAt
+    }
+}
+
+
+
+extension SessionWaitingEvent: Equatable, Hashable {
+    public static func ==(lhs: SessionWaitingEvent, rhs: SessionWaitingEvent) -> Bool {
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        if lhs.cwd != rhs.cwd {
+            return false
+        }
+        if lhs.observedAt != rhs.observedAt {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(sessionId)
+        hasher.combine(cwd)
+        hasher.combine(observedAt)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSessionWaitingEvent: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SessionWaitingEvent {
+        return
+            try SessionWaitingEvent(
+                sessionId: FfiConverterString.read(from: &buf), 
+                cwd: FfiConverterString.read(from: &buf), 
+                observedAt: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SessionWaitingEvent, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.sessionId, into: &buf)
+        FfiConverterString.write(value.cwd, into: &buf)
+        FfiConverterString.write(value.observedAt, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionWaitingEvent_lift(_ buf: RustBuffer) throws -> SessionWaitingEvent {
+    return try FfiConverterTypeSessionWaitingEvent.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSessionWaitingEvent_lower(_ value: SessionWaitingEvent) -> RustBuffer {
+    return FfiConverterTypeSessionWaitingEvent.lower(value)
+}
+
+
+public struct SetupStatus {
+    public var dependencies: [DependencyStatus]
+    public var hooks: HookStatus
+    public var storageReady: Bool
+    public var allReady: Bool
+    public var blockingReason: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(dependencies: [DependencyStatus], hooks: HookStatus, storageReady: Bool, allReady: Bool, blockingReason: String?) {
+        self.dependencies = dependencies
+        self.hooks = hooks
+        self.storageReady = storageReady
+        self.allReady = allReady
+        self.blockingReason = blockingReason
+    }
+}
+
+
+
+extension SetupStatus: Equatable, Hashable {
+    public static func ==(lhs: SetupStatus, rhs: SetupStatus) -> Bool {
+        if lhs.dependencies != rhs.dependencies {
+            return false
+        }
+        if lhs.hooks != rhs.hooks {
+            return false
+        }
+        if lhs.storageReady != rhs.storageReady {
+            return false
+        }
+        if lhs.allReady != rhs.allReady {
+            return false
+        }
+        if lhs.blockingReason != rhs.blockingReason {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(dependencies)
+        hasher.combine(hooks)
+        hasher.combine(storageReady)
+        hasher.combine(allReady)
+        hasher.combine(blockingReason)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSetupStatus: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SetupStatus {
+        return
+            try SetupStatus(
+                dependencies: FfiConverterSequenceTypeDependencyStatus.read(from: &buf), 
+                hooks: FfiConverterTypeHookStatus.read(from: &buf), 
+                storageReady: FfiConverterBool.read(from: &buf), 
+                allReady: FfiConverterBool.read(from: &buf), 
+                blockingReason: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SetupStatus, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeDependencyStatus.write(value.dependencies, into: &buf)
+        FfiConverterTypeHookStatus.write(value.hooks, into: &buf)
+        FfiConverterBool.write(value.storageReady, into: &buf)
+        FfiConverterBool.write(value.allReady, into: &buf)
+        FfiConverterOptionString.write(value.blockingReason, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSetupStatus_lift(_ buf: RustBuffer) throws -> SetupStatus {
+    return try FfiConverterTypeSetupStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSetupStatus_lower(_ value: SetupStatus) -> RustBuffer {
+    return FfiConverterTypeSetupStatus.lower(value)
+}
+
+
+/**
+ * Shell state as read from `~/.capacitor/shell-cwd.json`.
+ *
+ * This is the FFI-safe version of the shell state. Swift reads the JSON
+ * and converts it to this type before passing to Rust.
+ */
+public struct ShellCwdStateFfi {
+    public var version: UInt32
+    public var shells: [String: ShellEntryFfi]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(version: UInt32, shells: [String: ShellEntryFfi]) {
+        self.version = version
+        self.shells = shells
+    }
+}
+
+
+
+extension ShellCwdStateFfi: Equatable, Hashable {
+    public static func ==(lhs: ShellCwdStateFfi, rhs: ShellCwdStateFfi) -> Bool {
+        if lhs.version != rhs.version {
+            return false
+        }
+        if lhs.shells != rhs.shells {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(version)
+        hasher.combine(shells)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeShellCwdStateFfi: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ShellCwdStateFfi {
+        return
+            try ShellCwdStateFfi(
+                version: FfiConverterUInt32.read(from: &buf), 
+                shells: FfiConverterDictionaryStringTypeShellEntryFfi.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ShellCwdStateFfi, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.version, into: &buf)
+        FfiConverterDictionaryStringTypeShellEntryFfi.write(value.shells, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeShellCwdStateFfi_lift(_ buf: RustBuffer) throws -> ShellCwdStateFfi {
+    return try FfiConverterTypeShellCwdStateFfi.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeShellCwdStateFfi_lower(_ value: ShellCwdStateFfi) -> RustBuffer {
+    return FfiConverterTypeShellCwdStateFfi.lower(value)
+}
+
+
+/**
+ * A single shell entry from the shell state.
+ */
+public struct ShellEntryFfi {
+    public var cwd: String
+    public var tty: String
+    public var parentApp: ParentApp
+    public var tmuxSession: String?
+    public var tmuxClientTty: String?
+    public var updatedAt: String
+    /**
+     * Whether the shell process is still running (verified via kill(pid, 0))
+     */
+    public var isLive: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(cwd: String, tty: String, parentApp: ParentApp, tmuxSession: String?, tmuxClientTty: String?, updatedAt: String, 
+        /**
+         * Whether the shell process is still running (verified via kill(pid, 0))
+         */isLive: Bool) {
+        self.cwd = cwd
+        self.tty = tty
+        self.parentApp = parentApp
+        self.tmuxSession = tmuxSession
+        self.tmuxClientTty = tmuxClientTty
+        self.updatedAt = updatedAt
+        self.isLive = isLive
+    }
+}
+
+
+
+extension ShellEntryFfi: Equatable, Hashable {
+    public static func ==(lhs: ShellEntryFfi, rhs: ShellEntryFfi) -> Bool {
+        if lhs.cwd != rhs.cwd {
+            return false
+        }
+        if lhs.tty != rhs.tty {
+            return false
+        }
+        if lhs.parentApp != rhs.parentApp {
+            return false
+        }
+        if lhs.tmuxSession != rhs.tmuxSession {
+            return false
+        }
+        if lhs.tmuxClientTty != rhs.tmuxClientTty {
+            return false
+        }
+        if lhs.updatedAt != rhs.updatedAt {
+            return false
+        }
+        if lhs.isLive != rhs.isLive {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(cwd)
+        hasher.combine(tty)
+        hasher.combine(parentApp)
+        hasher.combine(tmuxSession)
+        hasher.combine(tmuxClientTty)
+        hasher.combine(updatedAt)
+        hasher.combine(isLive)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeShellEntryFfi: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ShellEntryFfi {
+        return
+            try ShellEntryFfi(
+                cwd: FfiConverterString.read(from: &buf), 
+                tty: FfiConverterString.read(from: &buf), 
+                parentApp: FfiConverterTypeParentApp.read(from: &buf), 
+                tmuxSession: FfiConverterOptionString.read(from: &buf), 
+                tmuxClientTty: FfiConverterOptionString.read(from: &buf), 
+                updatedAt: FfiConverterString.read(from: &buf), 
+                isLive: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ShellEntryFfi, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.cwd, into: &buf)
+        FfiConverterString.write(value.tty, into: &buf)
+        FfiConverterTypeParentApp.write(value.parentApp, into: &buf)
+        FfiConverterOptionString.write(value.tmuxSession, into: &buf)
+        FfiConverterOptionString.write(value.tmuxClientTty, into: &buf)
+        FfiConverterString.write(value.updatedAt, into: &buf)
+        FfiConverterBool.write(value.isLive, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeShellEntryFfi_lift(_ buf: RustBuffer) throws -> ShellEntryFfi {
+    return try FfiConverterTypeShellEntryFfi.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeShellEntryFfi_lower(_ value: ShellEntryFfi) -> RustBuffer {
+    return FfiConverterTypeShellEntryFfi.lower(value)
+}
+
+
+/**
+ * How many live sessions are in each state, for an at-a-glance chart.
+ */
+public struct StateDistribution {
+    public var working: UInt32
+    public var waiting: UInt32
+    public var ready: UInt32
+    public var idle: UInt32
+    public var compacting: UInt32
+    public var thinking: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(working: UInt32, waiting: UInt32, ready: UInt32, idle: UInt32, compacting: UInt32, thinking: UInt32) {
+        self.working = working
+        self.waiting = waiting
+        self.ready = ready
+        self.idle = idle
+        self.compacting = compacting
+        self.thinking = thinking
+    }
+}
+
+
+
+extension StateDistribution: Equatable, Hashable {
+    public static func ==(lhs: StateDistribution, rhs: StateDistribution) -> Bool {
+        if lhs.working != rhs.working {
+            return false
+        }
+        if lhs.waiting != rhs.waiting {
+            return false
+        }
+        if lhs.ready != rhs.ready {
+            return false
+        }
+        if lhs.idle != rhs.idle {
+            return false
+        }
+        if lhs.compacting != rhs.compacting {
+            return false
+        }
+        if lhs.thinking != rhs.thinking {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(working)
+        hasher.combine(waiting)
+        hasher.combine(ready)
+        hasher.combine(idle)
+        hasher.combine(compacting)
+        hasher.combine(thinking)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStateDistribution: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StateDistribution {
+        return
+            try StateDistribution(
+                working: FfiConverterUInt32.read(from: &buf), 
+                waiting: FfiConverterUInt32.read(from: &buf), 
+                ready: FfiConverterUInt32.read(from: &buf), 
+                idle: FfiConverterUInt32.read(from: &buf), 
+                compacting: FfiConverterUInt32.read(from: &buf), 
+                thinking: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StateDistribution, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.working, into: &buf)
+        FfiConverterUInt32.write(value.waiting, into: &buf)
+        FfiConverterUInt32.write(value.ready, into: &buf)
+        FfiConverterUInt32.write(value.idle, into: &buf)
+        FfiConverterUInt32.write(value.compacting, into: &buf)
+        FfiConverterUInt32.write(value.thinking, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStateDistribution_lift(_ buf: RustBuffer) throws -> StateDistribution {
+    return try FfiConverterTypeStateDistribution.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStateDistribution_lower(_ value: StateDistribution) -> RustBuffer {
+    return FfiConverterTypeStateDistribution.lower(value)
+}
+
+
+/**
+ * One session's state changing, appearing, or ending.
+ */
+public struct StateFeedEvent {
+    public var sessionId: String
+    public var cwd: String
+    /**
+     * None when the session is new since the last poll
+     */
+    public var from: SessionState?
+    /**
+     * None when the session's record is gone (session ended)
+     */
+    public var to: SessionState?
+    /**
+     * When the change was observed (RFC 3339)
+     */
+    public var observedAt: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(sessionId: String, cwd: String, 
+        /**
+         * None when the session is new since the last poll
+         */from: SessionState?, 
+        /**
+         * None when the session's record is gone (session ended)
+         */to: SessionState?, 
+        /**
+         * When the change was observed (RFC 3339)
+         */observedAt: String) {
+        self.sessionId = sessionId
+        self.cwd = cwd
+        self.from = from
+        self.to = to
+        self.observedAt = observedAt
+    }
+}
+
+
+
+extension StateFeedEvent: Equatable, Hashable {
+    public static func ==(lhs: StateFeedEvent, rhs: StateFeedEvent) -> Bool {
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        if lhs.cwd != rhs.cwd {
+            return false
+        }
+        if lhs.from != rhs.from {
+            return false
+        }
+        if lhs.to != rhs.to {
+            return false
+        }
+        if lhs.observedAt != rhs.observedAt {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(sessionId)
+        hasher.combine(cwd)
+        hasher.combine(from)
+        hasher.combine(to)
+        hasher.combine(observedAt)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStateFeedEvent: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StateFeedEvent {
+        return
+            try StateFeedEvent(
+                sessionId: FfiConverterString.read(from: &buf), 
+                cwd: FfiConverterString.read(from: &buf), 
+                from: FfiConverterOptionTypeSessionState.read(from: &buf), 
+                to: FfiConverterOptionTypeSessionState.read(from: &buf), 
+                observedAt: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StateFeedEvent, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.sessionId, into: &buf)
+        FfiConverterString.write(value.cwd, into: &buf)
+        FfiConverterOptionTypeSessionState.write(value.from, into: &buf)
+        FfiConverterOptionTypeSessionState.write(value.to, into: &buf)
+        FfiConverterString.write(value.observedAt, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStateFeedEvent_lift(_ buf: RustBuffer) throws -> StateFeedEvent {
+    return try FfiConverterTypeStateFeedEvent.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStateFeedEvent_lower(_ value: StateFeedEvent) -> RustBuffer {
+    return FfiConverterTypeStateFeedEvent.lower(value)
+}
+
+
+/**
+ * The full stats cache, persisted to disk.
+ */
+public struct StatsCache {
+    /**
+     * Format version; caches written before versioning read as 0. Declared
+     * first so it is serialized ahead of `projects` and survives truncation.
+     */
+    public var version: UInt32
+    public var projects: [String: CachedProjectStats]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Format version; caches written before versioning read as 0. Declared
+         * first so it is serialized ahead of `projects` and survives truncation.
+         */version: UInt32, projects: [String: CachedProjectStats]) {
+        self.version = version
+        self.projects = projects
+    }
+}
+
+
+
+extension StatsCache: Equatable, Hashable {
+    public static func ==(lhs: StatsCache, rhs: StatsCache) -> Bool {
+        if lhs.version != rhs.version {
+            return false
+        }
+        if lhs.projects != rhs.projects {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(version)
+        hasher.combine(projects)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStatsCache: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StatsCache {
+        return
+            try StatsCache(
+                version: FfiConverterUInt32.read(from: &buf), 
+                projects: FfiConverterDictionaryStringTypeCachedProjectStats.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StatsCache, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.version, into: &buf)
+        FfiConverterDictionaryStringTypeCachedProjectStats.write(value.projects, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStatsCache_lift(_ buf: RustBuffer) throws -> StatsCache {
+    return try FfiConverterTypeStatsCache.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStatsCache_lower(_ value: StatsCache) -> RustBuffer {
+    return FfiConverterTypeStatsCache.lower(value)
+}
+
+
+/**
+ * Outcome of salvaging a partially-corrupt stats cache.
+ */
+public struct StatsCacheRepair {
+    /**
+     * Project entries kept
+     */
+    public var salvaged: UInt32
+    /**
+     * Project entries that could not be parsed and were discarded
+     */
+    public var dropped: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Project entries kept
+         */salvaged: UInt32, 
+        /**
+         * Project entries that could not be parsed and were discarded
+         */dropped: UInt32) {
+        self.salvaged = salvaged
+        self.dropped = dropped
+    }
+}
+
+
+
+extension StatsCacheRepair: Equatable, Hashable {
+    public static func ==(lhs: StatsCacheRepair, rhs: StatsCacheRepair) -> Bool {
+        if lhs.salvaged != rhs.salvaged {
+            return false
+        }
+        if lhs.dropped != rhs.dropped {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(salvaged)
+        hasher.combine(dropped)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStatsCacheRepair: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StatsCacheRepair {
+        return
+            try StatsCacheRepair(
+                salvaged: FfiConverterUInt32.read(from: &buf), 
+                dropped: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StatsCacheRepair, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.salvaged, into: &buf)
+        FfiConverterUInt32.write(value.dropped, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStatsCacheRepair_lift(_ buf: RustBuffer) throws -> StatsCacheRepair {
+    return try FfiConverterTypeStatsCacheRepair.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStatsCacheRepair_lower(_ value: StatsCacheRepair) -> RustBuffer {
+    return FfiConverterTypeStatsCacheRepair.lower(value)
+}
+
+
+/**
+ * A project's recomputed stats after its transcripts changed (`stats-changed`).
+ */
+public struct StatsChangedEvent {
+    public var projectPath: String
+    public var stats: ProjectStats
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(projectPath: String, stats: ProjectStats) {
+        self.projectPath = projectPath
+        self.stats = stats
+    }
+}
+
+
+
+extension StatsChangedEvent: Equatable, Hashable {
+    public static func ==(lhs: StatsChangedEvent, rhs: StatsChangedEvent) -> Bool {
+        if lhs.projectPath != rhs.projectPath {
+            return false
+        }
+        if lhs.stats != rhs.stats {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(projectPath)
+        hasher.combine(stats)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStatsChangedEvent: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StatsChangedEvent {
+        return
+            try StatsChangedEvent(
+                projectPath: FfiConverterString.read(from: &buf), 
+                stats: FfiConverterTypeProjectStats.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StatsChangedEvent, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.projectPath, into: &buf)
+        FfiConverterTypeProjectStats.write(value.stats, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStatsChangedEvent_lift(_ buf: RustBuffer) throws -> StatsChangedEvent {
+    return try FfiConverterTypeStatsChangedEvent.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStatsChangedEvent_lower(_ value: StatsChangedEvent) -> RustBuffer {
+    return FfiConverterTypeStatsChangedEvent.lower(value)
+}
+
+
+/**
+ * A project discovered in `~/.claude/projects/` but not yet pinned.
+ */
+public struct SuggestedProject {
+    public var path: String
+    public var displayPath: String
+    public var name: String
+    public var taskCount: UInt32
+    public var hasClaudeMd: Bool
+    public var hasProjectIndicators: Bool
+    /**
+     * How project-like the folder looks (0–100); suggestions are ranked by it
+     */
+    public var confidence: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(path: String, displayPath: String, name: String, taskCount: UInt32, hasClaudeMd: Bool, hasProjectIndicators: Bool, 
+        /**
+         * How project-like the folder looks (0–100); suggestions are ranked by it
+         */confidence: UInt32) {
+        self.path = path
+        self.displayPath = displayPath
+        self.name = name
+        self.taskCount = taskCount
+        self.hasClaudeMd = hasClaudeMd
+        self.hasProjectIndicators = hasProjectIndicators
+        self.confidence = confidence
+    }
+}
+
+
+
+extension SuggestedProject: Equatable, Hashable {
+    public static func ==(lhs: SuggestedProject, rhs: SuggestedProject) -> Bool {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.displayPath != rhs.displayPath {
+            return false
+        }
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.taskCount != rhs.taskCount {
+            return false
+        }
+        if lhs.hasClaudeMd != rhs.hasClaudeMd {
+            return false
+        }
+        if lhs.hasProjectIndicators != rhs.hasProjectIndicators {
+            return false
+        }
+        if lhs.confidence != rhs.confidence {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(path)
+        hasher.combine(displayPath)
+        hasher.combine(name)
+        hasher.combine(taskCount)
+        hasher.combine(hasClaudeMd)
+        hasher.combine(hasProjectIndicators)
+        hasher.combine(confidence)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSuggestedProject: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SuggestedProject {
+        return
+            try SuggestedProject(
+                path: FfiConverterString.read(from: &buf), 
+                displayPath: FfiConverterString.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                taskCount: FfiConverterUInt32.read(from: &buf), 
+                hasClaudeMd: FfiConverterBool.read(from: &buf), 
+                hasProjectIndicators: FfiConverterBool.read(from: &buf), 
+                confidence: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SuggestedProject, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterString.write(value.displayPath, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterUInt32.write(value.taskCount, into: &buf)
+        FfiConverterBool.write(value.hasClaudeMd, into: &buf)
+        FfiConverterBool.write(value.hasProjectIndicators, into: &buf)
+        FfiConverterUInt32.write(value.confidence, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSuggestedProject_lift(_ buf: RustBuffer) throws -> SuggestedProject {
+    return try FfiConverterTypeSuggestedProject.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSuggestedProject_lower(_ value: SuggestedProject) -> RustBuffer {
+    return FfiConverterTypeSuggestedProject.lower(value)
+}
+
+
+/**
+ * A task/session from a project (represents Claude Code sessions).
+ */
+public struct Task {
+    public var id: String
+    public var name: String
+    public var path: String
+    public var lastModified: String
+    public var summary: String?
+    public var firstMessage: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, name: String, path: String, lastModified: String, summary: String?, firstMessage: String?) {
+        self.id = id
+        self.name = name
+        self.path = path
+        self.lastModified = lastModified
+        self.summary = summary
+        self.firstMessage = firstMessage
+    }
+}
+
+
+
+extension Task: Equatable, Hashable {
+    public static func ==(lhs: Task, rhs: Task) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.lastModified != rhs.lastModified {
+            return false
+        }
+        if lhs.summary != rhs.summary {
+            return false
+        }
+        if lhs.firstMessage != rhs.firstMessage {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(name)
+        hasher.combine(path)
+        hasher.combine(lastModified)
+        hasher.combine(summary)
+        hasher.combine(firstMessage)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTask: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Task {
+        return
+            try Task(
+                id: FfiConverterString.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf), 
+                lastModified: FfiConverterString.read(from: &buf), 
+                summary: FfiConverterOptionString.read(from: &buf), 
+                firstMessage: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Task, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterString.write(value.lastModified, into: &buf)
+        FfiConverterOptionString.write(value.summary, into: &buf)
+        FfiConverterOptionString.write(value.firstMessage, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTask_lift(_ buf: RustBuffer) throws -> Task {
+    return try FfiConverterTypeTask.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTask_lower(_ value: Task) -> RustBuffer {
+    return FfiConverterTypeTask.lower(value)
+}
+
+
+/**
+ * App and session context attached to feedback reports and shown in the debug panel.
+ */
+public struct TelemetryContext {
+    public var appVersion: String
+    /**
+     * Hook heartbeat is fresh (or a session lock explains its absence)
+     */
+    public var hooksHealthy: Bool
+    public var pinnedProjectCount: UInt32
+    public var sessionSummary: SessionSummary
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(appVersion: String, 
+        /**
+         * Hook heartbeat is fresh (or a session lock explains its absence)
+         */hooksHealthy: Bool, pinnedProjectCount: UInt32, sessionSummary: SessionSummary) {
+        self.appVersion = appVersion
+        self.hooksHealthy = hooksHealthy
+        self.pinnedProjectCount = pinnedProjectCount
+        self.sessionSummary = sessionSummary
+    }
+}
+
+
+
+extension TelemetryContext: Equatable, Hashable {
+    public static func ==(lhs: TelemetryContext, rhs: TelemetryContext) -> Bool {
+        if lhs.appVersion != rhs.appVersion {
+            return false
+        }
+        if lhs.hooksHealthy != rhs.hooksHealthy {
+            return false
+        }
+        if lhs.pinnedProjectCount != rhs.pinnedProjectCount {
+            return false
+        }
+        if lhs.sessionSummary != rhs.sessionSummary {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(appVersion)
+        hasher.combine(hooksHealthy)
+        hasher.combine(pinnedProjectCount)
+        hasher.combine(sessionSummary)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTelemetryContext: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TelemetryContext {
+        return
+            try TelemetryContext(
+                appVersion: FfiConverterString.read(from: &buf), 
+                hooksHealthy: FfiConverterBool.read(from: &buf), 
+                pinnedProjectCount: FfiConverterUInt32.read(from: &buf), 
+                sessionSummary: FfiConverterTypeSessionSummary.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TelemetryContext, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.appVersion, into: &buf)
+        FfiConverterBool.write(value.hooksHealthy, into: &buf)
+        FfiConverterUInt32.write(value.pinnedProjectCount, into: &buf)
+        FfiConverterTypeSessionSummary.write(value.sessionSummary, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTelemetryContext_lift(_ buf: RustBuffer) throws -> TelemetryContext {
+    return try FfiConverterTypeTelemetryContext.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTelemetryContext_lower(_ value: TelemetryContext) -> RustBuffer {
+    return FfiConverterTypeTelemetryContext.lower(value)
+}
+
+
+/**
+ * A process to spawn that opens a terminal, for the app to run as-is.
+ */
+public struct TerminalLaunch {
+    public var program: String
+    public var args: [String]
+    /**
+     * Directory to spawn the program in, when the path can't safely be an argument
+     */
+    public var cwd: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(program: String, args: [String], 
+        /**
+         * Directory to spawn the program in, when the path can't safely be an argument
+         */cwd: String?) {
+        self.program = program
+        self.args = args
+        self.cwd = cwd
+    }
+}
+
+
+
+extension TerminalLaunch: Equatable, Hashable {
+    public static func ==(lhs: TerminalLaunch, rhs: TerminalLaunch) -> Bool {
+        if lhs.program != rhs.program {
+            return false
+        }
+        if lhs.args != rhs.args {
+            return false
+        }
+        if lhs.cwd != rhs.cwd {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(program)
+        hasher.combine(args)
+        hasher.combine(cwd)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTerminalLaunch: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TerminalLaunch {
+        return
+            try TerminalLaunch(
+                program: FfiConverterString.read(from: &buf), 
+                args: FfiConverterSequenceString.read(from: &buf), 
+                cwd: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TerminalLaunch, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.program, into: &buf)
+        FfiConverterSequenceString.write(value.args, into: &buf)
+        FfiConverterOptionString.write(value.cwd, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTerminalLaunch_lift(_ buf: RustBuffer) throws -> TerminalLaunch {
+    return try FfiConverterTypeTerminalLaunch.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTerminalLaunch_lower(_ value: TerminalLaunch) -> RustBuffer {
+    return FfiConverterTypeTerminalLaunch.lower(value)
+}
+
+
+/**
+ * Context about tmux state, queried by Swift before calling the resolver.
+ */
+public struct TmuxContextFfi {
+    /**
+     * Session name if one exists at the project path
+     */
+    public var sessionAtPath: String?
+    /**
+     * Whether any tmux client is currently attached
+     */
+    public var hasAttachedClient: Bool
+    /**
+     * User's home directory (e.g., "/Users/pete") - excluded from parent matching
+     */
+    public var homeDir: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Session name if one exists at the project path
+         */sessionAtPath: String?, 
+        /**
+         * Whether any tmux client is currently attached
+         */hasAttachedClient: Bool, 
+        /**
+         * User's home directory (e.g., "/Users/pete") - excluded from parent matching
+         */homeDir: String) {
+        self.sessionAtPath = sessionAtPath
+        self.hasAttachedClient = hasAttachedClient
+        self.homeDir = homeDir
+    }
+}
+
+
+
+extension TmuxContextFfi: Equatable, Hashable {
+    public static func ==(lhs: TmuxContextFfi, rhs: TmuxContextFfi) -> Bool {
+        if lhs.sessionAtPath != rhs.sessionAtPath {
+            return false
+        }
+        if lhs.hasAttachedClient != rhs.hasAttachedClient {
+            return false
+        }
+        if lhs.homeDir != rhs.homeDir {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(sessionAtPath)
+        hasher.combine(hasAttachedClient)
+        hasher.combine(homeDir)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTmuxContextFfi: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TmuxContextFfi {
+        return
+            try TmuxContextFfi(
+                sessionAtPath: FfiConverterOptionString.read(from: &buf), 
+                hasAttachedClient: FfiConverterBool.read(from: &buf), 
+                homeDir: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TmuxContextFfi, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.sessionAtPath, into: &buf)
+        FfiConverterBool.write(value.hasAttachedClient, into: &buf)
+        FfiConverterString.write(value.homeDir, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTmuxContextFfi_lift(_ buf: RustBuffer) throws -> TmuxContextFfi {
+    return try FfiConverterTypeTmuxContextFfi.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTmuxContextFfi_lower(_ value: TmuxContextFfi) -> RustBuffer {
+    return FfiConverterTypeTmuxContextFfi.lower(value)
+}
+
+
+/**
+ * Token counts by type, for one model family.
+ */
+public struct TokenCounts {
+    public var input: UInt64
+    public var output: UInt64
+    public var cacheRead: UInt64
+    public var cacheCreation: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(input: UInt64, output: UInt64, cacheRead: UInt64, cacheCreation: UInt64) {
+        self.input = input
+        self.output = output
+        self.cacheRead = cacheRead
+        self.cacheCreation = cacheCreation
+    }
+}
+
+
+
+extension TokenCounts: Equatable, Hashable {
+    public static func ==(lhs: TokenCounts, rhs: TokenCounts) -> Bool {
+        if lhs.input != rhs.input {
+            return false
+        }
+        if lhs.output != rhs.output {
+            return false
+        }
+        if lhs.cacheRead != rhs.cacheRead {
+            return false
+        }
+        if lhs.cacheCreation != rhs.cacheCreation {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(input)
+        hasher.combine(output)
+        hasher.combine(cacheRead)
+        hasher.combine(cacheCreation)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTokenCounts: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TokenCounts {
+        return
+            try TokenCounts(
+                input: FfiConverterUInt64.read(from: &buf), 
+                output: FfiConverterUInt64.read(from: &buf), 
+                cacheRead: FfiConverterUInt64.read(from: &buf), 
+                cacheCreation: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TokenCounts, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.input, into: &buf)
+        FfiConverterUInt64.write(value.output, into: &buf)
+        FfiConverterUInt64.write(value.cacheRead, into: &buf)
+        FfiConverterUInt64.write(value.cacheCreation, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTokenCounts_lift(_ buf: RustBuffer) throws -> TokenCounts {
+    return try FfiConverterTypeTokenCounts.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTokenCounts_lower(_ value: TokenCounts) -> RustBuffer {
+    return FfiConverterTypeTokenCounts.lower(value)
+}
+
+
+/**
+ * One user or assistant message, as plain text.
+ */
+public struct TranscriptMessage {
+    /**
+     * "user" or "assistant"
+     */
+    public var role: String
+    public var text: String
+    public var timestamp: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * "user" or "assistant"
+         */role: String, text: String, timestamp: String?) {
+        self.role = role
+        self.text = text
+        self.timestamp = timestamp
+    }
+}
+
+
+
+extension TranscriptMessage: Equatable, Hashable {
+    public static func ==(lhs: TranscriptMessage, rhs: TranscriptMessage) -> Bool {
+        if lhs.role != rhs.role {
+            return false
+        }
+        if lhs.text != rhs.text {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(role)
+        hasher.combine(text)
+        hasher.combine(timestamp)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTranscriptMessage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TranscriptMessage {
+        return
+            try TranscriptMessage(
+                role: FfiConverterString.read(from: &buf), 
+                text: FfiConverterString.read(from: &buf), 
+                timestamp: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TranscriptMessage, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.role, into: &buf)
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterOptionString.write(value.timestamp, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTranscriptMessage_lift(_ buf: RustBuffer) throws -> TranscriptMessage {
+    return try FfiConverterTypeTranscriptMessage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTranscriptMessage_lower(_ value: TranscriptMessage) -> RustBuffer {
+    return FfiConverterTypeTranscriptMessage.lower(value)
+}
+
+
+/**
+ * The terminal app and shell attached to a tty, from shell-cwd.json.
+ */
+public struct TtyTerminal {
+    public var parentApp: ParentApp
+    public var cwd: String
+    /**
+     * Shell PID (the shell-cwd.json key)
+     */
+    public var pid: UInt32
+    public var tmuxSession: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(parentApp: ParentApp, cwd: String, 
+        /**
+         * Shell PID (the shell-cwd.json key)
+         */pid: UInt32, tmuxSession: String?) {
+        self.parentApp = parentApp
+        self.cwd = cwd
+        self.pid = pid
+        self.tmuxSession = tmuxSession
+    }
+}
+
+
+
+extension TtyTerminal: Equatable, Hashable {
+    public static func ==(lhs: TtyTerminal, rhs: TtyTerminal) -> Bool {
+        if lhs.parentApp != rhs.parentApp {
+            return false
+        }
+        if lhs.cwd != rhs.cwd {
+            return false
+        }
+        if lhs.pid != rhs.pid {
+            return false
+        }
+        if lhs.tmuxSession != rhs.tmuxSession {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(parentApp)
+        hasher.combine(cwd)
+        hasher.combine(pid)
+        hasher.combine(tmuxSession)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTtyTerminal: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TtyTerminal {
+        return
+            try TtyTerminal(
+                parentApp: FfiConverterTypeParentApp.read(from: &buf), 
+                cwd: FfiConverterString.read(from: &buf), 
+                pid: FfiConverterUInt32.read(from: &buf), 
+                tmuxSession: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TtyTerminal, into buf: inout [UInt8]) {
+        FfiConverterTypeParentApp.write(value.parentApp, into: &buf)
+        FfiConverterString.write(value.cwd, into: &buf)
+        FfiConverterUInt32.write(value.pid, into: &buf)
+        FfiConverterOptionString.write(value.tmuxSession, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTtyTerminal_lift(_ buf: RustBuffer) throws -> TtyTerminal {
+    return try FfiConverterTypeTtyTerminal.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTtyTerminal_lower(_ value: TtyTerminal) -> RustBuffer {
+    return FfiConverterTypeTtyTerminal.lower(value)
+}
+
+
+/**
+ * FFI-friendly validation result for Swift/Kotlin/Python.
+ *
+ * Uses flat structure instead of enum variants for better FFI compatibility.
+ */
+public struct ValidationResultFfi {
+    /**
+     * Result type: "valid", "suggest_parent", "missing_claude_md", "not_a_project", "path_not_found", "dangerous_path"
+     */
+    public var resultType: String
+    /**
+     * The path (canonical or as provided, depending on result type)
+     */
+    public var path: String
+    /**
+     * For suggest_parent: the suggested project path
+     */
+    public var suggestedPath: String?
+    /**
+     * Human-readable reason for the result
+     */
+    public var reason: String?
+    /**
+     * Whether the project has a CLAUDE.md file
+     */
+    public var hasClaudeMd: Bool
+    /**
+     * Whether the project has other markers (.git, package.json, etc.)
+     */
+    public var hasOtherMarkers: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Result type: "valid", "suggest_parent", "missing_claude_md", "not_a_project", "path_not_found", "dangerous_path"
+         */resultType: String, 
+        /**
+         * The path (canonical or as provided, depending on result type)
+         */path: String, 
+        /**
+         * For suggest_parent: the suggested project path
+         */suggestedPath: String?, 
+        /**
+         * Human-readable reason for the result
+         */reason: String?, 
+        /**
+         * Whether the project has a CLAUDE.md file
+         */hasClaudeMd: Bool, 
+        /**
+         * Whether the project has other markers (.git, package.json, etc.)
+         */hasOtherMarkers: Bool) {
+        self.resultType = resultType
+        self.path = path
+        self.suggestedPath = suggestedPath
+        self.reason = reason
+        self.hasClaudeMd = hasClaudeMd
+        self.hasOtherMarkers = hasOtherMarkers
+    }
+}
+
+
+
+extension ValidationResultFfi: Equatable, Hashable {
+    public static func ==(lhs: ValidationResultFfi, rhs: ValidationResultFfi) -> Bool {
+        if lhs.resultType != rhs.resultType {
+            return false
+        }
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.suggestedPath != rhs.suggestedPath {
+            return false
+        }
+        if lhs.reason != rhs.reason {
+            return false
+        }
+        if lhs.hasClaudeMd != rhs.hasClaudeMd {
+            return false
+        }
+        if lhs.hasOtherMarkers != rhs.hasOtherMarkers {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(resultType)
+        hasher.combine(path)
+        hasher.combine(suggestedPath)
+        hasher.combine(reason)
+        hasher.combine(hasClaudeMd)
+        hasher.combine(hasOtherMarkers)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeValidationResultFfi: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ValidationResultFfi {
+        return
+            try ValidationResultFfi(
+                resultType: FfiConverterString.read(from: &buf), 
+                path: FfiConverterString.read(from: &buf), 
+                suggestedPath: FfiConverterOptionString.read(from: &buf), 
+                reason: FfiConverterOptionString.read(from: &buf), 
+                hasClaudeMd: FfiConverterBool.read(from: &buf), 
+                hasOtherMarkers: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ValidationResultFfi, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.resultType, into: &buf)
+        FfiConverterString.write(value.path, into: &buf)
+        FfiConverterOptionString.write(value.suggestedPath, into: &buf)
+        FfiConverterOptionString.write(value.reason, into: &buf)
+        FfiConverterBool.write(value.hasClaudeMd, into: &buf)
+        FfiConverterBool.write(value.hasOtherMarkers, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeValidationResultFfi_lift(_ buf: RustBuffer) throws -> ValidationResultFfi {
+    return try FfiConverterTypeValidationResultFfi.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeValidationResultFfi_lower(_ value: ValidationResultFfi) -> RustBuffer {
+    return FfiConverterTypeValidationResultFfi.lower(value)
+}
+
+
+/**
+ * A session record whose working directory no longer exists and whose session
+ * holds no live lock.
+ *
+ * Unlike stale records, which may belong to a quiet but valid session, these
+ * can never become active again.
+ */
+public struct ZombieSession {
+    public var sessionId: String
+    /**
+     * The record's cwd, which is gone from disk
+     */
+    public var cwd: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(sessionId: String, 
+        /**
+         * The record's cwd, which is gone from disk
+         */cwd: String) {
+        self.sessionId = sessionId
+        self.cwd = cwd
+    }
+}
+
+
+
+extension ZombieSession: Equatable, Hashable {
+    public static func ==(lhs: ZombieSession, rhs: ZombieSession) -> Bool {
+        if lhs.sessionId != rhs.sessionId {
+            return false
+        }
+        if lhs.cwd != rhs.cwd {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(sessionId)
+        hasher.combine(cwd)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeZombieSession: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ZombieSession {
+        return
+            try ZombieSession(
+                sessionId: FfiConverterString.read(from: &buf), 
+                cwd: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ZombieSession, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.sessionId, into: &buf)
+        FfiConverterString.write(value.cwd, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeZombieSession_lift(_ buf: RustBuffer) throws -> ZombieSession {
+    return try FfiConverterTypeZombieSession.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeZombieSession_lower(_ value: ZombieSession) -> RustBuffer {
+    return FfiConverterTypeZombieSession.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * A single action for Swift to execute.
+ */
+
+public enum ActivationAction {
+    
+    /**
+     * Activate a terminal by querying for TTY ownership (AppleScript)
+     */
+    case activateByTty(tty: String, terminalType: TerminalType
+    )
+    /**
+     * Activate app by bringing its window to front
+     */
+    case activateApp(appName: String
+    )
+    /**
+     * Focus kitty window by shell PID using `kitty @`
+     */
+    case activateKittyWindow(shellPid: UInt32
+    )
+    /**
+     * Activate IDE and run CLI to focus correct window
+     */
+    case activateIdeWindow(ideType: IdeType, projectPath: String
+    )
+    /**
+     * Switch tmux session in attached client
+     */
+    case switchTmuxSession(sessionName: String
+    )
+    /**
+     * Discover host terminal via TTY, then switch tmux session
+     */
+    case activateHostThenSwitchTmux(hostTty: String, sessionName: String
+    )
+    /**
+     * Launch new terminal with tmux attach
+     */
+    case launchTerminalWithTmux(sessionName: String, projectPath: String
+    )
+    /**
+     * Launch new terminal at project path (no tmux)
+     */
+    case launchNewTerminal(projectPath: String, projectName: String
+    )
+    /**
+     * Activate first running terminal from priority list
+     */
+    case activatePriorityFallback
+    /**
+     * Do nothing
+     */
+    case skip
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeActivationAction: FfiConverterRustBuffer {
+    typealias SwiftType = ActivationAction
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ActivationAction {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .activateByTty(tty: try FfiConverterString.read(from: &buf), terminalType: try FfiConverterTypeTerminalType.read(from: &buf)
+        )
+        
+        case 2: return .activateApp(appName: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .activateKittyWindow(shellPid: try FfiConverterUInt32.read(from: &buf)
+        )
+        
+        case 4: return .activateIdeWindow(ideType: try FfiConverterTypeIdeType.read(from: &buf), projectPath: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 5: return .switchTmuxSession(sessionName: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 6: return .activateHostThenSwitchTmux(hostTty: try FfiConverterString.read(from: &buf), sessionName: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 7: return .launchTerminalWithTmux(sessionName: try FfiConverterString.read(from: &buf), projectPath: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 8: return .launchNewTerminal(projectPath: try FfiConverterString.read(from: &buf), projectName: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 9: return .activatePriorityFallback
+        
+        case 10: return .skip
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ActivationAction, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .activateByTty(tty,terminalType):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(tty, into: &buf)
+            FfiConverterTypeTerminalType.write(terminalType, into: &buf)
+            
+        
+        case let .activateApp(appName):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(appName, into: &buf)
+            
+        
+        case let .activateKittyWindow(shellPid):
+            writeInt(&buf, Int32(3))
+            FfiConverterUInt32.write(shellPid, into: &buf)
+            
+        
+        case let .activateIdeWindow(ideType,projectPath):
+            writeInt(&buf, Int32(4))
+            FfiConverterTypeIdeType.write(ideType, into: &buf)
+            FfiConverterString.write(projectPath, into: &buf)
+            
+        
+        case let .switchTmuxSession(sessionName):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(sessionName, into: &buf)
+            
+        
+        case let .activateHostThenSwitchTmux(hostTty,sessionName):
+            writeInt(&buf, Int32(6))
+            FfiConverterString.write(hostTty, into: &buf)
+            FfiConverterString.write(sessionName, into: &buf)
+            
+        
+        case let .launchTerminalWithTmux(sessionName,projectPath):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(sessionName, into: &buf)
+            FfiConverterString.write(projectPath, into: &buf)
+            
+        
+        case let .launchNewTerminal(projectPath,projectName):
+            writeInt(&buf, Int32(8))
+            FfiConverterString.write(projectPath, into: &buf)
+            FfiConverterString.write(projectName, into: &buf)
+            
+        
+        case .activatePriorityFallback:
+            writeInt(&buf, Int32(9))
+        
+        
+        case .skip:
+            writeInt(&buf, Int32(10))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeActivationAction_lift(_ buf: RustBuffer) throws -> ActivationAction {
+    return try FfiConverterTypeActivationAction.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeActivationAction_lower(_ value: ActivationAction) -> RustBuffer {
+    return FfiConverterTypeActivationAction.lower(value)
+}
+
+
+
+extension ActivationAction: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * How a file differs from `HEAD`.
+ */
+
+public enum AgentChangeKind {
+    
+    case modified
+    case added
+    case deleted
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAgentChangeKind: FfiConverterRustBuffer {
+    typealias SwiftType = AgentChangeKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AgentChangeKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .modified
+        
+        case 2: return .added
+        
+        case 3: return .deleted
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: AgentChangeKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .modified:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .added:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .deleted:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAgentChangeKind_lift(_ buf: RustBuffer) throws -> AgentChangeKind {
+    return try FfiConverterTypeAgentChangeKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAgentChangeKind_lower(_ value: AgentChangeKind) -> RustBuffer {
+    return FfiConverterTypeAgentChangeKind.lower(value)
+}
+
+
+
+extension AgentChangeKind: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Universal agent states - maps to any CLI agent's activity
+ */
+
+public enum AgentState {
+    
+    case idle
+    case ready
+    case working
+    case waiting
+    case thinking
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAgentState: FfiConverterRustBuffer {
+    typealias SwiftType = AgentState
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AgentState {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .idle
+        
+        case 2: return .ready
+        
+        case 3: return .working
+        
+        case 4: return .waiting
+        
+        case 5: return .thinking
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: AgentState, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .idle:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .ready:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .working:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .waiting:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .thinking:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAgentState_lift(_ buf: RustBuffer) throws -> AgentState {
+    return try FfiConverterTypeAgentState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAgentState_lower(_ value: AgentState) -> RustBuffer {
+    return FfiConverterTypeAgentState.lower(value)
+}
+
+
+
+extension AgentState: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Known agent types - flat enum for UniFFI compatibility
+ */
+
+public enum AgentType {
+    
+    case claude
+    case codex
+    case aider
+    case amp
+    case openCode
+    case droid
+    case cursor
+    case other
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAgentType: FfiConverterRustBuffer {
+    typealias SwiftType = AgentType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AgentType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .claude
+        
+        case 2: return .codex
+        
+        case 3: return .aider
+        
+        case 4: return .amp
+        
+        case 5: return .openCode
+        
+        case 6: return .droid
+        
+        case 7: return .cursor
+        
+        case 8: return .other
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: AgentType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .claude:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .codex:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .aider:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .amp:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .openCode:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .droid:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .cursor:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .other:
+            writeInt(&buf, Int32(8))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAgentType_lift(_ buf: RustBuffer) throws -> AgentType {
+    return try FfiConverterTypeAgentType.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAgentType_lower(_ value: AgentType) -> RustBuffer {
+    return FfiConverterTypeAgentType.lower(value)
+}
+
+
+
+extension AgentType: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Where this month's spend stands against `HudConfig.monthly_budget_usd`.
+ */
+
+public enum BudgetLevel {
+    
+    /**
+     * Under 80% of the budget, or no budget set
+     */
+    case ok
+    /**
+     * At or above 80% of the budget
+     */
+    case warning
+    /**
+     * At or above the budget
+     */
+    case over
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeProjectStats: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectStats {
-        return
-            try ProjectStats(
-                totalInputTokens: FfiConverterUInt64.read(from: &buf),
-                totalOutputTokens: FfiConverterUInt64.read(from: &buf),
-                totalCacheReadTokens: FfiConverterUInt64.read(from: &buf),
-                totalCacheCreationTokens: FfiConverterUInt64.read(from: &buf),
-                opusMessages: FfiConverterUInt32.read(from: &buf),
-                sonnetMessages: FfiConverterUInt32.read(from: &buf),
-                haikuMessages: FfiConverterUInt32.read(from: &buf),
-                sessionCount: FfiConverterUInt32.read(from: &buf),
-                latestSummary: FfiConverterOptionString.read(from: &buf),
-                firstActivity: FfiConverterOptionString.read(from: &buf),
-                lastActivity: FfiConverterOptionString.read(from: &buf)
-            )
+public struct FfiConverterTypeBudgetLevel: FfiConverterRustBuffer {
+    typealias SwiftType = BudgetLevel
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BudgetLevel {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .ok
+        
+        case 2: return .warning
+        
+        case 3: return .over
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: ProjectStats, into buf: inout [UInt8]) {
-        FfiConverterUInt64.write(value.totalInputTokens, into: &buf)
-        FfiConverterUInt64.write(value.totalOutputTokens, into: &buf)
-        FfiConverterUInt64.write(value.totalCacheReadTokens, into: &buf)
-        FfiConverterUInt64.write(value.totalCacheCreationTokens, into: &buf)
-        FfiConverterUInt32.write(value.opusMessages, into: &buf)
-        FfiConverterUInt32.write(value.sonnetMessages, into: &buf)
-        FfiConverterUInt32.write(value.haikuMessages, into: &buf)
-        FfiConverterUInt32.write(value.sessionCount, into: &buf)
-        FfiConverterOptionString.write(value.latestSummary, into: &buf)
-        FfiConverterOptionString.write(value.firstActivity, into: &buf)
-        FfiConverterOptionString.write(value.lastActivity, into: &buf)
+    public static func write(_ value: BudgetLevel, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .ok:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .warning:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .over:
+            writeInt(&buf, Int32(3))
+        
+        }
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProjectStats_lift(_ buf: RustBuffer) throws -> ProjectStats {
-    return try FfiConverterTypeProjectStats.lift(buf)
+public func FfiConverterTypeBudgetLevel_lift(_ buf: RustBuffer) throws -> BudgetLevel {
+    return try FfiConverterTypeBudgetLevel.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProjectStats_lower(_ value: ProjectStats) -> RustBuffer {
-    return FfiConverterTypeProjectStats.lower(value)
+public func FfiConverterTypeBudgetLevel_lower(_ value: BudgetLevel) -> RustBuffer {
+    return FfiConverterTypeBudgetLevel.lower(value)
 }
 
+
+
+extension BudgetLevel: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Project status as stored in .claude/hud-status.json within each project.
+ * Status of a project creation.
  */
-public struct ProjectStatus {
-    public var workingOn: String?
-    public var nextStep: String?
-    public var status: String?
-    public var blocker: String?
-    public var updatedAt: String?
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(workingOn: String?, nextStep: String?, status: String?, blocker: String?, updatedAt: String?) {
-        self.workingOn = workingOn
-        self.nextStep = nextStep
-        self.status = status
-        self.blocker = blocker
-        self.updatedAt = updatedAt
-    }
+public enum CreationStatus {
+    
+    case pending
+    case inProgress
+    case completed
+    case failed
+    case cancelled
 }
 
-extension ProjectStatus: Equatable, Hashable {
-    public static func == (lhs: ProjectStatus, rhs: ProjectStatus) -> Bool {
-        if lhs.workingOn != rhs.workingOn {
-            return false
-        }
-        if lhs.nextStep != rhs.nextStep {
-            return false
-        }
-        if lhs.status != rhs.status {
-            return false
-        }
-        if lhs.blocker != rhs.blocker {
-            return false
-        }
-        if lhs.updatedAt != rhs.updatedAt {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(workingOn)
-        hasher.combine(nextStep)
-        hasher.combine(status)
-        hasher.combine(blocker)
-        hasher.combine(updatedAt)
-    }
-}
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeProjectStatus: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectStatus {
-        return
-            try ProjectStatus(
-                workingOn: FfiConverterOptionString.read(from: &buf),
-                nextStep: FfiConverterOptionString.read(from: &buf),
-                status: FfiConverterOptionString.read(from: &buf),
-                blocker: FfiConverterOptionString.read(from: &buf),
-                updatedAt: FfiConverterOptionString.read(from: &buf)
-            )
+public struct FfiConverterTypeCreationStatus: FfiConverterRustBuffer {
+    typealias SwiftType = CreationStatus
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CreationStatus {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .pending
+        
+        case 2: return .inProgress
+        
+        case 3: return .completed
+        
+        case 4: return .failed
+        
+        case 5: return .cancelled
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: ProjectStatus, into buf: inout [UInt8]) {
-        FfiConverterOptionString.write(value.workingOn, into: &buf)
-        FfiConverterOptionString.write(value.nextStep, into: &buf)
-        FfiConverterOptionString.write(value.status, into: &buf)
-        FfiConverterOptionString.write(value.blocker, into: &buf)
-        FfiConverterOptionString.write(value.updatedAt, into: &buf)
+    public static func write(_ value: CreationStatus, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .pending:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .inProgress:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .completed:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .failed:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .cancelled:
+            writeInt(&buf, Int32(5))
+        
+        }
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProjectStatus_lift(_ buf: RustBuffer) throws -> ProjectStatus {
-    return try FfiConverterTypeProjectStatus.lift(buf)
+public func FfiConverterTypeCreationStatus_lift(_ buf: RustBuffer) throws -> CreationStatus {
+    return try FfiConverterTypeCreationStatus.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProjectStatus_lower(_ value: ProjectStatus) -> RustBuffer {
-    return FfiConverterTypeProjectStatus.lower(value)
+public func FfiConverterTypeCreationStatus_lower(_ value: CreationStatus) -> RustBuffer {
+    return FfiConverterTypeCreationStatus.lower(value)
 }
 
-public struct SetupStatus {
-    public var dependencies: [DependencyStatus]
-    public var hooks: HookStatus
-    public var storageReady: Bool
-    public var allReady: Bool
-    public var blockingReason: String?
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(dependencies: [DependencyStatus], hooks: HookStatus, storageReady: Bool, allReady: Bool, blockingReason: String?) {
-        self.dependencies = dependencies
-        self.hooks = hooks
-        self.storageReady = storageReady
-        self.allReady = allReady
-        self.blockingReason = blockingReason
-    }
-}
 
-extension SetupStatus: Equatable, Hashable {
-    public static func == (lhs: SetupStatus, rhs: SetupStatus) -> Bool {
-        if lhs.dependencies != rhs.dependencies {
-            return false
-        }
-        if lhs.hooks != rhs.hooks {
-            return false
-        }
-        if lhs.storageReady != rhs.storageReady {
-            return false
-        }
-        if lhs.allReady != rhs.allReady {
-            return false
-        }
-        if lhs.blockingReason != rhs.blockingReason {
-            return false
-        }
-        return true
-    }
+extension CreationStatus: Equatable, Hashable {}
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(dependencies)
-        hasher.combine(hooks)
-        hasher.combine(storageReady)
-        hasher.combine(allReady)
-        hasher.combine(blockingReason)
-    }
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The health status of the hook binary based on heartbeat freshness.
+ */
+
+public enum HookHealthStatus {
+    
+    /**
+     * Hooks are firing normally (heartbeat within threshold)
+     */
+    case healthy
+    /**
+     * No heartbeat file exists (hooks never fired or file deleted)
+     */
+    case unknown
+    /**
+     * Heartbeat is stale (hooks stopped firing)
+     */
+    case stale(lastSeenSecs: UInt64
+    )
+    /**
+     * Heartbeat file exists but can't be read
+     */
+    case unreadable(reason: String
+    )
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSetupStatus: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SetupStatus {
-        return
-            try SetupStatus(
-                dependencies: FfiConverterSequenceTypeDependencyStatus.read(from: &buf),
-                hooks: FfiConverterTypeHookStatus.read(from: &buf),
-                storageReady: FfiConverterBool.read(from: &buf),
-                allReady: FfiConverterBool.read(from: &buf),
-                blockingReason: FfiConverterOptionString.read(from: &buf)
-            )
+public struct FfiConverterTypeHookHealthStatus: FfiConverterRustBuffer {
+    typealias SwiftType = HookHealthStatus
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookHealthStatus {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .healthy
+        
+        case 2: return .unknown
+        
+        case 3: return .stale(lastSeenSecs: try FfiConverterUInt64.read(from: &buf)
+        )
+        
+        case 4: return .unreadable(reason: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: SetupStatus, into buf: inout [UInt8]) {
-        FfiConverterSequenceTypeDependencyStatus.write(value.dependencies, into: &buf)
-        FfiConverterTypeHookStatus.write(value.hooks, into: &buf)
-        FfiConverterBool.write(value.storageReady, into: &buf)
-        FfiConverterBool.write(value.allReady, into: &buf)
-        FfiConverterOptionString.write(value.blockingReason, into: &buf)
+    public static func write(_ value: HookHealthStatus, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .healthy:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .unknown:
+            writeInt(&buf, Int32(2))
+        
+        
+        case let .stale(lastSeenSecs):
+            writeInt(&buf, Int32(3))
+            FfiConverterUInt64.write(lastSeenSecs, into: &buf)
+            
+        
+        case let .unreadable(reason):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(reason, into: &buf)
+            
+        }
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSetupStatus_lift(_ buf: RustBuffer) throws -> SetupStatus {
-    return try FfiConverterTypeSetupStatus.lift(buf)
+public func FfiConverterTypeHookHealthStatus_lift(_ buf: RustBuffer) throws -> HookHealthStatus {
+    return try FfiConverterTypeHookHealthStatus.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSetupStatus_lower(_ value: SetupStatus) -> RustBuffer {
-    return FfiConverterTypeSetupStatus.lower(value)
+public func FfiConverterTypeHookHealthStatus_lower(_ value: HookHealthStatus) -> RustBuffer {
+    return FfiConverterTypeHookHealthStatus.lower(value)
 }
 
+
+
+extension HookHealthStatus: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Shell state as read from `~/.capacitor/shell-cwd.json`.
+ * The primary issue preventing hooks from working correctly.
  *
- * This is the FFI-safe version of the shell state. Swift reads the JSON
- * and converts it to this type before passing to Rust.
+ * Issues are prioritized: policy blocks are shown first (can't auto-fix),
+ * then installation issues, then runtime issues.
  */
-public struct ShellCwdStateFfi {
-    public var version: UInt32
-    public var shells: [String: ShellEntryFfi]
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(version: UInt32, shells: [String: ShellEntryFfi]) {
-        self.version = version
-        self.shells = shells
-    }
+public enum HookIssue {
+    
+    /**
+     * Hooks disabled by policy (disableAllHooks or allowManagedHooksOnly)
+     */
+    case policyBlocked(reason: String
+    )
+    /**
+     * The hud-hook binary is not installed
+     */
+    case binaryMissing
+    /**
+     * The hud-hook binary exists but crashes (e.g., macOS codesigning)
+     */
+    case binaryBroken(reason: String
+    )
+    /**
+     * The hud-hook symlink exists but points to a missing target (app moved, cargo clean, etc.)
+     */
+    case symlinkBroken(target: String, reason: String
+    )
+    /**
+     * Hook configuration missing or incomplete in settings.json
+     */
+    case configMissing
+    /**
+     * Hooks are installed but not firing (heartbeat stale or missing)
+     */
+    case notFiring(lastSeenSecs: UInt64?
+    )
 }
 
-extension ShellCwdStateFfi: Equatable, Hashable {
-    public static func == (lhs: ShellCwdStateFfi, rhs: ShellCwdStateFfi) -> Bool {
-        if lhs.version != rhs.version {
-            return false
-        }
-        if lhs.shells != rhs.shells {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(version)
-        hasher.combine(shells)
-    }
-}
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeShellCwdStateFfi: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ShellCwdStateFfi {
-        return
-            try ShellCwdStateFfi(
-                version: FfiConverterUInt32.read(from: &buf),
-                shells: FfiConverterDictionaryStringTypeShellEntryFfi.read(from: &buf)
-            )
+public struct FfiConverterTypeHookIssue: FfiConverterRustBuffer {
+    typealias SwiftType = HookIssue
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookIssue {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .policyBlocked(reason: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .binaryMissing
+        
+        case 3: return .binaryBroken(reason: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 4: return .symlinkBroken(target: try FfiConverterString.read(from: &buf), reason: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 5: return .configMissing
+        
+        case 6: return .notFiring(lastSeenSecs: try FfiConverterOptionUInt64.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: ShellCwdStateFfi, into buf: inout [UInt8]) {
-        FfiConverterUInt32.write(value.version, into: &buf)
-        FfiConverterDictionaryStringTypeShellEntryFfi.write(value.shells, into: &buf)
+    public static func write(_ value: HookIssue, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .policyBlocked(reason):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(reason, into: &buf)
+            
+        
+        case .binaryMissing:
+            writeInt(&buf, Int32(2))
+        
+        
+        case let .binaryBroken(reason):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(reason, into: &buf)
+            
+        
+        case let .symlinkBroken(target,reason):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(target, into: &buf)
+            FfiConverterString.write(reason, into: &buf)
+            
+        
+        case .configMissing:
+            writeInt(&buf, Int32(5))
+        
+        
+        case let .notFiring(lastSeenSecs):
+            writeInt(&buf, Int32(6))
+            FfiConverterOptionUInt64.write(lastSeenSecs, into: &buf)
+            
+        }
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeShellCwdStateFfi_lift(_ buf: RustBuffer) throws -> ShellCwdStateFfi {
-    return try FfiConverterTypeShellCwdStateFfi.lift(buf)
+public func FfiConverterTypeHookIssue_lift(_ buf: RustBuffer) throws -> HookIssue {
+    return try FfiConverterTypeHookIssue.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeShellCwdStateFfi_lower(_ value: ShellCwdStateFfi) -> RustBuffer {
-    return FfiConverterTypeShellCwdStateFfi.lower(value)
+public func FfiConverterTypeHookIssue_lower(_ value: HookIssue) -> RustBuffer {
+    return FfiConverterTypeHookIssue.lower(value)
 }
 
-/**
- * A single shell entry from the shell state.
- */
-public struct ShellEntryFfi {
-    public var cwd: String
-    public var tty: String
-    public var parentApp: ParentApp
-    public var tmuxSession: String?
-    public var tmuxClientTty: String?
-    public var updatedAt: String
+
+
+extension HookIssue: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum HookStatus {
+    
+    case notInstalled
+    case installed(version: String
+    )
+    case policyBlocked(reason: String
+    )
+    case binaryBroken(reason: String
+    )
     /**
-     * Whether the shell process is still running (verified via kill(pid, 0))
+     * Symlink exists but target is missing (e.g., app moved or repo cleaned)
      */
-    public var isLive: Bool
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(cwd: String, tty: String, parentApp: ParentApp, tmuxSession: String?, tmuxClientTty: String?, updatedAt: String,
-                /**
-                    * Whether the shell process is still running (verified via kill(pid, 0))
-                    */ isLive: Bool)
-    {
-        self.cwd = cwd
-        self.tty = tty
-        self.parentApp = parentApp
-        self.tmuxSession = tmuxSession
-        self.tmuxClientTty = tmuxClientTty
-        self.updatedAt = updatedAt
-        self.isLive = isLive
-    }
+    case symlinkBroken(target: String, reason: String
+    )
 }
 
-extension ShellEntryFfi: Equatable, Hashable {
-    public static func == (lhs: ShellEntryFfi, rhs: ShellEntryFfi) -> Bool {
-        if lhs.cwd != rhs.cwd {
-            return false
-        }
-        if lhs.tty != rhs.tty {
-            return false
-        }
-        if lhs.parentApp != rhs.parentApp {
-            return false
-        }
-        if lhs.tmuxSession != rhs.tmuxSession {
-            return false
-        }
-        if lhs.tmuxClientTty != rhs.tmuxClientTty {
-            return false
-        }
-        if lhs.updatedAt != rhs.updatedAt {
-            return false
-        }
-        if lhs.isLive != rhs.isLive {
-            return false
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHookStatus: FfiConverterRustBuffer {
+    typealias SwiftType = HookStatus
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookStatus {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .notInstalled
+        
+        case 2: return .installed(version: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .policyBlocked(reason: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 4: return .binaryBroken(reason: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 5: return .symlinkBroken(target: try FfiConverterString.read(from: &buf), reason: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
         }
-        return true
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(cwd)
-        hasher.combine(tty)
-        hasher.combine(parentApp)
-        hasher.combine(tmuxSession)
-        hasher.combine(tmuxClientTty)
-        hasher.combine(updatedAt)
-        hasher.combine(isLive)
+    public static func write(_ value: HookStatus, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .notInstalled:
+            writeInt(&buf, Int32(1))
+        
+        
+        case let .installed(version):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(version, into: &buf)
+            
+        
+        case let .policyBlocked(reason):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(reason, into: &buf)
+            
+        
+        case let .binaryBroken(reason):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(reason, into: &buf)
+            
+        
+        case let .symlinkBroken(target,reason):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(target, into: &buf)
+            FfiConverterString.write(reason, into: &buf)
+            
+        }
     }
 }
 
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeShellEntryFfi: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ShellEntryFfi {
-        return
-            try ShellEntryFfi(
-                cwd: FfiConverterString.read(from: &buf),
-                tty: FfiConverterString.read(from: &buf),
-                parentApp: FfiConverterTypeParentApp.read(from: &buf),
-                tmuxSession: FfiConverterOptionString.read(from: &buf),
-                tmuxClientTty: FfiConverterOptionString.read(from: &buf),
-                updatedAt: FfiConverterString.read(from: &buf),
-                isLive: FfiConverterBool.read(from: &buf)
-            )
-    }
-
-    public static func write(_ value: ShellEntryFfi, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.cwd, into: &buf)
-        FfiConverterString.write(value.tty, into: &buf)
-        FfiConverterTypeParentApp.write(value.parentApp, into: &buf)
-        FfiConverterOptionString.write(value.tmuxSession, into: &buf)
-        FfiConverterOptionString.write(value.tmuxClientTty, into: &buf)
-        FfiConverterString.write(value.updatedAt, into: &buf)
-        FfiConverterBool.write(value.isLive, into: &buf)
-    }
-}
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeShellEntryFfi_lift(_ buf: RustBuffer) throws -> ShellEntryFfi {
-    return try FfiConverterTypeShellEntryFfi.lift(buf)
+public func FfiConverterTypeHookStatus_lift(_ buf: RustBuffer) throws -> HookStatus {
+    return try FfiConverterTypeHookStatus.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeShellEntryFfi_lower(_ value: ShellEntryFfi) -> RustBuffer {
-    return FfiConverterTypeShellEntryFfi.lower(value)
+public func FfiConverterTypeHookStatus_lower(_ value: HookStatus) -> RustBuffer {
+    return FfiConverterTypeHookStatus.lower(value)
 }
 
+
+
+extension HookStatus: Equatable, Hashable {}
+
+
+
+
 /**
- * The full stats cache, persisted to disk.
+ * FFI-safe error type for use across language boundaries.
+ *
+ * This simplified error type contains just an error message string,
+ * making it compatible with UniFFI's error handling.
  */
-public struct StatsCache {
-    public var projects: [String: CachedProjectStats]
+public enum HudFfiError {
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(projects: [String: CachedProjectStats]) {
-        self.projects = projects
-    }
+    
+    
+    case General(message: String
+    )
 }
 
-extension StatsCache: Equatable, Hashable {
-    public static func == (lhs: StatsCache, rhs: StatsCache) -> Bool {
-        if lhs.projects != rhs.projects {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(projects)
-    }
-}
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeStatsCache: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StatsCache {
-        return
-            try StatsCache(
-                projects: FfiConverterDictionaryStringTypeCachedProjectStats.read(from: &buf)
+public struct FfiConverterTypeHudFfiError: FfiConverterRustBuffer {
+    typealias SwiftType = HudFfiError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HudFfiError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .General(
+            message: try FfiConverterString.read(from: &buf)
             )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: StatsCache, into buf: inout [UInt8]) {
-        FfiConverterDictionaryStringTypeCachedProjectStats.write(value.projects, into: &buf)
+    public static func write(_ value: HudFfiError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .General(message):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(message, into: &buf)
+            
+        }
     }
 }
 
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-public func FfiConverterTypeStatsCache_lift(_ buf: RustBuffer) throws -> StatsCache {
-    return try FfiConverterTypeStatsCache.lift(buf)
-}
 
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-public func FfiConverterTypeStatsCache_lower(_ value: StatsCache) -> RustBuffer {
-    return FfiConverterTypeStatsCache.lower(value)
+extension HudFfiError: Equatable, Hashable {}
+
+extension HudFfiError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
 }
 
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * A project discovered in `~/.claude/projects/` but not yet pinned.
+ * IDE types for window activation via CLI.
  */
-public struct SuggestedProject {
-    public var path: String
-    public var displayPath: String
-    public var name: String
-    public var taskCount: UInt32
-    public var hasClaudeMd: Bool
-    public var hasProjectIndicators: Bool
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(path: String, displayPath: String, name: String, taskCount: UInt32, hasClaudeMd: Bool, hasProjectIndicators: Bool) {
-        self.path = path
-        self.displayPath = displayPath
-        self.name = name
-        self.taskCount = taskCount
-        self.hasClaudeMd = hasClaudeMd
-        self.hasProjectIndicators = hasProjectIndicators
-    }
+public enum IdeType {
+    
+    case cursor
+    case vsCode
+    case vsCodeInsiders
+    case zed
 }
 
-extension SuggestedProject: Equatable, Hashable {
-    public static func == (lhs: SuggestedProject, rhs: SuggestedProject) -> Bool {
-        if lhs.path != rhs.path {
-            return false
-        }
-        if lhs.displayPath != rhs.displayPath {
-            return false
-        }
-        if lhs.name != rhs.name {
-            return false
-        }
-        if lhs.taskCount != rhs.taskCount {
-            return false
-        }
-        if lhs.hasClaudeMd != rhs.hasClaudeMd {
-            return false
-        }
-        if lhs.hasProjectIndicators != rhs.hasProjectIndicators {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(path)
-        hasher.combine(displayPath)
-        hasher.combine(name)
-        hasher.combine(taskCount)
-        hasher.combine(hasClaudeMd)
-        hasher.combine(hasProjectIndicators)
-    }
-}
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSuggestedProject: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SuggestedProject {
-        return
-            try SuggestedProject(
-                path: FfiConverterString.read(from: &buf),
-                displayPath: FfiConverterString.read(from: &buf),
-                name: FfiConverterString.read(from: &buf),
-                taskCount: FfiConverterUInt32.read(from: &buf),
-                hasClaudeMd: FfiConverterBool.read(from: &buf),
-                hasProjectIndicators: FfiConverterBool.read(from: &buf)
-            )
+public struct FfiConverterTypeIdeType: FfiConverterRustBuffer {
+    typealias SwiftType = IdeType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> IdeType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .cursor
+        
+        case 2: return .vsCode
+        
+        case 3: return .vsCodeInsiders
+        
+        case 4: return .zed
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: SuggestedProject, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.path, into: &buf)
-        FfiConverterString.write(value.displayPath, into: &buf)
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterUInt32.write(value.taskCount, into: &buf)
-        FfiConverterBool.write(value.hasClaudeMd, into: &buf)
-        FfiConverterBool.write(value.hasProjectIndicators, into: &buf)
+    public static func write(_ value: IdeType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .cursor:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .vsCode:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .vsCodeInsiders:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .zed:
+            writeInt(&buf, Int32(4))
+        
+        }
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSuggestedProject_lift(_ buf: RustBuffer) throws -> SuggestedProject {
-    return try FfiConverterTypeSuggestedProject.lift(buf)
+public func FfiConverterTypeIdeType_lift(_ buf: RustBuffer) throws -> IdeType {
+    return try FfiConverterTypeIdeType.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSuggestedProject_lower(_ value: SuggestedProject) -> RustBuffer {
-    return FfiConverterTypeSuggestedProject.lower(value)
+public func FfiConverterTypeIdeType_lower(_ value: IdeType) -> RustBuffer {
+    return FfiConverterTypeIdeType.lower(value)
 }
 
-/**
- * A task/session from a project (represents Claude Code sessions).
- */
-public struct Task {
-    public var id: String
-    public var name: String
-    public var path: String
-    public var lastModified: String
-    public var summary: String?
-    public var firstMessage: String?
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(id: String, name: String, path: String, lastModified: String, summary: String?, firstMessage: String?) {
-        self.id = id
-        self.name = name
-        self.path = path
-        self.lastModified = lastModified
-        self.summary = summary
-        self.firstMessage = firstMessage
-    }
-}
 
-extension Task: Equatable, Hashable {
-    public static func == (lhs: Task, rhs: Task) -> Bool {
-        if lhs.id != rhs.id {
-            return false
-        }
-        if lhs.name != rhs.name {
-            return false
-        }
-        if lhs.path != rhs.path {
-            return false
-        }
-        if lhs.lastModified != rhs.lastModified {
-            return false
-        }
-        if lhs.summary != rhs.summary {
-            return false
-        }
-        if lhs.firstMessage != rhs.firstMessage {
-            return false
-        }
-        return true
-    }
+extension IdeType: Equatable, Hashable {}
+
+
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(id)
-        hasher.combine(name)
-        hasher.combine(path)
-        hasher.combine(lastModified)
-        hasher.combine(summary)
-        hasher.combine(firstMessage)
-    }
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Where an MCP server is configured.
+ */
+
+public enum McpScope {
+    
+    case global
+    case project
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTask: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Task {
-        return
-            try Task(
-                id: FfiConverterString.read(from: &buf),
-                name: FfiConverterString.read(from: &buf),
-                path: FfiConverterString.read(from: &buf),
-                lastModified: FfiConverterString.read(from: &buf),
-                summary: FfiConverterOptionString.read(from: &buf),
-                firstMessage: FfiConverterOptionString.read(from: &buf)
-            )
+public struct FfiConverterTypeMcpScope: FfiConverterRustBuffer {
+    typealias SwiftType = McpScope
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> McpScope {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .global
+        
+        case 2: return .project
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: Task, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.id, into: &buf)
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterString.write(value.path, into: &buf)
-        FfiConverterString.write(value.lastModified, into: &buf)
-        FfiConverterOptionString.write(value.summary, into: &buf)
-        FfiConverterOptionString.write(value.firstMessage, into: &buf)
+    public static func write(_ value: McpScope, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .global:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .project:
+            writeInt(&buf, Int32(2))
+        
+        }
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTask_lift(_ buf: RustBuffer) throws -> Task {
-    return try FfiConverterTypeTask.lift(buf)
+public func FfiConverterTypeMcpScope_lift(_ buf: RustBuffer) throws -> McpScope {
+    return try FfiConverterTypeMcpScope.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTask_lower(_ value: Task) -> RustBuffer {
-    return FfiConverterTypeTask.lower(value)
+public func FfiConverterTypeMcpScope_lower(_ value: McpScope) -> RustBuffer {
+    return FfiConverterTypeMcpScope.lower(value)
 }
 
+
+
+extension McpScope: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Context about tmux state, queried by Swift before calling the resolver.
+ * Model family, as inferred from a transcript's model id.
  */
-public struct TmuxContextFfi {
-    /**
-     * Session name if one exists at the project path
-     */
-    public var sessionAtPath: String?
-    /**
-     * Whether any tmux client is currently attached
-     */
-    public var hasAttachedClient: Bool
-    /**
-     * User's home directory (e.g., "/Users/pete") - excluded from parent matching
-     */
-    public var homeDir: String
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Session name if one exists at the project path
-         */ sessionAtPath: String?,
-        /**
-            * Whether any tmux client is currently attached
-            */ hasAttachedClient: Bool,
-        /**
-            * User's home directory (e.g., "/Users/pete") - excluded from parent matching
-            */ homeDir: String
-    ) {
-        self.sessionAtPath = sessionAtPath
-        self.hasAttachedClient = hasAttachedClient
-        self.homeDir = homeDir
-    }
+public enum ModelFamily {
+    
+    case opus
+    case sonnet
+    case haiku
+    case other
 }
 
-extension TmuxContextFfi: Equatable, Hashable {
-    public static func == (lhs: TmuxContextFfi, rhs: TmuxContextFfi) -> Bool {
-        if lhs.sessionAtPath != rhs.sessionAtPath {
-            return false
-        }
-        if lhs.hasAttachedClient != rhs.hasAttachedClient {
-            return false
-        }
-        if lhs.homeDir != rhs.homeDir {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(sessionAtPath)
-        hasher.combine(hasAttachedClient)
-        hasher.combine(homeDir)
-    }
-}
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTmuxContextFfi: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TmuxContextFfi {
-        return
-            try TmuxContextFfi(
-                sessionAtPath: FfiConverterOptionString.read(from: &buf),
-                hasAttachedClient: FfiConverterBool.read(from: &buf),
-                homeDir: FfiConverterString.read(from: &buf)
-            )
+public struct FfiConverterTypeModelFamily: FfiConverterRustBuffer {
+    typealias SwiftType = ModelFamily
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ModelFamily {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .opus
+        
+        case 2: return .sonnet
+        
+        case 3: return .haiku
+        
+        case 4: return .other
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: TmuxContextFfi, into buf: inout [UInt8]) {
-        FfiConverterOptionString.write(value.sessionAtPath, into: &buf)
-        FfiConverterBool.write(value.hasAttachedClient, into: &buf)
-        FfiConverterString.write(value.homeDir, into: &buf)
+    public static func write(_ value: ModelFamily, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .opus:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .sonnet:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .haiku:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .other:
+            writeInt(&buf, Int32(4))
+        
+        }
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTmuxContextFfi_lift(_ buf: RustBuffer) throws -> TmuxContextFfi {
-    return try FfiConverterTypeTmuxContextFfi.lift(buf)
+public func FfiConverterTypeModelFamily_lift(_ buf: RustBuffer) throws -> ModelFamily {
+    return try FfiConverterTypeModelFamily.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTmuxContextFfi_lower(_ value: TmuxContextFfi) -> RustBuffer {
-    return FfiConverterTypeTmuxContextFfi.lower(value)
+public func FfiConverterTypeModelFamily_lower(_ value: ModelFamily) -> RustBuffer {
+    return FfiConverterTypeModelFamily.lower(value)
 }
 
+
+
+extension ModelFamily: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * FFI-friendly validation result for Swift/Kotlin/Python.
+ * The parent application hosting a shell session.
  *
- * Uses flat structure instead of enum variants for better FFI compatibility.
+ * This is the authoritative enum for app identification, exported via UniFFI
+ * to Swift. All app classification logic should use this type rather than
+ * parsing strings directly.
+ *
+ * **JSON serialization:** Uses lowercase strings (e.g., `ParentApp::ITerm` → `"iterm2"`)
+ * for backward compatibility with existing `shell-cwd.json` files.
  */
-public struct ValidationResultFfi {
-    /**
-     * Result type: "valid", "suggest_parent", "missing_claude_md", "not_a_project", "path_not_found", "dangerous_path"
-     */
-    public var resultType: String
-    /**
-     * The path (canonical or as provided, depending on result type)
-     */
-    public var path: String
-    /**
-     * For suggest_parent: the suggested project path
-     */
-    public var suggestedPath: String?
-    /**
-     * Human-readable reason for the result
-     */
-    public var reason: String?
-    /**
-     * Whether the project has a CLAUDE.md file
-     */
-    public var hasClaudeMd: Bool
-    /**
-     * Whether the project has other markers (.git, package.json, etc.)
-     */
-    public var hasOtherMarkers: Bool
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Result type: "valid", "suggest_parent", "missing_claude_md", "not_a_project", "path_not_found", "dangerous_path"
-         */ resultType: String,
-        /**
-            * The path (canonical or as provided, depending on result type)
-            */ path: String,
-        /**
-            * For suggest_parent: the suggested project path
-            */ suggestedPath: String?,
-        /**
-            * Human-readable reason for the result
-            */ reason: String?,
-        /**
-            * Whether the project has a CLAUDE.md file
-            */ hasClaudeMd: Bool,
-        /**
-            * Whether the project has other markers (.git, package.json, etc.)
-            */ hasOtherMarkers: Bool
-    ) {
-        self.resultType = resultType
-        self.path = path
-        self.suggestedPath = suggestedPath
-        self.reason = reason
-        self.hasClaudeMd = hasClaudeMd
-        self.hasOtherMarkers = hasOtherMarkers
-    }
+public enum ParentApp {
+    
+    case ghostty
+    case iTerm
+    case terminal
+    case alacritty
+    case kitty
+    case warp
+    case cursor
+    case vsCode
+    case vsCodeInsiders
+    case zed
+    case tmux
+    case unknown
 }
 
-extension ValidationResultFfi: Equatable, Hashable {
-    public static func == (lhs: ValidationResultFfi, rhs: ValidationResultFfi) -> Bool {
-        if lhs.resultType != rhs.resultType {
-            return false
-        }
-        if lhs.path != rhs.path {
-            return false
-        }
-        if lhs.suggestedPath != rhs.suggestedPath {
-            return false
-        }
-        if lhs.reason != rhs.reason {
-            return false
-        }
-        if lhs.hasClaudeMd != rhs.hasClaudeMd {
-            return false
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeParentApp: FfiConverterRustBuffer {
+    typealias SwiftType = ParentApp
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ParentApp {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .ghostty
+        
+        case 2: return .iTerm
+        
+        case 3: return .terminal
+        
+        case 4: return .alacritty
+        
+        case 5: return .kitty
+        
+        case 6: return .warp
+        
+        case 7: return .cursor
+        
+        case 8: return .vsCode
+        
+        case 9: return .vsCodeInsiders
+        
+        case 10: return .zed
+        
+        case 11: return .tmux
+        
+        case 12: return .unknown
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
         }
-        if lhs.hasOtherMarkers != rhs.hasOtherMarkers {
-            return false
+    }
+
+    public static func write(_ value: ParentApp, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .ghostty:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .iTerm:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .terminal:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .alacritty:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .kitty:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .warp:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .cursor:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .vsCode:
+            writeInt(&buf, Int32(8))
+        
+        
+        case .vsCodeInsiders:
+            writeInt(&buf, Int32(9))
+        
+        
+        case .zed:
+            writeInt(&buf, Int32(10))
+        
+        
+        case .tmux:
+            writeInt(&buf, Int32(11))
+        
+        
+        case .unknown:
+            writeInt(&buf, Int32(12))
+        
         }
-        return true
     }
+}
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(resultType)
-        hasher.combine(path)
-        hasher.combine(suggestedPath)
-        hasher.combine(reason)
-        hasher.combine(hasClaudeMd)
-        hasher.combine(hasOtherMarkers)
-    }
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeParentApp_lift(_ buf: RustBuffer) throws -> ParentApp {
+    return try FfiConverterTypeParentApp.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeValidationResultFfi: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ValidationResultFfi {
-        return
-            try ValidationResultFfi(
-                resultType: FfiConverterString.read(from: &buf),
-                path: FfiConverterString.read(from: &buf),
-                suggestedPath: FfiConverterOptionString.read(from: &buf),
-                reason: FfiConverterOptionString.read(from: &buf),
-                hasClaudeMd: FfiConverterBool.read(from: &buf),
-                hasOtherMarkers: FfiConverterBool.read(from: &buf)
-            )
+public func FfiConverterTypeParentApp_lower(_ value: ParentApp) -> RustBuffer {
+    return FfiConverterTypeParentApp.lower(value)
+}
+
+
+
+extension ParentApp: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum PermissionOutcome {
+    
+    case approved
+    case denied
+    /**
+     * Prompted, but no tool result yet
+     */
+    case pending
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePermissionOutcome: FfiConverterRustBuffer {
+    typealias SwiftType = PermissionOutcome
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PermissionOutcome {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .approved
+        
+        case 2: return .denied
+        
+        case 3: return .pending
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: ValidationResultFfi, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.resultType, into: &buf)
-        FfiConverterString.write(value.path, into: &buf)
-        FfiConverterOptionString.write(value.suggestedPath, into: &buf)
-        FfiConverterOptionString.write(value.reason, into: &buf)
-        FfiConverterBool.write(value.hasClaudeMd, into: &buf)
-        FfiConverterBool.write(value.hasOtherMarkers, into: &buf)
+    public static func write(_ value: PermissionOutcome, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .approved:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .denied:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .pending:
+            writeInt(&buf, Int32(3))
+        
+        }
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeValidationResultFfi_lift(_ buf: RustBuffer) throws -> ValidationResultFfi {
-    return try FfiConverterTypeValidationResultFfi.lift(buf)
+public func FfiConverterTypePermissionOutcome_lift(_ buf: RustBuffer) throws -> PermissionOutcome {
+    return try FfiConverterTypePermissionOutcome.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeValidationResultFfi_lower(_ value: ValidationResultFfi) -> RustBuffer {
-    return FfiConverterTypeValidationResultFfi.lower(value)
+public func FfiConverterTypePermissionOutcome_lower(_ value: PermissionOutcome) -> RustBuffer {
+    return FfiConverterTypePermissionOutcome.lower(value)
 }
 
+
+
+extension PermissionOutcome: Equatable, Hashable {}
+
+
+
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * A single action for Swift to execute.
+ * Which config file [`open_project_config`] chose.
  */
 
-public enum ActivationAction {
-    /**
-     * Activate a terminal by querying for TTY ownership (AppleScript)
-     */
-    case activateByTty(tty: String, terminalType: TerminalType)
-    /**
-     * Activate app by bringing its window to front
-     */
-    case activateApp(appName: String
-    )
-    /**
-     * Focus kitty window by shell PID using `kitty @`
-     */
-    case activateKittyWindow(shellPid: UInt32
-    )
+public enum ProjectConfigKind {
+    
     /**
-     * Activate IDE and run CLI to focus correct window
-     */
-    case activateIdeWindow(ideType: IdeType, projectPath: String)
-    /**
-     * Switch tmux session in attached client
-     */
-    case switchTmuxSession(sessionName: String
-    )
-    /**
-     * Discover host terminal via TTY, then switch tmux session
-     */
-    case activateHostThenSwitchTmux(hostTty: String, sessionName: String)
-    /**
-     * Launch new terminal with tmux attach
+     * The project's `CLAUDE.md`
      */
-    case launchTerminalWithTmux(sessionName: String, projectPath: String)
+    case instructions
     /**
-     * Launch new terminal at project path (no tmux)
-     */
-    case launchNewTerminal(projectPath: String, projectName: String)
-    /**
-     * Activate first running terminal from priority list
+     * The project's `.claude/settings.local.json`
      */
-    case activatePriorityFallback
+    case localSettings
     /**
-     * Do nothing
+     * `~/.claude/settings.json`
      */
-    case skip
+    case globalSettings
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeActivationAction: FfiConverterRustBuffer {
-    typealias SwiftType = ActivationAction
+public struct FfiConverterTypeProjectConfigKind: FfiConverterRustBuffer {
+    typealias SwiftType = ProjectConfigKind
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ActivationAction {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProjectConfigKind {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-        case 1: return try .activateByTty(tty: FfiConverterString.read(from: &buf), terminalType: FfiConverterTypeTerminalType.read(from: &buf))
-
-        case 2: return try .activateApp(appName: FfiConverterString.read(from: &buf)
-            )
-
-        case 3: return try .activateKittyWindow(shellPid: FfiConverterUInt32.read(from: &buf)
-            )
-
-        case 4: return try .activateIdeWindow(ideType: FfiConverterTypeIdeType.read(from: &buf), projectPath: FfiConverterString.read(from: &buf))
-
-        case 5: return try .switchTmuxSession(sessionName: FfiConverterString.read(from: &buf)
-            )
-
-        case 6: return try .activateHostThenSwitchTmux(hostTty: FfiConverterString.read(from: &buf), sessionName: FfiConverterString.read(from: &buf))
-
-        case 7: return try .launchTerminalWithTmux(sessionName: FfiConverterString.read(from: &buf), projectPath: FfiConverterString.read(from: &buf))
-
-        case 8: return try .launchNewTerminal(projectPath: FfiConverterString.read(from: &buf), projectName: FfiConverterString.read(from: &buf))
-
-        case 9: return .activatePriorityFallback
-
-        case 10: return .skip
-
+        
+        case 1: return .instructions
+        
+        case 2: return .localSettings
+        
+        case 3: return .globalSettings
+        
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: ActivationAction, into buf: inout [UInt8]) {
+    public static func write(_ value: ProjectConfigKind, into buf: inout [UInt8]) {
         switch value {
-        case let .activateByTty(tty, terminalType):
+        
+        
+        case .instructions:
             writeInt(&buf, Int32(1))
-            FfiConverterString.write(tty, into: &buf)
-            FfiConverterTypeTerminalType.write(terminalType, into: &buf)
-
-        case let .activateApp(appName):
+        
+        
+        case .localSettings:
             writeInt(&buf, Int32(2))
-            FfiConverterString.write(appName, into: &buf)
-
-        case let .activateKittyWindow(shellPid):
+        
+        
+        case .globalSettings:
             writeInt(&buf, Int32(3))
-            FfiConverterUInt32.write(shellPid, into: &buf)
-
-        case let .activateIdeWindow(ideType, projectPath):
-            writeInt(&buf, Int32(4))
-            FfiConverterTypeIdeType.write(ideType, into: &buf)
-            FfiConverterString.write(projectPath, into: &buf)
-
-        case let .switchTmuxSession(sessionName):
-            writeInt(&buf, Int32(5))
-            FfiConverterString.write(sessionName, into: &buf)
-
-        case let .activateHostThenSwitchTmux(hostTty, sessionName):
-            writeInt(&buf, Int32(6))
-            FfiConverterString.write(hostTty, into: &buf)
-            FfiConverterString.write(sessionName, into: &buf)
-
-        case let .launchTerminalWithTmux(sessionName, projectPath):
-            writeInt(&buf, Int32(7))
-            FfiConverterString.write(sessionName, into: &buf)
-            FfiConverterString.write(projectPath, into: &buf)
-
-        case let .launchNewTerminal(projectPath, projectName):
-            writeInt(&buf, Int32(8))
-            FfiConverterString.write(projectPath, into: &buf)
-            FfiConverterString.write(projectName, into: &buf)
-
-        case .activatePriorityFallback:
-            writeInt(&buf, Int32(9))
-
-        case .skip:
-            writeInt(&buf, Int32(10))
+        
         }
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeActivationAction_lift(_ buf: RustBuffer) throws -> ActivationAction {
-    return try FfiConverterTypeActivationAction.lift(buf)
+public func FfiConverterTypeProjectConfigKind_lift(_ buf: RustBuffer) throws -> ProjectConfigKind {
+    return try FfiConverterTypeProjectConfigKind.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeActivationAction_lower(_ value: ActivationAction) -> RustBuffer {
-    return FfiConverterTypeActivationAction.lower(value)
+public func FfiConverterTypeProjectConfigKind_lower(_ value: ProjectConfigKind) -> RustBuffer {
+    return FfiConverterTypeProjectConfigKind.lower(value)
 }
 
-extension ActivationAction: Equatable, Hashable {}
+
+
+extension ProjectConfigKind: Equatable, Hashable {}
+
+
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Universal agent states - maps to any CLI agent's activity
+ * The current state of a Claude Code session.
+ *
+ * States this build doesn't know (written by a newer hook) read as Idle, so one
+ * unfamiliar record can't make the whole state file fail to load.
  */
 
-public enum AgentState {
-    case idle
-    case ready
+public enum SessionState {
+    
     case working
+    case ready
+    case idle
+    case compacting
     case waiting
+    /**
+     * Claude is reasoning before its next tool call or reply: set when a prompt
+     * is submitted or a tool returns, until the next tool call or stop.
+     */
+    case thinking
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeAgentState: FfiConverterRustBuffer {
-    typealias SwiftType = AgentState
+public struct FfiConverterTypeSessionState: FfiConverterRustBuffer {
+    typealias SwiftType = SessionState
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AgentState {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SessionState {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-        case 1: return .idle
-
+        
+        case 1: return .working
+        
         case 2: return .ready
-
-        case 3: return .working
-
-        case 4: return .waiting
-
+        
+        case 3: return .idle
+        
+        case 4: return .compacting
+        
+        case 5: return .waiting
+        
+        case 6: return .thinking
+        
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: AgentState, into buf: inout [UInt8]) {
+    public static func write(_ value: SessionState, into buf: inout [UInt8]) {
         switch value {
-        case .idle:
+        
+        
+        case .working:
             writeInt(&buf, Int32(1))
-
+        
+        
         case .ready:
             writeInt(&buf, Int32(2))
-
-        case .working:
+        
+        
+        case .idle:
             writeInt(&buf, Int32(3))
-
-        case .waiting:
+        
+        
+        case .compacting:
             writeInt(&buf, Int32(4))
+        
+        
+        case .waiting:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .thinking:
+            writeInt(&buf, Int32(6))
+        
         }
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeAgentState_lift(_ buf: RustBuffer) throws -> AgentState {
-    return try FfiConverterTypeAgentState.lift(buf)
+public func FfiConverterTypeSessionState_lift(_ buf: RustBuffer) throws -> SessionState {
+    return try FfiConverterTypeSessionState.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeAgentState_lower(_ value: AgentState) -> RustBuffer {
-    return FfiConverterTypeAgentState.lower(value)
-}
-
-extension AgentState: Equatable, Hashable {}
-
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * Known agent types - flat enum for UniFFI compatibility
- */
-
-public enum AgentType {
-    case claude
-    case codex
-    case aider
-    case amp
-    case openCode
-    case droid
-    case other
+public func FfiConverterTypeSessionState_lower(_ value: SessionState) -> RustBuffer {
+    return FfiConverterTypeSessionState.lower(value)
 }
 
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeAgentType: FfiConverterRustBuffer {
-    typealias SwiftType = AgentType
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AgentType {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return .claude
 
-        case 2: return .codex
+extension SessionState: Equatable, Hashable {}
 
-        case 3: return .aider
 
-        case 4: return .amp
 
-        case 5: return .openCode
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Terminal types that support TTY-based tab selection.
+ */
+
+public enum TerminalType {
+    
+    case iTerm
+    case terminalApp
+    case ghostty
+    case alacritty
+    case kitty
+    case warp
+    case unknown
+}
 
-        case 6: return .droid
 
-        case 7: return .other
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTerminalType: FfiConverterRustBuffer {
+    typealias SwiftType = TerminalType
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TerminalType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .iTerm
+        
+        case 2: return .terminalApp
+        
+        case 3: return .ghostty
+        
+        case 4: return .alacritty
+        
+        case 5: return .kitty
+        
+        case 6: return .warp
+        
+        case 7: return .unknown
+        
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: AgentType, into buf: inout [UInt8]) {
+    public static func write(_ value: TerminalType, into buf: inout [UInt8]) {
         switch value {
-        case .claude:
+        
+        
+        case .iTerm:
             writeInt(&buf, Int32(1))
-
-        case .codex:
+        
+        
+        case .terminalApp:
             writeInt(&buf, Int32(2))
-
-        case .aider:
+        
+        
+        case .ghostty:
             writeInt(&buf, Int32(3))
-
-        case .amp:
+        
+        
+        case .alacritty:
             writeInt(&buf, Int32(4))
-
-        case .openCode:
+        
+        
+        case .kitty:
             writeInt(&buf, Int32(5))
-
-        case .droid:
+        
+        
+        case .warp:
             writeInt(&buf, Int32(6))
-
-        case .other:
+        
+        
+        case .unknown:
             writeInt(&buf, Int32(7))
+        
         }
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeAgentType_lift(_ buf: RustBuffer) throws -> AgentType {
-    return try FfiConverterTypeAgentType.lift(buf)
+public func FfiConverterTypeTerminalType_lift(_ buf: RustBuffer) throws -> TerminalType {
+    return try FfiConverterTypeTerminalType.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeAgentType_lower(_ value: AgentType) -> RustBuffer {
-    return FfiConverterTypeAgentType.lower(value)
+public func FfiConverterTypeTerminalType_lower(_ value: TerminalType) -> RustBuffer {
+    return FfiConverterTypeTerminalType.lower(value)
 }
 
-extension AgentType: Equatable, Hashable {}
+
+
+extension TerminalType: Equatable, Hashable {}
+
+
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Status of a project creation.
+ * Which way a metric moved over a window.
  */
 
-public enum CreationStatus {
-    case pending
-    case inProgress
-    case completed
-    case failed
-    case cancelled
+public enum TrendDirection {
+    
+    case up
+    case down
+    /**
+     * Within a few percent, or too few active days to tell
+     */
+    case flat
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCreationStatus: FfiConverterRustBuffer {
-    typealias SwiftType = CreationStatus
+public struct FfiConverterTypeTrendDirection: FfiConverterRustBuffer {
+    typealias SwiftType = TrendDirection
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CreationStatus {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TrendDirection {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-        case 1: return .pending
-
-        case 2: return .inProgress
-
-        case 3: return .completed
-
-        case 4: return .failed
-
-        case 5: return .cancelled
-
+        
+        case 1: return .up
+        
+        case 2: return .down
+        
+        case 3: return .flat
+        
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: CreationStatus, into buf: inout [UInt8]) {
+    public static func write(_ value: TrendDirection, into buf: inout [UInt8]) {
         switch value {
-        case .pending:
+        
+        
+        case .up:
             writeInt(&buf, Int32(1))
-
-        case .inProgress:
+        
+        
+        case .down:
             writeInt(&buf, Int32(2))
-
-        case .completed:
+        
+        
+        case .flat:
             writeInt(&buf, Int32(3))
-
-        case .failed:
-            writeInt(&buf, Int32(4))
-
-        case .cancelled:
-            writeInt(&buf, Int32(5))
+        
         }
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCreationStatus_lift(_ buf: RustBuffer) throws -> CreationStatus {
-    return try FfiConverterTypeCreationStatus.lift(buf)
+public func FfiConverterTypeTrendDirection_lift(_ buf: RustBuffer) throws -> TrendDirection {
+    return try FfiConverterTypeTrendDirection.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCreationStatus_lower(_ value: CreationStatus) -> RustBuffer {
-    return FfiConverterTypeCreationStatus.lower(value)
+public func FfiConverterTypeTrendDirection_lower(_ value: TrendDirection) -> RustBuffer {
+    return FfiConverterTypeTrendDirection.lower(value)
 }
 
-extension CreationStatus: Equatable, Hashable {}
+
+
+extension TrendDirection: Equatable, Hashable {}
+
+
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * The health status of the hook binary based on heartbeat freshness.
+ * Time bucket size for usage time series.
  */
 
-public enum HookHealthStatus {
-    /**
-     * Hooks are firing normally (heartbeat within threshold)
-     */
-    case healthy
-    /**
-     * No heartbeat file exists (hooks never fired or file deleted)
-     */
-    case unknown
-    /**
-     * Heartbeat is stale (hooks stopped firing)
-     */
-    case stale(lastSeenSecs: UInt64
-    )
+public enum UsageBucket {
+    
+    case day
     /**
-     * Heartbeat file exists but can't be read
+     * ISO weeks, starting Monday
      */
-    case unreadable(reason: String
-    )
+    case week
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHookHealthStatus: FfiConverterRustBuffer {
-    typealias SwiftType = HookHealthStatus
+public struct FfiConverterTypeUsageBucket: FfiConverterRustBuffer {
+    typealias SwiftType = UsageBucket
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookHealthStatus {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UsageBucket {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-        case 1: return .healthy
-
-        case 2: return .unknown
-
-        case 3: return try .stale(lastSeenSecs: FfiConverterUInt64.read(from: &buf)
-            )
-
-        case 4: return try .unreadable(reason: FfiConverterString.read(from: &buf)
-            )
-
+        
+        case 1: return .day
+        
+        case 2: return .week
+        
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: HookHealthStatus, into buf: inout [UInt8]) {
+    public static func write(_ value: UsageBucket, into buf: inout [UInt8]) {
         switch value {
-        case .healthy:
+        
+        
+        case .day:
             writeInt(&buf, Int32(1))
-
-        case .unknown:
+        
+        
+        case .week:
             writeInt(&buf, Int32(2))
-
-        case let .stale(lastSeenSecs):
-            writeInt(&buf, Int32(3))
-            FfiConverterUInt64.write(lastSeenSecs, into: &buf)
-
-        case let .unreadable(reason):
-            writeInt(&buf, Int32(4))
-            FfiConverterString.write(reason, into: &buf)
+        
         }
     }
 }
 
+
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHookHealthStatus_lift(_ buf: RustBuffer) throws -> HookHealthStatus {
-    return try FfiConverterTypeHookHealthStatus.lift(buf)
+public func FfiConverterTypeUsageBucket_lift(_ buf: RustBuffer) throws -> UsageBucket {
+    return try FfiConverterTypeUsageBucket.lift(buf)
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHookHealthStatus_lower(_ value: HookHealthStatus) -> RustBuffer {
-    return FfiConverterTypeHookHealthStatus.lower(value)
+public func FfiConverterTypeUsageBucket_lower(_ value: UsageBucket) -> RustBuffer {
+    return FfiConverterTypeUsageBucket.lower(value)
 }
 
-extension HookHealthStatus: Equatable, Hashable {}
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * The primary issue preventing hooks from working correctly.
- *
- * Issues are prioritized: policy blocks are shown first (can't auto-fix),
- * then installation issues, then runtime issues.
- */
 
-public enum HookIssue {
-    /**
-     * Hooks disabled by policy (disableAllHooks or allowManagedHooksOnly)
-     */
-    case policyBlocked(reason: String
-    )
-    /**
-     * The hud-hook binary is not installed
-     */
-    case binaryMissing
-    /**
-     * The hud-hook binary exists but crashes (e.g., macOS codesigning)
-     */
-    case binaryBroken(reason: String
-    )
-    /**
-     * The hud-hook symlink exists but points to a missing target (app moved, cargo clean, etc.)
-     */
-    case symlinkBroken(target: String, reason: String)
-    /**
-     * Hook configuration missing or incomplete in settings.json
-     */
-    case configMissing
-    /**
-     * Hooks are installed but not firing (heartbeat stale or missing)
-     */
-    case notFiring(lastSeenSecs: UInt64?
-    )
+extension UsageBucket: Equatable, Hashable {}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt8: FfiConverterRustBuffer {
+    typealias SwiftType = UInt8?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt8.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt8.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHookIssue: FfiConverterRustBuffer {
-    typealias SwiftType = HookIssue
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookIssue {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return try .policyBlocked(reason: FfiConverterString.read(from: &buf)
-            )
-
-        case 2: return .binaryMissing
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
 
-        case 3: return try .binaryBroken(reason: FfiConverterString.read(from: &buf)
-            )
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
 
-        case 4: return try .symlinkBroken(target: FfiConverterString.read(from: &buf), reason: FfiConverterString.read(from: &buf))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
-        case 5: return .configMissing
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
+    typealias SwiftType = Double?
 
-        case 6: return try .notFiring(lastSeenSecs: FfiConverterOptionUInt64.read(from: &buf)
-            )
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterDouble.write(value, into: &buf)
+    }
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterDouble.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
+}
 
-    public static func write(_ value: HookIssue, into buf: inout [UInt8]) {
-        switch value {
-        case let .policyBlocked(reason):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(reason, into: &buf)
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
+    typealias SwiftType = Bool?
 
-        case .binaryMissing:
-            writeInt(&buf, Int32(2))
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterBool.write(value, into: &buf)
+    }
 
-        case let .binaryBroken(reason):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(reason, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterBool.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
-        case let .symlinkBroken(target, reason):
-            writeInt(&buf, Int32(4))
-            FfiConverterString.write(target, into: &buf)
-            FfiConverterString.write(reason, into: &buf)
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
 
-        case .configMissing:
-            writeInt(&buf, Int32(5))
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
 
-        case let .notFiring(lastSeenSecs):
-            writeInt(&buf, Int32(6))
-            FfiConverterOptionUInt64.write(lastSeenSecs, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHookIssue_lift(_ buf: RustBuffer) throws -> HookIssue {
-    return try FfiConverterTypeHookIssue.lift(buf)
+fileprivate struct FfiConverterOptionTypeActiveWorkingSession: FfiConverterRustBuffer {
+    typealias SwiftType = ActiveWorkingSession?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeActiveWorkingSession.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeActiveWorkingSession.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHookIssue_lower(_ value: HookIssue) -> RustBuffer {
-    return FfiConverterTypeHookIssue.lower(value)
+fileprivate struct FfiConverterOptionTypeAgentSession: FfiConverterRustBuffer {
+    typealias SwiftType = AgentSession?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeAgentSession.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeAgentSession.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-extension HookIssue: Equatable, Hashable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeArtifactOrigin: FfiConverterRustBuffer {
+    typealias SwiftType = ArtifactOrigin?
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeArtifactOrigin.write(value, into: &buf)
+    }
 
-public enum HookStatus {
-    case notInstalled
-    case installed(version: String
-    )
-    case policyBlocked(reason: String
-    )
-    case binaryBroken(reason: String
-    )
-    /**
-     * Symlink exists but target is missing (e.g., app moved or repo cleaned)
-     */
-    case symlinkBroken(target: String, reason: String)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeArtifactOrigin.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHookStatus: FfiConverterRustBuffer {
-    typealias SwiftType = HookStatus
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HookStatus {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return .notInstalled
+fileprivate struct FfiConverterOptionTypeBudgetStatus: FfiConverterRustBuffer {
+    typealias SwiftType = BudgetStatus?
 
-        case 2: return try .installed(version: FfiConverterString.read(from: &buf)
-            )
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeBudgetStatus.write(value, into: &buf)
+    }
 
-        case 3: return try .policyBlocked(reason: FfiConverterString.read(from: &buf)
-            )
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeBudgetStatus.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
-        case 4: return try .binaryBroken(reason: FfiConverterString.read(from: &buf)
-            )
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeContextBurnRate: FfiConverterRustBuffer {
+    typealias SwiftType = ContextBurnRate?
 
-        case 5: return try .symlinkBroken(target: FfiConverterString.read(from: &buf), reason: FfiConverterString.read(from: &buf))
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeContextBurnRate.write(value, into: &buf)
+    }
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeContextBurnRate.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
+}
 
-    public static func write(_ value: HookStatus, into buf: inout [UInt8]) {
-        switch value {
-        case .notInstalled:
-            writeInt(&buf, Int32(1))
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeContextInfo: FfiConverterRustBuffer {
+    typealias SwiftType = ContextInfo?
 
-        case let .installed(version):
-            writeInt(&buf, Int32(2))
-            FfiConverterString.write(version, into: &buf)
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeContextInfo.write(value, into: &buf)
+    }
 
-        case let .policyBlocked(reason):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(reason, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeContextInfo.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
-        case let .binaryBroken(reason):
-            writeInt(&buf, Int32(4))
-            FfiConverterString.write(reason, into: &buf)
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeCreationProgress: FfiConverterRustBuffer {
+    typealias SwiftType = CreationProgress?
 
-        case let .symlinkBroken(target, reason):
-            writeInt(&buf, Int32(5))
-            FfiConverterString.write(target, into: &buf)
-            FfiConverterString.write(reason, into: &buf)
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeCreationProgress.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeCreationProgress.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHookStatus_lift(_ buf: RustBuffer) throws -> HookStatus {
-    return try FfiConverterTypeHookStatus.lift(buf)
+fileprivate struct FfiConverterOptionTypeGlobalInstructions: FfiConverterRustBuffer {
+    typealias SwiftType = GlobalInstructions?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeGlobalInstructions.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeGlobalInstructions.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHookStatus_lower(_ value: HookStatus) -> RustBuffer {
-    return FfiConverterTypeHookStatus.lower(value)
-}
+fileprivate struct FfiConverterOptionTypeInstallResult: FfiConverterRustBuffer {
+    typealias SwiftType = InstallResult?
 
-extension HookStatus: Equatable, Hashable {}
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeInstallResult.write(value, into: &buf)
+    }
 
-/**
- * FFI-safe error type for use across language boundaries.
- *
- * This simplified error type contains just an error message string,
- * making it compatible with UniFFI's error handling.
- */
-public enum HudFfiError {
-    case General(message: String
-    )
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeInstallResult.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHudFfiError: FfiConverterRustBuffer {
-    typealias SwiftType = HudFfiError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HudFfiError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return try .General(
-                message: FfiConverterString.read(from: &buf)
-            )
+fileprivate struct FfiConverterOptionTypeProjectStats: FfiConverterRustBuffer {
+    typealias SwiftType = ProjectStats?
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeProjectStats.write(value, into: &buf)
     }
 
-    public static func write(_ value: HudFfiError, into buf: inout [UInt8]) {
-        switch value {
-        case let .General(message):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(message, into: &buf)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeProjectStats.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-extension HudFfiError: Equatable, Hashable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeProjectStatus: FfiConverterRustBuffer {
+    typealias SwiftType = ProjectStatus?
 
-extension HudFfiError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeProjectStatus.write(value, into: &buf)
     }
-}
-
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * IDE types for window activation via CLI.
- */
 
-public enum IdeType {
-    case cursor
-    case vsCode
-    case vsCodeInsiders
-    case zed
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeProjectStatus.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeIdeType: FfiConverterRustBuffer {
-    typealias SwiftType = IdeType
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> IdeType {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return .cursor
+fileprivate struct FfiConverterOptionTypeSessionLatency: FfiConverterRustBuffer {
+    typealias SwiftType = SessionLatency?
 
-        case 2: return .vsCode
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSessionLatency.write(value, into: &buf)
+    }
 
-        case 3: return .vsCodeInsiders
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSessionLatency.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
-        case 4: return .zed
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeSessionReceipt: FfiConverterRustBuffer {
+    typealias SwiftType = SessionReceipt?
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSessionReceipt.write(value, into: &buf)
     }
 
-    public static func write(_ value: IdeType, into buf: inout [UInt8]) {
-        switch value {
-        case .cursor:
-            writeInt(&buf, Int32(1))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSessionReceipt.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
-        case .vsCode:
-            writeInt(&buf, Int32(2))
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeShellCwdStateFfi: FfiConverterRustBuffer {
+    typealias SwiftType = ShellCwdStateFfi?
 
-        case .vsCodeInsiders:
-            writeInt(&buf, Int32(3))
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeShellCwdStateFfi.write(value, into: &buf)
+    }
 
-        case .zed:
-            writeInt(&buf, Int32(4))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeShellCwdStateFfi.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeIdeType_lift(_ buf: RustBuffer) throws -> IdeType {
-    return try FfiConverterTypeIdeType.lift(buf)
+fileprivate struct FfiConverterOptionTypeTtyTerminal: FfiConverterRustBuffer {
+    typealias SwiftType = TtyTerminal?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTtyTerminal.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTtyTerminal.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeIdeType_lower(_ value: IdeType) -> RustBuffer {
-    return FfiConverterTypeIdeType.lower(value)
+fileprivate struct FfiConverterOptionTypeActivationAction: FfiConverterRustBuffer {
+    typealias SwiftType = ActivationAction?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeActivationAction.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeActivationAction.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-extension IdeType: Equatable, Hashable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeHookIssue: FfiConverterRustBuffer {
+    typealias SwiftType = HookIssue?
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * The parent application hosting a shell session.
- *
- * This is the authoritative enum for app identification, exported via UniFFI
- * to Swift. All app classification logic should use this type rather than
- * parsing strings directly.
- *
- * **JSON serialization:** Uses lowercase strings (e.g., `ParentApp::ITerm` → `"iterm2"`)
- * for backward compatibility with existing `shell-cwd.json` files.
- */
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeHookIssue.write(value, into: &buf)
+    }
 
-public enum ParentApp {
-    case ghostty
-    case iTerm
-    case terminal
-    case alacritty
-    case kitty
-    case warp
-    case cursor
-    case vsCode
-    case vsCodeInsiders
-    case zed
-    case tmux
-    case unknown
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeHookIssue.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeParentApp: FfiConverterRustBuffer {
-    typealias SwiftType = ParentApp
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ParentApp {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return .ghostty
-
-        case 2: return .iTerm
+fileprivate struct FfiConverterOptionTypeSessionState: FfiConverterRustBuffer {
+    typealias SwiftType = SessionState?
 
-        case 3: return .terminal
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSessionState.write(value, into: &buf)
+    }
 
-        case 4: return .alacritty
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSessionState.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
-        case 5: return .kitty
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt32]
 
-        case 6: return .warp
+    public static func write(_ value: [UInt32], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt32.write(item, into: &buf)
+        }
+    }
 
-        case 7: return .cursor
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt32] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt32]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt32.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case 8: return .vsCode
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
 
-        case 9: return .vsCodeInsiders
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
 
-        case 10: return .zed
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case 11: return .tmux
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeAgentChange: FfiConverterRustBuffer {
+    typealias SwiftType = [AgentChange]
 
-        case 12: return .unknown
+    public static func write(_ value: [AgentChange], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeAgentChange.write(item, into: &buf)
+        }
+    }
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [AgentChange] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [AgentChange]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeAgentChange.read(from: &buf))
         }
+        return seq
     }
+}
 
-    public static func write(_ value: ParentApp, into buf: inout [UInt8]) {
-        switch value {
-        case .ghostty:
-            writeInt(&buf, Int32(1))
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeAgentSession: FfiConverterRustBuffer {
+    typealias SwiftType = [AgentSession]
 
-        case .iTerm:
-            writeInt(&buf, Int32(2))
+    public static func write(_ value: [AgentSession], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeAgentSession.write(item, into: &buf)
+        }
+    }
 
-        case .terminal:
-            writeInt(&buf, Int32(3))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [AgentSession] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [AgentSession]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeAgentSession.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case .alacritty:
-            writeInt(&buf, Int32(4))
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeArtifact: FfiConverterRustBuffer {
+    typealias SwiftType = [Artifact]
 
-        case .kitty:
-            writeInt(&buf, Int32(5))
+    public static func write(_ value: [Artifact], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeArtifact.write(item, into: &buf)
+        }
+    }
 
-        case .warp:
-            writeInt(&buf, Int32(6))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Artifact] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Artifact]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeArtifact.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case .cursor:
-            writeInt(&buf, Int32(7))
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeBrokenHook: FfiConverterRustBuffer {
+    typealias SwiftType = [BrokenHook]
 
-        case .vsCode:
-            writeInt(&buf, Int32(8))
+    public static func write(_ value: [BrokenHook], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeBrokenHook.write(item, into: &buf)
+        }
+    }
 
-        case .vsCodeInsiders:
-            writeInt(&buf, Int32(9))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [BrokenHook] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [BrokenHook]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeBrokenHook.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case .zed:
-            writeInt(&buf, Int32(10))
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeCompactingSession: FfiConverterRustBuffer {
+    typealias SwiftType = [CompactingSession]
 
-        case .tmux:
-            writeInt(&buf, Int32(11))
+    public static func write(_ value: [CompactingSession], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCompactingSession.write(item, into: &buf)
+        }
+    }
 
-        case .unknown:
-            writeInt(&buf, Int32(12))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CompactingSession] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [CompactingSession]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCompactingSession.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeParentApp_lift(_ buf: RustBuffer) throws -> ParentApp {
-    return try FfiConverterTypeParentApp.lift(buf)
+fileprivate struct FfiConverterSequenceTypeDependencyStatus: FfiConverterRustBuffer {
+    typealias SwiftType = [DependencyStatus]
+
+    public static func write(_ value: [DependencyStatus], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDependencyStatus.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DependencyStatus] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DependencyStatus]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDependencyStatus.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeParentApp_lower(_ value: ParentApp) -> RustBuffer {
-    return FfiConverterTypeParentApp.lower(value)
-}
-
-extension ParentApp: Equatable, Hashable {}
+fileprivate struct FfiConverterSequenceTypeDetectedTerminal: FfiConverterRustBuffer {
+    typealias SwiftType = [DetectedTerminal]
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * The current state of a Claude Code session.
- */
+    public static func write(_ value: [DetectedTerminal], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDetectedTerminal.write(item, into: &buf)
+        }
+    }
 
-public enum SessionState {
-    case working
-    case ready
-    case idle
-    case compacting
-    case waiting
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DetectedTerminal] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DetectedTerminal]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDetectedTerminal.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSessionState: FfiConverterRustBuffer {
-    typealias SwiftType = SessionState
+fileprivate struct FfiConverterSequenceTypeEffectiveArtifact: FfiConverterRustBuffer {
+    typealias SwiftType = [EffectiveArtifact]
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SessionState {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return .working
+    public static func write(_ value: [EffectiveArtifact], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeEffectiveArtifact.write(item, into: &buf)
+        }
+    }
 
-        case 2: return .ready
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [EffectiveArtifact] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [EffectiveArtifact]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeEffectiveArtifact.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case 3: return .idle
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeEfficiencyPoint: FfiConverterRustBuffer {
+    typealias SwiftType = [EfficiencyPoint]
 
-        case 4: return .compacting
+    public static func write(_ value: [EfficiencyPoint], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeEfficiencyPoint.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [EfficiencyPoint] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [EfficiencyPoint]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeEfficiencyPoint.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case 5: return .waiting
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeEncodingMismatch: FfiConverterRustBuffer {
+    typealias SwiftType = [EncodingMismatch]
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: [EncodingMismatch], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeEncodingMismatch.write(item, into: &buf)
         }
     }
 
-    public static func write(_ value: SessionState, into buf: inout [UInt8]) {
-        switch value {
-        case .working:
-            writeInt(&buf, Int32(1))
-
-        case .ready:
-            writeInt(&buf, Int32(2))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [EncodingMismatch] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [EncodingMismatch]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeEncodingMismatch.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case .idle:
-            writeInt(&buf, Int32(3))
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFileStatsContribution: FfiConverterRustBuffer {
+    typealias SwiftType = [FileStatsContribution]
 
-        case .compacting:
-            writeInt(&buf, Int32(4))
+    public static func write(_ value: [FileStatsContribution], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFileStatsContribution.write(item, into: &buf)
+        }
+    }
 
-        case .waiting:
-            writeInt(&buf, Int32(5))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FileStatsContribution] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FileStatsContribution]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFileStatsContribution.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSessionState_lift(_ buf: RustBuffer) throws -> SessionState {
-    return try FfiConverterTypeSessionState.lift(buf)
+fileprivate struct FfiConverterSequenceTypeHookLatencyStats: FfiConverterRustBuffer {
+    typealias SwiftType = [HookLatencyStats]
+
+    public static func write(_ value: [HookLatencyStats], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeHookLatencyStats.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [HookLatencyStats] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [HookLatencyStats]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeHookLatencyStats.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSessionState_lower(_ value: SessionState) -> RustBuffer {
-    return FfiConverterTypeSessionState.lower(value)
-}
+fileprivate struct FfiConverterSequenceTypeHostAppSessions: FfiConverterRustBuffer {
+    typealias SwiftType = [HostAppSessions]
 
-extension SessionState: Equatable, Hashable {}
-
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * Terminal types that support TTY-based tab selection.
- */
+    public static func write(_ value: [HostAppSessions], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeHostAppSessions.write(item, into: &buf)
+        }
+    }
 
-public enum TerminalType {
-    case iTerm
-    case terminalApp
-    case ghostty
-    case alacritty
-    case kitty
-    case warp
-    case unknown
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [HostAppSessions] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [HostAppSessions]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeHostAppSessions.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTerminalType: FfiConverterRustBuffer {
-    typealias SwiftType = TerminalType
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TerminalType {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        case 1: return .iTerm
+fileprivate struct FfiConverterSequenceTypeHotFile: FfiConverterRustBuffer {
+    typealias SwiftType = [HotFile]
 
-        case 2: return .terminalApp
+    public static func write(_ value: [HotFile], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeHotFile.write(item, into: &buf)
+        }
+    }
 
-        case 3: return .ghostty
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [HotFile] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [HotFile]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeHotFile.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case 4: return .alacritty
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeIdea: FfiConverterRustBuffer {
+    typealias SwiftType = [Idea]
 
-        case 5: return .kitty
+    public static func write(_ value: [Idea], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeIdea.write(item, into: &buf)
+        }
+    }
 
-        case 6: return .warp
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Idea] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Idea]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeIdea.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case 7: return .unknown
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeMaintenanceItem: FfiConverterRustBuffer {
+    typealias SwiftType = [MaintenanceItem]
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: [MaintenanceItem], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeMaintenanceItem.write(item, into: &buf)
         }
     }
 
-    public static func write(_ value: TerminalType, into buf: inout [UInt8]) {
-        switch value {
-        case .iTerm:
-            writeInt(&buf, Int32(1))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [MaintenanceItem] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [MaintenanceItem]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeMaintenanceItem.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case .terminalApp:
-            writeInt(&buf, Int32(2))
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeMcpServer: FfiConverterRustBuffer {
+    typealias SwiftType = [McpServer]
 
-        case .ghostty:
-            writeInt(&buf, Int32(3))
+    public static func write(_ value: [McpServer], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeMcpServer.write(item, into: &buf)
+        }
+    }
 
-        case .alacritty:
-            writeInt(&buf, Int32(4))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [McpServer] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [McpServer]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeMcpServer.read(from: &buf))
+        }
+        return seq
+    }
+}
 
-        case .kitty:
-            writeInt(&buf, Int32(5))
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeModelPeriodUsage: FfiConverterRustBuffer {
+    typealias SwiftType = [ModelPeriodUsage]
 
-        case .warp:
-            writeInt(&buf, Int32(6))
+    public static func write(_ value: [ModelPeriodUsage], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeModelPeriodUsage.write(item, into: &buf)
+        }
+    }
 
-        case .unknown:
-            writeInt(&buf, Int32(7))
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ModelPeriodUsage] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ModelPeriodUsage]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeModelPeriodUsage.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTerminalType_lift(_ buf: RustBuffer) throws -> TerminalType {
-    return try FfiConverterTypeTerminalType.lift(buf)
+fileprivate struct FfiConverterSequenceTypeModelUsagePoint: FfiConverterRustBuffer {
+    typealias SwiftType = [ModelUsagePoint]
+
+    public static func write(_ value: [ModelUsagePoint], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeModelUsagePoint.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ModelUsagePoint] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ModelUsagePoint]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeModelUsagePoint.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTerminalType_lower(_ value: TerminalType) -> RustBuffer {
-    return FfiConverterTypeTerminalType.lower(value)
-}
+fileprivate struct FfiConverterSequenceTypeNextStep: FfiConverterRustBuffer {
+    typealias SwiftType = [NextStep]
 
-extension TerminalType: Equatable, Hashable {}
+    public static func write(_ value: [NextStep], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeNextStep.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [NextStep] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [NextStep]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeNextStep.read(from: &buf))
+        }
+        return seq
+    }
+}
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionUInt8: FfiConverterRustBuffer {
-    typealias SwiftType = UInt8?
+fileprivate struct FfiConverterSequenceTypeOrphanedLiveSession: FfiConverterRustBuffer {
+    typealias SwiftType = [OrphanedLiveSession]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [OrphanedLiveSession], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeOrphanedLiveSession.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterUInt8.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterUInt8.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [OrphanedLiveSession] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [OrphanedLiveSession]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeOrphanedLiveSession.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
-    typealias SwiftType = UInt64?
+fileprivate struct FfiConverterSequenceTypeOverlappingPins: FfiConverterRustBuffer {
+    typealias SwiftType = [OverlappingPins]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [OverlappingPins], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeOverlappingPins.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterUInt64.write(value, into: &buf)
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterUInt64.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [OverlappingPins] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [OverlappingPins]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeOverlappingPins.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionBool: FfiConverterRustBuffer {
-    typealias SwiftType = Bool?
+fileprivate struct FfiConverterSequenceTypePermissionEntry: FfiConverterRustBuffer {
+    typealias SwiftType = [PermissionEntry]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [PermissionEntry], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypePermissionEntry.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterBool.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterBool.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [PermissionEntry] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [PermissionEntry]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypePermissionEntry.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
+fileprivate struct FfiConverterSequenceTypePermissionHeavySession: FfiConverterRustBuffer {
+    typealias SwiftType = [PermissionHeavySession]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [PermissionHeavySession], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypePermissionHeavySession.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [PermissionHeavySession] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [PermissionHeavySession]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypePermissionHeavySession.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionTypeAgentSession: FfiConverterRustBuffer {
-    typealias SwiftType = AgentSession?
+fileprivate struct FfiConverterSequenceTypePinnedProjectAudit: FfiConverterRustBuffer {
+    typealias SwiftType = [PinnedProjectAudit]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [PinnedProjectAudit], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypePinnedProjectAudit.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeAgentSession.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeAgentSession.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [PinnedProjectAudit] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [PinnedProjectAudit]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypePinnedProjectAudit.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionTypeContextInfo: FfiConverterRustBuffer {
-    typealias SwiftType = ContextInfo?
+fileprivate struct FfiConverterSequenceTypePlugin: FfiConverterRustBuffer {
+    typealias SwiftType = [Plugin]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [Plugin], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypePlugin.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeContextInfo.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeContextInfo.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Plugin] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Plugin]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypePlugin.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionTypeCreationProgress: FfiConverterRustBuffer {
-    typealias SwiftType = CreationProgress?
+fileprivate struct FfiConverterSequenceTypeProject: FfiConverterRustBuffer {
+    typealias SwiftType = [Project]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [Project], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeProject.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeCreationProgress.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeCreationProgress.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Project] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Project]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeProject.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionTypeProjectStats: FfiConverterRustBuffer {
-    typealias SwiftType = ProjectStats?
+fileprivate struct FfiConverterSequenceTypeProjectGroup: FfiConverterRustBuffer {
+    typealias SwiftType = [ProjectGroup]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [ProjectGroup], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeProjectGroup.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeProjectStats.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeProjectStats.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ProjectGroup] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ProjectGroup]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeProjectGroup.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionTypeProjectStatus: FfiConverterRustBuffer {
-    typealias SwiftType = ProjectStatus?
+fileprivate struct FfiConverterSequenceTypeProjectSubagents: FfiConverterRustBuffer {
+    typealias SwiftType = [ProjectSubagents]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [ProjectSubagents], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeProjectSubagents.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeProjectStatus.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeProjectStatus.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ProjectSubagents] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ProjectSubagents]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeProjectSubagents.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionTypeShellCwdStateFfi: FfiConverterRustBuffer {
-    typealias SwiftType = ShellCwdStateFfi?
+fileprivate struct FfiConverterSequenceTypeSessionCost: FfiConverterRustBuffer {
+    typealias SwiftType = [SessionCost]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [SessionCost], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSessionCost.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeShellCwdStateFfi.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeShellCwdStateFfi.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SessionCost] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SessionCost]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSessionCost.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionTypeActivationAction: FfiConverterRustBuffer {
-    typealias SwiftType = ActivationAction?
+fileprivate struct FfiConverterSequenceTypeSessionGroup: FfiConverterRustBuffer {
+    typealias SwiftType = [SessionGroup]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [SessionGroup], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSessionGroup.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeActivationAction.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeActivationAction.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SessionGroup] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SessionGroup]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSessionGroup.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterOptionTypeHookIssue: FfiConverterRustBuffer {
-    typealias SwiftType = HookIssue?
+fileprivate struct FfiConverterSequenceTypeSessionIdCollision: FfiConverterRustBuffer {
+    typealias SwiftType = [SessionIdCollision]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [SessionIdCollision], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSessionIdCollision.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeHookIssue.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeHookIssue.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SessionIdCollision] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SessionIdCollision]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSessionIdCollision.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]
+fileprivate struct FfiConverterSequenceTypeSessionWaitingEvent: FfiConverterRustBuffer {
+    typealias SwiftType = [SessionWaitingEvent]
 
-    public static func write(_ value: [String], into buf: inout [UInt8]) {
+    public static func write(_ value: [SessionWaitingEvent], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterString.write(item, into: &buf)
+            FfiConverterTypeSessionWaitingEvent.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SessionWaitingEvent] {
         let len: Int32 = try readInt(&buf)
-        var seq = [String]()
+        var seq = [SessionWaitingEvent]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterString.read(from: &buf))
+            seq.append(try FfiConverterTypeSessionWaitingEvent.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeAgentSession: FfiConverterRustBuffer {
-    typealias SwiftType = [AgentSession]
+fileprivate struct FfiConverterSequenceTypeStateFeedEvent: FfiConverterRustBuffer {
+    typealias SwiftType = [StateFeedEvent]
 
-    public static func write(_ value: [AgentSession], into buf: inout [UInt8]) {
+    public static func write(_ value: [StateFeedEvent], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeAgentSession.write(item, into: &buf)
+            FfiConverterTypeStateFeedEvent.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [AgentSession] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [StateFeedEvent] {
         let len: Int32 = try readInt(&buf)
-        var seq = [AgentSession]()
+        var seq = [StateFeedEvent]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeAgentSession.read(from: &buf))
+            seq.append(try FfiConverterTypeStateFeedEvent.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeArtifact: FfiConverterRustBuffer {
-    typealias SwiftType = [Artifact]
+fileprivate struct FfiConverterSequenceTypeStatsChangedEvent: FfiConverterRustBuffer {
+    typealias SwiftType = [StatsChangedEvent]
 
-    public static func write(_ value: [Artifact], into buf: inout [UInt8]) {
+    public static func write(_ value: [StatsChangedEvent], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeArtifact.write(item, into: &buf)
+            FfiConverterTypeStatsChangedEvent.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Artifact] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [StatsChangedEvent] {
         let len: Int32 = try readInt(&buf)
-        var seq = [Artifact]()
+        var seq = [StatsChangedEvent]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeArtifact.read(from: &buf))
+            seq.append(try FfiConverterTypeStatsChangedEvent.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeDependencyStatus: FfiConverterRustBuffer {
-    typealias SwiftType = [DependencyStatus]
+fileprivate struct FfiConverterSequenceTypeSuggestedProject: FfiConverterRustBuffer {
+    typealias SwiftType = [SuggestedProject]
 
-    public static func write(_ value: [DependencyStatus], into buf: inout [UInt8]) {
+    public static func write(_ value: [SuggestedProject], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeDependencyStatus.write(item, into: &buf)
+            FfiConverterTypeSuggestedProject.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DependencyStatus] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SuggestedProject] {
         let len: Int32 = try readInt(&buf)
-        var seq = [DependencyStatus]()
+        var seq = [SuggestedProject]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeDependencyStatus.read(from: &buf))
+            seq.append(try FfiConverterTypeSuggestedProject.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeIdea: FfiConverterRustBuffer {
-    typealias SwiftType = [Idea]
+fileprivate struct FfiConverterSequenceTypeTask: FfiConverterRustBuffer {
+    typealias SwiftType = [Task]
 
-    public static func write(_ value: [Idea], into buf: inout [UInt8]) {
+    public static func write(_ value: [Task], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeIdea.write(item, into: &buf)
+            FfiConverterTypeTask.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Idea] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Task] {
         let len: Int32 = try readInt(&buf)
-        var seq = [Idea]()
+        var seq = [Task]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeIdea.read(from: &buf))
+            seq.append(try FfiConverterTypeTask.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypePlugin: FfiConverterRustBuffer {
-    typealias SwiftType = [Plugin]
+fileprivate struct FfiConverterSequenceTypeTranscriptMessage: FfiConverterRustBuffer {
+    typealias SwiftType = [TranscriptMessage]
 
-    public static func write(_ value: [Plugin], into buf: inout [UInt8]) {
+    public static func write(_ value: [TranscriptMessage], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypePlugin.write(item, into: &buf)
+            FfiConverterTypeTranscriptMessage.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Plugin] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TranscriptMessage] {
         let len: Int32 = try readInt(&buf)
-        var seq = [Plugin]()
+        var seq = [TranscriptMessage]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypePlugin.read(from: &buf))
+            seq.append(try FfiConverterTypeTranscriptMessage.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeProject: FfiConverterRustBuffer {
-    typealias SwiftType = [Project]
+fileprivate struct FfiConverterSequenceTypeZombieSession: FfiConverterRustBuffer {
+    typealias SwiftType = [ZombieSession]
 
-    public static func write(_ value: [Project], into buf: inout [UInt8]) {
+    public static func write(_ value: [ZombieSession], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeProject.write(item, into: &buf)
+            FfiConverterTypeZombieSession.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Project] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ZombieSession] {
         let len: Int32 = try readInt(&buf)
-        var seq = [Project]()
+        var seq = [ZombieSession]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeProject.read(from: &buf))
+            seq.append(try FfiConverterTypeZombieSession.read(from: &buf))
         }
         return seq
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeSuggestedProject: FfiConverterRustBuffer {
-    typealias SwiftType = [SuggestedProject]
-
-    public static func write(_ value: [SuggestedProject], into buf: inout [UInt8]) {
+fileprivate struct FfiConverterDictionaryStringUInt32: FfiConverterRustBuffer {
+    public static func write(_ value: [String: UInt32], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeSuggestedProject.write(item, into: &buf)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterUInt32.write(value, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SuggestedProject] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: UInt32] {
         let len: Int32 = try readInt(&buf)
-        var seq = [SuggestedProject]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeSuggestedProject.read(from: &buf))
+        var dict = [String: UInt32]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterUInt32.read(from: &buf)
+            dict[key] = value
         }
-        return seq
+        return dict
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterSequenceTypeTask: FfiConverterRustBuffer {
-    typealias SwiftType = [Task]
-
-    public static func write(_ value: [Task], into buf: inout [UInt8]) {
+fileprivate struct FfiConverterDictionaryStringString: FfiConverterRustBuffer {
+    public static func write(_ value: [String: String], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeTask.write(item, into: &buf)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterString.write(value, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Task] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: String] {
         let len: Int32 = try readInt(&buf)
-        var seq = [Task]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            try seq.append(FfiConverterTypeTask.read(from: &buf))
+        var dict = [String: String]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterString.read(from: &buf)
+            dict[key] = value
         }
-        return seq
+        return dict
     }
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterDictionaryStringTypeCachedFileInfo: FfiConverterRustBuffer {
+fileprivate struct FfiConverterDictionaryStringTypeCachedFileInfo: FfiConverterRustBuffer {
     public static func write(_ value: [String: CachedFileInfo], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
@@ -6582,7 +16787,7 @@ private struct FfiConverterDictionaryStringTypeCachedFileInfo: FfiConverterRustB
         let len: Int32 = try readInt(&buf)
         var dict = [String: CachedFileInfo]()
         dict.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
+        for _ in 0..<len {
             let key = try FfiConverterString.read(from: &buf)
             let value = try FfiConverterTypeCachedFileInfo.read(from: &buf)
             dict[key] = value
@@ -6592,9 +16797,9 @@ private struct FfiConverterDictionaryStringTypeCachedFileInfo: FfiConverterRustB
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterDictionaryStringTypeCachedProjectStats: FfiConverterRustBuffer {
+fileprivate struct FfiConverterDictionaryStringTypeCachedProjectStats: FfiConverterRustBuffer {
     public static func write(_ value: [String: CachedProjectStats], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
@@ -6608,7 +16813,7 @@ private struct FfiConverterDictionaryStringTypeCachedProjectStats: FfiConverterR
         let len: Int32 = try readInt(&buf)
         var dict = [String: CachedProjectStats]()
         dict.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
+        for _ in 0..<len {
             let key = try FfiConverterString.read(from: &buf)
             let value = try FfiConverterTypeCachedProjectStats.read(from: &buf)
             dict[key] = value
@@ -6618,9 +16823,9 @@ private struct FfiConverterDictionaryStringTypeCachedProjectStats: FfiConverterR
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterDictionaryStringTypeProjectSessionState: FfiConverterRustBuffer {
+fileprivate struct FfiConverterDictionaryStringTypeProjectSessionState: FfiConverterRustBuffer {
     public static func write(_ value: [String: ProjectSessionState], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
@@ -6634,7 +16839,7 @@ private struct FfiConverterDictionaryStringTypeProjectSessionState: FfiConverter
         let len: Int32 = try readInt(&buf)
         var dict = [String: ProjectSessionState]()
         dict.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
+        for _ in 0..<len {
             let key = try FfiConverterString.read(from: &buf)
             let value = try FfiConverterTypeProjectSessionState.read(from: &buf)
             dict[key] = value
@@ -6644,9 +16849,9 @@ private struct FfiConverterDictionaryStringTypeProjectSessionState: FfiConverter
 }
 
 #if swift(>=5.8)
-    @_documentation(visibility: private)
+@_documentation(visibility: private)
 #endif
-private struct FfiConverterDictionaryStringTypeShellEntryFfi: FfiConverterRustBuffer {
+fileprivate struct FfiConverterDictionaryStringTypeShellEntryFfi: FfiConverterRustBuffer {
     public static func write(_ value: [String: ShellEntryFfi], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
@@ -6660,7 +16865,7 @@ private struct FfiConverterDictionaryStringTypeShellEntryFfi: FfiConverterRustBu
         let len: Int32 = try readInt(&buf)
         var dict = [String: ShellEntryFfi]()
         dict.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
+        for _ in 0..<len {
             let key = try FfiConverterString.read(from: &buf)
             let value = try FfiConverterTypeShellEntryFfi.read(from: &buf)
             dict[key] = value
@@ -6669,6 +16874,31 @@ private struct FfiConverterDictionaryStringTypeShellEntryFfi: FfiConverterRustBu
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDictionaryStringSequenceString: FfiConverterRustBuffer {
+    public static func write(_ value: [String: [String]], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterSequenceString.write(value, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: [String]] {
+        let len: Int32 = try readInt(&buf)
+        var dict = [String: [String]]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterSequenceString.read(from: &buf)
+            dict[key] = value
+        }
+        return dict
+    }
+}
 /**
  * Check if two paths refer to the same location or are parent/child.
  *
@@ -6679,12 +16909,12 @@ private struct FfiConverterDictionaryStringTypeShellEntryFfi: FfiConverterRustBu
  * This allows activating a shell in `/project/src` when clicking `/project`.
  */
 public func pathsMatch(a: String, b: String) -> Bool {
-    return try! FfiConverterBool.lift(try! rustCall {
-        uniffi_hud_core_fn_func_paths_match(
-            FfiConverterString.lower(a),
-            FfiConverterString.lower(b), $0
-        )
-    })
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_hud_core_fn_func_paths_match(
+        FfiConverterString.lower(a),
+        FfiConverterString.lower(b),$0
+    )
+})
 }
 
 private enum InitializationResult {
@@ -6692,7 +16922,6 @@ private enum InitializationResult {
     case contractVersionMismatch
     case apiChecksumMismatch
 }
-
 // Use a global variable to perform the versioning checks. Swift ensures that
 // the code inside is only computed once.
 private var initializationResult: InitializationResult = {
@@ -6703,130 +16932,403 @@ private var initializationResult: InitializationResult = {
     if bindings_contract_version != scaffolding_contract_version {
         return InitializationResult.contractVersionMismatch
     }
-    if uniffi_hud_core_checksum_func_paths_match() != 59842 {
+    if (uniffi_hud_core_checksum_func_paths_match() != 59842) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_active_working_session() != 54543) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_activity_sparkline() != 44407) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_activity_streak() != 14351) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_add_project() != 9786) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_agent_edit_diff() != 4378) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_artifact_origin() != 32984) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_audit_hooks() != 42835) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_audit_pinned_projects() != 60745) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_authorship_ratio() != 55768) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_backup_capacitor_state() != 55195) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_budget_status() != 4963) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_capacitor_dir() != 14668) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_capture_idea() != 29080) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_check_budget_threshold() != 27336) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_check_dependency() != 39610) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_check_global_instructions_changed() != 9475) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_check_hook_health() != 32052) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_check_setup_status() != 11613) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_classify_directory() != 38613) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_claude_dir() != 58851) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_clean_dead_locks() != 64695) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_clear_tombstones() != 20868) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_clock_skew_check() != 6889) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_compact_state_store() != 59616) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_compacting_sessions() != 55971) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_context_burn_rate() != 59041) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_create_project_claude_md() != 43265) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_delete_orphaned_dirs() != 62329) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_delete_project_history() != 42617) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_detect_session_id_collisions() != 30644) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_detect_terminals() != 14564) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_efficiency_trend() != 39753) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_export_dashboard_json() != 22186) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_export_session_trace() != 60731) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_export_stats_csv() != 45424) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_find_overlapping_pins() != 22313) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_find_zombie_sessions() != 3863) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_force_release_lock() != 36746) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_agent_sessions() != 47314) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_all_agent_sessions() != 27566) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_all_session_states() != 34670) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_config() != 46018) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_hook_diagnostic() != 34425) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_hook_status() != 42858) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_ideas_file_path() != 48517) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_primary_agent_session() != 36656) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_project_details() != 880) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_project_status() != 14524) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_session_context() != 1051) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_session_state() != 25344) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_session_transcript() != 53118) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_get_suggested_projects() != 38527) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_git_branch() != 54658) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_git_stash_count() != 51407) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_global_cache_savings_usd() != 58138) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_group_continued_sessions() != 12141) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_group_projects_by_root() != 52067) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_hook_latency_stats() != 35775) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_hot_files() != 5158) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_install_hook_binary_from_path() != 61995) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_install_hooks() != 27088) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_install_hooks_with_confirm() != 62342) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_invalidate_agent_cache() != 27475) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_is_git_repo() != 60847) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_launch_command() != 26988) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_list_artifacts() != 60146) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_list_installed_agents() != 5136) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_list_plugin_artifacts() != 63205) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_list_plugins() != 12518) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_list_projects() != 27816) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_load_dashboard() != 60780) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_load_ideas() != 23681) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_load_ideas_order() != 374) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_load_mcp_servers() != 45092) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_model_usage_timeseries() != 6406) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_next_steps_digest() != 58464) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_open_local_settings() != 21874) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_open_mcp_config() != 20966) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_open_project_config() != 8106) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_open_task_context() != 22070) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_orphaned_live_sessions() != 33414) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_permission_heavy_sessions() != 10085) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_permission_history() != 4630) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_poll_session_waiting() != 32732) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_poll_state_feed() != 47966) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_poll_stats_changes() != 55672) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_project_velocity() != 19552) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_projects_with_subagents() != 37166) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_prune_activity() != 21294) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_prune_missing_projects() != 32371) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hud_core_checksum_method_hudengine_prune_sessions() != 18823) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_add_project() != 9786 {
+    if (uniffi_hud_core_checksum_method_hudengine_read_global_instructions() != 55448) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_capacitor_dir() != 14668 {
+    if (uniffi_hud_core_checksum_method_hudengine_read_status_gen_log() != 43766) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_capture_idea() != 29080 {
+    if (uniffi_hud_core_checksum_method_hudengine_reap_stale_locks() != 54875) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_check_dependency() != 39610 {
+    if (uniffi_hud_core_checksum_method_hudengine_remove_project() != 46288) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_check_hook_health() != 32052 {
+    if (uniffi_hud_core_checksum_method_hudengine_remove_zombie_sessions() != 50647) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_check_setup_status() != 11613 {
+    if (uniffi_hud_core_checksum_method_hudengine_reorder_projects() != 71) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_claude_dir() != 58851 {
+    if (uniffi_hud_core_checksum_method_hudengine_repair_stats_cache() != 42447) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_create_project_claude_md() != 43265 {
+    if (uniffi_hud_core_checksum_method_hudengine_resolve_activation() != 6034) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_get_agent_sessions() != 47314 {
+    if (uniffi_hud_core_checksum_method_hudengine_resolve_effective_artifacts() != 61886) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_get_all_agent_sessions() != 27566 {
+    if (uniffi_hud_core_checksum_method_hudengine_resolve_path_state() != 568) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_get_all_session_states() != 34670 {
+    if (uniffi_hud_core_checksum_method_hudengine_restore_capacitor_state() != 6189) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_get_config() != 46018 {
+    if (uniffi_hud_core_checksum_method_hudengine_run_hook_test() != 45458) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_get_hook_diagnostic() != 34425 {
+    if (uniffi_hud_core_checksum_method_hudengine_run_startup_cleanup() != 39678) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_get_hook_status() != 42858 {
+    if (uniffi_hud_core_checksum_method_hudengine_save_ideas_order() != 34756) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_get_ideas_file_path() != 48517 {
+    if (uniffi_hud_core_checksum_method_hudengine_session_duration_stats() != 24045) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_get_primary_agent_session() != 36656 {
+    if (uniffi_hud_core_checksum_method_hudengine_session_latency() != 17041) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_get_project_status() != 14524 {
+    if (uniffi_hud_core_checksum_method_hudengine_session_receipt() != 51241) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_get_session_state() != 25344 {
+    if (uniffi_hud_core_checksum_method_hudengine_sessions_by_host_app() != 44851) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_get_suggested_projects() != 38527 {
+    if (uniffi_hud_core_checksum_method_hudengine_set_manual_order() != 28554) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_install_hook_binary_from_path() != 61995 {
+    if (uniffi_hud_core_checksum_method_hudengine_set_project_alias() != 354) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_install_hooks() != 3648 {
+    if (uniffi_hud_core_checksum_method_hudengine_set_watchers_paused() != 9472) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_invalidate_agent_cache() != 27475 {
+    if (uniffi_hud_core_checksum_method_hudengine_state_distribution() != 39246) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_list_artifacts() != 5270 {
+    if (uniffi_hud_core_checksum_method_hudengine_stats_breakdown() != 10651) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_list_installed_agents() != 5136 {
+    if (uniffi_hud_core_checksum_method_hudengine_subscribe_state_feed() != 10243) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_list_plugins() != 12518 {
+    if (uniffi_hud_core_checksum_method_hudengine_telemetry_context() != 7919) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_list_projects() != 27816 {
+    if (uniffi_hud_core_checksum_method_hudengine_terminal_for_tty() != 49580) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_load_dashboard() != 23101 {
+    if (uniffi_hud_core_checksum_method_hudengine_terminal_launch() != 35555) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_load_ideas() != 23681 {
+    if (uniffi_hud_core_checksum_method_hudengine_toggle_plugin_artifact() != 13350) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_load_ideas_order() != 374 {
+    if (uniffi_hud_core_checksum_method_hudengine_top_sessions_by_cost() != 46329) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_remove_project() != 46288 {
+    if (uniffi_hud_core_checksum_method_hudengine_uncommitted_agent_changes() != 20249) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_resolve_activation() != 6034 {
+    if (uniffi_hud_core_checksum_method_hudengine_unsubscribe_state_feed() != 8620) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_run_hook_test() != 45458 {
+    if (uniffi_hud_core_checksum_method_hudengine_update_idea_description() != 28488) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_run_startup_cleanup() != 39678 {
+    if (uniffi_hud_core_checksum_method_hudengine_update_idea_effort() != 54859) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_save_ideas_order() != 34756 {
+    if (uniffi_hud_core_checksum_method_hudengine_update_idea_status() != 241) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_update_idea_description() != 28488 {
+    if (uniffi_hud_core_checksum_method_hudengine_update_idea_title() != 28398) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_update_idea_effort() != 54859 {
+    if (uniffi_hud_core_checksum_method_hudengine_update_idea_triage() != 43321) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_update_idea_status() != 241 {
+    if (uniffi_hud_core_checksum_method_hudengine_usage_for_period() != 5419) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_update_idea_title() != 28398 {
+    if (uniffi_hud_core_checksum_method_hudengine_validate_project() != 446) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_update_idea_triage() != 43321 {
+    if (uniffi_hud_core_checksum_method_hudengine_verify_project_encoding() != 27949) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_method_hudengine_validate_project() != 446 {
+    if (uniffi_hud_core_checksum_method_hudengine_watchers_paused() != 46024) {
         return InitializationResult.apiChecksumMismatch
     }
-    if uniffi_hud_core_checksum_constructor_hudengine_new() != 11939 {
+    if (uniffi_hud_core_checksum_constructor_hudengine_new() != 11939) {
         return InitializationResult.apiChecksumMismatch
     }
 
@@ -6844,4 +17346,4 @@ private func uniffiEnsureInitialized() {
     }
 }
 
-// swiftlint:enable all
+// swiftlint:enable all
\ No newline at end of file
@@ -265,6 +265,21 @@ void uniffi_hud_core_fn_free_hudengine(void*_Nonnull ptr, RustCallStatus *_Nonnu
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_CONSTRUCTOR_HUDENGINE_NEW
 void*_Nonnull uniffi_hud_core_fn_constructor_hudengine_new(RustCallStatus *_Nonnull out_status
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_ACTIVE_WORKING_SESSION
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_ACTIVE_WORKING_SESSION
+RustBuffer uniffi_hud_core_fn_method_hudengine_active_working_session(void*_Nonnull ptr, RustBuffer shell_state, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_ACTIVITY_SPARKLINE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_ACTIVITY_SPARKLINE
+RustBuffer uniffi_hud_core_fn_method_hudengine_activity_sparkline(void*_Nonnull ptr, RustBuffer project_path, uint32_t buckets, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_ACTIVITY_STREAK
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_ACTIVITY_STREAK
+RustBuffer uniffi_hud_core_fn_method_hudengine_activity_streak(void*_Nonnull ptr, RustBuffer project_path, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_ADD_PROJECT
@@ -272,6 +287,41 @@ void*_Nonnull uniffi_hud_core_fn_constructor_hudengine_new(RustCallStatus *_Nonn
 void uniffi_hud_core_fn_method_hudengine_add_project(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_AGENT_EDIT_DIFF
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_AGENT_EDIT_DIFF
+RustBuffer uniffi_hud_core_fn_method_hudengine_agent_edit_diff(void*_Nonnull ptr, RustBuffer project_path, RustBuffer file_path, int8_t include_untracked, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_ARTIFACT_ORIGIN
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_ARTIFACT_ORIGIN
+RustBuffer uniffi_hud_core_fn_method_hudengine_artifact_origin(void*_Nonnull ptr, RustBuffer artifact_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_AUDIT_HOOKS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_AUDIT_HOOKS
+RustBuffer uniffi_hud_core_fn_method_hudengine_audit_hooks(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_AUDIT_PINNED_PROJECTS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_AUDIT_PINNED_PROJECTS
+RustBuffer uniffi_hud_core_fn_method_hudengine_audit_pinned_projects(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_AUTHORSHIP_RATIO
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_AUTHORSHIP_RATIO
+RustBuffer uniffi_hud_core_fn_method_hudengine_authorship_ratio(void*_Nonnull ptr, RustBuffer project_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_BACKUP_CAPACITOR_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_BACKUP_CAPACITOR_STATE
+uint32_t uniffi_hud_core_fn_method_hudengine_backup_capacitor_state(void*_Nonnull ptr, RustBuffer dest, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_BUDGET_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_BUDGET_STATUS
+RustBuffer uniffi_hud_core_fn_method_hudengine_budget_status(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CAPACITOR_DIR
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CAPACITOR_DIR
 RustBuffer uniffi_hud_core_fn_method_hudengine_capacitor_dir(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -282,11 +332,21 @@ RustBuffer uniffi_hud_core_fn_method_hudengine_capacitor_dir(void*_Nonnull ptr,
 RustBuffer uniffi_hud_core_fn_method_hudengine_capture_idea(void*_Nonnull ptr, RustBuffer project_path, RustBuffer idea_text, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CHECK_BUDGET_THRESHOLD
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CHECK_BUDGET_THRESHOLD
+RustBuffer uniffi_hud_core_fn_method_hudengine_check_budget_threshold(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CHECK_DEPENDENCY
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CHECK_DEPENDENCY
 RustBuffer uniffi_hud_core_fn_method_hudengine_check_dependency(void*_Nonnull ptr, RustBuffer name, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CHECK_GLOBAL_INSTRUCTIONS_CHANGED
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CHECK_GLOBAL_INSTRUCTIONS_CHANGED
+RustBuffer uniffi_hud_core_fn_method_hudengine_check_global_instructions_changed(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CHECK_HOOK_HEALTH
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CHECK_HOOK_HEALTH
 RustBuffer uniffi_hud_core_fn_method_hudengine_check_hook_health(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -297,16 +357,106 @@ RustBuffer uniffi_hud_core_fn_method_hudengine_check_hook_health(void*_Nonnull p
 RustBuffer uniffi_hud_core_fn_method_hudengine_check_setup_status(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CLASSIFY_DIRECTORY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CLASSIFY_DIRECTORY
+RustBuffer uniffi_hud_core_fn_method_hudengine_classify_directory(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CLAUDE_DIR
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CLAUDE_DIR
 RustBuffer uniffi_hud_core_fn_method_hudengine_claude_dir(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CLEAN_DEAD_LOCKS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CLEAN_DEAD_LOCKS
+uint32_t uniffi_hud_core_fn_method_hudengine_clean_dead_locks(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CLEAR_TOMBSTONES
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CLEAR_TOMBSTONES
+RustBuffer uniffi_hud_core_fn_method_hudengine_clear_tombstones(void*_Nonnull ptr, int8_t dry_run, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CLOCK_SKEW_CHECK
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CLOCK_SKEW_CHECK
+RustBuffer uniffi_hud_core_fn_method_hudengine_clock_skew_check(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_COMPACT_STATE_STORE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_COMPACT_STATE_STORE
+uint32_t uniffi_hud_core_fn_method_hudengine_compact_state_store(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_COMPACTING_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_COMPACTING_SESSIONS
+RustBuffer uniffi_hud_core_fn_method_hudengine_compacting_sessions(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CONTEXT_BURN_RATE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CONTEXT_BURN_RATE
+RustBuffer uniffi_hud_core_fn_method_hudengine_context_burn_rate(void*_Nonnull ptr, RustBuffer session_id, RustBuffer project_path, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CREATE_PROJECT_CLAUDE_MD
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_CREATE_PROJECT_CLAUDE_MD
 void uniffi_hud_core_fn_method_hudengine_create_project_claude_md(void*_Nonnull ptr, RustBuffer project_path, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_DELETE_ORPHANED_DIRS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_DELETE_ORPHANED_DIRS
+RustBuffer uniffi_hud_core_fn_method_hudengine_delete_orphaned_dirs(void*_Nonnull ptr, int8_t dry_run, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_DELETE_PROJECT_HISTORY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_DELETE_PROJECT_HISTORY
+uint64_t uniffi_hud_core_fn_method_hudengine_delete_project_history(void*_Nonnull ptr, RustBuffer project_path, RustBuffer confirm_token, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_DETECT_SESSION_ID_COLLISIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_DETECT_SESSION_ID_COLLISIONS
+RustBuffer uniffi_hud_core_fn_method_hudengine_detect_session_id_collisions(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_DETECT_TERMINALS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_DETECT_TERMINALS
+RustBuffer uniffi_hud_core_fn_method_hudengine_detect_terminals(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_EFFICIENCY_TREND
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_EFFICIENCY_TREND
+RustBuffer uniffi_hud_core_fn_method_hudengine_efficiency_trend(void*_Nonnull ptr, RustBuffer project_path, uint32_t days, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_EXPORT_DASHBOARD_JSON
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_EXPORT_DASHBOARD_JSON
+uint32_t uniffi_hud_core_fn_method_hudengine_export_dashboard_json(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_EXPORT_SESSION_TRACE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_EXPORT_SESSION_TRACE
+RustBuffer uniffi_hud_core_fn_method_hudengine_export_session_trace(void*_Nonnull ptr, RustBuffer session_id, RustBuffer project_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_EXPORT_STATS_CSV
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_EXPORT_STATS_CSV
+uint32_t uniffi_hud_core_fn_method_hudengine_export_stats_csv(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_FIND_OVERLAPPING_PINS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_FIND_OVERLAPPING_PINS
+RustBuffer uniffi_hud_core_fn_method_hudengine_find_overlapping_pins(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_FIND_ZOMBIE_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_FIND_ZOMBIE_SESSIONS
+RustBuffer uniffi_hud_core_fn_method_hudengine_find_zombie_sessions(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_FORCE_RELEASE_LOCK
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_FORCE_RELEASE_LOCK
+uint32_t uniffi_hud_core_fn_method_hudengine_force_release_lock(void*_Nonnull ptr, RustBuffer session_id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_AGENT_SESSIONS
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_AGENT_SESSIONS
 RustBuffer uniffi_hud_core_fn_method_hudengine_get_agent_sessions(void*_Nonnull ptr, RustBuffer project_path, RustCallStatus *_Nonnull out_status
@@ -347,21 +497,71 @@ RustBuffer uniffi_hud_core_fn_method_hudengine_get_ideas_file_path(void*_Nonnull
 RustBuffer uniffi_hud_core_fn_method_hudengine_get_primary_agent_session(void*_Nonnull ptr, RustBuffer project_path, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_PROJECT_DETAILS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_PROJECT_DETAILS
+RustBuffer uniffi_hud_core_fn_method_hudengine_get_project_details(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_PROJECT_STATUS
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_PROJECT_STATUS
 RustBuffer uniffi_hud_core_fn_method_hudengine_get_project_status(void*_Nonnull ptr, RustBuffer project_path, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_SESSION_CONTEXT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_SESSION_CONTEXT
+RustBuffer uniffi_hud_core_fn_method_hudengine_get_session_context(void*_Nonnull ptr, RustBuffer session_id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_SESSION_STATE
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_SESSION_STATE
 RustBuffer uniffi_hud_core_fn_method_hudengine_get_session_state(void*_Nonnull ptr, RustBuffer project_path, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_SESSION_TRANSCRIPT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_SESSION_TRANSCRIPT
+RustBuffer uniffi_hud_core_fn_method_hudengine_get_session_transcript(void*_Nonnull ptr, RustBuffer session_id, uint32_t limit, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_SUGGESTED_PROJECTS
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GET_SUGGESTED_PROJECTS
 RustBuffer uniffi_hud_core_fn_method_hudengine_get_suggested_projects(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GIT_BRANCH
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GIT_BRANCH
+RustBuffer uniffi_hud_core_fn_method_hudengine_git_branch(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GIT_STASH_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GIT_STASH_COUNT
+RustBuffer uniffi_hud_core_fn_method_hudengine_git_stash_count(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GLOBAL_CACHE_SAVINGS_USD
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GLOBAL_CACHE_SAVINGS_USD
+double uniffi_hud_core_fn_method_hudengine_global_cache_savings_usd(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GROUP_CONTINUED_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GROUP_CONTINUED_SESSIONS
+RustBuffer uniffi_hud_core_fn_method_hudengine_group_continued_sessions(void*_Nonnull ptr, RustBuffer project_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GROUP_PROJECTS_BY_ROOT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_GROUP_PROJECTS_BY_ROOT
+RustBuffer uniffi_hud_core_fn_method_hudengine_group_projects_by_root(void*_Nonnull ptr, uint32_t depth, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_HOOK_LATENCY_STATS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_HOOK_LATENCY_STATS
+RustBuffer uniffi_hud_core_fn_method_hudengine_hook_latency_stats(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_HOT_FILES
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_HOT_FILES
+RustBuffer uniffi_hud_core_fn_method_hudengine_hot_files(void*_Nonnull ptr, RustBuffer project_path, uint32_t limit, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_INSTALL_HOOK_BINARY_FROM_PATH
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_INSTALL_HOOK_BINARY_FROM_PATH
 RustBuffer uniffi_hud_core_fn_method_hudengine_install_hook_binary_from_path(void*_Nonnull ptr, RustBuffer source_path, RustCallStatus *_Nonnull out_status
@@ -372,11 +572,26 @@ RustBuffer uniffi_hud_core_fn_method_hudengine_install_hook_binary_from_path(voi
 RustBuffer uniffi_hud_core_fn_method_hudengine_install_hooks(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_INSTALL_HOOKS_WITH_CONFIRM
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_INSTALL_HOOKS_WITH_CONFIRM
+RustBuffer uniffi_hud_core_fn_method_hudengine_install_hooks_with_confirm(void*_Nonnull ptr, int8_t confirm, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_INVALIDATE_AGENT_CACHE
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_INVALIDATE_AGENT_CACHE
 void uniffi_hud_core_fn_method_hudengine_invalidate_agent_cache(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_IS_GIT_REPO
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_IS_GIT_REPO
+RustBuffer uniffi_hud_core_fn_method_hudengine_is_git_repo(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_LAUNCH_COMMAND
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_LAUNCH_COMMAND
+RustBuffer uniffi_hud_core_fn_method_hudengine_launch_command(void*_Nonnull ptr, RustBuffer project_path, RustBuffer claude_path, RustBuffer resume_session_id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_LIST_ARTIFACTS
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_LIST_ARTIFACTS
 RustBuffer uniffi_hud_core_fn_method_hudengine_list_artifacts(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -387,6 +602,11 @@ RustBuffer uniffi_hud_core_fn_method_hudengine_list_artifacts(void*_Nonnull ptr,
 RustBuffer uniffi_hud_core_fn_method_hudengine_list_installed_agents(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_LIST_PLUGIN_ARTIFACTS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_LIST_PLUGIN_ARTIFACTS
+RustBuffer uniffi_hud_core_fn_method_hudengine_list_plugin_artifacts(void*_Nonnull ptr, RustBuffer plugin_id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_LIST_PLUGINS
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_LIST_PLUGINS
 RustBuffer uniffi_hud_core_fn_method_hudengine_list_plugins(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -412,16 +632,151 @@ RustBuffer uniffi_hud_core_fn_method_hudengine_load_ideas(void*_Nonnull ptr, Rus
 RustBuffer uniffi_hud_core_fn_method_hudengine_load_ideas_order(void*_Nonnull ptr, RustBuffer project_path, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_LOAD_MCP_SERVERS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_LOAD_MCP_SERVERS
+RustBuffer uniffi_hud_core_fn_method_hudengine_load_mcp_servers(void*_Nonnull ptr, RustBuffer project_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_MODEL_USAGE_TIMESERIES
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_MODEL_USAGE_TIMESERIES
+RustBuffer uniffi_hud_core_fn_method_hudengine_model_usage_timeseries(void*_Nonnull ptr, RustBuffer project_path, RustBuffer bucket, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_NEXT_STEPS_DIGEST
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_NEXT_STEPS_DIGEST
+RustBuffer uniffi_hud_core_fn_method_hudengine_next_steps_digest(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_OPEN_LOCAL_SETTINGS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_OPEN_LOCAL_SETTINGS
+RustBuffer uniffi_hud_core_fn_method_hudengine_open_local_settings(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_OPEN_MCP_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_OPEN_MCP_CONFIG
+RustBuffer uniffi_hud_core_fn_method_hudengine_open_mcp_config(void*_Nonnull ptr, RustBuffer scope, RustBuffer project_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_OPEN_PROJECT_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_OPEN_PROJECT_CONFIG
+RustBuffer uniffi_hud_core_fn_method_hudengine_open_project_config(void*_Nonnull ptr, RustBuffer path, int8_t create_local_settings, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_OPEN_TASK_CONTEXT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_OPEN_TASK_CONTEXT
+RustBuffer uniffi_hud_core_fn_method_hudengine_open_task_context(void*_Nonnull ptr, RustBuffer session_id, RustBuffer project_path, RustBuffer editor, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_ORPHANED_LIVE_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_ORPHANED_LIVE_SESSIONS
+RustBuffer uniffi_hud_core_fn_method_hudengine_orphaned_live_sessions(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PERMISSION_HEAVY_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PERMISSION_HEAVY_SESSIONS
+RustBuffer uniffi_hud_core_fn_method_hudengine_permission_heavy_sessions(void*_Nonnull ptr, uint32_t limit, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PERMISSION_HISTORY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PERMISSION_HISTORY
+RustBuffer uniffi_hud_core_fn_method_hudengine_permission_history(void*_Nonnull ptr, RustBuffer session_id, RustBuffer project_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_POLL_SESSION_WAITING
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_POLL_SESSION_WAITING
+RustBuffer uniffi_hud_core_fn_method_hudengine_poll_session_waiting(void*_Nonnull ptr, uint64_t subscription_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_POLL_STATE_FEED
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_POLL_STATE_FEED
+RustBuffer uniffi_hud_core_fn_method_hudengine_poll_state_feed(void*_Nonnull ptr, uint64_t subscription_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_POLL_STATS_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_POLL_STATS_CHANGES
+RustBuffer uniffi_hud_core_fn_method_hudengine_poll_stats_changes(void*_Nonnull ptr, RustBuffer project_paths, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PROJECT_VELOCITY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PROJECT_VELOCITY
+RustBuffer uniffi_hud_core_fn_method_hudengine_project_velocity(void*_Nonnull ptr, RustBuffer project_path, uint32_t days, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PROJECTS_WITH_SUBAGENTS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PROJECTS_WITH_SUBAGENTS
+RustBuffer uniffi_hud_core_fn_method_hudengine_projects_with_subagents(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PRUNE_ACTIVITY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PRUNE_ACTIVITY
+RustBuffer uniffi_hud_core_fn_method_hudengine_prune_activity(void*_Nonnull ptr, int8_t dry_run, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PRUNE_MISSING_PROJECTS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PRUNE_MISSING_PROJECTS
+RustBuffer uniffi_hud_core_fn_method_hudengine_prune_missing_projects(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PRUNE_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_PRUNE_SESSIONS
+uint32_t uniffi_hud_core_fn_method_hudengine_prune_sessions(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_READ_GLOBAL_INSTRUCTIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_READ_GLOBAL_INSTRUCTIONS
+RustBuffer uniffi_hud_core_fn_method_hudengine_read_global_instructions(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_READ_STATUS_GEN_LOG
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_READ_STATUS_GEN_LOG
+RustBuffer uniffi_hud_core_fn_method_hudengine_read_status_gen_log(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_REAP_STALE_LOCKS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_REAP_STALE_LOCKS
+RustBuffer uniffi_hud_core_fn_method_hudengine_reap_stale_locks(void*_Nonnull ptr, int8_t dry_run, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_REMOVE_PROJECT
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_REMOVE_PROJECT
 void uniffi_hud_core_fn_method_hudengine_remove_project(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_REMOVE_ZOMBIE_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_REMOVE_ZOMBIE_SESSIONS
+RustBuffer uniffi_hud_core_fn_method_hudengine_remove_zombie_sessions(void*_Nonnull ptr, int8_t dry_run, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_REORDER_PROJECTS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_REORDER_PROJECTS
+void uniffi_hud_core_fn_method_hudengine_reorder_projects(void*_Nonnull ptr, RustBuffer paths, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_REPAIR_STATS_CACHE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_REPAIR_STATS_CACHE
+RustBuffer uniffi_hud_core_fn_method_hudengine_repair_stats_cache(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_RESOLVE_ACTIVATION
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_RESOLVE_ACTIVATION
 RustBuffer uniffi_hud_core_fn_method_hudengine_resolve_activation(void*_Nonnull ptr, RustBuffer project_path, RustBuffer shell_state, RustBuffer tmux_context, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_RESOLVE_EFFECTIVE_ARTIFACTS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_RESOLVE_EFFECTIVE_ARTIFACTS
+RustBuffer uniffi_hud_core_fn_method_hudengine_resolve_effective_artifacts(void*_Nonnull ptr, RustBuffer project_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_RESOLVE_PATH_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_RESOLVE_PATH_STATE
+RustBuffer uniffi_hud_core_fn_method_hudengine_resolve_path_state(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_RESTORE_CAPACITOR_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_RESTORE_CAPACITOR_STATE
+RustBuffer uniffi_hud_core_fn_method_hudengine_restore_capacitor_state(void*_Nonnull ptr, RustBuffer src, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_RUN_HOOK_TEST
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_RUN_HOOK_TEST
 RustBuffer uniffi_hud_core_fn_method_hudengine_run_hook_test(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
@@ -437,6 +792,91 @@ RustBuffer uniffi_hud_core_fn_method_hudengine_run_startup_cleanup(void*_Nonnull
 void uniffi_hud_core_fn_method_hudengine_save_ideas_order(void*_Nonnull ptr, RustBuffer project_path, RustBuffer idea_ids, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SESSION_DURATION_STATS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SESSION_DURATION_STATS
+RustBuffer uniffi_hud_core_fn_method_hudengine_session_duration_stats(void*_Nonnull ptr, RustBuffer project_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SESSION_LATENCY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SESSION_LATENCY
+RustBuffer uniffi_hud_core_fn_method_hudengine_session_latency(void*_Nonnull ptr, RustBuffer session_id, RustBuffer project_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SESSION_RECEIPT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SESSION_RECEIPT
+RustBuffer uniffi_hud_core_fn_method_hudengine_session_receipt(void*_Nonnull ptr, RustBuffer session_id, RustBuffer project_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SESSIONS_BY_HOST_APP
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SESSIONS_BY_HOST_APP
+RustBuffer uniffi_hud_core_fn_method_hudengine_sessions_by_host_app(void*_Nonnull ptr, RustBuffer shell_state, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SET_MANUAL_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SET_MANUAL_ORDER
+void uniffi_hud_core_fn_method_hudengine_set_manual_order(void*_Nonnull ptr, int8_t enabled, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SET_PROJECT_ALIAS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SET_PROJECT_ALIAS
+void uniffi_hud_core_fn_method_hudengine_set_project_alias(void*_Nonnull ptr, RustBuffer path, RustBuffer alias, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SET_WATCHERS_PAUSED
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SET_WATCHERS_PAUSED
+void uniffi_hud_core_fn_method_hudengine_set_watchers_paused(void*_Nonnull ptr, int8_t paused, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_STATE_DISTRIBUTION
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_STATE_DISTRIBUTION
+RustBuffer uniffi_hud_core_fn_method_hudengine_state_distribution(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_STATS_BREAKDOWN
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_STATS_BREAKDOWN
+RustBuffer uniffi_hud_core_fn_method_hudengine_stats_breakdown(void*_Nonnull ptr, RustBuffer project_path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SUBSCRIBE_STATE_FEED
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_SUBSCRIBE_STATE_FEED
+uint64_t uniffi_hud_core_fn_method_hudengine_subscribe_state_feed(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_TELEMETRY_CONTEXT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_TELEMETRY_CONTEXT
+RustBuffer uniffi_hud_core_fn_method_hudengine_telemetry_context(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_TERMINAL_FOR_TTY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_TERMINAL_FOR_TTY
+RustBuffer uniffi_hud_core_fn_method_hudengine_terminal_for_tty(void*_Nonnull ptr, RustBuffer tty, RustBuffer shell_state, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_TERMINAL_LAUNCH
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_TERMINAL_LAUNCH
+RustBuffer uniffi_hud_core_fn_method_hudengine_terminal_launch(void*_Nonnull ptr, RustBuffer project_path, int8_t run_claude, RustBuffer resume_session_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_TOGGLE_PLUGIN_ARTIFACT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_TOGGLE_PLUGIN_ARTIFACT
+void uniffi_hud_core_fn_method_hudengine_toggle_plugin_artifact(void*_Nonnull ptr, RustBuffer plugin_id, RustBuffer artifact_type, RustBuffer artifact_name, int8_t enabled, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_TOP_SESSIONS_BY_COST
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_TOP_SESSIONS_BY_COST
+RustBuffer uniffi_hud_core_fn_method_hudengine_top_sessions_by_cost(void*_Nonnull ptr, uint32_t limit, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_UNCOMMITTED_AGENT_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_UNCOMMITTED_AGENT_CHANGES
+RustBuffer uniffi_hud_core_fn_method_hudengine_uncommitted_agent_changes(void*_Nonnull ptr, RustBuffer project_path, int8_t include_untracked, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_UNSUBSCRIBE_STATE_FEED
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_UNSUBSCRIBE_STATE_FEED
+int8_t uniffi_hud_core_fn_method_hudengine_unsubscribe_state_feed(void*_Nonnull ptr, uint64_t subscription_id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_UPDATE_IDEA_DESCRIPTION
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_UPDATE_IDEA_DESCRIPTION
 void uniffi_hud_core_fn_method_hudengine_update_idea_description(void*_Nonnull ptr, RustBuffer project_path, RustBuffer idea_id, RustBuffer new_description, RustCallStatus *_Nonnull out_status
@@ -462,11 +902,26 @@ void uniffi_hud_core_fn_method_hudengine_update_idea_title(void*_Nonnull ptr, Ru
 void uniffi_hud_core_fn_method_hudengine_update_idea_triage(void*_Nonnull ptr, RustBuffer project_path, RustBuffer idea_id, RustBuffer new_triage, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_USAGE_FOR_PERIOD
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_USAGE_FOR_PERIOD
+RustBuffer uniffi_hud_core_fn_method_hudengine_usage_for_period(void*_Nonnull ptr, RustBuffer start, RustBuffer end, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_VALIDATE_PROJECT
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_VALIDATE_PROJECT
 RustBuffer uniffi_hud_core_fn_method_hudengine_validate_project(void*_Nonnull ptr, RustBuffer path, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_VERIFY_PROJECT_ENCODING
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_VERIFY_PROJECT_ENCODING
+RustBuffer uniffi_hud_core_fn_method_hudengine_verify_project_encoding(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_WATCHERS_PAUSED
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_METHOD_HUDENGINE_WATCHERS_PAUSED
+int8_t uniffi_hud_core_fn_method_hudengine_watchers_paused(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_FUNC_PATHS_MATCH
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_FN_FUNC_PATHS_MATCH
 int8_t uniffi_hud_core_fn_func_paths_match(RustBuffer a, RustBuffer b, RustCallStatus *_Nonnull out_status
@@ -756,12 +1211,72 @@ void ffi_hud_core_rust_future_complete_void(uint64_t handle, RustCallStatus *_No
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_FUNC_PATHS_MATCH
 uint16_t uniffi_hud_core_checksum_func_paths_match(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_ACTIVE_WORKING_SESSION
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_ACTIVE_WORKING_SESSION
+uint16_t uniffi_hud_core_checksum_method_hudengine_active_working_session(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_ACTIVITY_SPARKLINE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_ACTIVITY_SPARKLINE
+uint16_t uniffi_hud_core_checksum_method_hudengine_activity_sparkline(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_ACTIVITY_STREAK
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_ACTIVITY_STREAK
+uint16_t uniffi_hud_core_checksum_method_hudengine_activity_streak(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_ADD_PROJECT
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_ADD_PROJECT
 uint16_t uniffi_hud_core_checksum_method_hudengine_add_project(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_AGENT_EDIT_DIFF
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_AGENT_EDIT_DIFF
+uint16_t uniffi_hud_core_checksum_method_hudengine_agent_edit_diff(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_ARTIFACT_ORIGIN
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_ARTIFACT_ORIGIN
+uint16_t uniffi_hud_core_checksum_method_hudengine_artifact_origin(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_AUDIT_HOOKS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_AUDIT_HOOKS
+uint16_t uniffi_hud_core_checksum_method_hudengine_audit_hooks(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_AUDIT_PINNED_PROJECTS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_AUDIT_PINNED_PROJECTS
+uint16_t uniffi_hud_core_checksum_method_hudengine_audit_pinned_projects(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_AUTHORSHIP_RATIO
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_AUTHORSHIP_RATIO
+uint16_t uniffi_hud_core_checksum_method_hudengine_authorship_ratio(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_BACKUP_CAPACITOR_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_BACKUP_CAPACITOR_STATE
+uint16_t uniffi_hud_core_checksum_method_hudengine_backup_capacitor_state(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_BUDGET_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_BUDGET_STATUS
+uint16_t uniffi_hud_core_checksum_method_hudengine_budget_status(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CAPACITOR_DIR
@@ -774,12 +1289,24 @@ uint16_t uniffi_hud_core_checksum_method_hudengine_capacitor_dir(void
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CAPTURE_IDEA
 uint16_t uniffi_hud_core_checksum_method_hudengine_capture_idea(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CHECK_BUDGET_THRESHOLD
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CHECK_BUDGET_THRESHOLD
+uint16_t uniffi_hud_core_checksum_method_hudengine_check_budget_threshold(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CHECK_DEPENDENCY
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CHECK_DEPENDENCY
 uint16_t uniffi_hud_core_checksum_method_hudengine_check_dependency(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CHECK_GLOBAL_INSTRUCTIONS_CHANGED
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CHECK_GLOBAL_INSTRUCTIONS_CHANGED
+uint16_t uniffi_hud_core_checksum_method_hudengine_check_global_instructions_changed(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CHECK_HOOK_HEALTH
@@ -792,18 +1319,126 @@ uint16_t uniffi_hud_core_checksum_method_hudengine_check_hook_health(void
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CHECK_SETUP_STATUS
 uint16_t uniffi_hud_core_checksum_method_hudengine_check_setup_status(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CLASSIFY_DIRECTORY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CLASSIFY_DIRECTORY
+uint16_t uniffi_hud_core_checksum_method_hudengine_classify_directory(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CLAUDE_DIR
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CLAUDE_DIR
 uint16_t uniffi_hud_core_checksum_method_hudengine_claude_dir(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CLEAN_DEAD_LOCKS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CLEAN_DEAD_LOCKS
+uint16_t uniffi_hud_core_checksum_method_hudengine_clean_dead_locks(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CLEAR_TOMBSTONES
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CLEAR_TOMBSTONES
+uint16_t uniffi_hud_core_checksum_method_hudengine_clear_tombstones(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CLOCK_SKEW_CHECK
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CLOCK_SKEW_CHECK
+uint16_t uniffi_hud_core_checksum_method_hudengine_clock_skew_check(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_COMPACT_STATE_STORE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_COMPACT_STATE_STORE
+uint16_t uniffi_hud_core_checksum_method_hudengine_compact_state_store(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_COMPACTING_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_COMPACTING_SESSIONS
+uint16_t uniffi_hud_core_checksum_method_hudengine_compacting_sessions(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CONTEXT_BURN_RATE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CONTEXT_BURN_RATE
+uint16_t uniffi_hud_core_checksum_method_hudengine_context_burn_rate(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CREATE_PROJECT_CLAUDE_MD
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_CREATE_PROJECT_CLAUDE_MD
 uint16_t uniffi_hud_core_checksum_method_hudengine_create_project_claude_md(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_DELETE_ORPHANED_DIRS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_DELETE_ORPHANED_DIRS
+uint16_t uniffi_hud_core_checksum_method_hudengine_delete_orphaned_dirs(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_DELETE_PROJECT_HISTORY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_DELETE_PROJECT_HISTORY
+uint16_t uniffi_hud_core_checksum_method_hudengine_delete_project_history(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_DETECT_SESSION_ID_COLLISIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_DETECT_SESSION_ID_COLLISIONS
+uint16_t uniffi_hud_core_checksum_method_hudengine_detect_session_id_collisions(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_DETECT_TERMINALS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_DETECT_TERMINALS
+uint16_t uniffi_hud_core_checksum_method_hudengine_detect_terminals(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_EFFICIENCY_TREND
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_EFFICIENCY_TREND
+uint16_t uniffi_hud_core_checksum_method_hudengine_efficiency_trend(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_EXPORT_DASHBOARD_JSON
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_EXPORT_DASHBOARD_JSON
+uint16_t uniffi_hud_core_checksum_method_hudengine_export_dashboard_json(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_EXPORT_SESSION_TRACE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_EXPORT_SESSION_TRACE
+uint16_t uniffi_hud_core_checksum_method_hudengine_export_session_trace(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_EXPORT_STATS_CSV
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_EXPORT_STATS_CSV
+uint16_t uniffi_hud_core_checksum_method_hudengine_export_stats_csv(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_FIND_OVERLAPPING_PINS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_FIND_OVERLAPPING_PINS
+uint16_t uniffi_hud_core_checksum_method_hudengine_find_overlapping_pins(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_FIND_ZOMBIE_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_FIND_ZOMBIE_SESSIONS
+uint16_t uniffi_hud_core_checksum_method_hudengine_find_zombie_sessions(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_FORCE_RELEASE_LOCK
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_FORCE_RELEASE_LOCK
+uint16_t uniffi_hud_core_checksum_method_hudengine_force_release_lock(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_AGENT_SESSIONS
@@ -852,24 +1487,84 @@ uint16_t uniffi_hud_core_checksum_method_hudengine_get_ideas_file_path(void
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_PRIMARY_AGENT_SESSION
 uint16_t uniffi_hud_core_checksum_method_hudengine_get_primary_agent_session(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_PROJECT_DETAILS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_PROJECT_DETAILS
+uint16_t uniffi_hud_core_checksum_method_hudengine_get_project_details(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_PROJECT_STATUS
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_PROJECT_STATUS
 uint16_t uniffi_hud_core_checksum_method_hudengine_get_project_status(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_SESSION_CONTEXT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_SESSION_CONTEXT
+uint16_t uniffi_hud_core_checksum_method_hudengine_get_session_context(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_SESSION_STATE
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_SESSION_STATE
 uint16_t uniffi_hud_core_checksum_method_hudengine_get_session_state(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_SESSION_TRANSCRIPT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_SESSION_TRANSCRIPT
+uint16_t uniffi_hud_core_checksum_method_hudengine_get_session_transcript(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_SUGGESTED_PROJECTS
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GET_SUGGESTED_PROJECTS
 uint16_t uniffi_hud_core_checksum_method_hudengine_get_suggested_projects(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GIT_BRANCH
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GIT_BRANCH
+uint16_t uniffi_hud_core_checksum_method_hudengine_git_branch(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GIT_STASH_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GIT_STASH_COUNT
+uint16_t uniffi_hud_core_checksum_method_hudengine_git_stash_count(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GLOBAL_CACHE_SAVINGS_USD
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GLOBAL_CACHE_SAVINGS_USD
+uint16_t uniffi_hud_core_checksum_method_hudengine_global_cache_savings_usd(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GROUP_CONTINUED_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GROUP_CONTINUED_SESSIONS
+uint16_t uniffi_hud_core_checksum_method_hudengine_group_continued_sessions(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GROUP_PROJECTS_BY_ROOT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_GROUP_PROJECTS_BY_ROOT
+uint16_t uniffi_hud_core_checksum_method_hudengine_group_projects_by_root(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_HOOK_LATENCY_STATS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_HOOK_LATENCY_STATS
+uint16_t uniffi_hud_core_checksum_method_hudengine_hook_latency_stats(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_HOT_FILES
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_HOT_FILES
+uint16_t uniffi_hud_core_checksum_method_hudengine_hot_files(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_INSTALL_HOOK_BINARY_FROM_PATH
@@ -882,12 +1577,30 @@ uint16_t uniffi_hud_core_checksum_method_hudengine_install_hook_binary_from_path
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_INSTALL_HOOKS
 uint16_t uniffi_hud_core_checksum_method_hudengine_install_hooks(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_INSTALL_HOOKS_WITH_CONFIRM
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_INSTALL_HOOKS_WITH_CONFIRM
+uint16_t uniffi_hud_core_checksum_method_hudengine_install_hooks_with_confirm(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_INVALIDATE_AGENT_CACHE
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_INVALIDATE_AGENT_CACHE
 uint16_t uniffi_hud_core_checksum_method_hudengine_invalidate_agent_cache(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_IS_GIT_REPO
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_IS_GIT_REPO
+uint16_t uniffi_hud_core_checksum_method_hudengine_is_git_repo(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_LAUNCH_COMMAND
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_LAUNCH_COMMAND
+uint16_t uniffi_hud_core_checksum_method_hudengine_launch_command(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_LIST_ARTIFACTS
@@ -900,6 +1613,12 @@ uint16_t uniffi_hud_core_checksum_method_hudengine_list_artifacts(void
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_LIST_INSTALLED_AGENTS
 uint16_t uniffi_hud_core_checksum_method_hudengine_list_installed_agents(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_LIST_PLUGIN_ARTIFACTS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_LIST_PLUGIN_ARTIFACTS
+uint16_t uniffi_hud_core_checksum_method_hudengine_list_plugin_artifacts(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_LIST_PLUGINS
@@ -930,18 +1649,180 @@ uint16_t uniffi_hud_core_checksum_method_hudengine_load_ideas(void
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_LOAD_IDEAS_ORDER
 uint16_t uniffi_hud_core_checksum_method_hudengine_load_ideas_order(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_LOAD_MCP_SERVERS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_LOAD_MCP_SERVERS
+uint16_t uniffi_hud_core_checksum_method_hudengine_load_mcp_servers(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_MODEL_USAGE_TIMESERIES
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_MODEL_USAGE_TIMESERIES
+uint16_t uniffi_hud_core_checksum_method_hudengine_model_usage_timeseries(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_NEXT_STEPS_DIGEST
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_NEXT_STEPS_DIGEST
+uint16_t uniffi_hud_core_checksum_method_hudengine_next_steps_digest(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_OPEN_LOCAL_SETTINGS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_OPEN_LOCAL_SETTINGS
+uint16_t uniffi_hud_core_checksum_method_hudengine_open_local_settings(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_OPEN_MCP_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_OPEN_MCP_CONFIG
+uint16_t uniffi_hud_core_checksum_method_hudengine_open_mcp_config(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_OPEN_PROJECT_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_OPEN_PROJECT_CONFIG
+uint16_t uniffi_hud_core_checksum_method_hudengine_open_project_config(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_OPEN_TASK_CONTEXT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_OPEN_TASK_CONTEXT
+uint16_t uniffi_hud_core_checksum_method_hudengine_open_task_context(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_ORPHANED_LIVE_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_ORPHANED_LIVE_SESSIONS
+uint16_t uniffi_hud_core_checksum_method_hudengine_orphaned_live_sessions(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PERMISSION_HEAVY_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PERMISSION_HEAVY_SESSIONS
+uint16_t uniffi_hud_core_checksum_method_hudengine_permission_heavy_sessions(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PERMISSION_HISTORY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PERMISSION_HISTORY
+uint16_t uniffi_hud_core_checksum_method_hudengine_permission_history(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_POLL_SESSION_WAITING
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_POLL_SESSION_WAITING
+uint16_t uniffi_hud_core_checksum_method_hudengine_poll_session_waiting(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_POLL_STATE_FEED
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_POLL_STATE_FEED
+uint16_t uniffi_hud_core_checksum_method_hudengine_poll_state_feed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_POLL_STATS_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_POLL_STATS_CHANGES
+uint16_t uniffi_hud_core_checksum_method_hudengine_poll_stats_changes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PROJECT_VELOCITY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PROJECT_VELOCITY
+uint16_t uniffi_hud_core_checksum_method_hudengine_project_velocity(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PROJECTS_WITH_SUBAGENTS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PROJECTS_WITH_SUBAGENTS
+uint16_t uniffi_hud_core_checksum_method_hudengine_projects_with_subagents(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PRUNE_ACTIVITY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PRUNE_ACTIVITY
+uint16_t uniffi_hud_core_checksum_method_hudengine_prune_activity(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PRUNE_MISSING_PROJECTS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PRUNE_MISSING_PROJECTS
+uint16_t uniffi_hud_core_checksum_method_hudengine_prune_missing_projects(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PRUNE_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_PRUNE_SESSIONS
+uint16_t uniffi_hud_core_checksum_method_hudengine_prune_sessions(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_READ_GLOBAL_INSTRUCTIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_READ_GLOBAL_INSTRUCTIONS
+uint16_t uniffi_hud_core_checksum_method_hudengine_read_global_instructions(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_READ_STATUS_GEN_LOG
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_READ_STATUS_GEN_LOG
+uint16_t uniffi_hud_core_checksum_method_hudengine_read_status_gen_log(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_REAP_STALE_LOCKS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_REAP_STALE_LOCKS
+uint16_t uniffi_hud_core_checksum_method_hudengine_reap_stale_locks(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_REMOVE_PROJECT
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_REMOVE_PROJECT
 uint16_t uniffi_hud_core_checksum_method_hudengine_remove_project(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_REMOVE_ZOMBIE_SESSIONS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_REMOVE_ZOMBIE_SESSIONS
+uint16_t uniffi_hud_core_checksum_method_hudengine_remove_zombie_sessions(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_REORDER_PROJECTS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_REORDER_PROJECTS
+uint16_t uniffi_hud_core_checksum_method_hudengine_reorder_projects(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_REPAIR_STATS_CACHE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_REPAIR_STATS_CACHE
+uint16_t uniffi_hud_core_checksum_method_hudengine_repair_stats_cache(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_RESOLVE_ACTIVATION
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_RESOLVE_ACTIVATION
 uint16_t uniffi_hud_core_checksum_method_hudengine_resolve_activation(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_RESOLVE_EFFECTIVE_ARTIFACTS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_RESOLVE_EFFECTIVE_ARTIFACTS
+uint16_t uniffi_hud_core_checksum_method_hudengine_resolve_effective_artifacts(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_RESOLVE_PATH_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_RESOLVE_PATH_STATE
+uint16_t uniffi_hud_core_checksum_method_hudengine_resolve_path_state(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_RESTORE_CAPACITOR_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_RESTORE_CAPACITOR_STATE
+uint16_t uniffi_hud_core_checksum_method_hudengine_restore_capacitor_state(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_RUN_HOOK_TEST
@@ -960,6 +1841,108 @@ uint16_t uniffi_hud_core_checksum_method_hudengine_run_startup_cleanup(void
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SAVE_IDEAS_ORDER
 uint16_t uniffi_hud_core_checksum_method_hudengine_save_ideas_order(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SESSION_DURATION_STATS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SESSION_DURATION_STATS
+uint16_t uniffi_hud_core_checksum_method_hudengine_session_duration_stats(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SESSION_LATENCY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SESSION_LATENCY
+uint16_t uniffi_hud_core_checksum_method_hudengine_session_latency(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SESSION_RECEIPT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SESSION_RECEIPT
+uint16_t uniffi_hud_core_checksum_method_hudengine_session_receipt(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SESSIONS_BY_HOST_APP
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SESSIONS_BY_HOST_APP
+uint16_t uniffi_hud_core_checksum_method_hudengine_sessions_by_host_app(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SET_MANUAL_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SET_MANUAL_ORDER
+uint16_t uniffi_hud_core_checksum_method_hudengine_set_manual_order(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SET_PROJECT_ALIAS
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SET_PROJECT_ALIAS
+uint16_t uniffi_hud_core_checksum_method_hudengine_set_project_alias(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SET_WATCHERS_PAUSED
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SET_WATCHERS_PAUSED
+uint16_t uniffi_hud_core_checksum_method_hudengine_set_watchers_paused(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_STATE_DISTRIBUTION
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_STATE_DISTRIBUTION
+uint16_t uniffi_hud_core_checksum_method_hudengine_state_distribution(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_STATS_BREAKDOWN
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_STATS_BREAKDOWN
+uint16_t uniffi_hud_core_checksum_method_hudengine_stats_breakdown(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SUBSCRIBE_STATE_FEED
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_SUBSCRIBE_STATE_FEED
+uint16_t uniffi_hud_core_checksum_method_hudengine_subscribe_state_feed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_TELEMETRY_CONTEXT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_TELEMETRY_CONTEXT
+uint16_t uniffi_hud_core_checksum_method_hudengine_telemetry_context(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_TERMINAL_FOR_TTY
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_TERMINAL_FOR_TTY
+uint16_t uniffi_hud_core_checksum_method_hudengine_terminal_for_tty(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_TERMINAL_LAUNCH
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_TERMINAL_LAUNCH
+uint16_t uniffi_hud_core_checksum_method_hudengine_terminal_launch(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_TOGGLE_PLUGIN_ARTIFACT
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_TOGGLE_PLUGIN_ARTIFACT
+uint16_t uniffi_hud_core_checksum_method_hudengine_toggle_plugin_artifact(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_TOP_SESSIONS_BY_COST
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_TOP_SESSIONS_BY_COST
+uint16_t uniffi_hud_core_checksum_method_hudengine_top_sessions_by_cost(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_UNCOMMITTED_AGENT_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_UNCOMMITTED_AGENT_CHANGES
+uint16_t uniffi_hud_core_checksum_method_hudengine_uncommitted_agent_changes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_UNSUBSCRIBE_STATE_FEED
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_UNSUBSCRIBE_STATE_FEED
+uint16_t uniffi_hud_core_checksum_method_hudengine_unsubscribe_state_feed(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_UPDATE_IDEA_DESCRIPTION
@@ -990,12 +1973,30 @@ uint16_t uniffi_hud_core_checksum_method_hudengine_update_idea_title(void
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_UPDATE_IDEA_TRIAGE
 uint16_t uniffi_hud_core_checksum_method_hudengine_update_idea_triage(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_USAGE_FOR_PERIOD
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_USAGE_FOR_PERIOD
+uint16_t uniffi_hud_core_checksum_method_hudengine_usage_for_period(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_VALIDATE_PROJECT
 #define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_VALIDATE_PROJECT
 uint16_t uniffi_hud_core_checksum_method_hudengine_validate_project(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_VERIFY_PROJECT_ENCODING
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_VERIFY_PROJECT_ENCODING
+uint16_t uniffi_hud_core_checksum_method_hudengine_verify_project_encoding(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_WATCHERS_PAUSED
+#define UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_METHOD_HUDENGINE_WATCHERS_PAUSED
+uint16_t uniffi_hud_core_checksum_method_hudengine_watchers_paused(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HUD_CORE_CHECKSUM_CONSTRUCTOR_HUDENGINE_NEW
@@ -630,6 +630,46 @@ fn normalize_path_for_actions(path: &str) -> String {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// TTY Lookup
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// The terminal app and shell attached to a tty, from shell-cwd.json.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TtyTerminal {
+    pub parent_app: ParentApp,
+    pub cwd: String,
+    /// Shell PID (the shell-cwd.json key)
+    pub pid: u32,
+    pub tmux_session: Option<String>,
+}
+
+/// Finds the shell running on `tty` and the terminal app hosting it.
+///
+/// Accepts `ttys003` or `/dev/ttys003`. When several shells share a tty
+/// (nested shells), live ones win, then the most recently updated. Returns
+/// None for ttys not in the shell state.
+pub fn terminal_for_tty(tty: &str, shell_state: Option<&ShellCwdStateFfi>) -> Option<TtyTerminal> {
+    let wanted = tty.trim_start_matches("/dev/");
+    shell_state?
+        .shells
+        .iter()
+        .filter(|(_, shell)| shell.tty.trim_start_matches("/dev/") == wanted)
+        .filter_map(|(pid, shell)| Some((pid.parse::<u32>().ok()?, shell)))
+        .max_by_key(|(_, shell)| {
+            (
+                shell.is_live,
+                chrono::DateTime::parse_from_rfc3339(&shell.updated_at).ok(),
+            )
+        })
+        .map(|(pid, shell)| TtyTerminal {
+            parent_app: shell.parent_app,
+            cwd: shell.cwd.clone(),
+            pid,
+            tmux_session: shell.tmux_session.clone(),
+        })
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Tests
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -699,6 +739,79 @@ mod tests {
 
     const TEST_HOME_DIR: &str = "/Users/pete";
 
+    #[test]
+    fn terminal_for_tty_resolves_recorded_shell() {
+        let state = make_shell_state(vec![
+            (
+                "100",
+                make_shell_entry("/Users/pete/app", "/dev/ttys003", ParentApp::ITerm, None),
+            ),
+            (
+                "200",
+                make_shell_entry(
+                    "/Users/pete/other",
+                    "/dev/ttys004",
+                    ParentApp::Ghostty,
+                    None,
+                ),
+            ),
+        ]);
+
+        let terminal = terminal_for_tty("ttys003", Some(&state)).unwrap();
+        assert_eq!(terminal.parent_app, ParentApp::ITerm);
+        assert_eq!(terminal.cwd, "/Users/pete/app");
+        assert_eq!(terminal.pid, 100);
+        assert_eq!(
+            terminal_for_tty("/dev/ttys004", Some(&state)).map(|t| t.pid),
+            Some(200)
+        );
+
+        assert_eq!(terminal_for_tty("/dev/ttys009", Some(&state)), None);
+        assert_eq!(terminal_for_tty("/dev/ttys003", None), None);
+    }
+
+    #[test]
+    fn terminal_for_tty_prefers_live_then_newest_shell() {
+        let state = make_shell_state(vec![
+            (
+                "1",
+                make_shell_entry_full(
+                    "/dead",
+                    "/dev/ttys001",
+                    ParentApp::Terminal,
+                    None,
+                    "2026-01-27T12:00:00Z",
+                    false,
+                ),
+            ),
+            (
+                "2",
+                make_shell_entry_with_time(
+                    "/older",
+                    "/dev/ttys001",
+                    ParentApp::Terminal,
+                    None,
+                    "2026-01-27T10:00:00Z",
+                ),
+            ),
+            (
+                "3",
+                make_shell_entry_with_time(
+                    "/newer",
+                    "/dev/ttys001",
+                    ParentApp::Terminal,
+                    None,
+                    "2026-01-27T11:00:00Z",
+                ),
+            ),
+        ]);
+
+        assert_eq!(
+            terminal_for_tty("/dev/ttys001", Some(&state)).map(|t| t.cwd),
+            Some("/newer".to_string())
+        );
+    }
+
     fn tmux_context_none() -> TmuxContextFfi {
         TmuxContextFfi {
             session_at_path: None,
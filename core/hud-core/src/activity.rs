@@ -28,7 +28,7 @@
 
 use crate::boundaries::find_project_boundary;
 use crate::error::{HudError, Result};
-use crate::state::normalize_path_for_comparison;
+use crate::state::{normalize_path_for_comparison, normalize_path_for_matching};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
@@ -63,6 +63,24 @@ pub struct FileActivity {
     pub timestamp: String,
 }
 
+impl FileActivity {
+    /// True for tools that modify the file (anything other than `Read`).
+    pub fn is_write(&self) -> bool {
+        self.tool != "Read"
+    }
+}
+
+/// A frequently edited file, aggregated across sessions.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, uniffi::Record)]
+pub struct HotFile {
+    /// Absolute path to the file
+    pub file_path: String,
+    /// Number of write-type tool uses (Edit, Write, NotebookEdit)
+    pub edit_count: u32,
+    /// ISO 8601 timestamp of the most recent edit
+    pub last_edited_at: String,
+}
+
 /// Activity records for a single session.
 ///
 /// Uses `#[serde(default)]` on all fields for forward compatibility.
@@ -398,11 +416,105 @@ impl ActivityStore {
         self.sessions.retain(|_, s| !s.activity.is_empty());
     }
 
+    /// Entries `cleanup_old_entries` would remove, without modifying the store.
+    pub fn expired_entries(&self, threshold: Duration) -> Vec<&FileActivity> {
+        self.sessions
+            .values()
+            .flat_map(|s| s.activity.iter())
+            .filter(|a| !is_within_threshold(&a.timestamp, threshold))
+            .collect()
+    }
+
     /// Removes all activity for a session (called on SessionEnd).
     pub fn remove_session(&mut self, session_id: &str) {
         self.sessions.remove(session_id);
     }
 
+    /// Returns the most-edited files in a project across all sessions.
+    ///
+    /// Relative file paths are resolved against the session's cwd, and entries
+    /// are deduped by absolute path. Ordered by edit count, then most recent edit.
+    pub fn hot_files(&self, project_path: &str, limit: usize) -> Vec<HotFile> {
+        let normalized_project = normalize_path_for_matching(project_path);
+        let prefix = format!("{}/", normalized_project.trim_end_matches('/'));
+
+        let mut by_path: HashMap<String, HotFile> = HashMap::new();
+        for session in self.sessions.values() {
+            for activity in session.activity.iter().filter(|a| a.is_write()) {
+                let absolute = if Path::new(&activity.file_path).is_absolute() {
+                    activity.file_path.clone()
+                } else {
+                    Path::new(&session.cwd)
+                        .join(&activity.file_path)
+                        .to_string_lossy()
+                        .to_string()
+                };
+                let key = normalize_path_for_matching(&absolute);
+                let in_project = key.starts_with(&prefix)
+                    || normalize_path_for_matching(&activity.project_path) == normalized_project;
+                if !in_project {
+                    continue;
+                }
+
+                let entry = by_path.entry(key).or_insert_with(|| HotFile {
+                    file_path: absolute,
+                    edit_count: 0,
+                    last_edited_at: String::new(),
+                });
+                entry.edit_count += 1;
+                if activity.timestamp > entry.last_edited_at {
+                    entry.last_edited_at = activity.timestamp.clone();
+                }
+            }
+        }
+
+        let mut files: Vec<HotFile> = by_path.into_values().collect();
+        files.sort_by(|a, b| {
+            b.edit_count
+                .cmp(&a.edit_count)
+                .then_with(|| b.last_edited_at.cmp(&a.last_edited_at))
+        });
+        files.truncate(limit);
+        files
+    }
+
+    /// Returns the files a session most recently wrote in a project, newest first.
+    ///
+    /// Paths are resolved against the session's cwd and deduped.
+    pub fn recent_session_edits(
+        &self,
+        session_id: &str,
+        project_path: &str,
+        limit: usize,
+    ) -> Vec<String> {
+        let Some(session) = self.sessions.get(session_id) else {
+            return Vec::new();
+        };
+        let normalized_project = normalize_path_for_matching(project_path);
+        let prefix = format!("{}/", normalized_project.trim_end_matches('/'));
+
+        let mut seen = std::collections::HashSet::new();
+        let mut files = Vec::new();
+        for activity in session.activity.iter().filter(|a| a.is_write()) {
+            let absolute = if Path::new(&activity.file_path).is_absolute() {
+                activity.file_path.clone()
+            } else {
+                Path::new(&session.cwd)
+                    .join(&activity.file_path)
+                    .to_string_lossy()
+                    .to_string()
+            };
+            let key = normalize_path_for_matching(&absolute);
+            if key.starts_with(&prefix) && seen.insert(key) {
+                files.push(absolute);
+                if files.len() == limit {
+                    break;
+                }
+            }
+        }
+        files
+    }
+
     /// Gets the most recently active project for a session.
     pub fn most_recent_project(&self, session_id: &str) -> Option<String> {
         self.sessions
@@ -830,6 +942,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hot_files_ranks_by_edit_count_across_sessions() {
+        let mut store = ActivityStore::new();
+        let tmp = create_test_dir();
+        create_dir(tmp.path(), ".git");
+        let src = create_dir(tmp.path(), "src");
+        create_file(&src, "lib.rs");
+        create_file(&src, "main.rs");
+        let project = tmp.path().to_str().unwrap();
+        let lib = src.join("lib.rs");
+        let main = src.join("main.rs");
+
+        for session in ["session-1", "session-2", "session-3"] {
+            store.record_activity(
+                session,
+                project,
+                lib.to_str().unwrap(),
+                "Edit",
+                &recent_timestamp(),
+            );
+        }
+        store.record_activity(
+            "session-1",
+            project,
+            main.to_str().unwrap(),
+            "Write",
+            &recent_timestamp(),
+        );
+        // Reads don't count as edits
+        store.record_activity(
+            "session-2",
+            project,
+            main.to_str().unwrap(),
+            "Read",
+            &recent_timestamp(),
+        );
+
+        let hot = store.hot_files(project, 10);
+        assert_eq!(hot.len(), 2);
+        assert_eq!(hot[0].file_path, lib.to_string_lossy());
+        assert_eq!(hot[0].edit_count, 3);
+        assert_eq!(hot[1].file_path, main.to_string_lossy());
+        assert_eq!(hot[1].edit_count, 1);
+
+        assert_eq!(store.hot_files(project, 1).len(), 1);
+    }
+
+    #[test]
+    fn hot_files_resolves_relative_paths_against_session_cwd() {
+        let mut store = ActivityStore::new();
+        let tmp = create_test_dir();
+        create_dir(tmp.path(), ".git");
+        let src = create_dir(tmp.path(), "src");
+        create_file(&src, "lib.rs");
+        let project = tmp.path().to_str().unwrap();
+        let lib = src.join("lib.rs");
+
+        store.record_activity(
+            "session-1",
+            project,
+            lib.to_str().unwrap(),
+            "Edit",
+            &recent_timestamp(),
+        );
+        store
+            .sessions
+            .get_mut("session-1")
+            .unwrap()
+            .activity
+            .insert(
+                0,
+                FileActivity {
+                    project_path: project.to_string(),
+                    file_path: "src/lib.rs".to_string(),
+                    tool: "Edit".to_string(),
+                    timestamp: recent_timestamp(),
+                },
+            );
+
+        let hot = store.hot_files(project, 10);
+        assert_eq!(hot.len(), 1);
+        assert_eq!(hot[0].edit_count, 2);
+    }
+
     #[test]
     fn most_recent_project_returns_latest() {
         let mut store = ActivityStore::new();
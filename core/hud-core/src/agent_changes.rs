@@ -0,0 +1,416 @@
+//! Review of uncommitted changes made by Claude sessions.
+//!
+//! Intersects `git status` for a project with the file-activity feed
+//! (`~/.capacitor/file-activity.json`) so the UI can show only the files
+//! an agent actually touched, not every dirty file in the working tree.
+//!
+//! Untracked files are included by default: when Claude creates a new module
+//! there is no prior version to diff against, so the full file content is
+//! rendered as an addition. Callers can opt out with `include_untracked = false`.
+
+use crate::activity::ActivityStore;
+use crate::error::{HudError, Result};
+use crate::state::normalize_path_for_matching;
+use crate::storage::StorageConfig;
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+
+/// How a file differs from `HEAD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+pub enum AgentChangeKind {
+    Modified,
+    Added,
+    Deleted,
+}
+
+/// A file with uncommitted changes that an agent session edited.
+#[derive(Debug, Clone, Serialize, Deserialize, uniffi::Record)]
+pub struct AgentChange {
+    /// Absolute path to the changed file
+    pub file_path: String,
+    /// Path relative to the project root
+    pub relative_path: String,
+    pub kind: AgentChangeKind,
+    /// True when git does not track the file yet (newly created)
+    pub untracked: bool,
+    /// The last tool that wrote the file (Edit, Write, NotebookEdit)
+    pub last_tool: String,
+    /// ISO 8601 timestamp of the last agent edit
+    pub last_edited_at: String,
+}
+
+/// One entry from `git status --porcelain`.
+struct GitStatusEntry {
+    /// Path relative to the project root
+    relative_path: String,
+    kind: AgentChangeKind,
+    untracked: bool,
+}
+
+/// Lists uncommitted files in a project that were edited by an agent session.
+///
+/// Results are sorted by most recent edit first.
+pub fn uncommitted_agent_changes(
+    project_path: &str,
+    include_untracked: bool,
+) -> Result<Vec<AgentChange>> {
+    uncommitted_agent_changes_with_storage(
+        &StorageConfig::default(),
+        project_path,
+        include_untracked,
+    )
+}
+
+pub fn uncommitted_agent_changes_with_storage(
+    storage: &StorageConfig,
+    project_path: &str,
+    include_untracked: bool,
+) -> Result<Vec<AgentChange>> {
+    let edits = agent_edits_in_project(storage, project_path);
+    if edits.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let project_root = project_path.trim_end_matches('/');
+    let mut changes: Vec<AgentChange> = git_status(project_path)?
+        .into_iter()
+        .filter(|entry| include_untracked || !entry.untracked)
+        .filter_map(|entry| {
+            let (tool, timestamp) =
+                edits.get(&normalize_path_for_matching(&entry.relative_path))?;
+            Some(AgentChange {
+                file_path: format!("{}/{}", project_root, entry.relative_path),
+                relative_path: entry.relative_path,
+                kind: entry.kind,
+                untracked: entry.untracked,
+                last_tool: tool.clone(),
+                last_edited_at: timestamp.clone(),
+            })
+        })
+        .collect();
+
+    changes.sort_by(|a, b| {
+        b.last_edited_at
+            .cmp(&a.last_edited_at)
+            .then_with(|| a.relative_path.cmp(&b.relative_path))
+    });
+    Ok(changes)
+}
+
+/// Returns a unified diff for one agent-edited file.
+///
+/// Tracked files use `git diff HEAD`. Untracked files have no prior version,
+/// so their full content is rendered as a new-file diff.
+pub fn agent_edit_diff(
+    project_path: &str,
+    file_path: &str,
+    include_untracked: bool,
+) -> Result<String> {
+    agent_edit_diff_with_storage(
+        &StorageConfig::default(),
+        project_path,
+        file_path,
+        include_untracked,
+    )
+}
+
+pub fn agent_edit_diff_with_storage(
+    storage: &StorageConfig,
+    project_path: &str,
+    file_path: &str,
+    include_untracked: bool,
+) -> Result<String> {
+    let target = normalize_path_for_matching(file_path);
+    let change = uncommitted_agent_changes_with_storage(storage, project_path, include_untracked)?
+        .into_iter()
+        .find(|c| {
+            normalize_path_for_matching(&c.file_path) == target
+                || normalize_path_for_matching(&c.relative_path) == target
+        })
+        .ok_or_else(|| HudError::FileNotFound(Path::new(file_path).to_path_buf()))?;
+
+    if change.untracked {
+        let content = fs::read_to_string(&change.file_path).map_err(|e| HudError::Io {
+            context: format!("Failed to read {}", change.file_path),
+            source: e,
+        })?;
+        return Ok(render_new_file_diff(&change.relative_path, &content));
+    }
+
+    run_git(
+        project_path,
+        &["diff", "HEAD", "--", change.relative_path.as_str()],
+    )
+}
+
+/// Renders file content as a unified diff against `/dev/null`.
+fn render_new_file_diff(relative_path: &str, content: &str) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut diff = format!(
+        "diff --git a/{path} b/{path}\nnew file mode 100644\n--- /dev/null\n+++ b/{path}\n",
+        path = relative_path
+    );
+    if !lines.is_empty() {
+        diff.push_str(&format!("@@ -0,0 +1,{} @@\n", lines.len()));
+        for line in lines {
+            diff.push('+');
+            diff.push_str(line);
+            diff.push('\n');
+        }
+    }
+    diff
+}
+
+/// Collects the most recent write-type activity per file inside the project.
+///
+/// Returns project-relative path (normalized for matching) → (tool, timestamp).
+/// Reads are ignored since they never produce changes.
+fn agent_edits_in_project(
+    storage: &StorageConfig,
+    project_path: &str,
+) -> HashMap<String, (String, String)> {
+    let store = ActivityStore::load(&storage.file_activity_file());
+    let prefix = format!("{}/", normalize_path_for_matching(project_path));
+
+    let mut edits: HashMap<String, (String, String)> = HashMap::new();
+    for session in store.sessions.values() {
+        for activity in session.activity.iter().filter(|a| a.is_write()) {
+            let file_path = normalize_path_for_matching(&activity.file_path);
+            let Some(relative) = file_path.strip_prefix(&prefix) else {
+                continue;
+            };
+            let seen_newer = matches!(
+                edits.get(relative),
+                Some((_, ts)) if *ts >= activity.timestamp
+            );
+            if !seen_newer {
+                edits.insert(
+                    relative.to_string(),
+                    (activity.tool.clone(), activity.timestamp.clone()),
+                );
+            }
+        }
+    }
+    edits
+}
+
+/// Parses `git status` for the project directory.
+///
+/// Paths from git are relative to the repository root; they are rebased onto
+/// the project directory using `--show-prefix` so monorepo subprojects work.
+fn git_status(project_path: &str) -> Result<Vec<GitStatusEntry>> {
+    let prefix = run_git(project_path, &["rev-parse", "--show-prefix"])?;
+    let prefix = prefix.trim();
+    let output = run_git(
+        project_path,
+        &[
+            "status",
+            "--porcelain=v1",
+            "-z",
+            "--untracked-files=all",
+            "--",
+            ".",
+        ],
+    )?;
+
+    let mut entries = Vec::new();
+    let mut fields = output.split('\0');
+    while let Some(field) = fields.next() {
+        if field.len() < 4 {
+            continue;
+        }
+        let (status, path) = field.split_at(3);
+        let status = status.trim_end();
+
+        // Renames and copies carry the original path in the next field
+        if status.contains('R') || status.contains('C') {
+            fields.next();
+        }
+
+        let (kind, untracked) = match status {
+            "??" => (AgentChangeKind::Added, true),
+            s if s.contains('A') => (AgentChangeKind::Added, false),
+            s if s.contains('D') => (AgentChangeKind::Deleted, false),
+            _ => (AgentChangeKind::Modified, false),
+        };
+
+        let Some(relative_path) = path.strip_prefix(prefix) else {
+            continue;
+        };
+        entries.push(GitStatusEntry {
+            relative_path: relative_path.to_string(),
+            kind,
+            untracked,
+        });
+    }
+    Ok(entries)
+}
+
+fn run_git(project_path: &str, args: &[&str]) -> Result<String> {
+    let command = format!("git {}", args.join(" "));
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project_path)
+        .args(args)
+        .output()
+        .map_err(|e| HudError::CommandFailed {
+            command: command.clone(),
+            details: e.to_string(),
+        })?;
+
+    if !output.status.success() {
+        return Err(HudError::CommandFailed {
+            command,
+            details: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activity::now_iso8601;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args([
+                "-c",
+                "user.name=Test",
+                "-c",
+                "user.email=test@example.com",
+                "-c",
+                "commit.gpgsign=false",
+            ])
+            .args(args)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    /// Creates a git repo with one committed file and an isolated storage config.
+    fn setup() -> (TempDir, StorageConfig, String) {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().join("project");
+        fs::create_dir_all(&project).unwrap();
+        git(&project, &["init", "-q"]);
+        fs::write(project.join("tracked.rs"), "fn a() {}\n").unwrap();
+        git(&project, &["add", "."]);
+        git(&project, &["commit", "-q", "-m", "init"]);
+
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        fs::create_dir_all(storage.root()).unwrap();
+        (temp, storage, project.to_string_lossy().to_string())
+    }
+
+    fn record_edits(storage: &StorageConfig, project: &str, files: &[(&str, &str)]) {
+        let mut store = ActivityStore::new();
+        for (file, tool) in files {
+            store.record_activity(
+                "session-1",
+                project,
+                &format!("{}/{}", project, file),
+                tool,
+                &now_iso8601(),
+            );
+        }
+        store.save(&storage.file_activity_file()).unwrap();
+    }
+
+    #[test]
+    fn includes_new_untracked_file_as_addition() {
+        let (_temp, storage, project) = setup();
+        fs::write(format!("{}/tracked.rs", project), "fn b() {}\n").unwrap();
+        fs::write(format!("{}/new_module.rs", project), "pub fn new() {}\n").unwrap();
+        fs::write(format!("{}/unrelated.rs", project), "// user file\n").unwrap();
+        record_edits(
+            &storage,
+            &project,
+            &[("tracked.rs", "Edit"), ("new_module.rs", "Write")],
+        );
+
+        let changes = uncommitted_agent_changes_with_storage(&storage, &project, true).unwrap();
+        assert_eq!(changes.len(), 2);
+
+        let added = changes
+            .iter()
+            .find(|c| c.relative_path == "new_module.rs")
+            .expect("new file should be listed");
+        assert_eq!(added.kind, AgentChangeKind::Added);
+        assert!(added.untracked);
+        assert_eq!(added.last_tool, "Write");
+
+        let modified = changes
+            .iter()
+            .find(|c| c.relative_path == "tracked.rs")
+            .unwrap();
+        assert_eq!(modified.kind, AgentChangeKind::Modified);
+        assert!(!modified.untracked);
+
+        assert!(!changes.iter().any(|c| c.relative_path == "unrelated.rs"));
+    }
+
+    #[test]
+    fn excludes_untracked_when_flag_is_off() {
+        let (_temp, storage, project) = setup();
+        fs::write(format!("{}/new_module.rs", project), "pub fn new() {}\n").unwrap();
+        record_edits(&storage, &project, &[("new_module.rs", "Write")]);
+
+        let changes = uncommitted_agent_changes_with_storage(&storage, &project, false).unwrap();
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn ignores_read_only_activity() {
+        let (_temp, storage, project) = setup();
+        fs::write(format!("{}/tracked.rs", project), "fn b() {}\n").unwrap();
+        record_edits(&storage, &project, &[("tracked.rs", "Read")]);
+
+        let changes = uncommitted_agent_changes_with_storage(&storage, &project, true).unwrap();
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn untracked_diff_renders_full_content() {
+        let (_temp, storage, project) = setup();
+        fs::write(
+            format!("{}/new_module.rs", project),
+            "pub fn one() {}\npub fn two() {}\n",
+        )
+        .unwrap();
+        record_edits(&storage, &project, &[("new_module.rs", "Write")]);
+
+        let diff = agent_edit_diff_with_storage(
+            &storage,
+            &project,
+            &format!("{}/new_module.rs", project),
+            true,
+        )
+        .unwrap();
+        assert!(diff.contains("--- /dev/null"));
+        assert!(diff.contains("@@ -0,0 +1,2 @@"));
+        assert!(diff.contains("+pub fn one() {}"));
+        assert!(diff.contains("+pub fn two() {}"));
+
+        let excluded = agent_edit_diff_with_storage(&storage, &project, "new_module.rs", false);
+        assert!(excluded.is_err());
+    }
+
+    #[test]
+    fn tracked_diff_uses_git() {
+        let (_temp, storage, project) = setup();
+        fs::write(format!("{}/tracked.rs", project), "fn b() {}\n").unwrap();
+        record_edits(&storage, &project, &[("tracked.rs", "Edit")]);
+
+        let diff = agent_edit_diff_with_storage(&storage, &project, "tracked.rs", true).unwrap();
+        assert!(diff.contains("-fn a() {}"));
+        assert!(diff.contains("+fn b() {}"));
+    }
+}
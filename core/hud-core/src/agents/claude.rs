@@ -3,8 +3,9 @@
 use std::path::PathBuf;
 use std::time::SystemTime;
 
+use crate::boundaries::find_project_boundary;
 use crate::sessions::READY_STALE_THRESHOLD_SECS;
-use crate::state::{resolve_state_with_details, StateStore};
+use crate::state::{resolve_state_with_config, ResolverConfig, StateStore};
 use crate::storage::StorageConfig;
 use crate::types::SessionState;
 use chrono::Utc;
@@ -43,6 +44,7 @@ impl ClaudeAdapter {
             SessionState::Compacting => AgentState::Working,
             SessionState::Waiting => AgentState::Waiting,
             SessionState::Idle => AgentState::Idle,
+            SessionState::Thinking => AgentState::Thinking,
         }
     }
 
@@ -50,6 +52,7 @@ impl ClaudeAdapter {
         match state {
             SessionState::Compacting => Some("compacting context".to_string()),
             SessionState::Waiting => Some("waiting for permission".to_string()),
+            SessionState::Thinking => Some("thinking".to_string()),
             _ => None,
         }
     }
@@ -120,7 +123,8 @@ impl AgentAdapter for ClaudeAdapter {
         };
 
         // v3 resolver handles both lock-based detection and fresh record fallback
-        let details = resolve_state_with_details(&lock_dir, &store, project_path)?;
+        let resolver_config = ResolverConfig::load(&self.storage);
+        let details = resolve_state_with_config(&lock_dir, &store, project_path, &resolver_config)?;
 
         // Use the resolved session_id for metadata lookup (exact-match-only policy).
         // Path-based lookup could return a different session in multi-session scenarios.
@@ -148,10 +152,12 @@ impl AgentAdapter for ClaudeAdapter {
             agent_name: self.display_name().to_string(),
             state: Self::map_state(details.state),
             session_id: details.session_id,
+            project_path: session_project_root(&details.cwd),
             cwd: details.cwd,
             detail: Self::state_detail(details.state),
             working_on: record.and_then(|r| r.working_on.clone()),
             updated_at: record.map(|r| r.updated_at.to_rfc3339()),
+            active_subagent_count: record.map_or(0, |r| r.active_subagent_count),
         })
     }
 
@@ -180,9 +186,11 @@ impl AgentAdapter for ClaudeAdapter {
                 state: Self::map_state(r.state),
                 session_id: Some(r.session_id.clone()),
                 cwd: r.cwd.clone(),
+                project_path: session_project_root(&r.cwd),
                 detail: Self::state_detail(r.state),
                 working_on: r.working_on.clone(),
                 updated_at: Some(r.updated_at.to_rfc3339()),
+                active_subagent_count: r.active_subagent_count,
             })
             .collect()
     }
@@ -193,6 +201,11 @@ impl AgentAdapter for ClaudeAdapter {
     }
 }
 
+/// Walks up from a session's cwd to the nearest project boundary.
+fn session_project_root(cwd: &str) -> Option<String> {
+    find_project_boundary(cwd).map(|boundary| boundary.path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,6 +321,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_state_mapping_thinking_with_detail() {
+        assert_eq!(
+            ClaudeAdapter::map_state(SessionState::Thinking),
+            AgentState::Thinking
+        );
+        assert_eq!(
+            ClaudeAdapter::state_detail(SessionState::Thinking),
+            Some("thinking".to_string())
+        );
+    }
+
     #[test]
     fn test_state_mapping_waiting_with_detail() {
         assert_eq!(
@@ -391,6 +416,38 @@ mod tests {
         assert_eq!(session.cwd, "/project");
     }
 
+    #[test]
+    fn test_detect_session_resolves_project_root_from_subdirectory() {
+        let temp = tempdir().unwrap();
+        let capacitor_root = temp.path().join("capacitor");
+        let claude_root = temp.path().join("claude");
+        let sessions_dir = capacitor_root.join("sessions");
+        std::fs::create_dir_all(&sessions_dir).unwrap();
+        std::fs::create_dir_all(&claude_root).unwrap();
+
+        let repo = temp.path().join("repo");
+        let src = repo.join("src");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::create_dir_all(&src).unwrap();
+        let src = src.to_string_lossy().to_string();
+
+        create_lock(&sessions_dir, std::process::id(), &src);
+        let mut store = StateStore::new(&capacitor_root.join("sessions.json"));
+        store.update("test-session", SessionState::Working, &src);
+        store.save().unwrap();
+
+        let storage = StorageConfig::with_roots(capacitor_root, claude_root);
+        let session = ClaudeAdapter::with_storage(storage)
+            .detect_session(&src)
+            .unwrap();
+
+        assert_eq!(session.cwd, src);
+        assert_eq!(
+            session.project_path.as_deref(),
+            Some(repo.to_string_lossy().as_ref())
+        );
+    }
+
     #[test]
     fn test_detect_session_returns_recent_state_without_lock() {
         let temp = tempdir().unwrap();
@@ -0,0 +1,225 @@
+//! Cursor agent adapter backed by the chat stores Cursor keeps per workspace.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::config::load_hud_config_with_storage;
+use crate::sessions::READY_STALE_THRESHOLD_SECS;
+use crate::storage::StorageConfig;
+use chrono::{DateTime, Utc};
+
+use super::types::{AgentSession, AgentState, AgentType};
+use super::AgentAdapter;
+
+/// A chat store written within this many seconds means the agent is still
+/// streaming a response or running tools.
+const CURSOR_ACTIVE_SECS: u64 = 10;
+
+/// Adapter for Cursor agent sessions.
+///
+/// Cursor keeps one directory per chat under
+/// `~/.cursor/chats/<md5 of workspace path>/<chat id>/`, and rewrites the chat's
+/// store files as the agent works. There is no explicit state, so it is
+/// inferred from how recently the newest chat was written:
+/// - within [`CURSOR_ACTIVE_SECS`] → Working
+/// - within [`READY_STALE_THRESHOLD_SECS`] → Ready
+/// - older → no session
+///
+/// Workspace directories are hashed, so sessions can only be found for known
+/// paths; `all_sessions` checks each pinned project. We never write to `~/.cursor/`.
+pub struct CursorAdapter {
+    storage: StorageConfig,
+    cursor_root: PathBuf,
+}
+
+impl CursorAdapter {
+    pub fn new() -> Self {
+        let home = dirs::home_dir().unwrap_or_else(std::env::temp_dir);
+        Self {
+            storage: StorageConfig::default(),
+            cursor_root: home.join(".cursor"),
+        }
+    }
+
+    /// Creates an adapter with custom storage and Cursor directories.
+    /// Used for testing with isolated directories.
+    pub fn with_roots(storage: StorageConfig, cursor_root: PathBuf) -> Self {
+        Self {
+            storage,
+            cursor_root,
+        }
+    }
+
+    fn chats_dir(&self) -> PathBuf {
+        self.cursor_root.join("chats")
+    }
+
+    /// Returns the chats directory for a workspace: `~/.cursor/chats/<md5(path)>/`
+    fn workspace_chats_dir(&self, project_path: &str) -> PathBuf {
+        let path = project_path.trim_end_matches('/');
+        self.chats_dir()
+            .join(format!("{:x}", md5::compute(path.as_bytes())))
+    }
+
+    fn map_age(age_secs: u64) -> Option<AgentState> {
+        if age_secs <= CURSOR_ACTIVE_SECS {
+            Some(AgentState::Working)
+        } else if age_secs <= READY_STALE_THRESHOLD_SECS as u64 {
+            Some(AgentState::Ready)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for CursorAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AgentAdapter for CursorAdapter {
+    fn id(&self) -> &'static str {
+        "cursor"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Cursor"
+    }
+
+    fn is_installed(&self) -> bool {
+        self.cursor_root.is_dir()
+    }
+
+    fn detect_session(&self, project_path: &str) -> Option<AgentSession> {
+        let (chat_id, modified) = newest_chat(&self.workspace_chats_dir(project_path))?;
+        let age = SystemTime::now()
+            .duration_since(modified)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let state = Self::map_age(age)?;
+
+        Some(AgentSession {
+            agent_type: AgentType::Cursor,
+            agent_name: self.display_name().to_string(),
+            state,
+            session_id: Some(chat_id),
+            cwd: project_path.to_string(),
+            project_path: Some(project_path.to_string()),
+            detail: None,
+            working_on: None,
+            updated_at: Some(DateTime::<Utc>::from(modified).to_rfc3339()),
+            active_subagent_count: 0,
+        })
+    }
+
+    fn all_sessions(&self) -> Vec<AgentSession> {
+        load_hud_config_with_storage(&self.storage)
+            .pinned_projects
+            .iter()
+            .filter_map(|path| self.detect_session(path))
+            .collect()
+    }
+
+    fn state_mtime(&self) -> Option<SystemTime> {
+        std::fs::read_dir(self.chats_dir())
+            .ok()?
+            .flatten()
+            .filter_map(|workspace| newest_chat(&workspace.path()).map(|(_, modified)| modified))
+            .max()
+    }
+}
+
+/// Finds the most recently written chat in a workspace's chats directory,
+/// returning its id and the mtime of its newest file.
+fn newest_chat(workspace_dir: &Path) -> Option<(String, SystemTime)> {
+    std::fs::read_dir(workspace_dir)
+        .ok()?
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|chat| {
+            let modified = std::fs::read_dir(chat.path())
+                .ok()?
+                .flatten()
+                .filter_map(|f| f.metadata().ok()?.modified().ok())
+                .max()?;
+            Some((chat.file_name().to_string_lossy().into_owned(), modified))
+        })
+        .max_by_key(|(_, modified)| *modified)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::save_hud_config_with_storage;
+    use std::time::Duration;
+    use tempfile::tempdir;
+
+    fn write_chat(adapter: &CursorAdapter, project_path: &str, chat_id: &str, age: Duration) {
+        let chat_dir = adapter.workspace_chats_dir(project_path).join(chat_id);
+        std::fs::create_dir_all(&chat_dir).unwrap();
+        let store = std::fs::File::create(chat_dir.join("store.db")).unwrap();
+        store.set_modified(SystemTime::now() - age).unwrap();
+    }
+
+    fn setup() -> (tempfile::TempDir, CursorAdapter) {
+        let temp = tempdir().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let cursor_root = temp.path().join("cursor");
+        std::fs::create_dir_all(&cursor_root).unwrap();
+        (temp, CursorAdapter::with_roots(storage, cursor_root))
+    }
+
+    #[test]
+    fn test_not_installed_without_cursor_dir() {
+        let temp = tempdir().unwrap();
+        let adapter = CursorAdapter::with_roots(
+            StorageConfig::with_root(temp.path().join("capacitor")),
+            temp.path().join("missing"),
+        );
+        assert_eq!(adapter.id(), "cursor");
+        assert!(!adapter.is_installed());
+        assert!(adapter.detect_session("/project").is_none());
+        assert!(adapter.state_mtime().is_none());
+    }
+
+    #[test]
+    fn test_detect_session_maps_chat_age_to_state() {
+        let (_temp, adapter) = setup();
+        assert!(adapter.is_installed());
+
+        write_chat(&adapter, "/work/active", "chat-1", Duration::ZERO);
+        write_chat(&adapter, "/work/idle", "chat-2", Duration::from_secs(120));
+        write_chat(&adapter, "/work/old", "chat-3", Duration::from_secs(3600));
+
+        let active = adapter.detect_session("/work/active").unwrap();
+        assert_eq!(active.agent_type, AgentType::Cursor);
+        assert_eq!(active.state, AgentState::Working);
+        assert_eq!(active.session_id.as_deref(), Some("chat-1"));
+        assert_eq!(
+            adapter.detect_session("/work/idle/").unwrap().state,
+            AgentState::Ready
+        );
+        assert!(adapter.detect_session("/work/old").is_none());
+        assert!(adapter.detect_session("/work/none").is_none());
+    }
+
+    #[test]
+    fn test_all_sessions_checks_pinned_projects() {
+        let (_temp, adapter) = setup();
+        write_chat(&adapter, "/work/a", "old", Duration::from_secs(60));
+        write_chat(&adapter, "/work/a", "new", Duration::ZERO);
+        write_chat(&adapter, "/work/unpinned", "chat", Duration::ZERO);
+
+        let mut config = load_hud_config_with_storage(&adapter.storage);
+        config.pinned_projects = vec!["/work/a".to_string(), "/work/b".to_string()];
+        save_hud_config_with_storage(&adapter.storage, &config).unwrap();
+
+        let sessions = adapter.all_sessions();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].cwd, "/work/a");
+        assert_eq!(sessions[0].session_id.as_deref(), Some("new"));
+        assert!(adapter.state_mtime().is_some());
+    }
+}
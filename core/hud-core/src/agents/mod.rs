@@ -2,12 +2,14 @@
 //! Add new adapters in `registry.rs` so they are discoverable by clients.
 
 mod claude;
+mod cursor;
 mod registry;
 mod stubs;
 mod types;
 
 pub use claude::ClaudeAdapter;
-pub use registry::AgentRegistry;
+pub use cursor::CursorAdapter;
+pub use registry::{all_adapters, AgentRegistry};
 pub use stubs::{AiderAdapter, AmpAdapter, CodexAdapter, DroidAdapter, OpenCodeAdapter};
 pub use types::{AdapterError, AgentConfig, AgentSession, AgentState, AgentType};
 
@@ -5,6 +5,7 @@ use std::sync::{Arc, RwLock};
 use std::time::SystemTime;
 
 use super::claude::ClaudeAdapter;
+use super::cursor::CursorAdapter;
 use super::stubs::{AiderAdapter, AmpAdapter, CodexAdapter, DroidAdapter, OpenCodeAdapter};
 use super::types::{AgentConfig, AgentSession};
 use super::AgentAdapter;
@@ -202,17 +203,26 @@ impl AgentRegistry {
     }
 
     fn create_adapters() -> Vec<Arc<dyn AgentAdapter>> {
-        vec![
-            Arc::new(ClaudeAdapter::new()),
-            Arc::new(CodexAdapter::new()),
-            Arc::new(AiderAdapter::new()),
-            Arc::new(AmpAdapter::new()),
-            Arc::new(OpenCodeAdapter::new()),
-            Arc::new(DroidAdapter::new()),
-        ]
+        all_adapters().into_iter().map(Arc::from).collect()
     }
 }
 
+/// Every known agent adapter, installed or not, in default display order.
+///
+/// The registry is built from this list; callers that want to iterate agent
+/// types directly (e.g. to show several agents' sessions together) can use it too.
+pub fn all_adapters() -> Vec<Box<dyn AgentAdapter>> {
+    vec![
+        Box::new(ClaudeAdapter::new()),
+        Box::new(CursorAdapter::new()),
+        Box::new(CodexAdapter::new()),
+        Box::new(AiderAdapter::new()),
+        Box::new(AmpAdapter::new()),
+        Box::new(OpenCodeAdapter::new()),
+        Box::new(DroidAdapter::new()),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,9 +236,11 @@ mod tests {
             state,
             session_id: Some("test-session".to_string()),
             cwd: cwd.to_string(),
+            project_path: None,
             detail: None,
             working_on: None,
             updated_at: None,
+            active_subagent_count: 0,
         }
     }
 
@@ -374,4 +386,13 @@ mod tests {
         let sessions2 = registry.all_sessions_cached();
         assert_eq!(sessions2.len(), 0);
     }
+
+    #[test]
+    fn test_all_adapters_have_unique_ids() {
+        let ids: Vec<_> = all_adapters().iter().map(|a| a.id()).collect();
+        assert_eq!(ids[0], "claude");
+        assert!(ids.contains(&"cursor"));
+        let unique: std::collections::HashSet<_> = ids.iter().collect();
+        assert_eq!(unique.len(), ids.len());
+    }
 }
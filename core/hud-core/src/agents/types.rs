@@ -10,6 +10,7 @@ pub enum AgentState {
     Ready,
     Working,
     Waiting,
+    Thinking,
 }
 
 impl std::fmt::Display for AgentState {
@@ -19,6 +20,7 @@ impl std::fmt::Display for AgentState {
             AgentState::Ready => write!(f, "ready"),
             AgentState::Working => write!(f, "working"),
             AgentState::Waiting => write!(f, "waiting"),
+            AgentState::Thinking => write!(f, "thinking"),
         }
     }
 }
@@ -32,6 +34,7 @@ pub enum AgentType {
     Amp,
     OpenCode,
     Droid,
+    Cursor,
     Other,
 }
 
@@ -44,6 +47,7 @@ impl AgentType {
             Self::Amp => "amp",
             Self::OpenCode => "opencode",
             Self::Droid => "droid",
+            Self::Cursor => "cursor",
             Self::Other => "other",
         }
     }
@@ -56,6 +60,7 @@ impl AgentType {
             Self::Amp => "Amp",
             Self::OpenCode => "OpenCode",
             Self::Droid => "Droid",
+            Self::Cursor => "Cursor",
             Self::Other => "Other",
         }
     }
@@ -79,12 +84,20 @@ pub struct AgentSession {
     #[serde(default)]
     pub session_id: Option<String>,
     pub cwd: String,
+    /// Project root enclosing `cwd` (nearest boundary such as a git root or
+    /// manifest), so a session started in `repo/src` groups under `repo`.
+    /// None when no boundary is found.
+    #[serde(default)]
+    pub project_path: Option<String>,
     #[serde(default)]
     pub detail: Option<String>,
     #[serde(default)]
     pub working_on: Option<String>,
     #[serde(default)]
     pub updated_at: Option<String>,
+    /// Subagents (Task tool) currently running under this session
+    #[serde(default)]
+    pub active_subagent_count: u32,
 }
 
 /// Agent configuration with user preferences
@@ -136,6 +149,7 @@ mod tests {
         assert_eq!(AgentState::Ready.to_string(), "ready");
         assert_eq!(AgentState::Working.to_string(), "working");
         assert_eq!(AgentState::Waiting.to_string(), "waiting");
+        assert_eq!(AgentState::Thinking.to_string(), "thinking");
     }
 
     #[test]
@@ -146,6 +160,7 @@ mod tests {
         assert_eq!(AgentType::Amp.id(), "amp");
         assert_eq!(AgentType::OpenCode.id(), "opencode");
         assert_eq!(AgentType::Droid.id(), "droid");
+        assert_eq!(AgentType::Cursor.id(), "cursor");
         assert_eq!(AgentType::Other.id(), "other");
     }
 
@@ -3,17 +3,21 @@
 //! This module handles:
 //! - Counting artifacts (skills, commands, agents)
 //! - Parsing frontmatter from markdown files
-//! - Collecting artifact metadata
+//! - Collecting artifact metadata, reusing cached parses for unchanged files
 //! - Frontmatter parsing is best-effort; missing fields default to empty strings.
 
+use crate::config::{load_hud_config_with_storage, save_hud_config_with_storage};
 use crate::patterns::{
     RE_FRONTMATTER, RE_FRONTMATTER_DESC, RE_FRONTMATTER_NAME, RE_MD_BOLD_ASTERISK,
     RE_MD_BOLD_UNDERSCORE, RE_MD_CODE, RE_MD_HEADING, RE_MD_ITALIC_ASTERISK,
     RE_MD_ITALIC_UNDERSCORE, RE_MD_LINK,
 };
-use crate::types::Artifact;
+use crate::storage::StorageConfig;
+use crate::types::{Artifact, ArtifactCache, CachedArtifact, EffectiveArtifact};
 use fs_err as fs;
+use std::collections::HashMap;
 use std::path::Path;
+use std::time::UNIX_EPOCH;
 use walkdir::WalkDir;
 
 /// Counts artifacts of a given type in a directory.
@@ -103,6 +107,18 @@ pub fn strip_markdown(text: &str) -> String {
 
 /// Collects all artifacts of a given type from a directory.
 pub fn collect_artifacts_from_dir(dir: &Path, artifact_type: &str, source: &str) -> Vec<Artifact> {
+    collect_artifacts_from_dir_cached(dir, artifact_type, source, &mut ArtifactCache::default())
+}
+
+/// Like [`collect_artifacts_from_dir`], but only re-parses files whose size or
+/// mtime changed since they were recorded in `cache`; unchanged files come back
+/// from the cache without being read.
+pub fn collect_artifacts_from_dir_cached(
+    dir: &Path,
+    artifact_type: &str,
+    source: &str,
+    cache: &mut ArtifactCache,
+) -> Vec<Artifact> {
     let mut artifacts = Vec::new();
 
     if !dir.exists() {
@@ -118,6 +134,9 @@ pub fn collect_artifacts_from_dir(dir: &Path, artifact_type: &str, source: &str)
                 .filter_map(|e| e.ok())
             {
                 if entry.file_type().is_dir() {
+                    if !is_utf8_path(entry.path()) {
+                        continue;
+                    }
                     let skill_md = entry.path().join("SKILL.md");
                     let skill_path = if skill_md.exists() {
                         skill_md
@@ -130,26 +149,14 @@ pub fn collect_artifacts_from_dir(dir: &Path, artifact_type: &str, source: &str)
                         }
                     };
 
-                    if let Ok(content) = fs::read_to_string(&skill_path) {
-                        let (name, description) =
-                            parse_frontmatter(&content).unwrap_or_else(|| {
-                                (
-                                    entry.file_name().to_string_lossy().to_string(),
-                                    String::new(),
-                                )
-                            });
-                        artifacts.push(Artifact {
-                            artifact_type: "skill".to_string(),
-                            name: if name.is_empty() {
-                                entry.file_name().to_string_lossy().to_string()
-                            } else {
-                                name
-                            },
-                            description,
-                            source: source.to_string(),
-                            path: skill_path.to_string_lossy().to_string(),
-                        });
-                    }
+                    let fallback_name = entry.file_name().to_string_lossy().to_string();
+                    artifacts.extend(load_artifact(
+                        &skill_path,
+                        "skill",
+                        &fallback_name,
+                        source,
+                        cache,
+                    ));
                 }
             }
         }
@@ -161,32 +168,21 @@ pub fn collect_artifacts_from_dir(dir: &Path, artifact_type: &str, source: &str)
                 .filter_map(|e| e.ok())
             {
                 if entry.path().extension().is_some_and(|ext| ext == "md") {
-                    if let Ok(content) = fs::read_to_string(entry.path()) {
-                        let (name, description) =
-                            parse_frontmatter(&content).unwrap_or_else(|| {
-                                let file_stem = entry
-                                    .path()
-                                    .file_stem()
-                                    .map(|s| s.to_string_lossy().to_string())
-                                    .unwrap_or_default();
-                                (file_stem, String::new())
-                            });
-                        artifacts.push(Artifact {
-                            artifact_type: artifact_type.to_string(),
-                            name: if name.is_empty() {
-                                entry
-                                    .path()
-                                    .file_stem()
-                                    .map(|s| s.to_string_lossy().to_string())
-                                    .unwrap_or_default()
-                            } else {
-                                name
-                            },
-                            description,
-                            source: source.to_string(),
-                            path: entry.path().to_string_lossy().to_string(),
-                        });
+                    if !is_utf8_path(entry.path()) {
+                        continue;
                     }
+                    let file_stem = entry
+                        .path()
+                        .file_stem()
+                        .map(|s| s.to_string_lossy().to_string())
+                        .unwrap_or_default();
+                    artifacts.extend(load_artifact(
+                        entry.path(),
+                        artifact_type,
+                        &file_stem,
+                        source,
+                        cache,
+                    ));
                 }
             }
         }
@@ -195,3 +191,216 @@ pub fn collect_artifacts_from_dir(dir: &Path, artifact_type: &str, source: &str)
 
     artifacts
 }
+
+/// Returns the artifact defined by one markdown file, from the cache when the
+/// file's size and mtime match what was recorded, otherwise by parsing it.
+///
+/// The name falls back to `fallback_name` (skill directory or file stem) when
+/// the frontmatter doesn't set one.
+fn load_artifact(
+    path: &Path,
+    artifact_type: &str,
+    fallback_name: &str,
+    source: &str,
+    cache: &mut ArtifactCache,
+) -> Option<Artifact> {
+    let key = path.to_string_lossy().to_string();
+    let metadata = fs::metadata(path).ok()?;
+    let size = metadata.len();
+    let mtime = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    if let Some(cached) = cache.files.get(&key) {
+        if cached.size == size && cached.mtime == mtime {
+            return Some(Artifact {
+                source: source.to_string(),
+                ..cached.artifact.clone()
+            });
+        }
+    }
+
+    let content = fs::read_to_string(path).ok()?;
+    let (name, description) =
+        parse_frontmatter(&content).unwrap_or_else(|| (fallback_name.to_string(), String::new()));
+    let artifact = Artifact {
+        artifact_type: artifact_type.to_string(),
+        name: if name.is_empty() {
+            fallback_name.to_string()
+        } else {
+            name
+        },
+        description,
+        source: source.to_string(),
+        path: key.clone(),
+        enabled: true,
+    };
+    cache.insert(
+        key,
+        CachedArtifact {
+            size,
+            mtime,
+            artifact: artifact.clone(),
+        },
+    );
+    Some(artifact)
+}
+
+/// Collects skills, commands, and agents from a directory laid out like
+/// `~/.claude` or a plugin install (`skills/`, `commands/`, `agents/`).
+pub fn collect_artifacts_from_root(root: &Path, source: &str) -> Vec<Artifact> {
+    collect_artifacts_from_root_cached(root, source, &mut ArtifactCache::default())
+}
+
+/// Cache-aware [`collect_artifacts_from_root`]; see [`collect_artifacts_from_dir_cached`].
+pub fn collect_artifacts_from_root_cached(
+    root: &Path,
+    source: &str,
+    cache: &mut ArtifactCache,
+) -> Vec<Artifact> {
+    [
+        ("skills", "skill"),
+        ("commands", "command"),
+        ("agents", "agent"),
+    ]
+    .iter()
+    .flat_map(|(dir, artifact_type)| {
+        collect_artifacts_from_dir_cached(&root.join(dir), artifact_type, source, cache)
+    })
+    .collect()
+}
+
+/// Key identifying an artifact within a plugin in `HudConfig.disabled_artifacts`.
+fn artifact_key(artifact_type: &str, name: &str) -> String {
+    format!("{}:{}", artifact_type, name)
+}
+
+/// Switches a single skill, command, or agent of a plugin on or off, leaving
+/// the rest of the plugin enabled.
+///
+/// Recorded in Capacitor's config (not Claude's settings), so it only affects
+/// what the HUD lists as active.
+pub fn toggle_plugin_artifact(
+    plugin_id: &str,
+    artifact_type: &str,
+    artifact_name: &str,
+    enabled: bool,
+) -> Result<(), String> {
+    toggle_plugin_artifact_with_storage(
+        &StorageConfig::default(),
+        plugin_id,
+        artifact_type,
+        artifact_name,
+        enabled,
+    )
+}
+
+pub fn toggle_plugin_artifact_with_storage(
+    storage: &StorageConfig,
+    plugin_id: &str,
+    artifact_type: &str,
+    artifact_name: &str,
+    enabled: bool,
+) -> Result<(), String> {
+    if !matches!(artifact_type, "skill" | "command" | "agent") {
+        return Err(format!("Unknown artifact type: {}", artifact_type));
+    }
+
+    let mut config = load_hud_config_with_storage(storage);
+    let key = artifact_key(artifact_type, artifact_name);
+    let disabled = config
+        .disabled_artifacts
+        .entry(plugin_id.to_string())
+        .or_default();
+    disabled.retain(|k| k != &key);
+    if !enabled {
+        disabled.push(key);
+        disabled.sort();
+    }
+    if disabled.is_empty() {
+        config.disabled_artifacts.remove(plugin_id);
+    }
+    save_hud_config_with_storage(storage, &config)
+}
+
+/// Sets `enabled` to false on artifacts listed in a plugin's disabled entries.
+pub fn mark_disabled_artifacts(artifacts: &mut [Artifact], disabled: &[String]) {
+    for artifact in artifacts {
+        artifact.enabled =
+            !disabled.contains(&artifact_key(&artifact.artifact_type, &artifact.name));
+    }
+}
+
+/// Precedence when two sources provide a same-named artifact; lower wins.
+/// Project artifacts override plugins, which override the user's global ones.
+fn source_rank(source: &str) -> u8 {
+    match source {
+        "Project" => 0,
+        "Global" => 2,
+        _ => 1,
+    }
+}
+
+/// Decides which artifacts a project actually gets.
+///
+/// Each candidate is paired with whether its source is enabled for the project.
+/// Among enabled artifacts sharing a type and name, the highest-precedence
+/// source wins and the rest are marked as shadowed by it.
+pub fn resolve_artifact_precedence(candidates: Vec<(Artifact, bool)>) -> Vec<EffectiveArtifact> {
+    let mut winners: HashMap<(String, String), usize> = HashMap::new();
+    for (i, (artifact, enabled)) in candidates.iter().enumerate() {
+        if !enabled {
+            continue;
+        }
+        let key = (artifact.artifact_type.clone(), artifact.name.clone());
+        let winner = winners.entry(key).or_insert(i);
+        if source_rank(&artifact.source) < source_rank(&candidates[*winner].0.source) {
+            *winner = i;
+        }
+    }
+
+    let mut resolved: Vec<EffectiveArtifact> = candidates
+        .iter()
+        .enumerate()
+        .map(|(i, (artifact, enabled))| {
+            let key = (artifact.artifact_type.clone(), artifact.name.clone());
+            let shadowed_by = winners
+                .get(&key)
+                .filter(|&&winner| *enabled && winner != i)
+                .map(|&winner| candidates[winner].0.source.clone());
+            EffectiveArtifact {
+                artifact: artifact.clone(),
+                active: *enabled && shadowed_by.is_none(),
+                shadowed_by,
+                disabled: !enabled,
+            }
+        })
+        .collect();
+
+    resolved.sort_by(|a, b| {
+        a.artifact
+            .artifact_type
+            .cmp(&b.artifact.artifact_type)
+            .then_with(|| {
+                a.artifact
+                    .name
+                    .to_lowercase()
+                    .cmp(&b.artifact.name.to_lowercase())
+            })
+            .then_with(|| source_rank(&a.artifact.source).cmp(&source_rank(&b.artifact.source)))
+    });
+    resolved
+}
+
+/// Artifact paths are handed to clients as strings, so a lossy conversion would
+/// point at a file that doesn't exist. Such entries are skipped with a warning.
+fn is_utf8_path(path: &Path) -> bool {
+    if path.to_str().is_some() {
+        return true;
+    }
+    tracing::warn!(path = %path.display(), "Skipping artifact with non-UTF-8 path");
+    false
+}
@@ -0,0 +1,186 @@
+//! Backup and restore of the whole `~/.capacitor` directory.
+//!
+//! A backup is a gzip-compressed tarball of everything under the storage root
+//! except lock directories, which describe processes on the machine that wrote
+//! them, and earlier safety backups. It is what users attach to bug reports and
+//! carry to a new machine.
+//!
+//! Restoring unpacks into a staging directory first, so a corrupt archive leaves
+//! the current state untouched, and writes a safety backup of the current state
+//! to `backups/` before replacing anything.
+
+use crate::storage::StorageConfig;
+use chrono::Utc;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use fs_err as fs;
+use std::ffi::OsStr;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Top-level entries never archived or replaced: live lock dirs and backups.
+const EXCLUDED_ENTRIES: &[&str] = &["sessions", "backups"];
+
+/// Archives the Capacitor state directory to a `.tar.gz` at `dest`.
+///
+/// Returns the number of files archived.
+pub fn backup_capacitor_state(dest: &str) -> Result<u32, String> {
+    backup_capacitor_state_with_storage(&StorageConfig::default(), dest)
+}
+
+pub fn backup_capacitor_state_with_storage(
+    storage: &StorageConfig,
+    dest: &str,
+) -> Result<u32, String> {
+    write_archive(storage.root(), Path::new(dest))
+}
+
+/// Replaces the Capacitor state directory with the contents of a backup.
+///
+/// Lock directories are left as they are. Returns the path of the safety backup
+/// taken of the previous state.
+pub fn restore_capacitor_state(src: &str) -> Result<String, String> {
+    restore_capacitor_state_with_storage(&StorageConfig::default(), src)
+}
+
+pub fn restore_capacitor_state_with_storage(
+    storage: &StorageConfig,
+    src: &str,
+) -> Result<String, String> {
+    let root = storage.root();
+    fs::create_dir_all(root).map_err(|e| e.to_string())?;
+
+    let staging = tempfile::Builder::new()
+        .prefix(".restore-")
+        .tempdir_in(root)
+        .map_err(|e| format!("Failed to create staging directory: {}", e))?;
+    let archive = fs::File::open(src).map_err(|e| e.to_string())?;
+    tar::Archive::new(GzDecoder::new(archive))
+        .unpack(staging.path())
+        .map_err(|e| format!("Failed to read backup {}: {}", src, e))?;
+
+    let safety_backup = storage.backups_dir().join(format!(
+        "pre-restore-{}.tar.gz",
+        Utc::now().format("%Y%m%d-%H%M%S")
+    ));
+    write_archive(root, &safety_backup)?;
+
+    for entry in fs::read_dir(root).map_err(|e| e.to_string())?.flatten() {
+        let path = entry.path();
+        if is_excluded(&entry.file_name()) || path == staging.path() {
+            continue;
+        }
+        let removed = if path.is_dir() {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_file(&path)
+        };
+        removed.map_err(|e| e.to_string())?;
+    }
+
+    for entry in fs::read_dir(staging.path())
+        .map_err(|e| e.to_string())?
+        .flatten()
+    {
+        if is_excluded(&entry.file_name()) {
+            continue;
+        }
+        fs::rename(entry.path(), root.join(entry.file_name())).map_err(|e| e.to_string())?;
+    }
+
+    Ok(safety_backup.to_string_lossy().into_owned())
+}
+
+fn is_excluded(name: &OsStr) -> bool {
+    name.to_str()
+        .is_some_and(|name| EXCLUDED_ENTRIES.contains(&name) || name.starts_with(".restore-"))
+}
+
+fn write_archive(root: &Path, dest: &Path) -> Result<u32, String> {
+    if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let file = fs::File::create(dest).map_err(|e| e.to_string())?;
+    // The archive may be written inside the directory being archived
+    let dest = std::fs::canonicalize(dest).map_err(|e| e.to_string())?;
+    let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+
+    let mut count = 0;
+    let entries = WalkDir::new(root)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|e| e.depth() > 1 || !is_excluded(e.file_name()));
+    for entry in entries {
+        let entry = entry.map_err(|e| e.to_string())?;
+        if !entry.file_type().is_file()
+            || std::fs::canonicalize(entry.path()).is_ok_and(|p| p == dest)
+        {
+            continue;
+        }
+        let name = entry.path().strip_prefix(root).map_err(|e| e.to_string())?;
+        builder
+            .append_path_with_name(entry.path(), name)
+            .map_err(|e| format!("Failed to archive {}: {}", entry.path().display(), e))?;
+        count += 1;
+    }
+
+    builder
+        .into_inner()
+        .and_then(|encoder| encoder.finish())
+        .map_err(|e| format!("Failed to write backup {}: {}", dest.display(), e))?;
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn backup_and_restore_round_trip_state_files() {
+        let temp = TempDir::new().unwrap();
+        let storage = StorageConfig::with_root(temp.path().join("capacitor"));
+        storage.ensure_dirs().unwrap();
+        fs::write(storage.config_file(), r#"{"terminal_app":"Ghostty"}"#).unwrap();
+        fs::write(storage.sessions_file(), r#"{"version":3,"sessions":{}}"#).unwrap();
+        let lock = storage.sessions_dir().join("abc.lock");
+        fs::create_dir_all(&lock).unwrap();
+        fs::write(lock.join("pid"), "123").unwrap();
+
+        let dest = temp.path().join("out/backup.tar.gz");
+        let count = backup_capacitor_state_with_storage(&storage, dest.to_str().unwrap()).unwrap();
+        assert_eq!(count, 2, "lock dirs are not archived");
+
+        fs::write(storage.config_file(), r#"{"terminal_app":"Warp"}"#).unwrap();
+        fs::write(storage.root().join("stray.json"), "{}").unwrap();
+        let safety =
+            restore_capacitor_state_with_storage(&storage, dest.to_str().unwrap()).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(storage.config_file()).unwrap(),
+            r#"{"terminal_app":"Ghostty"}"#
+        );
+        assert_eq!(
+            fs::read_to_string(storage.sessions_file()).unwrap(),
+            r#"{"version":3,"sessions":{}}"#
+        );
+        assert!(!storage.root().join("stray.json").exists());
+        assert!(lock.join("pid").exists());
+        assert!(Path::new(&safety).exists());
+    }
+
+    #[test]
+    fn restore_of_corrupt_archive_leaves_state_untouched() {
+        let temp = TempDir::new().unwrap();
+        let storage = StorageConfig::with_root(temp.path().join("capacitor"));
+        storage.ensure_dirs().unwrap();
+        fs::write(storage.config_file(), "{}").unwrap();
+        let bogus = temp.path().join("bogus.tar.gz");
+        fs::write(&bogus, "not an archive").unwrap();
+
+        assert!(restore_capacitor_state_with_storage(&storage, bogus.to_str().unwrap()).is_err());
+        assert_eq!(fs::read_to_string(storage.config_file()).unwrap(), "{}");
+        assert!(!storage.backups_dir().exists());
+    }
+}
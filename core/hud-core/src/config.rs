@@ -2,16 +2,22 @@
 //!
 //! Handles paths and persistence for:
 //! - HUD configuration (pinned projects)
-//! - Statistics cache
+//! - Statistics cache (JSON, or MessagePack when `binary_stats_cache` is on)
+//! - Artifact parse cache
 //!
 //! Note: This module uses `StorageConfig::default()` for paths. For testing
 //! with custom paths, use the `StorageConfig` struct directly.
 //! Reads are best-effort; malformed files return defaults to keep the app usable.
 
 use crate::storage::StorageConfig;
-use crate::types::{HudConfig, StatsCache};
+use crate::types::{
+    ArtifactCache, CachedProjectStats, HudConfig, StatsCache, StatsCacheRepair, STATS_CACHE_VERSION,
+};
 use fs_err as fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Leading bytes of a binary stats cache: magic followed by a format version.
+const STATS_CACHE_BINARY_HEADER: &[u8] = b"CAPSTATS\x01";
 
 /// Returns the path to the Claude directory (~/.claude).
 ///
@@ -41,6 +47,27 @@ pub fn get_projects_config_path_for(storage: &StorageConfig) -> PathBuf {
     storage.projects_file()
 }
 
+/// The global `~/.claude/CLAUDE.md` as of one read.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct GlobalInstructions {
+    pub path: String,
+    /// None when the file doesn't exist
+    pub content: Option<String>,
+}
+
+/// Reads the global `~/.claude/CLAUDE.md`, None if it doesn't exist.
+pub fn read_global_instructions() -> Option<String> {
+    read_global_instructions_with_storage(&StorageConfig::default())
+}
+
+pub fn read_global_instructions_with_storage(storage: &StorageConfig) -> Option<String> {
+    fs::read_to_string(global_instructions_path(storage)).ok()
+}
+
+pub fn global_instructions_path(storage: &StorageConfig) -> PathBuf {
+    storage.claude_root().join("CLAUDE.md")
+}
+
 /// Loads the HUD configuration, returning defaults if file doesn't exist.
 pub fn load_hud_config() -> HudConfig {
     load_hud_config_with_storage(&StorageConfig::default())
@@ -96,14 +123,27 @@ pub fn load_stats_cache() -> StatsCache {
 }
 
 /// Loads the statistics cache for a specific storage root.
+///
+/// A binary cache is preferred when present; otherwise the legacy JSON cache is
+/// read. The next save rewrites it in whichever format the config selects.
+/// A cache from another [`STATS_CACHE_VERSION`] is discarded.
 pub fn load_stats_cache_with_storage(storage: &StorageConfig) -> StatsCache {
-    let path = get_stats_cache_path_for(storage);
-    fs::read_to_string(&path)
-        .ok()
-        .and_then(|c| serde_json::from_str(&c).ok())
+    read_binary_stats_cache(&storage.stats_cache_binary_file())
+        .or_else(|| {
+            fs::read_to_string(get_stats_cache_path_for(storage))
+                .ok()
+                .and_then(|c| serde_json::from_str(&c).ok())
+        })
+        .filter(|cache| cache.version == STATS_CACHE_VERSION)
         .unwrap_or_default()
 }
 
+fn read_binary_stats_cache(path: &Path) -> Option<StatsCache> {
+    let bytes = fs::read(path).ok()?;
+    let body = bytes.strip_prefix(STATS_CACHE_BINARY_HEADER)?;
+    rmp_serde::from_slice(body).ok()
+}
+
 /// Saves the statistics cache to disk.
 pub fn save_stats_cache(cache: &StatsCache) -> Result<(), String> {
     save_stats_cache_with_storage(&StorageConfig::default(), cache)
@@ -114,19 +154,274 @@ pub fn save_stats_cache_with_storage(
     storage: &StorageConfig,
     cache: &StatsCache,
 ) -> Result<(), String> {
-    let path = get_stats_cache_path_for(storage);
+    let json_path = get_stats_cache_path_for(storage);
+    let binary_path = storage.stats_cache_binary_file();
 
     // Ensure parent directory exists
-    if let Some(parent) = path.parent() {
+    if let Some(parent) = json_path.parent() {
         fs::create_dir_all(parent)
             .map_err(|e| format!("Failed to create cache directory: {}", e))?;
     }
 
+    // Only one format is kept on disk, so a stale copy can never shadow the other
+    let (path, content, other) = if load_hud_config_with_storage(storage).binary_stats_cache {
+        let mut content = STATS_CACHE_BINARY_HEADER.to_vec();
+        content.extend(
+            rmp_serde::to_vec_named(cache)
+                .map_err(|e| format!("Failed to serialize cache: {}", e))?,
+        );
+        (binary_path, content, json_path)
+    } else {
+        let content =
+            serde_json::to_vec(cache).map_err(|e| format!("Failed to serialize cache: {}", e))?;
+        (json_path, content, binary_path)
+    };
+
+    fs::write(&path, content).map_err(|e| format!("Failed to write cache: {}", e))?;
+    match fs::remove_file(&other) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            Err(format!("Failed to remove old cache: {}", e))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Loads the artifact parse cache for a specific storage root, returning an
+/// empty cache if the file is missing or unreadable.
+pub fn load_artifact_cache_with_storage(storage: &StorageConfig) -> ArtifactCache {
+    fs::read_to_string(storage.artifacts_cache_file())
+        .ok()
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+/// Saves the artifact parse cache for a specific storage root.
+pub fn save_artifact_cache_with_storage(
+    storage: &StorageConfig,
+    cache: &ArtifactCache,
+) -> Result<(), String> {
+    let path = storage.artifacts_cache_file();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+    }
     let content =
-        serde_json::to_string(cache).map_err(|e| format!("Failed to serialize cache: {}", e))?;
+        serde_json::to_vec(cache).map_err(|e| format!("Failed to serialize cache: {}", e))?;
     fs::write(&path, content).map_err(|e| format!("Failed to write cache: {}", e))
 }
 
+/// Salvages valid project entries from a partially-corrupt statistics cache.
+///
+/// `load_stats_cache` discards the whole file if any part fails to parse, which
+/// forces every project to be recomputed. This parses the cache project by project,
+/// keeps the entries that decode, and rewrites the file with just those.
+/// A missing cache is a no-op.
+pub fn repair_stats_cache() -> Result<StatsCacheRepair, String> {
+    repair_stats_cache_with_storage(&StorageConfig::default())
+}
+
+/// Salvages the statistics cache for a specific storage root.
+///
+/// Like loading, this prefers the binary cache when present and falls back to
+/// the JSON one. Entries salvaged from another [`STATS_CACHE_VERSION`] keep
+/// their totals but lose their per-file subtotals, so every file is re-parsed.
+pub fn repair_stats_cache_with_storage(
+    storage: &StorageConfig,
+) -> Result<StatsCacheRepair, String> {
+    let (entries, mut dropped, version) =
+        if let Ok(bytes) = fs::read(storage.stats_cache_binary_file()) {
+            bytes
+                .strip_prefix(STATS_CACHE_BINARY_HEADER)
+                .map(salvage_binary_project_entries)
+                .unwrap_or_default()
+        } else if let Ok(content) = fs::read_to_string(get_stats_cache_path_for(storage)) {
+            let (entries, dropped) = salvage_project_entries(&content);
+            (entries, dropped, json_cache_version(&content))
+        } else {
+            return Ok(StatsCacheRepair::default());
+        };
+
+    let mut cache = StatsCache::default();
+    for (project_path, value) in entries {
+        match serde_json::from_value::<CachedProjectStats>(value) {
+            Ok(mut stats) => {
+                if version != STATS_CACHE_VERSION {
+                    for file in stats.files.values_mut() {
+                        file.subtotal = None;
+                    }
+                }
+                cache.projects.insert(project_path, stats);
+            }
+            Err(_) => dropped += 1,
+        }
+    }
+
+    save_stats_cache_with_storage(storage, &cache)?;
+    Ok(StatsCacheRepair {
+        salvaged: cache.projects.len() as u32,
+        dropped,
+    })
+}
+
+/// Splits the `projects` map into raw entries without requiring the whole file to
+/// be valid JSON. Stops at the first entry that isn't well-formed (e.g. a truncated
+/// write) and counts it as dropped.
+fn salvage_project_entries(content: &str) -> (Vec<(String, serde_json::Value)>, u32) {
+    if let Ok(value) = serde_json::from_str::<serde_json::Value>(content) {
+        let entries = value
+            .get("projects")
+            .and_then(|p| p.as_object())
+            .map(|map| map.clone().into_iter().collect())
+            .unwrap_or_default();
+        return (entries, 0);
+    }
+
+    let Some(start) = content.find("\"projects\"") else {
+        return (Vec::new(), 0);
+    };
+    let mut rest = content[start + "\"projects\"".len()..].trim_start();
+    let Some(after_brace) = rest
+        .strip_prefix(':')
+        .map(str::trim_start)
+        .and_then(|r| r.strip_prefix('{'))
+    else {
+        return (Vec::new(), 0);
+    };
+    rest = after_brace;
+
+    let mut entries = Vec::new();
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        if rest.is_empty() || rest.starts_with('}') {
+            return (entries, 0);
+        }
+
+        let Some((key, after_key)) = next_json::<String>(rest) else {
+            return (entries, 1);
+        };
+        let Some(after_colon) = after_key.trim_start().strip_prefix(':') else {
+            return (entries, 1);
+        };
+        let Some((value, after_value)) = next_json::<serde_json::Value>(after_colon) else {
+            return (entries, 1);
+        };
+        entries.push((key, value));
+        rest = after_value;
+    }
+}
+
+/// Reads the leading `version` field of a JSON cache, which is written before
+/// `projects` so a truncated file still has it. Missing means 0.
+fn json_cache_version(content: &str) -> u32 {
+    let Some(rest) = content.trim_start().strip_prefix('{') else {
+        return 0;
+    };
+    match next_json::<String>(rest) {
+        Some((key, after_key)) if key == "version" => after_key
+            .trim_start()
+            .strip_prefix(':')
+            .and_then(next_json::<u32>)
+            .map_or(0, |(version, _)| version),
+        _ => 0,
+    }
+}
+
+/// Binary counterpart of [`salvage_project_entries`]: decodes the `projects`
+/// entries one at a time and stops at the first that doesn't decode. Also
+/// returns the cache version.
+fn salvage_binary_project_entries(body: &[u8]) -> (Vec<(String, serde_json::Value)>, u32, u32) {
+    let mut salvage = BinarySalvage::default();
+    let mut deserializer = rmp_serde::Deserializer::new(body);
+    // Entries are collected as they decode, so a failure still leaves them in place
+    let _ = serde::de::DeserializeSeed::deserialize(&mut salvage, &mut deserializer);
+    (salvage.entries, salvage.dropped, salvage.version)
+}
+
+#[derive(Default)]
+struct BinarySalvage {
+    entries: Vec<(String, serde_json::Value)>,
+    dropped: u32,
+    version: u32,
+}
+
+impl<'de> serde::de::DeserializeSeed<'de> for &mut BinarySalvage {
+    type Value = ();
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(CacheVisitor(self))
+    }
+}
+
+/// Walks the top-level cache map, handing `projects` to [`ProjectsVisitor`] and
+/// recording `version`.
+struct CacheVisitor<'a>(&'a mut BinarySalvage);
+
+impl<'de> serde::de::Visitor<'de> for CacheVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a stats cache map")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "projects" {
+                map.next_value_seed(ProjectsSeed(&mut *self.0))?;
+            } else if key == "version" {
+                self.0.version = map.next_value()?;
+            } else {
+                map.next_value::<serde::de::IgnoredAny>()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+struct ProjectsSeed<'a>(&'a mut BinarySalvage);
+
+impl<'de> serde::de::DeserializeSeed<'de> for ProjectsSeed<'_> {
+    type Value = ();
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(ProjectsVisitor(self.0))
+    }
+}
+
+/// Collects `projects` entries, counting the first undecodable one as dropped.
+struct ProjectsVisitor<'a>(&'a mut BinarySalvage);
+
+impl<'de> serde::de::Visitor<'de> for ProjectsVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a map of project entries")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        loop {
+            let entry = map.next_key::<String>().and_then(|key| match key {
+                Some(key) => Ok(Some((key, map.next_value::<serde_json::Value>()?))),
+                None => Ok(None),
+            });
+            match entry {
+                Ok(Some(entry)) => self.0.entries.push(entry),
+                Ok(None) => return Ok(()),
+                Err(e) => {
+                    self.0.dropped += 1;
+                    return Err(e);
+                }
+            }
+        }
+    }
+}
+
+/// Parses one JSON value from the front of `input`, returning it and the remainder.
+fn next_json<T: serde::de::DeserializeOwned>(input: &str) -> Option<(T, &str)> {
+    let mut stream = serde_json::Deserializer::from_str(input).into_iter::<T>();
+    let value = stream.next()?.ok()?;
+    Some((value, &input[stream.byte_offset()..]))
+}
+
 /// Resolves a symlink to its canonical path.
 pub fn resolve_symlink(path: &PathBuf) -> Option<PathBuf> {
     if path.exists() {
@@ -135,3 +430,189 @@ pub fn resolve_symlink(path: &PathBuf) -> Option<PathBuf> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, StorageConfig) {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        fs::create_dir_all(storage.root()).unwrap();
+        (temp, storage)
+    }
+
+    fn valid_entry() -> &'static str {
+        r#"{"files":{"a.jsonl":{"size":10,"mtime":1}},"stats":{"total_input_tokens":5,"total_output_tokens":1,"total_cache_read_tokens":0,"total_cache_creation_tokens":0,"opus_messages":0,"sonnet_messages":1,"haiku_messages":0,"session_count":1,"latest_summary":null,"first_activity":null,"last_activity":null}}"#
+    }
+
+    #[test]
+    fn repair_keeps_valid_entries_and_drops_malformed_one() {
+        let (_temp, storage) = setup();
+        let content = format!(
+            r#"{{"projects":{{"/a":{},"/broken":{{"files":"nope"}},"/b":{}}}}}"#,
+            valid_entry(),
+            valid_entry()
+        );
+        fs::write(storage.stats_cache_file(), content).unwrap();
+        assert!(load_stats_cache_with_storage(&storage).projects.is_empty());
+
+        let repair = repair_stats_cache_with_storage(&storage).unwrap();
+        assert_eq!(
+            repair,
+            StatsCacheRepair {
+                salvaged: 2,
+                dropped: 1
+            }
+        );
+
+        let cache = load_stats_cache_with_storage(&storage);
+        assert!(cache.projects.contains_key("/a"));
+        assert!(cache.projects.contains_key("/b"));
+        assert!(!cache.projects.contains_key("/broken"));
+    }
+
+    #[test]
+    fn repair_salvages_entries_before_truncation() {
+        let (_temp, storage) = setup();
+        let content = format!(r#"{{"projects":{{"/a":{},"/b":{{"files":{{"#, valid_entry());
+        fs::write(storage.stats_cache_file(), content).unwrap();
+
+        let repair = repair_stats_cache_with_storage(&storage).unwrap();
+        assert_eq!(repair.salvaged, 1);
+        assert_eq!(repair.dropped, 1);
+        assert!(load_stats_cache_with_storage(&storage)
+            .projects
+            .contains_key("/a"));
+    }
+
+    #[test]
+    fn repair_missing_cache_is_noop() {
+        let (_temp, storage) = setup();
+        let repair = repair_stats_cache_with_storage(&storage).unwrap();
+        assert_eq!(repair, StatsCacheRepair::default());
+        assert!(!storage.stats_cache_file().exists());
+    }
+
+    fn enable_binary_cache(storage: &StorageConfig) {
+        let config = HudConfig {
+            binary_stats_cache: true,
+            ..Default::default()
+        };
+        save_hud_config_with_storage(storage, &config).unwrap();
+    }
+
+    #[test]
+    fn binary_cache_round_trips() {
+        let (_temp, storage) = setup();
+        enable_binary_cache(&storage);
+        let mut cache = StatsCache::default();
+        cache.projects.insert(
+            "/a".to_string(),
+            serde_json::from_str(valid_entry()).unwrap(),
+        );
+
+        save_stats_cache_with_storage(&storage, &cache).unwrap();
+
+        let bytes = fs::read(storage.stats_cache_binary_file()).unwrap();
+        assert!(bytes.starts_with(STATS_CACHE_BINARY_HEADER));
+        assert!(!storage.stats_cache_file().exists());
+        let loaded = load_stats_cache_with_storage(&storage);
+        assert_eq!(loaded.projects["/a"].stats.total_input_tokens, 5);
+        assert_eq!(loaded.projects["/a"].files["a.jsonl"].size, 10);
+    }
+
+    #[test]
+    fn repair_salvages_binary_cache() {
+        let (_temp, storage) = setup();
+        enable_binary_cache(&storage);
+        let entry: serde_json::Value = serde_json::from_str(valid_entry()).unwrap();
+        let cache = serde_json::json!({
+            "projects": {"/a": entry, "/broken": {"files": "nope"}}
+        });
+        let mut content = STATS_CACHE_BINARY_HEADER.to_vec();
+        content.extend(rmp_serde::to_vec_named(&cache).unwrap());
+        fs::write(storage.stats_cache_binary_file(), &content).unwrap();
+        assert!(load_stats_cache_with_storage(&storage).projects.is_empty());
+
+        let repair = repair_stats_cache_with_storage(&storage).unwrap();
+        assert_eq!(
+            repair,
+            StatsCacheRepair {
+                salvaged: 1,
+                dropped: 1
+            }
+        );
+        assert!(load_stats_cache_with_storage(&storage)
+            .projects
+            .contains_key("/a"));
+    }
+
+    #[test]
+    fn repair_salvages_binary_entries_before_truncation() {
+        let (_temp, storage) = setup();
+        enable_binary_cache(&storage);
+        let entry: serde_json::Value = serde_json::from_str(valid_entry()).unwrap();
+        let cache = serde_json::json!({"projects": {"/a": entry.clone(), "/b": entry}});
+        let mut content = STATS_CACHE_BINARY_HEADER.to_vec();
+        content.extend(rmp_serde::to_vec_named(&cache).unwrap());
+        content.truncate(content.len() - 20);
+        fs::write(storage.stats_cache_binary_file(), &content).unwrap();
+
+        let repair = repair_stats_cache_with_storage(&storage).unwrap();
+        assert_eq!(repair.salvaged, 1);
+        assert_eq!(repair.dropped, 1);
+        assert_eq!(load_stats_cache_with_storage(&storage).projects.len(), 1);
+    }
+
+    #[test]
+    fn legacy_json_cache_is_read_and_migrated_to_binary() {
+        let (_temp, storage) = setup();
+        fs::write(
+            storage.stats_cache_file(),
+            format!(
+                r#"{{"version":{},"projects":{{"/a":{}}}}}"#,
+                STATS_CACHE_VERSION,
+                valid_entry()
+            ),
+        )
+        .unwrap();
+        enable_binary_cache(&storage);
+
+        let cache = load_stats_cache_with_storage(&storage);
+        assert_eq!(cache.projects["/a"].stats.total_input_tokens, 5);
+
+        save_stats_cache_with_storage(&storage, &cache).unwrap();
+        assert!(!storage.stats_cache_file().exists());
+        assert!(load_stats_cache_with_storage(&storage)
+            .projects
+            .contains_key("/a"));
+    }
+
+    #[test]
+    fn cache_from_older_parser_is_discarded_and_repair_drops_subtotals() {
+        let (_temp, storage) = setup();
+        let mut entry: CachedProjectStats = serde_json::from_str(valid_entry()).unwrap();
+        entry.files.get_mut("a.jsonl").unwrap().subtotal = Some(entry.stats.clone());
+        let mut cache = StatsCache::default();
+        cache.projects.insert("/a".to_string(), entry);
+        save_stats_cache_with_storage(&storage, &cache).unwrap();
+        assert!(json_cache_version(&fs::read_to_string(storage.stats_cache_file()).unwrap()) > 0);
+        assert_eq!(load_stats_cache_with_storage(&storage).projects.len(), 1);
+
+        cache.version = 0;
+        save_stats_cache_with_storage(&storage, &cache).unwrap();
+        assert!(load_stats_cache_with_storage(&storage).projects.is_empty());
+
+        assert_eq!(
+            repair_stats_cache_with_storage(&storage).unwrap().salvaged,
+            1
+        );
+        let repaired = load_stats_cache_with_storage(&storage);
+        assert_eq!(repaired.version, STATS_CACHE_VERSION);
+        assert!(repaired.projects["/a"].files["a.jsonl"].subtotal.is_none());
+        assert_eq!(repaired.projects["/a"].stats.total_input_tokens, 5);
+    }
+}
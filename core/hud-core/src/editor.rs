@@ -0,0 +1,127 @@
+//! Reopening a task in an IDE with the files the session was editing.
+//!
+//! Like terminal activation, this only decides what to run: Rust returns an
+//! [`EditorLaunch`] and Swift spawns it.
+
+use crate::activity::ActivityStore;
+use crate::storage::StorageConfig;
+use crate::types::ParentApp;
+use std::path::Path;
+
+/// Maximum number of files opened alongside the project.
+pub const TASK_CONTEXT_MAX_FILES: usize = 8;
+
+/// A command line that opens the project and files in an IDE.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct EditorLaunch {
+    /// IDE command-line launcher (e.g. `cursor`, `code`)
+    pub command: String,
+    /// Project folder first, then the files to open
+    pub args: Vec<String>,
+}
+
+/// The command-line launcher for an IDE, None for apps that aren't IDEs.
+pub fn editor_command(editor: ParentApp) -> Option<&'static str> {
+    match editor {
+        ParentApp::Cursor => Some("cursor"),
+        ParentApp::VSCode => Some("code"),
+        ParentApp::VSCodeInsiders => Some("code-insiders"),
+        ParentApp::Zed => Some("zed"),
+        _ => None,
+    }
+}
+
+/// Builds the IDE invocation that restores a session's working context: the
+/// project plus up to [`TASK_CONTEXT_MAX_FILES`] of the session's most recent
+/// edits that still exist.
+pub fn open_task_context(
+    session_id: &str,
+    project_path: &str,
+    editor: ParentApp,
+) -> Result<EditorLaunch, String> {
+    open_task_context_with_storage(&StorageConfig::default(), session_id, project_path, editor)
+}
+
+pub fn open_task_context_with_storage(
+    storage: &StorageConfig,
+    session_id: &str,
+    project_path: &str,
+    editor: ParentApp,
+) -> Result<EditorLaunch, String> {
+    let command =
+        editor_command(editor).ok_or_else(|| format!("{:?} is not a supported editor", editor))?;
+    if !Path::new(project_path).is_dir() {
+        return Err(format!("Project folder not found: {}", project_path));
+    }
+
+    let store = ActivityStore::load(&storage.file_activity_file());
+    // Ask for extra candidates so deleted files don't eat into the cap
+    let files = store
+        .recent_session_edits(session_id, project_path, TASK_CONTEXT_MAX_FILES * 2)
+        .into_iter()
+        .filter(|f| Path::new(f).is_file())
+        .take(TASK_CONTEXT_MAX_FILES);
+
+    Ok(EditorLaunch {
+        command: command.to_string(),
+        args: std::iter::once(project_path.to_string())
+            .chain(files)
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fs_err as fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn opens_project_with_session_edits_newest_first() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        fs::create_dir_all(storage.root()).unwrap();
+        let project = temp.path().join("app");
+        fs::create_dir_all(project.join("src")).unwrap();
+        let project_str = project.to_string_lossy().to_string();
+        for name in ["src/a.rs", "src/b.rs", "src/c.rs"] {
+            fs::write(project.join(name), "").unwrap();
+        }
+
+        let mut store = ActivityStore::new();
+        let mut record = |session: &str, file: &str, tool: &str, ts: &str| {
+            store.record_activity(session, &project_str, file, tool, ts);
+        };
+        record("s1", "src/a.rs", "Edit", "2026-01-01T10:00:00Z");
+        record("s1", "src/b.rs", "Read", "2026-01-01T10:01:00Z");
+        record(
+            "s1",
+            &format!("{}/src/c.rs", project_str),
+            "Write",
+            "2026-01-01T10:02:00Z",
+        );
+        record("s1", "src/a.rs", "Edit", "2026-01-01T10:03:00Z");
+        record("s1", "src/gone.rs", "Edit", "2026-01-01T10:04:00Z");
+        record("s2", "src/b.rs", "Edit", "2026-01-01T10:05:00Z");
+        store.save(&storage.file_activity_file()).unwrap();
+
+        let launch =
+            open_task_context_with_storage(&storage, "s1", &project_str, ParentApp::Cursor)
+                .unwrap();
+        assert_eq!(launch.command, "cursor");
+        assert_eq!(
+            launch.args,
+            vec![
+                project_str.clone(),
+                format!("{}/src/a.rs", project_str),
+                format!("{}/src/c.rs", project_str),
+            ]
+        );
+
+        assert!(
+            open_task_context_with_storage(&storage, "s1", &project_str, ParentApp::Ghostty)
+                .is_err()
+        );
+    }
+}
@@ -17,26 +17,86 @@
 //! let states = engine.get_all_session_states(&projects);
 //! ```
 
-use crate::agents::{AgentConfig, AgentRegistry, AgentSession};
-use crate::artifacts::{collect_artifacts_from_dir, count_artifacts_in_dir, count_hooks_in_dir};
-use crate::config::{load_hud_config_with_storage, resolve_symlink, save_hud_config_with_storage};
+use crate::activity::{ActivityStore, HotFile};
+use crate::agent_changes::{
+    agent_edit_diff_with_storage, uncommitted_agent_changes_with_storage, AgentChange,
+};
+use crate::agents::{AgentAdapter, AgentConfig, AgentRegistry, AgentSession, ClaudeAdapter};
+use crate::artifacts::{
+    collect_artifacts_from_dir_cached, collect_artifacts_from_root_cached, count_artifacts_in_dir,
+    count_hooks_in_dir, mark_disabled_artifacts, resolve_artifact_precedence,
+    toggle_plugin_artifact_with_storage,
+};
+use crate::config::{
+    global_instructions_path, load_artifact_cache_with_storage, load_hud_config_with_storage,
+    read_global_instructions_with_storage, repair_stats_cache_with_storage, resolve_symlink,
+    save_artifact_cache_with_storage, save_hud_config_with_storage, GlobalInstructions,
+};
 use crate::error::HudFfiError;
-use crate::projects::{has_project_indicators, load_projects_with_storage};
+use crate::export::{export_stats_csv_with_storage, write_dashboard_json, DashboardExport};
+use crate::feed::{waiting_events, SessionWaitingEvent, StateFeedEvent, StateSnapshot};
+use crate::hook_latency::HookLatencyStats;
+use crate::mcp::{
+    load_mcp_servers_with_storage, open_mcp_config_with_storage, McpScope, McpServer,
+};
+use crate::permissions::{
+    permission_heavy_sessions_with_storage, permission_history_with_storage, PermissionEntry,
+    PermissionHeavySession,
+};
+use crate::projects::{
+    audit_pinned_projects_with_storage, classify_directory_with_storage,
+    delete_project_history_with_storage, find_overlapping_pins_with_storage, git_branch,
+    git_stash_count, group_projects_by_root_with_storage, has_project_indicators, is_git_repo,
+    load_project_details_with_storage, load_projects_with_storage, open_local_settings,
+    open_project_config_with_storage, project_plugin_overrides,
+    prune_missing_projects_with_storage, recent_claude_project_folders,
+    reorder_projects_with_storage, set_manual_order_with_storage, set_project_alias_with_storage,
+    verify_project_encoding_with_storage, DirectoryClassification, GitRepoCheck, OverlappingPins,
+    PinnedProjectAudit, ProjectConfigFile, ProjectEncodingReport, ProjectGroup,
+};
+use crate::receipt::{session_receipt_with_storage, SessionReceipt};
 use crate::sessions::{
-    detect_session_state_with_storage, get_all_session_states_with_storage, read_project_status,
-    ProjectStatus,
+    active_working_session_with_storage, clock_skew_check_with_storage,
+    compacting_sessions_with_storage, detect_session_id_collisions_with_storage,
+    detect_session_state_with_storage, force_release_lock_with_storage,
+    get_all_session_states_with_storage, next_steps_digest_with_storage,
+    orphaned_live_sessions_with_storage, projects_with_subagents_with_storage, read_project_status,
+    read_status_gen_log_with_storage, session_summary_with_storage,
+    sessions_by_host_app_with_storage, state_distribution_with_storage, ActiveWorkingSession,
+    ClockSkewReport, CompactingSession, HostAppSessions, NextStep, OrphanedLiveSession,
+    ProjectStatus, ProjectSubagents, SessionIdCollision, StateDistribution,
+};
+use crate::setup::{
+    BrokenHook, DependencyStatus, HookInstallOutcome, HookStatus, InstallResult, SetupChecker,
+    SetupStatus,
+};
+use crate::state::{MaintenanceReport, ResolverConfig, StateStore, ZombieSession};
+use crate::stats::{
+    activity_sparkline_with_storage, activity_streak_with_storage, authorship_ratio_with_storage,
+    budget_status_with_storage, context_burn_rate_with_storage, efficiency_trend_with_storage,
+    model_usage_timeseries_with_storage, poll_stats_changes_with_storage,
+    project_velocity_with_storage, session_latency_with_storage, stats_breakdown_with_storage,
+    top_sessions_by_cost_with_storage, usage_for_period_with_storage, TranscriptFingerprint,
 };
-use crate::setup::{DependencyStatus, HookStatus, InstallResult, SetupChecker, SetupStatus};
 use crate::storage::StorageConfig;
+use crate::tasks::SessionGroup;
+use crate::terminals::{DetectedTerminal, TerminalLaunch};
+use crate::trace::export_session_trace_with_storage;
+use crate::transcript::{session_transcript_with_storage, TranscriptMessage};
 use crate::types::{
-    Artifact, DashboardData, GlobalConfig, HookDiagnosticReport, HookIssue, HookTestResult,
-    HudConfig, Plugin, PluginManifest, Project, ProjectSessionState, SuggestedProject,
+    ActivityStreak, Artifact, ArtifactCache, ArtifactOrigin, AuthorshipRatio, BudgetLevel,
+    BudgetStatus, ContextBurnRate, ContextInfo, DashboardData, EffectiveArtifact, EfficiencyTrend,
+    FileStatsContribution, GlobalConfig, HookDiagnosticReport, HookIssue, HookTestResult,
+    HudConfig, ModelUsagePoint, PeriodUsage, Plugin, PluginManifest, Project, ProjectDetails,
+    ProjectSessionState, ProjectVelocity, SessionCost, SessionDurationStats, SessionLatency,
+    StatsCacheRepair, StatsChangedEvent, SuggestedProject, TelemetryContext, UsageBucket,
 };
 use crate::validation::{create_claude_md, validate_project_path, ValidationResultFfi};
 use fs_err as fs;
 use std::collections::HashMap;
-use std::path::PathBuf;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 /// The main engine for Claude HUD operations.
 ///
@@ -46,6 +106,17 @@ use std::sync::Arc;
 pub struct HudEngine {
     storage: StorageConfig,
     agent_registry: Arc<AgentRegistry>,
+    /// Shared with client polling loops; set while the HUD window is hidden
+    watchers_paused: Arc<AtomicBool>,
+    /// Last snapshot seen by each state feed subscriber
+    state_feeds: Mutex<HashMap<u64, StateSnapshot>>,
+    next_feed_id: AtomicU64,
+    /// Budget level seen by the last `check_budget_threshold` call
+    last_budget_level: Mutex<BudgetLevel>,
+    /// Transcript fingerprints seen by the last `poll_stats_changes` call, per project
+    stats_fingerprints: Mutex<HashMap<String, TranscriptFingerprint>>,
+    /// Global CLAUDE.md content seen by the last `check_global_instructions_changed` call
+    last_global_instructions: Mutex<Option<String>>,
 }
 
 impl HudEngine {
@@ -57,10 +128,17 @@ impl HudEngine {
         let agent_config = AgentConfig::default();
         let agent_registry = Arc::new(AgentRegistry::new(agent_config));
         agent_registry.initialize_all();
+        let global_instructions = read_global_instructions_with_storage(&storage);
 
         Ok(Self {
             storage,
             agent_registry,
+            watchers_paused: Arc::new(AtomicBool::new(false)),
+            state_feeds: Mutex::new(HashMap::new()),
+            next_feed_id: AtomicU64::new(1),
+            last_budget_level: Mutex::new(BudgetLevel::Ok),
+            stats_fingerprints: Mutex::new(HashMap::new()),
+            last_global_instructions: Mutex::new(global_instructions),
         })
     }
 
@@ -69,6 +147,22 @@ impl HudEngine {
     pub fn storage(&self) -> &StorageConfig {
         &self.storage
     }
+
+    /// Drops cache entries for deleted files and persists the artifact cache if
+    /// anything changed. Best-effort: a failed write only costs a re-parse later.
+    fn store_artifact_cache(&self, mut cache: ArtifactCache) {
+        cache.prune_missing();
+        if cache.dirty {
+            let _ = save_artifact_cache_with_storage(&self.storage, &cache);
+        }
+    }
+
+    fn lock_state_feeds(&self) -> std::sync::MutexGuard<'_, HashMap<u64, StateSnapshot>> {
+        // A panic mid-poll leaves at worst a stale snapshot, so recover from poisoning
+        self.state_feeds
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
 }
 
 #[uniffi::export]
@@ -93,6 +187,23 @@ impl HudEngine {
         self.storage.root().to_string_lossy().to_string()
     }
 
+    /// Pauses or resumes background refresh while the HUD isn't visible.
+    ///
+    /// While paused, the poll and check methods clients call from their refresh
+    /// loop (`poll_stats_changes`, `check_budget_threshold`,
+    /// `check_global_instructions_changed`, `poll_state_feed`,
+    /// `poll_session_waiting`) return nothing without touching disk. Their
+    /// baselines are kept, so the first poll after resuming reports whatever
+    /// changed in the meantime. Pausing on window blur saves CPU and battery.
+    pub fn set_watchers_paused(&self, paused: bool) {
+        self.watchers_paused.store(paused, Ordering::Relaxed);
+    }
+
+    /// Returns true while background refresh is paused.
+    pub fn watchers_paused(&self) -> bool {
+        self.watchers_paused.load(Ordering::Relaxed)
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Projects API
     // ─────────────────────────────────────────────────────────────────────────────
@@ -102,6 +213,207 @@ impl HudEngine {
         load_projects_with_storage(&self.storage).map_err(HudFfiError::from)
     }
 
+    /// Total estimated prompt-cache savings in dollars across pinned projects.
+    pub fn global_cache_savings_usd(&self) -> f64 {
+        load_projects_with_storage(&self.storage)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|p| p.stats.as_ref())
+            .map(|s| s.cache_savings_usd)
+            .sum()
+    }
+
+    /// Token usage per day or week for a project, split by model family.
+    ///
+    /// Suited to a stacked area chart; gaps between active buckets are zero-filled.
+    pub fn model_usage_timeseries(
+        &self,
+        project_path: String,
+        bucket: UsageBucket,
+    ) -> Vec<ModelUsagePoint> {
+        model_usage_timeseries_with_storage(&self.storage, &project_path, bucket)
+    }
+
+    /// Sessions and file edits per day for a project over the last `days` days,
+    /// normalized both per calendar day and per active day.
+    pub fn project_velocity(&self, project_path: String, days: u32) -> ProjectVelocity {
+        project_velocity_with_storage(&self.storage, &project_path, days)
+    }
+
+    /// Current and longest runs of consecutive active days for a project.
+    pub fn activity_streak(&self, project_path: String) -> ActivityStreak {
+        activity_streak_with_storage(&self.storage, &project_path)
+    }
+
+    /// Output tokens per uncached input token for each of the last `days` days,
+    /// plus whether that ratio is trending up, down, or flat.
+    pub fn efficiency_trend(&self, project_path: String, days: u32) -> EfficiencyTrend {
+        efficiency_trend_with_storage(&self.storage, &project_path, days)
+    }
+
+    /// Recomputed stats for each of `project_paths` whose session transcripts
+    /// changed since the previous call.
+    ///
+    /// This is the `stats-changed` event: clients call it from their refresh loop
+    /// to keep dashboard stats live. The first call for a project only records a
+    /// baseline, and changes are held until transcripts have been quiet for
+    /// [`crate::stats::STATS_CHANGE_DEBOUNCE`], so one burst yields one event.
+    pub fn poll_stats_changes(
+        &self,
+        project_paths: Vec<String>,
+    ) -> Result<Vec<StatsChangedEvent>, HudFfiError> {
+        if self.watchers_paused() {
+            return Ok(vec![]);
+        }
+        let mut seen = self
+            .stats_fingerprints
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        poll_stats_changes_with_storage(&self.storage, &project_paths, &mut seen)
+            .map_err(HudFfiError::from)
+    }
+
+    /// File edits over the last 24 hours in `buckets` equal buckets, oldest first,
+    /// for the inline activity chart on a project card.
+    pub fn activity_sparkline(&self, project_path: String, buckets: u32) -> Vec<u32> {
+        activity_sparkline_with_storage(&self.storage, &project_path, buckets)
+    }
+
+    /// Each session file's token and message contribution to a project's stats,
+    /// largest first, for tracking down where usage comes from.
+    pub fn stats_breakdown(&self, project_path: String) -> Vec<FileStatsContribution> {
+        stats_breakdown_with_storage(&self.storage, &project_path)
+    }
+
+    /// The `limit` most expensive sessions across all projects, most expensive first.
+    pub fn top_sessions_by_cost(&self, limit: u32) -> Vec<SessionCost> {
+        top_sessions_by_cost_with_storage(&self.storage, limit)
+    }
+
+    /// A project's sessions with resumed continuations folded into one entry
+    /// each, newest first.
+    pub fn group_continued_sessions(&self, project_path: String) -> Vec<SessionGroup> {
+        crate::tasks::group_continued_sessions_with_storage(&self.storage, &project_path)
+    }
+
+    /// Characters the user typed versus characters Claude wrote in a project's
+    /// sessions, ignoring tool traffic.
+    pub fn authorship_ratio(&self, project_path: String) -> AuthorshipRatio {
+        authorship_ratio_with_storage(&self.storage, &project_path)
+    }
+
+    /// Token totals and estimated cost across all projects for days from `start`
+    /// to `end` inclusive (`YYYY-MM-DD`), for week and billing-month views.
+    pub fn usage_for_period(&self, start: String, end: String) -> Result<PeriodUsage, HudFfiError> {
+        usage_for_period_with_storage(&self.storage, &start, &end).map_err(HudFfiError::from)
+    }
+
+    /// This month's spend against `HudConfig.monthly_budget_usd`.
+    pub fn budget_status(&self) -> Result<BudgetStatus, HudFfiError> {
+        budget_status_with_storage(&self.storage).map_err(HudFfiError::from)
+    }
+
+    /// Returns the budget status when spend has crossed the 80% or 100% mark
+    /// since the previous check, and None otherwise.
+    ///
+    /// This is the `budget-threshold` event: clients call it from their refresh
+    /// loop and notify once per crossing. A drop (e.g. a new month) resets it quietly.
+    pub fn check_budget_threshold(&self) -> Result<Option<BudgetStatus>, HudFfiError> {
+        if self.watchers_paused() {
+            return Ok(None);
+        }
+        let status = budget_status_with_storage(&self.storage).map_err(HudFfiError::from)?;
+        let mut last = self
+            .last_budget_level
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let crossed = status.level > *last;
+        *last = status.level;
+        Ok(crossed.then_some(status))
+    }
+
+    /// Content of the global `~/.claude/CLAUDE.md`, None if it doesn't exist.
+    pub fn read_global_instructions(&self) -> Option<String> {
+        read_global_instructions_with_storage(&self.storage)
+    }
+
+    /// Returns the global `~/.claude/CLAUDE.md` when its content has changed
+    /// (including being created or deleted) since the previous check, and None otherwise.
+    ///
+    /// This is the `global-instructions-changed` event: clients call it from their
+    /// refresh loop to keep an instructions editor or preview current.
+    pub fn check_global_instructions_changed(&self) -> Option<GlobalInstructions> {
+        if self.watchers_paused() {
+            return None;
+        }
+        let content = read_global_instructions_with_storage(&self.storage);
+        let mut last = self
+            .last_global_instructions
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if *last == content {
+            return None;
+        }
+        *last = content.clone();
+        Some(GlobalInstructions {
+            path: global_instructions_path(&self.storage)
+                .to_string_lossy()
+                .to_string(),
+            content,
+        })
+    }
+
+    /// Returns the project's `.claude/settings.local.json` for editing, creating it as `{}`
+    /// if absent. `Project.has_local_settings` tells the UI whether to label it create or edit.
+    pub fn open_local_settings(&self, path: String) -> Result<String, HudFfiError> {
+        open_local_settings(&path).map_err(HudFfiError::from)
+    }
+
+    /// The file behind a project's "edit config" button: `CLAUDE.md`, else
+    /// `.claude/settings.local.json`, else global settings. With `create_local_settings`,
+    /// a missing local settings file is created rather than falling back to global.
+    pub fn open_project_config(
+        &self,
+        path: String,
+        create_local_settings: bool,
+    ) -> Result<ProjectConfigFile, HudFfiError> {
+        open_project_config_with_storage(&self.storage, &path, create_local_settings)
+            .map_err(HudFfiError::from)
+    }
+
+    /// A project's card plus its instructions content, tasks, and git state.
+    pub fn get_project_details(&self, path: String) -> Result<ProjectDetails, HudFfiError> {
+        load_project_details_with_storage(&self.storage, &path).map_err(HudFfiError::from)
+    }
+
+    /// Whether a path is inside a git repository, checked without spawning git.
+    pub fn is_git_repo(&self, path: String) -> GitRepoCheck {
+        is_git_repo(&path)
+    }
+
+    /// The checked-out branch, or `detached @ <short sha>` for a detached HEAD.
+    /// None if the path isn't a git repository.
+    pub fn git_branch(&self, path: String) -> Option<String> {
+        git_branch(&path)
+    }
+
+    /// Number of git stash entries for a project, None if it isn't a git repository.
+    pub fn git_stash_count(&self, path: String) -> Option<u32> {
+        git_stash_count(&path)
+    }
+
+    /// Groups pinned projects by their common ancestor `depth` levels below home.
+    pub fn group_projects_by_root(&self, depth: u32) -> Vec<ProjectGroup> {
+        group_projects_by_root_with_storage(&self.storage, depth)
+    }
+
+    /// Audits how often Claude project folder names decode to the wrong path.
+    ///
+    /// Compares each folder's decoded path with the cwd recorded in its transcripts.
+    pub fn verify_project_encoding(&self) -> ProjectEncodingReport {
+        verify_project_encoding_with_storage(&self.storage)
+    }
+
     /// Gets the HUD configuration (pinned projects, terminal app, etc.)
     pub fn get_config(&self) -> HudConfig {
         load_hud_config_with_storage(&self.storage)
@@ -135,6 +447,52 @@ impl HudEngine {
         save_hud_config_with_storage(&self.storage, &config).map_err(HudFfiError::from)
     }
 
+    /// Sets a project's display name; an empty alias restores the folder name.
+    pub fn set_project_alias(&self, path: String, alias: String) -> Result<(), HudFfiError> {
+        set_project_alias_with_storage(&self.storage, &path, &alias).map_err(HudFfiError::from)
+    }
+
+    /// Sets the pinned projects' order and switches the list to manual order.
+    ///
+    /// `paths` must be exactly the currently pinned projects.
+    pub fn reorder_projects(&self, paths: Vec<String>) -> Result<(), HudFfiError> {
+        reorder_projects_with_storage(&self.storage, &paths).map_err(HudFfiError::from)
+    }
+
+    /// Switches the project list between manual order and most-recent-activity order.
+    pub fn set_manual_order(&self, enabled: bool) -> Result<(), HudFfiError> {
+        set_manual_order_with_storage(&self.storage, enabled).map_err(HudFfiError::from)
+    }
+
+    /// Reports which pinned projects no longer exist, with a guessed new location.
+    pub fn audit_pinned_projects(&self) -> Vec<PinnedProjectAudit> {
+        audit_pinned_projects_with_storage(&self.storage)
+    }
+
+    /// Pinned projects nested inside other pinned projects, so the UI can
+    /// suggest consolidating them.
+    pub fn find_overlapping_pins(&self) -> Vec<OverlappingPins> {
+        find_overlapping_pins_with_storage(&self.storage)
+    }
+
+    /// Unpins every project whose folder is missing. Returns the removed paths.
+    pub fn prune_missing_projects(&self) -> Result<Vec<String>, HudFfiError> {
+        prune_missing_projects_with_storage(&self.storage).map_err(HudFfiError::from)
+    }
+
+    /// Permanently deletes a project's Claude conversation history.
+    ///
+    /// `confirm_token` must be the project name. Refuses while a session is
+    /// running in the project. Returns bytes reclaimed.
+    pub fn delete_project_history(
+        &self,
+        project_path: String,
+        confirm_token: String,
+    ) -> Result<u64, HudFfiError> {
+        delete_project_history_with_storage(&self.storage, &project_path, &confirm_token)
+            .map_err(HudFfiError::from)
+    }
+
     /// Discovers suggested projects based on activity in ~/.claude/projects.
     pub fn get_suggested_projects(&self) -> Result<Vec<SuggestedProject>, HudFfiError> {
         let projects_dir = self.storage.claude_root().join("projects");
@@ -147,67 +505,80 @@ impl HudEngine {
 
         let mut suggestions: Vec<(SuggestedProject, u32)> = Vec::new();
 
-        if let Ok(entries) = fs::read_dir(&projects_dir) {
-            for entry in entries.filter_map(|e| e.ok()) {
-                if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+        for folder in recent_claude_project_folders(&projects_dir, config.max_scan_folders) {
+            // A lossy name would resolve to the wrong project and mis-key its stats
+            let Some(encoded_name) = folder
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(String::from)
+            else {
+                tracing::warn!(
+                    path = %folder.display(),
+                    "Skipping Claude project folder with non-UTF-8 name"
+                );
+                continue;
+            };
+
+            // Try to resolve the encoded path
+            if let Some(real_path) = crate::projects::try_resolve_encoded_path(&encoded_name) {
+                if pinned_set.contains(&real_path) {
                     continue;
                 }
 
-                let encoded_name = entry.file_name().to_string_lossy().to_string();
-
-                // Try to resolve the encoded path
-                if let Some(real_path) = crate::projects::try_resolve_encoded_path(&encoded_name) {
-                    if pinned_set.contains(&real_path) {
-                        continue;
-                    }
-
-                    let project_path = PathBuf::from(&real_path);
-                    let has_indicators = has_project_indicators(&project_path);
-                    let has_claude_md = project_path.join("CLAUDE.md").exists();
-
-                    let task_count = fs::read_dir(entry.path())
-                        .map(|entries| {
-                            entries
-                                .filter_map(|e| e.ok())
-                                .filter(|e| e.path().extension().is_some_and(|ext| ext == "jsonl"))
-                                .count() as u32
-                        })
-                        .unwrap_or(0);
-
-                    let display_path = if real_path.starts_with("/Users/") {
-                        format!(
-                            "~/{}",
-                            real_path.split('/').skip(3).collect::<Vec<_>>().join("/")
-                        )
-                    } else {
-                        real_path.clone()
-                    };
-
-                    let name = real_path
-                        .split('/')
-                        .next_back()
-                        .unwrap_or(&real_path)
-                        .to_string();
-
-                    suggestions.push((
-                        SuggestedProject {
-                            path: real_path,
-                            display_path,
-                            name,
-                            task_count,
-                            has_claude_md,
-                            has_project_indicators: has_indicators,
-                        },
+                let project_path = PathBuf::from(&real_path);
+                let has_indicators = has_project_indicators(&project_path);
+                let confidence = classify_directory_with_storage(&self.storage, &real_path).score;
+                let has_claude_md = project_path.join("CLAUDE.md").exists();
+
+                let task_count = fs::read_dir(&folder)
+                    .map(|entries| {
+                        entries
+                            .filter_map(|e| e.ok())
+                            .filter(|e| e.path().extension().is_some_and(|ext| ext == "jsonl"))
+                            .count() as u32
+                    })
+                    .unwrap_or(0);
+
+                let display_path = if real_path.starts_with("/Users/") {
+                    format!(
+                        "~/{}",
+                        real_path.split('/').skip(3).collect::<Vec<_>>().join("/")
+                    )
+                } else {
+                    real_path.clone()
+                };
+
+                let name = real_path
+                    .split('/')
+                    .next_back()
+                    .unwrap_or(&real_path)
+                    .to_string();
+
+                suggestions.push((
+                    SuggestedProject {
+                        path: real_path,
+                        display_path,
+                        name,
                         task_count,
-                    ));
-                }
+                        has_claude_md,
+                        has_project_indicators: has_indicators,
+                        confidence,
+                    },
+                    task_count,
+                ));
             }
         }
 
-        suggestions.sort_by(|a, b| b.1.cmp(&a.1));
+        // Most project-like first; task count breaks ties
+        suggestions.sort_by(|a, b| b.0.confidence.cmp(&a.0.confidence).then(b.1.cmp(&a.1)));
         Ok(suggestions.into_iter().map(|(s, _)| s).collect())
     }
 
+    /// Scores how project-like a directory is, with the signals found.
+    pub fn classify_directory(&self, path: String) -> DirectoryClassification {
+        classify_directory_with_storage(&self.storage, &path)
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Session State API
     // ─────────────────────────────────────────────────────────────────────────────
@@ -235,6 +606,99 @@ impl HudEngine {
         read_project_status(&project_path)
     }
 
+    /// Collects the non-empty `next_step` of every pinned project, newest first,
+    /// as a cross-project agenda.
+    pub fn next_steps_digest(&self) -> Vec<NextStep> {
+        next_steps_digest_with_storage(&self.storage)
+    }
+
+    /// Reads the tail of the status generation log for debugging.
+    ///
+    /// Empty unless `HudConfig.status_gen_logging` is on and the hook has run.
+    pub fn read_status_gen_log(&self) -> Vec<String> {
+        read_status_gen_log_with_storage(&self.storage)
+    }
+
+    /// Estimates how quickly a session is filling its context window.
+    ///
+    /// Returns None when the transcript has too few usage blocks to tell.
+    pub fn context_burn_rate(
+        &self,
+        session_id: String,
+        project_path: String,
+    ) -> Option<ContextBurnRate> {
+        context_burn_rate_with_storage(&self.storage, &session_id, &project_path)
+    }
+
+    /// How full a session's context window is, so the HUD can warn before an
+    /// auto-compact. None when the session has no transcript usage yet.
+    pub fn get_session_context(&self, session_id: String) -> Option<ContextInfo> {
+        crate::stats::session_context_with_storage(&self.storage, &session_id)
+    }
+
+    /// Average, median, and longest session length for a project, to tell quick
+    /// tweaks from long deep-work sessions.
+    pub fn session_duration_stats(&self, project_path: String) -> SessionDurationStats {
+        crate::stats::session_duration_stats_with_storage(&self.storage, &project_path)
+    }
+
+    /// Measures time-to-first-response across a session's prompts.
+    ///
+    /// Returns None when the transcript has no timed prompt/response pairs.
+    pub fn session_latency(
+        &self,
+        session_id: String,
+        project_path: String,
+    ) -> Option<SessionLatency> {
+        session_latency_with_storage(&self.storage, &session_id, &project_path)
+    }
+
+    /// The last `limit` user/assistant messages of a session as plain text,
+    /// oldest first, for peeking at what it's doing. Empty when not found.
+    pub fn get_session_transcript(&self, session_id: String, limit: u32) -> Vec<TranscriptMessage> {
+        session_transcript_with_storage(&self.storage, &session_id, limit as usize)
+    }
+
+    /// One-glance summary of a session for sharing: first prompt, final reply,
+    /// duration, tokens and cost, edited files, and models.
+    ///
+    /// Returns None when the transcript is missing.
+    pub fn session_receipt(
+        &self,
+        session_id: String,
+        project_path: String,
+    ) -> Option<SessionReceipt> {
+        session_receipt_with_storage(&self.storage, &session_id, &project_path)
+    }
+
+    /// Lists recent permission prompts and denials for a session, oldest first.
+    pub fn permission_history(
+        &self,
+        session_id: String,
+        project_path: String,
+    ) -> Vec<PermissionEntry> {
+        permission_history_with_storage(&self.storage, &session_id, &project_path)
+    }
+
+    /// The `limit` sessions across all projects that raised the most permission
+    /// prompts, with the tools involved.
+    pub fn permission_heavy_sessions(&self, limit: u32) -> Vec<PermissionHeavySession> {
+        permission_heavy_sessions_with_storage(&self.storage, limit)
+    }
+
+    /// Exports a session's state transitions and tool uses as a JSON trace.
+    ///
+    /// See [`crate::trace`] for the schema.
+    pub fn export_session_trace(
+        &self,
+        session_id: String,
+        project_path: String,
+    ) -> Result<String, HudFfiError> {
+        let trace = export_session_trace_with_storage(&self.storage, &session_id, &project_path);
+        serde_json::to_string_pretty(&trace)
+            .map_err(|e| HudFfiError::from(format!("Failed to serialize trace: {}", e)))
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Multi-Agent API
     // ─────────────────────────────────────────────────────────────────────────────
@@ -255,6 +719,14 @@ impl HudEngine {
         self.agent_registry.detect_primary_session(&project_path)
     }
 
+    /// Resolves the Claude session for any path, pinned or not.
+    ///
+    /// Reads the state store and locks directly on each call, bypassing the
+    /// registry cache. Suits one-off lookups such as hovering a suggested project.
+    pub fn resolve_path_state(&self, path: String) -> Option<AgentSession> {
+        ClaudeAdapter::with_storage(self.storage.clone()).detect_session(&path)
+    }
+
     /// Gets all agent sessions across all projects (cached).
     ///
     /// Uses mtime-based caching for efficient repeated calls.
@@ -282,52 +754,97 @@ impl HudEngine {
             .collect()
     }
 
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Agent Changes API
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    /// Lists uncommitted files in a project that agent sessions edited.
+    ///
+    /// Newly-created untracked files are included unless `include_untracked` is false.
+    pub fn uncommitted_agent_changes(
+        &self,
+        project_path: String,
+        include_untracked: bool,
+    ) -> Result<Vec<AgentChange>, HudFfiError> {
+        uncommitted_agent_changes_with_storage(&self.storage, &project_path, include_untracked)
+            .map_err(HudFfiError::from)
+    }
+
+    /// Returns a unified diff for an agent-edited file.
+    ///
+    /// Untracked files render their full content as an addition.
+    pub fn agent_edit_diff(
+        &self,
+        project_path: String,
+        file_path: String,
+        include_untracked: bool,
+    ) -> Result<String, HudFfiError> {
+        agent_edit_diff_with_storage(&self.storage, &project_path, &file_path, include_untracked)
+            .map_err(HudFfiError::from)
+    }
+
+    /// Returns the most-edited files in a project, aggregated across all sessions
+    /// in the file-activity feed.
+    pub fn hot_files(&self, project_path: String, limit: u32) -> Vec<HotFile> {
+        ActivityStore::load(&self.storage.file_activity_file())
+            .hot_files(&project_path, limit as usize)
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Artifacts API
     // ─────────────────────────────────────────────────────────────────────────────
 
     /// Lists all artifacts (skills, commands, agents) from global and plugin sources.
+    ///
+    /// Plugin artifacts switched off with `toggle_plugin_artifact` are left out.
     pub fn list_artifacts(&self) -> Vec<Artifact> {
         let mut artifacts = Vec::new();
+        let mut cache = load_artifact_cache_with_storage(&self.storage);
 
         // Global artifacts
         if let Some(skills_dir) = resolve_symlink(&self.storage.claude_root().join("skills")) {
-            artifacts.extend(collect_artifacts_from_dir(&skills_dir, "skill", "Global"));
+            artifacts.extend(collect_artifacts_from_dir_cached(
+                &skills_dir,
+                "skill",
+                "Global",
+                &mut cache,
+            ));
         }
         if let Some(commands_dir) = resolve_symlink(&self.storage.claude_root().join("commands")) {
-            artifacts.extend(collect_artifacts_from_dir(
+            artifacts.extend(collect_artifacts_from_dir_cached(
                 &commands_dir,
                 "command",
                 "Global",
+                &mut cache,
             ));
         }
         if let Some(agents_dir) = resolve_symlink(&self.storage.claude_root().join("agents")) {
-            artifacts.extend(collect_artifacts_from_dir(&agents_dir, "agent", "Global"));
+            artifacts.extend(collect_artifacts_from_dir_cached(
+                &agents_dir,
+                "agent",
+                "Global",
+                &mut cache,
+            ));
         }
 
         // Plugin artifacts
         if let Ok(plugins) = self.list_plugins() {
+            let disabled = load_hud_config_with_storage(&self.storage).disabled_artifacts;
             for plugin in plugins {
                 if plugin.enabled {
-                    let plugin_path = PathBuf::from(&plugin.path);
-                    artifacts.extend(collect_artifacts_from_dir(
-                        &plugin_path.join("skills"),
-                        "skill",
-                        &plugin.name,
-                    ));
-                    artifacts.extend(collect_artifacts_from_dir(
-                        &plugin_path.join("commands"),
-                        "command",
-                        &plugin.name,
-                    ));
-                    artifacts.extend(collect_artifacts_from_dir(
-                        &plugin_path.join("agents"),
-                        "agent",
+                    let mut plugin_artifacts = collect_artifacts_from_root_cached(
+                        &PathBuf::from(&plugin.path),
                         &plugin.name,
-                    ));
+                        &mut cache,
+                    );
+                    if let Some(disabled) = disabled.get(&plugin.id) {
+                        mark_disabled_artifacts(&mut plugin_artifacts, disabled);
+                    }
+                    artifacts.extend(plugin_artifacts.into_iter().filter(|a| a.enabled));
                 }
             }
         }
+        self.store_artifact_cache(cache);
 
         // Sort by type first, then by name (matching lib.rs behavior)
         artifacts.sort_by(|a, b| {
@@ -341,6 +858,168 @@ impl HudEngine {
         artifacts
     }
 
+    /// Lists one plugin's artifacts, including ones switched off, with `enabled`
+    /// set so the UI can render a toggle next to each.
+    pub fn list_plugin_artifacts(&self, plugin_id: String) -> Vec<Artifact> {
+        let Some(plugin) = self
+            .list_plugins()
+            .unwrap_or_default()
+            .into_iter()
+            .find(|p| p.id == plugin_id)
+        else {
+            return Vec::new();
+        };
+        let mut cache = load_artifact_cache_with_storage(&self.storage);
+        let mut artifacts = collect_artifacts_from_root_cached(
+            &PathBuf::from(&plugin.path),
+            &plugin.name,
+            &mut cache,
+        );
+        self.store_artifact_cache(cache);
+        if let Some(disabled) = load_hud_config_with_storage(&self.storage)
+            .disabled_artifacts
+            .get(&plugin_id)
+        {
+            mark_disabled_artifacts(&mut artifacts, disabled);
+        }
+        artifacts
+    }
+
+    /// Switches a single skill, command, or agent of a plugin on or off.
+    ///
+    /// `artifact_type` is "skill", "command", or "agent".
+    pub fn toggle_plugin_artifact(
+        &self,
+        plugin_id: String,
+        artifact_type: String,
+        artifact_name: String,
+        enabled: bool,
+    ) -> Result<(), HudFfiError> {
+        toggle_plugin_artifact_with_storage(
+            &self.storage,
+            &plugin_id,
+            &artifact_type,
+            &artifact_name,
+            enabled,
+        )
+        .map_err(HudFfiError::from)
+    }
+
+    /// Lists every artifact a project can see and whether it is actually active there.
+    ///
+    /// Combines global, plugin, and project (`<project>/.claude/`) artifacts, applies
+    /// the project's `enabledPlugins` overrides, and marks same-named artifacts
+    /// shadowed by a higher-precedence source (project, then plugin, then global).
+    pub fn resolve_effective_artifacts(&self, project_path: String) -> Vec<EffectiveArtifact> {
+        let project = PathBuf::from(&project_path);
+        let overrides = project_plugin_overrides(&project);
+        let mut cache = load_artifact_cache_with_storage(&self.storage);
+
+        let mut candidates: Vec<(Artifact, bool)> = Vec::new();
+        for (dir, artifact_type) in [
+            ("skills", "skill"),
+            ("commands", "command"),
+            ("agents", "agent"),
+        ] {
+            if let Some(resolved) = resolve_symlink(&self.storage.claude_root().join(dir)) {
+                candidates.extend(
+                    collect_artifacts_from_dir_cached(
+                        &resolved,
+                        artifact_type,
+                        "Global",
+                        &mut cache,
+                    )
+                    .into_iter()
+                    .map(|a| (a, true)),
+                );
+            }
+        }
+        let disabled = load_hud_config_with_storage(&self.storage).disabled_artifacts;
+        for plugin in self.list_plugins().unwrap_or_default() {
+            let enabled = overrides.get(&plugin.id).copied().unwrap_or(plugin.enabled);
+            let mut plugin_artifacts = collect_artifacts_from_root_cached(
+                &PathBuf::from(&plugin.path),
+                &plugin.name,
+                &mut cache,
+            );
+            if let Some(disabled) = disabled.get(&plugin.id) {
+                mark_disabled_artifacts(&mut plugin_artifacts, disabled);
+            }
+            candidates.extend(plugin_artifacts.into_iter().map(|a| {
+                let active = enabled && a.enabled;
+                (a, active)
+            }));
+        }
+        candidates.extend(
+            collect_artifacts_from_root_cached(&project.join(".claude"), "Project", &mut cache)
+                .into_iter()
+                .map(|a| (a, true)),
+        );
+        self.store_artifact_cache(cache);
+
+        resolve_artifact_precedence(candidates)
+    }
+
+    /// Resolves which plugin (or "Global"/"Project") provides an artifact.
+    ///
+    /// Paths are compared after canonicalization so artifacts reached through
+    /// symlinked global dirs (e.g. `~/.claude/skills -> ~/dotfiles/skills`) still
+    /// resolve. Returns None when the path isn't under any known artifact root.
+    ///
+    /// The global Claude dir is checked before project dirs: with `~` pinned as a
+    /// project, `~/.claude` looks like a project's `.claude` folder.
+    pub fn artifact_origin(&self, artifact_path: String) -> Option<ArtifactOrigin> {
+        let path = PathBuf::from(&artifact_path);
+        let path = fs::canonicalize(&path).unwrap_or(path);
+
+        for plugin in self.list_plugins().unwrap_or_default() {
+            let plugin_path = PathBuf::from(&plugin.path);
+            let plugin_path = fs::canonicalize(&plugin_path).unwrap_or(plugin_path);
+            if path.starts_with(&plugin_path) {
+                return Some(ArtifactOrigin {
+                    source: plugin.name,
+                    plugin_id: Some(plugin.id),
+                });
+            }
+        }
+
+        const ARTIFACT_DIRS: [&str; 3] = ["skills", "commands", "agents"];
+        let under = |dir: &Path| {
+            path.starts_with(dir)
+                || resolve_symlink(&dir.to_path_buf()).is_some_and(|r| path.starts_with(r))
+        };
+
+        let claude_root = self.storage.claude_root();
+        let is_global = ARTIFACT_DIRS
+            .iter()
+            .any(|dir| under(&claude_root.join(dir)));
+        if is_global || under(claude_root) {
+            // Anything else in the global Claude dir isn't a project artifact
+            return is_global.then(|| ArtifactOrigin {
+                source: "Global".to_string(),
+                plugin_id: None,
+            });
+        }
+
+        // Project-level artifacts live in `<project>/.claude/{skills,commands,agents}`
+        let is_project = path.ancestors().any(|dir| {
+            dir.file_name()
+                .is_some_and(|name| ARTIFACT_DIRS.iter().any(|d| name == *d))
+                && dir
+                    .parent()
+                    .and_then(|parent| parent.file_name())
+                    .is_some_and(|name| name == ".claude")
+        });
+        if is_project {
+            return Some(ArtifactOrigin {
+                source: "Project".to_string(),
+                plugin_id: None,
+            });
+        }
+
+        None
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Plugins API
     // ─────────────────────────────────────────────────────────────────────────────
@@ -434,14 +1113,38 @@ impl HudEngine {
         Ok(plugins)
     }
 
+    // ─────────────────────────────────────────────────────────────────────────────
+    // MCP Servers API
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    /// Lists MCP servers from global settings and, if given, the project's `.mcp.json`.
+    pub fn load_mcp_servers(&self, project_path: Option<String>) -> Vec<McpServer> {
+        load_mcp_servers_with_storage(&self.storage, project_path.as_deref())
+    }
+
+    /// Returns the MCP config file to edit for a scope.
+    ///
+    /// Creates an empty project `.mcp.json` if needed. The client opens the returned path.
+    pub fn open_mcp_config(
+        &self,
+        scope: McpScope,
+        project_path: Option<String>,
+    ) -> Result<String, HudFfiError> {
+        open_mcp_config_with_storage(&self.storage, scope, project_path.as_deref())
+            .map_err(HudFfiError::from)
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Dashboard API
     // ─────────────────────────────────────────────────────────────────────────────
 
     /// Loads all dashboard data in one call.
+    ///
+    /// Succeeds with partial data when plugins, settings, or projects fail to load;
+    /// each non-fatal failure is reported in `DashboardData.warnings`.
     pub fn load_dashboard(&self) -> Result<DashboardData, HudFfiError> {
         let settings_path = self.storage.claude_root().join("settings.json");
-        let instructions_path = self.storage.claude_root().join("CLAUDE.md");
+        let instructions_path = global_instructions_path(&self.storage);
 
         let skills_dir = resolve_symlink(&self.storage.claude_root().join("skills"));
         let commands_dir = resolve_symlink(&self.storage.claude_root().join("commands"));
@@ -474,16 +1177,63 @@ impl HudEngine {
                 .unwrap_or(0),
         };
 
-        let plugins = self.list_plugins().unwrap_or_default();
-        let projects = self.list_projects().unwrap_or_default();
+        // Partial failures degrade to empty sections; warnings tell the UI why.
+        let mut warnings = Vec::new();
+
+        if let Err(e) = self.prune_sessions() {
+            tracing::warn!(error = %e, "Failed to prune stale sessions");
+        }
+
+        if settings_path.exists() {
+            let parsed = fs::read_to_string(&settings_path)
+                .map_err(|e| e.to_string())
+                .and_then(|c| {
+                    serde_json::from_str::<serde_json::Value>(&c).map_err(|e| e.to_string())
+                });
+            if let Err(e) = parsed {
+                warnings.push(format!("Couldn't read settings.json: {}", e));
+            }
+        }
+
+        let plugins = self.list_plugins().unwrap_or_else(|e| {
+            warnings.push(format!("Couldn't load plugins: {}", e));
+            Vec::new()
+        });
+        let projects = self.list_projects().unwrap_or_else(|e| {
+            warnings.push(format!("Couldn't load projects: {}", e));
+            Vec::new()
+        });
+        warnings.extend(
+            projects
+                .iter()
+                .filter(|p| p.is_missing)
+                .map(|p| format!("Project folder not found: {}", p.path)),
+        );
 
         Ok(DashboardData {
             global,
             plugins,
             projects,
+            warnings,
         })
     }
 
+    /// Writes everything `load_dashboard` returns, plus each project's session
+    /// state, to `path` as pretty-printed JSON. Returns the number of projects exported.
+    pub fn export_dashboard_json(&self, path: String) -> Result<u32, HudFfiError> {
+        let dashboard = self.load_dashboard()?;
+        let paths: Vec<String> = dashboard.projects.iter().map(|p| p.path.clone()).collect();
+        let session_states = get_all_session_states_with_storage(&self.storage, &paths);
+        write_dashboard_json(&path, &DashboardExport::new(dashboard, session_states))
+            .map_err(HudFfiError::from)
+    }
+
+    /// Writes per-project token and cost stats for the pinned projects to `path`
+    /// as CSV, one row per project, from the stats cache. Returns the row count.
+    pub fn export_stats_csv(&self, path: String) -> Result<u32, HudFfiError> {
+        export_stats_csv_with_storage(&self.storage, &path).map_err(HudFfiError::from)
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Idea Capture API
     // ─────────────────────────────────────────────────────────────────────────────
@@ -718,11 +1468,27 @@ impl HudEngine {
     /// - Hook binary is missing or broken
     /// - Hooks are disabled by policy (disableAllHooks or allowManagedHooksOnly)
     /// - File system operations fail
+    ///
+    /// Deprecated: writes settings.json without showing what changes first, which
+    /// can clobber existing Stop hooks. Use `install_hooks_with_confirm`.
     pub fn install_hooks(&self) -> Result<InstallResult, HudFfiError> {
         let checker = SetupChecker::new(self.storage.clone());
         checker.install_hooks()
     }
 
+    /// Two-phase hook install.
+    ///
+    /// With `confirm` false, returns the current and proposed `hooks.Stop` from
+    /// ~/.claude/settings.json and writes nothing. Call again with `confirm` true
+    /// to perform the install; the outcome then also carries the install result.
+    pub fn install_hooks_with_confirm(
+        &self,
+        confirm: bool,
+    ) -> Result<HookInstallOutcome, HudFfiError> {
+        let checker = SetupChecker::new(self.storage.clone());
+        checker.install_hooks_with_confirm(confirm)
+    }
+
     /// Returns the current hook status without full setup check.
     ///
     /// Useful for quick hook status checks in the UI.
@@ -731,7 +1497,93 @@ impl HudEngine {
         checker.check_setup_status().hooks
     }
 
-    // ─────────────────────────────────────────────────────────────────────────────
+    /// Lists hook commands in ~/.claude/settings.json whose script or binary is
+    /// missing or not executable, including hooks added by other tools.
+    pub fn audit_hooks(&self) -> Vec<BrokenHook> {
+        let checker = SetupChecker::new(self.storage.clone());
+        checker.audit_hooks()
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // State Feed API
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    /// Starts a feed of session state changes across all projects and returns
+    /// its subscription id.
+    ///
+    /// The feed begins at the current state: the first poll reports only what
+    /// changed after subscribing. Call [`Self::unsubscribe_state_feed`] when done.
+    pub fn subscribe_state_feed(&self) -> u64 {
+        let id = self.next_feed_id.fetch_add(1, Ordering::Relaxed);
+        let snapshot = StateSnapshot::load(&self.storage);
+        self.lock_state_feeds().insert(id, snapshot);
+        id
+    }
+
+    /// Returns the state changes since this subscription's previous poll, one
+    /// event per session that appeared, changed state, or ended.
+    pub fn poll_state_feed(
+        &self,
+        subscription_id: u64,
+    ) -> Result<Vec<StateFeedEvent>, HudFfiError> {
+        if self.watchers_paused() {
+            return if self.lock_state_feeds().contains_key(&subscription_id) {
+                Ok(vec![])
+            } else {
+                Err(format!("Unknown state feed subscription {}", subscription_id).into())
+            };
+        }
+        let next = StateSnapshot::load(&self.storage);
+        let mut feeds = self.lock_state_feeds();
+        let previous = feeds
+            .get_mut(&subscription_id)
+            .ok_or_else(|| format!("Unknown state feed subscription {}", subscription_id))?;
+        let events = previous.diff(&next);
+        *previous = next;
+        Ok(events)
+    }
+
+    /// Ends a state feed subscription. Returns false if it was already gone.
+    pub fn unsubscribe_state_feed(&self, subscription_id: u64) -> bool {
+        self.lock_state_feeds().remove(&subscription_id).is_some()
+    }
+
+    /// Returns the sessions that started waiting on the user (usually for a
+    /// permission prompt) since this subscription's previous poll.
+    ///
+    /// This is the `session-waiting` event: a Waiting-only view of
+    /// [`Self::poll_state_feed`] for the same subscription. Clients call it from
+    /// their refresh loop and notify once per event.
+    pub fn poll_session_waiting(
+        &self,
+        subscription_id: u64,
+    ) -> Result<Vec<SessionWaitingEvent>, HudFfiError> {
+        Ok(waiting_events(&self.poll_state_feed(subscription_id)?))
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Backup API
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    /// Archives `~/.capacitor` (minus lock directories) to a `.tar.gz` at `dest`,
+    /// for bug reports and moving to a new machine. Returns the file count.
+    pub fn backup_capacitor_state(&self, dest: String) -> Result<u32, HudFfiError> {
+        crate::backup::backup_capacitor_state_with_storage(&self.storage, &dest)
+            .map_err(HudFfiError::from)
+    }
+
+    /// Replaces `~/.capacitor` with the contents of a backup and returns the
+    /// path of the safety backup taken of the previous state.
+    ///
+    /// Watchers are paused for the duration so clients don't read half-restored files.
+    pub fn restore_capacitor_state(&self, src: String) -> Result<String, HudFfiError> {
+        let was_paused = self.watchers_paused.swap(true, Ordering::Relaxed);
+        let result = crate::backup::restore_capacitor_state_with_storage(&self.storage, &src);
+        self.watchers_paused.store(was_paused, Ordering::Relaxed);
+        result.map_err(HudFfiError::from)
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
     // Cleanup API
     // ─────────────────────────────────────────────────────────────────────────────
 
@@ -746,9 +1598,111 @@ impl HudEngine {
         crate::state::run_startup_cleanup(
             &self.storage.sessions_dir(),
             &self.storage.sessions_file(),
+            ResolverConfig::load(&self.storage).stale_threshold_secs,
         )
     }
 
+    /// Prunes stale `sessions.json` records whose session holds no live lock,
+    /// returning how many were removed. Also runs on each dashboard load.
+    pub fn prune_sessions(&self) -> Result<u32, HudFfiError> {
+        let mut store = StateStore::load(&self.storage.sessions_file())?;
+        let pruned = store.prune_stale(
+            &self.storage.sessions_dir(),
+            ResolverConfig::load(&self.storage).stale_threshold_secs,
+        );
+        if pruned > 0 {
+            store.save()?;
+        }
+        Ok(pruned as u32)
+    }
+
+    /// Drops session records that are stale and hold no live lock, for installs
+    /// where ended sessions were never cleaned up (e.g. SessionEnd never fired).
+    ///
+    /// Fresh and locked sessions are kept. Returns how many records were removed.
+    pub fn compact_state_store(&self) -> Result<u32, HudFfiError> {
+        crate::state::compact_state_store(
+            &self.storage.sessions_dir(),
+            &self.storage.sessions_file(),
+            ResolverConfig::load(&self.storage).stale_threshold_secs,
+        )
+        .map_err(HudFfiError::from)
+    }
+
+    /// Removes lock directories left by crashed sessions (dead PIDs) and returns
+    /// how many were removed. Also runs as part of `run_startup_cleanup`.
+    pub fn clean_dead_locks(&self) -> u32 {
+        crate::state::clean_dead_locks(&self.storage.sessions_dir()) as u32
+    }
+
+    /// Removes lock directories whose process has exited.
+    ///
+    /// With `dry_run`, returns what would be removed without deleting anything.
+    pub fn reap_stale_locks(&self, dry_run: bool) -> MaintenanceReport {
+        crate::state::reap_stale_locks(&self.storage.sessions_dir(), dry_run)
+    }
+
+    /// Removes orphaned legacy (path-hash) lock directories.
+    ///
+    /// With `dry_run`, returns what would be removed without deleting anything.
+    pub fn delete_orphaned_dirs(&self, dry_run: bool) -> MaintenanceReport {
+        crate::state::delete_orphaned_dirs(&self.storage.sessions_dir(), dry_run)
+    }
+
+    /// Removes expired session-end tombstones.
+    ///
+    /// With `dry_run`, returns what would be removed without deleting anything.
+    pub fn clear_tombstones(&self, dry_run: bool) -> MaintenanceReport {
+        crate::state::clear_tombstones(&self.storage.root().join("ended-sessions"), dry_run)
+    }
+
+    /// Removes old entries from the file activity feed.
+    ///
+    /// With `dry_run`, returns what would be removed without modifying the feed.
+    pub fn prune_activity(&self, dry_run: bool) -> MaintenanceReport {
+        crate::state::prune_activity(&self.storage.file_activity_file(), dry_run)
+    }
+
+    /// Lists session records whose working directory has been deleted and that
+    /// hold no live lock. These can never become active again.
+    pub fn find_zombie_sessions(&self) -> Result<Vec<ZombieSession>, HudFfiError> {
+        crate::state::find_zombie_sessions(
+            &self.storage.sessions_dir(),
+            &self.storage.sessions_file(),
+        )
+        .map_err(HudFfiError::from)
+    }
+
+    /// Removes the records listed by `find_zombie_sessions`, re-checking each first.
+    ///
+    /// With `dry_run`, returns what would be removed without modifying the store.
+    pub fn remove_zombie_sessions(&self, dry_run: bool) -> MaintenanceReport {
+        crate::state::remove_zombie_sessions(
+            &self.storage.sessions_dir(),
+            &self.storage.sessions_file(),
+            dry_run,
+        )
+    }
+
+    /// Lists running sessions whose working directory has been deleted.
+    pub fn orphaned_live_sessions(&self) -> Vec<OrphanedLiveSession> {
+        orphaned_live_sessions_with_storage(&self.storage)
+    }
+
+    /// Releases the locks of a session listed by `orphaned_live_sessions`.
+    ///
+    /// Refuses sessions whose folder still exists. Returns the number of locks released.
+    pub fn force_release_lock(&self, session_id: String) -> Result<u32, HudFfiError> {
+        force_release_lock_with_storage(&self.storage, &session_id).map_err(HudFfiError::from)
+    }
+
+    /// Salvages valid project entries from a partially-corrupt stats cache.
+    ///
+    /// Returns how many entries were kept vs dropped.
+    pub fn repair_stats_cache(&self) -> Result<StatsCacheRepair, HudFfiError> {
+        repair_stats_cache_with_storage(&self.storage).map_err(HudFfiError::from)
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Hook Health API
     // ─────────────────────────────────────────────────────────────────────────────
@@ -816,6 +1770,12 @@ impl HudEngine {
         }
     }
 
+    /// Min/median/p95 run times of recent hook invocations, per subcommand,
+    /// from the hook's debug log. Empty until the hook has logged timed runs.
+    pub fn hook_latency_stats(&self) -> Vec<HookLatencyStats> {
+        crate::hook_latency::hook_latency_stats_with_storage(&self.storage)
+    }
+
     /// Returns a unified diagnostic report for hook status.
     ///
     /// This combines setup status (is everything installed?) with health status
@@ -908,6 +1868,33 @@ impl HudEngine {
             symlink_path: symlink_path.to_string_lossy().to_string(),
             symlink_target,
             last_heartbeat_age_secs: health.last_heartbeat_age_secs,
+            clock_skew_warning: self.clock_skew_check().warning,
+        }
+    }
+
+    /// Compares the newest session record timestamp to now and warns when the
+    /// difference suggests a skewed clock (e.g. a VM with the wrong time).
+    pub fn clock_skew_check(&self) -> ClockSkewReport {
+        clock_skew_check_with_storage(&self.storage)
+    }
+
+    /// Lists session ids recorded under unrelated project paths, a sign that
+    /// separate Claude profiles are writing colliding ids into shared state.
+    pub fn detect_session_id_collisions(&self) -> Vec<SessionIdCollision> {
+        detect_session_id_collisions_with_storage(&self.storage)
+    }
+
+    /// Assembles the app and session context for feedback reports and the debug panel.
+    pub fn telemetry_context(&self) -> TelemetryContext {
+        let config = load_hud_config_with_storage(&self.storage);
+        TelemetryContext {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            hooks_healthy: matches!(
+                self.check_hook_health().status,
+                crate::types::HookHealthStatus::Healthy
+            ),
+            pinned_project_count: config.pinned_projects.len() as u32,
+            session_summary: session_summary_with_storage(&self.storage),
         }
     }
 
@@ -987,6 +1974,132 @@ impl HudEngine {
     ) -> crate::activation::ActivationDecision {
         crate::activation::resolve_activation(&project_path, shell_state.as_ref(), &tmux_context)
     }
+
+    /// The terminal app and shell cwd for a tty, or None if no tracked shell uses it.
+    ///
+    /// # Arguments
+    /// * `shell_state` - Current contents of shell-cwd.json (may be None if file missing)
+    pub fn terminal_for_tty(
+        &self,
+        tty: String,
+        shell_state: Option<crate::activation::ShellCwdStateFfi>,
+    ) -> Option<crate::activation::TtyTerminal> {
+        crate::activation::terminal_for_tty(&tty, shell_state.as_ref())
+    }
+
+    /// Groups live sessions by the terminal or IDE hosting them.
+    ///
+    /// # Arguments
+    /// * `shell_state` - Current contents of shell-cwd.json (may be None if file missing)
+    pub fn sessions_by_host_app(
+        &self,
+        shell_state: Option<crate::activation::ShellCwdStateFfi>,
+    ) -> Vec<HostAppSessions> {
+        sessions_by_host_app_with_storage(&self.storage, shell_state.as_ref())
+    }
+
+    /// Returns the most recently updated Working or Compacting session across all
+    /// projects, for the global "jump to active work" shortcut.
+    ///
+    /// # Arguments
+    /// * `shell_state` - Current contents of shell-cwd.json (may be None if file missing)
+    pub fn active_working_session(
+        &self,
+        shell_state: Option<crate::activation::ShellCwdStateFfi>,
+    ) -> Option<ActiveWorkingSession> {
+        active_working_session_with_storage(&self.storage, shell_state.as_ref())
+    }
+
+    /// Lists live sessions that are compacting their context right now, so the
+    /// HUD can warn against closing them. Empty when none are.
+    pub fn compacting_sessions(&self) -> Vec<CompactingSession> {
+        compacting_sessions_with_storage(&self.storage)
+    }
+
+    /// Projects whose live session is running subagents, with the count, so the
+    /// HUD can show parallel agent work. Empty when none are.
+    pub fn projects_with_subagents(&self) -> Vec<ProjectSubagents> {
+        projects_with_subagents_with_storage(&self.storage)
+    }
+
+    /// Live sessions across all projects counted by state, for a dashboard chart.
+    pub fn state_distribution(&self) -> StateDistribution {
+        state_distribution_with_storage(&self.storage)
+    }
+
+    /// Returns the IDE command that reopens a session's task: the project plus
+    /// the files the session most recently edited (capped). Swift runs it.
+    pub fn open_task_context(
+        &self,
+        session_id: String,
+        project_path: String,
+        editor: crate::types::ParentApp,
+    ) -> Result<crate::editor::EditorLaunch, HudFfiError> {
+        crate::editor::open_task_context_with_storage(
+            &self.storage,
+            &session_id,
+            &project_path,
+            editor,
+        )
+        .map_err(HudFfiError::from)
+    }
+
+    /// Lists the known terminal apps that are installed on this system.
+    ///
+    /// Drives the terminal picker in settings.
+    pub fn detect_terminals(&self) -> Vec<DetectedTerminal> {
+        crate::terminals::detect_terminals()
+    }
+
+    /// The shell command a new terminal runs to start (or resume) Claude in a
+    /// project, honoring the project's custom launch command if configured.
+    pub fn launch_command(
+        &self,
+        project_path: String,
+        claude_path: String,
+        resume_session_id: Option<String>,
+    ) -> String {
+        let config = load_hud_config_with_storage(&self.storage);
+        crate::terminals::launch_command(
+            &config,
+            &project_path,
+            &claude_path,
+            resume_session_id.as_deref(),
+        )
+    }
+
+    /// How to open a terminal at a project, running its launch command when
+    /// `run_claude` is set, with `--resume <id>` when `resume_session_id` is given.
+    ///
+    /// On macOS this is the configured `HudConfig.terminal_app`, each with its own
+    /// strategy (`open -a` with arguments, or AppleScript that types the command).
+    /// On Linux it is `$TERMINAL` or the first installed common emulator, and on
+    /// Windows, Windows Terminal or `cmd`. The app spawns the returned process.
+    ///
+    /// Returns an error listing the probed terminals when none is installed.
+    pub fn terminal_launch(
+        &self,
+        project_path: String,
+        run_claude: bool,
+        resume_session_id: Option<String>,
+    ) -> Result<TerminalLaunch, HudFfiError> {
+        let config = load_hud_config_with_storage(&self.storage);
+        let resume_session_id = resume_session_id.as_deref();
+        let command = run_claude.then(|| {
+            if cfg!(windows) {
+                crate::terminals::windows_launch_command(&config, &project_path, resume_session_id)
+            } else {
+                crate::terminals::launch_command(
+                    &config,
+                    &project_path,
+                    "claude",
+                    resume_session_id,
+                )
+            }
+        });
+        crate::terminals::launch_terminal(&config.terminal_app, &project_path, command.as_deref())
+            .map_err(HudFfiError::from)
+    }
 }
 
 impl HudEngine {
@@ -1118,4 +2231,494 @@ mod tests {
             "test_state_file_io should clean up its test file"
         );
     }
+
+    #[test]
+    fn paused_watchers_skip_polls_until_resumed() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        fs::create_dir_all(storage.root()).unwrap();
+        let engine = HudEngine::with_storage(storage.clone()).unwrap();
+        let mut store = crate::state::StateStore::new(&storage.sessions_file());
+        store.update("s1", crate::types::SessionState::Working, "/p");
+        store.save().unwrap();
+
+        let id = engine.subscribe_state_feed();
+        let waiting_id = engine.subscribe_state_feed();
+        assert!(engine.check_global_instructions_changed().is_none());
+
+        engine.set_watchers_paused(true);
+        assert!(engine.watchers_paused());
+        store.update("s1", crate::types::SessionState::Waiting, "/p");
+        store.save().unwrap();
+        fs::create_dir_all(storage.claude_root()).unwrap();
+        fs::write(global_instructions_path(&storage), "# Rules").unwrap();
+
+        assert!(engine.poll_state_feed(id).unwrap().is_empty());
+        assert!(engine.poll_session_waiting(waiting_id).unwrap().is_empty());
+        assert!(engine.check_global_instructions_changed().is_none());
+        assert!(engine.check_budget_threshold().unwrap().is_none());
+        assert!(engine
+            .poll_stats_changes(vec!["/p".into()])
+            .unwrap()
+            .is_empty());
+        assert!(engine.poll_state_feed(waiting_id + 1).is_err());
+
+        // Changes made while paused are reported on the first poll after resuming
+        engine.set_watchers_paused(false);
+        assert_eq!(engine.poll_state_feed(id).unwrap().len(), 1);
+        assert_eq!(engine.poll_session_waiting(waiting_id).unwrap().len(), 1);
+        assert!(engine.check_global_instructions_changed().is_some());
+    }
+
+    #[test]
+    fn state_feed_reports_transition_once() {
+        let temp = TempDir::new().unwrap();
+        let storage = StorageConfig::with_root(temp.path().to_path_buf());
+        let engine = HudEngine::with_storage(storage.clone()).unwrap();
+        let mut store = crate::state::StateStore::new(&storage.sessions_file());
+        store.update("s1", crate::types::SessionState::Working, "/p");
+        store.save().unwrap();
+
+        let id = engine.subscribe_state_feed();
+        assert!(engine.poll_state_feed(id).unwrap().is_empty());
+
+        store.update("s1", crate::types::SessionState::Ready, "/p");
+        store.save().unwrap();
+        let events = engine.poll_state_feed(id).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].from, Some(crate::types::SessionState::Working));
+        assert_eq!(events[0].to, Some(crate::types::SessionState::Ready));
+        assert!(engine.poll_state_feed(id).unwrap().is_empty());
+
+        assert!(engine.unsubscribe_state_feed(id));
+        assert!(engine.poll_state_feed(id).is_err());
+        assert!(!engine.unsubscribe_state_feed(id));
+    }
+
+    #[test]
+    fn session_waiting_is_reported_once() {
+        let temp = TempDir::new().unwrap();
+        let storage = StorageConfig::with_root(temp.path().to_path_buf());
+        let engine = HudEngine::with_storage(storage.clone()).unwrap();
+        let mut store = crate::state::StateStore::new(&storage.sessions_file());
+        store.update("s1", crate::types::SessionState::Working, "/p");
+        store.save().unwrap();
+
+        let id = engine.subscribe_state_feed();
+        store.update("s1", crate::types::SessionState::Waiting, "/p");
+        store.save().unwrap();
+        let waiting = engine.poll_session_waiting(id).unwrap();
+        assert_eq!(waiting.len(), 1);
+        assert_eq!(waiting[0].session_id, "s1");
+        assert_eq!(waiting[0].cwd, "/p");
+        assert!(engine.poll_session_waiting(id).unwrap().is_empty());
+
+        store.update("s1", crate::types::SessionState::Working, "/p");
+        store.save().unwrap();
+        assert!(engine.poll_session_waiting(id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn resolve_path_state_works_for_unpinned_path_with_lock() {
+        use crate::state::lock::tests_helper::create_lock;
+
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let project = "/tmp/hud-core-unpinned-preview";
+        fs::create_dir_all(storage.sessions_dir()).unwrap();
+        let mut store = crate::state::StateStore::new(&storage.sessions_file());
+        store.update("session-1", crate::types::SessionState::Working, project);
+        store.save().unwrap();
+        create_lock(&storage.sessions_dir(), std::process::id(), project);
+
+        let engine = HudEngine::with_storage(storage).unwrap();
+        assert!(engine.get_config().pinned_projects.is_empty());
+
+        let session = engine.resolve_path_state(project.to_string()).unwrap();
+        assert_eq!(session.state, crate::agents::AgentState::Working);
+        assert_eq!(session.session_id.as_deref(), Some("session-1"));
+        assert!(engine
+            .resolve_path_state("/tmp/hud-core-elsewhere".to_string())
+            .is_none());
+    }
+
+    fn write_plugin_registry(claude_root: &std::path::Path, id: &str, install_path: &str) {
+        let plugins_dir = claude_root.join("plugins");
+        fs::create_dir_all(&plugins_dir).unwrap();
+        fs::write(
+            plugins_dir.join("installed_plugins.json"),
+            serde_json::json!({
+                "plugins": { id: [{ "installPath": install_path }] }
+            })
+            .to_string(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn artifact_origin_resolves_plugin_id() {
+        let temp = TempDir::new().unwrap();
+        let claude_root = temp.path().join("claude");
+        let plugin_dir = claude_root.join("plugins/cache/acme/tools/1.0.0");
+        let skill_dir = plugin_dir.join("skills/deploy");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(skill_dir.join("SKILL.md"), "# Deploy").unwrap();
+        write_plugin_registry(&claude_root, "tools@acme", &plugin_dir.to_string_lossy());
+
+        let storage = StorageConfig::with_roots(temp.path().join("capacitor"), claude_root);
+        let engine = HudEngine::with_storage(storage).unwrap();
+
+        let origin = engine
+            .artifact_origin(skill_dir.join("SKILL.md").to_string_lossy().to_string())
+            .unwrap();
+        assert_eq!(origin.plugin_id.as_deref(), Some("tools@acme"));
+        assert_eq!(origin.source, "tools@acme");
+    }
+
+    #[test]
+    fn artifact_origin_follows_symlinked_global_dir() {
+        let temp = TempDir::new().unwrap();
+        let claude_root = temp.path().join("claude");
+        let dotfiles_skills = temp.path().join("dotfiles/skills");
+        fs::create_dir_all(dotfiles_skills.join("review")).unwrap();
+        fs::write(dotfiles_skills.join("review/SKILL.md"), "# Review").unwrap();
+        fs::create_dir_all(&claude_root).unwrap();
+        std::os::unix::fs::symlink(&dotfiles_skills, claude_root.join("skills")).unwrap();
+
+        let storage = StorageConfig::with_roots(temp.path().join("capacitor"), claude_root);
+        let engine = HudEngine::with_storage(storage).unwrap();
+
+        let origin = engine
+            .artifact_origin(
+                dotfiles_skills
+                    .join("review/SKILL.md")
+                    .to_string_lossy()
+                    .to_string(),
+            )
+            .unwrap();
+        assert_eq!(origin.source, "Global");
+        assert_eq!(origin.plugin_id, None);
+    }
+
+    #[test]
+    fn artifact_origin_detects_project_and_unknown_paths() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let engine = HudEngine::with_storage(storage).unwrap();
+
+        let project_cmd = temp.path().join("repo/.claude/commands/ship.md");
+        let origin = engine
+            .artifact_origin(project_cmd.to_string_lossy().to_string())
+            .unwrap();
+        assert_eq!(origin.source, "Project");
+
+        let elsewhere = temp.path().join("notes/readme.md");
+        assert!(engine
+            .artifact_origin(elsewhere.to_string_lossy().to_string())
+            .is_none());
+    }
+
+    #[test]
+    fn artifact_origin_never_labels_global_claude_dir_as_project() {
+        // With the home directory pinned, `~/.claude` looks like a project's `.claude`
+        let temp = TempDir::new().unwrap();
+        let claude_root = temp.path().join("home/.claude");
+        let storage = StorageConfig::with_roots(temp.path().join("capacitor"), claude_root.clone());
+        let engine = HudEngine::with_storage(storage).unwrap();
+
+        let global_cmd = claude_root.join("commands/ship.md");
+        let origin = engine
+            .artifact_origin(global_cmd.to_string_lossy().to_string())
+            .unwrap();
+        assert_eq!(origin.source, "Global");
+
+        let unregistered = claude_root.join("plugins/cache/acme/tools/1.0.0/skills/a/SKILL.md");
+        assert!(engine
+            .artifact_origin(unregistered.to_string_lossy().to_string())
+            .is_none());
+    }
+
+    #[test]
+    fn effective_artifacts_plugin_skill_shadows_global() {
+        let temp = TempDir::new().unwrap();
+        let claude_root = temp.path().join("claude");
+        fs::create_dir_all(claude_root.join("skills/deploy")).unwrap();
+        fs::write(claude_root.join("skills/deploy/SKILL.md"), "# Deploy").unwrap();
+        let plugin_dir = claude_root.join("plugins/cache/acme/tools/1.0.0");
+        fs::create_dir_all(plugin_dir.join("skills/deploy")).unwrap();
+        fs::write(plugin_dir.join("skills/deploy/SKILL.md"), "# Deploy").unwrap();
+        fs::create_dir_all(plugin_dir.join("commands")).unwrap();
+        fs::write(plugin_dir.join("commands/ship.md"), "# Ship").unwrap();
+        write_plugin_registry(&claude_root, "tools@acme", &plugin_dir.to_string_lossy());
+
+        let storage = StorageConfig::with_roots(temp.path().join("capacitor"), claude_root);
+        let engine = HudEngine::with_storage(storage).unwrap();
+        let project = temp.path().join("repo");
+
+        let resolved = engine.resolve_effective_artifacts(project.to_string_lossy().to_string());
+        let deploy: Vec<_> = resolved
+            .iter()
+            .filter(|e| e.artifact.name == "deploy")
+            .map(|e| {
+                (
+                    e.artifact.source.as_str(),
+                    e.active,
+                    e.shadowed_by.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            deploy,
+            vec![
+                ("tools@acme", true, None),
+                ("Global", false, Some("tools@acme")),
+            ]
+        );
+
+        fs::create_dir_all(project.join(".claude")).unwrap();
+        fs::write(
+            project.join(".claude/settings.local.json"),
+            r#"{"enabledPlugins":{"tools@acme":false}}"#,
+        )
+        .unwrap();
+        let resolved = engine.resolve_effective_artifacts(project.to_string_lossy().to_string());
+        let ship = resolved.iter().find(|e| e.artifact.name == "ship").unwrap();
+        assert!(ship.disabled && !ship.active);
+        let global_deploy = resolved
+            .iter()
+            .find(|e| e.artifact.name == "deploy" && e.artifact.source == "Global")
+            .unwrap();
+        assert!(global_deploy.active);
+    }
+
+    #[test]
+    fn toggled_off_plugin_artifact_is_hidden_from_list() {
+        let temp = TempDir::new().unwrap();
+        let claude_root = temp.path().join("claude");
+        let plugin_dir = claude_root.join("plugins/cache/acme/tools/1.0.0");
+        fs::create_dir_all(plugin_dir.join("skills/deploy")).unwrap();
+        fs::write(plugin_dir.join("skills/deploy/SKILL.md"), "# Deploy").unwrap();
+        fs::create_dir_all(plugin_dir.join("commands")).unwrap();
+        fs::write(plugin_dir.join("commands/ship.md"), "# Ship").unwrap();
+        write_plugin_registry(&claude_root, "tools@acme", &plugin_dir.to_string_lossy());
+
+        let storage = StorageConfig::with_roots(temp.path().join("capacitor"), claude_root);
+        let engine = HudEngine::with_storage(storage).unwrap();
+        let names = |artifacts: Vec<Artifact>| -> Vec<(String, bool)> {
+            artifacts.into_iter().map(|a| (a.name, a.enabled)).collect()
+        };
+        assert_eq!(
+            names(engine.list_artifacts()),
+            vec![("ship".to_string(), true), ("deploy".to_string(), true)]
+        );
+
+        assert!(engine
+            .toggle_plugin_artifact(
+                "tools@acme".to_string(),
+                "widget".to_string(),
+                "deploy".to_string(),
+                false
+            )
+            .is_err());
+        engine
+            .toggle_plugin_artifact(
+                "tools@acme".to_string(),
+                "skill".to_string(),
+                "deploy".to_string(),
+                false,
+            )
+            .unwrap();
+        assert_eq!(
+            names(engine.list_artifacts()),
+            vec![("ship".to_string(), true)]
+        );
+        let mut all = names(engine.list_plugin_artifacts("tools@acme".to_string()));
+        all.sort();
+        assert_eq!(
+            all,
+            vec![("deploy".to_string(), false), ("ship".to_string(), true)]
+        );
+        let resolved = engine
+            .resolve_effective_artifacts(temp.path().join("repo").to_string_lossy().to_string());
+        assert!(resolved
+            .iter()
+            .any(|e| e.artifact.name == "deploy" && !e.active));
+
+        engine
+            .toggle_plugin_artifact(
+                "tools@acme".to_string(),
+                "skill".to_string(),
+                "deploy".to_string(),
+                true,
+            )
+            .unwrap();
+        assert_eq!(engine.list_artifacts().len(), 2);
+        assert!(engine.get_config().disabled_artifacts.is_empty());
+    }
+
+    #[test]
+    fn list_artifacts_reuses_cached_parse_for_unchanged_files() {
+        let temp = TempDir::new().unwrap();
+        let claude_root = temp.path().join("claude");
+        let skill_md = claude_root.join("skills/deploy/SKILL.md");
+        fs::create_dir_all(skill_md.parent().unwrap()).unwrap();
+        fs::write(&skill_md, "---\nname: deploy\ndescription: v1\n---\n").unwrap();
+        let storage = StorageConfig::with_roots(temp.path().join("capacitor"), claude_root);
+        let engine = HudEngine::with_storage(storage.clone()).unwrap();
+        let description = || {
+            engine
+                .list_artifacts()
+                .first()
+                .map(|a| a.description.clone())
+        };
+
+        assert_eq!(description().as_deref(), Some("v1"));
+
+        // An unchanged file is served from the cache without being re-read
+        let mut cache = load_artifact_cache_with_storage(&storage);
+        let key = skill_md.to_string_lossy().to_string();
+        cache.files.get_mut(&key).unwrap().artifact.description = "cached".to_string();
+        save_artifact_cache_with_storage(&storage, &cache).unwrap();
+        assert_eq!(description().as_deref(), Some("cached"));
+
+        fs::write(
+            &skill_md,
+            "---\nname: deploy\ndescription: v2 updated\n---\n",
+        )
+        .unwrap();
+        assert_eq!(description().as_deref(), Some("v2 updated"));
+
+        fs::remove_file(&skill_md).unwrap();
+        assert!(engine.list_artifacts().is_empty());
+        assert!(!load_artifact_cache_with_storage(&storage)
+            .files
+            .contains_key(&key));
+    }
+
+    #[test]
+    fn global_instructions_change_is_reported_once() {
+        let temp = TempDir::new().unwrap();
+        let claude_root = temp.path().join("claude");
+        fs::create_dir_all(&claude_root).unwrap();
+        fs::write(claude_root.join("CLAUDE.md"), "# Rules\n").unwrap();
+        let storage = StorageConfig::with_roots(temp.path().join("capacitor"), claude_root.clone());
+        let engine = HudEngine::with_storage(storage).unwrap();
+
+        assert_eq!(
+            engine.read_global_instructions().as_deref(),
+            Some("# Rules\n")
+        );
+        assert!(engine.check_global_instructions_changed().is_none());
+
+        fs::write(claude_root.join("CLAUDE.md"), "# Rules\n- Be brief\n").unwrap();
+        let change = engine.check_global_instructions_changed().unwrap();
+        assert_eq!(change.content.as_deref(), Some("# Rules\n- Be brief\n"));
+        assert!(engine.check_global_instructions_changed().is_none());
+
+        fs::remove_file(claude_root.join("CLAUDE.md")).unwrap();
+        assert_eq!(
+            engine.check_global_instructions_changed().unwrap().content,
+            None
+        );
+    }
+
+    #[test]
+    fn load_dashboard_reports_corrupt_plugin_registry() {
+        let temp = TempDir::new().unwrap();
+        let claude_root = temp.path().join("claude");
+        fs::create_dir_all(claude_root.join("plugins")).unwrap();
+        fs::write(
+            claude_root.join("plugins/installed_plugins.json"),
+            "{ not valid json",
+        )
+        .unwrap();
+        fs::write(claude_root.join("settings.json"), "{}").unwrap();
+
+        let project_dir = temp.path().join("project");
+        fs::create_dir_all(&project_dir).unwrap();
+        let storage = StorageConfig::with_roots(temp.path().join("capacitor"), claude_root);
+        let engine = HudEngine::with_storage(storage).unwrap();
+        engine
+            .add_project(project_dir.to_string_lossy().to_string())
+            .unwrap();
+
+        let dashboard = engine.load_dashboard().unwrap();
+        assert!(dashboard.plugins.is_empty());
+        assert_eq!(dashboard.projects.len(), 1);
+        assert!(dashboard.global.settings_exists);
+        assert_eq!(dashboard.warnings.len(), 1);
+        assert!(dashboard.warnings[0].starts_with("Couldn't load plugins"));
+    }
+
+    #[test]
+    fn load_dashboard_without_problems_has_no_warnings() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let engine = HudEngine::with_storage(storage).unwrap();
+
+        let dashboard = engine.load_dashboard().unwrap();
+        assert!(dashboard.warnings.is_empty());
+    }
+
+    #[test]
+    fn export_dashboard_json_includes_project_stats_and_states() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let engine = HudEngine::with_storage(storage).unwrap();
+        let project_dir = temp.path().join("app");
+        fs::create_dir_all(&project_dir).unwrap();
+        let project_path = project_dir.to_string_lossy().to_string();
+        engine.add_project(project_path.clone()).unwrap();
+
+        let dest = temp.path().join("out/dashboard.json");
+        assert!(engine
+            .export_dashboard_json(dest.to_string_lossy().to_string())
+            .is_err());
+        fs::create_dir_all(dest.parent().unwrap()).unwrap();
+        let count = engine
+            .export_dashboard_json(dest.to_string_lossy().to_string())
+            .unwrap();
+        assert_eq!(count, 1);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&dest).unwrap()).unwrap();
+        let project = &json["dashboard"]["projects"][0];
+        assert_eq!(project["path"], project_path.as_str());
+        assert!(project["stats"].is_object());
+        assert_eq!(json["session_states"][&project_path]["state"], "idle");
+        assert!(json["exported_at"].is_string());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn suggested_projects_skip_non_utf8_folders() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let temp = TempDir::new().unwrap();
+        let claude_projects = temp.path().join("claude").join("projects");
+        let project_dir = temp.path().join("project");
+        fs::create_dir_all(&project_dir).unwrap();
+        let encoded = crate::projects::encode_project_path(project_dir.to_str().unwrap());
+        fs::create_dir_all(claude_projects.join(&encoded)).unwrap();
+
+        // Same name with an invalid byte appended; lossy decoding would map it
+        // onto a path ending in U+FFFD instead of skipping it
+        let mut bad_name = encoded.into_bytes();
+        bad_name.extend_from_slice(b"\xff");
+        fs::create_dir_all(claude_projects.join(OsStr::from_bytes(&bad_name))).unwrap();
+
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let engine = HudEngine::with_storage(storage).unwrap();
+
+        let suggestions = engine.get_suggested_projects().unwrap();
+        let paths: Vec<&str> = suggestions.iter().map(|s| s.path.as_str()).collect();
+        assert_eq!(paths, vec![project_dir.to_str().unwrap()]);
+    }
 }
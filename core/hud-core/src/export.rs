@@ -0,0 +1,197 @@
+//! File exports of dashboard data and usage stats for scripting, backups, and
+//! spreadsheets.
+//!
+//! Exports are written to a temp file beside the destination and renamed into
+//! place, so a reader never sees a half-written file.
+
+use crate::config::{load_hud_config_with_storage, load_stats_cache_with_storage};
+use crate::projects::project_name;
+use crate::storage::StorageConfig;
+use crate::types::{DashboardData, ProjectSessionState};
+use chrono::Utc;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+
+/// Everything the dashboard shows, in one document.
+///
+/// Each project carries its full token/cost stats, and session states are keyed
+/// by project path.
+#[derive(Debug, Clone, Serialize)]
+pub struct DashboardExport {
+    /// When the export was taken (RFC 3339)
+    pub exported_at: String,
+    pub dashboard: DashboardData,
+    pub session_states: HashMap<String, ProjectSessionState>,
+}
+
+impl DashboardExport {
+    pub fn new(
+        dashboard: DashboardData,
+        session_states: HashMap<String, ProjectSessionState>,
+    ) -> Self {
+        Self {
+            exported_at: Utc::now().to_rfc3339(),
+            dashboard,
+            session_states,
+        }
+    }
+}
+
+/// Writes an export as pretty-printed JSON to `dest`.
+///
+/// Returns the number of projects exported.
+pub fn write_dashboard_json(dest: &str, export: &DashboardExport) -> Result<u32, String> {
+    let content = serde_json::to_vec_pretty(export)
+        .map_err(|e| format!("Failed to serialize export: {}", e))?;
+    write_file_atomic(Path::new(dest), &content)?;
+    Ok(export.dashboard.projects.len() as u32)
+}
+
+/// Header row of the stats CSV.
+const STATS_CSV_COLUMNS: &[&str] = &[
+    "name",
+    "path",
+    "input_tokens",
+    "output_tokens",
+    "cache_read_tokens",
+    "cache_creation_tokens",
+    "opus_messages",
+    "sonnet_messages",
+    "haiku_messages",
+    "session_count",
+    "first_activity",
+    "last_activity",
+    "estimated_cost_usd",
+];
+
+/// Writes one CSV row of token and cost stats per pinned project to `dest`.
+///
+/// Stats come from the stats cache as of the last dashboard load; nothing is
+/// re-parsed, so projects never loaded export as zeros. Returns the number of
+/// rows written, or an error when no projects are pinned.
+pub fn export_stats_csv(dest: &str) -> Result<u32, String> {
+    export_stats_csv_with_storage(&StorageConfig::default(), dest)
+}
+
+pub fn export_stats_csv_with_storage(storage: &StorageConfig, dest: &str) -> Result<u32, String> {
+    let config = load_hud_config_with_storage(storage);
+    if config.pinned_projects.is_empty() {
+        return Err("No pinned projects to export".to_string());
+    }
+    let cache = load_stats_cache_with_storage(storage);
+
+    let mut csv = STATS_CSV_COLUMNS.join(",");
+    csv.push('\n');
+    for path in &config.pinned_projects {
+        let stats = cache
+            .projects
+            .get(path)
+            .map(|cached| cached.stats.clone())
+            .unwrap_or_default();
+        let alias = config.project_aliases.get(path).map(String::as_str);
+        let row = [
+            project_name(path, alias),
+            path.clone(),
+            stats.total_input_tokens.to_string(),
+            stats.total_output_tokens.to_string(),
+            stats.total_cache_read_tokens.to_string(),
+            stats.total_cache_creation_tokens.to_string(),
+            stats.opus_messages.to_string(),
+            stats.sonnet_messages.to_string(),
+            stats.haiku_messages.to_string(),
+            stats.session_count.to_string(),
+            stats.first_activity.unwrap_or_default(),
+            stats.last_activity.unwrap_or_default(),
+            format!("{:.2}", stats.estimated_cost_usd),
+        ];
+        let fields: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+
+    write_file_atomic(Path::new(dest), csv.as_bytes())?;
+    Ok(config.pinned_projects.len() as u32)
+}
+
+/// Quotes a CSV field when it contains a comma, quote, or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Writes `content` to a temp file in the destination's directory, then renames
+/// it over `dest`.
+fn write_file_atomic(dest: &Path, content: &[u8]) -> Result<(), String> {
+    let dir = match dest.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let mut tmp = tempfile::NamedTempFile::new_in(dir)
+        .map_err(|e| format!("Failed to create temp file in {}: {}", dir.display(), e))?;
+    tmp.write_all(content)
+        .and_then(|_| tmp.flush())
+        .map_err(|e| format!("Failed to write export: {}", e))?;
+    tmp.persist(dest)
+        .map_err(|e| format!("Failed to write {}: {}", dest.display(), e.error))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{save_hud_config_with_storage, save_stats_cache_with_storage};
+    use crate::types::{CachedProjectStats, ProjectStats, StatsCache};
+    use fs_err as fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn stats_csv_has_a_row_per_pinned_project_from_cache() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dest = temp.path().join("stats.csv");
+        let dest = dest.to_string_lossy().to_string();
+        assert!(export_stats_csv_with_storage(&storage, &dest).is_err());
+        assert!(!Path::new(&dest).exists());
+
+        let mut config = load_hud_config_with_storage(&storage);
+        config.pinned_projects = vec!["/work/app".to_string(), "/work/new".to_string()];
+        config
+            .project_aliases
+            .insert("/work/app".to_string(), "App, \"v2\"".to_string());
+        save_hud_config_with_storage(&storage, &config).unwrap();
+
+        let mut cache = StatsCache::default();
+        cache.projects.insert(
+            "/work/app".to_string(),
+            CachedProjectStats {
+                files: Default::default(),
+                stats: ProjectStats {
+                    total_input_tokens: 1200,
+                    total_output_tokens: 300,
+                    opus_messages: 2,
+                    session_count: 1,
+                    first_activity: Some("2026-01-01T10:00:00Z".to_string()),
+                    estimated_cost_usd: 0.42,
+                    ..Default::default()
+                },
+            },
+        );
+        save_stats_cache_with_storage(&storage, &cache).unwrap();
+
+        assert_eq!(export_stats_csv_with_storage(&storage, &dest).unwrap(), 2);
+        let csv = fs::read_to_string(&dest).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], STATS_CSV_COLUMNS.join(","));
+        assert_eq!(
+            lines[1],
+            "\"App, \"\"v2\"\"\",/work/app,1200,300,0,0,2,0,0,1,2026-01-01T10:00:00Z,,0.42"
+        );
+        assert_eq!(lines[2], "new,/work/new,0,0,0,0,0,0,0,0,,,0.00");
+    }
+}
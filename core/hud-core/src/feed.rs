@@ -0,0 +1,171 @@
+//! Push-style feed of session state changes for external integrations.
+//!
+//! The core has no background threads, so the feed is edge-detected on demand:
+//! a subscriber keeps the last [`StateSnapshot`] it saw, and each poll diffs it
+//! against the current state store. Status bars, Stream Deck plugins and the
+//! like get one event per change without diffing full state themselves.
+
+use crate::state::StateStore;
+use crate::storage::StorageConfig;
+use crate::types::SessionState;
+use chrono::Utc;
+use std::collections::BTreeMap;
+
+/// One session's state changing, appearing, or ending.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct StateFeedEvent {
+    pub session_id: String,
+    pub cwd: String,
+    /// None when the session is new since the last poll
+    pub from: Option<SessionState>,
+    /// None when the session's record is gone (session ended)
+    pub to: Option<SessionState>,
+    /// When the change was observed (RFC 3339)
+    pub observed_at: String,
+}
+
+/// Session states as of one read of the state store, keyed by session id.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StateSnapshot {
+    sessions: BTreeMap<String, (SessionState, String)>,
+}
+
+impl StateSnapshot {
+    /// Reads the current state of every session record.
+    pub fn load(storage: &StorageConfig) -> Self {
+        let state_file = storage.sessions_file();
+        let store = StateStore::load(&state_file).unwrap_or_else(|_| StateStore::new(&state_file));
+        Self {
+            sessions: store
+                .sessions()
+                .map(|r| (r.session_id.clone(), (r.state, r.cwd.clone())))
+                .collect(),
+        }
+    }
+
+    /// Lists the changes from `self` to `next`, ordered by session id.
+    pub fn diff(&self, next: &StateSnapshot) -> Vec<StateFeedEvent> {
+        let observed_at = Utc::now().to_rfc3339();
+        let event = |session_id: &str, cwd: &str, from, to| StateFeedEvent {
+            session_id: session_id.to_string(),
+            cwd: cwd.to_string(),
+            from,
+            to,
+            observed_at: observed_at.clone(),
+        };
+
+        let mut events: Vec<StateFeedEvent> = next
+            .sessions
+            .iter()
+            .filter_map(|(id, (state, cwd))| match self.sessions.get(id) {
+                Some((previous, _)) if previous == state => None,
+                previous => Some(event(id, cwd, previous.map(|(s, _)| *s), Some(*state))),
+            })
+            .collect();
+        events.extend(
+            self.sessions
+                .iter()
+                .filter(|(id, _)| !next.sessions.contains_key(*id))
+                .map(|(id, (state, cwd))| event(id, cwd, Some(*state), None)),
+        );
+        events.sort_by(|a, b| a.session_id.cmp(&b.session_id));
+        events
+    }
+}
+
+/// A session starting to wait on the user, typically for a permission prompt.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct SessionWaitingEvent {
+    pub session_id: String,
+    pub cwd: String,
+    /// When the change was observed (RFC 3339)
+    pub observed_at: String,
+}
+
+/// Picks out the transitions into [`SessionState::Waiting`].
+pub fn waiting_events(events: &[StateFeedEvent]) -> Vec<SessionWaitingEvent> {
+    events
+        .iter()
+        .filter(|e| e.to == Some(SessionState::Waiting))
+        .map(|e| SessionWaitingEvent {
+            session_id: e.session_id.clone(),
+            cwd: e.cwd.clone(),
+            observed_at: e.observed_at.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn transition_produces_one_event() {
+        let temp = TempDir::new().unwrap();
+        let storage = StorageConfig::with_root(temp.path().to_path_buf());
+        let mut store = StateStore::new(&storage.sessions_file());
+        store.update("s1", SessionState::Working, "/p/a");
+        store.update("s2", SessionState::Ready, "/p/b");
+        store.save().unwrap();
+        let before = StateSnapshot::load(&storage);
+
+        store.update("s1", SessionState::Waiting, "/p/a");
+        store.save().unwrap();
+        let events = before.diff(&StateSnapshot::load(&storage));
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].session_id, "s1");
+        assert_eq!(events[0].cwd, "/p/a");
+        assert_eq!(events[0].from, Some(SessionState::Working));
+        assert_eq!(events[0].to, Some(SessionState::Waiting));
+    }
+
+    #[test]
+    fn new_and_ended_sessions_are_reported() {
+        let mut before = StateSnapshot::default();
+        before
+            .sessions
+            .insert("old".to_string(), (SessionState::Ready, "/p".to_string()));
+        let mut after = StateSnapshot::default();
+        after
+            .sessions
+            .insert("new".to_string(), (SessionState::Working, "/q".to_string()));
+
+        let events = before.diff(&after);
+        let summary: Vec<_> = events
+            .iter()
+            .map(|e| (e.session_id.as_str(), e.from, e.to))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("new", None, Some(SessionState::Working)),
+                ("old", Some(SessionState::Ready), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn only_transitions_into_waiting_are_picked() {
+        let mut before = StateSnapshot::default();
+        before.sessions.insert(
+            "s1".to_string(),
+            (SessionState::Working, "/p/a".to_string()),
+        );
+        let mut after = StateSnapshot::default();
+        after.sessions.insert(
+            "s1".to_string(),
+            (SessionState::Waiting, "/p/a".to_string()),
+        );
+        after
+            .sessions
+            .insert("s2".to_string(), (SessionState::Ready, "/p/b".to_string()));
+
+        let waiting = waiting_events(&before.diff(&after));
+        assert_eq!(waiting.len(), 1);
+        assert_eq!(waiting[0].session_id, "s1");
+        assert_eq!(waiting[0].cwd, "/p/a");
+        assert!(waiting_events(&after.diff(&after)).is_empty());
+    }
+}
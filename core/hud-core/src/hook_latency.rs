@@ -0,0 +1,177 @@
+//! Hook execution timing, read back from the hook's debug log.
+//!
+//! `hud-hook` logs a `hook finished` line with its own run time after each
+//! `handle` or `cwd` invocation, in the daily `~/.capacitor/hud-hook-debug.*.log`
+//! files. The shell integration aims for `cwd` under 15ms; these stats show
+//! whether that holds on the user's machine.
+
+use crate::storage::StorageConfig;
+use chrono::{DateTime, Utc};
+use fs_err as fs;
+use std::collections::BTreeMap;
+
+/// Prefix of the hook's rotating debug log files.
+const HOOK_LOG_PREFIX: &str = "hud-hook-debug";
+
+/// Message the hook logs when a run completes.
+const HOOK_FINISHED_MESSAGE: &str = "hook finished";
+
+/// Most recent runs per command included in the stats.
+const HOOK_LATENCY_MAX_SAMPLES: usize = 500;
+
+/// Run-time distribution for one hook subcommand.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct HookLatencyStats {
+    /// Hook subcommand ("handle" or "cwd")
+    pub command: String,
+    pub sample_count: u32,
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+}
+
+/// Summarizes recent hook run times from the debug logs, one entry per subcommand.
+///
+/// Returns an empty list when no timed runs have been logged yet.
+pub fn hook_latency_stats() -> Vec<HookLatencyStats> {
+    hook_latency_stats_with_storage(&StorageConfig::default())
+}
+
+pub fn hook_latency_stats_with_storage(storage: &StorageConfig) -> Vec<HookLatencyStats> {
+    let contents: Vec<String> = fs::read_dir(storage.root())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|e| {
+            e.file_name()
+                .to_str()
+                .is_some_and(|name| name.starts_with(HOOK_LOG_PREFIX) && name.ends_with(".log"))
+        })
+        .filter_map(|e| fs::read_to_string(e.path()).ok())
+        .collect();
+    hook_latency_from_lines(contents.iter().flat_map(|c| c.lines()))
+}
+
+/// Computes latency stats from log lines, keeping the newest runs per command.
+pub fn hook_latency_from_lines<'a>(
+    lines: impl IntoIterator<Item = &'a str>,
+) -> Vec<HookLatencyStats> {
+    let mut runs: BTreeMap<String, Vec<(DateTime<Utc>, f64)>> = BTreeMap::new();
+    for line in lines {
+        if let Some((at, command, ms)) = parse_finished_line(line) {
+            runs.entry(command).or_default().push((at, ms));
+        }
+    }
+
+    runs.into_iter()
+        .map(|(command, mut samples)| {
+            samples.sort_by_key(|(at, _)| std::cmp::Reverse(*at));
+            samples.truncate(HOOK_LATENCY_MAX_SAMPLES);
+            let mut durations: Vec<f64> = samples.into_iter().map(|(_, ms)| ms).collect();
+            durations.sort_by(|a, b| a.total_cmp(b));
+
+            let n = durations.len();
+            let mid = n / 2;
+            let median_ms = if n % 2 == 0 {
+                (durations[mid - 1] + durations[mid]) / 2.0
+            } else {
+                durations[mid]
+            };
+            // Nearest-rank percentile
+            let p95_index = ((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1;
+
+            HookLatencyStats {
+                command,
+                sample_count: n as u32,
+                min_ms: durations[0],
+                median_ms,
+                p95_ms: durations[p95_index],
+                max_ms: durations[n - 1],
+            }
+        })
+        .collect()
+}
+
+/// Parses `<rfc3339> DEBUG hud_hook: hook finished command=cwd duration_us=812`.
+fn parse_finished_line(line: &str) -> Option<(DateTime<Utc>, String, f64)> {
+    let (timestamp, rest) = line.split_once(' ')?;
+    let (_, fields) = rest.split_once(HOOK_FINISHED_MESSAGE)?;
+    let at = DateTime::parse_from_rfc3339(timestamp).ok()?;
+
+    let field = |key: &str| {
+        fields
+            .split_whitespace()
+            .find_map(|token| token.strip_prefix(key)?.strip_prefix('='))
+    };
+    let command = field("command")?.trim_matches('"').to_string();
+    let duration_us: u64 = field("duration_us")?.parse().ok()?;
+
+    Some((at.with_timezone(&Utc), command, duration_us as f64 / 1000.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn finished(second: u32, command: &str, duration_us: u64) -> String {
+        format!(
+            "2026-01-01T10:00:{:02}.000000Z DEBUG hud_hook: hook finished command={} duration_us={}",
+            second, command, duration_us
+        )
+    }
+
+    #[test]
+    fn computes_min_median_p95_per_command() {
+        let mut lines: Vec<String> = (1..=20)
+            .map(|i| finished(i, "cwd", i as u64 * 1000))
+            .collect();
+        lines.push(finished(30, "handle", 4_500));
+        lines.push(
+            "2026-01-01T10:00:31.000000Z DEBUG hud_hook::handle: Unhandled event".to_string(),
+        );
+
+        let stats = hook_latency_from_lines(lines.iter().map(String::as_str));
+
+        assert_eq!(stats.len(), 2);
+        let cwd = &stats[0];
+        assert_eq!(cwd.command, "cwd");
+        assert_eq!(cwd.sample_count, 20);
+        assert_eq!(cwd.min_ms, 1.0);
+        assert_eq!(cwd.median_ms, 10.5);
+        assert_eq!(cwd.p95_ms, 19.0);
+        assert_eq!(cwd.max_ms, 20.0);
+        assert_eq!(stats[1].command, "handle");
+        assert_eq!(stats[1].median_ms, 4.5);
+    }
+
+    #[test]
+    fn reads_rotated_log_files() {
+        let temp = TempDir::new().unwrap();
+        let storage = StorageConfig::with_root(temp.path().to_path_buf());
+        fs::write(
+            temp.path().join("hud-hook-debug.2026-01-01.log"),
+            finished(1, "cwd", 2_000),
+        )
+        .unwrap();
+        fs::write(
+            temp.path().join("hud-hook-debug.2026-01-02.log"),
+            finished(2, "cwd", 4_000),
+        )
+        .unwrap();
+        fs::write(temp.path().join("other.log"), finished(3, "cwd", 90_000)).unwrap();
+
+        let stats = hook_latency_stats_with_storage(&storage);
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].sample_count, 2);
+        assert_eq!(stats[0].median_ms, 3.0);
+    }
+
+    #[test]
+    fn no_logs_means_no_stats() {
+        let temp = TempDir::new().unwrap();
+        let storage = StorageConfig::with_root(temp.path().to_path_buf());
+        assert!(hook_latency_stats_with_storage(&storage).is_empty());
+    }
+}
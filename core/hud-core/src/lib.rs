@@ -28,38 +28,67 @@ uniffi::setup_scaffolding!();
 // Public modules
 pub mod activation;
 pub mod activity;
+pub mod agent_changes;
 pub mod agents;
 pub mod artifacts;
+pub mod backup;
 pub mod boundaries;
 pub mod config;
+pub mod editor;
 pub mod engine;
 pub mod error;
+pub mod export;
+pub mod feed;
+pub mod hook_latency;
 pub mod ideas;
+pub mod mcp;
 pub mod patterns;
+pub mod permissions;
 pub mod projects;
+pub mod receipt;
 pub mod sessions;
 pub mod setup;
 pub mod state;
 pub mod stats;
 pub mod storage;
+pub mod tasks;
+pub mod terminals;
+pub mod trace;
+pub mod transcript;
 pub mod types;
 pub mod validation;
 
 // Re-export commonly used items at crate root
 pub use activation::*;
 pub use activity::*;
+pub use agent_changes::*;
 pub use agents::{AgentAdapter, AgentConfig, AgentRegistry, AgentSession, AgentState, AgentType};
 pub use artifacts::*;
+pub use backup::*;
 pub use boundaries::*;
 pub use config::*;
+pub use editor::*;
 pub use engine::HudEngine;
 pub use error::{HudError, HudFfiError, Result};
+pub use export::*;
+pub use feed::*;
+pub use hook_latency::*;
 pub use ideas::*;
+pub use mcp::*;
 pub use patterns::*;
+pub use permissions::*;
 pub use projects::*;
+pub use receipt::*;
 pub use sessions::*;
-pub use setup::{DependencyStatus, HookStatus, InstallResult, SetupStatus};
+pub use setup::{
+    BrokenHook, DependencyStatus, HookInstallOutcome, HookInstallPreview, HookStatus,
+    InstallResult, SetupStatus,
+};
 pub use stats::*;
 pub use storage::*;
+pub use tasks::*;
+pub use terminals::*;
+pub use trace::*;
+pub use transcript::*;
 pub use types::*;
 pub use validation::*;
@@ -0,0 +1,233 @@
+//! MCP server definitions from Claude Code configuration.
+//!
+//! Servers are read from two places:
+//! - Global: the `mcpServers` object in `~/.claude/settings.json`
+//! - Project: the `mcpServers` object in `<project>/.mcp.json`
+//!
+//! Reads are best-effort: a missing or malformed file contributes no servers.
+//! We never write to `~/.claude/`; only a project's `.mcp.json` may be created.
+
+use crate::error::{HudError, Result};
+use crate::storage::StorageConfig;
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+const PROJECT_MCP_FILE: &str = ".mcp.json";
+const EMPTY_MCP_CONFIG: &str = "{\n  \"mcpServers\": {}\n}\n";
+
+/// Where an MCP server is configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+pub enum McpScope {
+    Global,
+    Project,
+}
+
+/// An MCP server definition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct McpServer {
+    pub name: String,
+    /// Executable for stdio servers, None for remote (url) servers
+    pub command: Option<String>,
+    pub args: Vec<String>,
+    /// Endpoint for http/sse servers
+    pub url: Option<String>,
+    pub scope: McpScope,
+    /// File the server is defined in
+    pub config_path: String,
+}
+
+/// Loads MCP servers from global settings and, if given, the project's `.mcp.json`.
+///
+/// Global servers come first; each group is sorted by name.
+pub fn load_mcp_servers(project_path: Option<&str>) -> Vec<McpServer> {
+    load_mcp_servers_with_storage(&StorageConfig::default(), project_path)
+}
+
+pub fn load_mcp_servers_with_storage(
+    storage: &StorageConfig,
+    project_path: Option<&str>,
+) -> Vec<McpServer> {
+    let mut servers = read_mcp_servers(&storage.claude_settings_file(), McpScope::Global);
+    if let Some(project) = project_path {
+        servers.extend(read_mcp_servers(
+            &Path::new(project).join(PROJECT_MCP_FILE),
+            McpScope::Project,
+        ));
+    }
+    servers
+}
+
+/// Returns the config file to edit for the given scope, for the client to open.
+///
+/// For project scope, creates an empty `.mcp.json` if the project has none.
+/// The global settings file is never created here.
+pub fn open_mcp_config(scope: McpScope, project_path: Option<&str>) -> Result<String> {
+    open_mcp_config_with_storage(&StorageConfig::default(), scope, project_path)
+}
+
+pub fn open_mcp_config_with_storage(
+    storage: &StorageConfig,
+    scope: McpScope,
+    project_path: Option<&str>,
+) -> Result<String> {
+    let path = match scope {
+        McpScope::Global => storage.claude_settings_file(),
+        McpScope::Project => {
+            let project = project_path.ok_or_else(|| HudError::InvalidProjectPath {
+                path: String::new(),
+                reason: "Project path required for project MCP config".to_string(),
+            })?;
+            let project_dir = Path::new(project);
+            if !project_dir.is_dir() {
+                return Err(HudError::ProjectNotFound(project.to_string()));
+            }
+            let path = project_dir.join(PROJECT_MCP_FILE);
+            if !path.exists() {
+                fs::write(&path, EMPTY_MCP_CONFIG).map_err(|e| HudError::Io {
+                    context: format!("Failed to create {}", path.display()),
+                    source: e,
+                })?;
+            }
+            path
+        }
+    };
+
+    if !path.exists() {
+        return Err(HudError::FileNotFound(path));
+    }
+    Ok(path.to_string_lossy().to_string())
+}
+
+fn read_mcp_servers(path: &Path, scope: McpScope) -> Vec<McpServer> {
+    let Some(servers) = fs::read_to_string(path)
+        .ok()
+        .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok())
+        .and_then(|v| v.get("mcpServers").and_then(|s| s.as_object()).cloned())
+    else {
+        return Vec::new();
+    };
+
+    let mut result: Vec<McpServer> = servers
+        .into_iter()
+        .map(|(name, def)| McpServer {
+            name,
+            command: def
+                .get("command")
+                .and_then(|c| c.as_str())
+                .map(String::from),
+            args: def
+                .get("args")
+                .and_then(|a| a.as_array())
+                .map(|a| {
+                    a.iter()
+                        .filter_map(|v| v.as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            url: def.get("url").and_then(|u| u.as_str()).map(String::from),
+            scope,
+            config_path: path.to_string_lossy().to_string(),
+        })
+        .collect();
+    result.sort_by(|a, b| a.name.cmp(&b.name));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, StorageConfig) {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        fs::create_dir_all(storage.claude_root()).unwrap();
+        (temp, storage)
+    }
+
+    #[test]
+    fn parses_global_settings_with_two_servers() {
+        let (_temp, storage) = setup();
+        fs::write(
+            storage.claude_settings_file(),
+            r#"{
+                "hooks": {},
+                "mcpServers": {
+                    "github": {"command": "npx", "args": ["-y", "@modelcontextprotocol/server-github"]},
+                    "docs": {"type": "http", "url": "https://example.com/mcp"}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let servers = load_mcp_servers_with_storage(&storage, None);
+        assert_eq!(servers.len(), 2);
+        assert_eq!(servers[0].name, "docs");
+        assert_eq!(servers[0].url.as_deref(), Some("https://example.com/mcp"));
+        assert_eq!(servers[0].command, None);
+        assert_eq!(servers[1].name, "github");
+        assert_eq!(servers[1].command.as_deref(), Some("npx"));
+        assert_eq!(servers[1].args.len(), 2);
+        assert!(servers.iter().all(|s| s.scope == McpScope::Global));
+    }
+
+    #[test]
+    fn includes_project_servers_after_global() {
+        let (temp, storage) = setup();
+        fs::write(
+            storage.claude_settings_file(),
+            r#"{"mcpServers": {"global-one": {"command": "a"}}}"#,
+        )
+        .unwrap();
+        let project = temp.path().join("project");
+        fs::create_dir_all(&project).unwrap();
+        fs::write(
+            project.join(PROJECT_MCP_FILE),
+            r#"{"mcpServers": {"local-one": {"command": "b"}}}"#,
+        )
+        .unwrap();
+
+        let servers = load_mcp_servers_with_storage(&storage, Some(project.to_str().unwrap()));
+        let scoped: Vec<_> = servers.iter().map(|s| (s.name.as_str(), s.scope)).collect();
+        assert_eq!(
+            scoped,
+            vec![
+                ("global-one", McpScope::Global),
+                ("local-one", McpScope::Project)
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_or_missing_config_yields_no_servers() {
+        let (_temp, storage) = setup();
+        assert!(load_mcp_servers_with_storage(&storage, None).is_empty());
+        fs::write(storage.claude_settings_file(), "{ not json").unwrap();
+        assert!(load_mcp_servers_with_storage(&storage, None).is_empty());
+    }
+
+    #[test]
+    fn open_project_config_creates_empty_file_once() {
+        let (temp, storage) = setup();
+        let project = temp.path().join("project");
+        fs::create_dir_all(&project).unwrap();
+
+        let path =
+            open_mcp_config_with_storage(&storage, McpScope::Project, project.to_str()).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), EMPTY_MCP_CONFIG);
+
+        fs::write(&path, r#"{"mcpServers": {"x": {"command": "y"}}}"#).unwrap();
+        open_mcp_config_with_storage(&storage, McpScope::Project, project.to_str()).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains("\"x\""));
+    }
+
+    #[test]
+    fn open_global_config_requires_existing_settings() {
+        let (_temp, storage) = setup();
+        assert!(open_mcp_config_with_storage(&storage, McpScope::Global, None).is_err());
+        fs::write(storage.claude_settings_file(), "{}").unwrap();
+        assert!(open_mcp_config_with_storage(&storage, McpScope::Global, None).is_ok());
+    }
+}
@@ -29,6 +29,9 @@ pub static RE_SUMMARY: Lazy<Regex> =
     Lazy::new(|| Regex::new(r#""type":"summary","summary":"([^"]+)""#).unwrap());
 pub static RE_TIMESTAMP: Lazy<Regex> =
     Lazy::new(|| Regex::new(r#""timestamp":"(\d{4}-\d{2}-\d{2}T[^"]+)""#).unwrap());
+/// A bare timestamp value, as captured by [`RE_TIMESTAMP`]
+pub static RE_TIMESTAMP_VALUE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\d{4}-\d{2}-\d{2}T").unwrap());
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // Frontmatter Parsing Regexes
@@ -0,0 +1,451 @@
+//! Recent permission prompts for a session, to help tune allow rules.
+//!
+//! Claude Code doesn't log permission prompts in the transcript directly, so
+//! they are reconstructed from two sources:
+//! - The transitions log: each `PermissionRequest` hook event marks a prompt.
+//!   The prompting tool is the latest `tool_use` at or before that moment.
+//! - The transcript: a `tool_result` carrying the rejection message means the
+//!   user denied the tool, with or without a logged prompt.
+//!
+//! A prompted tool whose result isn't a rejection is reported as approved; one
+//! with no result yet is still pending.
+//!
+//! [`permission_heavy_sessions`] ranks sessions across projects by prompt count,
+//! showing where approval friction concentrates.
+
+use crate::projects::session_transcript_path;
+use crate::state::{load_session_transitions, load_transitions};
+use crate::storage::StorageConfig;
+use chrono::{DateTime, Utc};
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Maximum number of entries returned, newest kept.
+const PERMISSION_HISTORY_LIMIT: usize = 50;
+
+/// Substrings Claude Code puts in a tool result when a tool use is refused.
+const REJECTION_MARKERS: &[&str] = &[
+    "doesn't want to proceed with this tool use",
+    "tool use was rejected",
+    "has been denied",
+];
+
+const PERMISSION_REQUEST_EVENT: &str = "PermissionRequest";
+
+/// Tools listed per session in [`PermissionHeavySession::top_tools`].
+const TOP_TOOLS_PER_SESSION: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
+pub enum PermissionOutcome {
+    Approved,
+    Denied,
+    /// Prompted, but no tool result yet
+    Pending,
+}
+
+/// One permission prompt (or refusal) in a session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct PermissionEntry {
+    /// ISO 8601 timestamp of the tool use
+    pub timestamp: String,
+    pub tool_name: String,
+    /// Short description of the target (command, file path, or URL)
+    pub detail: Option<String>,
+    pub outcome: PermissionOutcome,
+}
+
+/// A session and how many permission prompts it raised.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct PermissionHeavySession {
+    pub session_id: String,
+    /// Working directory the session ran in
+    pub project_path: String,
+    pub prompt_count: u32,
+    /// Tools behind the prompts and denials, most frequent first
+    pub top_tools: Vec<String>,
+}
+
+struct ToolUse {
+    id: String,
+    name: String,
+    detail: Option<String>,
+    at: DateTime<Utc>,
+}
+
+/// Lists recent permission prompts for a session, oldest first.
+pub fn permission_history(session_id: &str, project_path: &str) -> Vec<PermissionEntry> {
+    permission_history_with_storage(&StorageConfig::default(), session_id, project_path)
+}
+
+pub fn permission_history_with_storage(
+    storage: &StorageConfig,
+    session_id: &str,
+    project_path: &str,
+) -> Vec<PermissionEntry> {
+    let content = session_transcript_path(storage, project_path, session_id)
+        .and_then(|path| fs::read_to_string(path).ok())
+        .unwrap_or_default();
+    let prompts: Vec<DateTime<Utc>> =
+        load_session_transitions(&storage.transitions_file(), session_id)
+            .into_iter()
+            .filter(|t| t.event.as_deref() == Some(PERMISSION_REQUEST_EVENT))
+            .map(|t| t.at)
+            .collect();
+
+    permission_history_from_content(&content, &prompts)
+}
+
+/// The `limit` sessions with the most permission prompts across all projects,
+/// most prompts first.
+pub fn permission_heavy_sessions(limit: u32) -> Vec<PermissionHeavySession> {
+    permission_heavy_sessions_with_storage(&StorageConfig::default(), limit)
+}
+
+pub fn permission_heavy_sessions_with_storage(
+    storage: &StorageConfig,
+    limit: u32,
+) -> Vec<PermissionHeavySession> {
+    // session_id -> (cwd, prompt times)
+    let mut by_session: HashMap<String, (String, Vec<DateTime<Utc>>)> = HashMap::new();
+    for transition in load_transitions(&storage.transitions_file()) {
+        if transition.event.as_deref() != Some(PERMISSION_REQUEST_EVENT) {
+            continue;
+        }
+        let entry = by_session
+            .entry(transition.session_id)
+            .or_insert_with(|| (transition.cwd, Vec::new()));
+        entry.1.push(transition.at);
+    }
+
+    let mut sessions: Vec<PermissionHeavySession> = by_session
+        .into_iter()
+        .map(|(session_id, (project_path, prompts))| {
+            let content = session_transcript_path(storage, &project_path, &session_id)
+                .and_then(|path| fs::read_to_string(path).ok())
+                .unwrap_or_default();
+            let top_tools = top_tools(&permission_entries(&content, &prompts));
+            PermissionHeavySession {
+                session_id,
+                project_path,
+                prompt_count: prompts.len() as u32,
+                top_tools,
+            }
+        })
+        .collect();
+
+    sessions.sort_by(|a, b| {
+        b.prompt_count
+            .cmp(&a.prompt_count)
+            .then_with(|| a.session_id.cmp(&b.session_id))
+    });
+    sessions.truncate(limit as usize);
+    sessions
+}
+
+/// Tool names by how often they appear in `entries`, most frequent first.
+fn top_tools(entries: &[PermissionEntry]) -> Vec<String> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for entry in entries {
+        *counts.entry(entry.tool_name.as_str()).or_default() += 1;
+    }
+    let mut tools: Vec<(&str, usize)> = counts.into_iter().collect();
+    tools.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    tools
+        .into_iter()
+        .take(TOP_TOOLS_PER_SESSION)
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+/// Builds the history from transcript content and permission prompt times.
+pub fn permission_history_from_content(
+    content: &str,
+    prompts: &[DateTime<Utc>],
+) -> Vec<PermissionEntry> {
+    let mut entries = permission_entries(content, prompts);
+    let excess = entries.len().saturating_sub(PERMISSION_HISTORY_LIMIT);
+    entries.drain(..excess);
+    entries
+}
+
+/// Every prompted or denied tool use in the transcript, oldest first.
+fn permission_entries(content: &str, prompts: &[DateTime<Utc>]) -> Vec<PermissionEntry> {
+    let mut tool_uses: Vec<ToolUse> = Vec::new();
+    let mut rejected: HashMap<String, bool> = HashMap::new();
+
+    for line in content.lines() {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        let Some(blocks) = value
+            .get("message")
+            .and_then(|m| m.get("content"))
+            .and_then(|c| c.as_array())
+        else {
+            continue;
+        };
+        let at = value
+            .get("timestamp")
+            .and_then(|t| t.as_str())
+            .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| t.with_timezone(&Utc));
+
+        for block in blocks {
+            match block.get("type").and_then(|t| t.as_str()) {
+                Some("tool_use") => {
+                    let (Some(id), Some(name), Some(at)) = (
+                        block.get("id").and_then(|v| v.as_str()),
+                        block.get("name").and_then(|v| v.as_str()),
+                        at,
+                    ) else {
+                        continue;
+                    };
+                    tool_uses.push(ToolUse {
+                        id: id.to_string(),
+                        name: name.to_string(),
+                        detail: block.get("input").and_then(tool_detail),
+                        at,
+                    });
+                }
+                Some("tool_result") => {
+                    if let Some(id) = block.get("tool_use_id").and_then(|v| v.as_str()) {
+                        rejected.insert(id.to_string(), is_rejection(block));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    tool_uses.sort_by_key(|t| t.at);
+
+    // Tool uses that triggered a logged prompt
+    let mut prompted: Vec<usize> = prompts
+        .iter()
+        .filter_map(|prompt_at| tool_uses.iter().rposition(|t| t.at <= *prompt_at))
+        .collect();
+    prompted.sort_unstable();
+    prompted.dedup();
+
+    tool_uses
+        .iter()
+        .enumerate()
+        .filter_map(|(i, tool)| {
+            let outcome = match rejected.get(&tool.id) {
+                Some(true) => PermissionOutcome::Denied,
+                Some(false) if prompted.contains(&i) => PermissionOutcome::Approved,
+                None if prompted.contains(&i) => PermissionOutcome::Pending,
+                _ => return None,
+            };
+            Some(PermissionEntry {
+                timestamp: tool.at.to_rfc3339(),
+                tool_name: tool.name.clone(),
+                detail: tool.detail.clone(),
+                outcome,
+            })
+        })
+        .collect()
+}
+
+fn tool_detail(input: &serde_json::Value) -> Option<String> {
+    ["command", "file_path", "notebook_path", "url", "pattern"]
+        .iter()
+        .find_map(|key| input.get(*key).and_then(|v| v.as_str()))
+        .map(String::from)
+}
+
+fn is_rejection(result: &serde_json::Value) -> bool {
+    let text = match result.get("content") {
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(serde_json::Value::Array(parts)) => parts
+            .iter()
+            .filter_map(|p| p.get("text").and_then(|t| t.as_str()))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => return false,
+    };
+    REJECTION_MARKERS.iter().any(|marker| text.contains(marker))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects::encode_project_path;
+    use crate::state::{append_transition, TransitionRecord};
+    use crate::types::SessionState;
+    use tempfile::TempDir;
+
+    fn tool_use(ts: &str, id: &str, name: &str, input: &str) -> String {
+        format!(
+            r#"{{"type":"assistant","timestamp":"{}","message":{{"role":"assistant","content":[{{"type":"tool_use","id":"{}","name":"{}","input":{}}}]}}}}"#,
+            ts, id, name, input
+        )
+    }
+
+    fn tool_result(ts: &str, id: &str, text: &str) -> String {
+        format!(
+            r#"{{"type":"user","timestamp":"{}","message":{{"role":"user","content":[{{"type":"tool_result","tool_use_id":"{}","content":"{}"}}]}}}}"#,
+            ts, id, text
+        )
+    }
+
+    fn at(ts: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(ts)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn fixture() -> String {
+        [
+            tool_use(
+                "2026-01-01T10:00:00Z",
+                "t1",
+                "Read",
+                r#"{"file_path":"/p/a.rs"}"#,
+            ),
+            tool_result("2026-01-01T10:00:01Z", "t1", "fn main() {}"),
+            tool_use(
+                "2026-01-01T10:01:00Z",
+                "t2",
+                "Bash",
+                r#"{"command":"cargo test"}"#,
+            ),
+            tool_result("2026-01-01T10:01:30Z", "t2", "test result: ok"),
+            tool_use(
+                "2026-01-01T10:02:00Z",
+                "t3",
+                "Bash",
+                r#"{"command":"rm -rf target"}"#,
+            ),
+            tool_result(
+                "2026-01-01T10:02:10Z",
+                "t3",
+                "The user doesn't want to proceed with this tool use. The tool use was rejected.",
+            ),
+            tool_use(
+                "2026-01-01T10:03:00Z",
+                "t4",
+                "WebFetch",
+                r#"{"url":"https://x.dev"}"#,
+            ),
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn extracts_prompts_and_denials_in_order() {
+        let prompts = [
+            at("2026-01-01T10:01:05Z"),
+            at("2026-01-01T10:02:05Z"),
+            at("2026-01-01T10:03:01Z"),
+        ];
+        let entries = permission_history_from_content(&fixture(), &prompts);
+
+        let summary: Vec<_> = entries
+            .iter()
+            .map(|e| (e.tool_name.as_str(), e.detail.as_deref(), e.outcome))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Bash", Some("cargo test"), PermissionOutcome::Approved),
+                ("Bash", Some("rm -rf target"), PermissionOutcome::Denied),
+                (
+                    "WebFetch",
+                    Some("https://x.dev"),
+                    PermissionOutcome::Pending
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn denials_are_found_without_logged_prompts() {
+        let entries = permission_history_from_content(&fixture(), &[]);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].outcome, PermissionOutcome::Denied);
+        assert_eq!(
+            entries[0].timestamp,
+            at("2026-01-01T10:02:00Z").to_rfc3339()
+        );
+    }
+
+    #[test]
+    fn reads_transcript_and_transitions_from_storage() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/p"));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("s1.jsonl"), fixture()).unwrap();
+        append_transition(
+            &storage.transitions_file(),
+            &TransitionRecord {
+                session_id: "s1".to_string(),
+                cwd: "/p".to_string(),
+                from: Some(SessionState::Working),
+                to: SessionState::Waiting,
+                event: Some(PERMISSION_REQUEST_EVENT.to_string()),
+                at: at("2026-01-01T10:01:05Z"),
+            },
+        )
+        .unwrap();
+
+        let entries = permission_history_with_storage(&storage, "s1", "/p");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].outcome, PermissionOutcome::Approved);
+        assert!(permission_history_with_storage(&storage, "missing", "/p").is_empty());
+    }
+
+    #[test]
+    fn ranks_sessions_by_permission_prompts() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/p"));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("busy.jsonl"), fixture()).unwrap();
+
+        let prompt = |session_id: &str, cwd: &str, ts: &str| {
+            append_transition(
+                &storage.transitions_file(),
+                &TransitionRecord {
+                    session_id: session_id.to_string(),
+                    cwd: cwd.to_string(),
+                    from: Some(SessionState::Working),
+                    to: SessionState::Waiting,
+                    event: Some(PERMISSION_REQUEST_EVENT.to_string()),
+                    at: at(ts),
+                },
+            )
+            .unwrap();
+        };
+        prompt("quiet", "/q", "2026-01-01T09:00:00Z");
+        for ts in [
+            "2026-01-01T10:01:05Z",
+            "2026-01-01T10:01:10Z",
+            "2026-01-01T10:02:05Z",
+            "2026-01-01T10:02:06Z",
+            "2026-01-01T10:03:01Z",
+        ] {
+            prompt("busy", "/p", ts);
+        }
+
+        let sessions = permission_heavy_sessions_with_storage(&storage, 10);
+        let ranking: Vec<_> = sessions
+            .iter()
+            .map(|s| (s.session_id.as_str(), s.prompt_count))
+            .collect();
+        assert_eq!(ranking, vec![("busy", 5), ("quiet", 1)]);
+        assert_eq!(sessions[0].project_path, "/p");
+        assert_eq!(sessions[0].top_tools, vec!["Bash", "WebFetch"]);
+        assert!(sessions[1].top_tools.is_empty());
+
+        assert_eq!(permission_heavy_sessions_with_storage(&storage, 1).len(), 1);
+    }
+}
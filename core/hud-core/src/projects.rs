@@ -5,15 +5,24 @@
 //! - Building project metadata from paths
 //! - Loading pinned projects with statistics
 
+use crate::activity::ActivityStore;
 use crate::config::{
-    load_hud_config_with_storage, load_stats_cache_with_storage, save_stats_cache_with_storage,
+    load_hud_config_with_storage, load_stats_cache_with_storage, save_hud_config_with_storage,
+    save_stats_cache_with_storage,
 };
+use crate::state::{find_all_locks_for_path, normalize_path_for_matching};
 use crate::stats::compute_project_stats;
-use crate::storage::StorageConfig;
-use crate::types::{Project, StatsCache};
+use crate::storage::{resolve_legacy_encoded_path, StorageConfig};
+use crate::tasks::group_continued_sessions_with_storage;
+use crate::types::{Project, ProjectDetails, StatsCache};
 use fs_err as fs;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
+use walkdir::WalkDir;
+
+const CLAUDE_MD: &str = "CLAUDE.md";
+const AGENTS_MD: &str = "AGENTS.md";
 
 // Heuristic markers only; absence does not mean a directory is not a project.
 /// Project type indicators - files that suggest a directory is a code project.
@@ -44,11 +53,101 @@ pub fn has_project_indicators(project_path: &Path) -> bool {
         .any(|indicator| project_path.join(indicator).exists())
 }
 
+/// Weighted project signals for [`classify_directory`]. Version control and
+/// language manifests are strong evidence; build helpers like a Makefile are weak.
+const CLASSIFICATION_WEIGHTS: &[(&str, u32)] = &[
+    (".git", 40),
+    ("package.json", 30),
+    ("Cargo.toml", 30),
+    ("pyproject.toml", 30),
+    ("go.mod", 30),
+    ("Gemfile", 30),
+    ("build.gradle", 30),
+    ("pom.xml", 30),
+    ("composer.json", 30),
+    ("mix.exs", 30),
+    ("pubspec.yaml", 30),
+    (CLAUDE_MD, 20),
+    (AGENTS_MD, 20),
+    ("requirements.txt", 15),
+    ("tsconfig.json", 15),
+    ("CMakeLists.txt", 15),
+    (".gitignore", 10),
+    ("Makefile", 5),
+];
+
+/// Weight for Claude sessions in the directory within [`RECENT_ACTIVITY_DAYS`].
+const RECENT_ACTIVITY_WEIGHT: u32 = 15;
+const RECENT_ACTIVITY_DAYS: u64 = 14;
+const RECENT_ACTIVITY_INDICATOR: &str = "recent activity";
+
+/// How project-like a directory looks.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, uniffi::Record)]
+pub struct DirectoryClassification {
+    /// 0–100; higher means more confidently a project
+    pub score: u32,
+    /// Signals found, strongest first (file names, or "recent activity")
+    pub indicators: Vec<String>,
+}
+
+/// Scores a directory by the project signals it contains.
+pub fn classify_directory(path: &str) -> DirectoryClassification {
+    classify_directory_with_storage(&StorageConfig::default(), path)
+}
+
+pub fn classify_directory_with_storage(
+    storage: &StorageConfig,
+    path: &str,
+) -> DirectoryClassification {
+    let dir = Path::new(path);
+    let mut score = 0;
+    let mut indicators = Vec::new();
+
+    for (name, weight) in CLASSIFICATION_WEIGHTS {
+        if dir.join(name).exists() {
+            score += weight;
+            indicators.push(name.to_string());
+        }
+    }
+
+    let recent_cutoff =
+        SystemTime::now() - std::time::Duration::from_secs(RECENT_ACTIVITY_DAYS * 86_400);
+    let has_recent_activity = fs::read_dir(
+        storage
+            .claude_projects_dir()
+            .join(encode_project_path(path)),
+    )
+    .into_iter()
+    .flatten()
+    .flatten()
+    .filter(|e| e.path().extension().is_some_and(|ext| ext == "jsonl"))
+    .any(|e| {
+        e.metadata()
+            .and_then(|m| m.modified())
+            .is_ok_and(|t| t >= recent_cutoff)
+    });
+    if has_recent_activity {
+        score += RECENT_ACTIVITY_WEIGHT;
+        indicators.push(RECENT_ACTIVITY_INDICATOR.to_string());
+    }
+
+    DirectoryClassification {
+        score: score.min(100),
+        indicators,
+    }
+}
+
 /// Formats a SystemTime as a human-readable relative time string.
+///
+/// Times up to a minute ahead (ordinary clock skew) read as "just now"; anything
+/// further ahead reads as "in the future".
 pub fn format_relative_time(system_time: SystemTime) -> String {
     let now = SystemTime::now();
-    let duration = now.duration_since(system_time).unwrap_or_default();
-    let secs = duration.as_secs();
+    let secs = match now.duration_since(system_time) {
+        Ok(duration) => duration.as_secs(),
+        Err(ahead) if ahead.duration().as_secs() < 60 => 0,
+        Err(_) => return "in the future".to_string(),
+    };
 
     if secs < 60 {
         "just now".to_string()
@@ -73,13 +172,27 @@ pub fn format_relative_time(system_time: SystemTime) -> String {
         } else {
             format!("{} days ago", days)
         }
-    } else {
+    } else if secs < 30 * 86400 {
         let weeks = secs / 604800;
         if weeks == 1 {
             "1 week ago".to_string()
         } else {
             format!("{} weeks ago", weeks)
         }
+    } else if secs < 365 * 86400 {
+        let months = secs / (30 * 86400);
+        if months == 1 {
+            "1 month ago".to_string()
+        } else {
+            format!("{} months ago", months)
+        }
+    } else {
+        let years = secs / (365 * 86400);
+        if years == 1 {
+            "1 year ago".to_string()
+        } else {
+            format!("{} years ago", years)
+        }
     }
 }
 
@@ -94,6 +207,17 @@ pub fn get_claude_md_preview(path: &Path) -> Option<String> {
     }
 }
 
+/// Finds the project's agent instructions file.
+///
+/// Prefers CLAUDE.md; falls back to AGENTS.md (the cross-agent convention)
+/// when CLAUDE.md is absent.
+pub fn find_instructions_file(project_path: &Path) -> Option<PathBuf> {
+    [CLAUDE_MD, AGENTS_MD]
+        .iter()
+        .map(|name| project_path.join(name))
+        .find(|path| path.exists())
+}
+
 /// Counts JSONL session files in a project directory.
 pub fn count_tasks_in_project(claude_projects_dir: &Path, encoded_name: &str) -> u32 {
     let project_dir = claude_projects_dir.join(encoded_name);
@@ -116,40 +240,360 @@ pub fn encode_project_path(path: &str) -> String {
     path.replace('/', "-")
 }
 
+/// Path to a session's transcript under `~/.claude/projects/`.
+///
+/// Returns None for session IDs that could escape the project directory.
+pub fn session_transcript_path(
+    storage: &StorageConfig,
+    project_path: &str,
+    session_id: &str,
+) -> Option<PathBuf> {
+    if session_id.is_empty() || session_id.contains(['/', '\\']) || session_id.contains("..") {
+        return None;
+    }
+    Some(
+        storage
+            .claude_projects_dir()
+            .join(encode_project_path(project_path))
+            .join(format!("{}.jsonl", session_id)),
+    )
+}
+
+/// Lists folders in a Claude projects directory, newest mtime first.
+///
+/// With `max_folders`, only that many of the most recent folders are returned,
+/// bounding scan cost on installs with hundreds of project folders.
+pub fn recent_claude_project_folders(
+    projects_dir: &Path,
+    max_folders: Option<u32>,
+) -> Vec<PathBuf> {
+    let mut folders: Vec<(SystemTime, PathBuf)> = fs::read_dir(projects_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|e| {
+            let mtime = e
+                .metadata()
+                .and_then(|m| m.modified())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            (mtime, e.path())
+        })
+        .collect();
+    folders.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    if let Some(max) = max_folders {
+        folders.truncate(max as usize);
+    }
+    folders.into_iter().map(|(_, path)| path).collect()
+}
+
 /// Attempts to resolve an encoded project path back to a real path.
+///
+/// Hyphens are ambiguous in the encoding, so the filesystem is probed: starting
+/// from the home directory when the name begins with it, and backtracking out
+/// of segment groupings that don't exist.
 pub fn try_resolve_encoded_path(encoded_name: &str) -> Option<String> {
-    if encoded_name.is_empty() || !encoded_name.starts_with('-') {
-        return None;
+    resolve_legacy_encoded_path(encoded_name, dirs::home_dir().as_deref())
+}
+
+/// A Claude projects folder whose decoded path disagrees with its transcripts.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, uniffi::Record)]
+pub struct EncodingMismatch {
+    /// Folder name under `~/.claude/projects/`
+    pub folder: String,
+    /// Working directory recorded in the folder's transcripts
+    pub transcript_cwd: String,
+    /// What `try_resolve_encoded_path` produced, None if it found nothing
+    pub decoded_path: Option<String>,
+}
+
+/// Result of auditing `try_resolve_encoded_path` against transcript data.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, uniffi::Record)]
+pub struct ProjectEncodingReport {
+    /// Folders with a transcript cwd to compare against
+    pub checked: u32,
+    /// Folders skipped because no transcript recorded a cwd
+    pub without_cwd: u32,
+    pub mismatches: Vec<EncodingMismatch>,
+}
+
+/// Audits the lossy folder-name decoder against the real cwd in each folder's
+/// transcripts. Read-only.
+pub fn verify_project_encoding() -> ProjectEncodingReport {
+    verify_project_encoding_with_storage(&StorageConfig::default())
+}
+
+pub fn verify_project_encoding_with_storage(storage: &StorageConfig) -> ProjectEncodingReport {
+    let mut report = ProjectEncodingReport::default();
+    let Ok(entries) = fs::read_dir(storage.claude_projects_dir()) else {
+        return report;
+    };
+
+    let mut folders: Vec<(String, PathBuf)> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+        .filter_map(|e| Some((e.file_name().to_str()?.to_string(), e.path())))
+        .collect();
+    folders.sort();
+
+    for (folder, dir) in folders {
+        let Some(transcript_cwd) = transcript_cwd(&dir) else {
+            report.without_cwd += 1;
+            continue;
+        };
+        report.checked += 1;
+
+        let decoded_path = try_resolve_encoded_path(&folder);
+        if decoded_path.as_deref() != Some(transcript_cwd.as_str()) {
+            report.mismatches.push(EncodingMismatch {
+                folder,
+                transcript_cwd,
+                decoded_path,
+            });
+        }
     }
 
-    let without_leading = &encoded_name[1..];
-    let parts: Vec<&str> = without_leading.split('-').collect();
+    report
+}
+
+/// Whether a pinned project still exists, and where it may have moved.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, uniffi::Record)]
+pub struct PinnedProjectAudit {
+    pub path: String,
+    pub exists: bool,
+    /// Best guess at the project's new location when it is missing
+    pub relocated_to: Option<String>,
+}
+
+/// Checks every pinned project against the filesystem.
+///
+/// For missing projects, guesses a new location: first a Claude transcript cwd
+/// with the same folder name, then a same-named folder one level under an
+/// existing ancestor (e.g. `~/Code/app` moved to `~/Code/archive/app`).
+pub fn audit_pinned_projects() -> Vec<PinnedProjectAudit> {
+    audit_pinned_projects_with_storage(&StorageConfig::default())
+}
 
-    for num_parts in 1..=parts.len() {
-        let prefix = parts[..num_parts].join("/");
-        let candidate = format!("/{}", prefix);
+pub fn audit_pinned_projects_with_storage(storage: &StorageConfig) -> Vec<PinnedProjectAudit> {
+    let config = load_hud_config_with_storage(storage);
+    let mut transcript_cwds: Option<Vec<String>> = None;
 
-        if PathBuf::from(&candidate).exists() {
-            if num_parts == parts.len() {
-                return Some(candidate);
+    config
+        .pinned_projects
+        .iter()
+        .map(|path| {
+            if Path::new(path).is_dir() {
+                return PinnedProjectAudit {
+                    path: path.clone(),
+                    exists: true,
+                    relocated_to: None,
+                };
+            }
+            let cwds = transcript_cwds.get_or_insert_with(|| all_transcript_cwds(storage));
+            PinnedProjectAudit {
+                path: path.clone(),
+                exists: false,
+                relocated_to: guess_relocation(path, cwds),
             }
+        })
+        .collect()
+}
+
+/// Unpins projects whose folder no longer exists. Returns the removed paths.
+pub fn prune_missing_projects() -> Result<Vec<String>, String> {
+    prune_missing_projects_with_storage(&StorageConfig::default())
+}
+
+pub fn prune_missing_projects_with_storage(storage: &StorageConfig) -> Result<Vec<String>, String> {
+    let mut config = load_hud_config_with_storage(storage);
+    let (kept, removed): (Vec<String>, Vec<String>) = config
+        .pinned_projects
+        .drain(..)
+        .partition(|p| Path::new(p).is_dir());
+    if removed.is_empty() {
+        return Ok(removed);
+    }
+
+    config.pinned_projects = kept;
+    save_hud_config_with_storage(storage, &config)?;
+    Ok(removed)
+}
+
+/// Sets a project's display name. An empty (or whitespace-only) alias clears
+/// it, so the name falls back to the folder name.
+pub fn set_project_alias(path: &str, alias: &str) -> Result<(), String> {
+    set_project_alias_with_storage(&StorageConfig::default(), path, alias)
+}
+
+pub fn set_project_alias_with_storage(
+    storage: &StorageConfig,
+    path: &str,
+    alias: &str,
+) -> Result<(), String> {
+    let mut config = load_hud_config_with_storage(storage);
+    let alias = alias.trim();
+    if alias.is_empty() {
+        config.project_aliases.remove(path);
+    } else {
+        config
+            .project_aliases
+            .insert(path.to_string(), alias.to_string());
+    }
+    save_hud_config_with_storage(storage, &config)
+}
+
+/// Replaces the pinned projects' order and switches the list to manual order.
+///
+/// `paths` must contain exactly the currently pinned projects, so a stale UI
+/// can't unpin or add projects by reordering.
+pub fn reorder_projects(paths: &[String]) -> Result<(), String> {
+    reorder_projects_with_storage(&StorageConfig::default(), paths)
+}
+
+pub fn reorder_projects_with_storage(
+    storage: &StorageConfig,
+    paths: &[String],
+) -> Result<(), String> {
+    let mut config = load_hud_config_with_storage(storage);
+
+    let mut requested: Vec<&String> = paths.iter().collect();
+    let mut pinned: Vec<&String> = config.pinned_projects.iter().collect();
+    requested.sort();
+    pinned.sort();
+    if requested != pinned {
+        return Err("Reordered paths must match the pinned projects exactly".to_string());
+    }
+
+    config.pinned_projects = paths.to_vec();
+    config.manual_order = true;
+    save_hud_config_with_storage(storage, &config)
+}
+
+/// Sets whether pinned projects keep their manual order or sort by activity.
+pub fn set_manual_order(enabled: bool) -> Result<(), String> {
+    set_manual_order_with_storage(&StorageConfig::default(), enabled)
+}
+
+pub fn set_manual_order_with_storage(storage: &StorageConfig, enabled: bool) -> Result<(), String> {
+    let mut config = load_hud_config_with_storage(storage);
+    config.manual_order = enabled;
+    save_hud_config_with_storage(storage, &config)
+}
+
+/// Two pinned projects where one contains the other, e.g. `repo` and `repo/app`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, uniffi::Record)]
+pub struct OverlappingPins {
+    pub ancestor: String,
+    pub descendant: String,
+}
+
+/// Finds pinned projects nested inside other pinned projects.
+///
+/// Sessions in a subdirectory already count toward the enclosing project, so
+/// pinning both splits one repo's state across two cards; the UI can suggest
+/// keeping just one. Each nested pair is reported, in pin order of the ancestor.
+pub fn find_overlapping_pins() -> Vec<OverlappingPins> {
+    find_overlapping_pins_with_storage(&StorageConfig::default())
+}
 
-            let suffix = parts[num_parts..].join("-");
-            let full_candidate = format!("{}/{}", candidate, suffix);
-            if PathBuf::from(&full_candidate).exists() {
-                return Some(full_candidate);
+pub fn find_overlapping_pins_with_storage(storage: &StorageConfig) -> Vec<OverlappingPins> {
+    let pinned = load_hud_config_with_storage(storage).pinned_projects;
+    let normalized: Vec<String> = pinned
+        .iter()
+        .map(|p| normalize_path_for_matching(p))
+        .collect();
+
+    let mut overlaps = Vec::new();
+    for (i, ancestor) in normalized.iter().enumerate() {
+        let prefix = if ancestor == "/" {
+            ancestor.clone()
+        } else {
+            format!("{}/", ancestor)
+        };
+        for (j, descendant) in normalized.iter().enumerate() {
+            if i != j && descendant.starts_with(&prefix) {
+                overlaps.push(OverlappingPins {
+                    ancestor: pinned[i].clone(),
+                    descendant: pinned[j].clone(),
+                });
             }
         }
     }
+    overlaps
+}
+
+fn all_transcript_cwds(storage: &StorageConfig) -> Vec<String> {
+    fs::read_dir(storage.claude_projects_dir())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|e| transcript_cwd(&e.path()))
+        .collect()
+}
+
+fn guess_relocation(missing: &str, transcript_cwds: &[String]) -> Option<String> {
+    let missing_path = Path::new(missing);
+    let name = missing_path.file_name()?;
+
+    let from_transcripts = transcript_cwds
+        .iter()
+        .filter(|cwd| cwd.as_str() != missing)
+        .find(|cwd| {
+            let cwd = Path::new(cwd);
+            cwd.file_name() == Some(name) && cwd.is_dir()
+        });
+    if let Some(cwd) = from_transcripts {
+        return Some(cwd.clone());
+    }
 
-    None
+    let ancestor = missing_path.ancestors().skip(1).find(|a| a.is_dir())?;
+    let mut siblings: Vec<PathBuf> = fs::read_dir(ancestor)
+        .ok()?
+        .flatten()
+        .map(|e| e.path().join(name))
+        .filter(|candidate| candidate.is_dir())
+        .collect();
+    siblings.sort();
+    siblings
+        .into_iter()
+        .next()
+        .map(|p| p.to_string_lossy().to_string())
+}
+
+/// First `cwd` recorded in any transcript in a Claude projects folder.
+fn transcript_cwd(dir: &Path) -> Option<String> {
+    use std::io::{BufRead, BufReader};
+
+    let mut transcripts: Vec<PathBuf> = fs::read_dir(dir)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "jsonl"))
+        .collect();
+    transcripts.sort();
+
+    transcripts.iter().find_map(|path| {
+        let file = fs::File::open(path).ok()?;
+        BufReader::new(file)
+            .lines()
+            .map_while(|l| l.ok())
+            .find_map(|line| {
+                let value: serde_json::Value = serde_json::from_str(&line).ok()?;
+                value.get("cwd")?.as_str().map(String::from)
+            })
+    })
 }
 
 /// Builds a Project from a filesystem path.
+///
+/// `max_parse_bytes` is passed through to [`compute_project_stats`]. `alias`,
+/// when set, is used as the project name instead of the last path segment.
 pub fn build_project_from_path(
     path: &str,
     claude_dir: &Path,
     stats_cache: &mut StatsCache,
+    max_parse_bytes: Option<u64>,
+    alias: Option<&str>,
 ) -> Option<Project> {
     let project_path = PathBuf::from(path);
     if !project_path.exists() {
@@ -168,7 +612,7 @@ pub fn build_project_from_path(
         path.to_string()
     };
 
-    let project_name = path.split('/').next_back().unwrap_or(path).to_string();
+    let project_name = project_name(path, alias);
 
     let claude_project_dir = projects_dir.join(&encoded_name);
 
@@ -195,41 +639,53 @@ pub fn build_project_from_path(
     }
     let last_active = most_recent_mtime.map(format_relative_time);
 
-    let claude_md_path = project_path.join("CLAUDE.md");
-    let claude_md_exists = claude_md_path.exists();
-    let claude_md_preview = if claude_md_exists {
-        get_claude_md_preview(&claude_md_path)
-    } else {
-        None
-    };
+    let instructions_path = find_instructions_file(&project_path);
+    let claude_md_preview = instructions_path.as_deref().and_then(get_claude_md_preview);
+    let has_agents_md = project_path.join(AGENTS_MD).exists();
 
-    let local_settings_path = project_path.join(".claude").join("settings.local.json");
-    let has_local_settings = local_settings_path.exists();
+    let has_local_settings = local_settings_path(&project_path).exists();
 
     let task_count = count_tasks_in_project(&projects_dir, &encoded_name);
 
-    let stats = compute_project_stats(&projects_dir, &encoded_name, stats_cache, path);
+    let stats = compute_project_stats(
+        &projects_dir,
+        &encoded_name,
+        stats_cache,
+        path,
+        max_parse_bytes,
+    );
 
     Some(Project {
         name: project_name,
         path: path.to_string(),
         display_path,
         last_active,
-        claude_md_path: if claude_md_exists {
-            Some(claude_md_path.to_string_lossy().to_string())
-        } else {
-            None
-        },
+        claude_md_path: instructions_path
+            .as_ref()
+            .map(|p| p.to_string_lossy().to_string()),
         claude_md_preview,
         has_local_settings,
         task_count,
         stats: Some(stats),
         is_missing: false,
+        instructions_file: instructions_path
+            .as_ref()
+            .and_then(|p| p.file_name())
+            .map(|n| n.to_string_lossy().to_string()),
+        has_agents_md,
     })
 }
 
+/// Display name for a project: its alias if set, otherwise the last path segment.
+pub(crate) fn project_name(path: &str, alias: Option<&str>) -> String {
+    alias
+        .filter(|a| !a.is_empty())
+        .unwrap_or_else(|| path.split('/').next_back().unwrap_or(path))
+        .to_string()
+}
+
 /// Builds a minimal Project for a path that no longer exists on disk.
-fn build_missing_project(path: &str) -> Project {
+fn build_missing_project(path: &str, alias: Option<&str>) -> Project {
     let display_path = if path.starts_with("/Users/") {
         format!(
             "~/{}",
@@ -239,10 +695,8 @@ fn build_missing_project(path: &str) -> Project {
         path.to_string()
     };
 
-    let project_name = path.split('/').next_back().unwrap_or(path).to_string();
-
     Project {
-        name: project_name,
+        name: project_name(path, alias),
         path: path.to_string(),
         display_path,
         last_active: None,
@@ -252,10 +706,13 @@ fn build_missing_project(path: &str) -> Project {
         task_count: 0,
         stats: None,
         is_missing: true,
+        instructions_file: None,
+        has_agents_md: false,
     }
 }
 
-/// Loads all pinned projects, sorted by most recent activity.
+/// Loads all pinned projects, sorted by most recent activity, or in pinned
+/// order when `HudConfig.manual_order` is set.
 /// Missing projects (where the directory no longer exists) are included
 /// with is_missing=true so they can be displayed with a warning indicator.
 pub fn load_projects() -> Result<Vec<Project>, String> {
@@ -271,10 +728,17 @@ pub fn load_projects_with_storage(storage: &StorageConfig) -> Result<Vec<Project
     let mut projects: Vec<(Project, SystemTime)> = Vec::new();
 
     for path in &config.pinned_projects {
-        let project = if let Some(p) = build_project_from_path(path, claude_dir, &mut stats_cache) {
+        let alias = config.project_aliases.get(path).map(String::as_str);
+        let project = if let Some(p) = build_project_from_path(
+            path,
+            claude_dir,
+            &mut stats_cache,
+            config.max_parse_bytes,
+            alias,
+        ) {
             p
         } else {
-            build_missing_project(path)
+            build_missing_project(path, alias)
         };
 
         let encoded_name = encode_project_path(path);
@@ -289,7 +753,1205 @@ pub fn load_projects_with_storage(storage: &StorageConfig) -> Result<Vec<Project
 
     let _ = save_stats_cache_with_storage(storage, &stats_cache);
 
-    projects.sort_by(|a, b| b.1.cmp(&a.1));
+    if !config.manual_order {
+        projects.sort_by_key(|p| std::cmp::Reverse(p.1));
+    }
 
     Ok(projects.into_iter().map(|(p, _)| p).collect())
 }
+
+/// Loads one project with its instructions content, tasks, and git state.
+pub fn load_project_details(path: &str) -> Result<ProjectDetails, String> {
+    load_project_details_with_storage(&StorageConfig::default(), path)
+}
+
+pub fn load_project_details_with_storage(
+    storage: &StorageConfig,
+    path: &str,
+) -> Result<ProjectDetails, String> {
+    let config = load_hud_config_with_storage(storage);
+    let alias = config.project_aliases.get(path).map(String::as_str);
+    let mut stats_cache = load_stats_cache_with_storage(storage);
+    let project = build_project_from_path(
+        path,
+        storage.claude_root(),
+        &mut stats_cache,
+        config.max_parse_bytes,
+        alias,
+    )
+    .ok_or_else(|| format!("Project not found: {}", path))?;
+    let _ = save_stats_cache_with_storage(storage, &stats_cache);
+
+    let claude_md_content =
+        find_instructions_file(Path::new(path)).and_then(|file| fs::read_to_string(file).ok());
+    let tasks = group_continued_sessions_with_storage(storage, path)
+        .into_iter()
+        .map(|group| group.task)
+        .collect();
+
+    let (git_ahead, git_behind, git_has_upstream) = match git_ahead_behind(path) {
+        GitUpstream::Tracking { ahead, behind } => (ahead, behind, true),
+        GitUpstream::NoUpstream | GitUpstream::NotARepo => (0, 0, false),
+    };
+
+    Ok(ProjectDetails {
+        project,
+        claude_md_content,
+        tasks,
+        git_branch: git_branch(path),
+        git_head_commit: git_head_commit(path),
+        git_dirty: git_is_dirty(path),
+        git_stash_count: git_stash_count(path),
+        git_ahead,
+        git_behind,
+        git_has_upstream,
+    })
+}
+
+/// Permanently deletes a project's Claude conversation history.
+///
+/// Removes `~/.claude/projects/<encoded>` along with the project's stats-cache
+/// and file-activity entries. This is a deliberate, user-confirmed exception to
+/// sidecar purity, so it is guarded strictly:
+/// - `confirm_token` must equal the project name (last path component)
+/// - refuses while a live session lock exists for the project
+/// - only deletes a direct child of the Claude projects directory
+///
+/// Returns the number of bytes reclaimed.
+pub fn delete_project_history(path: &str, confirm_token: &str) -> Result<u64, String> {
+    delete_project_history_with_storage(&StorageConfig::default(), path, confirm_token)
+}
+
+pub fn delete_project_history_with_storage(
+    storage: &StorageConfig,
+    path: &str,
+    confirm_token: &str,
+) -> Result<u64, String> {
+    let project_name = path.trim_end_matches('/').rsplit('/').next().unwrap_or("");
+    if project_name.is_empty() || confirm_token != project_name {
+        return Err(format!(
+            "Confirmation token does not match project name '{}'",
+            project_name
+        ));
+    }
+
+    if !find_all_locks_for_path(&storage.sessions_dir(), path).is_empty() {
+        return Err(format!(
+            "Refusing to delete history while a session is running in {}",
+            path
+        ));
+    }
+
+    let projects_dir = storage.claude_projects_dir();
+    let encoded_name = encode_project_path(path);
+    if encoded_name.is_empty() || encoded_name.contains("..") {
+        return Err(format!("Invalid project path: {}", path));
+    }
+    let history_dir = projects_dir.join(&encoded_name);
+
+    let mut bytes_reclaimed = 0;
+    if history_dir.exists() {
+        let canonical_parent = fs::canonicalize(&history_dir)
+            .ok()
+            .and_then(|p| p.parent().map(Path::to_path_buf));
+        let canonical_projects_dir = fs::canonicalize(&projects_dir).ok();
+        if canonical_parent.is_none() || canonical_parent != canonical_projects_dir {
+            return Err(format!(
+                "Refusing to delete {}: not inside {}",
+                history_dir.display(),
+                projects_dir.display()
+            ));
+        }
+
+        bytes_reclaimed = WalkDir::new(&history_dir)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .filter_map(|e| e.metadata().ok())
+            .map(|m| m.len())
+            .sum();
+
+        fs::remove_dir_all(&history_dir)
+            .map_err(|e| format!("Failed to delete project history: {}", e))?;
+    }
+
+    let mut stats_cache = load_stats_cache_with_storage(storage);
+    if stats_cache.projects.remove(path).is_some() {
+        save_stats_cache_with_storage(storage, &stats_cache)?;
+    }
+
+    let activity_file = storage.file_activity_file();
+    let mut activity = ActivityStore::load(&activity_file);
+    let normalized = normalize_path_for_matching(path);
+    let mut activity_changed = false;
+    for session in activity.sessions.values_mut() {
+        let before = session.activity.len();
+        session
+            .activity
+            .retain(|a| normalize_path_for_matching(&a.project_path) != normalized);
+        activity_changed |= session.activity.len() != before;
+    }
+    if activity_changed {
+        activity.sessions.retain(|_, s| !s.activity.is_empty());
+        activity.save(&activity_file).map_err(String::from)?;
+    }
+
+    Ok(bytes_reclaimed)
+}
+
+/// Pinned projects that share an ancestor directory.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, uniffi::Record)]
+pub struct ProjectGroup {
+    /// Shared ancestor directory
+    pub root: String,
+    /// Member project paths, in pinned order
+    pub project_paths: Vec<String>,
+}
+
+/// Groups pinned projects by their ancestor `depth` levels below the home
+/// directory (or below `/` for paths outside home).
+///
+/// With depth 2, `~/Code/work/api` and `~/Code/work/web` share the root
+/// `~/Code/work`. A project is never its own root: shallower paths group under
+/// their parent. Groups are sorted by root.
+pub fn group_projects_by_root(depth: u32) -> Vec<ProjectGroup> {
+    group_projects_by_root_with_storage(&StorageConfig::default(), depth)
+}
+
+pub fn group_projects_by_root_with_storage(
+    storage: &StorageConfig,
+    depth: u32,
+) -> Vec<ProjectGroup> {
+    let config = load_hud_config_with_storage(storage);
+    group_project_paths(&config.pinned_projects, dirs::home_dir().as_deref(), depth)
+}
+
+/// Pure grouping over a list of paths; see [`group_projects_by_root`].
+pub fn group_project_paths(paths: &[String], home: Option<&Path>, depth: u32) -> Vec<ProjectGroup> {
+    let mut groups: Vec<ProjectGroup> = Vec::new();
+
+    for path in paths {
+        let project = Path::new(path);
+        let base = home
+            .filter(|h| project.starts_with(h) && project != *h)
+            .unwrap_or_else(|| Path::new("/"));
+        let Ok(relative) = project.strip_prefix(base) else {
+            continue;
+        };
+
+        let components: Vec<_> = relative.components().collect();
+        let take = (depth as usize).min(components.len().saturating_sub(1));
+        let root = components[..take]
+            .iter()
+            .fold(base.to_path_buf(), |acc, c| acc.join(c))
+            .to_string_lossy()
+            .to_string();
+
+        match groups.iter_mut().find(|g| g.root == root) {
+            Some(group) => group.project_paths.push(path.clone()),
+            None => groups.push(ProjectGroup {
+                root,
+                project_paths: vec![path.clone()],
+            }),
+        }
+    }
+
+    groups.sort_by(|a, b| a.root.cmp(&b.root));
+    groups
+}
+
+/// Per-project plugin switches from the `enabledPlugins` maps in the project's
+/// `.claude/settings.json` and `.claude/settings.local.json` (local wins).
+pub fn project_plugin_overrides(project_path: &Path) -> HashMap<String, bool> {
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Settings {
+        enabled_plugins: Option<HashMap<String, bool>>,
+    }
+
+    let claude_dir = project_path.join(".claude");
+    ["settings.json", "settings.local.json"]
+        .iter()
+        .filter_map(|name| fs::read_to_string(claude_dir.join(name)).ok())
+        .filter_map(|content| serde_json::from_str::<Settings>(&content).ok())
+        .flat_map(|settings| settings.enabled_plugins.unwrap_or_default())
+        .collect()
+}
+
+/// Path to a project's `.claude/settings.local.json`.
+fn local_settings_path(project_path: &Path) -> PathBuf {
+    project_path.join(".claude").join("settings.local.json")
+}
+
+/// Returns the project's `settings.local.json` for the client to open in an editor,
+/// creating it (and `.claude/`) as `{}` if absent. Existing files are left untouched.
+pub fn open_local_settings(path: &str) -> Result<String, String> {
+    let project_path = Path::new(path);
+    if !project_path.is_dir() {
+        return Err(format!("Project folder not found: {}", path));
+    }
+
+    let settings_path = local_settings_path(project_path);
+    if !settings_path.exists() {
+        if let Some(parent) = settings_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create .claude directory: {}", e))?;
+        }
+        fs::write(&settings_path, "{}\n")
+            .map_err(|e| format!("Failed to create settings.local.json: {}", e))?;
+    }
+
+    Ok(settings_path.to_string_lossy().to_string())
+}
+
+/// Which config file [`open_project_config`] chose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, uniffi::Enum)]
+pub enum ProjectConfigKind {
+    /// The project's `CLAUDE.md`
+    Instructions,
+    /// The project's `.claude/settings.local.json`
+    LocalSettings,
+    /// `~/.claude/settings.json`
+    GlobalSettings,
+}
+
+/// The config file to open for a project's "edit config" action.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, uniffi::Record)]
+pub struct ProjectConfigFile {
+    pub path: String,
+    pub kind: ProjectConfigKind,
+}
+
+/// Picks the most relevant config file for a project: its `CLAUDE.md`, else its
+/// `settings.local.json`, else the global `settings.json`.
+///
+/// With `create_local_settings`, a project without either file gets a `{}`
+/// `settings.local.json` instead of falling back to global settings. The client
+/// opens the returned path.
+pub fn open_project_config(
+    path: &str,
+    create_local_settings: bool,
+) -> Result<ProjectConfigFile, String> {
+    open_project_config_with_storage(&StorageConfig::default(), path, create_local_settings)
+}
+
+pub fn open_project_config_with_storage(
+    storage: &StorageConfig,
+    path: &str,
+    create_local_settings: bool,
+) -> Result<ProjectConfigFile, String> {
+    let project_path = Path::new(path);
+    if !project_path.is_dir() {
+        return Err(format!("Project folder not found: {}", path));
+    }
+
+    let claude_md = project_path.join(CLAUDE_MD);
+    if claude_md.is_file() {
+        return Ok(ProjectConfigFile {
+            path: claude_md.to_string_lossy().to_string(),
+            kind: ProjectConfigKind::Instructions,
+        });
+    }
+    if local_settings_path(project_path).is_file() || create_local_settings {
+        return Ok(ProjectConfigFile {
+            path: open_local_settings(path)?,
+            kind: ProjectConfigKind::LocalSettings,
+        });
+    }
+
+    let global = storage.claude_settings_file();
+    if !global.is_file() {
+        return Err(format!("No config file found for {}", path));
+    }
+    Ok(ProjectConfigFile {
+        path: global.to_string_lossy().to_string(),
+        kind: ProjectConfigKind::GlobalSettings,
+    })
+}
+
+/// Whether a directory is inside a git repository, and where its git dir is.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, uniffi::Record)]
+pub struct GitRepoCheck {
+    pub is_repo: bool,
+    /// `.git` itself, or the directory a worktree/submodule `.git` file points to
+    pub git_dir: Option<String>,
+}
+
+/// Checks for a `.git` directory or `gitdir:` file at `path` or the nearest
+/// ancestor that has one, without spawning git, so subdirectories of a
+/// repository count too.
+///
+/// A `.git` file (worktrees, submodules) only counts when its pointer resolves to
+/// an existing directory; relative pointers are resolved against the directory
+/// holding the `.git` file.
+pub fn is_git_repo(path: &str) -> GitRepoCheck {
+    let git_dir = Path::new(path).ancestors().find_map(|dir| {
+        let dot_git = dir.join(".git");
+        if dot_git.is_dir() {
+            Some(dot_git)
+        } else if dot_git.is_file() {
+            fs::read_to_string(&dot_git).ok().and_then(|content| {
+                let pointer = content.lines().next()?.strip_prefix("gitdir:")?.trim();
+                let target = dir.join(pointer);
+                target.is_dir().then_some(target)
+            })
+        } else {
+            None
+        }
+    });
+    GitRepoCheck {
+        is_repo: git_dir.is_some(),
+        git_dir: git_dir.map(|dir| dir.to_string_lossy().to_string()),
+    }
+}
+
+/// The checked-out branch name, or `detached @ <short sha>` when HEAD points
+/// straight at a commit. Reads `.git/HEAD` without spawning git.
+///
+/// Returns None for non-repos and unreadable HEAD files.
+pub fn git_branch(path: &str) -> Option<String> {
+    let git_dir = PathBuf::from(is_git_repo(path).git_dir?);
+    let head = fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let head = head.trim();
+    match head.strip_prefix("ref:") {
+        Some(reference) => {
+            let reference = reference.trim();
+            Some(
+                reference
+                    .strip_prefix("refs/heads/")
+                    .unwrap_or(reference)
+                    .to_string(),
+            )
+        }
+        None => is_commit_sha(head).then(|| format!("detached @ {}", &head[..7])),
+    }
+}
+
+/// The full SHA HEAD resolves to, following a branch ref through its loose
+/// file or `packed-refs`. None for unborn branches and non-repos.
+pub fn git_head_commit(path: &str) -> Option<String> {
+    let git_dir = PathBuf::from(is_git_repo(path).git_dir?);
+    let head = fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let head = head.trim();
+    let Some(reference) = head.strip_prefix("ref:") else {
+        return is_commit_sha(head).then(|| head.to_string());
+    };
+    let reference = reference.trim();
+
+    // Linked worktrees keep shared refs in the main repository's git dir
+    let common_dir = fs::read_to_string(git_dir.join("commondir"))
+        .ok()
+        .map(|dir| git_dir.join(dir.trim()))
+        .unwrap_or_else(|| git_dir.clone());
+
+    [&git_dir, &common_dir]
+        .iter()
+        .find_map(|dir| fs::read_to_string(dir.join(reference)).ok())
+        .map(|sha| sha.trim().to_string())
+        .filter(|sha| is_commit_sha(sha))
+        .or_else(|| {
+            let packed = fs::read_to_string(common_dir.join("packed-refs")).ok()?;
+            packed.lines().find_map(|line| {
+                let (sha, name) = line.split_once(' ')?;
+                (name == reference && is_commit_sha(sha)).then(|| sha.to_string())
+            })
+        })
+}
+
+fn is_commit_sha(value: &str) -> bool {
+    value.len() >= 40 && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// How a project's branch relates to its upstream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitUpstream {
+    /// Not inside a git work tree, or git is unavailable
+    NotARepo,
+    /// A repository whose branch tracks no upstream (or HEAD is detached)
+    NoUpstream,
+    Tracking {
+        ahead: u32,
+        behind: u32,
+    },
+}
+
+/// Commits ahead of and behind the branch's upstream, from the `branch.ab`
+/// header of `git status --porcelain=v2 --branch`.
+pub fn git_ahead_behind(path: &str) -> GitUpstream {
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(path)
+        .args([
+            "status",
+            "--porcelain=v2",
+            "--branch",
+            "--untracked-files=no",
+        ])
+        .output();
+    let Some(output) = output.ok().filter(|o| o.status.success()) else {
+        return GitUpstream::NotARepo;
+    };
+    // "# branch.ab +<ahead> -<behind>", present only when the upstream resolves
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| {
+            let (ahead, behind) = line.strip_prefix("# branch.ab ")?.split_once(' ')?;
+            Some(GitUpstream::Tracking {
+                ahead: ahead.strip_prefix('+')?.parse().ok()?,
+                behind: behind.strip_prefix('-')?.parse().ok()?,
+            })
+        })
+        .unwrap_or(GitUpstream::NoUpstream)
+}
+
+/// Whether the work tree has uncommitted changes, untracked files included.
+/// False for non-repos.
+pub fn git_is_dirty(path: &str) -> bool {
+    std::process::Command::new("git")
+        .arg("-C")
+        .arg(path)
+        .args(["status", "--porcelain"])
+        .output()
+        .is_ok_and(|output| output.status.success() && !output.stdout.is_empty())
+}
+
+/// Counts `git stash list` entries for the project's repository.
+///
+/// Returns None when the path isn't inside a git work tree (or git is unavailable).
+pub fn git_stash_count(path: &str) -> Option<u32> {
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(path)
+        .args(["stash", "list"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).lines().count() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn days_ago(days: u64) -> SystemTime {
+        SystemTime::now() - Duration::from_secs(days * 86400)
+    }
+
+    #[test]
+    fn relative_time_has_month_and_year_buckets() {
+        assert_eq!(format_relative_time(days_ago(14)), "2 weeks ago");
+        assert_eq!(format_relative_time(days_ago(45)), "1 month ago");
+        assert_eq!(format_relative_time(days_ago(100)), "3 months ago");
+        assert_eq!(format_relative_time(days_ago(800)), "2 years ago");
+    }
+
+    #[test]
+    fn relative_time_handles_future_timestamps() {
+        let now = SystemTime::now();
+        assert_eq!(
+            format_relative_time(now + Duration::from_secs(10)),
+            "just now"
+        );
+        assert_eq!(
+            format_relative_time(now + Duration::from_secs(3600)),
+            "in the future"
+        );
+    }
+
+    #[test]
+    fn is_git_repo_accepts_dirs_and_valid_gitdir_files() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path().join("repo");
+        fs::create_dir_all(repo.join(".git/worktrees/wt")).unwrap();
+        let check = is_git_repo(repo.to_str().unwrap());
+        assert!(check.is_repo);
+        assert_eq!(check.git_dir.as_deref(), repo.join(".git").to_str());
+
+        let subdir = repo.join("src/nested");
+        fs::create_dir_all(&subdir).unwrap();
+        let check = is_git_repo(subdir.to_str().unwrap());
+        assert_eq!(check.git_dir.as_deref(), repo.join(".git").to_str());
+
+        let worktree = temp.path().join("wt");
+        fs::create_dir_all(&worktree).unwrap();
+        fs::write(worktree.join(".git"), "gitdir: ../repo/.git/worktrees/wt\n").unwrap();
+        let check = is_git_repo(worktree.to_str().unwrap());
+        assert!(check.is_repo);
+        assert!(check.git_dir.unwrap().ends_with("repo/.git/worktrees/wt"));
+
+        let dangling = temp.path().join("dangling");
+        fs::create_dir_all(&dangling).unwrap();
+        fs::write(
+            dangling.join(".git"),
+            "gitdir: /nonexistent/.git/worktrees/x",
+        )
+        .unwrap();
+        assert!(!is_git_repo(dangling.to_str().unwrap()).is_repo);
+
+        let plain = temp.path().join("plain");
+        fs::create_dir_all(&plain).unwrap();
+        assert_eq!(
+            is_git_repo(plain.to_str().unwrap()),
+            GitRepoCheck {
+                is_repo: false,
+                git_dir: None
+            }
+        );
+    }
+
+    #[test]
+    fn git_branch_reports_branches_and_detached_heads() {
+        let temp = TempDir::new().unwrap();
+        let git_dir = temp.path().join(".git");
+        fs::create_dir_all(git_dir.join("refs/heads")).unwrap();
+        let path = temp.path().to_str().unwrap();
+        let sha = "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678";
+
+        fs::write(git_dir.join("HEAD"), "ref: refs/heads/feature/x\n").unwrap();
+        assert_eq!(git_branch(path).as_deref(), Some("feature/x"));
+        // Unborn branch: no loose ref and no packed-refs
+        assert_eq!(git_head_commit(path), None);
+
+        fs::write(
+            git_dir.join("packed-refs"),
+            format!(
+                "# pack-refs with: peeled fully-peeled sorted\n{} refs/heads/feature/x\n",
+                sha
+            ),
+        )
+        .unwrap();
+        assert_eq!(git_head_commit(path).as_deref(), Some(sha));
+
+        fs::write(git_dir.join("HEAD"), format!("{}\n", sha)).unwrap();
+        assert_eq!(git_branch(path).as_deref(), Some("detached @ a1b2c3d"));
+        assert_eq!(git_head_commit(path).as_deref(), Some(sha));
+
+        let subdir = temp.path().join("src");
+        fs::create_dir_all(&subdir).unwrap();
+        assert_eq!(
+            git_branch(subdir.to_str().unwrap()).as_deref(),
+            Some("detached @ a1b2c3d")
+        );
+
+        let elsewhere = TempDir::new().unwrap();
+        assert_eq!(git_branch(elsewhere.path().to_str().unwrap()), None);
+    }
+
+    fn write_transcript(storage: &StorageConfig, folder: &str, cwd: &str) {
+        let dir = storage.claude_projects_dir().join(folder);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("s1.jsonl"),
+            format!(
+                "{{\"type\":\"summary\",\"summary\":\"x\"}}\n{{\"type\":\"user\",\"cwd\":\"{}\"}}\n",
+                cwd
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn group_project_paths_clusters_by_ancestor_depth() {
+        let home = Path::new("/home/u");
+        let paths: Vec<String> = [
+            "/home/u/a/b/api",
+            "/home/u/a/c/web",
+            "/home/u/a/b/cli",
+            "/opt/tools/x",
+        ]
+        .iter()
+        .map(|p| p.to_string())
+        .collect();
+
+        let groups = group_project_paths(&paths, Some(home), 2);
+        let summary: Vec<(&str, Vec<&str>)> = groups
+            .iter()
+            .map(|g| {
+                (
+                    g.root.as_str(),
+                    g.project_paths.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("/home/u/a/b", vec!["/home/u/a/b/api", "/home/u/a/b/cli"]),
+                ("/home/u/a/c", vec!["/home/u/a/c/web"]),
+                ("/opt/tools", vec!["/opt/tools/x"]),
+            ]
+        );
+
+        let shallow = group_project_paths(&paths[..3], Some(home), 1);
+        assert_eq!(shallow.len(), 1);
+        assert_eq!(shallow[0].root, "/home/u/a");
+    }
+
+    #[test]
+    fn open_local_settings_creates_file_when_absent() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().join("project");
+        fs::create_dir_all(&project).unwrap();
+
+        let path = open_local_settings(&project.to_string_lossy()).unwrap();
+        assert_eq!(PathBuf::from(&path), local_settings_path(&project));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{}\n");
+
+        fs::write(&path, r#"{"permissions":{}}"#).unwrap();
+        open_local_settings(&project.to_string_lossy()).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), r#"{"permissions":{}}"#);
+    }
+
+    #[test]
+    fn open_local_settings_rejects_missing_project() {
+        let temp = TempDir::new().unwrap();
+        assert!(open_local_settings(&temp.path().join("gone").to_string_lossy()).is_err());
+    }
+
+    #[test]
+    fn verify_project_encoding_flags_unresolvable_cwd() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+
+        let hyphenated = temp.path().join("my-work").join("app");
+        let removed = temp.path().join("old-work").join("app");
+        fs::create_dir_all(&hyphenated).unwrap();
+        let hyphenated = hyphenated.to_string_lossy().to_string();
+        let removed = removed.to_string_lossy().to_string();
+
+        write_transcript(&storage, &encode_project_path(&hyphenated), &hyphenated);
+        write_transcript(&storage, &encode_project_path(&removed), &removed);
+        fs::create_dir_all(storage.claude_projects_dir().join("-no-transcripts")).unwrap();
+
+        let report = verify_project_encoding_with_storage(&storage);
+        assert_eq!(report.checked, 2);
+        assert_eq!(report.without_cwd, 1);
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.mismatches[0].transcript_cwd, removed);
+        assert_eq!(report.mismatches[0].decoded_path, None);
+    }
+
+    #[test]
+    fn agents_md_is_used_when_claude_md_is_absent() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().join("project");
+        fs::create_dir_all(&project).unwrap();
+        fs::write(project.join("AGENTS.md"), "# Agents\nUse pnpm.").unwrap();
+
+        let mut cache = StatsCache::default();
+        let built = build_project_from_path(
+            &project.to_string_lossy(),
+            &temp.path().join("claude"),
+            &mut cache,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(built.instructions_file.as_deref(), Some("AGENTS.md"));
+        assert_eq!(
+            built.claude_md_preview.as_deref(),
+            Some("# Agents\nUse pnpm.")
+        );
+        assert!(built.claude_md_path.unwrap().ends_with("AGENTS.md"));
+        assert!(built.has_agents_md);
+    }
+
+    #[test]
+    fn claude_md_is_preferred_when_both_exist() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().join("project");
+        fs::create_dir_all(&project).unwrap();
+        fs::write(project.join("CLAUDE.md"), "claude").unwrap();
+        fs::write(project.join("AGENTS.md"), "agents").unwrap();
+
+        let mut cache = StatsCache::default();
+        let built = build_project_from_path(
+            &project.to_string_lossy(),
+            &temp.path().join("claude"),
+            &mut cache,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(built.instructions_file.as_deref(), Some("CLAUDE.md"));
+        assert_eq!(built.claude_md_preview.as_deref(), Some("claude"));
+        assert!(built.has_agents_md);
+    }
+
+    fn history_setup() -> (TempDir, StorageConfig, String) {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let project = temp.path().join("my-app");
+        fs::create_dir_all(&project).unwrap();
+        let project = project.to_string_lossy().to_string();
+
+        let history_dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path(&project));
+        fs::create_dir_all(&history_dir).unwrap();
+        fs::write(history_dir.join("session.jsonl"), "0123456789").unwrap();
+        (temp, storage, project)
+    }
+
+    #[test]
+    fn delete_project_history_requires_matching_token() {
+        let (_temp, storage, project) = history_setup();
+        let history_dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path(&project));
+
+        let err = delete_project_history_with_storage(&storage, &project, "wrong").unwrap_err();
+        assert!(err.contains("Confirmation token"));
+        assert!(history_dir.exists());
+
+        let bytes = delete_project_history_with_storage(&storage, &project, "my-app").unwrap();
+        assert_eq!(bytes, 10);
+        assert!(!history_dir.exists());
+        assert!(storage.claude_projects_dir().exists());
+    }
+
+    #[test]
+    fn delete_project_history_refuses_with_live_lock() {
+        let (_temp, storage, project) = history_setup();
+        crate::state::tests_helper::create_session_lock(
+            &storage.sessions_dir(),
+            std::process::id(),
+            &project,
+            "live-session",
+        );
+
+        let err = delete_project_history_with_storage(&storage, &project, "my-app").unwrap_err();
+        assert!(err.contains("session is running"));
+        assert!(storage
+            .claude_projects_dir()
+            .join(encode_project_path(&project))
+            .exists());
+    }
+
+    #[test]
+    fn delete_project_history_clears_activity_entries() {
+        let (_temp, storage, project) = history_setup();
+        let mut activity = ActivityStore::new();
+        activity.record_activity(
+            "session-1",
+            &project,
+            &format!("{}/main.rs", project),
+            "Edit",
+            &crate::activity::now_iso8601(),
+        );
+        activity.record_activity(
+            "session-1",
+            "/elsewhere",
+            "/elsewhere/lib.rs",
+            "Edit",
+            &crate::activity::now_iso8601(),
+        );
+        fs::create_dir_all(storage.root()).unwrap();
+        activity.save(&storage.file_activity_file()).unwrap();
+
+        delete_project_history_with_storage(&storage, &project, "my-app").unwrap();
+
+        let remaining = ActivityStore::load(&storage.file_activity_file());
+        let entries = &remaining.sessions["session-1"].activity;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].project_path, "/elsewhere");
+    }
+
+    #[test]
+    fn git_stash_count_counts_entries_and_skips_non_git() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path().join("repo");
+        fs::create_dir_all(&repo).unwrap();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .arg("-C")
+                .arg(&repo)
+                .args([
+                    "-c",
+                    "user.name=Test",
+                    "-c",
+                    "user.email=test@example.com",
+                    "-c",
+                    "commit.gpgsign=false",
+                ])
+                .args(args)
+                .status()
+                .unwrap();
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        git(&["init", "-q"]);
+        fs::write(repo.join("a.txt"), "one\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "init"]);
+        assert_eq!(git_stash_count(repo.to_str().unwrap()), Some(0));
+
+        for content in ["two\n", "three\n"] {
+            fs::write(repo.join("a.txt"), content).unwrap();
+            git(&["stash", "-q"]);
+        }
+        assert_eq!(git_stash_count(repo.to_str().unwrap()), Some(2));
+
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let details = load_project_details_with_storage(&storage, repo.to_str().unwrap()).unwrap();
+        assert_eq!(details.git_stash_count, Some(2));
+
+        let plain = temp.path().join("plain");
+        fs::create_dir_all(&plain).unwrap();
+        assert_eq!(git_stash_count(plain.to_str().unwrap()), None);
+    }
+
+    #[test]
+    fn open_project_config_prefers_claude_md_then_local_settings() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let project = temp.path().join("project");
+        fs::create_dir_all(project.join(".claude")).unwrap();
+        let path = project.to_str().unwrap();
+
+        assert!(open_project_config_with_storage(&storage, path, false).is_err());
+        fs::create_dir_all(storage.claude_root()).unwrap();
+        fs::write(storage.claude_settings_file(), "{}").unwrap();
+        let chosen = open_project_config_with_storage(&storage, path, false).unwrap();
+        assert_eq!(chosen.kind, ProjectConfigKind::GlobalSettings);
+
+        let chosen = open_project_config_with_storage(&storage, path, true).unwrap();
+        assert_eq!(chosen.kind, ProjectConfigKind::LocalSettings);
+        assert_eq!(
+            fs::read_to_string(project.join(".claude/settings.local.json")).unwrap(),
+            "{}\n"
+        );
+
+        fs::write(project.join("CLAUDE.md"), "# Project\n").unwrap();
+        let chosen = open_project_config_with_storage(&storage, path, false).unwrap();
+        assert_eq!(chosen.kind, ProjectConfigKind::Instructions);
+        assert_eq!(chosen.path, project.join("CLAUDE.md").to_string_lossy());
+    }
+
+    #[test]
+    fn git_ahead_behind_compares_with_upstream() {
+        let temp = TempDir::new().unwrap();
+        let git = |dir: &Path, args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .arg("-C")
+                .arg(dir)
+                .args([
+                    "-c",
+                    "user.name=Test",
+                    "-c",
+                    "user.email=test@example.com",
+                    "-c",
+                    "commit.gpgsign=false",
+                ])
+                .args(args)
+                .status()
+                .unwrap();
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        let origin = temp.path().join("origin");
+        fs::create_dir_all(&origin).unwrap();
+        git(&origin, &["init", "-q"]);
+        git(&origin, &["commit", "-q", "--allow-empty", "-m", "init"]);
+        assert_eq!(
+            git_ahead_behind(origin.to_str().unwrap()),
+            GitUpstream::NoUpstream
+        );
+        assert_eq!(
+            git_ahead_behind(temp.path().to_str().unwrap()),
+            GitUpstream::NotARepo
+        );
+
+        let work = temp.path().join("work");
+        git(
+            temp.path(),
+            &[
+                "clone",
+                "-q",
+                origin.to_str().unwrap(),
+                work.to_str().unwrap(),
+            ],
+        );
+        assert_eq!(
+            git_ahead_behind(work.to_str().unwrap()),
+            GitUpstream::Tracking {
+                ahead: 0,
+                behind: 0
+            }
+        );
+
+        git(&work, &["commit", "-q", "--allow-empty", "-m", "local"]);
+        git(
+            &origin,
+            &["commit", "-q", "--allow-empty", "-m", "remote 1"],
+        );
+        git(
+            &origin,
+            &["commit", "-q", "--allow-empty", "-m", "remote 2"],
+        );
+        git(&work, &["fetch", "-q"]);
+        assert_eq!(
+            git_ahead_behind(work.to_str().unwrap()),
+            GitUpstream::Tracking {
+                ahead: 1,
+                behind: 2
+            }
+        );
+
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let details = load_project_details_with_storage(&storage, work.to_str().unwrap()).unwrap();
+        assert!(details.git_has_upstream);
+        assert_eq!((details.git_ahead, details.git_behind), (1, 2));
+        assert!(details.git_branch.is_some());
+        assert_eq!(details.git_head_commit.map(|sha| sha.len()), Some(40));
+        assert!(!details.git_dirty);
+    }
+
+    #[test]
+    fn load_project_details_reads_instructions_and_tasks() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let project = temp.path().join("project");
+        fs::create_dir_all(&project).unwrap();
+        fs::write(project.join("CLAUDE.md"), "# Rules\n").unwrap();
+        let path = project.to_str().unwrap();
+        let sessions = storage
+            .claude_projects_dir()
+            .join(encode_project_path(path));
+        fs::create_dir_all(&sessions).unwrap();
+        fs::write(
+            sessions.join("s1.jsonl"),
+            r#"{"type":"user","message":{"content":"Fix the build"}}"#,
+        )
+        .unwrap();
+
+        let details = load_project_details_with_storage(&storage, path).unwrap();
+        assert_eq!(details.claude_md_content.as_deref(), Some("# Rules\n"));
+        assert_eq!(details.tasks.len(), 1);
+        assert_eq!(details.tasks[0].name, "Fix the build");
+        assert!(!details.git_has_upstream);
+        assert_eq!((details.git_ahead, details.git_behind), (0, 0));
+        assert_eq!(details.git_branch, None);
+        assert_eq!(details.git_head_commit, None);
+        assert_eq!(details.git_stash_count, None);
+
+        let missing = temp.path().join("missing");
+        assert!(load_project_details_with_storage(&storage, missing.to_str().unwrap()).is_err());
+    }
+
+    #[test]
+    fn classify_directory_ranks_git_and_manifest_above_makefile() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let app = temp.path().join("app");
+        fs::create_dir_all(app.join(".git")).unwrap();
+        fs::write(app.join("package.json"), "{}").unwrap();
+        let scripts = temp.path().join("scripts");
+        fs::create_dir_all(&scripts).unwrap();
+        fs::write(scripts.join("Makefile"), "all:\n").unwrap();
+
+        let strong = classify_directory_with_storage(&storage, app.to_str().unwrap());
+        let weak = classify_directory_with_storage(&storage, scripts.to_str().unwrap());
+        assert_eq!(strong.indicators, vec![".git", "package.json"]);
+        assert_eq!(weak.indicators, vec!["Makefile"]);
+        assert!(strong.score > weak.score);
+
+        // Recent sessions add weight
+        let sessions = storage
+            .claude_projects_dir()
+            .join(encode_project_path(scripts.to_str().unwrap()));
+        fs::create_dir_all(&sessions).unwrap();
+        fs::write(sessions.join("s1.jsonl"), "").unwrap();
+        let active = classify_directory_with_storage(&storage, scripts.to_str().unwrap());
+        assert_eq!(active.score, weak.score + RECENT_ACTIVITY_WEIGHT);
+        assert!(active
+            .indicators
+            .contains(&RECENT_ACTIVITY_INDICATOR.to_string()));
+    }
+
+    #[test]
+    fn missing_pinned_project_is_audited_and_pruned() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let code = temp.path().join("code");
+        let live = code.join("live");
+        let moved = code.join("archive").join("old-app");
+        fs::create_dir_all(&live).unwrap();
+        fs::create_dir_all(&moved).unwrap();
+        let missing = code.join("old-app").to_string_lossy().to_string();
+        let live = live.to_string_lossy().to_string();
+
+        let mut config = load_hud_config_with_storage(&storage);
+        config.pinned_projects = vec![live.clone(), missing.clone()];
+        save_hud_config_with_storage(&storage, &config).unwrap();
+
+        let audit = audit_pinned_projects_with_storage(&storage);
+        assert_eq!(
+            audit,
+            vec![
+                PinnedProjectAudit {
+                    path: live.clone(),
+                    exists: true,
+                    relocated_to: None,
+                },
+                PinnedProjectAudit {
+                    path: missing.clone(),
+                    exists: false,
+                    relocated_to: Some(moved.to_string_lossy().to_string()),
+                },
+            ]
+        );
+
+        assert_eq!(
+            prune_missing_projects_with_storage(&storage),
+            Ok(vec![missing])
+        );
+        assert_eq!(
+            load_hud_config_with_storage(&storage).pinned_projects,
+            vec![live]
+        );
+        assert_eq!(prune_missing_projects_with_storage(&storage), Ok(vec![]));
+    }
+
+    #[test]
+    fn project_alias_replaces_name_until_cleared() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let project = temp.path().join("repo-a").join("web");
+        fs::create_dir_all(&project).unwrap();
+        let path = project.to_string_lossy().to_string();
+        let mut config = load_hud_config_with_storage(&storage);
+        config.pinned_projects = vec![path.clone(), "/gone/api".to_string()];
+        save_hud_config_with_storage(&storage, &config).unwrap();
+        let names = |storage: &StorageConfig| -> Vec<String> {
+            load_projects_with_storage(storage)
+                .unwrap()
+                .into_iter()
+                .map(|p| p.name)
+                .collect()
+        };
+
+        assert_eq!(names(&storage), vec!["web", "api"]);
+
+        set_project_alias_with_storage(&storage, &path, " A web ").unwrap();
+        set_project_alias_with_storage(&storage, "/gone/api", "Old API").unwrap();
+        assert_eq!(
+            load_hud_config_with_storage(&storage)
+                .project_aliases
+                .get(&path)
+                .map(String::as_str),
+            Some("A web")
+        );
+        assert_eq!(names(&storage), vec!["A web", "Old API"]);
+
+        set_project_alias_with_storage(&storage, &path, "").unwrap();
+        assert!(!load_hud_config_with_storage(&storage)
+            .project_aliases
+            .contains_key(&path));
+        assert_eq!(names(&storage), vec!["web", "Old API"]);
+    }
+
+    #[test]
+    fn manual_order_preserves_reordered_pins() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let mut config = load_hud_config_with_storage(&storage);
+        config.pinned_projects = vec!["/x/older".to_string(), "/x/newer".to_string()];
+        save_hud_config_with_storage(&storage, &config).unwrap();
+        fs::create_dir_all(
+            storage
+                .claude_projects_dir()
+                .join(encode_project_path("/x/newer")),
+        )
+        .unwrap();
+        let order = |storage: &StorageConfig| -> Vec<String> {
+            load_projects_with_storage(storage)
+                .unwrap()
+                .into_iter()
+                .map(|p| p.path)
+                .collect()
+        };
+
+        assert_eq!(order(&storage), vec!["/x/newer", "/x/older"]);
+
+        assert!(reorder_projects_with_storage(&storage, &["/x/older".to_string()]).is_err());
+        reorder_projects_with_storage(&storage, &["/x/older".to_string(), "/x/newer".to_string()])
+            .unwrap();
+        let config = load_hud_config_with_storage(&storage);
+        assert!(config.manual_order);
+        assert_eq!(config.pinned_projects, vec!["/x/older", "/x/newer"]);
+        assert_eq!(order(&storage), vec!["/x/older", "/x/newer"]);
+
+        set_manual_order_with_storage(&storage, false).unwrap();
+        assert_eq!(order(&storage), vec!["/x/newer", "/x/older"]);
+    }
+
+    #[test]
+    fn nested_pins_are_reported_as_overlapping() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let mut config = load_hud_config_with_storage(&storage);
+        config.pinned_projects = vec![
+            "/a/b".to_string(),
+            "/a/".to_string(),
+            "/c".to_string(),
+            "/ab".to_string(),
+        ];
+        save_hud_config_with_storage(&storage, &config).unwrap();
+
+        assert_eq!(
+            find_overlapping_pins_with_storage(&storage),
+            vec![OverlappingPins {
+                ancestor: "/a/".to_string(),
+                descendant: "/a/b".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn recent_claude_project_folders_keeps_newest() {
+        let temp = TempDir::new().unwrap();
+        let base = SystemTime::now() - std::time::Duration::from_secs(3600);
+        for i in 0..8u64 {
+            let dir = temp.path().join(format!("-folder-{}", i));
+            fs::create_dir_all(&dir).unwrap();
+            std::fs::File::open(&dir)
+                .unwrap()
+                .set_modified(base + std::time::Duration::from_secs(i * 60))
+                .unwrap();
+        }
+
+        let names = |folders: Vec<PathBuf>| -> Vec<String> {
+            folders
+                .iter()
+                .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+                .collect()
+        };
+        assert_eq!(
+            names(recent_claude_project_folders(temp.path(), Some(5))),
+            vec![
+                "-folder-7",
+                "-folder-6",
+                "-folder-5",
+                "-folder-4",
+                "-folder-3"
+            ]
+        );
+        assert_eq!(recent_claude_project_folders(temp.path(), None).len(), 8);
+    }
+}
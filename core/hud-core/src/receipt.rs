@@ -0,0 +1,187 @@
+//! Shareable one-glance summaries of what a session did and what it cost.
+//!
+//! A receipt composes the transcript parsers used elsewhere (first prompt,
+//! token/cost totals, session span) with the session's final reply, edited
+//! files, and models, so the client can render it as a card or copy it as text.
+
+use crate::projects::session_transcript_path;
+use crate::stats::{session_cost_from_content, session_span_secs};
+use crate::storage::StorageConfig;
+use crate::tasks::first_prompt;
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+
+/// Prompt and reply text are cut to this many characters to keep receipts compact.
+const RECEIPT_TEXT_MAX_CHARS: usize = 500;
+
+/// Tools whose `file_path` input means the session changed that file.
+const EDIT_TOOLS: &[&str] = &["Edit", "MultiEdit", "Write", "NotebookEdit"];
+
+/// Compact summary of a finished session.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, uniffi::Record)]
+pub struct SessionReceipt {
+    pub session_id: String,
+    pub project_path: String,
+    pub first_prompt: Option<String>,
+    /// Text of the last assistant message that had any
+    pub final_response: Option<String>,
+    /// Seconds between the first and last transcript timestamps
+    pub duration_secs: Option<f64>,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_read_tokens: u64,
+    pub cache_creation_tokens: u64,
+    /// Estimated cost at list prices
+    pub cost_usd: f64,
+    /// Files edited or written, in the order first touched
+    pub files_touched: Vec<String>,
+    /// Model ids used, in the order first seen
+    pub models: Vec<String>,
+}
+
+/// Builds a receipt for a session from its transcript.
+///
+/// Returns None when the transcript is missing.
+pub fn session_receipt(session_id: &str, project_path: &str) -> Option<SessionReceipt> {
+    session_receipt_with_storage(&StorageConfig::default(), session_id, project_path)
+}
+
+pub fn session_receipt_with_storage(
+    storage: &StorageConfig,
+    session_id: &str,
+    project_path: &str,
+) -> Option<SessionReceipt> {
+    let transcript = session_transcript_path(storage, project_path, session_id)?;
+    let content = fs::read_to_string(transcript).ok()?;
+    Some(receipt_from_content(&content, session_id, project_path))
+}
+
+/// Builds a receipt from transcript content.
+pub fn receipt_from_content(content: &str, session_id: &str, project_path: &str) -> SessionReceipt {
+    let cost = session_cost_from_content(content, session_id.to_string(), project_path);
+
+    let mut final_response = None;
+    let mut files_touched: Vec<String> = Vec::new();
+    let mut models: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if value.get("type").and_then(|t| t.as_str()) != Some("assistant") {
+            continue;
+        }
+        let Some(message) = value.get("message") else {
+            continue;
+        };
+
+        if let Some(model) = message.get("model").and_then(|m| m.as_str()) {
+            if model != "<synthetic>" && !models.iter().any(|m| m == model) {
+                models.push(model.to_string());
+            }
+        }
+
+        let blocks = message
+            .get("content")
+            .and_then(|c| c.as_array())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let text: Vec<&str> = blocks
+            .iter()
+            .filter(|b| b.get("type").and_then(|t| t.as_str()) == Some("text"))
+            .filter_map(|b| b.get("text").and_then(|t| t.as_str()))
+            .filter(|t| !t.trim().is_empty())
+            .collect();
+        if !text.is_empty() {
+            final_response = Some(text.join("\n"));
+        }
+
+        for block in blocks {
+            if block.get("type").and_then(|t| t.as_str()) != Some("tool_use") {
+                continue;
+            }
+            let is_edit = block
+                .get("name")
+                .and_then(|n| n.as_str())
+                .is_some_and(|name| EDIT_TOOLS.contains(&name));
+            let path = block.get("input").and_then(|input| {
+                input
+                    .get("file_path")
+                    .or_else(|| input.get("notebook_path"))
+                    .and_then(|p| p.as_str())
+            });
+            if let (true, Some(path)) = (is_edit, path) {
+                if !files_touched.iter().any(|f| f == path) {
+                    files_touched.push(path.to_string());
+                }
+            }
+        }
+    }
+
+    let truncate = |text: String| text.trim().chars().take(RECEIPT_TEXT_MAX_CHARS).collect();
+
+    SessionReceipt {
+        session_id: session_id.to_string(),
+        project_path: cost.project_path,
+        first_prompt: first_prompt(content).map(truncate),
+        final_response: final_response.map(truncate),
+        duration_secs: session_span_secs(content),
+        input_tokens: cost.input_tokens,
+        output_tokens: cost.output_tokens,
+        cache_read_tokens: cost.cache_read_tokens,
+        cache_creation_tokens: cost.cache_creation_tokens,
+        cost_usd: cost.cost_usd,
+        files_touched,
+        models,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects::encode_project_path;
+    use tempfile::TempDir;
+
+    const FIXTURE: &str = r#"{"type":"user","isMeta":true,"message":{"role":"user","content":"Caveat: injected context"}}
+{"type":"user","message":{"role":"user","content":"<command-name>/clear</command-name>"}}
+{"type":"user","cwd":"/work/app","timestamp":"2026-01-01T10:00:00Z","message":{"role":"user","content":"Add a **login** page"}}
+{"type":"assistant","timestamp":"2026-01-01T10:00:05Z","message":{"model":"claude-sonnet-4","usage":{"input_tokens":1000,"output_tokens":200},"content":[{"type":"text","text":"I'll add it."},{"type":"tool_use","id":"t1","name":"Write","input":{"file_path":"/work/app/login.tsx","content":"x"}}]}}
+{"type":"user","timestamp":"2026-01-01T10:00:06Z","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"t1","content":"ok"}]}}
+{"type":"assistant","timestamp":"2026-01-01T10:01:00Z","message":{"model":"claude-opus-4","usage":{"input_tokens":2000,"output_tokens":300,"cache_read_input_tokens":500},"content":[{"type":"tool_use","id":"t2","name":"Read","input":{"file_path":"/work/app/app.tsx"}},{"type":"tool_use","id":"t3","name":"Edit","input":{"file_path":"/work/app/app.tsx"}},{"type":"tool_use","id":"t4","name":"Edit","input":{"file_path":"/work/app/login.tsx"}}]}}
+{"type":"assistant","timestamp":"2026-01-01T10:02:00Z","message":{"model":"claude-sonnet-4","usage":{"input_tokens":10,"output_tokens":20},"content":[{"type":"text","text":"Done: the login page is wired up."}]}}"#;
+
+    #[test]
+    fn session_receipt_summarizes_fixture_session() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        assert!(session_receipt_with_storage(&storage, "s1", "/work/app").is_none());
+
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/work/app"));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("s1.jsonl"), FIXTURE).unwrap();
+
+        let receipt = session_receipt_with_storage(&storage, "s1", "/work/app").unwrap();
+        assert_eq!(receipt.session_id, "s1");
+        assert_eq!(receipt.project_path, "/work/app");
+        assert_eq!(
+            receipt.first_prompt.as_deref(),
+            Some("Add a **login** page")
+        );
+        assert_eq!(
+            receipt.final_response.as_deref(),
+            Some("Done: the login page is wired up.")
+        );
+        assert_eq!(receipt.duration_secs, Some(120.0));
+        assert_eq!(receipt.input_tokens, 3010);
+        assert_eq!(receipt.output_tokens, 520);
+        assert_eq!(receipt.cache_read_tokens, 500);
+        assert!(receipt.cost_usd > 0.0);
+        assert_eq!(
+            receipt.files_touched,
+            vec!["/work/app/login.tsx", "/work/app/app.tsx"]
+        );
+        assert_eq!(receipt.models, vec!["claude-sonnet-4", "claude-opus-4"]);
+    }
+}
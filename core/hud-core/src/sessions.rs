@@ -9,12 +9,19 @@
 //!
 //! We never write to `~/.claude/` (sidecar purity).
 
+use crate::activation::{paths_match, ShellCwdStateFfi, ShellEntryFfi};
 use crate::activity::ActivityStore;
-use crate::state::{resolve_state_with_details, StateStore};
+use crate::state::{
+    find_live_locks_with_missing_path, load_transitions, normalize_path_for_matching,
+    release_lock_by_session, resolve_state_with_config, ResolvedState, ResolverConfig,
+    SessionRecord, StateStore,
+};
 use crate::storage::StorageConfig;
-use crate::types::{ProjectSessionState, SessionState};
-use chrono::Utc;
+use crate::types::{HudConfig, ParentApp, ProjectSessionState, SessionState, SessionSummary};
+use chrono::{DateTime, Utc};
 use fs_err as fs;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
 use std::path::Path;
 
 /// Ready state becomes Idle after this many seconds without a lock.
@@ -32,15 +39,52 @@ pub fn detect_session_state(project_path: &str) -> ProjectSessionState {
 pub fn detect_session_state_with_storage(
     storage: &StorageConfig,
     project_path: &str,
+) -> ProjectSessionState {
+    let config = crate::config::load_hud_config_with_storage(storage);
+    detect_session_state_inner(storage, project_path, &config)
+}
+
+/// The state to show for a resolved session, turning Ready into Idle when it
+/// has sat too long: past [`READY_STALE_THRESHOLD_SECS`] without a lock, or past
+/// `idle_threshold_secs` (`HudConfig.idle_threshold_secs`) with one.
+pub fn ready_or_idle(
+    resolved: &ResolvedState,
+    record: Option<&SessionRecord>,
+    idle_threshold_secs: Option<u64>,
+) -> SessionState {
+    if resolved.state != SessionState::Ready {
+        return resolved.state;
+    }
+    let threshold = if resolved.is_from_lock {
+        idle_threshold_secs.map(|secs| i64::try_from(secs).unwrap_or(i64::MAX))
+    } else {
+        Some(READY_STALE_THRESHOLD_SECS)
+    };
+    let age = record.map(|rec| {
+        Utc::now()
+            .signed_duration_since(rec.state_changed_at)
+            .num_seconds()
+    });
+    match (threshold, age) {
+        (Some(threshold), Some(age)) if age > threshold => SessionState::Idle,
+        _ => resolved.state,
+    }
+}
+
+fn detect_session_state_inner(
+    storage: &StorageConfig,
+    project_path: &str,
+    config: &HudConfig,
 ) -> ProjectSessionState {
     // Both locks and state file are in ~/.capacitor/ (our namespace, sidecar purity)
     let lock_dir = storage.sessions_dir();
+    let resolver_config = ResolverConfig::from_hud_config(config);
     let state_file = storage.sessions_file();
 
     let store = StateStore::load(&state_file).unwrap_or_else(|_| StateStore::new(&state_file));
 
     // v3 resolver handles both lock-based detection and fresh record fallback
-    let resolved = resolve_state_with_details(&lock_dir, &store, project_path);
+    let resolved = resolve_state_with_config(&lock_dir, &store, project_path, &resolver_config);
 
     match resolved {
         Some(details) => {
@@ -49,25 +93,9 @@ pub fn detect_session_state_with_storage(
                 .as_ref()
                 .and_then(|sid| store.get_by_session_id(sid));
 
-            // Check if Ready state should become Idle (stale Ready without lock)
-            let final_state = if details.state == SessionState::Ready && !details.is_from_lock {
-                if let Some(rec) = record.as_ref() {
-                    let age = Utc::now()
-                        .signed_duration_since(rec.state_changed_at)
-                        .num_seconds();
-                    if age > READY_STALE_THRESHOLD_SECS {
-                        SessionState::Idle
-                    } else {
-                        details.state
-                    }
-                } else {
-                    details.state
-                }
-            } else {
-                details.state
-            };
+            let final_state = ready_or_idle(&details, record, config.idle_threshold_secs);
 
-            let is_working = final_state == SessionState::Working;
+            let is_working = matches!(final_state, SessionState::Working | SessionState::Thinking);
             let working_on = record.as_ref().and_then(|r| r.working_on.clone());
             let state_changed_at = record.as_ref().map(|r| r.state_changed_at.to_rfc3339());
             let updated_at = record.map(|r| r.updated_at.to_rfc3339());
@@ -81,6 +109,7 @@ pub fn detect_session_state_with_storage(
                 context: None,
                 thinking: Some(is_working),
                 is_locked: details.is_from_lock,
+                possibly_stale: details.possibly_stale,
             }
         }
         None => {
@@ -102,6 +131,7 @@ pub fn detect_session_state_with_storage(
                     context: None,
                     thinking: Some(true),
                     is_locked: false, // No lock at this path, but still working
+                    possibly_stale: false,
                 }
             } else {
                 ProjectSessionState {
@@ -113,6 +143,7 @@ pub fn detect_session_state_with_storage(
                     context: None,
                     thinking: None,
                     is_locked: false,
+                    possibly_stale: false,
                 }
             }
         }
@@ -133,17 +164,498 @@ pub fn get_all_session_states_with_storage(
     project_paths: &[String],
 ) -> std::collections::HashMap<String, ProjectSessionState> {
     let mut states = std::collections::HashMap::new();
+    let config = crate::config::load_hud_config_with_storage(storage);
 
     for path in project_paths {
         states.insert(
             path.clone(),
-            detect_session_state_with_storage(storage, path),
+            detect_session_state_inner(storage, path, &config),
         );
     }
 
     states
 }
 
+/// Newest record timestamps further than this into the future indicate clock skew.
+///
+/// Hooks and the app normally share a clock, so any real gap means the hook ran
+/// under a different clock (VM, container, manual time change).
+pub const CLOCK_SKEW_WARNING_SECS: i64 = 10 * 60;
+
+/// Result of comparing session record timestamps to the current time.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct ClockSkewReport {
+    /// `updated_at` of the newest record (RFC 3339), None if the store is empty
+    pub newest_record_at: Option<String>,
+    /// Newest record minus now, in seconds (positive = record is in the future)
+    pub skew_secs: i64,
+    pub is_skewed: bool,
+    pub warning: Option<String>,
+}
+
+/// Checks session records for implausible timestamps caused by clock skew.
+///
+/// The resolver trusts future timestamps as fresh, so a hook clock running ahead
+/// keeps sessions perpetually "fresh". Only future skew is flagged: an old newest
+/// record is normal (no recent activity) and can't be distinguished from skew.
+pub fn clock_skew_check() -> ClockSkewReport {
+    clock_skew_check_with_storage(&StorageConfig::default())
+}
+
+pub fn clock_skew_check_with_storage(storage: &StorageConfig) -> ClockSkewReport {
+    let state_file = storage.sessions_file();
+    let store = StateStore::load(&state_file).unwrap_or_else(|_| StateStore::new(&state_file));
+
+    let Some(newest) = store.sessions().map(|r| r.updated_at).max() else {
+        return ClockSkewReport {
+            newest_record_at: None,
+            skew_secs: 0,
+            is_skewed: false,
+            warning: None,
+        };
+    };
+
+    let skew_secs = newest.signed_duration_since(Utc::now()).num_seconds();
+    let is_skewed = skew_secs > CLOCK_SKEW_WARNING_SECS;
+    let warning = is_skewed.then(|| {
+        format!(
+            "Session timestamps are {} minutes in the future. Check the system clock; session states may look wrong until it is corrected.",
+            skew_secs / 60
+        )
+    });
+
+    ClockSkewReport {
+        newest_record_at: Some(newest.to_rfc3339()),
+        skew_secs,
+        is_skewed,
+        warning,
+    }
+}
+
+/// Tallies the records in the state store by their stored state.
+pub fn session_summary() -> SessionSummary {
+    session_summary_with_storage(&StorageConfig::default())
+}
+
+pub fn session_summary_with_storage(storage: &StorageConfig) -> SessionSummary {
+    let state_file = storage.sessions_file();
+    let store = StateStore::load(&state_file).unwrap_or_else(|_| StateStore::new(&state_file));
+    summarize_sessions(&store)
+}
+
+fn summarize_sessions(store: &StateStore) -> SessionSummary {
+    let mut summary = SessionSummary::default();
+    for record in store.sessions() {
+        let count = match record.state {
+            SessionState::Working => &mut summary.working,
+            SessionState::Waiting => &mut summary.waiting,
+            SessionState::Ready => &mut summary.ready,
+            SessionState::Idle => &mut summary.idle,
+            SessionState::Compacting => &mut summary.compacting,
+            SessionState::Thinking => &mut summary.thinking,
+        };
+        *count += 1;
+    }
+    summary
+}
+
+/// A session id seen under project paths that can't belong to one session.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct SessionIdCollision {
+    pub session_id: String,
+    /// Distinct paths the id was recorded under, sorted
+    pub paths: Vec<String>,
+}
+
+/// Finds session ids recorded under unrelated project paths.
+///
+/// Separate Claude config profiles share `~/.capacitor`, so a colliding id lets
+/// one profile's hook events overwrite another's record. Paths come from the
+/// state store (`project_dir`, falling back to `cwd`) and the transitions log.
+/// Paths nested inside one another are not a collision: a session that `cd`s
+/// into a subdirectory is still the same session.
+pub fn detect_session_id_collisions() -> Vec<SessionIdCollision> {
+    detect_session_id_collisions_with_storage(&StorageConfig::default())
+}
+
+pub fn detect_session_id_collisions_with_storage(
+    storage: &StorageConfig,
+) -> Vec<SessionIdCollision> {
+    let state_file = storage.sessions_file();
+    let store = StateStore::load(&state_file).unwrap_or_else(|_| StateStore::new(&state_file));
+
+    let mut paths_by_id: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for record in store.sessions() {
+        let path = record.project_dir.as_deref().unwrap_or(&record.cwd);
+        paths_by_id
+            .entry(record.session_id.clone())
+            .or_default()
+            .insert(normalize_path_for_matching(path));
+    }
+    for transition in load_transitions(&storage.transitions_file()) {
+        paths_by_id
+            .entry(transition.session_id)
+            .or_default()
+            .insert(normalize_path_for_matching(&transition.cwd));
+    }
+
+    paths_by_id
+        .into_iter()
+        .filter(|(_, paths)| {
+            paths.iter().any(|a| {
+                paths
+                    .iter()
+                    .any(|b| !is_same_or_nested(a, b) && !is_same_or_nested(b, a))
+            })
+        })
+        .map(|(session_id, paths)| SessionIdCollision {
+            session_id,
+            paths: paths.into_iter().collect(),
+        })
+        .collect()
+}
+
+fn is_same_or_nested(parent: &str, child: &str) -> bool {
+    child == parent
+        || parent == "/"
+        || child
+            .strip_prefix(parent)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Live sessions running inside one host app (terminal or IDE).
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct HostAppSessions {
+    pub parent_app: ParentApp,
+    /// Most recently updated session first
+    pub session_ids: Vec<String>,
+}
+
+/// Groups live sessions by the app hosting their shell.
+///
+/// A session is live when the resolver still attributes its cwd to it. Its host
+/// is the parent app of the most recently updated live shell whose cwd matches
+/// the session's. Sessions with no matching shell (or no shell state at all) are
+/// grouped under [`ParentApp::Unknown`], which always comes last.
+pub fn sessions_by_host_app(shell_state: Option<&ShellCwdStateFfi>) -> Vec<HostAppSessions> {
+    sessions_by_host_app_with_storage(&StorageConfig::default(), shell_state)
+}
+
+pub fn sessions_by_host_app_with_storage(
+    storage: &StorageConfig,
+    shell_state: Option<&ShellCwdStateFfi>,
+) -> Vec<HostAppSessions> {
+    let state_file = storage.sessions_file();
+    let store = StateStore::load(&state_file).unwrap_or_else(|_| StateStore::new(&state_file));
+    let lock_dir = storage.sessions_dir();
+    let resolver_config = ResolverConfig::load(storage);
+
+    let mut live: Vec<_> = live_records(&store, &lock_dir, &resolver_config)
+        .map(|(record, _)| record)
+        .collect();
+    live.sort_by_key(|r| std::cmp::Reverse(r.updated_at));
+
+    let mut groups: Vec<HostAppSessions> = Vec::new();
+    for record in live {
+        let parent_app = shell_state
+            .and_then(|state| host_shell_for_cwd(state, &record.cwd))
+            .map(|shell| shell.parent_app)
+            .unwrap_or(ParentApp::Unknown);
+        match groups.iter_mut().find(|g| g.parent_app == parent_app) {
+            Some(group) => group.session_ids.push(record.session_id.clone()),
+            None => groups.push(HostAppSessions {
+                parent_app,
+                session_ids: vec![record.session_id.clone()],
+            }),
+        }
+    }
+
+    groups.sort_by_key(|g| g.parent_app == ParentApp::Unknown);
+    groups
+}
+
+/// The session Claude is actively working in, for "jump to active work".
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct ActiveWorkingSession {
+    /// `project_dir` when the hook recorded one, otherwise the session's cwd
+    pub project_path: String,
+    pub session_id: String,
+    pub working_on: Option<String>,
+    pub state: SessionState,
+    pub parent_app: ParentApp,
+}
+
+/// Returns the live Working or Compacting session with the most recent update,
+/// across all projects. None when nothing is working.
+///
+/// The host app is resolved the same way as [`sessions_by_host_app`].
+pub fn active_working_session(
+    shell_state: Option<&ShellCwdStateFfi>,
+) -> Option<ActiveWorkingSession> {
+    active_working_session_with_storage(&StorageConfig::default(), shell_state)
+}
+
+pub fn active_working_session_with_storage(
+    storage: &StorageConfig,
+    shell_state: Option<&ShellCwdStateFfi>,
+) -> Option<ActiveWorkingSession> {
+    let state_file = storage.sessions_file();
+    let store = StateStore::load(&state_file).unwrap_or_else(|_| StateStore::new(&state_file));
+    let lock_dir = storage.sessions_dir();
+    let resolver_config = ResolverConfig::load(storage);
+
+    let (record, state) = live_records(&store, &lock_dir, &resolver_config)
+        .map(|(record, resolved)| (record, resolved.state))
+        .filter(|(_, state)| {
+            matches!(
+                state,
+                SessionState::Working | SessionState::Thinking | SessionState::Compacting
+            )
+        })
+        .max_by_key(|(record, _)| record.updated_at)?;
+
+    Some(ActiveWorkingSession {
+        project_path: record
+            .project_dir
+            .clone()
+            .unwrap_or_else(|| record.cwd.clone()),
+        session_id: record.session_id.clone(),
+        working_on: record.working_on.clone(),
+        state,
+        parent_app: shell_state
+            .and_then(|state| host_shell_for_cwd(state, &record.cwd))
+            .map(|shell| shell.parent_app)
+            .unwrap_or(ParentApp::Unknown),
+    })
+}
+
+/// A session compacting its context right now, which the user shouldn't interrupt.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct CompactingSession {
+    /// `project_dir` when the hook recorded one, otherwise the session's cwd
+    pub project_path: String,
+    pub session_id: String,
+    /// Seconds since the session entered Compacting
+    pub time_in_state_secs: u64,
+}
+
+/// Lists live sessions currently in Compacting, longest-running first.
+///
+/// Only sessions backed by a live lock count, so a record left in Compacting
+/// by a crashed process isn't reported.
+pub fn compacting_sessions() -> Vec<CompactingSession> {
+    compacting_sessions_with_storage(&StorageConfig::default())
+}
+
+pub fn compacting_sessions_with_storage(storage: &StorageConfig) -> Vec<CompactingSession> {
+    let state_file = storage.sessions_file();
+    let store = StateStore::load(&state_file).unwrap_or_else(|_| StateStore::new(&state_file));
+    let lock_dir = storage.sessions_dir();
+    let resolver_config = ResolverConfig::load(storage);
+    let now = Utc::now();
+
+    let mut sessions: Vec<CompactingSession> = live_records(&store, &lock_dir, &resolver_config)
+        .filter(|(_, resolved)| resolved.is_from_lock && resolved.state == SessionState::Compacting)
+        .map(|(record, _)| CompactingSession {
+            project_path: record
+                .project_dir
+                .clone()
+                .unwrap_or_else(|| record.cwd.clone()),
+            session_id: record.session_id.clone(),
+            time_in_state_secs: now
+                .signed_duration_since(record.state_changed_at)
+                .num_seconds()
+                .max(0) as u64,
+        })
+        .collect();
+
+    sessions.sort_by(|a, b| {
+        b.time_in_state_secs
+            .cmp(&a.time_in_state_secs)
+            .then_with(|| a.session_id.cmp(&b.session_id))
+    });
+    sessions
+}
+
+/// A project whose live session has subagents running in parallel.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct ProjectSubagents {
+    /// `project_dir` when the hook recorded one, otherwise the session's cwd
+    pub project_path: String,
+    pub session_id: String,
+    pub active_subagent_count: u32,
+}
+
+/// Lists projects whose live session has at least one subagent running,
+/// busiest first. Empty when none do.
+///
+/// As with [`compacting_sessions`], only sessions backed by their own live
+/// lock count, so a crashed session's leftover count isn't reported.
+pub fn projects_with_subagents() -> Vec<ProjectSubagents> {
+    projects_with_subagents_with_storage(&StorageConfig::default())
+}
+
+pub fn projects_with_subagents_with_storage(storage: &StorageConfig) -> Vec<ProjectSubagents> {
+    let state_file = storage.sessions_file();
+    let store = StateStore::load(&state_file).unwrap_or_else(|_| StateStore::new(&state_file));
+    let lock_dir = storage.sessions_dir();
+    let resolver_config = ResolverConfig::load(storage);
+
+    let mut projects: Vec<ProjectSubagents> = live_records(&store, &lock_dir, &resolver_config)
+        .filter(|(record, resolved)| resolved.is_from_lock && record.active_subagent_count > 0)
+        .map(|(record, _)| ProjectSubagents {
+            project_path: record
+                .project_dir
+                .clone()
+                .unwrap_or_else(|| record.cwd.clone()),
+            session_id: record.session_id.clone(),
+            active_subagent_count: record.active_subagent_count,
+        })
+        .collect();
+
+    projects.sort_by(|a, b| {
+        b.active_subagent_count
+            .cmp(&a.active_subagent_count)
+            .then_with(|| a.project_path.cmp(&b.project_path))
+    });
+    projects
+}
+
+/// How many live sessions are in each state, for an at-a-glance chart.
+#[derive(Debug, Clone, Default, PartialEq, Eq, uniffi::Record)]
+pub struct StateDistribution {
+    pub working: u32,
+    pub waiting: u32,
+    pub ready: u32,
+    pub idle: u32,
+    pub compacting: u32,
+    pub thinking: u32,
+}
+
+/// Counts live sessions across all projects by state.
+///
+/// As with [`compacting_sessions`], only records backed by their own live lock
+/// count, so sessions that ended without cleanup don't inflate the chart.
+pub fn state_distribution() -> StateDistribution {
+    state_distribution_with_storage(&StorageConfig::default())
+}
+
+pub fn state_distribution_with_storage(storage: &StorageConfig) -> StateDistribution {
+    let state_file = storage.sessions_file();
+    let store = StateStore::load(&state_file).unwrap_or_else(|_| StateStore::new(&state_file));
+    let lock_dir = storage.sessions_dir();
+    let resolver_config = ResolverConfig::load(storage);
+
+    let mut distribution = StateDistribution::default();
+    for (_, resolved) in live_records(&store, &lock_dir, &resolver_config) {
+        if !resolved.is_from_lock {
+            continue;
+        }
+        let count = match resolved.state {
+            SessionState::Working => &mut distribution.working,
+            SessionState::Waiting => &mut distribution.waiting,
+            SessionState::Ready => &mut distribution.ready,
+            SessionState::Idle => &mut distribution.idle,
+            SessionState::Compacting => &mut distribution.compacting,
+            SessionState::Thinking => &mut distribution.thinking,
+        };
+        *count += 1;
+    }
+    distribution
+}
+
+/// Session records the resolver still attributes their own cwd to, paired with
+/// the resolved state.
+///
+/// Resolving a record's cwd can land on another session sharing the directory;
+/// such superseded records are skipped. Callers that must ignore sessions left
+/// behind by a crash additionally require `is_from_lock`.
+fn live_records<'a>(
+    store: &'a StateStore,
+    lock_dir: &'a Path,
+    config: &'a ResolverConfig,
+) -> impl Iterator<Item = (&'a SessionRecord, ResolvedState)> + 'a {
+    store.sessions().filter_map(move |record| {
+        let resolved = resolve_state_with_config(lock_dir, store, &record.cwd, config)?;
+        (resolved.session_id.as_deref() == Some(record.session_id.as_str()))
+            .then_some((record, resolved))
+    })
+}
+
+fn host_shell_for_cwd<'a>(state: &'a ShellCwdStateFfi, cwd: &str) -> Option<&'a ShellEntryFfi> {
+    state
+        .shells
+        .values()
+        .filter(|shell| shell.is_live && paths_match(&shell.cwd, cwd))
+        .max_by_key(|shell| DateTime::parse_from_rfc3339(&shell.updated_at).ok())
+}
+
+/// A running session whose working directory has been deleted.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct OrphanedLiveSession {
+    /// The session's cwd, which no longer exists
+    pub path: String,
+    pub pid: u32,
+    /// None for legacy path-based locks
+    pub session_id: Option<String>,
+}
+
+/// Finds live sessions whose folder is gone, so users can kill or release them.
+pub fn orphaned_live_sessions() -> Vec<OrphanedLiveSession> {
+    orphaned_live_sessions_with_storage(&StorageConfig::default())
+}
+
+pub fn orphaned_live_sessions_with_storage(storage: &StorageConfig) -> Vec<OrphanedLiveSession> {
+    let mut sessions: Vec<OrphanedLiveSession> =
+        find_live_locks_with_missing_path(&storage.sessions_dir())
+            .into_iter()
+            .map(|lock| OrphanedLiveSession {
+                path: lock.path,
+                pid: lock.pid,
+                session_id: lock.session_id,
+            })
+            .collect();
+    sessions.sort_by(|a, b| a.path.cmp(&b.path).then(a.pid.cmp(&b.pid)));
+    sessions
+}
+
+/// Releases a session's locks even though its process is alive.
+///
+/// Guarded: only locks whose folder no longer exists are released, so this can't
+/// be used to hide a healthy session. Returns the number of locks released.
+pub fn force_release_lock(session_id: &str) -> Result<u32, String> {
+    force_release_lock_with_storage(&StorageConfig::default(), session_id)
+}
+
+pub fn force_release_lock_with_storage(
+    storage: &StorageConfig,
+    session_id: &str,
+) -> Result<u32, String> {
+    let lock_base = storage.sessions_dir();
+    let orphaned: Vec<_> = find_live_locks_with_missing_path(&lock_base)
+        .into_iter()
+        .filter(|lock| lock.session_id.as_deref() == Some(session_id))
+        .collect();
+    if orphaned.is_empty() {
+        return Err(format!(
+            "No live lock with a missing folder for session {}",
+            session_id
+        ));
+    }
+
+    let mut released = 0;
+    for lock in orphaned {
+        if !release_lock_by_session(&lock_base, session_id, lock.pid) {
+            return Err(format!(
+                "Failed to release lock for session {} (pid {})",
+                session_id, lock.pid
+            ));
+        }
+        released += 1;
+    }
+    Ok(released)
+}
+
 /// Project status as stored in .claude/hud-status.json within each project.
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, Default, uniffi::Record)]
 pub struct ProjectStatus {
@@ -169,10 +681,108 @@ pub fn read_project_status(project_path: &str) -> Option<ProjectStatus> {
     }
 }
 
+/// A project's pending next step, from its `hud-status.json`.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct NextStep {
+    pub project_path: String,
+    pub next_step: String,
+    pub updated_at: Option<String>,
+}
+
+/// Gathers the `next_step` of every pinned project into one agenda, most
+/// recently updated first.
+///
+/// Projects without a status file or with an empty `next_step` are skipped;
+/// entries without a parseable `updated_at` sort last.
+pub fn next_steps_digest() -> Vec<NextStep> {
+    next_steps_digest_with_storage(&StorageConfig::default())
+}
+
+pub fn next_steps_digest_with_storage(storage: &StorageConfig) -> Vec<NextStep> {
+    let config = crate::config::load_hud_config_with_storage(storage);
+    let mut steps: Vec<NextStep> = config
+        .pinned_projects
+        .iter()
+        .filter_map(|path| {
+            let status = read_project_status(path)?;
+            let next_step = status.next_step?.trim().to_string();
+            (!next_step.is_empty()).then(|| NextStep {
+                project_path: path.clone(),
+                next_step,
+                updated_at: status.updated_at,
+            })
+        })
+        .collect();
+
+    steps.sort_by_key(|step| {
+        std::cmp::Reverse(
+            step.updated_at
+                .as_deref()
+                .and_then(|t| DateTime::parse_from_rfc3339(t).ok()),
+        )
+    });
+    steps
+}
+
+/// Maximum lines returned from the status generation log.
+pub const STATUS_GEN_LOG_TAIL_LINES: usize = 200;
+
+/// Reads the tail of `~/.capacitor/status-gen.log`, oldest line first.
+///
+/// The log only exists when `HudConfig.status_gen_logging` is enabled; a missing
+/// log yields no lines.
+pub fn read_status_gen_log() -> Vec<String> {
+    read_status_gen_log_with_storage(&StorageConfig::default())
+}
+
+/// Appends status generation output to `~/.capacitor/status-gen.log` when
+/// `HudConfig.status_gen_logging` is on. Returns whether anything was written.
+///
+/// The status hook pipes its `claude -p` output here through
+/// `hud-hook status-gen-log`, so the flag is checked on every run.
+pub fn append_status_gen_log(output: &str) -> Result<bool, String> {
+    append_status_gen_log_with_storage(&StorageConfig::default(), output)
+}
+
+pub fn append_status_gen_log_with_storage(
+    storage: &StorageConfig,
+    output: &str,
+) -> Result<bool, String> {
+    if !crate::config::load_hud_config_with_storage(storage).status_gen_logging {
+        return Ok(false);
+    }
+    let path = storage.status_gen_log_file();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create log directory: {}", e))?;
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to open status log: {}", e))?;
+    let now = Utc::now().to_rfc3339();
+    for line in output.lines() {
+        writeln!(file, "{} {}", now, line)
+            .map_err(|e| format!("Failed to write status log: {}", e))?;
+    }
+    Ok(true)
+}
+
+pub fn read_status_gen_log_with_storage(storage: &StorageConfig) -> Vec<String> {
+    let Ok(content) = fs::read_to_string(storage.status_gen_log_file()) else {
+        return Vec::new();
+    };
+    let lines: Vec<&str> = content.lines().collect();
+    lines[lines.len().saturating_sub(STATUS_GEN_LOG_TAIL_LINES)..]
+        .iter()
+        .map(|l| l.to_string())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::state::normalize_path_for_hashing;
+    use crate::state::{append_transition, normalize_path_for_hashing, TransitionRecord};
     use chrono::{Duration as ChronoDuration, Utc};
     use std::path::PathBuf;
     use tempfile::TempDir;
@@ -210,6 +820,131 @@ mod tests {
         assert_ne!(hash1, hash2, "Different paths should have different hashes");
     }
 
+    #[test]
+    fn read_status_gen_log_returns_tail() {
+        let (_temp, storage) = setup_storage();
+        assert!(read_status_gen_log_with_storage(&storage).is_empty());
+
+        let lines: Vec<String> = (0..STATUS_GEN_LOG_TAIL_LINES + 5)
+            .map(|i| format!("line {}", i))
+            .collect();
+        fs::write(storage.status_gen_log_file(), lines.join("\n")).unwrap();
+
+        let tail = read_status_gen_log_with_storage(&storage);
+        assert_eq!(tail.len(), STATUS_GEN_LOG_TAIL_LINES);
+        assert_eq!(tail[0], "line 5");
+        assert_eq!(
+            tail.last().unwrap(),
+            &format!("line {}", STATUS_GEN_LOG_TAIL_LINES + 4)
+        );
+    }
+
+    #[test]
+    fn append_status_gen_log_respects_config_flag() {
+        let (_temp, storage) = setup_storage();
+        assert!(!append_status_gen_log_with_storage(&storage, "first").unwrap());
+        assert!(read_status_gen_log_with_storage(&storage).is_empty());
+
+        let config = HudConfig {
+            status_gen_logging: true,
+            ..HudConfig::default()
+        };
+        crate::config::save_hud_config_with_storage(&storage, &config).unwrap();
+        assert!(append_status_gen_log_with_storage(&storage, "second\nthird").unwrap());
+
+        let tail = read_status_gen_log_with_storage(&storage);
+        assert_eq!(tail.len(), 2);
+        assert!(tail[0].ends_with(" second"));
+        assert!(tail[1].ends_with(" third"));
+    }
+
+    #[test]
+    fn clock_skew_check_flags_future_records() {
+        let (_temp, storage) = setup_storage();
+        let mut store = StateStore::new(&storage.sessions_file());
+        store.update("session-1", SessionState::Working, "/tmp/project");
+        store.set_timestamp_for_test("session-1", Utc::now() + ChronoDuration::hours(2));
+        store.save().unwrap();
+
+        let report = clock_skew_check_with_storage(&storage);
+        assert!(report.is_skewed);
+        assert!(report.skew_secs > 7000);
+        assert!(report.warning.unwrap().contains("in the future"));
+    }
+
+    #[test]
+    fn clock_skew_check_accepts_current_and_missing_records() {
+        let (_temp, storage) = setup_storage();
+        assert!(!clock_skew_check_with_storage(&storage).is_skewed);
+
+        let mut store = StateStore::new(&storage.sessions_file());
+        store.update("session-1", SessionState::Ready, "/tmp/project");
+        store.save().unwrap();
+
+        let report = clock_skew_check_with_storage(&storage);
+        assert!(!report.is_skewed);
+        assert!(report.warning.is_none());
+        assert!(report.newest_record_at.is_some());
+    }
+
+    #[test]
+    fn session_summary_counts_mixed_states() {
+        let (_temp, storage) = setup_storage();
+        let mut store = StateStore::new(&storage.sessions_file());
+        store.update("w1", SessionState::Working, "/p/a");
+        store.update("w2", SessionState::Working, "/p/b");
+        store.update("wait", SessionState::Waiting, "/p/c");
+        store.update("r", SessionState::Ready, "/p/d");
+        store.update("c", SessionState::Compacting, "/p/e");
+        store.update("t", SessionState::Thinking, "/p/f");
+        store.save().unwrap();
+
+        assert_eq!(
+            session_summary_with_storage(&storage),
+            SessionSummary {
+                working: 2,
+                waiting: 1,
+                ready: 1,
+                idle: 0,
+                compacting: 1,
+                thinking: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn detect_session_id_collisions_flags_unrelated_project_dirs() {
+        let (_temp, storage) = setup_storage();
+        let mut store = StateStore::new(&storage.sessions_file());
+        store.update("shared", SessionState::Working, "/work/profile-b/src");
+        store.set_project_dir_for_test("shared", Some("/work/profile-b"));
+        store.update("nested", SessionState::Working, "/work/app/src");
+        store.save().unwrap();
+
+        for (session_id, cwd) in [("shared", "/work/profile-a"), ("nested", "/work/app")] {
+            append_transition(
+                &storage.transitions_file(),
+                &TransitionRecord {
+                    session_id: session_id.to_string(),
+                    cwd: cwd.to_string(),
+                    from: None,
+                    to: SessionState::Working,
+                    event: None,
+                    at: Utc::now(),
+                },
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            detect_session_id_collisions_with_storage(&storage),
+            vec![SessionIdCollision {
+                session_id: "shared".to_string(),
+                paths: vec!["/work/profile-a".to_string(), "/work/profile-b".to_string()],
+            }]
+        );
+    }
+
     #[test]
     fn test_detect_session_state_returns_idle_for_unknown() {
         let (_temp, storage) = setup_storage();
@@ -384,6 +1119,46 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn next_steps_digest_collects_pinned_projects_newest_first() {
+        let (temp, storage) = setup_storage();
+        let write_status = |name: &str, body: &str| {
+            let dir = temp.path().join(name);
+            fs::create_dir_all(dir.join(".claude")).unwrap();
+            fs::write(dir.join(".claude").join("hud-status.json"), body).unwrap();
+            dir.to_string_lossy().to_string()
+        };
+        let older = write_status(
+            "older",
+            r#"{"next_step": "Write migration", "updated_at": "2026-01-01T10:00:00Z"}"#,
+        );
+        let newer = write_status(
+            "newer",
+            r#"{"next_step": "Ship release", "updated_at": "2026-01-02T10:00:00Z"}"#,
+        );
+        let empty = write_status("empty", r#"{"next_step": "  "}"#);
+        let missing = temp.path().join("missing").to_string_lossy().to_string();
+
+        let config = crate::types::HudConfig {
+            pinned_projects: vec![older.clone(), empty, missing, newer.clone()],
+            ..Default::default()
+        };
+        crate::config::save_hud_config_with_storage(&storage, &config).unwrap();
+
+        let digest = next_steps_digest_with_storage(&storage);
+        let summary: Vec<_> = digest
+            .iter()
+            .map(|s| (s.project_path.as_str(), s.next_step.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (newer.as_str(), "Ship release"),
+                (older.as_str(), "Write migration")
+            ]
+        );
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Lock Namespace Tests
     // These tests verify locks are in ~/.capacitor/sessions/ (our namespace),
@@ -458,6 +1233,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn locked_ready_session_goes_idle_after_configured_threshold() {
+        let (_temp, storage) = setup_storage();
+        let test_path = "/test/session/idle";
+        create_capacitor_lock(&storage, test_path, std::process::id());
+
+        let now = chrono::Utc::now();
+        let state_content = format!(
+            r#"{{
+                "version": 3,
+                "sessions": {{
+                    "idle-session": {{
+                        "session_id": "idle-session",
+                        "state": "ready",
+                        "cwd": "{}",
+                        "project_dir": "{}",
+                        "updated_at": "{}",
+                        "state_changed_at": "{}"
+                    }}
+                }}
+            }}"#,
+            test_path,
+            test_path,
+            now.to_rfc3339(),
+            (now - ChronoDuration::hours(1)).to_rfc3339()
+        );
+        fs::write(storage.sessions_file(), state_content).unwrap();
+
+        // Without a threshold, a locked session stays Ready
+        let state = detect_session_state_with_storage(&storage, test_path);
+        assert_eq!(state.state, SessionState::Ready);
+        assert!(state.is_locked);
+
+        let config = crate::types::HudConfig {
+            idle_threshold_secs: Some(600),
+            ..Default::default()
+        };
+        crate::config::save_hud_config_with_storage(&storage, &config).unwrap();
+        let state = detect_session_state_with_storage(&storage, test_path);
+        assert_eq!(state.state, SessionState::Idle);
+        assert!(state.is_locked);
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Ready→Idle Staleness Tests (15 minute threshold)
     // These tests verify that Ready state becomes Idle after 15 minutes without lock
@@ -554,4 +1372,186 @@ mod tests {
             "Ready state at exactly 15 minutes should stay Ready (threshold is >15 min)"
         );
     }
+
+    #[test]
+    fn active_working_session_picks_freshest_working_session() {
+        let (_temp, storage) = setup_storage();
+        assert_eq!(active_working_session_with_storage(&storage, None), None);
+
+        let mut store = StateStore::new(&storage.sessions_file());
+        store.update("older", SessionState::Working, "/tmp/hud-active-a");
+        store.update("newer", SessionState::Working, "/tmp/hud-active-b");
+        store.update("ready", SessionState::Ready, "/tmp/hud-active-c");
+        store.set_timestamp_for_test("older", Utc::now() - ChronoDuration::seconds(30));
+        store.set_timestamp_for_test("ready", Utc::now() + ChronoDuration::seconds(5));
+        store.save().unwrap();
+
+        let active = active_working_session_with_storage(&storage, None).unwrap();
+        assert_eq!(active.session_id, "newer");
+        assert_eq!(active.project_path, "/tmp/hud-active-b");
+        assert_eq!(active.state, SessionState::Working);
+        assert_eq!(active.parent_app, ParentApp::Unknown);
+    }
+
+    #[test]
+    fn compacting_sessions_requires_live_lock() {
+        let (_temp, storage) = setup_storage();
+        assert!(compacting_sessions_with_storage(&storage).is_empty());
+
+        let pid = std::process::id();
+        let mut store = StateStore::new(&storage.sessions_file());
+        store.update("compacting", SessionState::Compacting, "/tmp/hud-compact-a");
+        store.update("ready", SessionState::Ready, "/tmp/hud-compact-b");
+        store.update("unlocked", SessionState::Compacting, "/tmp/hud-compact-c");
+        store.set_state_changed_at_for_test("compacting", Utc::now() - ChronoDuration::seconds(42));
+        store.save().unwrap();
+        create_capacitor_lock(&storage, "/tmp/hud-compact-a", pid);
+        create_capacitor_lock(&storage, "/tmp/hud-compact-b", pid);
+
+        let sessions = compacting_sessions_with_storage(&storage);
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].session_id, "compacting");
+        assert_eq!(sessions[0].project_path, "/tmp/hud-compact-a");
+        assert!((42..50).contains(&sessions[0].time_in_state_secs));
+    }
+
+    #[test]
+    fn projects_with_subagents_reports_live_counts() {
+        let (_temp, storage) = setup_storage();
+        assert!(projects_with_subagents_with_storage(&storage).is_empty());
+
+        let pid = std::process::id();
+        let mut store = StateStore::new(&storage.sessions_file());
+        store.update("parallel", SessionState::Working, "/tmp/hud-subagents-a");
+        store.update("solo", SessionState::Working, "/tmp/hud-subagents-b");
+        store.update("unlocked", SessionState::Working, "/tmp/hud-subagents-c");
+        store.adjust_subagent_count("parallel", 1);
+        store.adjust_subagent_count("parallel", 1);
+        store.adjust_subagent_count("unlocked", 1);
+        store.save().unwrap();
+        for cwd in ["/tmp/hud-subagents-a", "/tmp/hud-subagents-b"] {
+            create_capacitor_lock(&storage, cwd, pid);
+        }
+
+        assert_eq!(
+            projects_with_subagents_with_storage(&storage),
+            vec![ProjectSubagents {
+                project_path: "/tmp/hud-subagents-a".to_string(),
+                session_id: "parallel".to_string(),
+                active_subagent_count: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn state_distribution_counts_locked_sessions() {
+        let (_temp, storage) = setup_storage();
+        let pid = std::process::id();
+        let mut store = StateStore::new(&storage.sessions_file());
+        store.update("w1", SessionState::Working, "/tmp/hud-dist-a");
+        store.update("w2", SessionState::Working, "/tmp/hud-dist-b");
+        store.update("wait", SessionState::Waiting, "/tmp/hud-dist-c");
+        store.update("unlocked", SessionState::Working, "/tmp/hud-dist-d");
+        store.save().unwrap();
+        for cwd in ["/tmp/hud-dist-a", "/tmp/hud-dist-b", "/tmp/hud-dist-c"] {
+            create_capacitor_lock(&storage, cwd, pid);
+        }
+
+        assert_eq!(
+            state_distribution_with_storage(&storage),
+            StateDistribution {
+                working: 2,
+                waiting: 1,
+                ..StateDistribution::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_sessions_by_host_app_groups_by_shell_parent() {
+        let (_temp, storage) = setup_storage();
+        let mut store = StateStore::new(&storage.sessions_file());
+        store.update("session-cursor", SessionState::Working, "/tmp/hud-host-a");
+        store.update("session-ghostty", SessionState::Ready, "/tmp/hud-host-b");
+        store.update("session-orphan", SessionState::Ready, "/tmp/hud-host-c");
+        store.save().unwrap();
+
+        let shell = |cwd: &str, parent_app| ShellEntryFfi {
+            cwd: cwd.to_string(),
+            tty: "/dev/ttys001".to_string(),
+            parent_app,
+            tmux_session: None,
+            tmux_client_tty: None,
+            updated_at: Utc::now().to_rfc3339(),
+            is_live: true,
+        };
+        let shell_state = ShellCwdStateFfi {
+            version: 1,
+            shells: std::collections::HashMap::from([
+                (
+                    "101".to_string(),
+                    shell("/tmp/hud-host-a", ParentApp::Cursor),
+                ),
+                (
+                    "102".to_string(),
+                    shell("/tmp/hud-host-b/src", ParentApp::Ghostty),
+                ),
+            ]),
+        };
+
+        let groups = sessions_by_host_app_with_storage(&storage, Some(&shell_state));
+        let by_app = |app| {
+            groups
+                .iter()
+                .find(|g| g.parent_app == app)
+                .map(|g| g.session_ids.clone())
+        };
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(
+            by_app(ParentApp::Cursor),
+            Some(vec!["session-cursor".to_string()])
+        );
+        assert_eq!(
+            by_app(ParentApp::Ghostty),
+            Some(vec!["session-ghostty".to_string()])
+        );
+        assert_eq!(groups.last().unwrap().parent_app, ParentApp::Unknown);
+        assert_eq!(groups.last().unwrap().session_ids, vec!["session-orphan"]);
+    }
+
+    #[test]
+    fn test_orphaned_live_session_flagged_and_force_released() {
+        use crate::state::tests_helper::create_session_lock;
+
+        let (temp, storage, sessions_dir) = setup_storage_with_sessions();
+        let gone = temp.path().join("deleted-project");
+        let present = temp.path().join("present-project");
+        fs::create_dir_all(&gone).unwrap();
+        fs::create_dir_all(&present).unwrap();
+        let pid = std::process::id();
+        create_session_lock(&sessions_dir, pid, gone.to_str().unwrap(), "session-gone");
+        create_session_lock(&sessions_dir, pid, present.to_str().unwrap(), "session-ok");
+        fs::remove_dir_all(&gone).unwrap();
+
+        let orphaned = orphaned_live_sessions_with_storage(&storage);
+        assert_eq!(
+            orphaned,
+            vec![OrphanedLiveSession {
+                path: gone.to_string_lossy().to_string(),
+                pid,
+                session_id: Some("session-gone".to_string()),
+            }]
+        );
+
+        assert!(force_release_lock_with_storage(&storage, "session-ok").is_err());
+        assert_eq!(
+            force_release_lock_with_storage(&storage, "session-gone"),
+            Ok(1)
+        );
+        assert!(orphaned_live_sessions_with_storage(&storage).is_empty());
+        assert!(sessions_dir
+            .join(format!("session-ok-{}.lock", pid))
+            .is_dir());
+    }
 }
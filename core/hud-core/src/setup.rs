@@ -4,6 +4,7 @@
 //! - Checking dependencies (tmux, claude CLI)
 //! - Validating and installing session tracking hooks
 //! - Checking for policy flags that might block hooks
+//! - Auditing every hook command in settings for missing scripts
 //!
 //! ## Design
 //!
@@ -17,7 +18,7 @@ use fs_err as fs;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use tempfile::NamedTempFile;
 
@@ -27,7 +28,7 @@ const HOOK_COMMAND: &str = "$HOME/.local/bin/hud-hook handle";
 /// - `needs_matcher`: Events like PreToolUse need `matcher: "*"` to fire for all tools
 /// - `is_async`: If true, hook runs in background without blocking Claude Code
 ///   SessionEnd is sync to ensure cleanup completes before session exits
-const HUD_HOOK_EVENTS: [(&str, bool, bool); 9] = [
+const HUD_HOOK_EVENTS: [(&str, bool, bool); 11] = [
     ("SessionStart", false, true),
     ("SessionEnd", false, false), // Keep sync for guaranteed cleanup
     ("UserPromptSubmit", false, true),
@@ -37,6 +38,8 @@ const HUD_HOOK_EVENTS: [(&str, bool, bool); 9] = [
     ("Stop", false, true),
     ("PreCompact", false, true),
     ("Notification", false, true),
+    ("SubagentStart", false, true),
+    ("SubagentStop", false, true),
 ];
 
 const HOOK_TIMEOUT_SECONDS: u32 = 30;
@@ -85,6 +88,46 @@ pub struct InstallResult {
     pub script_path: Option<String>,
 }
 
+/// `hooks.Stop` in settings.json before and after a hook install.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct HookInstallPreview {
+    /// Current entries as pretty-printed JSON (`null` when there are none)
+    pub current_stop_hooks: String,
+    /// Entries the install would write, as pretty-printed JSON
+    pub proposed_stop_hooks: String,
+    pub changed: bool,
+}
+
+/// Outcome of [`SetupChecker::install_hooks_with_confirm`].
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct HookInstallOutcome {
+    pub preview: HookInstallPreview,
+    /// `None` when the call was a preview and nothing was written
+    pub result: Option<InstallResult>,
+}
+
+/// A hook entry in settings.json whose command can't run.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct BrokenHook {
+    pub event: String,
+    pub matcher: Option<String>,
+    pub command: String,
+    /// The script or binary the command refers to, after `~` and env expansion
+    pub target: String,
+    pub reason: String,
+}
+
+/// Interpreters whose first path argument is the script actually being run.
+const SCRIPT_INTERPRETERS: &[&str] = &[
+    "sh", "bash", "zsh", "fish", "node", "python", "python3", "ruby", "perl", "bun", "deno",
+];
+
+/// Shell builtins a hook command may start with. They have no file to check.
+const SHELL_BUILTINS: &[&str] = &[
+    "cd", "test", "[", "[[", ":", ".", "source", "echo", "printf", "true", "false", "exit",
+    "export", "eval", "exec", "set", "command",
+];
+
 pub struct SetupChecker {
     storage: StorageConfig,
 }
@@ -609,10 +652,98 @@ impl SetupChecker {
         })
     }
 
+    /// Checks every command hook in settings.json, including other tools' hooks,
+    /// and reports the ones whose script or binary is missing or not executable.
+    ///
+    /// Deleted or moved scripts make hooks fail silently, so this is a hygiene
+    /// check for the whole hooks config. Only the command's executable (or, for
+    /// `bash script.sh` style commands, the script) is checked; bare names are
+    /// looked up on `PATH`, then in the login shell. A missing or unparseable
+    /// settings file yields nothing.
+    pub fn audit_hooks(&self) -> Vec<BrokenHook> {
+        let Some(hooks) = fs::read_to_string(self.storage.claude_settings_file())
+            .ok()
+            .and_then(|c| serde_json::from_str::<SettingsFile>(&c).ok())
+            .and_then(|s| s.hooks)
+        else {
+            return Vec::new();
+        };
+
+        let mut events: Vec<_> = hooks.into_iter().collect();
+        events.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut broken = Vec::new();
+        for (event, configs) in events {
+            for config in configs {
+                for hook in config.hooks.iter().flatten() {
+                    let Some(command) = hook.command.as_deref() else {
+                        continue;
+                    };
+                    if let Some((target, reason)) = check_hook_command(command) {
+                        broken.push(BrokenHook {
+                            event: event.clone(),
+                            matcher: config.matcher.clone(),
+                            command: command.to_string(),
+                            target,
+                            reason,
+                        });
+                    }
+                }
+            }
+        }
+        broken
+    }
+
+    /// Shows what installing the hooks would do to `hooks.Stop` in settings.json
+    /// without writing anything.
+    ///
+    /// Stop is the event users most often have their own hooks on, so it is the
+    /// part worth reviewing before the install rewrites it.
+    pub fn preview_hook_install(&self) -> Result<HookInstallPreview, HudFfiError> {
+        let mut settings = self.read_settings()?;
+        let stop_hooks_json = |settings: &SettingsFile| {
+            let stop = settings.hooks.as_ref().and_then(|hooks| hooks.get("Stop"));
+            serde_json::to_string_pretty(&stop).map_err(|e| HudFfiError::General {
+                message: format!("Failed to serialize settings: {}", e),
+            })
+        };
+
+        let current_stop_hooks = stop_hooks_json(&settings)?;
+        self.apply_hud_hooks(&mut settings);
+        let proposed_stop_hooks = stop_hooks_json(&settings)?;
+
+        Ok(HookInstallPreview {
+            changed: current_stop_hooks != proposed_stop_hooks,
+            current_stop_hooks,
+            proposed_stop_hooks,
+        })
+    }
+
+    /// Two-phase hook install: with `confirm` false, only returns the preview;
+    /// with `confirm` true, returns the preview and runs [`Self::install_hooks`].
+    pub fn install_hooks_with_confirm(
+        &self,
+        confirm: bool,
+    ) -> Result<HookInstallOutcome, HudFfiError> {
+        let preview = self.preview_hook_install()?;
+        let result = if confirm {
+            Some(self.install_hooks()?)
+        } else {
+            None
+        };
+        Ok(HookInstallOutcome { preview, result })
+    }
+
     pub(crate) fn register_hooks_in_settings(&self) -> Result<(), HudFfiError> {
+        let mut settings = self.read_settings()?;
+        self.apply_hud_hooks(&mut settings);
+        self.write_settings(&settings)
+    }
+
+    fn read_settings(&self) -> Result<SettingsFile, HudFfiError> {
         let settings_path = self.storage.claude_settings_file();
 
-        let mut settings: SettingsFile = if settings_path.exists() {
+        let settings: SettingsFile = if settings_path.exists() {
             let content = fs::read_to_string(&settings_path).map_err(|e| HudFfiError::General {
                 message: format!("Failed to read settings: {}", e),
             })?;
@@ -626,7 +757,11 @@ impl SetupChecker {
         } else {
             SettingsFile::default()
         };
+        Ok(settings)
+    }
 
+    /// Adds or normalizes the HUD hook entry for every event it listens to.
+    fn apply_hud_hooks(&self, settings: &mut SettingsFile) {
         let hooks = settings.hooks.get_or_insert_with(HashMap::new);
 
         for (event, needs_matcher, is_async) in HUD_HOOK_EVENTS {
@@ -664,11 +799,13 @@ impl SetupChecker {
                 event_hooks.push(hook_config);
             }
         }
+    }
 
-        let content =
-            serde_json::to_string_pretty(&settings).map_err(|e| HudFfiError::General {
-                message: format!("Failed to serialize settings: {}", e),
-            })?;
+    fn write_settings(&self, settings: &SettingsFile) -> Result<(), HudFfiError> {
+        let settings_path = self.storage.claude_settings_file();
+        let content = serde_json::to_string_pretty(settings).map_err(|e| HudFfiError::General {
+            message: format!("Failed to serialize settings: {}", e),
+        })?;
 
         let settings_dir = settings_path.parent().ok_or_else(|| HudFfiError::General {
             message: "Settings path has no parent directory".to_string(),
@@ -732,6 +869,126 @@ fn which_with_fallback(binary: &str, fallback_paths: &[&str]) -> Option<String>
     None
 }
 
+/// Returns the target and a reason when a hook command can't run.
+///
+/// Leading `VAR=value` assignments are skipped, and commands starting with a
+/// shell builtin are not checked.
+fn check_hook_command(command: &str) -> Option<(String, String)> {
+    let mut words = command
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c| c == '"' || c == '\''))
+        .skip_while(|w| is_env_assignment(w));
+    let first = words.next()?;
+    if SHELL_BUILTINS.contains(&first) {
+        return None;
+    }
+    let program = expand_path(first);
+
+    let program_name = Path::new(&program)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(&program);
+    if SCRIPT_INTERPRETERS.contains(&program_name) {
+        if let Some(script) = words.find(|w| !w.starts_with('-')).map(expand_path) {
+            if script.contains('/') && !Path::new(&script).exists() {
+                return Some((script, "Script not found".to_string()));
+            }
+        }
+    }
+
+    if !program.contains('/') {
+        let on_path = std::env::var_os("PATH")
+            .map(|paths| {
+                std::env::split_paths(&paths).any(|dir| is_executable(&dir.join(&program)))
+            })
+            .unwrap_or(false);
+        // GUI apps get a minimal PATH, so ask the user's login shell before
+        // calling the command missing
+        if on_path || found_by_login_shell(&program) {
+            return None;
+        }
+        return Some((program, "Command not found in app PATH".to_string()));
+    }
+
+    let path = Path::new(&program);
+    if !path.exists() {
+        Some((program, "File not found".to_string()))
+    } else if !is_executable(path) {
+        Some((program, "File is not executable".to_string()))
+    } else {
+        None
+    }
+}
+
+/// Whether `word` is a shell `NAME=value` assignment.
+fn is_env_assignment(word: &str) -> bool {
+    word.split_once('=').is_some_and(|(name, _)| {
+        name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+/// Looks `program` up with `command -v` in the user's login shell.
+fn found_by_login_shell(program: &str) -> bool {
+    let shell = std::env::var("SHELL").unwrap_or_else(|_| "/bin/sh".to_string());
+    Command::new(shell)
+        .args(["-lc", "command -v \"$1\"", "sh", program])
+        .stdin(std::process::Stdio::null())
+        .output()
+        .map(|output| output.status.success() && !output.stdout.is_empty())
+        .unwrap_or(false)
+}
+
+/// Expands a leading `~` and `$VAR` / `${VAR}` references. Unknown variables
+/// expand to nothing, as in the shell.
+fn expand_path(word: &str) -> String {
+    let word = match (word.strip_prefix('~'), dirs::home_dir()) {
+        (Some(rest), Some(home)) if rest.is_empty() || rest.starts_with('/') => {
+            format!("{}{}", home.display(), rest)
+        }
+        _ => word.to_string(),
+    };
+
+    let mut expanded = String::new();
+    let mut chars = word.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            expanded.push(c);
+            continue;
+        }
+        let braced = chars.next_if_eq(&'{').is_some();
+        let mut name = String::new();
+        while let Some(&next) = chars.peek() {
+            if next.is_ascii_alphanumeric() || next == '_' {
+                name.push(next);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        if braced {
+            chars.next_if_eq(&'}');
+        }
+        if name.is_empty() {
+            expanded.push('$');
+        } else {
+            expanded.push_str(&std::env::var(&name).unwrap_or_default());
+        }
+    }
+    expanded
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path).is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
 /// Check if a command is the HUD hook binary.
 fn is_hud_hook_command(cmd: Option<&str>) -> bool {
     cmd.map(|c| c.contains("hud-hook")).unwrap_or(false)
@@ -808,6 +1065,12 @@ mod tests {
 
         let post_tool_use = &settings["hooks"]["PostToolUse"][0];
         assert_eq!(post_tool_use["matcher"], "*");
+
+        let events = settings["hooks"].as_object().unwrap();
+        assert_eq!(events.len(), 11);
+        assert!(events.contains_key("SubagentStart"));
+        assert!(events.contains_key("SubagentStop"));
+        assert!(checker.hooks_registered_in_settings());
     }
 
     #[test]
@@ -883,6 +1146,40 @@ mod tests {
         assert!(settings["hooks"]["SessionStart"].is_array());
     }
 
+    #[test]
+    fn test_install_without_confirm_writes_nothing() {
+        let (_temp, storage) = setup_test_env();
+
+        let existing =
+            r#"{"hooks":{"Stop":[{"hooks":[{"type":"command","command":"notify.sh"}]}]}}"#;
+        fs::write(storage.claude_settings_file(), existing).unwrap();
+
+        let checker = SetupChecker::new(storage.clone());
+        let outcome = checker.install_hooks_with_confirm(false).unwrap();
+
+        assert!(outcome.result.is_none());
+        assert!(outcome.preview.changed);
+        assert!(outcome.preview.current_stop_hooks.contains("notify.sh"));
+        assert!(!outcome.preview.current_stop_hooks.contains(HOOK_COMMAND));
+        assert!(outcome.preview.proposed_stop_hooks.contains("notify.sh"));
+        assert!(outcome.preview.proposed_stop_hooks.contains(HOOK_COMMAND));
+        assert_eq!(
+            fs::read_to_string(storage.claude_settings_file()).unwrap(),
+            existing
+        );
+    }
+
+    #[test]
+    fn test_preview_is_unchanged_once_hooks_are_registered() {
+        let (_temp, storage) = setup_test_env();
+        let checker = SetupChecker::new(storage.clone());
+        checker.register_hooks_in_settings().unwrap();
+
+        let preview = checker.preview_hook_install().unwrap();
+        assert!(!preview.changed);
+        assert_eq!(preview.current_stop_hooks, preview.proposed_stop_hooks);
+    }
+
     #[test]
     fn test_register_hooks_fails_on_corrupt_json() {
         let (_temp, storage) = setup_test_env();
@@ -963,4 +1260,71 @@ mod tests {
     //
     // If install_binary_from_path() needs testing, use integration tests that run
     // in an isolated environment, not unit tests that affect the developer's machine.
+
+    #[test]
+    fn audit_hooks_flags_missing_script_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let (temp, storage) = setup_test_env();
+        let valid = temp.path().join("valid-hook.sh");
+        fs::write(&valid, "#!/bin/sh\n").unwrap();
+        fs::set_permissions(&valid, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let missing = temp.path().join("deleted-hook.sh");
+
+        let settings = serde_json::json!({
+            "hooks": {
+                "PostToolUse": [{
+                    "matcher": "Edit",
+                    "hooks": [
+                        {"type": "command", "command": format!("{} --fast", valid.display())},
+                        {"type": "command", "command": format!("bash {}", missing.display())}
+                    ]
+                }],
+                "Stop": [{
+                    "hooks": [{"type": "command", "command": missing.display().to_string()}]
+                }]
+            }
+        });
+        fs::write(
+            storage.claude_settings_file(),
+            serde_json::to_string(&settings).unwrap(),
+        )
+        .unwrap();
+
+        let broken = SetupChecker::new(storage).audit_hooks();
+        let missing = missing.to_string_lossy().to_string();
+        assert_eq!(broken.len(), 2);
+        assert_eq!(broken[0].event, "PostToolUse");
+        assert_eq!(broken[0].matcher.as_deref(), Some("Edit"));
+        assert_eq!(broken[0].target, missing);
+        assert_eq!(broken[0].reason, "Script not found");
+        assert_eq!(broken[1].event, "Stop");
+        assert_eq!(broken[1].reason, "File not found");
+    }
+
+    #[test]
+    fn check_hook_command_skips_assignments_and_builtins() {
+        assert_eq!(check_hook_command("FOO=1 BAR=two sh -c true"), None);
+        assert_eq!(check_hook_command("cd /tmp && ./run.sh"), None);
+        assert_eq!(check_hook_command("[ -f /tmp/x ] && exit 0"), None);
+        assert_eq!(
+            check_hook_command("DEBUG=1 capacitor-no-such-command --flag"),
+            Some((
+                "capacitor-no-such-command".to_string(),
+                "Command not found in app PATH".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn expand_path_handles_home_and_env_vars() {
+        let home = dirs::home_dir().unwrap().display().to_string();
+        assert_eq!(expand_path("~/bin/x"), format!("{}/bin/x", home));
+        assert_eq!(
+            expand_path("$HOME/.local/bin/hud-hook"),
+            format!("{}/.local/bin/hud-hook", home)
+        );
+        assert_eq!(expand_path("${HOME}/x"), format!("{}/x", home));
+        assert_eq!(expand_path("/usr/bin/env"), "/usr/bin/env");
+    }
 }
@@ -18,14 +18,22 @@
 //!
 //! This runs once per app launch — frequent enough to prevent cruft accumulation,
 //! infrequent enough to not impact performance.
+//!
+//! The file-removing steps are also exposed as standalone maintenance commands
+//! (`reap_stale_locks`, `delete_orphaned_dirs`, `clear_tombstones`, `prune_activity`).
+//! Each takes `dry_run` and returns a [`MaintenanceReport`] listing what was (or
+//! would be) removed. `remove_zombie_sessions` is on-demand only: it drops
+//! records whose cwd was deleted and that hold no live lock.
 
 use fs_err as fs;
-use std::collections::HashSet;
 use std::path::Path;
+use walkdir::WalkDir;
 
 use chrono::{Duration, Utc};
 
-use super::lock::{is_pid_alive, read_lock_info};
+use super::lock::{
+    collect_active_session_ids, is_lock_process_alive, is_pid_alive, read_lock_info,
+};
 use super::store::StateStore;
 use crate::activity::{ActivityStore, CLEANUP_THRESHOLD};
 
@@ -99,7 +107,7 @@ pub fn cleanup_orphaned_lock_holders() -> CleanupStats {
                     stats.orphaned_processes_killed += 1;
                 } else {
                     // Failed to kill - might have already exited
-                    let errno = *libc::__error();
+                    let errno = std::io::Error::last_os_error().raw_os_error().unwrap_or(0);
                     if errno != libc::ESRCH {
                         // ESRCH = no such process (already dead), not an error
                         stats.errors.push(format!(
@@ -150,6 +158,72 @@ pub struct CleanupStats {
     pub errors: Vec<String>,
 }
 
+/// Something a maintenance command removed, or would remove in a dry run.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct MaintenanceItem {
+    /// File or directory path; for activity entries, the edited file's path
+    pub path: String,
+    /// Bytes on disk, 0 for entries inside a shared file
+    pub size_bytes: u64,
+}
+
+/// Result of a maintenance command, shared by every command that takes `dry_run`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, uniffi::Record)]
+pub struct MaintenanceReport {
+    pub dry_run: bool,
+    /// Items removed, or that would be removed when `dry_run` is true
+    pub items: Vec<MaintenanceItem>,
+    pub errors: Vec<String>,
+}
+
+impl MaintenanceReport {
+    fn new(dry_run: bool) -> Self {
+        Self {
+            dry_run,
+            ..Default::default()
+        }
+    }
+
+    /// Records `path` and removes it unless this is a dry run.
+    fn remove(&mut self, path: &Path, what: &str) {
+        let item = MaintenanceItem {
+            path: path.to_string_lossy().to_string(),
+            size_bytes: disk_size(path),
+        };
+        if !self.dry_run {
+            let result = if path.is_dir() {
+                fs::remove_dir_all(path)
+            } else {
+                fs::remove_file(path)
+            };
+            if let Err(e) = result {
+                self.errors.push(format!(
+                    "Failed to remove {} {}: {}",
+                    what,
+                    path.display(),
+                    e
+                ));
+                return;
+            }
+        }
+        self.items.push(item);
+    }
+
+    fn count(&self) -> u32 {
+        self.items.len() as u32
+    }
+}
+
+fn disk_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.metadata().ok())
+        .filter(|m| m.is_file())
+        .map(|m| m.len())
+        .sum()
+}
+
 /// Performs startup cleanup on all artifacts.
 ///
 /// This is the main entry point called on app launch.
@@ -165,7 +239,11 @@ pub struct CleanupStats {
 /// 3. Lost events self-heal on next hook event (state is refreshed)
 ///
 /// Adding file locking would add complexity for marginal benefit.
-pub fn run_startup_cleanup(lock_base: &Path, state_file: &Path) -> CleanupStats {
+pub fn run_startup_cleanup(
+    lock_base: &Path,
+    state_file: &Path,
+    stale_threshold_secs: i64,
+) -> CleanupStats {
     let mut stats = CleanupStats::default();
 
     // 0. Kill orphaned lock-holder processes FIRST (before cleaning lock files)
@@ -188,7 +266,7 @@ pub fn run_startup_cleanup(lock_base: &Path, state_file: &Path) -> CleanupStats
     // This is important for v4 session-based locks: when a session ends,
     // its lock is released but the record may linger. Without the stale Ready
     // fallback, these orphaned records should be cleaned up.
-    let orphan_stats = cleanup_orphaned_sessions(lock_base, state_file);
+    let orphan_stats = cleanup_orphaned_sessions(lock_base, state_file, stale_threshold_secs);
     stats.orphaned_sessions_removed = orphan_stats.orphaned_sessions_removed;
     stats.errors.extend(orphan_stats.errors);
 
@@ -208,28 +286,9 @@ pub fn run_startup_cleanup(lock_base: &Path, state_file: &Path) -> CleanupStats
 
     // 6. Clean up old file activity entries
     // Activity file is sibling to state file: ~/.capacitor/file-activity.json
-    let activity_file = state_file.with_file_name("file-activity.json");
-    if activity_file.exists() {
-        let mut activity_store = ActivityStore::load(&activity_file);
-        let entries_before: u32 = activity_store
-            .sessions
-            .values()
-            .map(|s| s.activity.len() as u32)
-            .sum();
-        activity_store.cleanup_old_entries(CLEANUP_THRESHOLD);
-        let entries_after: u32 = activity_store
-            .sessions
-            .values()
-            .map(|s| s.activity.len() as u32)
-            .sum();
-        stats.activity_entries_removed = entries_before.saturating_sub(entries_after);
-
-        if let Err(e) = activity_store.save(&activity_file) {
-            stats
-                .errors
-                .push(format!("Failed to save activity file: {}", e));
-        }
-    }
+    let activity_report = prune_activity(&state_file.with_file_name("file-activity.json"), false);
+    stats.activity_entries_removed = activity_report.count();
+    stats.errors.extend(activity_report.errors);
 
     stats
 }
@@ -242,17 +301,32 @@ pub fn run_startup_cleanup(lock_base: &Path, state_file: &Path) -> CleanupStats
 ///
 /// Note: Despite the name, this checks PID liveness, not timestamp staleness.
 fn cleanup_stale_locks(lock_base: &Path) -> CleanupStats {
-    let mut stats = CleanupStats::default();
+    let report = reap_stale_locks(lock_base, false);
+    CleanupStats {
+        locks_removed: report.count(),
+        errors: report.errors,
+        ..Default::default()
+    }
+}
+
+/// Removes lock directories whose PID is dead or whose metadata is unreadable.
+///
+/// A PID that is alive but started at a different time than the lock records
+/// has been recycled by an unrelated process, so its lock counts as dead.
+///
+/// With `dry_run`, reports the locks without removing them.
+pub fn reap_stale_locks(lock_base: &Path, dry_run: bool) -> MaintenanceReport {
+    let mut report = MaintenanceReport::new(dry_run);
 
     let entries = match fs::read_dir(lock_base) {
         Ok(e) => e,
         Err(e) => {
             if e.kind() != std::io::ErrorKind::NotFound {
-                stats
+                report
                     .errors
                     .push(format!("Failed to read lock directory: {}", e));
             }
-            return stats;
+            return report;
         }
     };
 
@@ -263,45 +337,57 @@ fn cleanup_stale_locks(lock_base: &Path) -> CleanupStats {
         }
 
         let should_remove = match read_lock_info(&path) {
-            Some(info) => !is_pid_alive(info.pid),
+            Some(info) => !is_lock_process_alive(&info),
             None => true, // Corrupt or unreadable lock — remove it
         };
 
         if should_remove {
-            if let Err(e) = fs::remove_dir_all(&path) {
-                stats.errors.push(format!(
-                    "Failed to remove stale lock {}: {}",
-                    path.display(),
-                    e
-                ));
-            } else {
-                stats.locks_removed += 1;
-            }
+            report.remove(&path, "stale lock");
         }
     }
 
-    stats
+    report
+}
+
+/// Removes lock directories left behind by crashed sessions, i.e. those whose
+/// PID is dead (or whose metadata is unreadable). Returns how many were removed.
+///
+/// Locks are normally released on `SessionEnd`, which never fires when Claude
+/// crashes. Runs as part of [`run_startup_cleanup`].
+pub fn clean_dead_locks(lock_base: &Path) -> usize {
+    cleanup_stale_locks(lock_base).locks_removed as usize
 }
 
 /// Removes legacy MD5-hash format locks.
+fn cleanup_legacy_locks(lock_base: &Path) -> CleanupStats {
+    let report = delete_orphaned_dirs(lock_base, false);
+    CleanupStats {
+        legacy_locks_removed: report.count(),
+        errors: report.errors,
+        ..Default::default()
+    }
+}
+
+/// Removes orphaned legacy MD5-hash lock directories.
 ///
 /// Legacy locks use the format `{32-hex-chars}.lock` (MD5 hash of path).
 /// Modern session-based locks use `{session_id}-{pid}.lock` (contain "-").
 ///
 /// This cleans up locks created by old versions of hud-hook that used
-/// path-based locking instead of session-based locking.
-fn cleanup_legacy_locks(lock_base: &Path) -> CleanupStats {
-    let mut stats = CleanupStats::default();
+/// path-based locking instead of session-based locking. Only locks with a dead
+/// PID or unreadable metadata are removed. With `dry_run`, nothing is deleted.
+pub fn delete_orphaned_dirs(lock_base: &Path, dry_run: bool) -> MaintenanceReport {
+    let mut report = MaintenanceReport::new(dry_run);
 
     let entries = match fs::read_dir(lock_base) {
         Ok(e) => e,
         Err(e) => {
             if e.kind() != std::io::ErrorKind::NotFound {
-                stats
+                report
                     .errors
                     .push(format!("Failed to read lock directory: {}", e));
             }
-            return stats;
+            return report;
         }
     };
 
@@ -331,24 +417,16 @@ fn cleanup_legacy_locks(lock_base: &Path) -> CleanupStats {
 
         // Read lock info and check if PID is dead
         let should_remove = match read_lock_info(&path) {
-            Some(info) => !is_pid_alive(info.pid),
+            Some(info) => !is_lock_process_alive(&info),
             None => true, // Corrupt or unreadable lock — remove it
         };
 
         if should_remove {
-            if let Err(e) = fs::remove_dir_all(&path) {
-                stats.errors.push(format!(
-                    "Failed to remove legacy lock {}: {}",
-                    path.display(),
-                    e
-                ));
-            } else {
-                stats.legacy_locks_removed += 1;
-            }
+            report.remove(&path, "legacy lock");
         }
     }
 
-    stats
+    report
 }
 
 /// Removes session records that don't have an active lock.
@@ -357,9 +435,14 @@ fn cleanup_legacy_locks(lock_base: &Path) -> CleanupStats {
 /// by session ID. Session records without active locks are orphaned and
 /// should be removed to prevent state pollution.
 ///
-/// Note: This only removes records that are stale (> 5 minutes old) to avoid
-/// race conditions where a record exists but the lock hasn't been created yet.
-fn cleanup_orphaned_sessions(lock_base: &Path, state_file: &Path) -> CleanupStats {
+/// Note: This only removes records older than `stale_threshold_secs` (the
+/// resolver's stale threshold) to avoid race conditions where a record exists
+/// but the lock hasn't been created yet.
+fn cleanup_orphaned_sessions(
+    lock_base: &Path,
+    state_file: &Path,
+    stale_threshold_secs: i64,
+) -> CleanupStats {
     let mut stats = CleanupStats::default();
 
     let mut store = match StateStore::load(state_file) {
@@ -372,35 +455,11 @@ fn cleanup_orphaned_sessions(lock_base: &Path, state_file: &Path) -> CleanupStat
         }
     };
 
-    // Collect all active session IDs from locks
-    let active_session_ids: HashSet<String> = collect_active_session_ids(lock_base);
-
-    // Find orphaned session records (stale records with no active lock)
-    let orphaned_session_ids: Vec<String> = store
-        .sessions()
-        .filter(|r| {
-            // Only consider stale records to avoid race conditions
-            if !r.is_stale() {
-                return false;
-            }
-
-            // Check if this session has an active lock
-            // For session-based locks (v4), we look for {session_id}.lock
-            // For legacy path-based locks (v3), we'd need path matching
-            !active_session_ids.contains(&r.session_id)
-        })
-        .map(|r| r.session_id.clone())
-        .collect();
-
-    if orphaned_session_ids.is_empty() {
+    stats.orphaned_sessions_removed = store.prune_stale(lock_base, stale_threshold_secs) as u32;
+    if stats.orphaned_sessions_removed == 0 {
         return stats;
     }
 
-    for session_id in &orphaned_session_ids {
-        store.remove(session_id);
-        stats.orphaned_sessions_removed += 1;
-    }
-
     if let Err(e) = store.save() {
         stats
             .errors
@@ -410,53 +469,134 @@ fn cleanup_orphaned_sessions(lock_base: &Path, state_file: &Path) -> CleanupStat
     stats
 }
 
-/// Collects all session IDs from active locks.
-fn collect_active_session_ids(lock_base: &Path) -> HashSet<String> {
-    let mut session_ids = HashSet::new();
+/// Removes stale session records with no live lock, returning how many went.
+///
+/// Same rule as the startup orphan sweep, exposed so long-running installs can
+/// shrink `sessions.json` on demand. Fresh records and records whose session
+/// still holds a lock are always kept.
+pub fn compact_state_store(
+    lock_base: &Path,
+    state_file: &Path,
+    stale_threshold_secs: i64,
+) -> Result<u32, String> {
+    let stats = cleanup_orphaned_sessions(lock_base, state_file, stale_threshold_secs);
+    match stats.errors.into_iter().next() {
+        Some(error) => Err(error),
+        None => Ok(stats.orphaned_sessions_removed),
+    }
+}
 
-    let entries = match fs::read_dir(lock_base) {
-        Ok(e) => e,
-        Err(_) => return session_ids,
-    };
+/// A session record whose working directory no longer exists and whose session
+/// holds no live lock.
+///
+/// Unlike stale records, which may belong to a quiet but valid session, these
+/// can never become active again.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct ZombieSession {
+    pub session_id: String,
+    /// The record's cwd, which is gone from disk
+    pub cwd: String,
+}
 
-    for entry in entries.flatten() {
-        let path = entry.path();
-        if !path.is_dir() || !path.extension().is_some_and(|e| e == "lock") {
-            continue;
+/// Lists zombie session records, sorted by session id.
+pub fn find_zombie_sessions(
+    lock_base: &Path,
+    state_file: &Path,
+) -> Result<Vec<ZombieSession>, String> {
+    let store = StateStore::load(state_file)?;
+    Ok(zombie_sessions_in(&store, lock_base))
+}
+
+/// Removes zombie session records from the state store.
+///
+/// Guarded: records are re-checked against the disk and live locks when this
+/// runs, so a session that came back to life since `find_zombie_sessions` is
+/// kept. Reported items carry the dead cwd and a size of 0. With `dry_run`,
+/// the state file is untouched.
+pub fn remove_zombie_sessions(
+    lock_base: &Path,
+    state_file: &Path,
+    dry_run: bool,
+) -> MaintenanceReport {
+    let mut report = MaintenanceReport::new(dry_run);
+
+    let mut store = match StateStore::load(state_file) {
+        Ok(s) => s,
+        Err(e) => {
+            report
+                .errors
+                .push(format!("Failed to load state file: {}", e));
+            return report;
         }
+    };
 
-        if let Some(info) = read_lock_info(&path) {
-            // Only consider alive locks
-            if is_pid_alive(info.pid) {
-                // For session-based locks, session_id is in meta.json
-                if let Some(sid) = info.session_id {
-                    session_ids.insert(sid);
-                }
-                // For legacy path-based locks, we can extract from lock dir name
-                // but those don't have session_id, so we skip them
-            }
+    let zombies = zombie_sessions_in(&store, lock_base);
+    if zombies.is_empty() {
+        return report;
+    }
+    report.items = zombies
+        .iter()
+        .map(|z| MaintenanceItem {
+            path: z.cwd.clone(),
+            size_bytes: 0,
+        })
+        .collect();
+
+    if !dry_run {
+        for zombie in &zombies {
+            store.remove(&zombie.session_id);
+        }
+        if let Err(e) = store.save() {
+            report
+                .errors
+                .push(format!("Failed to save cleaned state file: {}", e));
         }
     }
 
-    session_ids
+    report
+}
+
+fn zombie_sessions_in(store: &StateStore, lock_base: &Path) -> Vec<ZombieSession> {
+    let active_session_ids = collect_active_session_ids(lock_base);
+    let mut zombies: Vec<ZombieSession> = store
+        .sessions()
+        .filter(|r| !active_session_ids.contains(&r.session_id) && !Path::new(&r.cwd).exists())
+        .map(|r| ZombieSession {
+            session_id: r.session_id.clone(),
+            cwd: r.cwd.clone(),
+        })
+        .collect();
+    zombies.sort_by(|a, b| a.session_id.cmp(&b.session_id));
+    zombies
+}
+
+/// Removes tombstone files older than 1 minute.
+fn cleanup_old_tombstones(tombstones_dir: &Path) -> CleanupStats {
+    let report = clear_tombstones(tombstones_dir, false);
+    CleanupStats {
+        tombstones_removed: report.count(),
+        errors: report.errors,
+        ..Default::default()
+    }
 }
 
 /// Removes tombstone files older than 1 minute.
 ///
 /// Tombstones are used to prevent race conditions where events arrive after
 /// SessionEnd. They only need to live long enough to block stray events.
-fn cleanup_old_tombstones(tombstones_dir: &Path) -> CleanupStats {
-    let mut stats = CleanupStats::default();
+/// With `dry_run`, reports them without removing anything.
+pub fn clear_tombstones(tombstones_dir: &Path, dry_run: bool) -> MaintenanceReport {
+    let mut report = MaintenanceReport::new(dry_run);
 
     let entries = match fs::read_dir(tombstones_dir) {
         Ok(e) => e,
         Err(e) => {
             if e.kind() != std::io::ErrorKind::NotFound {
-                stats
+                report
                     .errors
                     .push(format!("Failed to read tombstones directory: {}", e));
             }
-            return stats;
+            return report;
         }
     };
 
@@ -477,19 +617,43 @@ fn cleanup_old_tombstones(tombstones_dir: &Path) -> CleanupStats {
         };
 
         if should_remove {
-            if let Err(e) = fs::remove_file(&path) {
-                stats.errors.push(format!(
-                    "Failed to remove tombstone {}: {}",
-                    path.display(),
-                    e
-                ));
-            } else {
-                stats.tombstones_removed += 1;
-            }
+            report.remove(&path, "tombstone");
         }
     }
 
-    stats
+    report
+}
+
+/// Removes file activity entries older than the activity cleanup threshold.
+///
+/// Entries live inside one shared file, so reported items carry the edited
+/// file's path and a size of 0. With `dry_run`, the activity file is untouched.
+pub fn prune_activity(activity_file: &Path, dry_run: bool) -> MaintenanceReport {
+    let mut report = MaintenanceReport::new(dry_run);
+    if !activity_file.exists() {
+        return report;
+    }
+
+    let mut activity_store = ActivityStore::load(activity_file);
+    report.items = activity_store
+        .expired_entries(CLEANUP_THRESHOLD)
+        .into_iter()
+        .map(|a| MaintenanceItem {
+            path: a.file_path.clone(),
+            size_bytes: 0,
+        })
+        .collect();
+
+    if !dry_run {
+        activity_store.cleanup_old_entries(CLEANUP_THRESHOLD);
+        if let Err(e) = activity_store.save(activity_file) {
+            report
+                .errors
+                .push(format!("Failed to save activity file: {}", e));
+        }
+    }
+
+    report
 }
 
 /// Removes expired session records (older than 24 hours).
@@ -541,6 +705,7 @@ fn cleanup_old_sessions(state_file: &Path) -> CleanupStats {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::types::STALE_THRESHOLD_SECS;
     use std::fs;
     use tempfile::tempdir;
 
@@ -588,6 +753,95 @@ mod tests {
         );
     }
 
+    #[test]
+    fn clean_dead_locks_removes_only_session_locks_with_dead_pids() {
+        use crate::state::lock::tests_helper::{
+            create_session_lock, create_session_lock_with_timestamps,
+        };
+        let temp = tempdir().unwrap();
+        let lock_base = temp.path().join("sessions");
+        fs::create_dir_all(&lock_base).unwrap();
+
+        create_session_lock_with_timestamps(&lock_base, 99999999, "/dead", "crashed", 1, 1);
+        create_session_lock(&lock_base, std::process::id(), "/live", "running");
+
+        assert_eq!(clean_dead_locks(&lock_base), 1);
+        assert!(!lock_base.join("crashed-99999999.lock").exists());
+        assert!(lock_base
+            .join(format!("running-{}.lock", std::process::id()))
+            .exists());
+        assert_eq!(clean_dead_locks(&lock_base), 0);
+    }
+
+    #[test]
+    fn reap_stale_locks_treats_recycled_pid_as_dead() {
+        use crate::state::lock::tests_helper::create_session_lock_with_timestamps;
+        let temp = tempdir().unwrap();
+        let lock_base = temp.path().join("sessions");
+        fs::create_dir_all(&lock_base).unwrap();
+
+        // Live PID, but the lock was taken by a process that started long ago
+        let pid = std::process::id();
+        create_session_lock_with_timestamps(&lock_base, pid, "/p", "recycled", 1, 1);
+        // Legacy lock without proc_started: plain PID check keeps it
+        create_lock_with_pid(&lock_base, "/legacy", pid);
+
+        let report = reap_stale_locks(&lock_base, false);
+        assert_eq!(report.items.len(), 1);
+        assert!(!lock_base.join(format!("recycled-{}.lock", pid)).exists());
+        assert!(lock_base
+            .join(format!("{:x}.lock", md5::compute("/legacy")))
+            .exists());
+    }
+
+    #[test]
+    fn reap_stale_locks_dry_run_reports_without_removing() {
+        let temp = tempdir().unwrap();
+        let lock_base = temp.path().join("sessions");
+        fs::create_dir_all(&lock_base).unwrap();
+
+        create_lock_with_pid(&lock_base, "/dead/project", 99999999);
+        create_lock_with_pid(&lock_base, "/live/project", std::process::id());
+        let dead_lock = lock_base.join(format!("{:x}.lock", md5::compute("/dead/project")));
+
+        let report = reap_stale_locks(&lock_base, true);
+
+        assert!(report.dry_run);
+        assert_eq!(report.items.len(), 1);
+        assert_eq!(report.items[0].path, dead_lock.to_string_lossy());
+        assert!(report.items[0].size_bytes > 0);
+        assert!(dead_lock.exists(), "Dry run must not remove the lock");
+
+        let report = reap_stale_locks(&lock_base, false);
+        assert_eq!(report.items.len(), 1);
+        assert!(!dead_lock.exists());
+    }
+
+    #[test]
+    fn prune_activity_dry_run_leaves_file_untouched() {
+        let temp = tempdir().unwrap();
+        let activity_file = temp.path().join("file-activity.json");
+        let mut store = ActivityStore::new();
+        let session = store.sessions.entry("s1".to_string()).or_default();
+        session.activity.push(crate::activity::FileActivity {
+            project_path: "/p".to_string(),
+            file_path: "/p/old.rs".to_string(),
+            tool: "Edit".to_string(),
+            timestamp: (Utc::now() - Duration::hours(3)).to_rfc3339(),
+        });
+        store.save(&activity_file).unwrap();
+        let before = fs::read_to_string(&activity_file).unwrap();
+
+        let report = prune_activity(&activity_file, true);
+        assert_eq!(report.items.len(), 1);
+        assert_eq!(report.items[0].path, "/p/old.rs");
+        assert_eq!(fs::read_to_string(&activity_file).unwrap(), before);
+
+        let report = prune_activity(&activity_file, false);
+        assert_eq!(report.items.len(), 1);
+        assert!(ActivityStore::load(&activity_file).sessions.is_empty());
+    }
+
     #[test]
     fn cleanup_removes_corrupt_locks() {
         let temp = tempdir().unwrap();
@@ -699,7 +953,7 @@ mod tests {
         });
         fs::write(&state_file, serde_json::to_string_pretty(&content).unwrap()).unwrap();
 
-        let stats = run_startup_cleanup(&lock_base, &state_file);
+        let stats = run_startup_cleanup(&lock_base, &state_file, STALE_THRESHOLD_SECS);
 
         // The stale lock is a legacy MD5-hash lock (create_lock_with_pid uses legacy format),
         // so it's cleaned by legacy_locks cleanup. Modern session-based locks would be
@@ -740,7 +994,7 @@ mod tests {
         });
         fs::write(&state_file, serde_json::to_string_pretty(&content).unwrap()).unwrap();
 
-        let stats = cleanup_orphaned_sessions(&lock_base, &state_file);
+        let stats = cleanup_orphaned_sessions(&lock_base, &state_file, STALE_THRESHOLD_SECS);
 
         assert_eq!(
             stats.orphaned_sessions_removed, 1,
@@ -775,7 +1029,7 @@ mod tests {
         });
         fs::write(&state_file, serde_json::to_string_pretty(&content).unwrap()).unwrap();
 
-        let stats = cleanup_orphaned_sessions(&lock_base, &state_file);
+        let stats = cleanup_orphaned_sessions(&lock_base, &state_file, STALE_THRESHOLD_SECS);
 
         assert_eq!(
             stats.orphaned_sessions_removed, 0,
@@ -786,4 +1040,154 @@ mod tests {
         let store = StateStore::load(&state_file).unwrap();
         assert!(store.get_by_session_id("fresh-session").is_some());
     }
+
+    #[test]
+    fn cleanup_orphaned_sessions_honors_raised_stale_threshold() {
+        let temp = tempdir().unwrap();
+        let lock_base = temp.path().join("sessions");
+        let state_file = temp.path().join("sessions.json");
+        fs::create_dir_all(&lock_base).unwrap();
+
+        // Stale under the 5-minute default, fresh under a 1-hour threshold
+        let quiet_time = (Utc::now() - Duration::minutes(10)).to_rfc3339();
+        let content = serde_json::json!({
+            "version": 3,
+            "sessions": {
+                "quiet-session": {
+                    "session_id": "quiet-session",
+                    "state": "working",
+                    "cwd": "/quiet/project",
+                    "updated_at": quiet_time,
+                    "state_changed_at": quiet_time
+                }
+            }
+        });
+        fs::write(&state_file, serde_json::to_string_pretty(&content).unwrap()).unwrap();
+
+        let stats = cleanup_orphaned_sessions(&lock_base, &state_file, 3600);
+        assert_eq!(stats.orphaned_sessions_removed, 0);
+
+        let stats = cleanup_orphaned_sessions(&lock_base, &state_file, STALE_THRESHOLD_SECS);
+        assert_eq!(stats.orphaned_sessions_removed, 1);
+    }
+
+    #[test]
+    fn compact_state_store_keeps_fresh_and_locked_records() {
+        let temp = tempdir().unwrap();
+        let lock_base = temp.path().join("sessions");
+        let state_file = temp.path().join("sessions.json");
+        fs::create_dir_all(&lock_base).unwrap();
+
+        let stale_time = (Utc::now() - Duration::minutes(10)).to_rfc3339();
+        let now = Utc::now().to_rfc3339();
+        let record = |id: &str, at: &str| {
+            serde_json::json!({
+                "session_id": id,
+                "state": "ready",
+                "cwd": format!("/{}", id),
+                "updated_at": at,
+                "state_changed_at": at
+            })
+        };
+        let content = serde_json::json!({
+            "version": 3,
+            "sessions": {
+                "stale": record("stale", &stale_time),
+                "fresh": record("fresh", &now),
+                "locked": record("locked", &stale_time)
+            }
+        });
+        fs::write(&state_file, serde_json::to_string_pretty(&content).unwrap()).unwrap();
+
+        let lock_dir = lock_base.join("locked-12345.lock");
+        fs::create_dir_all(&lock_dir).unwrap();
+        fs::write(lock_dir.join("pid"), std::process::id().to_string()).unwrap();
+        fs::write(
+            lock_dir.join("meta.json"),
+            format!(
+                r#"{{"pid": {}, "path": "/locked", "session_id": "locked"}}"#,
+                std::process::id()
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            compact_state_store(&lock_base, &state_file, STALE_THRESHOLD_SECS),
+            Ok(1)
+        );
+
+        let store = StateStore::load(&state_file).unwrap();
+        assert!(store.get_by_session_id("stale").is_none());
+        assert!(store.get_by_session_id("fresh").is_some());
+        assert!(store.get_by_session_id("locked").is_some());
+    }
+
+    #[test]
+    fn zombie_sessions_are_records_with_deleted_cwd_and_no_lock() {
+        let temp = tempdir().unwrap();
+        let lock_base = temp.path().join("sessions");
+        let state_file = temp.path().join("sessions.json");
+        fs::create_dir_all(&lock_base).unwrap();
+        let live_dir = temp.path().join("live");
+        fs::create_dir_all(&live_dir).unwrap();
+        let deleted_dir = temp.path().join("deleted");
+
+        let now = Utc::now().to_rfc3339();
+        let record = |id: &str, cwd: &Path| {
+            serde_json::json!({
+                "session_id": id,
+                "state": "ready",
+                "cwd": cwd.to_string_lossy(),
+                "updated_at": now,
+                "state_changed_at": now
+            })
+        };
+        let content = serde_json::json!({
+            "version": 3,
+            "sessions": {
+                "zombie": record("zombie", &deleted_dir),
+                "valid": record("valid", &live_dir),
+                "locked": record("locked", &deleted_dir)
+            }
+        });
+        fs::write(&state_file, serde_json::to_string_pretty(&content).unwrap()).unwrap();
+
+        let lock_dir = lock_base.join("locked-12345.lock");
+        fs::create_dir_all(&lock_dir).unwrap();
+        fs::write(lock_dir.join("pid"), std::process::id().to_string()).unwrap();
+        fs::write(
+            lock_dir.join("meta.json"),
+            format!(
+                r#"{{"pid": {}, "path": "{}", "session_id": "locked"}}"#,
+                std::process::id(),
+                deleted_dir.display()
+            ),
+        )
+        .unwrap();
+
+        let zombies = find_zombie_sessions(&lock_base, &state_file).unwrap();
+        assert_eq!(
+            zombies,
+            vec![ZombieSession {
+                session_id: "zombie".to_string(),
+                cwd: deleted_dir.to_string_lossy().to_string(),
+            }]
+        );
+
+        let preview = remove_zombie_sessions(&lock_base, &state_file, true);
+        assert_eq!(preview.items.len(), 1);
+        assert!(StateStore::load(&state_file)
+            .unwrap()
+            .get_by_session_id("zombie")
+            .is_some());
+
+        let report = remove_zombie_sessions(&lock_base, &state_file, false);
+        assert!(report.errors.is_empty());
+        assert_eq!(report.items[0].path, deleted_dir.to_string_lossy());
+
+        let store = StateStore::load(&state_file).unwrap();
+        assert!(store.get_by_session_id("zombie").is_none());
+        assert!(store.get_by_session_id("valid").is_some());
+        assert!(store.get_by_session_id("locked").is_some());
+    }
 }
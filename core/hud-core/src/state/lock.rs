@@ -48,6 +48,7 @@ use super::path_utils::{normalize_path_for_comparison, normalize_path_for_hashin
 use super::types::LockInfo;
 use fs_err as fs;
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::path::Path;
 
 // Thread-local sysinfo cache. We use per-PID refresh (O(1)) instead of scanning
@@ -208,6 +209,19 @@ fn is_pid_alive_verified(pid: u32, expected_start: Option<u64>) -> bool {
     }
 }
 
+/// Whether a lock's process is still the one that took the lock.
+///
+/// Locks recording `proc_started` are checked against the live process's start
+/// time, so a recycled PID reads as dead. Legacy locks without it fall back to
+/// a plain PID check. Used by cleanup, where the stricter legacy name check
+/// would remove locks held by non-`claude` processes.
+pub(crate) fn is_lock_process_alive(info: &LockInfo) -> bool {
+    match info.proc_started {
+        Some(started) => is_pid_alive_verified(info.pid, Some(started)),
+        None => is_pid_alive(info.pid),
+    }
+}
+
 pub(crate) fn read_lock_info(lock_dir: &Path) -> Option<LockInfo> {
     let pid_path = lock_dir.join("pid");
     let meta_path = lock_dir.join("meta.json");
@@ -243,6 +257,11 @@ pub(crate) fn read_lock_info(lock_dir: &Path) -> Option<LockInfo> {
         .get("lock_version")
         .and_then(|v| v.as_str().map(String::from));
 
+    // Handle transcript_path (resolver tiebreaker hint)
+    let transcript_path = meta
+        .get("transcript_path")
+        .and_then(|v| v.as_str().map(String::from));
+
     let info = LockInfo {
         pid,
         path,
@@ -250,6 +269,7 @@ pub(crate) fn read_lock_info(lock_dir: &Path) -> Option<LockInfo> {
         proc_started,
         created,
         lock_version,
+        transcript_path,
     };
 
     // Age-based expiry for legacy locks (no proc_started)
@@ -362,6 +382,58 @@ pub fn has_any_active_lock(lock_base: &Path) -> bool {
     false
 }
 
+/// Collects all session IDs from active locks.
+pub(crate) fn collect_active_session_ids(lock_base: &Path) -> HashSet<String> {
+    let mut session_ids = HashSet::new();
+
+    let entries = match fs::read_dir(lock_base) {
+        Ok(e) => e,
+        Err(_) => return session_ids,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() || !path.extension().is_some_and(|e| e == "lock") {
+            continue;
+        }
+
+        if let Some(info) = read_lock_info(&path) {
+            // Only consider alive locks
+            if is_lock_process_alive(&info) {
+                // For session-based locks, session_id is in meta.json
+                if let Some(sid) = info.session_id {
+                    session_ids.insert(sid);
+                }
+                // For legacy path-based locks, we can extract from lock dir name
+                // but those don't have session_id, so we skip them
+            }
+        }
+    }
+
+    session_ids
+}
+
+/// Finds live locks whose project path no longer exists on disk.
+///
+/// This happens when a session's folder is deleted while Claude keeps running:
+/// the lock holder still sees a live PID, so the lock is never released.
+pub fn find_live_locks_with_missing_path(lock_base: &Path) -> Vec<LockInfo> {
+    let entries = match fs::read_dir(lock_base) {
+        Ok(e) => e,
+        Err(_) => return Vec::new(),
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir() && path.extension().is_some_and(|e| e == "lock"))
+        .filter_map(|path| read_lock_info(&path))
+        .filter(|info| {
+            !Path::new(&info.path).exists() && is_pid_alive_verified(info.pid, info.proc_started)
+        })
+        .collect()
+}
+
 /// Returns true if there's an active lock at or under the given path.
 ///
 /// Checks:
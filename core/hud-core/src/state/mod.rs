@@ -35,6 +35,7 @@
 //! - [`lock`]: Lock file detection and PID verification
 //! - [`resolver`]: Fuses lock + state data to answer "is Claude running here?"
 //! - [`store`]: Reads/writes the JSON state file (`~/.capacitor/sessions.json`)
+//! - [`transitions`]: Append-only state transition log (`~/.capacitor/transitions.jsonl`)
 //! - [`types`]: Data structures, staleness thresholds, canonical state mapping
 //!
 //! # Key Entry Points
@@ -48,6 +49,7 @@ pub(crate) mod lock;
 mod path_utils;
 mod resolver;
 mod store;
+mod transitions;
 pub(crate) mod types;
 
 // Re-export path utilities for use across the crate
@@ -55,14 +57,25 @@ pub use path_utils::{
     normalize_path_for_comparison, normalize_path_for_hashing, normalize_path_for_matching,
 };
 
-pub use cleanup::{run_startup_cleanup, CleanupStats};
+pub use cleanup::{
+    clean_dead_locks, clear_tombstones, compact_state_store, delete_orphaned_dirs,
+    find_zombie_sessions, prune_activity, reap_stale_locks, remove_zombie_sessions,
+    run_startup_cleanup, CleanupStats, MaintenanceItem, MaintenanceReport, ZombieSession,
+};
 pub use lock::{
     count_other_session_locks, create_lock, create_session_lock, find_all_locks_for_path,
-    get_lock_info, get_session_lock_dir_path, is_pid_alive, is_session_running,
-    release_lock_by_session, update_lock_pid,
+    find_live_locks_with_missing_path, get_lock_info, get_session_lock_dir_path, is_pid_alive,
+    is_session_running, release_lock_by_session, update_lock_pid,
+};
+pub use resolver::{
+    resolve_state, resolve_state_with_config, resolve_state_with_details, ResolvedState,
+    ResolverConfig,
 };
-pub use resolver::{resolve_state, resolve_state_with_details, ResolvedState};
 pub use store::StateStore;
+pub use transitions::{
+    append_transition, load_session_transitions, load_transitions, TransitionRecord,
+    TRANSITIONS_MAX_BYTES,
+};
 pub use types::{
     HookEvent, HookInput, LastEvent, LockInfo, SessionRecord, ToolInput, ToolResponse,
 };
@@ -10,7 +10,9 @@ use crate::types::SessionState;
 use super::lock::{find_all_locks_for_path, find_lock_for_path};
 use super::path_utils::normalize_path_for_comparison;
 use super::store::StateStore;
-use super::types::SessionRecord;
+use super::types::{SessionRecord, ACTIVE_STATE_STALE_SECS, STALE_THRESHOLD_SECS};
+use crate::storage::StorageConfig;
+use crate::types::HudConfig;
 
 /// Normalizes a path for consistent comparison.
 /// Handles trailing slashes, case sensitivity (macOS), and symlinks.
@@ -27,13 +29,59 @@ pub struct ResolvedState {
     pub cwd: String,
     /// True if this state was resolved via a lock file (vs fresh record fallback).
     pub is_from_lock: bool,
+    /// True when a lock exists but the record is just past staleness, within
+    /// [`super::types::STALE_GRACE_SECS`]. The state is still trusted; the UI may hedge.
+    pub possibly_stale: bool,
+}
+
+/// Staleness thresholds used when resolving state.
+///
+/// The defaults are [`STALE_THRESHOLD_SECS`] and [`ACTIVE_STATE_STALE_SECS`].
+/// Slow machines or long tool calls can raise them via `HudConfig` so a busy
+/// session isn't marked stale mid-operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolverConfig {
+    /// Records not updated within this many seconds are untrusted without a lock
+    pub stale_threshold_secs: i64,
+    /// Lockless Working/Thinking/Waiting records fall back to Ready after this many seconds
+    pub active_state_stale_secs: i64,
+}
+
+impl Default for ResolverConfig {
+    fn default() -> Self {
+        Self {
+            stale_threshold_secs: STALE_THRESHOLD_SECS,
+            active_state_stale_secs: ACTIVE_STATE_STALE_SECS,
+        }
+    }
+}
+
+impl ResolverConfig {
+    /// Thresholds from `HudConfig`, with defaults for any left unset.
+    pub fn from_hud_config(config: &HudConfig) -> Self {
+        let secs = |value: Option<u64>, default: i64| {
+            value.map_or(default, |v| i64::try_from(v).unwrap_or(i64::MAX))
+        };
+        Self {
+            stale_threshold_secs: secs(config.stale_threshold_secs, STALE_THRESHOLD_SECS),
+            active_state_stale_secs: secs(config.active_state_stale_secs, ACTIVE_STATE_STALE_SECS),
+        }
+    }
+
+    /// Reads the thresholds from the Capacitor config file.
+    pub fn load(storage: &StorageConfig) -> Self {
+        Self::from_hud_config(&crate::config::load_hud_config_with_storage(storage))
+    }
 }
 
 /// Find the best record to associate with a given lock path.
-/// Prefers fresher records, then closer path match (exact > child > parent), then session_id.
+/// Prefers closer path match (exact > child > parent), then a record whose transcript
+/// matches the lock's transcript hint, then fresher records, then session_id.
 fn find_record_for_lock_path<'a>(
     store: &'a StateStore,
     lock_path: &str,
+    lock_transcript: Option<&str>,
+    config: &ResolverConfig,
 ) -> Option<&'a SessionRecord> {
     #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
     enum MatchType {
@@ -46,8 +94,13 @@ fn find_record_for_lock_path<'a>(
 
     let mut best: Option<(&SessionRecord, MatchType, bool)> = None;
 
+    // Transcript identity only counts when both sides carry a path
+    let matches_transcript = |record: &SessionRecord| {
+        lock_transcript.is_some() && record.transcript_path.as_deref() == lock_transcript
+    };
+
     for record in store.all_sessions() {
-        let record_is_stale = record.is_stale();
+        let record_is_stale = record.is_stale(config.stale_threshold_secs);
 
         // Consider both record.cwd and record.project_dir for matching.
         // Claude Code locks are keyed by a stable project path; some hook events may omit/shift cwd.
@@ -91,15 +144,21 @@ fn find_record_for_lock_path<'a>(
                 Some((best_record, best_match_type, best_is_stale)) => {
                     // Priority order:
                     // 1. Match type (Exact > Child > Parent) - most important
-                    // 2. Staleness (non-stale > stale)
-                    // 3. Timestamp (fresher > older)
-                    // 4. Session ID (lexicographic tiebreaker)
+                    // 2. Transcript identity (matches lock's transcript hint)
+                    // 3. Staleness (non-stale > stale)
+                    // 4. Timestamp (fresher > older)
+                    // 5. Session ID (lexicographic tiebreaker)
+                    let new_matches_transcript = matches_transcript(record);
+                    let best_matches_transcript = matches_transcript(best_record);
                     let should_replace = if new_match_type > best_match_type {
                         // New record has better match type (Exact > Child > Parent) - replace
                         true
                     } else if new_match_type < best_match_type {
                         // Existing best has better match type - don't replace
                         false
+                    } else if new_matches_transcript != best_matches_transcript {
+                        // Same match type, the lock's own transcript identifies the session
+                        new_matches_transcript
                     } else if best_is_stale && !record_is_stale {
                         // Same match type, prefer non-stale
                         true
@@ -152,6 +211,16 @@ pub fn resolve_state_with_details(
     lock_dir: &Path,
     store: &StateStore,
     project_path: &str,
+) -> Option<ResolvedState> {
+    resolve_state_with_config(lock_dir, store, project_path, &ResolverConfig::default())
+}
+
+/// [`resolve_state_with_details`] with custom staleness thresholds.
+pub fn resolve_state_with_config(
+    lock_dir: &Path,
+    store: &StateStore,
+    project_path: &str,
+    config: &ResolverConfig,
 ) -> Option<ResolvedState> {
     // Check for any active locks for this path (supports multiple concurrent sessions)
     let active_locks = find_all_locks_for_path(lock_dir, project_path);
@@ -161,11 +230,16 @@ pub fn resolve_state_with_details(
         // The lock proves Claude is running (lock holder monitors PID), so we trust the
         // recorded state even if the timestamp is stale.
         let lock = find_lock_for_path(lock_dir, project_path)?;
-        let record = find_record_for_lock_path(store, &lock.path);
-        let (state, session_id) = match record {
-            Some(r) => (r.state, Some(r.session_id.clone())),
+        let record =
+            find_record_for_lock_path(store, &lock.path, lock.transcript_path.as_deref(), config);
+        let (state, session_id, possibly_stale) = match record {
+            Some(r) => (
+                r.state,
+                Some(r.session_id.clone()),
+                r.is_within_stale_grace(config.stale_threshold_secs),
+            ),
             // No record but lock exists - session is active, just no state written yet
-            None => (SessionState::Ready, lock.session_id),
+            None => (SessionState::Ready, lock.session_id, false),
         };
 
         return Some(ResolvedState {
@@ -173,15 +247,16 @@ pub fn resolve_state_with_details(
             session_id,
             cwd: lock.path,
             is_from_lock: true,
+            possibly_stale,
         });
     }
 
     // No locks - check for fresh state record as fallback (exact or child matches only)
     // This handles edge cases where locks aren't created but state is written
     // We intentionally exclude parent matches to prevent child paths from inheriting parent state
-    if let Some(record) = find_fresh_record_for_path(store, project_path) {
+    if let Some(record) = find_fresh_record_for_path(store, project_path, config) {
         // Active state staleness - likely user interrupted
-        let state = if record.is_active_state_stale() {
+        let state = if record.is_active_state_stale(config.active_state_stale_secs) {
             SessionState::Ready
         } else {
             record.state
@@ -191,6 +266,7 @@ pub fn resolve_state_with_details(
             session_id: Some(record.session_id.clone()),
             cwd: record.cwd.clone(),
             is_from_lock: false,
+            possibly_stale: false,
         });
     }
 
@@ -207,13 +283,14 @@ pub fn resolve_state_with_details(
 fn find_fresh_record_for_path<'a>(
     store: &'a StateStore,
     project_path: &str,
+    config: &ResolverConfig,
 ) -> Option<&'a SessionRecord> {
     let path_normalized = normalize_path(project_path);
 
     let mut best: Option<&SessionRecord> = None;
 
     for record in store.all_sessions() {
-        if record.is_stale() {
+        if record.is_stale(config.stale_threshold_secs) {
             continue;
         }
 
@@ -273,6 +350,52 @@ mod tests {
         assert_eq!(resolved.session_id.as_deref(), Some("s1"));
     }
 
+    #[test]
+    fn transcript_hint_breaks_tie_between_same_cwd_records() {
+        let temp = tempdir().unwrap();
+        let lock_dir = temp.path().join("s-b-lock.lock");
+        std::fs::create_dir_all(&lock_dir).unwrap();
+        let pid = std::process::id();
+        let proc_started = crate::state::lock::get_process_start_time(pid).unwrap();
+        std::fs::write(lock_dir.join("pid"), pid.to_string()).unwrap();
+        std::fs::write(
+            lock_dir.join("meta.json"),
+            serde_json::json!({
+                "pid": pid,
+                "path": "/project",
+                "proc_started": proc_started,
+                "created": 1,
+                "transcript_path": "/transcripts/b.jsonl",
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        // Identical timestamps: without the hint, session_id ordering would pick "s-z"
+        let now = Utc::now();
+        let mut store = StateStore::new_in_memory();
+        store.update("s-b", SessionState::Waiting, "/project");
+        store.update("s-z", SessionState::Working, "/project");
+        for (sid, transcript) in [
+            ("s-b", "/transcripts/b.jsonl"),
+            ("s-z", "/transcripts/z.jsonl"),
+        ] {
+            store.set_timestamp_for_test(sid, now);
+            store.set_transcript_path_for_test(sid, Some(transcript));
+        }
+
+        let resolved = resolve_state_with_details(temp.path(), &store, "/project").unwrap();
+        assert_eq!(resolved.session_id.as_deref(), Some("s-b"));
+        assert_eq!(resolved.state, SessionState::Waiting);
+
+        // Without transcript paths the existing ordering applies
+        for sid in ["s-b", "s-z"] {
+            store.set_transcript_path_for_test(sid, None);
+        }
+        let resolved = resolve_state_with_details(temp.path(), &store, "/project").unwrap();
+        assert_eq!(resolved.session_id.as_deref(), Some("s-z"));
+    }
+
     #[test]
     fn parent_query_does_not_inherit_child_lock() {
         // With exact-match-only policy, parent paths don't inherit child session state.
@@ -365,6 +488,35 @@ mod tests {
         assert!(resolved.is_from_lock);
     }
 
+    #[test]
+    fn resolve_possibly_stale_just_past_threshold_with_lock() {
+        use crate::state::types::{STALE_GRACE_SECS, STALE_THRESHOLD_SECS};
+
+        let temp = tempdir().unwrap();
+        create_lock(temp.path(), std::process::id(), "/project");
+        let mut store = StateStore::new_in_memory();
+        store.update("s1", SessionState::Working, "/project");
+
+        store.set_timestamp_for_test(
+            "s1",
+            Utc::now() - Duration::seconds(STALE_THRESHOLD_SECS + 5),
+        );
+        let resolved = resolve_state_with_details(temp.path(), &store, "/project").unwrap();
+        assert_eq!(resolved.state, SessionState::Working);
+        assert!(resolved.possibly_stale);
+
+        // Fresh or far past the grace band: not hedged
+        store.set_timestamp_for_test("s1", Utc::now());
+        let resolved = resolve_state_with_details(temp.path(), &store, "/project").unwrap();
+        assert!(!resolved.possibly_stale);
+        store.set_timestamp_for_test(
+            "s1",
+            Utc::now() - Duration::seconds(STALE_THRESHOLD_SECS + STALE_GRACE_SECS + 5),
+        );
+        let resolved = resolve_state_with_details(temp.path(), &store, "/project").unwrap();
+        assert!(!resolved.possibly_stale);
+    }
+
     #[test]
     fn resolve_working_for_active_state_stale_with_lock() {
         use crate::state::types::ACTIVE_STATE_STALE_SECS;
@@ -385,6 +537,44 @@ mod tests {
         assert!(resolved.is_from_lock);
     }
 
+    #[test]
+    fn resolve_with_config_uses_configured_active_threshold() {
+        use crate::state::types::ACTIVE_STATE_STALE_SECS;
+
+        let temp = tempdir().unwrap();
+        let mut store = StateStore::new_in_memory();
+        store.update("s1", SessionState::Working, "/project");
+        let stale_time = Utc::now() - Duration::seconds(ACTIVE_STATE_STALE_SECS + 5);
+        store.set_timestamp_for_test("s1", stale_time);
+
+        let config = ResolverConfig {
+            active_state_stale_secs: 120,
+            ..ResolverConfig::default()
+        };
+        let resolved = resolve_state_with_config(temp.path(), &store, "/project", &config).unwrap();
+        assert_eq!(resolved.state, SessionState::Working);
+
+        let resolved =
+            resolve_state_with_config(temp.path(), &store, "/project", &ResolverConfig::default())
+                .unwrap();
+        assert_eq!(resolved.state, SessionState::Ready);
+    }
+
+    #[test]
+    fn resolver_config_from_hud_config_falls_back_to_defaults() {
+        let mut hud = crate::types::HudConfig::default();
+        assert_eq!(
+            ResolverConfig::from_hud_config(&hud),
+            ResolverConfig::default()
+        );
+
+        hud.stale_threshold_secs = Some(600);
+        hud.active_state_stale_secs = Some(90);
+        let config = ResolverConfig::from_hud_config(&hud);
+        assert_eq!(config.stale_threshold_secs, 600);
+        assert_eq!(config.active_state_stale_secs, 90);
+    }
+
     #[test]
     fn resolve_working_when_fresh_active_state_with_lock() {
         use crate::state::types::ACTIVE_STATE_STALE_SECS;
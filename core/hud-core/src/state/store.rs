@@ -41,6 +41,7 @@ use tempfile::NamedTempFile;
 
 use crate::types::SessionState;
 
+use super::lock::collect_active_session_ids;
 use super::types::SessionRecord;
 
 /// The on-disk JSON structure for the state file.
@@ -183,10 +184,45 @@ impl StateStore {
         );
     }
 
+    /// Adds `delta` to a session's running subagent count, clamping at zero.
+    ///
+    /// Does nothing if the session has no record.
+    pub fn adjust_subagent_count(&mut self, session_id: &str, delta: i32) {
+        if let Some(record) = self.sessions.get_mut(session_id) {
+            record.active_subagent_count =
+                record.active_subagent_count.saturating_add_signed(delta);
+        }
+    }
+
+    /// Clears a session's running subagent count, for events that end the turn.
+    ///
+    /// Does nothing if the session has no record.
+    pub fn reset_subagent_count(&mut self, session_id: &str) {
+        if let Some(record) = self.sessions.get_mut(session_id) {
+            record.active_subagent_count = 0;
+        }
+    }
+
     pub fn remove(&mut self, session_id: &str) {
         self.sessions.remove(session_id);
     }
 
+    /// Removes records that are older than `stale_threshold_secs` and whose
+    /// session holds no live lock in `lock_dir`, returning how many were removed.
+    ///
+    /// Catches sessions that ended without a `SessionEnd` event (crashes, killed
+    /// terminals). Liveness is checked by session id, not cwd: the hook rewrites
+    /// `cwd` as the session moves, so a live session may not match its old path.
+    /// Call [`StateStore::save`] afterwards to persist.
+    pub fn prune_stale(&mut self, lock_dir: &Path, stale_threshold_secs: i64) -> usize {
+        let live = collect_active_session_ids(lock_dir);
+        let before = self.sessions.len();
+        self.sessions.retain(|_, record| {
+            !record.is_stale(stale_threshold_secs) || live.contains(&record.session_id)
+        });
+        before - self.sessions.len()
+    }
+
     pub fn get_by_session_id(&self, session_id: &str) -> Option<&SessionRecord> {
         self.sessions.get(session_id)
     }
@@ -226,6 +262,19 @@ impl StateStore {
         }
     }
 
+    /// Test helper: Set transcript_path for a session record.
+    /// Only available with the `test-helpers` feature or in tests.
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn set_transcript_path_for_test(
+        &mut self,
+        session_id: &str,
+        transcript_path: Option<&str>,
+    ) {
+        if let Some(record) = self.sessions.get_mut(session_id) {
+            record.transcript_path = transcript_path.map(|s| s.to_string());
+        }
+    }
+
     /// Test helper: Set project_dir for a session record.
     /// Only available with the `test-helpers` feature or in tests.
     #[cfg(any(test, feature = "test-helpers"))]
@@ -239,6 +288,7 @@ impl StateStore {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::types::STALE_THRESHOLD_SECS;
     use tempfile::tempdir;
 
     #[test]
@@ -256,6 +306,27 @@ mod tests {
         assert_eq!(record.cwd, "/project");
     }
 
+    #[test]
+    fn test_adjust_subagent_count_clamps_at_zero() {
+        let mut store = StateStore::new_in_memory();
+        store.update("session-1", SessionState::Working, "/project");
+        let count = |store: &StateStore| {
+            store
+                .get_by_session_id("session-1")
+                .unwrap()
+                .active_subagent_count
+        };
+
+        store.adjust_subagent_count("session-1", -1);
+        assert_eq!(count(&store), 0);
+        store.adjust_subagent_count("session-1", 1);
+        store.adjust_subagent_count("session-1", 1);
+        assert_eq!(count(&store), 2);
+        store.update("session-1", SessionState::Ready, "/project");
+        store.adjust_subagent_count("session-1", -1);
+        assert_eq!(count(&store), 1);
+    }
+
     #[test]
     fn test_update_overwrites_session() {
         let mut store = StateStore::new_in_memory();
@@ -341,4 +412,66 @@ mod tests {
         let store = StateStore::load(&file).unwrap();
         assert_eq!(store.all_sessions().count(), 0);
     }
+
+    #[test]
+    fn test_load_unknown_state_reads_as_idle() {
+        let temp = tempdir().unwrap();
+        let file = temp.path().join("state.json");
+        fs::write(
+            &file,
+            r#"{"version":3,"sessions":{
+                "a":{"session_id":"a","state":"pondering","cwd":"/a","updated_at":"2026-01-01T00:00:00Z","state_changed_at":"2026-01-01T00:00:00Z"},
+                "b":{"session_id":"b","state":"working","cwd":"/b","updated_at":"2026-01-01T00:00:00Z","state_changed_at":"2026-01-01T00:00:00Z"}
+            }}"#,
+        )
+        .unwrap();
+
+        let store = StateStore::load(&file).unwrap();
+        assert_eq!(
+            store.get_by_session_id("a").map(|r| r.state),
+            Some(SessionState::Idle)
+        );
+        assert_eq!(
+            store.get_by_session_id("b").map(|r| r.state),
+            Some(SessionState::Working)
+        );
+    }
+
+    #[test]
+    fn test_prune_stale_drops_old_lockless_records_only() {
+        let temp = tempdir().unwrap();
+        let lock_dir = temp.path().join("sessions");
+        fs::create_dir_all(&lock_dir).unwrap();
+
+        let mut store = StateStore::new_in_memory();
+        store.update("fresh", SessionState::Ready, "/fresh");
+        store.update("old", SessionState::Ready, "/old");
+        store.set_timestamp_for_test("old", Utc::now() - chrono::Duration::minutes(10));
+
+        assert_eq!(store.prune_stale(&lock_dir, STALE_THRESHOLD_SECS), 1);
+        assert!(store.get_by_session_id("fresh").is_some());
+        assert!(store.get_by_session_id("old").is_none());
+        assert_eq!(store.prune_stale(&lock_dir, STALE_THRESHOLD_SECS), 0);
+    }
+
+    #[test]
+    fn test_prune_stale_keeps_live_session_that_changed_directory() {
+        let temp = tempdir().unwrap();
+        let lock_dir = temp.path().join("sessions");
+        fs::create_dir_all(&lock_dir).unwrap();
+        // The lock still names the directory the session started in
+        crate::state::lock::tests_helper::create_session_lock(
+            &lock_dir,
+            std::process::id(),
+            "/started-here",
+            "moved",
+        );
+
+        let mut store = StateStore::new_in_memory();
+        store.update("moved", SessionState::Ready, "/moved-to-here");
+        store.set_timestamp_for_test("moved", Utc::now() - chrono::Duration::minutes(10));
+
+        assert_eq!(store.prune_stale(&lock_dir, STALE_THRESHOLD_SECS), 0);
+        assert!(store.get_by_session_id("moved").is_some());
+    }
 }
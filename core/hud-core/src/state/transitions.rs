@@ -0,0 +1,162 @@
+//! Append-only log of session state transitions (`~/.capacitor/transitions.jsonl`).
+//!
+//! `sessions.json` only holds the latest state per session. The hook handler appends
+//! one line here whenever a session's state actually changes, so readers can
+//! reconstruct a timeline (traces, analytics) after the fact.
+//!
+//! The log is bounded: when it grows past [`TRANSITIONS_MAX_BYTES`], the oldest
+//! half of the lines is dropped on the next append.
+
+use chrono::{DateTime, Utc};
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::Path;
+
+use crate::types::SessionState;
+
+/// Size at which the log is compacted to its newest half.
+pub const TRANSITIONS_MAX_BYTES: u64 = 2 * 1024 * 1024;
+
+/// A single state change for a session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransitionRecord {
+    pub session_id: String,
+    pub cwd: String,
+    /// Previous state, None when the session had no record yet
+    #[serde(default)]
+    pub from: Option<SessionState>,
+    pub to: SessionState,
+    /// Hook event that caused the transition (e.g. "UserPromptSubmit")
+    #[serde(default)]
+    pub event: Option<String>,
+    pub at: DateTime<Utc>,
+}
+
+/// Appends a transition to the log, compacting it first if it is too large.
+pub fn append_transition(path: &Path, record: &TransitionRecord) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create transitions directory: {}", e))?;
+    }
+
+    if fs::metadata(path).is_ok_and(|m| m.len() > TRANSITIONS_MAX_BYTES) {
+        compact(path)?;
+    }
+
+    let line = serde_json::to_string(record)
+        .map_err(|e| format!("Failed to serialize transition: {}", e))?;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open transitions log: {}", e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("Failed to append transition: {}", e))
+}
+
+/// Loads all transitions, oldest first. Corrupt lines are skipped.
+pub fn load_transitions(path: &Path) -> Vec<TransitionRecord> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// Loads the transitions for one session, oldest first.
+pub fn load_session_transitions(path: &Path, session_id: &str) -> Vec<TransitionRecord> {
+    load_transitions(path)
+        .into_iter()
+        .filter(|t| t.session_id == session_id)
+        .collect()
+}
+
+/// Keeps the newest half of the log's lines.
+fn compact(path: &Path) -> Result<(), String> {
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read transitions log: {}", e))?;
+    let lines: Vec<&str> = content.lines().collect();
+    let keep = &lines[lines.len() / 2..];
+    let mut compacted = keep.join("\n");
+    if !compacted.is_empty() {
+        compacted.push('\n');
+    }
+
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let mut tmp = tempfile::NamedTempFile::new_in(dir)
+        .map_err(|e| format!("Failed to create temp file: {}", e))?;
+    tmp.write_all(compacted.as_bytes())
+        .map_err(|e| format!("Failed to write temp file: {}", e))?;
+    tmp.persist(path)
+        .map_err(|e| format!("Failed to persist transitions log: {}", e.error))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn record(session_id: &str, to: SessionState) -> TransitionRecord {
+        TransitionRecord {
+            session_id: session_id.to_string(),
+            cwd: "/project".to_string(),
+            from: None,
+            to,
+            event: Some("UserPromptSubmit".to_string()),
+            at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn appends_and_loads_in_order() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("transitions.jsonl");
+
+        append_transition(&path, &record("s1", SessionState::Working)).unwrap();
+        append_transition(&path, &record("s2", SessionState::Working)).unwrap();
+        append_transition(&path, &record("s1", SessionState::Ready)).unwrap();
+
+        assert_eq!(load_transitions(&path).len(), 3);
+        let s1: Vec<_> = load_session_transitions(&path, "s1")
+            .into_iter()
+            .map(|t| t.to)
+            .collect();
+        assert_eq!(s1, vec![SessionState::Working, SessionState::Ready]);
+    }
+
+    #[test]
+    fn skips_corrupt_lines() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("transitions.jsonl");
+        append_transition(&path, &record("s1", SessionState::Working)).unwrap();
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(file, "{{ not json").unwrap();
+        append_transition(&path, &record("s1", SessionState::Ready)).unwrap();
+
+        assert_eq!(load_transitions(&path).len(), 2);
+    }
+
+    #[test]
+    fn missing_file_loads_empty() {
+        let temp = TempDir::new().unwrap();
+        assert!(load_transitions(&temp.path().join("missing.jsonl")).is_empty());
+    }
+
+    #[test]
+    fn compacts_when_oversized() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("transitions.jsonl");
+        let line = serde_json::to_string(&record("old", SessionState::Working)).unwrap();
+        let count = (TRANSITIONS_MAX_BYTES as usize / line.len()) + 10;
+        fs::write(&path, format!("{}\n", line).repeat(count)).unwrap();
+
+        append_transition(&path, &record("new", SessionState::Ready)).unwrap();
+
+        let loaded = load_transitions(&path);
+        assert!(loaded.len() < count);
+        assert_eq!(loaded.last().unwrap().session_id, "new");
+    }
+}
@@ -70,6 +70,8 @@ pub enum HookEvent {
     Stop {
         stop_hook_active: bool,
     },
+    SubagentStart,
+    SubagentStop,
     Unknown {
         event_name: String,
     },
@@ -112,6 +114,8 @@ impl HookInput {
             "Stop" => HookEvent::Stop {
                 stop_hook_active: self.stop_hook_active.unwrap_or(false),
             },
+            "SubagentStart" => HookEvent::SubagentStart,
+            "SubagentStop" => HookEvent::SubagentStop,
             _ => HookEvent::Unknown {
                 event_name: event_name.to_string(),
             },
@@ -143,7 +147,12 @@ fn normalize_path(path: &str) -> String {
 /// Records older than this are considered stale and untrusted without a lock.
 pub const STALE_THRESHOLD_SECS: i64 = 300; // 5 minutes
 
-/// Active states (Working, Waiting) fall back to Ready after this threshold.
+/// Grace band past [`STALE_THRESHOLD_SECS`] in which a locked session is reported
+/// as possibly stale rather than trusted outright. Long tool calls emit no hooks,
+/// so the UI hedges ("Working (maybe idle)") instead of flipping state.
+pub const STALE_GRACE_SECS: i64 = 180; // 3 minutes
+
+/// Active states (Working, Thinking, Waiting) fall back to Ready after this threshold.
 /// This handles user interruptions (Escape key, cancel) where no hook event fires.
 /// 30 seconds balances interrupt recovery with accuracy during long generations
 /// (tool-free responses don't emit heartbeat events).
@@ -153,15 +162,16 @@ pub const ACTIVE_STATE_STALE_SECS: i64 = 30;
 // Canonical hook→state mapping (implemented in core/hud-hook/)
 //
 // SessionStart           → ready    (+ creates lock)
-// UserPromptSubmit       → working  (+ creates lock if missing)
+// UserPromptSubmit       → thinking (+ creates lock if missing)
 // PreToolUse             → working  (heartbeat if already working)
-// PostToolUse            → working  (+ tracks file activity)
+// PostToolUse            → thinking (heartbeat if already thinking; + tracks file activity)
 // PermissionRequest      → waiting
 // Notification           → ready    (only idle_prompt type; others ignored)
 // PreCompact             → compacting
 // Stop                   → ready    (ignored if stop_hook_active=true)
 // SessionEnd             → removes session record
-// SubagentStop           → ignored  (metadata only, no state change)
+// SubagentStart          → no state change (+1 active_subagent_count)
+// SubagentStop           → no state change (-1 active_subagent_count, clamped at 0)
 // -----------------------------------------------------------------------------
 
 /// Most recent hook event observed for this session (captured for debugging + future features).
@@ -215,30 +225,47 @@ pub struct SessionRecord {
 }
 
 impl SessionRecord {
-    /// Returns true if this record is stale (not updated within [`STALE_THRESHOLD_SECS`]).
+    /// Returns true if this record is stale (not updated within `threshold_secs`,
+    /// normally [`STALE_THRESHOLD_SECS`]).
     #[must_use]
-    pub fn is_stale(&self) -> bool {
+    pub fn is_stale(&self, threshold_secs: i64) -> bool {
         let now = Utc::now();
         let age = now.signed_duration_since(self.updated_at);
-        age.num_seconds() > STALE_THRESHOLD_SECS
+        age.num_seconds() > threshold_secs
+    }
+
+    /// Returns true if this record is stale but still within [`STALE_GRACE_SECS`] of
+    /// `threshold_secs`.
+    #[must_use]
+    pub fn is_within_stale_grace(&self, threshold_secs: i64) -> bool {
+        let age = Utc::now()
+            .signed_duration_since(self.updated_at)
+            .num_seconds();
+        age > threshold_secs && age <= threshold_secs.saturating_add(STALE_GRACE_SECS)
     }
 
     /// Returns true if this record is in an "active" state that hasn't been updated recently.
-    /// Active states (Working, Waiting) should have frequent hook updates from tool use events.
-    /// If stale, the user likely interrupted (Escape key, cancel) and we should show Ready.
+    /// Active states (Working, Thinking, Waiting) should have frequent hook updates from tool
+    /// use events. If stale, the user likely interrupted (Escape key, cancel) and we should
+    /// show Ready.
     ///
     /// Note: Compacting is NOT included here because it receives no heartbeat updates after
     /// PreCompact fires. Compaction can take 30+ seconds, so it uses the general staleness
     /// threshold instead of this aggressive 5-second check.
+    ///
+    /// `threshold_secs` is normally [`ACTIVE_STATE_STALE_SECS`].
     #[must_use]
-    pub fn is_active_state_stale(&self) -> bool {
-        let is_active = matches!(self.state, SessionState::Working | SessionState::Waiting);
+    pub fn is_active_state_stale(&self, threshold_secs: i64) -> bool {
+        let is_active = matches!(
+            self.state,
+            SessionState::Working | SessionState::Thinking | SessionState::Waiting
+        );
         if !is_active {
             return false;
         }
         let now = Utc::now();
         let age = now.signed_duration_since(self.updated_at);
-        age.num_seconds() > ACTIVE_STATE_STALE_SECS
+        age.num_seconds() > threshold_secs
     }
 }
 
@@ -263,6 +290,10 @@ pub struct LockInfo {
     /// Used to identify locks from old versions that might need special handling.
     #[serde(default)]
     pub lock_version: Option<String>,
+    /// Transcript path of the owning session, when known.
+    /// Used as a resolver tiebreaker when several records share the lock's cwd.
+    #[serde(default)]
+    pub transcript_path: Option<String>,
 }
 
 #[cfg(test)]
@@ -289,14 +320,14 @@ mod tests {
     #[test]
     fn test_is_stale_fresh_record() {
         let record = make_record(Utc::now());
-        assert!(!record.is_stale());
+        assert!(!record.is_stale(STALE_THRESHOLD_SECS));
     }
 
     #[test]
     fn test_is_stale_old_record() {
         let old_time = Utc::now() - Duration::seconds(STALE_THRESHOLD_SECS + 1);
         let record = make_record(old_time);
-        assert!(record.is_stale());
+        assert!(record.is_stale(STALE_THRESHOLD_SECS));
     }
 
     #[test]
@@ -304,7 +335,7 @@ mod tests {
         // Exactly at threshold should NOT be stale (uses >)
         let boundary_time = Utc::now() - Duration::seconds(STALE_THRESHOLD_SECS);
         let record = make_record(boundary_time);
-        assert!(!record.is_stale());
+        assert!(!record.is_stale(STALE_THRESHOLD_SECS));
     }
 
     fn make_record_with_state(
@@ -329,21 +360,21 @@ mod tests {
     #[test]
     fn test_active_state_stale_working_fresh() {
         let record = make_record_with_state(Utc::now(), crate::types::SessionState::Working);
-        assert!(!record.is_active_state_stale());
+        assert!(!record.is_active_state_stale(ACTIVE_STATE_STALE_SECS));
     }
 
     #[test]
     fn test_active_state_stale_working_old() {
         let old_time = Utc::now() - Duration::seconds(ACTIVE_STATE_STALE_SECS + 1);
         let record = make_record_with_state(old_time, crate::types::SessionState::Working);
-        assert!(record.is_active_state_stale());
+        assert!(record.is_active_state_stale(ACTIVE_STATE_STALE_SECS));
     }
 
     #[test]
     fn test_active_state_stale_waiting_old() {
         let old_time = Utc::now() - Duration::seconds(ACTIVE_STATE_STALE_SECS + 1);
         let record = make_record_with_state(old_time, crate::types::SessionState::Waiting);
-        assert!(record.is_active_state_stale());
+        assert!(record.is_active_state_stale(ACTIVE_STATE_STALE_SECS));
     }
 
     #[test]
@@ -352,7 +383,15 @@ mod tests {
         // updates after PreCompact fires, and compaction can take 30+ seconds
         let old_time = Utc::now() - Duration::seconds(ACTIVE_STATE_STALE_SECS + 1);
         let record = make_record_with_state(old_time, crate::types::SessionState::Compacting);
-        assert!(!record.is_active_state_stale());
+        assert!(!record.is_active_state_stale(ACTIVE_STATE_STALE_SECS));
+    }
+
+    #[test]
+    fn test_active_state_stale_thinking_decays() {
+        // A lockless Thinking record must fall back to Ready like Working does
+        let old_time = Utc::now() - Duration::seconds(ACTIVE_STATE_STALE_SECS + 1);
+        let record = make_record_with_state(old_time, crate::types::SessionState::Thinking);
+        assert!(record.is_active_state_stale(ACTIVE_STATE_STALE_SECS));
     }
 
     #[test]
@@ -360,7 +399,7 @@ mod tests {
         // Ready is not an "active" state, so it should never be active-stale
         let old_time = Utc::now() - Duration::seconds(ACTIVE_STATE_STALE_SECS + 1);
         let record = make_record_with_state(old_time, crate::types::SessionState::Ready);
-        assert!(!record.is_active_state_stale());
+        assert!(!record.is_active_state_stale(ACTIVE_STATE_STALE_SECS));
     }
 
     #[test]
@@ -368,6 +407,6 @@ mod tests {
         // Idle is not an "active" state, so it should never be active-stale
         let old_time = Utc::now() - Duration::seconds(ACTIVE_STATE_STALE_SECS + 1);
         let record = make_record_with_state(old_time, crate::types::SessionState::Idle);
-        assert!(!record.is_active_state_stale());
+        assert!(!record.is_active_state_stale(ACTIVE_STATE_STALE_SECS));
     }
 }
@@ -5,75 +5,207 @@
 //! Parsing is best-effort; malformed lines simply do not contribute to totals.
 
 use crate::patterns::*;
-use crate::types::{CachedFileInfo, CachedProjectStats, ProjectStats, StatsCache};
+use crate::projects::session_transcript_path;
+use crate::storage::StorageConfig;
+use crate::types::{
+    ActivityStreak, AuthorshipRatio, BudgetLevel, BudgetStatus, CachedFileInfo, CachedProjectStats,
+    ContextBurnRate, ContextInfo, EfficiencyPoint, EfficiencyTrend, FileStatsContribution,
+    ModelFamily, ModelPeriodUsage, ModelUsagePoint, PeriodUsage, ProjectStats, ProjectVelocity,
+    SessionCost, SessionDurationStats, SessionLatency, StatsCache, StatsChangedEvent, TokenCounts,
+    TrendDirection, UsageBucket,
+};
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveTime, Utc};
 use fs_err as fs;
-use std::collections::HashMap;
+use once_cell::sync::Lazy;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
-/// Parses statistics from session file content and accumulates into stats.
+/// How long a project's transcripts must be quiet before changed stats are
+/// recomputed, so a burst of writes while Claude works yields one event.
+pub const STATS_CHANGE_DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// Parses a session file's content line by line into `stats`.
+///
+/// Each line is read as JSON and only its structured fields count: usage and
+/// model from `message`, `summary` lines, and the top-level `timestamp`. Text
+/// that merely quotes usage JSON (e.g. an assistant reply) is ignored. Lines
+/// that aren't valid JSON fall back to regex extraction.
 pub fn parse_stats_from_content(content: &str, stats: &mut ProjectStats) {
-    for cap in RE_INPUT_TOKENS.captures_iter(content) {
-        if let Ok(n) = cap[1].parse::<u64>() {
-            stats.total_input_tokens += n;
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        match serde_json::from_str::<serde_json::Value>(line) {
+            Ok(value) => parse_stats_from_value(&value, stats),
+            Err(_) => parse_stats_from_raw_line(line, stats),
         }
     }
+}
 
-    for cap in RE_OUTPUT_TOKENS.captures_iter(content) {
-        if let Ok(n) = cap[1].parse::<u64>() {
-            stats.total_output_tokens += n;
+fn parse_stats_from_value(value: &serde_json::Value, stats: &mut ProjectStats) {
+    if let Some(message) = value.get("message") {
+        let model = message
+            .get("model")
+            .and_then(|m| m.as_str())
+            .and_then(|m| m.strip_prefix("claude-"));
+        if let Some(usage) = message.get("usage") {
+            let tokens = |key: &str| usage.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+            add_usage(
+                stats,
+                model.map_or(ModelFamily::Other, ModelFamily::from_model_id),
+                TokenCounts {
+                    input: tokens("input_tokens"),
+                    output: tokens("output_tokens"),
+                    cache_read: tokens("cache_read_input_tokens"),
+                    cache_creation: tokens("cache_creation_input_tokens"),
+                },
+            );
+        }
+        if let Some(model) = model {
+            count_model_message(model, stats);
         }
     }
 
-    for cap in RE_CACHE_READ.captures_iter(content) {
-        if let Ok(n) = cap[1].parse::<u64>() {
-            stats.total_cache_read_tokens += n;
+    if value.get("type").and_then(|t| t.as_str()) == Some("summary") {
+        if let Some(summary) = value.get("summary").and_then(|s| s.as_str()) {
+            stats.latest_summary = Some(summary.to_string());
         }
     }
 
-    for cap in RE_CACHE_CREATE.captures_iter(content) {
-        if let Ok(n) = cap[1].parse::<u64>() {
-            stats.total_cache_creation_tokens += n;
-        }
+    if let Some(ts) = value_timestamp(value) {
+        record_activity(ts, stats);
     }
+}
 
-    for cap in RE_MODEL.captures_iter(content) {
-        let model = &cap[1];
-        if model.contains("opus") {
-            stats.opus_messages += 1;
-        } else if model.contains("sonnet") {
-            stats.sonnet_messages += 1;
-        } else if model.contains("haiku") {
-            stats.haiku_messages += 1;
+/// A JSON line's top-level `timestamp`, if it is a well-formed message timestamp.
+fn value_timestamp(value: &serde_json::Value) -> Option<&str> {
+    value
+        .get("timestamp")
+        .and_then(|t| t.as_str())
+        .filter(|ts| RE_TIMESTAMP_VALUE.is_match(ts))
+}
+
+/// Timestamps in a line that isn't valid JSON.
+fn raw_line_timestamps(line: &str) -> impl Iterator<Item = &str> {
+    RE_TIMESTAMP
+        .captures_iter(line)
+        .filter_map(|cap| cap.get(1).map(|m| m.as_str()))
+}
+
+/// Calls `visit` with each activity timestamp in a session file's content, by
+/// the same per-line rules as [`parse_stats_from_content`].
+fn for_each_activity_timestamp(content: &str, mut visit: impl FnMut(&str)) {
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        match serde_json::from_str::<serde_json::Value>(line) {
+            Ok(value) => value_timestamp(&value).into_iter().for_each(&mut visit),
+            Err(_) => raw_line_timestamps(line).for_each(&mut visit),
         }
     }
+}
+
+/// Regex extraction for a line that isn't valid JSON (e.g. truncated mid-write).
+fn parse_stats_from_raw_line(line: &str, stats: &mut ProjectStats) {
+    let sum = |re: &regex::Regex| -> u64 {
+        re.captures_iter(line)
+            .filter_map(|cap| cap[1].parse::<u64>().ok())
+            .sum()
+    };
+    // A truncated line can't be split by model, so it goes to the first one named
+    let family = RE_MODEL.captures(line).map_or(ModelFamily::Other, |cap| {
+        ModelFamily::from_model_id(&cap[1])
+    });
+    add_usage(
+        stats,
+        family,
+        TokenCounts {
+            input: sum(&RE_INPUT_TOKENS),
+            output: sum(&RE_OUTPUT_TOKENS),
+            cache_read: sum(&RE_CACHE_READ),
+            cache_creation: sum(&RE_CACHE_CREATE),
+        },
+    );
+
+    for cap in RE_MODEL.captures_iter(line) {
+        count_model_message(&cap[1], stats);
+    }
 
-    if let Some(cap) = RE_SUMMARY.captures_iter(content).last() {
+    if let Some(cap) = RE_SUMMARY.captures_iter(line).last() {
         stats.latest_summary = Some(cap[1].to_string());
     }
 
-    for cap in RE_TIMESTAMP.captures_iter(content) {
-        let ts = &cap[1];
-        let date = ts.split('T').next().unwrap_or(ts);
+    for ts in raw_line_timestamps(line) {
+        record_activity(ts, stats);
+    }
+}
 
-        if stats.first_activity.is_none() || stats.first_activity.as_deref() > Some(date) {
-            stats.first_activity = Some(date.to_string());
-        }
-        if stats.last_activity.is_none() || stats.last_activity.as_deref() < Some(date) {
-            stats.last_activity = Some(date.to_string());
-        }
+/// Adds usage to the token totals and to `family`'s share of them.
+fn add_usage(stats: &mut ProjectStats, family: ModelFamily, tokens: TokenCounts) {
+    stats.total_input_tokens += tokens.input;
+    stats.total_output_tokens += tokens.output;
+    stats.total_cache_read_tokens += tokens.cache_read;
+    stats.total_cache_creation_tokens += tokens.cache_creation;
+    let usage = match family {
+        ModelFamily::Opus => &mut stats.opus_usage,
+        ModelFamily::Sonnet => &mut stats.sonnet_usage,
+        ModelFamily::Haiku => &mut stats.haiku_usage,
+        ModelFamily::Other => &mut stats.other_usage,
+    };
+    usage.add(&tokens);
+}
+
+/// Each model family's share of a project's token usage.
+fn usage_by_family(stats: &ProjectStats) -> [(ModelFamily, &TokenCounts); 4] {
+    [
+        (ModelFamily::Opus, &stats.opus_usage),
+        (ModelFamily::Sonnet, &stats.sonnet_usage),
+        (ModelFamily::Haiku, &stats.haiku_usage),
+        (ModelFamily::Other, &stats.other_usage),
+    ]
+}
+
+/// Counts one message for the model family named in `model` (id without `claude-`).
+fn count_model_message(model: &str, stats: &mut ProjectStats) {
+    if model.contains("opus") {
+        stats.opus_messages += 1;
+    } else if model.contains("sonnet") {
+        stats.sonnet_messages += 1;
+    } else if model.contains("haiku") {
+        stats.haiku_messages += 1;
+    }
+}
+
+/// Folds a message timestamp into the first/last activity dates and daily counts.
+fn record_activity(ts: &str, stats: &mut ProjectStats) {
+    let date = ts.split('T').next().unwrap_or(ts);
+
+    if stats.first_activity.is_none() || stats.first_activity.as_deref() > Some(date) {
+        stats.first_activity = Some(date.to_string());
+    }
+    if stats.last_activity.is_none() || stats.last_activity.as_deref() < Some(date) {
+        stats.last_activity = Some(date.to_string());
+    }
+    *stats.daily_activity.entry(date.to_string()).or_default() += 1;
+    if let Ok(t) = DateTime::parse_from_rfc3339(ts) {
+        let hour = t.with_timezone(&Utc).format(HOUR_KEY_FORMAT).to_string();
+        *stats.hourly_activity.entry(hour).or_default() += 1;
     }
 }
 
+/// Key format for `ProjectStats.hourly_activity`.
+const HOUR_KEY_FORMAT: &str = "%Y-%m-%dT%H";
+
 /// Computes project statistics with intelligent caching.
 ///
-/// Uses file mtime to determine if re-parsing is needed, avoiding
-/// redundant file reads for unchanged session files.
+/// Each session file's parsed subtotal is cached alongside its size and mtime,
+/// so only new or modified files are re-read; the project totals are the sum
+/// of the per-file subtotals. Deleted files simply drop out of the sum.
+///
+/// Files larger than `max_parse_bytes` are not parsed; they are listed in
+/// `skipped_files` and the stats are marked `partial`.
 pub fn compute_project_stats(
     claude_projects_dir: &Path,
     encoded_name: &str,
     cache: &mut StatsCache,
     project_path: &str,
+    max_parse_bytes: Option<u64>,
 ) -> ProjectStats {
     let project_dir = claude_projects_dir.join(encoded_name);
 
@@ -81,74 +213,2514 @@ pub fn compute_project_stats(
         return ProjectStats::default();
     }
 
-    let cached = cache.projects.get(project_path);
+    let cached_files = cache.projects.get(project_path).map(|c| &c.files);
     let mut current_files: HashMap<String, CachedFileInfo> = HashMap::new();
-    let mut needs_recompute = false;
-
-    if let Ok(entries) = fs::read_dir(&project_dir) {
-        for entry in entries.filter_map(|e| e.ok()) {
-            let path = entry.path();
-            if path.extension().is_some_and(|ext| ext == "jsonl") {
-                let filename = entry.file_name().to_string_lossy().to_string();
-                let metadata = entry.metadata().ok();
-
-                let size = metadata.as_ref().map(|m| m.len()).unwrap_or(0);
-                let mtime = metadata
-                    .as_ref()
-                    .and_then(|m| m.modified().ok())
-                    .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
-                    .map(|d| d.as_secs())
-                    .unwrap_or(0);
-
-                current_files.insert(filename.clone(), CachedFileInfo { size, mtime });
-
-                let cached_file = cached.and_then(|c| c.files.get(&filename));
-                let is_new_or_modified =
-                    cached_file.map_or(true, |cf| cf.size != size || cf.mtime != mtime);
-
-                if is_new_or_modified {
-                    needs_recompute = true;
+    let mut oversized: Vec<String> = Vec::new();
+
+    for entry in fs::read_dir(&project_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+    {
+        let path = entry.path();
+        if !path.extension().is_some_and(|ext| ext == "jsonl") {
+            continue;
+        }
+        let Some(filename) = entry.file_name().to_str().map(String::from) else {
+            tracing::warn!(
+                path = %path.display(),
+                "Skipping session file with non-UTF-8 name"
+            );
+            continue;
+        };
+        let (size, mtime) = file_size_and_mtime(&entry);
+
+        let subtotal = if max_parse_bytes.is_some_and(|max| size > max) {
+            oversized.push(filename.clone());
+            None
+        } else {
+            let unchanged = cached_files
+                .and_then(|files| files.get(&filename))
+                .filter(|cf| cf.size == size && cf.mtime == mtime)
+                .and_then(|cf| cf.subtotal.clone())
+                // Subtotals cached before daily_activity existed have activity but no days
+                .filter(|sub| sub.first_activity.is_none() || !sub.daily_activity.is_empty());
+            unchanged.or_else(|| {
+                let content = fs::read_to_string(&path).ok()?;
+                let mut subtotal = ProjectStats::default();
+                parse_stats_from_content(&content, &mut subtotal);
+                Some(subtotal)
+            })
+        };
+
+        current_files.insert(
+            filename,
+            CachedFileInfo {
+                size,
+                mtime,
+                subtotal,
+            },
+        );
+    }
+    oversized.sort();
+
+    let mut stats = sum_file_subtotals(&current_files);
+    stats.session_count = current_files.len() as u32;
+    stats.partial = !oversized.is_empty();
+    stats.skipped_files = oversized;
+    stats.cache_savings_usd = cache_savings_usd(&stats);
+    stats.estimated_cost_usd = estimate_cost(&stats);
+
+    cache.projects.insert(
+        project_path.to_string(),
+        CachedProjectStats {
+            files: current_files,
+            stats: stats.clone(),
+        },
+    );
+
+    stats
+}
+
+/// Cheap summary of a project's transcript files, compared between polls to
+/// notice changes without parsing anything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TranscriptFingerprint {
+    files: usize,
+    total_bytes: u64,
+    newest_mtime: Option<SystemTime>,
+}
+
+impl TranscriptFingerprint {
+    pub fn load(storage: &StorageConfig, project_path: &str) -> Self {
+        let project_dir = storage
+            .claude_projects_dir()
+            .join(crate::projects::encode_project_path(project_path));
+        let mut fingerprint = Self::default();
+        for metadata in parseable_files_in(&project_dir, None)
+            .iter()
+            .filter_map(|e| e.metadata().ok())
+        {
+            fingerprint.files += 1;
+            fingerprint.total_bytes += metadata.len();
+            fingerprint.newest_mtime = fingerprint.newest_mtime.max(metadata.modified().ok());
+        }
+        fingerprint
+    }
+
+    /// True while the newest transcript was written within [`STATS_CHANGE_DEBOUNCE`].
+    pub fn is_settling(&self, now: SystemTime) -> bool {
+        self.newest_mtime
+            .and_then(|mtime| now.duration_since(mtime).ok())
+            .is_some_and(|age| age < STATS_CHANGE_DEBOUNCE)
+    }
+}
+
+/// Recomputes stats for projects whose transcripts changed since `seen` was
+/// last updated, returning one [`StatsChangedEvent`] per changed project.
+///
+/// Projects not yet in `seen` are recorded without an event. Changed projects
+/// still inside the debounce window are left for a later poll. Stats come from
+/// the incremental cache path, so only modified files are re-parsed.
+pub fn poll_stats_changes_with_storage(
+    storage: &StorageConfig,
+    project_paths: &[String],
+    seen: &mut HashMap<String, TranscriptFingerprint>,
+) -> Result<Vec<StatsChangedEvent>, String> {
+    let now = SystemTime::now();
+    let changed: Vec<(&String, TranscriptFingerprint)> = project_paths
+        .iter()
+        .filter_map(|path| {
+            let fingerprint = TranscriptFingerprint::load(storage, path);
+            match seen.get(path) {
+                None => {
+                    seen.insert(path.clone(), fingerprint);
+                    None
                 }
+                Some(previous) if *previous == fingerprint || fingerprint.is_settling(now) => None,
+                Some(_) => Some((path, fingerprint)),
+            }
+        })
+        .collect();
+    if changed.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let max_parse_bytes = crate::config::load_hud_config_with_storage(storage).max_parse_bytes;
+    let mut cache = crate::config::load_stats_cache_with_storage(storage);
+    let events: Vec<StatsChangedEvent> = changed
+        .into_iter()
+        .map(|(path, fingerprint)| {
+            seen.insert(path.clone(), fingerprint);
+            StatsChangedEvent {
+                project_path: path.clone(),
+                stats: compute_project_stats(
+                    &storage.claude_projects_dir(),
+                    &crate::projects::encode_project_path(path),
+                    &mut cache,
+                    path,
+                    max_parse_bytes,
+                ),
+            }
+        })
+        .collect();
+    crate::config::save_stats_cache_with_storage(storage, &cache)?;
+    Ok(events)
+}
+
+/// Adds up per-file subtotals. The latest summary comes from the most recently
+/// modified file that has one.
+fn sum_file_subtotals(files: &HashMap<String, CachedFileInfo>) -> ProjectStats {
+    let mut ordered: Vec<(&String, &CachedFileInfo)> = files.iter().collect();
+    ordered.sort_by(|a, b| a.1.mtime.cmp(&b.1.mtime).then_with(|| a.0.cmp(b.0)));
+
+    let mut stats = ProjectStats::default();
+    for sub in ordered
+        .into_iter()
+        .filter_map(|(_, info)| info.subtotal.as_ref())
+    {
+        for (family, usage) in usage_by_family(sub) {
+            add_usage(&mut stats, family, *usage);
+        }
+        stats.opus_messages += sub.opus_messages;
+        stats.sonnet_messages += sub.sonnet_messages;
+        stats.haiku_messages += sub.haiku_messages;
+        if sub.latest_summary.is_some() {
+            stats.latest_summary = sub.latest_summary.clone();
+        }
+        if let Some(first) = &sub.first_activity {
+            if stats.first_activity.as_ref().map_or(true, |f| first < f) {
+                stats.first_activity = Some(first.clone());
+            }
+        }
+        if let Some(last) = &sub.last_activity {
+            if stats.last_activity.as_ref().map_or(true, |l| last > l) {
+                stats.last_activity = Some(last.clone());
             }
         }
+        for (day, count) in &sub.daily_activity {
+            *stats.daily_activity.entry(day.clone()).or_default() += count;
+        }
+        for (hour, count) in &sub.hourly_activity {
+            *stats.hourly_activity.entry(hour.clone()).or_default() += count;
+        }
+    }
+    stats
+}
+
+/// Refreshes a project's entry in the stats cache and returns it, so views can
+/// build on the per-file subtotals without re-parsing unchanged files. Saving
+/// the refreshed cache is best-effort.
+fn refreshed_project_cache(storage: &StorageConfig, project_path: &str) -> CachedProjectStats {
+    let max_parse_bytes = crate::config::load_hud_config_with_storage(storage).max_parse_bytes;
+    let mut cache = crate::config::load_stats_cache_with_storage(storage);
+    compute_project_stats(
+        &storage.claude_projects_dir(),
+        &crate::projects::encode_project_path(project_path),
+        &mut cache,
+        project_path,
+        max_parse_bytes,
+    );
+    if let Err(e) = crate::config::save_stats_cache_with_storage(storage, &cache) {
+        tracing::warn!(error = %e, "Failed to save stats cache");
     }
+    cache.projects.remove(project_path).unwrap_or_default()
+}
+
+/// Lists each session file's contribution to a project's stats, largest first.
+///
+/// Composed from the per-file subtotals in the stats cache (refreshed first),
+/// so the breakdown always adds up to the project's `ProjectStats`. Files over
+/// `HudConfig.max_parse_bytes` have no subtotal and are left out, as in the totals.
+pub fn stats_breakdown(project_path: &str) -> Vec<FileStatsContribution> {
+    stats_breakdown_with_storage(&StorageConfig::default(), project_path)
+}
+
+pub fn stats_breakdown_with_storage(
+    storage: &StorageConfig,
+    project_path: &str,
+) -> Vec<FileStatsContribution> {
+    let mut contributions: Vec<FileStatsContribution> =
+        refreshed_project_cache(storage, project_path)
+            .files
+            .into_iter()
+            .filter_map(|(file_name, info)| {
+                let stats = info.subtotal?;
+                Some(FileStatsContribution {
+                    file_name,
+                    input_tokens: stats.total_input_tokens,
+                    output_tokens: stats.total_output_tokens,
+                    cache_read_tokens: stats.total_cache_read_tokens,
+                    cache_creation_tokens: stats.total_cache_creation_tokens,
+                    message_count: stats.opus_messages
+                        + stats.sonnet_messages
+                        + stats.haiku_messages,
+                    token_share: 0.0,
+                })
+            })
+            .collect();
 
-    let file_count_changed = cached.map_or(true, |c| c.files.len() != current_files.len());
-    if file_count_changed {
-        needs_recompute = true;
+    let total: u64 = contributions
+        .iter()
+        .map(|c| c.input_tokens + c.output_tokens)
+        .sum();
+    if total > 0 {
+        for c in &mut contributions {
+            c.token_share = (c.input_tokens + c.output_tokens) as f64 / total as f64;
+        }
     }
+    contributions.sort_by(|a, b| {
+        (b.input_tokens + b.output_tokens)
+            .cmp(&(a.input_tokens + a.output_tokens))
+            .then_with(|| a.file_name.cmp(&b.file_name))
+    });
+    contributions
+}
+
+/// A project's session files, minus any over `HudConfig.max_parse_bytes`.
+fn parseable_session_files(storage: &StorageConfig, project_path: &str) -> Vec<fs::DirEntry> {
+    let max_parse_bytes = crate::config::load_hud_config_with_storage(storage).max_parse_bytes;
+    let project_dir = storage
+        .claude_projects_dir()
+        .join(crate::projects::encode_project_path(project_path));
+    parseable_files_in(&project_dir, max_parse_bytes)
+}
+
+fn parseable_files_in(project_dir: &Path, max_parse_bytes: Option<u64>) -> Vec<fs::DirEntry> {
+    fs::read_dir(project_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "jsonl"))
+        .filter(|e| {
+            let size = e.metadata().map(|m| m.len()).unwrap_or(0);
+            !max_parse_bytes.is_some_and(|max| size > max)
+        })
+        .collect()
+}
+
+/// A file's size and mtime (whole seconds), as recorded in `CachedFileInfo`.
+fn file_size_and_mtime(entry: &fs::DirEntry) -> (u64, u64) {
+    let metadata = entry.metadata().ok();
+    let size = metadata.as_ref().map(|m| m.len()).unwrap_or(0);
+    let mtime = metadata
+        .as_ref()
+        .and_then(|m| m.modified().ok())
+        .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    (size, mtime)
+}
 
-    if !needs_recompute {
-        if let Some(c) = cached {
-            return c.stats.clone();
+/// Reads session files one at a time and passes each one's content to `visit`.
+///
+/// With `since`, files last modified before that UTC day are skipped without
+/// being read: a transcript only grows, so it can't hold entries newer than its mtime.
+fn for_each_transcript(
+    files: impl IntoIterator<Item = fs::DirEntry>,
+    since: Option<NaiveDate>,
+    mut visit: impl FnMut(&str),
+) {
+    let cutoff = since.map(|day| SystemTime::from(day.and_time(NaiveTime::MIN).and_utc()));
+    for entry in files {
+        let modified = entry.metadata().ok().and_then(|m| m.modified().ok());
+        if cutoff.is_some_and(|cutoff| modified.is_some_and(|m| m < cutoff)) {
+            continue;
+        }
+        if let Ok(content) = fs::read_to_string(entry.path()) {
+            visit(&content);
         }
     }
+}
+
+/// Compares how much text the user typed with how much Claude wrote back,
+/// across all of a project's sessions.
+///
+/// The stats cache only tracks tokens, so transcripts are read directly here.
+pub fn authorship_ratio(project_path: &str) -> AuthorshipRatio {
+    authorship_ratio_with_storage(&StorageConfig::default(), project_path)
+}
 
-    let mut stats = ProjectStats {
-        session_count: current_files.len() as u32,
-        ..Default::default()
+pub fn authorship_ratio_with_storage(
+    storage: &StorageConfig,
+    project_path: &str,
+) -> AuthorshipRatio {
+    let mut ratio = AuthorshipRatio::default();
+    for entry in parseable_session_files(storage, project_path) {
+        if let Ok(content) = fs::read_to_string(entry.path()) {
+            add_authorship_from_content(&content, &mut ratio);
+        }
+    }
+    ratio.ratio = if ratio.assistant_chars > 0 {
+        ratio.human_chars as f64 / ratio.assistant_chars as f64
+    } else {
+        0.0
     };
+    ratio
+}
 
-    for entry in fs::read_dir(&project_dir)
+/// Adds one transcript's prompt and reply characters to the totals.
+///
+/// Only text blocks count: tool calls, tool results, thinking, and meta lines
+/// (injected context, command output) are skipped.
+pub fn add_authorship_from_content(content: &str, ratio: &mut AuthorshipRatio) {
+    for line in content.lines() {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if value.get("isMeta").and_then(|m| m.as_bool()) == Some(true) {
+            continue;
+        }
+        let Some(message_content) = value.get("message").and_then(|m| m.get("content")) else {
+            continue;
+        };
+        let chars = text_char_count(message_content);
+        match value.get("type").and_then(|t| t.as_str()) {
+            Some("user") => ratio.human_chars += chars,
+            Some("assistant") => ratio.assistant_chars += chars,
+            _ => {}
+        }
+    }
+}
+
+/// Characters in a message's text, whether stored as a string or as blocks.
+fn text_char_count(content: &serde_json::Value) -> u64 {
+    match content {
+        serde_json::Value::String(text) => text.chars().count() as u64,
+        serde_json::Value::Array(blocks) => blocks
+            .iter()
+            .filter(|b| b.get("type").and_then(|t| t.as_str()) == Some("text"))
+            .filter_map(|b| b.get("text").and_then(|t| t.as_str()))
+            .map(|text| text.chars().count() as u64)
+            .sum(),
+        _ => 0,
+    }
+}
+
+/// List prices per million tokens for one model family.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelPricing {
+    pub input_per_mtok: f64,
+    pub output_per_mtok: f64,
+    pub cache_read_per_mtok: f64,
+    pub cache_write_per_mtok: f64,
+}
+
+/// Prices used for cost estimates. Update when Anthropic's price list changes.
+pub const OPUS_PRICING: ModelPricing = ModelPricing {
+    input_per_mtok: 15.0,
+    output_per_mtok: 75.0,
+    cache_read_per_mtok: 1.5,
+    cache_write_per_mtok: 18.75,
+};
+pub const SONNET_PRICING: ModelPricing = ModelPricing {
+    input_per_mtok: 3.0,
+    output_per_mtok: 15.0,
+    cache_read_per_mtok: 0.3,
+    cache_write_per_mtok: 3.75,
+};
+pub const HAIKU_PRICING: ModelPricing = ModelPricing {
+    input_per_mtok: 0.8,
+    output_per_mtok: 4.0,
+    cache_read_per_mtok: 0.08,
+    cache_write_per_mtok: 1.0,
+};
+
+/// Per-family price table used by cost estimates. Families missing from the
+/// table (including `Other`) are priced as Sonnet.
+pub static MODEL_PRICING: Lazy<HashMap<ModelFamily, ModelPricing>> = Lazy::new(|| {
+    HashMap::from([
+        (ModelFamily::Opus, OPUS_PRICING),
+        (ModelFamily::Sonnet, SONNET_PRICING),
+        (ModelFamily::Haiku, HAIKU_PRICING),
+    ])
+});
+
+impl ModelPricing {
+    /// Dollar cost of the given token counts at these prices, unrounded.
+    ///
+    /// Every cost estimate goes through here so they all price the same token types.
+    pub fn cost_usd(&self, input: u64, output: u64, cache_read: u64, cache_creation: u64) -> f64 {
+        (input as f64 * self.input_per_mtok
+            + output as f64 * self.output_per_mtok
+            + cache_read as f64 * self.cache_read_per_mtok
+            + cache_creation as f64 * self.cache_write_per_mtok)
+            / 1_000_000.0
+    }
+}
+
+impl ModelFamily {
+    /// Infers the family from a model id like `claude-sonnet-4-20250514`.
+    pub fn from_model_id(model: &str) -> Self {
+        if model.contains("opus") {
+            ModelFamily::Opus
+        } else if model.contains("sonnet") {
+            ModelFamily::Sonnet
+        } else if model.contains("haiku") {
+            ModelFamily::Haiku
+        } else {
+            ModelFamily::Other
+        }
+    }
+
+    /// List prices for the family; unknown models are priced as Sonnet.
+    pub fn pricing(self) -> ModelPricing {
+        MODEL_PRICING.get(&self).copied().unwrap_or(SONNET_PRICING)
+    }
+}
+
+/// Estimated dollar cost of a project's token usage, rounded to cents.
+///
+/// Each family's tokens are priced at its own `MODEL_PRICING` rates, the same
+/// way `usage_for_period` and `budget_status` price them.
+pub fn estimate_cost(stats: &ProjectStats) -> f64 {
+    let dollars: f64 = usage_by_family(stats)
+        .iter()
+        .map(|(family, usage)| usage.cost_usd(*family))
+        .sum();
+    (dollars * 100.0).round() / 100.0
+}
+
+/// Dollar value saved by reading tokens from the prompt cache instead of paying
+/// the full input rate, at each family's own rates.
+pub fn cache_savings_usd(stats: &ProjectStats) -> f64 {
+    usage_by_family(stats)
+        .iter()
+        .map(|(family, usage)| {
+            let pricing = family.pricing();
+            usage.cache_read as f64 / 1_000_000.0
+                * (pricing.input_per_mtok - pricing.cache_read_per_mtok)
+        })
+        .sum()
+}
+
+/// Token usage per time bucket and model family for a project, oldest first.
+///
+/// Buckets between the first and last active one are included with zero usage
+/// so the series can be charted directly.
+pub fn model_usage_timeseries(project_path: &str, bucket: UsageBucket) -> Vec<ModelUsagePoint> {
+    model_usage_timeseries_with_storage(&StorageConfig::default(), project_path, bucket)
+}
+
+pub fn model_usage_timeseries_with_storage(
+    storage: &StorageConfig,
+    project_path: &str,
+    bucket: UsageBucket,
+) -> Vec<ModelUsagePoint> {
+    let mut buckets = BTreeMap::new();
+    for_each_transcript(
+        parseable_session_files(storage, project_path),
+        None,
+        |content| add_model_usage(content, bucket, &mut buckets),
+    );
+    model_usage_series(buckets, bucket)
+}
+
+/// Buckets usage from transcript contents. Assistant lines need a timestamp,
+/// a model, and a usage block to count.
+pub fn model_usage_from_contents<'a>(
+    contents: impl IntoIterator<Item = &'a str>,
+    bucket: UsageBucket,
+) -> Vec<ModelUsagePoint> {
+    let mut buckets = BTreeMap::new();
+    for content in contents {
+        add_model_usage(content, bucket, &mut buckets);
+    }
+    model_usage_series(buckets, bucket)
+}
+
+fn add_model_usage(
+    content: &str,
+    bucket: UsageBucket,
+    buckets: &mut BTreeMap<NaiveDate, ModelUsagePoint>,
+) {
+    for line in content.lines() {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        let Some(message) = value.get("message") else {
+            continue;
+        };
+        let (Some(at), Some(model), Some(usage)) = (
+            value
+                .get("timestamp")
+                .and_then(|t| t.as_str())
+                .and_then(|t| DateTime::parse_from_rfc3339(t).ok()),
+            message.get("model").and_then(|m| m.as_str()),
+            message.get("usage"),
+        ) else {
+            continue;
+        };
+        let tokens: u64 = ["input_tokens", "output_tokens"]
+            .iter()
+            .filter_map(|key| usage.get(*key).and_then(|v| v.as_u64()))
+            .sum();
+
+        let start = bucket_start(at.with_timezone(&Utc).date_naive(), bucket);
+        let point = buckets.entry(start).or_default();
+        if model.contains("opus") {
+            point.opus_tokens += tokens;
+        } else if model.contains("sonnet") {
+            point.sonnet_tokens += tokens;
+        } else if model.contains("haiku") {
+            point.haiku_tokens += tokens;
+        } else {
+            point.other_tokens += tokens;
+        }
+    }
+}
+
+/// Orders buckets into a series, zero-filling gaps between active ones.
+fn model_usage_series(
+    mut buckets: BTreeMap<NaiveDate, ModelUsagePoint>,
+    bucket: UsageBucket,
+) -> Vec<ModelUsagePoint> {
+    let (Some(first), Some(last)) = (
+        buckets.keys().next().copied(),
+        buckets.keys().next_back().copied(),
+    ) else {
+        return Vec::new();
+    };
+    let step = match bucket {
+        UsageBucket::Day => 1,
+        UsageBucket::Week => 7,
+    };
+
+    let mut series = Vec::new();
+    let mut day = first;
+    while day <= last {
+        let mut point = buckets.remove(&day).unwrap_or_default();
+        point.bucket_start = day.format("%Y-%m-%d").to_string();
+        series.push(point);
+        day += chrono::Duration::days(step);
+    }
+    series
+}
+
+fn bucket_start(date: NaiveDate, bucket: UsageBucket) -> NaiveDate {
+    match bucket {
+        UsageBucket::Day => date,
+        UsageBucket::Week => {
+            date - chrono::Duration::days(i64::from(date.weekday().num_days_from_monday()))
+        }
+    }
+}
+
+/// Aggregates usage across all projects for days from `start` to `end`
+/// inclusive (both `YYYY-MM-DD`, UTC), with an estimated cost per model family.
+pub fn usage_for_period(start: &str, end: &str) -> Result<PeriodUsage, String> {
+    usage_for_period_with_storage(&StorageConfig::default(), start, end)
+}
+
+pub fn usage_for_period_with_storage(
+    storage: &StorageConfig,
+    start: &str,
+    end: &str,
+) -> Result<PeriodUsage, String> {
+    let (first, last) = parse_period(start, end)?;
+    let max_parse_bytes = crate::config::load_hud_config_with_storage(storage).max_parse_bytes;
+    let mut daily = BTreeMap::new();
+    for project in fs::read_dir(storage.claude_projects_dir())
         .into_iter()
         .flatten()
-        .filter_map(|e| e.ok())
+        .flatten()
+        .filter(|e| e.path().is_dir())
     {
-        let path = entry.path();
-        if path.extension().is_some_and(|ext| ext == "jsonl") {
-            if let Ok(content) = fs::read_to_string(&path) {
-                parse_stats_from_content(&content, &mut stats);
-            }
+        for_each_transcript(
+            parseable_files_in(&project.path(), max_parse_bytes),
+            Some(first),
+            |content| add_daily_tokens(content, &mut daily),
+        );
+    }
+    Ok(period_usage_from_daily(&daily, first, last))
+}
+
+/// Aggregates usage from transcript contents for days from `start` to `end`.
+pub fn usage_for_period_from_contents<'a>(
+    contents: impl IntoIterator<Item = &'a str>,
+    start: &str,
+    end: &str,
+) -> Result<PeriodUsage, String> {
+    let (first, last) = parse_period(start, end)?;
+    Ok(period_usage_from_daily(
+        &daily_tokens_by_family(contents),
+        first,
+        last,
+    ))
+}
+
+fn parse_period(start: &str, end: &str) -> Result<(NaiveDate, NaiveDate), String> {
+    let parse_day = |day: &str| {
+        NaiveDate::parse_from_str(day, "%Y-%m-%d")
+            .map_err(|e| format!("Invalid date '{}': {}", day, e))
+    };
+    let (first, last) = (parse_day(start)?, parse_day(end)?);
+    if first > last {
+        return Err(format!("Period start {} is after end {}", start, end));
+    }
+    Ok((first, last))
+}
+
+fn period_usage_from_daily(
+    daily: &BTreeMap<NaiveDate, HashMap<ModelFamily, TokenCounts>>,
+    first: NaiveDate,
+    last: NaiveDate,
+) -> PeriodUsage {
+    let mut by_family: HashMap<ModelFamily, TokenCounts> = HashMap::new();
+    for (_, families) in daily.range(first..=last) {
+        for (family, tokens) in families {
+            by_family.entry(*family).or_default().add(tokens);
         }
     }
 
-    cache.projects.insert(
-        project_path.to_string(),
-        CachedProjectStats {
-            files: current_files,
-            stats: stats.clone(),
-        },
+    let mut models: Vec<ModelPeriodUsage> = by_family
+        .into_iter()
+        .map(|(family, tokens)| ModelPeriodUsage {
+            family,
+            input_tokens: tokens.input,
+            output_tokens: tokens.output,
+            cache_read_tokens: tokens.cache_read,
+            cache_creation_tokens: tokens.cache_creation,
+            cost_usd: tokens.cost_usd(family),
+        })
+        .collect();
+    models.sort_by(|a, b| b.cost_usd.total_cmp(&a.cost_usd));
+
+    PeriodUsage {
+        start: first.format("%Y-%m-%d").to_string(),
+        end: last.format("%Y-%m-%d").to_string(),
+        input_tokens: models.iter().map(|m| m.input_tokens).sum(),
+        output_tokens: models.iter().map(|m| m.output_tokens).sum(),
+        cache_read_tokens: models.iter().map(|m| m.cache_read_tokens).sum(),
+        cache_creation_tokens: models.iter().map(|m| m.cache_creation_tokens).sum(),
+        cost_usd: models.iter().map(|m| m.cost_usd).sum(),
+        models,
+    }
+}
+
+/// Fraction of the monthly budget at which `budget_status` starts warning.
+const BUDGET_WARNING_FRACTION: f64 = 0.8;
+
+/// Compares this month's spend across all projects with `HudConfig.monthly_budget_usd`.
+pub fn budget_status() -> Result<BudgetStatus, String> {
+    budget_status_with_storage(&StorageConfig::default())
+}
+
+pub fn budget_status_with_storage(storage: &StorageConfig) -> Result<BudgetStatus, String> {
+    let budget = crate::config::load_hud_config_with_storage(storage).monthly_budget_usd;
+    let today = Utc::now().date_naive();
+    let month_start = today.with_day(1).unwrap_or(today);
+    let usage = usage_for_period_with_storage(
+        storage,
+        &month_start.format("%Y-%m-%d").to_string(),
+        &today.format("%Y-%m-%d").to_string(),
+    )?;
+    Ok(budget_status_from_spend(
+        today.format("%Y-%m").to_string(),
+        usage.cost_usd,
+        budget,
+    ))
+}
+
+/// Builds a budget status from a month's spend. Budgets of zero or less are ignored.
+pub fn budget_status_from_spend(
+    month: String,
+    spent_usd: f64,
+    budget_usd: Option<f64>,
+) -> BudgetStatus {
+    let budget_usd = budget_usd.filter(|b| *b > 0.0);
+    let fraction = budget_usd.map(|b| spent_usd / b);
+    let level = match fraction {
+        Some(f) if f >= 1.0 => BudgetLevel::Over,
+        Some(f) if f >= BUDGET_WARNING_FRACTION => BudgetLevel::Warning,
+        _ => BudgetLevel::Ok,
+    };
+    BudgetStatus {
+        month,
+        spent_usd,
+        budget_usd,
+        percent_used: fraction.map(|f| f * 100.0),
+        level,
+    }
+}
+
+impl TokenCounts {
+    fn add(&mut self, other: &TokenCounts) {
+        self.input += other.input;
+        self.output += other.output;
+        self.cache_read += other.cache_read;
+        self.cache_creation += other.cache_creation;
+    }
+
+    fn cost_usd(&self, family: ModelFamily) -> f64 {
+        family.pricing().cost_usd(
+            self.input,
+            self.output,
+            self.cache_read,
+            self.cache_creation,
+        )
+    }
+}
+
+/// The `limit` most expensive sessions across all projects, by estimated cost.
+///
+/// Files whose size and mtime match the stats cache are priced from their
+/// cached subtotal; only new or changed transcripts are parsed. Files over
+/// `HudConfig.max_parse_bytes` are skipped.
+pub fn top_sessions_by_cost(limit: u32) -> Vec<SessionCost> {
+    top_sessions_by_cost_with_storage(&StorageConfig::default(), limit)
+}
+
+pub fn top_sessions_by_cost_with_storage(storage: &StorageConfig, limit: u32) -> Vec<SessionCost> {
+    let max_parse_bytes = crate::config::load_hud_config_with_storage(storage).max_parse_bytes;
+    let cache = crate::config::load_stats_cache_with_storage(storage);
+    let cached_by_folder: HashMap<String, (&String, &CachedProjectStats)> = cache
+        .projects
+        .iter()
+        .map(|(path, cached)| (crate::projects::encode_project_path(path), (path, cached)))
+        .collect();
+
+    let mut sessions: Vec<SessionCost> = fs::read_dir(storage.claude_projects_dir())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|e| e.path().is_dir())
+        .flat_map(|project| {
+            let folder = project.file_name().to_string_lossy().to_string();
+            let cached = cached_by_folder.get(&folder).copied();
+            let fallback_path = StorageConfig::try_resolve_encoded_path(&folder)
+                .unwrap_or_else(|| StorageConfig::decode_path(&folder));
+            parseable_files_in(&project.path(), max_parse_bytes)
+                .into_iter()
+                .filter_map(move |e| {
+                    let session_id = e.path().file_stem()?.to_str()?.to_string();
+                    let (size, mtime) = file_size_and_mtime(&e);
+                    let unchanged = cached.and_then(|(project_path, cached)| {
+                        let file = cached.files.get(e.file_name().to_str()?)?;
+                        let subtotal = file.subtotal.as_ref()?;
+                        (file.size == size && file.mtime == mtime)
+                            .then(|| session_cost(subtotal, session_id.clone(), project_path))
+                    });
+                    unchanged.or_else(|| {
+                        let content = fs::read_to_string(e.path()).ok()?;
+                        Some(session_cost_from_content(
+                            &content,
+                            session_id,
+                            &fallback_path,
+                        ))
+                    })
+                })
+        })
+        .filter(|s| s.cost_usd > 0.0)
+        .collect();
+
+    sessions.sort_by(|a, b| {
+        b.cost_usd
+            .total_cmp(&a.cost_usd)
+            .then_with(|| a.session_id.cmp(&b.session_id))
+    });
+    sessions.truncate(limit as usize);
+    sessions
+}
+
+/// Totals one transcript's usage and cost.
+///
+/// The project path is the transcript's first recorded `cwd`, falling back to
+/// `fallback_path` (the decoded projects folder name).
+pub fn session_cost_from_content(
+    content: &str,
+    session_id: String,
+    fallback_path: &str,
+) -> SessionCost {
+    let mut stats = ProjectStats::default();
+    parse_stats_from_content(content, &mut stats);
+
+    let project_path = content
+        .lines()
+        .filter(|line| line.contains("\"cwd\""))
+        .find_map(|line| {
+            let value: serde_json::Value = serde_json::from_str(line).ok()?;
+            value.get("cwd")?.as_str().map(String::from)
+        })
+        .unwrap_or_else(|| fallback_path.to_string());
+
+    session_cost(&stats, session_id, &project_path)
+}
+
+/// Prices one transcript's parsed stats at each model family's rates.
+fn session_cost(stats: &ProjectStats, session_id: String, project_path: &str) -> SessionCost {
+    SessionCost {
+        project_path: project_path.to_string(),
+        session_id,
+        summary: stats.latest_summary.clone(),
+        input_tokens: stats.total_input_tokens,
+        output_tokens: stats.total_output_tokens,
+        cache_read_tokens: stats.total_cache_read_tokens,
+        cache_creation_tokens: stats.total_cache_creation_tokens,
+        cost_usd: usage_by_family(stats)
+            .iter()
+            .map(|(family, usage)| usage.cost_usd(*family))
+            .sum(),
+    }
+}
+
+/// Buckets usage blocks by UTC day and model family.
+fn daily_tokens_by_family<'a>(
+    contents: impl IntoIterator<Item = &'a str>,
+) -> BTreeMap<NaiveDate, HashMap<ModelFamily, TokenCounts>> {
+    let mut days = BTreeMap::new();
+    for content in contents {
+        add_daily_tokens(content, &mut days);
+    }
+    days
+}
+
+fn add_daily_tokens(
+    content: &str,
+    days: &mut BTreeMap<NaiveDate, HashMap<ModelFamily, TokenCounts>>,
+) {
+    for line in content.lines() {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        let Some(message) = value.get("message") else {
+            continue;
+        };
+        let (Some(at), Some(model), Some(usage)) = (
+            value
+                .get("timestamp")
+                .and_then(|t| t.as_str())
+                .and_then(|t| DateTime::parse_from_rfc3339(t).ok()),
+            message.get("model").and_then(|m| m.as_str()),
+            message.get("usage"),
+        ) else {
+            continue;
+        };
+        let count = |key: &str| usage.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+
+        let tokens = days
+            .entry(at.with_timezone(&Utc).date_naive())
+            .or_default()
+            .entry(ModelFamily::from_model_id(model))
+            .or_default();
+        tokens.input += count("input_tokens");
+        tokens.output += count("output_tokens");
+        tokens.cache_read += count("cache_read_input_tokens");
+        tokens.cache_creation += count("cache_creation_input_tokens");
+    }
+}
+
+/// Relative change in output per input token, between the earlier and later
+/// halves of a window's active days, below which the trend is flat.
+const EFFICIENCY_FLAT_THRESHOLD: f64 = 0.05;
+
+/// Output tokens per uncached input token for each of the last `days` days,
+/// with whether it is trending up or down.
+pub fn efficiency_trend(project_path: &str, days: u32) -> EfficiencyTrend {
+    efficiency_trend_with_storage(&StorageConfig::default(), project_path, days)
+}
+
+pub fn efficiency_trend_with_storage(
+    storage: &StorageConfig,
+    project_path: &str,
+    days: u32,
+) -> EfficiencyTrend {
+    let today = Utc::now().date_naive();
+    let window_start = today - chrono::Duration::days(i64::from(days.saturating_sub(1)));
+    let mut daily = BTreeMap::new();
+    for_each_transcript(
+        parseable_session_files(storage, project_path),
+        Some(window_start),
+        |content| add_daily_tokens(content, &mut daily),
     );
+    efficiency_from_daily(&daily, today, days)
+}
 
-    stats
+/// Computes the efficiency series for the `days` days ending on `today`.
+///
+/// Days without activity are included with no ratio and don't count toward the
+/// trend, which compares the average ratio of the earlier half of the active
+/// days against the later half.
+pub fn efficiency_from_contents<'a>(
+    contents: impl IntoIterator<Item = &'a str>,
+    today: NaiveDate,
+    days: u32,
+) -> EfficiencyTrend {
+    efficiency_from_daily(&daily_tokens_by_family(contents), today, days)
+}
+
+fn efficiency_from_daily(
+    daily: &BTreeMap<NaiveDate, HashMap<ModelFamily, TokenCounts>>,
+    today: NaiveDate,
+    days: u32,
+) -> EfficiencyTrend {
+    let points: Vec<EfficiencyPoint> = (0..days)
+        .rev()
+        .map(|ago| today - chrono::Duration::days(i64::from(ago)))
+        .map(|day| {
+            let families = daily.get(&day);
+            let input_tokens = families.map_or(0, |f| f.values().map(|t| t.input).sum());
+            let output_tokens = families.map_or(0, |f| f.values().map(|t| t.output).sum());
+            EfficiencyPoint {
+                day: day.format("%Y-%m-%d").to_string(),
+                input_tokens,
+                output_tokens,
+                output_per_input: (input_tokens > 0)
+                    .then(|| output_tokens as f64 / input_tokens as f64),
+            }
+        })
+        .collect();
+
+    let ratios: Vec<f64> = points.iter().filter_map(|p| p.output_per_input).collect();
+    let direction = if ratios.len() < 2 {
+        TrendDirection::Flat
+    } else {
+        let (earlier, later) = ratios.split_at(ratios.len() / 2);
+        let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len() as f64;
+        let (earlier, later) = (mean(earlier), mean(later));
+        if earlier == 0.0 {
+            if later > 0.0 {
+                TrendDirection::Up
+            } else {
+                TrendDirection::Flat
+            }
+        } else {
+            let change = (later - earlier) / earlier;
+            if change > EFFICIENCY_FLAT_THRESHOLD {
+                TrendDirection::Up
+            } else if change < -EFFICIENCY_FLAT_THRESHOLD {
+                TrendDirection::Down
+            } else {
+                TrendDirection::Flat
+            }
+        }
+    };
+
+    EfficiencyTrend { points, direction }
+}
+
+/// Measures how many sessions and file edits a project saw per day over the
+/// last `days` days.
+///
+/// Sessions come from transcript timestamps; edits come from the file-activity
+/// feed, which only keeps recent entries, so edit rates cover what it retains.
+pub fn project_velocity(project_path: &str, days: u32) -> ProjectVelocity {
+    project_velocity_with_storage(&StorageConfig::default(), project_path, days)
+}
+
+pub fn project_velocity_with_storage(
+    storage: &StorageConfig,
+    project_path: &str,
+    days: u32,
+) -> ProjectVelocity {
+    let project_dir = storage
+        .claude_projects_dir()
+        .join(crate::projects::encode_project_path(project_path));
+    let sessions: Vec<Vec<DateTime<Utc>>> = fs::read_dir(&project_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "jsonl"))
+        .filter_map(|e| fs::read_to_string(e.path()).ok())
+        .map(|content| activity_times(&content))
+        .collect();
+
+    let edits = project_edit_timestamps(storage, project_path);
+    velocity_from_timestamps(&sessions, &edits, Utc::now(), days)
+}
+
+/// Timestamps of file writes recorded for a project in the file-activity feed.
+fn project_edit_timestamps(storage: &StorageConfig, project_path: &str) -> Vec<DateTime<Utc>> {
+    let project = crate::state::normalize_path_for_matching(project_path);
+    crate::activity::ActivityStore::load(&storage.file_activity_file())
+        .sessions
+        .values()
+        .flat_map(|s| s.activity.iter())
+        .filter(|a| {
+            a.is_write() && crate::state::normalize_path_for_matching(&a.project_path) == project
+        })
+        .filter_map(|a| DateTime::parse_from_rfc3339(&a.timestamp).ok())
+        .map(|t| t.with_timezone(&Utc))
+        .collect()
+}
+
+/// Computes velocity from per-session timestamps and edit timestamps.
+pub fn velocity_from_timestamps(
+    sessions: &[Vec<DateTime<Utc>>],
+    edits: &[DateTime<Utc>],
+    now: DateTime<Utc>,
+    days: u32,
+) -> ProjectVelocity {
+    let since = now - chrono::Duration::days(i64::from(days));
+    let in_window = |t: &&DateTime<Utc>| **t > since && **t <= now;
+
+    let mut active: std::collections::HashSet<NaiveDate> = std::collections::HashSet::new();
+    let mut session_count = 0u32;
+    for timestamps in sessions {
+        let mut counted = false;
+        for t in timestamps.iter().filter(in_window) {
+            active.insert(t.date_naive());
+            counted = true;
+        }
+        session_count += u32::from(counted);
+    }
+    let mut edit_count = 0u32;
+    for t in edits.iter().filter(in_window) {
+        active.insert(t.date_naive());
+        edit_count += 1;
+    }
+
+    let active_days = active.len() as u32;
+    let rate = |count: u32, over: u32| {
+        if over == 0 {
+            0.0
+        } else {
+            f64::from(count) / f64::from(over)
+        }
+    };
+
+    ProjectVelocity {
+        window_days: days,
+        active_days,
+        session_count,
+        edit_count,
+        sessions_per_calendar_day: rate(session_count, days),
+        sessions_per_active_day: rate(session_count, active_days),
+        edits_per_calendar_day: rate(edit_count, days),
+        edits_per_active_day: rate(edit_count, active_days),
+    }
+}
+
+/// Computes a project's current and longest streaks of consecutive active days.
+///
+/// Days are taken from transcript timestamps converted to the local timezone,
+/// so a late-evening session counts toward the day the user saw it. The
+/// timestamps come from the stats cache, so only changed files are re-read.
+pub fn activity_streak(project_path: &str) -> ActivityStreak {
+    activity_streak_with_storage(&StorageConfig::default(), project_path)
+}
+
+pub fn activity_streak_with_storage(storage: &StorageConfig, project_path: &str) -> ActivityStreak {
+    // Unlike `daily_activity`, which is keyed by UTC date, streaks follow the
+    // user's local calendar, so they're built from the cached UTC hours
+    let stats = refreshed_project_cache(storage, project_path).stats;
+    let dates = stats.hourly_activity.keys().filter_map(|hour| {
+        let t =
+            chrono::NaiveDateTime::parse_from_str(&format!("{hour}:00"), "%Y-%m-%dT%H:%M").ok()?;
+        Some(t.and_utc().with_timezone(&Local).date_naive())
+    });
+
+    streak_from_dates(dates, Local::now().date_naive())
+}
+
+/// Computes streaks from active dates (duplicates allowed) as of `today`.
+pub fn streak_from_dates(
+    dates: impl IntoIterator<Item = NaiveDate>,
+    today: NaiveDate,
+) -> ActivityStreak {
+    let days: std::collections::BTreeSet<NaiveDate> = dates.into_iter().collect();
+
+    let mut longest = 0u32;
+    let mut run = 0u32;
+    let mut previous: Option<NaiveDate> = None;
+    for &day in &days {
+        run = match previous {
+            Some(prev) if prev.succ_opt() == Some(day) => run + 1,
+            _ => 1,
+        };
+        longest = longest.max(run);
+        previous = Some(day);
+    }
+
+    // `run` is the streak ending on the last active day; it only counts as
+    // current if that day is today or yesterday.
+    let current = match previous {
+        Some(last) if last == today || last.succ_opt() == Some(today) => run,
+        _ => 0,
+    };
+
+    ActivityStreak {
+        current_days: current,
+        longest_days: longest,
+        last_active_date: previous.map(|d| d.format("%Y-%m-%d").to_string()),
+    }
+}
+
+/// Window covered by [`activity_sparkline`].
+const SPARKLINE_WINDOW_SECS: i64 = 24 * 60 * 60;
+
+/// Upper bound on sparkline resolution (15-minute buckets).
+const SPARKLINE_MAX_BUCKETS: u32 = 96;
+
+/// Counts a project's file edits over the last 24 hours in `buckets` equal
+/// buckets, oldest first. Empty buckets are zero.
+pub fn activity_sparkline(project_path: &str, buckets: u32) -> Vec<u32> {
+    activity_sparkline_with_storage(&StorageConfig::default(), project_path, buckets)
+}
+
+pub fn activity_sparkline_with_storage(
+    storage: &StorageConfig,
+    project_path: &str,
+    buckets: u32,
+) -> Vec<u32> {
+    let edits = project_edit_timestamps(storage, project_path);
+    sparkline_from_timestamps(&edits, Utc::now(), buckets)
+}
+
+/// Buckets timestamps over the 24 hours ending at `now`.
+pub fn sparkline_from_timestamps(
+    times: &[DateTime<Utc>],
+    now: DateTime<Utc>,
+    buckets: u32,
+) -> Vec<u32> {
+    let buckets = buckets.clamp(1, SPARKLINE_MAX_BUCKETS);
+    let mut counts = vec![0u32; buckets as usize];
+    let since = now - chrono::Duration::seconds(SPARKLINE_WINDOW_SECS);
+    for t in times.iter().filter(|t| **t > since && **t <= now) {
+        let offset = t.signed_duration_since(since).num_seconds();
+        let index =
+            (offset * i64::from(buckets) / SPARKLINE_WINDOW_SECS).min(i64::from(buckets) - 1);
+        counts[index as usize] += 1;
+    }
+    counts
+}
+
+/// Context window size assumed for burn-rate estimates.
+pub const CONTEXT_WINDOW_TOKENS: u64 = 200_000;
+
+/// Number of trailing usage blocks used to estimate the burn rate.
+const BURN_RATE_WINDOW: usize = 5;
+
+/// Estimates how fast a session is filling its context window, from the last
+/// few usage blocks in its transcript.
+///
+/// Returns None when the transcript is missing or has fewer than two timed
+/// usage blocks.
+pub fn context_burn_rate(session_id: &str, project_path: &str) -> Option<ContextBurnRate> {
+    context_burn_rate_with_storage(&StorageConfig::default(), session_id, project_path)
+}
+
+pub fn context_burn_rate_with_storage(
+    storage: &StorageConfig,
+    session_id: &str,
+    project_path: &str,
+) -> Option<ContextBurnRate> {
+    let transcript = session_transcript_path(storage, project_path, session_id)?;
+    let content = fs::read_to_string(transcript).ok()?;
+    burn_rate_from_content(&content)
+}
+
+/// Computes the burn rate from transcript content.
+///
+/// Context size at each assistant turn is its input plus cached input tokens;
+/// the rate is the growth between the first and last block in the window.
+pub fn burn_rate_from_content(content: &str) -> Option<ContextBurnRate> {
+    let mut model = None;
+    let blocks: Vec<(DateTime<Utc>, u64)> = content
+        .lines()
+        .filter_map(|line| {
+            let value: serde_json::Value = serde_json::from_str(line).ok()?;
+            let message = value.get("message")?;
+            let usage = message.get("usage")?;
+            let at = DateTime::parse_from_rfc3339(value.get("timestamp")?.as_str()?).ok()?;
+            if let Some(name) = message.get("model").and_then(|m| m.as_str()) {
+                model = Some(name.to_string());
+            }
+            Some((at.with_timezone(&Utc), context_tokens(usage)))
+        })
+        .collect();
+
+    let window = &blocks[blocks.len().saturating_sub(BURN_RATE_WINDOW)..];
+    let (first, last) = (window.first()?, window.last()?);
+    let elapsed_secs = (last.0 - first.0).num_seconds();
+    if elapsed_secs <= 0 {
+        return None;
+    }
+
+    let tokens_per_minute = (last.1 as f64 - first.1 as f64) / (elapsed_secs as f64 / 60.0);
+    let context_size = context_size_for_usage(model.as_deref(), last.1);
+    let remaining = context_size.saturating_sub(last.1);
+    let minutes_remaining = (tokens_per_minute > 0.0).then(|| remaining as f64 / tokens_per_minute);
+
+    Some(ContextBurnRate {
+        tokens_per_minute,
+        tokens_used: last.1,
+        context_size,
+        minutes_remaining,
+    })
+}
+
+/// Input plus cached input tokens of a usage block: the whole prompt sent for
+/// that turn, and so the context size at that point.
+fn context_tokens(usage: &serde_json::Value) -> u64 {
+    [
+        "input_tokens",
+        "cache_read_input_tokens",
+        "cache_creation_input_tokens",
+    ]
+    .iter()
+    .filter_map(|key| usage.get(*key).and_then(|v| v.as_u64()))
+    .sum()
+}
+
+/// Context window size for a model id.
+///
+/// Model ids given with the CLI's `[1m]` suffix get the 1M-token window;
+/// everything else gets the standard window.
+pub fn context_size_for_model(model: &str) -> u64 {
+    if model.to_ascii_lowercase().ends_with("[1m]") {
+        1_000_000
+    } else {
+        CONTEXT_WINDOW_TOKENS
+    }
+}
+
+/// Context window size for a transcript's model and current prompt size.
+///
+/// Transcripts record the API model id, which never carries the `[1m]` alias
+/// suffix, so a prompt larger than the standard window is what reveals a
+/// session running with the 1M-token window.
+fn context_size_for_usage(model: Option<&str>, tokens_used: u64) -> u64 {
+    let size = model.map_or(CONTEXT_WINDOW_TOKENS, context_size_for_model);
+    if tokens_used > size {
+        1_000_000
+    } else {
+        size
+    }
+}
+
+/// How full a session's context window is, from its latest usage block.
+///
+/// Returns None when the session or its transcript can't be found, or the
+/// transcript has no usage yet.
+pub fn session_context(session_id: &str) -> Option<ContextInfo> {
+    session_context_with_storage(&StorageConfig::default(), session_id)
+}
+
+pub fn session_context_with_storage(
+    storage: &StorageConfig,
+    session_id: &str,
+) -> Option<ContextInfo> {
+    let transcript = crate::transcript::locate_transcript(storage, session_id)?;
+    let content = fs::read_to_string(transcript).ok()?;
+    context_info_from_content(&content)
+}
+
+/// Computes context usage from transcript content.
+///
+/// Each assistant turn's input plus cached input tokens is the whole prompt
+/// sent for that turn, so the most recent one is the current context size.
+/// Synthetic messages (local errors, interrupts) carry no real usage and are skipped.
+pub fn context_info_from_content(content: &str) -> Option<ContextInfo> {
+    let (message, timestamp) = content.lines().rev().find_map(|line| {
+        let value: serde_json::Value = serde_json::from_str(line).ok()?;
+        if value.get("type")?.as_str()? != "assistant" {
+            return None;
+        }
+        let message = value.get("message")?;
+        message.get("usage")?;
+        if message.get("model").and_then(|m| m.as_str()) == Some("<synthetic>") {
+            return None;
+        }
+        let timestamp = value
+            .get("timestamp")
+            .and_then(|t| t.as_str())
+            .map(String::from);
+        Some((message.clone(), timestamp))
+    })?;
+
+    let tokens_used = context_tokens(message.get("usage")?);
+    let context_size =
+        context_size_for_usage(message.get("model").and_then(|m| m.as_str()), tokens_used);
+
+    Some(ContextInfo {
+        percent_used: (tokens_used.saturating_mul(100) / context_size).min(100) as u32,
+        tokens_used,
+        context_size,
+        updated_at: timestamp,
+    })
+}
+
+/// Average, median, and longest session length for a project.
+///
+/// A session's length runs from its first to its last transcript timestamp;
+/// sessions with fewer than two timestamps are skipped. Files over
+/// `HudConfig.max_parse_bytes` are left out.
+pub fn session_duration_stats(project_path: &str) -> SessionDurationStats {
+    session_duration_stats_with_storage(&StorageConfig::default(), project_path)
+}
+
+pub fn session_duration_stats_with_storage(
+    storage: &StorageConfig,
+    project_path: &str,
+) -> SessionDurationStats {
+    let durations = parseable_session_files(storage, project_path)
+        .into_iter()
+        .filter_map(|e| fs::read_to_string(e.path()).ok())
+        .filter_map(|content| session_span_secs(&content));
+    duration_stats(durations.collect())
+}
+
+/// A transcript's activity timestamps, parsed, in file order.
+fn activity_times(content: &str) -> Vec<DateTime<Utc>> {
+    let mut times = Vec::new();
+    for_each_activity_timestamp(content, |ts| {
+        if let Ok(t) = DateTime::parse_from_rfc3339(ts) {
+            times.push(t.with_timezone(&Utc));
+        }
+    });
+    times
+}
+
+/// Seconds between the earliest and latest activity timestamp in a transcript.
+pub(crate) fn session_span_secs(content: &str) -> Option<f64> {
+    let mut times = activity_times(content).into_iter();
+    let first = times.next()?;
+    let (min, max) = times.fold((first, first), |(min, max), t| (min.min(t), max.max(t)));
+    (max > min).then(|| (max - min).num_milliseconds() as f64 / 1000.0)
+}
+
+fn duration_stats(mut durations: Vec<f64>) -> SessionDurationStats {
+    if durations.is_empty() {
+        return SessionDurationStats::default();
+    }
+    durations.sort_by(|a, b| a.total_cmp(b));
+
+    let n = durations.len();
+    let mid = n / 2;
+    SessionDurationStats {
+        session_count: n as u32,
+        average_secs: durations.iter().sum::<f64>() / n as f64,
+        median_secs: if n % 2 == 0 {
+            (durations[mid - 1] + durations[mid]) / 2.0
+        } else {
+            durations[mid]
+        },
+        longest_secs: durations[n - 1],
+    }
+}
+
+/// Measures how quickly Claude responded to each prompt in a session.
+///
+/// Returns None when the transcript is missing or has no timed prompt/response pair.
+pub fn session_latency(session_id: &str, project_path: &str) -> Option<SessionLatency> {
+    session_latency_with_storage(&StorageConfig::default(), session_id, project_path)
+}
+
+pub fn session_latency_with_storage(
+    storage: &StorageConfig,
+    session_id: &str,
+    project_path: &str,
+) -> Option<SessionLatency> {
+    let transcript = session_transcript_path(storage, project_path, session_id)?;
+    let content = fs::read_to_string(transcript).ok()?;
+    latency_from_content(&content)
+}
+
+/// Computes latency from transcript content.
+///
+/// A prompt is a user message with text (tool results don't count). Its response
+/// time runs to the first assistant message after it; its turn runs to the last
+/// assistant message before the next prompt. Lines without timestamps are skipped.
+pub fn latency_from_content(content: &str) -> Option<SessionLatency> {
+    struct Turn {
+        prompt_at: DateTime<Utc>,
+        first_reply_at: Option<DateTime<Utc>>,
+        last_reply_at: Option<DateTime<Utc>>,
+    }
+
+    let mut turns: Vec<Turn> = Vec::new();
+    for line in content.lines() {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        let Some(at) = value
+            .get("timestamp")
+            .and_then(|t| t.as_str())
+            .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| t.with_timezone(&Utc))
+        else {
+            continue;
+        };
+
+        match value.get("type").and_then(|t| t.as_str()) {
+            Some("user") if is_prompt(&value) => turns.push(Turn {
+                prompt_at: at,
+                first_reply_at: None,
+                last_reply_at: None,
+            }),
+            Some("assistant") => {
+                if let Some(turn) = turns.last_mut() {
+                    turn.first_reply_at.get_or_insert(at);
+                    turn.last_reply_at = Some(at);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let secs = |from: DateTime<Utc>, to: DateTime<Utc>| {
+        (to - from).num_milliseconds().max(0) as f64 / 1000.0
+    };
+    let mut responses: Vec<f64> = turns
+        .iter()
+        .filter_map(|t| Some(secs(t.prompt_at, t.first_reply_at?)))
+        .collect();
+    if responses.is_empty() {
+        return None;
+    }
+    responses.sort_by(|a, b| a.total_cmp(b));
+
+    let mid = responses.len() / 2;
+    let median = if responses.len() % 2 == 0 {
+        (responses[mid - 1] + responses[mid]) / 2.0
+    } else {
+        responses[mid]
+    };
+    let total_assistant_secs = turns
+        .iter()
+        .filter_map(|t| Some(secs(t.prompt_at, t.last_reply_at?)))
+        .sum();
+
+    Some(SessionLatency {
+        prompt_count: responses.len() as u32,
+        min_response_secs: responses[0],
+        median_response_secs: median,
+        max_response_secs: responses[responses.len() - 1],
+        total_assistant_secs,
+    })
+}
+
+/// Whether a user transcript line is a typed prompt rather than a tool result.
+fn is_prompt(value: &serde_json::Value) -> bool {
+    match value.get("message").and_then(|m| m.get("content")) {
+        Some(serde_json::Value::String(_)) => true,
+        Some(serde_json::Value::Array(blocks)) => blocks
+            .iter()
+            .any(|b| b.get("type").and_then(|t| t.as_str()) == Some("text")),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects::encode_project_path;
+    use tempfile::TempDir;
+
+    fn usage_line(timestamp: &str, input: u64, cache_read: u64) -> String {
+        format!(
+            r#"{{"type":"assistant","timestamp":"{}","message":{{"model":"claude-sonnet-4","usage":{{"input_tokens":{},"cache_read_input_tokens":{},"output_tokens":50}}}}}}"#,
+            timestamp, input, cache_read
+        )
+    }
+
+    #[test]
+    fn parse_stats_ignores_usage_quoted_in_message_text() {
+        let content = [
+            r#"{"type":"assistant","timestamp":"2026-01-01T10:00:00Z","message":{"model":"claude-opus-4","usage":{"input_tokens":100,"output_tokens":20,"cache_read_input_tokens":5}}}"#,
+            r#"{"type":"assistant","timestamp":"2026-01-01T10:01:00Z","message":{"model":"claude-sonnet-4","content":[{"type":"text","text":"Last turn used {\"input_tokens\":100,\"output_tokens\":20} with \"model\":\"claude-opus-4\""}],"usage":{"input_tokens":7,"output_tokens":3}}}"#,
+            r#"{"type":"summary","summary":"Fix parser"}"#,
+        ]
+        .join("\n");
+
+        let mut stats = ProjectStats::default();
+        parse_stats_from_content(&content, &mut stats);
+        assert_eq!(stats.total_input_tokens, 107);
+        assert_eq!(stats.total_output_tokens, 23);
+        assert_eq!(stats.total_cache_read_tokens, 5);
+        assert_eq!((stats.opus_messages, stats.sonnet_messages), (1, 1));
+        assert_eq!(stats.latest_summary.as_deref(), Some("Fix parser"));
+        assert_eq!(stats.daily_activity.get("2026-01-01"), Some(&2));
+    }
+
+    #[test]
+    fn parse_stats_falls_back_to_regex_for_truncated_lines() {
+        let content = [
+            r#"{"message":{"model":"claude-haiku-4","usage":{"input_tokens":10,"output_tokens":1}}}"#,
+            r#"{"timestamp":"2026-01-02T09:00:00Z","message":{"model":"claude-haiku-4","usage":{"input_tokens":4,"output_tok"#,
+        ]
+        .join("\n");
+
+        let mut stats = ProjectStats::default();
+        parse_stats_from_content(&content, &mut stats);
+        assert_eq!(stats.total_input_tokens, 14);
+        assert_eq!(stats.total_output_tokens, 1);
+        assert_eq!(stats.haiku_messages, 2);
+        assert_eq!(stats.last_activity.as_deref(), Some("2026-01-02"));
+    }
+
+    #[test]
+    fn cache_savings_uses_each_family_rate() {
+        let reads = |cache_read| TokenCounts {
+            cache_read,
+            ..Default::default()
+        };
+        let stats = ProjectStats {
+            total_cache_read_tokens: 2_000_000,
+            opus_usage: reads(1_000_000),
+            sonnet_usage: reads(1_000_000),
+            ..Default::default()
+        };
+        // Opus saves $13.50/Mtok, Sonnet $2.70/Mtok
+        assert!((cache_savings_usd(&stats) - 16.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cache_savings_is_zero_without_cache_reads() {
+        let stats = ProjectStats {
+            total_input_tokens: 5_000_000,
+            sonnet_messages: 10,
+            ..Default::default()
+        };
+        assert_eq!(cache_savings_usd(&stats), 0.0);
+    }
+
+    #[test]
+    fn efficiency_trend_reports_improvement_across_days() {
+        let line = |day: &str, input: u64, output: u64| {
+            format!(
+                r#"{{"timestamp":"{}T12:00:00Z","message":{{"model":"claude-sonnet-4","usage":{{"input_tokens":{},"output_tokens":{}}}}}}}"#,
+                day, input, output
+            )
+        };
+        let content = [
+            line("2026-03-01", 1000, 200),
+            line("2026-03-03", 1000, 500),
+            line("2026-03-03", 1000, 500),
+        ]
+        .join("\n");
+        let today = NaiveDate::from_ymd_opt(2026, 3, 3).unwrap();
+
+        let trend = efficiency_from_contents([content.as_str()], today, 3);
+
+        assert_eq!(trend.direction, TrendDirection::Up);
+        assert_eq!(trend.points.len(), 3);
+        assert_eq!(trend.points[0].day, "2026-03-01");
+        assert_eq!(trend.points[0].output_per_input, Some(0.2));
+        assert_eq!(trend.points[1].input_tokens, 0);
+        assert_eq!(trend.points[1].output_per_input, None);
+        assert_eq!(trend.points[2].input_tokens, 2000);
+        assert_eq!(trend.points[2].output_per_input, Some(0.5));
+    }
+
+    #[test]
+    fn efficiency_trend_is_flat_without_enough_activity() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 3).unwrap();
+        let trend = efficiency_from_contents(std::iter::empty(), today, 7);
+        assert_eq!(trend.direction, TrendDirection::Flat);
+        assert_eq!(trend.points.len(), 7);
+        assert!(trend.points.iter().all(|p| p.output_per_input.is_none()));
+    }
+
+    #[test]
+    fn estimate_cost_prices_each_family_at_its_own_rates() {
+        let million = TokenCounts {
+            input: 1_000_000,
+            output: 1_000_000,
+            cache_read: 1_000_000,
+            cache_creation: 1_000_000,
+        };
+        let stats = ProjectStats {
+            opus_usage: million,
+            sonnet_usage: million,
+            ..Default::default()
+        };
+        // Opus 15 + 75 + 1.5 + 18.75 = 110.25, Sonnet 3 + 15 + 0.3 + 3.75 = 22.05
+        assert_eq!(estimate_cost(&stats), 132.3);
+    }
+
+    #[test]
+    fn estimate_cost_is_not_diluted_by_many_small_messages() {
+        let line = |model: &str, input: u64| {
+            format!(
+                r#"{{"message":{{"model":"claude-{}","usage":{{"input_tokens":{},"output_tokens":0}}}}}}"#,
+                model, input
+            )
+        };
+        let mut content = vec![line("opus-4", 100_000)];
+        content.extend((0..9).map(|_| line("haiku-4", 10)));
+        let mut stats = ProjectStats::default();
+        parse_stats_from_content(&content.join("\n"), &mut stats);
+
+        assert_eq!(stats.opus_usage.input, 100_000);
+        assert_eq!(stats.haiku_usage.input, 90);
+        // $1.50 of Opus input plus a fraction of a cent of Haiku
+        assert_eq!(estimate_cost(&stats), 1.5);
+    }
+
+    #[test]
+    fn estimate_cost_prices_unknown_models_as_sonnet_and_rounds_to_cents() {
+        let input = |input| ProjectStats {
+            other_usage: TokenCounts {
+                input,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        // $0.003702 rounds to $0.00; 10x that rounds to $0.04
+        assert_eq!(estimate_cost(&input(1_234)), 0.0);
+        assert_eq!(estimate_cost(&input(12_340)), 0.04);
+        assert_eq!(estimate_cost(&ProjectStats::default()), 0.0);
+    }
+
+    #[test]
+    fn compute_project_stats_fills_cache_savings() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("-p");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("s1.jsonl"),
+            r#"{"message":{"model":"claude-sonnet-4","usage":{"input_tokens":10,"cache_read_input_tokens":1000000,"output_tokens":5}}}"#,
+        )
+        .unwrap();
+
+        let mut cache = StatsCache::default();
+        let stats = compute_project_stats(temp.path(), "-p", &mut cache, "/p", None);
+        assert!((stats.cache_savings_usd - 2.7).abs() < 1e-9);
+        assert_eq!(stats.estimated_cost_usd, 0.3);
+
+        // Cached path recomputes the derived value too
+        let cached = compute_project_stats(temp.path(), "-p", &mut cache, "/p", None);
+        assert_eq!(cached.cache_savings_usd, stats.cache_savings_usd);
+        assert_eq!(cached.estimated_cost_usd, stats.estimated_cost_usd);
+    }
+
+    #[test]
+    fn compute_project_stats_skips_files_over_parse_limit() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("-p");
+        fs::create_dir_all(&dir).unwrap();
+        let line = r#"{"message":{"model":"claude-sonnet-4","usage":{"input_tokens":10,"output_tokens":5}}}"#;
+        fs::write(dir.join("small.jsonl"), line).unwrap();
+        fs::write(dir.join("huge.jsonl"), [line; 20].join("\n")).unwrap();
+
+        let mut cache = StatsCache::default();
+        let stats = compute_project_stats(temp.path(), "-p", &mut cache, "/p", Some(200));
+        assert!(stats.partial);
+        assert_eq!(stats.skipped_files, vec!["huge.jsonl"]);
+        assert_eq!(stats.session_count, 2);
+        assert_eq!(stats.total_input_tokens, 10);
+
+        // Lifting the limit invalidates the cached partial result
+        let full = compute_project_stats(temp.path(), "-p", &mut cache, "/p", None);
+        assert!(!full.partial);
+        assert_eq!(full.total_input_tokens, 210);
+    }
+
+    #[test]
+    fn compute_project_stats_reparses_only_changed_files() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("-p");
+        fs::create_dir_all(&dir).unwrap();
+        let line = |input: u64| {
+            format!(
+                r#"{{"message":{{"model":"claude-sonnet-4","usage":{{"input_tokens":{},"output_tokens":1}}}}}}"#,
+                input
+            )
+        };
+        fs::write(dir.join("a.jsonl"), line(10)).unwrap();
+        fs::write(dir.join("b.jsonl"), line(20)).unwrap();
+
+        let mut cache = StatsCache::default();
+        let stats = compute_project_stats(temp.path(), "-p", &mut cache, "/p", None);
+        assert_eq!(stats.total_input_tokens, 30);
+
+        // A tampered subtotal for an unchanged file is trusted, proving it wasn't re-read
+        let files = &mut cache.projects.get_mut("/p").unwrap().files;
+        files
+            .get_mut("a.jsonl")
+            .unwrap()
+            .subtotal
+            .as_mut()
+            .unwrap()
+            .sonnet_usage
+            .input = 1000;
+        fs::write(dir.join("b.jsonl"), [line(20), line(5)].join("\n")).unwrap();
+        let stats = compute_project_stats(temp.path(), "-p", &mut cache, "/p", None);
+        assert_eq!(stats.total_input_tokens, 1025);
+
+        fs::remove_file(dir.join("a.jsonl")).unwrap();
+        let stats = compute_project_stats(temp.path(), "-p", &mut cache, "/p", None);
+        assert_eq!(stats.total_input_tokens, 25);
+        assert_eq!(stats.session_count, 1);
+        assert_eq!(cache.projects["/p"].files.len(), 1);
+    }
+
+    #[test]
+    fn stats_changes_are_reported_once_writes_settle() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/p"));
+        fs::create_dir_all(&dir).unwrap();
+        let transcript = dir.join("s1.jsonl");
+        let line = r#"{"message":{"model":"claude-sonnet-4","usage":{"input_tokens":10,"output_tokens":5}}}"#;
+        fs::write(&transcript, line).unwrap();
+        let projects = vec!["/p".to_string()];
+        let mut seen = HashMap::new();
+
+        // First poll only records a baseline
+        assert!(
+            poll_stats_changes_with_storage(&storage, &projects, &mut seen)
+                .unwrap()
+                .is_empty()
+        );
+
+        fs::write(&transcript, [line, line].join("\n")).unwrap();
+        assert!(
+            poll_stats_changes_with_storage(&storage, &projects, &mut seen)
+                .unwrap()
+                .is_empty(),
+            "a just-written file is still settling"
+        );
+
+        fs::File::options()
+            .write(true)
+            .open(&transcript)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(10))
+            .unwrap();
+        let events = poll_stats_changes_with_storage(&storage, &projects, &mut seen).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].project_path, "/p");
+        assert_eq!(events[0].stats.total_input_tokens, 20);
+        assert!(
+            poll_stats_changes_with_storage(&storage, &projects, &mut seen)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn daily_activity_is_summed_across_files_and_cached() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("-p");
+        fs::create_dir_all(&dir).unwrap();
+        let line = |ts: &str| format!(r#"{{"type":"user","timestamp":"{}"}}"#, ts);
+        fs::write(
+            dir.join("a.jsonl"),
+            [line("2026-01-01T09:00:00Z"), line("2026-01-02T09:00:00Z")].join("\n"),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("b.jsonl"),
+            [line("2026-01-02T10:00:00Z"), line("2026-01-02T11:00:00Z")].join("\n"),
+        )
+        .unwrap();
+
+        let mut cache = StatsCache::default();
+        let stats = compute_project_stats(temp.path(), "-p", &mut cache, "/p", None);
+        let expected: HashMap<String, u32> =
+            HashMap::from([("2026-01-01".to_string(), 1), ("2026-01-02".to_string(), 3)]);
+        assert_eq!(stats.daily_activity, expected);
+
+        let json = serde_json::to_string(&cache).unwrap();
+        let mut restored: StatsCache = serde_json::from_str(&json).unwrap();
+        let cached = compute_project_stats(temp.path(), "-p", &mut restored, "/p", None);
+        assert_eq!(cached.daily_activity, expected);
+    }
+
+    #[test]
+    fn subtotals_cached_without_daily_activity_are_reparsed() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("-p");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("a.jsonl"),
+            r#"{"type":"user","timestamp":"2026-01-01T09:00:00Z"}"#,
+        )
+        .unwrap();
+
+        let mut cache = StatsCache::default();
+        compute_project_stats(temp.path(), "-p", &mut cache, "/p", None);
+        let files = &mut cache.projects.get_mut("/p").unwrap().files;
+        files
+            .get_mut("a.jsonl")
+            .unwrap()
+            .subtotal
+            .as_mut()
+            .unwrap()
+            .daily_activity
+            .clear();
+
+        let stats = compute_project_stats(temp.path(), "-p", &mut cache, "/p", None);
+        assert_eq!(stats.daily_activity.get("2026-01-01"), Some(&1));
+    }
+
+    #[test]
+    fn stats_breakdown_sums_to_project_total() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/p"));
+        fs::create_dir_all(&dir).unwrap();
+        let line = |input: u64| {
+            format!(
+                r#"{{"message":{{"model":"claude-opus-4","usage":{{"input_tokens":{},"output_tokens":10}}}}}}"#,
+                input
+            )
+        };
+        fs::write(dir.join("small.jsonl"), line(90)).unwrap();
+        fs::write(
+            dir.join("big.jsonl"),
+            [line(120), line(130), line(120)].join("\n"),
+        )
+        .unwrap();
+
+        let breakdown = stats_breakdown_with_storage(&storage, "/p");
+        let names: Vec<_> = breakdown.iter().map(|c| c.file_name.as_str()).collect();
+        assert_eq!(names, vec!["big.jsonl", "small.jsonl"]);
+        assert_eq!(breakdown[0].message_count, 3);
+        assert!((breakdown[0].token_share - 0.8).abs() < 1e-9);
+        // Built on the stats cache, which is refreshed and saved along the way
+        let saved = crate::config::load_stats_cache_with_storage(&storage);
+        assert_eq!(saved.projects["/p"].files.len(), 2);
+
+        let mut cache = StatsCache::default();
+        let total = compute_project_stats(
+            &storage.claude_projects_dir(),
+            &encode_project_path("/p"),
+            &mut cache,
+            "/p",
+            None,
+        );
+        assert_eq!(
+            breakdown.iter().map(|c| c.input_tokens).sum::<u64>(),
+            total.total_input_tokens
+        );
+        assert_eq!(
+            breakdown.iter().map(|c| c.output_tokens).sum::<u64>(),
+            total.total_output_tokens
+        );
+    }
+
+    #[test]
+    fn model_usage_buckets_by_day_and_week() {
+        let usage = |ts: &str, model: &str, input: u64, output: u64| {
+            format!(
+                r#"{{"type":"assistant","timestamp":"{}","message":{{"model":"{}","usage":{{"input_tokens":{},"output_tokens":{}}}}}}}"#,
+                ts, model, input, output
+            )
+        };
+        // Monday and Tuesday of the same week, with a gap day before Thursday
+        let content = [
+            usage("2026-01-05T09:00:00Z", "claude-sonnet-4", 100, 10),
+            usage("2026-01-05T17:00:00Z", "claude-sonnet-4", 50, 5),
+            usage("2026-01-06T10:00:00Z", "claude-opus-4", 200, 20),
+        ]
+        .join("\n");
+
+        let daily = model_usage_from_contents([content.as_str()], UsageBucket::Day);
+        assert_eq!(
+            daily,
+            vec![
+                ModelUsagePoint {
+                    bucket_start: "2026-01-05".to_string(),
+                    sonnet_tokens: 165,
+                    ..Default::default()
+                },
+                ModelUsagePoint {
+                    bucket_start: "2026-01-06".to_string(),
+                    opus_tokens: 220,
+                    ..Default::default()
+                },
+            ]
+        );
+
+        let later = usage("2026-01-08T10:00:00Z", "claude-haiku-4", 1, 1);
+        let daily = model_usage_from_contents([content.as_str(), &later], UsageBucket::Day);
+        assert_eq!(daily.len(), 4);
+        assert_eq!(
+            daily[2],
+            ModelUsagePoint {
+                bucket_start: "2026-01-07".to_string(),
+                ..Default::default()
+            }
+        );
+
+        let weekly = model_usage_from_contents([content.as_str()], UsageBucket::Week);
+        assert_eq!(
+            weekly,
+            vec![ModelUsagePoint {
+                bucket_start: "2026-01-05".to_string(),
+                opus_tokens: 220,
+                sonnet_tokens: 165,
+                ..Default::default()
+            }]
+        );
+    }
+
+    #[test]
+    fn sparkline_counts_only_active_hours() {
+        let at = |ts: &str| {
+            DateTime::parse_from_rfc3339(ts)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        let now = at("2026-01-02T00:00:00Z");
+        let edits: Vec<_> = [
+            "2026-01-01T03:05:00Z",
+            "2026-01-01T03:50:00Z",
+            "2026-01-01T17:10:00Z",
+            "2026-01-01T17:20:00Z",
+            "2026-01-01T17:30:00Z",
+            "2025-12-31T17:30:00Z", // Outside the window
+        ]
+        .iter()
+        .map(|ts| at(ts))
+        .collect();
+
+        let counts = sparkline_from_timestamps(&edits, now, 24);
+        assert_eq!(counts.len(), 24);
+        assert_eq!(counts[3], 2);
+        assert_eq!(counts[17], 3);
+        assert_eq!(counts.iter().sum::<u32>(), 5);
+        assert_eq!(sparkline_from_timestamps(&[], now, 0), vec![0]);
+    }
+
+    #[test]
+    fn usage_for_period_includes_only_days_in_range() {
+        let usage = |ts: &str, model: &str, input: u64, output: u64| {
+            format!(
+                r#"{{"type":"assistant","timestamp":"{}","message":{{"model":"{}","usage":{{"input_tokens":{},"output_tokens":{}}}}}}}"#,
+                ts, model, input, output
+            )
+        };
+        let contents = [
+            usage("2026-01-31T23:00:00Z", "claude-opus-4", 9_000, 9_000), // Before
+            usage("2026-02-01T08:00:00Z", "claude-opus-4", 1_000_000, 0),
+            usage("2026-02-14T12:00:00Z", "claude-sonnet-4", 0, 1_000_000),
+            usage("2026-02-28T23:59:00Z", "claude-sonnet-4", 1_000_000, 0),
+            usage("2026-03-01T00:00:00Z", "claude-haiku-4", 7_000, 7_000), // After
+        ]
+        .join("\n");
+
+        let period =
+            usage_for_period_from_contents([contents.as_str()], "2026-02-01", "2026-02-28")
+                .unwrap();
+        assert_eq!(period.input_tokens, 2_000_000);
+        assert_eq!(period.output_tokens, 1_000_000);
+        assert!((period.cost_usd - (15.0 + 15.0 + 3.0)).abs() < 1e-9);
+        let families: Vec<_> = period.models.iter().map(|m| m.family).collect();
+        assert_eq!(families, vec![ModelFamily::Sonnet, ModelFamily::Opus]);
+
+        assert!(
+            usage_for_period_from_contents([contents.as_str()], "2026-03-01", "2026-02-01")
+                .is_err()
+        );
+        assert!(
+            usage_for_period_from_contents([contents.as_str()], "February", "2026-02-28").is_err()
+        );
+    }
+
+    #[test]
+    fn velocity_over_two_active_days_of_seven() {
+        let now = DateTime::parse_from_rfc3339("2026-01-08T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let at = |ts: &str| {
+            DateTime::parse_from_rfc3339(ts)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        let sessions = vec![
+            vec![at("2026-01-03T09:00:00Z"), at("2026-01-03T10:00:00Z")],
+            vec![at("2026-01-03T15:00:00Z")],
+            vec![at("2026-01-06T09:00:00Z")],
+            vec![at("2025-12-20T09:00:00Z")], // Outside the window
+        ];
+        let edits: Vec<_> = [
+            "2026-01-03T09:30:00Z",
+            "2026-01-03T09:40:00Z",
+            "2026-01-06T09:10:00Z",
+            "2026-01-06T09:20:00Z",
+            "2026-01-06T09:30:00Z",
+            "2026-01-06T09:40:00Z",
+        ]
+        .iter()
+        .map(|ts| at(ts))
+        .collect();
+
+        let v = velocity_from_timestamps(&sessions, &edits, now, 7);
+        assert_eq!(v.active_days, 2);
+        assert_eq!(v.session_count, 3);
+        assert_eq!(v.edit_count, 6);
+        assert!((v.sessions_per_active_day - 1.5).abs() < 1e-9);
+        assert!((v.sessions_per_calendar_day - 3.0 / 7.0).abs() < 1e-9);
+        assert!((v.edits_per_active_day - 3.0).abs() < 1e-9);
+        assert!((v.edits_per_calendar_day - 6.0 / 7.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn streak_counts_three_consecutive_days() {
+        let day = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let dates = ["2026-03-01", "2026-03-02", "2026-03-02", "2026-03-03"].map(day);
+
+        let streak = streak_from_dates(dates, day("2026-03-03"));
+        assert_eq!(streak.current_days, 3);
+        assert_eq!(streak.longest_days, 3);
+        assert_eq!(streak.last_active_date.as_deref(), Some("2026-03-03"));
+
+        let streak = streak_from_dates(dates, day("2026-03-04"));
+        assert_eq!(streak.current_days, 3, "yesterday keeps the streak alive");
+
+        let streak = streak_from_dates(dates, day("2026-03-05"));
+        assert_eq!(streak.current_days, 0);
+        assert_eq!(streak.longest_days, 3);
+    }
+
+    #[test]
+    fn activity_streak_is_built_from_cached_hours() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/tmp/project"));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("s1.jsonl"),
+            r#"{"type":"user","timestamp":"2026-03-01T12:00:00Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            activity_streak_with_storage(&storage, "/tmp/project").longest_days,
+            1
+        );
+
+        // A tampered subtotal for an unchanged file is trusted, proving it wasn't re-read
+        let mut cache = crate::config::load_stats_cache_with_storage(&storage);
+        let files = &mut cache.projects.get_mut("/tmp/project").unwrap().files;
+        let subtotal = files
+            .get_mut("s1.jsonl")
+            .unwrap()
+            .subtotal
+            .as_mut()
+            .unwrap();
+        assert_eq!(subtotal.hourly_activity.get("2026-03-01T12"), Some(&1));
+        for hour in ["2026-03-02T12", "2026-03-03T12"] {
+            subtotal.hourly_activity.insert(hour.to_string(), 1);
+        }
+        crate::config::save_stats_cache_with_storage(&storage, &cache).unwrap();
+
+        assert_eq!(
+            activity_streak_with_storage(&storage, "/tmp/project").longest_days,
+            3
+        );
+    }
+
+    #[test]
+    fn activity_streak_reads_only_top_level_timestamps() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/tmp/project"));
+        fs::create_dir_all(&dir).unwrap();
+        let content = [
+            r#"{"type":"user","timestamp":"2026-03-01T12:00:00Z"}"#,
+            // A nested timestamp (e.g. from a tool result) isn't activity
+            r#"{"type":"user","timestamp":"2026-03-02T12:00:00Z","toolUseResult":{"timestamp":"2026-03-09T12:00:00Z"}}"#,
+        ]
+        .join("\n");
+        fs::write(dir.join("s1.jsonl"), content).unwrap();
+
+        let local_day = |ts: &str| {
+            DateTime::parse_from_rfc3339(ts)
+                .unwrap()
+                .with_timezone(&Local)
+                .date_naive()
+                .format("%Y-%m-%d")
+                .to_string()
+        };
+        let streak = activity_streak_with_storage(&storage, "/tmp/project");
+        assert_eq!(streak.longest_days, 2);
+        assert_eq!(
+            streak.last_active_date,
+            Some(local_day("2026-03-02T12:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn latency_from_two_timed_pairs() {
+        let content = [
+            r#"{"type":"user","timestamp":"2026-01-01T10:00:00Z","message":{"role":"user","content":"first"}}"#,
+            r#"{"type":"assistant","timestamp":"2026-01-01T10:00:02Z","message":{"role":"assistant","content":[]}}"#,
+            r#"{"type":"user","timestamp":"2026-01-01T10:00:03Z","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"t1","content":"ok"}]}}"#,
+            r#"{"type":"assistant","timestamp":"2026-01-01T10:00:10Z","message":{"role":"assistant","content":[]}}"#,
+            r#"{"type":"user","timestamp":"2026-01-01T10:05:00Z","message":{"role":"user","content":[{"type":"text","text":"second"}]}}"#,
+            r#"{"type":"assistant","message":{"role":"assistant","content":[]}}"#,
+            r#"{"type":"assistant","timestamp":"2026-01-01T10:05:04Z","message":{"role":"assistant","content":[]}}"#,
+        ]
+        .join("\n");
+
+        let latency = latency_from_content(&content).unwrap();
+        assert_eq!(latency.prompt_count, 2);
+        assert_eq!(latency.min_response_secs, 2.0);
+        assert_eq!(latency.max_response_secs, 4.0);
+        assert_eq!(latency.median_response_secs, 3.0);
+        assert_eq!(latency.total_assistant_secs, 14.0);
+    }
+
+    #[test]
+    fn authorship_counts_prompt_and_reply_text_only() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/tmp/project"));
+        fs::create_dir_all(&dir).unwrap();
+        let reply = "x".repeat(40);
+        let content = [
+            r#"{"type":"user","message":{"content":"fix bug"}}"#.to_string(),
+            r#"{"type":"user","isMeta":true,"message":{"content":"<local-command-stdout>ok</local-command-stdout>"}}"#.to_string(),
+            format!(
+                r#"{{"type":"assistant","message":{{"content":[{{"type":"text","text":"{}"}},{{"type":"tool_use","name":"Read","input":{{}}}}]}}}}"#,
+                reply
+            ),
+            r#"{"type":"user","message":{"content":[{"type":"tool_result","content":"file contents"}]}}"#.to_string(),
+        ]
+        .join("\n");
+        fs::write(dir.join("s1.jsonl"), content).unwrap();
+
+        let ratio = authorship_ratio_with_storage(&storage, "/tmp/project");
+        assert_eq!(ratio.human_chars, 7);
+        assert_eq!(ratio.assistant_chars, 40);
+        assert_eq!(ratio.ratio, 7.0 / 40.0);
+    }
+
+    #[test]
+    fn cache_writes_are_priced_in_every_cost() {
+        let content = r#"{"type":"assistant","timestamp":"2026-02-01T10:00:00Z","message":{"model":"claude-sonnet-4","usage":{"input_tokens":1000,"output_tokens":1000,"cache_read_input_tokens":1000000,"cache_creation_input_tokens":1000000}}}"#;
+        let expected = 0.003 + 0.015 + 0.3 + 3.75;
+
+        let session = session_cost_from_content(content, "s1".to_string(), "/p");
+        assert_eq!(session.cache_creation_tokens, 1_000_000);
+        assert!((session.cost_usd - expected).abs() < 1e-9);
+
+        let period = usage_for_period_from_contents([content], "2026-02-01", "2026-02-01").unwrap();
+        assert_eq!(period.cache_creation_tokens, 1_000_000);
+        assert!((period.cost_usd - expected).abs() < 1e-9);
+
+        let mut stats = ProjectStats::default();
+        parse_stats_from_content(content, &mut stats);
+        assert_eq!(estimate_cost(&stats), (expected * 100.0).round() / 100.0);
+    }
+
+    #[test]
+    fn top_sessions_ranked_by_cost() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/tmp/project"));
+        fs::create_dir_all(&dir).unwrap();
+        let session = |output: u64| {
+            format!(
+                r#"{{"type":"summary","summary":"Work {}"}}
+{{"type":"assistant","cwd":"/tmp/project","timestamp":"2026-01-01T10:00:00Z","message":{{"model":"claude-sonnet-4","usage":{{"input_tokens":1000,"output_tokens":{}}}}}}}"#,
+                output, output
+            )
+        };
+        fs::write(dir.join("cheap.jsonl"), session(1_000)).unwrap();
+        fs::write(dir.join("pricey.jsonl"), session(100_000)).unwrap();
+        fs::write(dir.join("middle.jsonl"), session(10_000)).unwrap();
+
+        let top = top_sessions_by_cost_with_storage(&storage, 2);
+        let ids: Vec<&str> = top.iter().map(|s| s.session_id.as_str()).collect();
+        assert_eq!(ids, vec!["pricey", "middle"]);
+        assert_eq!(top[0].project_path, "/tmp/project");
+        assert_eq!(top[0].summary.as_deref(), Some("Work 100000"));
+        assert_eq!(top[0].output_tokens, 100_000);
+        assert!((top[0].cost_usd - 1.503).abs() < 1e-9);
+    }
+
+    #[test]
+    fn top_sessions_reuse_cached_subtotals_for_unchanged_files() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/tmp/project"));
+        fs::create_dir_all(&dir).unwrap();
+        let line = |output: u64| {
+            format!(
+                r#"{{"type":"assistant","message":{{"model":"claude-sonnet-4","usage":{{"input_tokens":0,"output_tokens":{}}}}}}}"#,
+                output
+            )
+        };
+        fs::write(dir.join("a.jsonl"), line(1_000)).unwrap();
+        fs::write(dir.join("b.jsonl"), line(2_000)).unwrap();
+        refreshed_project_cache(&storage, "/tmp/project");
+
+        // A tampered subtotal for an unchanged file is trusted, proving it wasn't re-read
+        let mut cache = crate::config::load_stats_cache_with_storage(&storage);
+        let files = &mut cache.projects.get_mut("/tmp/project").unwrap().files;
+        let subtotal = files.get_mut("a.jsonl").unwrap().subtotal.as_mut().unwrap();
+        subtotal.total_output_tokens = 5_000;
+        subtotal.sonnet_usage.output = 5_000;
+        crate::config::save_stats_cache_with_storage(&storage, &cache).unwrap();
+        fs::write(dir.join("b.jsonl"), [line(2_000), line(1_000)].join("\n")).unwrap();
+
+        let top = top_sessions_by_cost_with_storage(&storage, 2);
+        let outputs: Vec<(&str, u64)> = top
+            .iter()
+            .map(|s| (s.session_id.as_str(), s.output_tokens))
+            .collect();
+        assert_eq!(outputs, vec![("a", 5_000), ("b", 3_000)]);
+        assert_eq!(top[0].project_path, "/tmp/project");
+    }
+
+    #[test]
+    fn budget_levels_follow_spend() {
+        let level = |spent: f64, budget: Option<f64>| {
+            budget_status_from_spend("2026-01".to_string(), spent, budget).level
+        };
+        assert_eq!(level(50.0, Some(100.0)), BudgetLevel::Ok);
+        assert_eq!(level(85.0, Some(100.0)), BudgetLevel::Warning);
+        assert_eq!(level(100.0, Some(100.0)), BudgetLevel::Over);
+        assert_eq!(level(500.0, None), BudgetLevel::Ok);
+        assert_eq!(level(500.0, Some(0.0)), BudgetLevel::Ok);
+    }
+
+    #[test]
+    fn budget_status_warns_over_eighty_percent() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let config = crate::types::HudConfig {
+            monthly_budget_usd: Some(1.0),
+            ..Default::default()
+        };
+        crate::config::save_hud_config_with_storage(&storage, &config).unwrap();
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/tmp/project"));
+        fs::create_dir_all(&dir).unwrap();
+        // 60k Sonnet output tokens at $15/Mtok = $0.90
+        fs::write(
+            dir.join("s1.jsonl"),
+            format!(
+                r#"{{"type":"assistant","timestamp":"{}","message":{{"model":"claude-sonnet-4","usage":{{"output_tokens":60000}}}}}}"#,
+                Utc::now().to_rfc3339()
+            ),
+        )
+        .unwrap();
+
+        let status = budget_status_with_storage(&storage).unwrap();
+        assert_eq!(status.level, BudgetLevel::Warning);
+        assert_eq!(status.budget_usd, Some(1.0));
+        assert!((status.percent_used.unwrap() - 90.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn usage_for_period_skips_files_over_parse_limit() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/tmp/project"));
+        fs::create_dir_all(&dir).unwrap();
+        let line = |output: u64| {
+            format!(
+                r#"{{"type":"assistant","timestamp":"{}","message":{{"model":"claude-sonnet-4","usage":{{"output_tokens":{}}}}}}}"#,
+                Utc::now().to_rfc3339(),
+                output
+            )
+        };
+        fs::write(dir.join("small.jsonl"), line(100)).unwrap();
+        fs::write(dir.join("big.jsonl"), line(1_000_000).repeat(10)).unwrap();
+        let config = crate::types::HudConfig {
+            max_parse_bytes: Some(1_000),
+            ..Default::default()
+        };
+        crate::config::save_hud_config_with_storage(&storage, &config).unwrap();
+
+        let today = Utc::now().date_naive().format("%Y-%m-%d").to_string();
+        let usage = usage_for_period_with_storage(&storage, &today, &today).unwrap();
+        assert_eq!(usage.output_tokens, 100);
+    }
+
+    #[test]
+    fn session_durations_average_known_spans() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/tmp/project"));
+        fs::create_dir_all(&dir).unwrap();
+        let line = |ts: &str| format!(r#"{{"type":"user","timestamp":"{}"}}"#, ts);
+        // 10 minutes and 30 minutes; the single-timestamp session is skipped
+        fs::write(
+            dir.join("short.jsonl"),
+            [line("2026-01-01T10:00:00Z"), line("2026-01-01T10:10:00Z")].join("\n"),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("long.jsonl"),
+            [
+                line("2026-01-02T09:00:00Z"),
+                line("2026-01-02T09:05:00Z"),
+                line("2026-01-02T09:30:00Z"),
+            ]
+            .join("\n"),
+        )
+        .unwrap();
+        fs::write(dir.join("blip.jsonl"), line("2026-01-03T09:00:00Z")).unwrap();
+
+        let stats = session_duration_stats_with_storage(&storage, "/tmp/project");
+        assert_eq!(stats.session_count, 2);
+        assert_eq!(stats.average_secs, 1200.0);
+        assert_eq!(stats.median_secs, 1200.0);
+        assert_eq!(stats.longest_secs, 1800.0);
+    }
+
+    #[test]
+    fn session_span_ignores_nested_timestamps() {
+        let content = [
+            r#"{"type":"user","timestamp":"2026-01-01T10:00:00Z","toolUseResult":{"file":{"timestamp":"2025-01-01T00:00:00Z"}}}"#,
+            r#"{"type":"assistant","timestamp":"2026-01-01T10:05:00Z"}"#,
+        ]
+        .join("\n");
+        assert_eq!(session_span_secs(&content), Some(300.0));
+    }
+
+    #[test]
+    fn latency_needs_a_timed_response() {
+        let content =
+            r#"{"type":"user","timestamp":"2026-01-01T10:00:00Z","message":{"content":"hi"}}"#;
+        assert!(latency_from_content(content).is_none());
+    }
+
+    #[test]
+    fn burn_rate_from_two_timed_blocks() {
+        let content = [
+            r#"{"type":"user","timestamp":"2026-01-01T10:00:00Z","message":{"content":"hi"}}"#
+                .to_string(),
+            usage_line("2026-01-01T10:00:00Z", 1_000, 99_000),
+            usage_line("2026-01-01T10:10:00Z", 1_000, 119_000),
+        ]
+        .join("\n");
+
+        let rate = burn_rate_from_content(&content).unwrap();
+        assert_eq!(rate.tokens_per_minute, 2_000.0);
+        assert_eq!(rate.tokens_used, 120_000);
+        assert_eq!(rate.minutes_remaining, Some(40.0));
+    }
+
+    #[test]
+    fn burn_rate_needs_two_blocks() {
+        assert!(burn_rate_from_content(&usage_line("2026-01-01T10:00:00Z", 10, 0)).is_none());
+        assert!(burn_rate_from_content("").is_none());
+    }
+
+    #[test]
+    fn burn_rate_has_no_eta_when_context_shrinks() {
+        let content = [
+            usage_line("2026-01-01T10:00:00Z", 1_000, 150_000),
+            usage_line("2026-01-01T10:05:00Z", 1_000, 20_000),
+        ]
+        .join("\n");
+
+        let rate = burn_rate_from_content(&content).unwrap();
+        assert!(rate.tokens_per_minute < 0.0);
+        assert_eq!(rate.minutes_remaining, None);
+    }
+
+    #[test]
+    fn burn_rate_uses_the_model_context_window() {
+        let content = [
+            usage_line("2026-01-01T10:00:00Z", 0, 250_000),
+            usage_line("2026-01-01T10:10:00Z", 0, 350_000),
+        ]
+        .join("\n");
+
+        let rate = burn_rate_from_content(&content).unwrap();
+        assert_eq!(rate.context_size, 1_000_000);
+        assert_eq!(rate.minutes_remaining, Some(65.0));
+    }
+
+    #[test]
+    fn context_burn_rate_reads_session_transcript() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/tmp/project"));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("s1.jsonl"),
+            [
+                usage_line("2026-01-01T10:00:00Z", 500, 0),
+                usage_line("2026-01-01T10:01:00Z", 1_500, 0),
+            ]
+            .join("\n"),
+        )
+        .unwrap();
+
+        let rate = context_burn_rate_with_storage(&storage, "s1", "/tmp/project").unwrap();
+        assert_eq!(rate.tokens_per_minute, 1_000.0);
+        assert!(context_burn_rate_with_storage(&storage, "missing", "/tmp/project").is_none());
+    }
+
+    #[test]
+    fn context_info_uses_latest_real_usage_block() {
+        let content = [
+            usage_line("2026-01-01T10:00:00Z", 1_000, 20_000),
+            usage_line("2026-01-01T10:01:00Z", 2_000, 148_000),
+            r#"{"type":"assistant","timestamp":"2026-01-01T10:02:00Z","message":{"model":"<synthetic>","usage":{"input_tokens":0,"output_tokens":0}}}"#
+                .to_string(),
+        ]
+        .join("\n");
+
+        let info = context_info_from_content(&content).unwrap();
+        assert_eq!(info.tokens_used, 150_000);
+        assert_eq!(info.context_size, CONTEXT_WINDOW_TOKENS);
+        assert_eq!(info.percent_used, 75);
+        assert_eq!(info.updated_at.as_deref(), Some("2026-01-01T10:01:00Z"));
+        assert!(context_info_from_content("").is_none());
+        assert_eq!(context_size_for_model("claude-sonnet-4-5[1m]"), 1_000_000);
+
+        // Transcripts carry the API model id, so an oversized prompt marks the 1M window
+        let long = usage_line("2026-01-01T10:03:00Z", 10_000, 290_000);
+        let info = context_info_from_content(&long).unwrap();
+        assert_eq!(info.context_size, 1_000_000);
+        assert_eq!(info.percent_used, 30);
+    }
+
+    #[test]
+    fn session_context_finds_transcript_by_session_id() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        fs::create_dir_all(storage.root()).unwrap();
+        let mut store = crate::state::StateStore::new(&storage.sessions_file());
+        store.update("s1", crate::types::SessionState::Working, "/tmp/project");
+        store.save().unwrap();
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/tmp/project"));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("s1.jsonl"),
+            usage_line("2026-01-01T10:00:00Z", 10_000, 90_000),
+        )
+        .unwrap();
+
+        let info = session_context_with_storage(&storage, "s1").unwrap();
+        assert_eq!(info.percent_used, 50);
+        assert!(session_context_with_storage(&storage, "missing").is_none());
+    }
 }
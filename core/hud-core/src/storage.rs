@@ -96,11 +96,31 @@ impl StorageConfig {
         self.root.join("stats-cache.json")
     }
 
+    /// Path to stats-cache.bin (binary token usage cache, see `HudConfig.binary_stats_cache`).
+    pub fn stats_cache_binary_file(&self) -> PathBuf {
+        self.root.join("stats-cache.bin")
+    }
+
+    /// Path to artifacts-cache.json (parsed skill/command/agent files).
+    pub fn artifacts_cache_file(&self) -> PathBuf {
+        self.root.join("artifacts-cache.json")
+    }
+
     /// Path to file-activity.json (file activity tracking).
     pub fn file_activity_file(&self) -> PathBuf {
         self.root.join("file-activity.json")
     }
 
+    /// Path to transitions.jsonl (append-only state transition log).
+    pub fn transitions_file(&self) -> PathBuf {
+        self.root.join("transitions.jsonl")
+    }
+
+    /// Path to status-gen.log (status generation subprocess output, when enabled).
+    pub fn status_gen_log_file(&self) -> PathBuf {
+        self.root.join("status-gen.log")
+    }
+
     /// Path to config.json (app preferences).
     pub fn config_file(&self) -> PathBuf {
         self.root.join("config.json")
@@ -125,6 +145,11 @@ impl StorageConfig {
         self.root.join("agents")
     }
 
+    /// Path to backups/ directory (safety backups taken before a restore).
+    pub fn backups_dir(&self) -> PathBuf {
+        self.root.join("backups")
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // Per-Project Paths
     // ─────────────────────────────────────────────────────────────────────────────
@@ -220,33 +245,7 @@ impl StorageConfig {
             return None;
         }
 
-        if !encoded.starts_with('-') {
-            return None;
-        }
-
-        let without_leading = &encoded[1..];
-        let parts: Vec<&str> = without_leading.split('-').collect();
-
-        // Try progressively longer path prefixes
-        for num_parts in 1..=parts.len() {
-            let prefix = parts[..num_parts].join("/");
-            let candidate = format!("/{}", prefix);
-
-            if PathBuf::from(&candidate).exists() {
-                if num_parts == parts.len() {
-                    return Some(candidate);
-                }
-
-                // Try the rest as a hyphenated suffix
-                let suffix = parts[num_parts..].join("-");
-                let full_candidate = format!("{}/{}", candidate, suffix);
-                if PathBuf::from(&full_candidate).exists() {
-                    return Some(full_candidate);
-                }
-            }
-        }
-
-        None
+        resolve_legacy_encoded_path(encoded, dirs::home_dir().as_deref())
     }
 
     const ENCODED_PREFIX: &'static str = "p2_";
@@ -292,6 +291,63 @@ impl StorageConfig {
     }
 }
 
+/// Resolves a legacy (`/` → `-`) encoded path by probing the filesystem.
+///
+/// The encoding is lossy, so each `-` may be a separator or part of a name.
+/// When the name starts with the encoded `home`, resolution starts there;
+/// otherwise it starts at `/`. From each directory, segment groupings are tried
+/// in turn and abandoned when they lead to a path that doesn't exist, so
+/// hyphenated names like `my-cool-project` are found at any depth.
+pub(crate) fn resolve_legacy_encoded_path(encoded: &str, home: Option<&Path>) -> Option<String> {
+    let rest = encoded.strip_prefix('-')?;
+
+    if let Some(home) = home.filter(|h| h.is_dir()) {
+        let encoded_home = home.to_str()?.trim_end_matches('/').replace('/', "-");
+        if encoded == encoded_home {
+            return home.to_str().map(String::from);
+        }
+        if let Some(under_home) = encoded.strip_prefix(&format!("{}-", encoded_home)) {
+            let parts: Vec<&str> = under_home.split('-').collect();
+            if let Some(found) = resolve_segments(home, &parts) {
+                return found.to_str().map(String::from);
+            }
+        }
+    }
+
+    let parts: Vec<&str> = rest.split('-').collect();
+    resolve_segments(Path::new("/"), &parts)?
+        .to_str()
+        .map(String::from)
+}
+
+/// Depth-first search for an existing path under `base` whose components,
+/// joined with `-`, spell out `parts`. Shorter names are tried first.
+fn resolve_segments(base: &Path, parts: &[&str]) -> Option<PathBuf> {
+    if parts.is_empty() {
+        return base.exists().then(|| base.to_path_buf());
+    }
+
+    for take in 1..=parts.len() {
+        let name = parts[..take].join("-");
+        if name.is_empty() {
+            continue;
+        }
+        let candidate = base.join(&name);
+        let rest = &parts[take..];
+        if rest.is_empty() {
+            if candidate.exists() {
+                return Some(candidate);
+            }
+        } else if candidate.is_dir() {
+            if let Some(found) = resolve_segments(&candidate, rest) {
+                return Some(found);
+            }
+        }
+    }
+
+    None
+}
+
 fn decode_percent(encoded: &str) -> String {
     let bytes = encoded.as_bytes();
     let mut out = Vec::with_capacity(bytes.len());
@@ -418,6 +474,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_stats_cache_binary_file_path() {
+        let config = StorageConfig::with_root(PathBuf::from("/tmp/capacitor"));
+        assert_eq!(
+            config.stats_cache_binary_file(),
+            PathBuf::from("/tmp/capacitor/stats-cache.bin")
+        );
+    }
+
     #[test]
     fn test_file_activity_file_path() {
         let config = StorageConfig::with_root(PathBuf::from("/tmp/capacitor"));
@@ -427,6 +492,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_status_gen_log_file_path() {
+        let config = StorageConfig::with_root(PathBuf::from("/tmp/capacitor"));
+        assert_eq!(
+            config.status_gen_log_file(),
+            PathBuf::from("/tmp/capacitor/status-gen.log")
+        );
+    }
+
+    #[test]
+    fn test_transitions_file_path() {
+        let config = StorageConfig::with_root(PathBuf::from("/tmp/capacitor"));
+        assert_eq!(
+            config.transitions_file(),
+            PathBuf::from("/tmp/capacitor/transitions.jsonl")
+        );
+    }
+
     #[test]
     fn test_config_file_path() {
         let config = StorageConfig::with_root(PathBuf::from("/tmp/capacitor"));
@@ -676,4 +759,52 @@ mod tests {
     fn test_try_resolve_encoded_path_no_leading_dash() {
         assert!(StorageConfig::try_resolve_encoded_path("no-leading-dash").is_none());
     }
+
+    fn legacy_encode(path: &Path) -> String {
+        path.to_string_lossy().replace('/', "-")
+    }
+
+    #[test]
+    fn test_resolve_legacy_path_with_hyphens_at_several_depths() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().join("my-code/web-apps/my-cool-project");
+        std::fs::create_dir_all(&project).unwrap();
+        // Decoys that match a prefix of the encoded name but dead-end
+        std::fs::create_dir_all(temp.path().join("my/code")).unwrap();
+        std::fs::create_dir_all(temp.path().join("my-code/web")).unwrap();
+
+        assert_eq!(
+            resolve_legacy_encoded_path(&legacy_encode(&project), None),
+            Some(project.to_string_lossy().to_string())
+        );
+        assert!(resolve_legacy_encoded_path(
+            &legacy_encode(&temp.path().join("my-code/web-apps/missing")),
+            None
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_resolve_legacy_path_anchors_on_home() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path().join("home/first-last");
+        let project = home.join("Code/my-app");
+        std::fs::create_dir_all(&project).unwrap();
+
+        assert_eq!(
+            resolve_legacy_encoded_path(&legacy_encode(&project), Some(&home)),
+            Some(project.to_string_lossy().to_string())
+        );
+        assert_eq!(
+            resolve_legacy_encoded_path(&legacy_encode(&home), Some(&home)),
+            Some(home.to_string_lossy().to_string())
+        );
+        // Paths outside home still resolve from the root
+        let other = temp.path().join("srv/data-sets");
+        std::fs::create_dir_all(&other).unwrap();
+        assert_eq!(
+            resolve_legacy_encoded_path(&legacy_encode(&other), Some(&home)),
+            Some(other.to_string_lossy().to_string())
+        );
+    }
 }
@@ -0,0 +1,305 @@
+//! Logical sessions assembled from a project's transcript files.
+//!
+//! Resuming a session can make Claude Code start a new `.jsonl` file that
+//! continues the same conversation. The new file links back either through a
+//! leading summary whose `leafUuid` names a message in the earlier file, or by
+//! carrying the earlier file's `sessionId` on its lines. Following those links
+//! lets the task list show one entry per conversation instead of one per file.
+
+use crate::stats::parse_stats_from_content;
+use crate::storage::StorageConfig;
+use crate::transcript::line_message;
+use crate::types::{ProjectStats, Task};
+use chrono::{DateTime, Utc};
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// Maximum characters of a first prompt used as a task name.
+const TASK_NAME_MAX_CHARS: usize = 80;
+
+/// One conversation, possibly spread across several resumed transcript files.
+#[derive(Debug, Serialize, Deserialize, Clone, uniffi::Record)]
+pub struct SessionGroup {
+    /// Named after the first session; path and timestamp are the latest file's
+    pub task: Task,
+    /// Session ids (transcript file stems) in the chain, oldest first
+    pub session_ids: Vec<String>,
+    /// Stats summed across every file in the chain
+    pub stats: ProjectStats,
+}
+
+/// What one transcript file contributes to grouping.
+struct Transcript {
+    id: String,
+    path: PathBuf,
+    modified: SystemTime,
+    content: String,
+    uuids: HashSet<String>,
+    /// `leafUuid`s from the summary lines at the top of the file
+    leaf_refs: Vec<String>,
+    /// A `sessionId` on its lines other than its own
+    foreign_session: Option<String>,
+}
+
+/// Groups a project's transcripts into logical sessions, newest first.
+pub fn group_continued_sessions(project_path: &str) -> Vec<SessionGroup> {
+    group_continued_sessions_with_storage(&StorageConfig::default(), project_path)
+}
+
+pub fn group_continued_sessions_with_storage(
+    storage: &StorageConfig,
+    project_path: &str,
+) -> Vec<SessionGroup> {
+    let project_dir = storage
+        .claude_projects_dir()
+        .join(crate::projects::encode_project_path(project_path));
+
+    let transcripts: Vec<Transcript> = fs::read_dir(&project_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "jsonl"))
+        .filter_map(|e| {
+            let path = e.path();
+            let id = path.file_stem()?.to_str()?.to_string();
+            let modified = e
+                .metadata()
+                .and_then(|m| m.modified())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            let content = fs::read_to_string(&path).ok()?;
+            Some(read_transcript(id, path, modified, content))
+        })
+        .collect();
+
+    group_transcripts(transcripts)
+}
+
+fn read_transcript(id: String, path: PathBuf, modified: SystemTime, content: String) -> Transcript {
+    let mut uuids = HashSet::new();
+    let mut leaf_refs = Vec::new();
+    let mut foreign_session = None;
+    let mut in_header = true;
+
+    for line in content.lines() {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        let field = |key: &str| value.get(key).and_then(|v| v.as_str());
+
+        if field("type") == Some("summary") {
+            if in_header {
+                leaf_refs.extend(field("leafUuid").map(String::from));
+            }
+            continue;
+        }
+        in_header = false;
+
+        uuids.extend(field("uuid").map(String::from));
+        if foreign_session.is_none() {
+            foreign_session = field("sessionId")
+                .filter(|session| *session != id)
+                .map(String::from);
+        }
+    }
+
+    Transcript {
+        id,
+        path,
+        modified,
+        content,
+        uuids,
+        leaf_refs,
+        foreign_session,
+    }
+}
+
+fn group_transcripts(transcripts: Vec<Transcript>) -> Vec<SessionGroup> {
+    let index_by_id: HashMap<&str, usize> = transcripts
+        .iter()
+        .enumerate()
+        .map(|(i, t)| (t.id.as_str(), i))
+        .collect();
+    let index_by_uuid: HashMap<&str, usize> = transcripts
+        .iter()
+        .enumerate()
+        .flat_map(|(i, t)| t.uuids.iter().map(move |u| (u.as_str(), i)))
+        .collect();
+
+    let parents: Vec<Option<usize>> = transcripts
+        .iter()
+        .enumerate()
+        .map(|(i, t)| {
+            t.leaf_refs
+                .iter()
+                .filter_map(|leaf| index_by_uuid.get(leaf.as_str()).copied())
+                .chain(
+                    t.foreign_session
+                        .as_deref()
+                        .and_then(|s| index_by_id.get(s).copied()),
+                )
+                .find(|&parent| parent != i)
+        })
+        .collect();
+
+    let root_of = |mut i: usize| {
+        let mut seen = HashSet::new();
+        while let Some(parent) = parents[i] {
+            if !seen.insert(i) {
+                break;
+            }
+            i = parent;
+        }
+        i
+    };
+    let mut chains: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for i in 0..transcripts.len() {
+        chains.entry(root_of(i)).or_default().push(i);
+    }
+
+    let mut groups: Vec<(SystemTime, SessionGroup)> = chains
+        .into_values()
+        .map(|mut members| {
+            members.sort_by_key(|&i| (transcripts[i].modified, transcripts[i].id.clone()));
+            let first = &transcripts[members[0]];
+            let latest = &transcripts[members[members.len() - 1]];
+
+            let mut stats = ProjectStats::default();
+            for &i in &members {
+                parse_stats_from_content(&transcripts[i].content, &mut stats);
+            }
+            stats.session_count = members.len() as u32;
+
+            let first_message = first_prompt(&first.content);
+            let name = stats
+                .latest_summary
+                .clone()
+                .or_else(|| {
+                    first_message
+                        .as_ref()
+                        .map(|m| m.chars().take(TASK_NAME_MAX_CHARS).collect())
+                })
+                .unwrap_or_else(|| first.id.clone());
+
+            let group = SessionGroup {
+                task: Task {
+                    id: first.id.clone(),
+                    name,
+                    path: latest.path.to_string_lossy().to_string(),
+                    last_modified: DateTime::<Utc>::from(latest.modified).to_rfc3339(),
+                    summary: stats.latest_summary.clone(),
+                    first_message,
+                },
+                session_ids: members.iter().map(|&i| transcripts[i].id.clone()).collect(),
+                stats,
+            };
+            (latest.modified, group)
+        })
+        .collect();
+
+    groups.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.task.id.cmp(&b.1.task.id)));
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+/// Text of the first typed prompt in a transcript, skipping meta lines and
+/// slash-command scaffolding the same way the transcript view does.
+pub(crate) fn first_prompt(content: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let value: serde_json::Value = serde_json::from_str(line).ok()?;
+        let (role, text) = line_message(&value)?;
+        (role == "user").then_some(text)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects::encode_project_path;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn transcript(id: &str, seconds: u64, content: &str) -> Transcript {
+        read_transcript(
+            id.to_string(),
+            PathBuf::from(format!("/p/{}.jsonl", id)),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(seconds),
+            content.to_string(),
+        )
+    }
+
+    #[test]
+    fn resume_chain_is_grouped_into_one_session() {
+        let original = transcript(
+            "a",
+            100,
+            r#"{"type":"user","sessionId":"a","uuid":"u1","message":{"content":"add login page"}}
+{"type":"assistant","sessionId":"a","uuid":"u2","message":{"model":"claude-sonnet-4","usage":{"input_tokens":10,"output_tokens":5}}}"#,
+        );
+        let resumed = transcript(
+            "b",
+            200,
+            r#"{"type":"summary","summary":"Login page","leafUuid":"u2"}
+{"type":"user","sessionId":"b","uuid":"u3","message":{"content":"continue"}}
+{"type":"assistant","sessionId":"b","uuid":"u4","message":{"model":"claude-sonnet-4","usage":{"input_tokens":20,"output_tokens":7}}}"#,
+        );
+        let unrelated = transcript(
+            "c",
+            150,
+            r#"{"type":"user","sessionId":"c","uuid":"u5","message":{"content":"fix tests"}}"#,
+        );
+
+        let groups = group_transcripts(vec![resumed, unrelated, original]);
+
+        assert_eq!(groups.len(), 2);
+        let chain = &groups[0];
+        assert_eq!(chain.session_ids, vec!["a", "b"]);
+        assert_eq!(chain.task.id, "a");
+        assert_eq!(chain.task.name, "Login page");
+        assert_eq!(chain.task.path, "/p/b.jsonl");
+        assert_eq!(chain.task.first_message.as_deref(), Some("add login page"));
+        assert_eq!(chain.stats.total_input_tokens, 30);
+        assert_eq!(chain.stats.total_output_tokens, 12);
+        assert_eq!(chain.stats.session_count, 2);
+        assert_eq!(groups[1].session_ids, vec!["c"]);
+    }
+
+    #[test]
+    fn shared_session_id_links_files() {
+        let original = transcript(
+            "a",
+            100,
+            r#"{"type":"user","sessionId":"a","uuid":"u1","message":{"content":"hi"}}"#,
+        );
+        let continued = transcript(
+            "b",
+            200,
+            r#"{"type":"user","sessionId":"a","uuid":"u2","message":{"content":"more"}}"#,
+        );
+
+        let groups = group_transcripts(vec![original, continued]);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].session_ids, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn reads_project_transcripts_from_storage() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/tmp/project"));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("s1.jsonl"),
+            r#"{"type":"user","sessionId":"s1","uuid":"u1","message":{"content":"hello"}}"#,
+        )
+        .unwrap();
+
+        let groups = group_continued_sessions_with_storage(&storage, "/tmp/project");
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].task.name, "hello");
+    }
+}
@@ -0,0 +1,740 @@
+//! Terminal app discovery for launch and settings UI.
+//!
+//! `KNOWN_TERMINALS` is the candidate set of terminal emulators Capacitor knows how
+//! to drive. Detection checks which of them are actually installed:
+//! - macOS: app bundles in `/Applications` (and friends), then Spotlight (`mdfind`)
+//! - Linux/other: the app's CLI binary on `PATH`
+//!
+//! This is unrelated to hud-hook's process-name table for parent app detection,
+//! which also covers IDEs and multiplexers.
+
+use crate::types::HudConfig;
+use std::path::{Path, PathBuf};
+
+/// A terminal emulator Capacitor can detect and launch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnownTerminal {
+    /// Display name, matching `HudConfig.terminal_app` values
+    pub name: &'static str,
+    /// macOS bundle directory name (e.g. "Ghostty.app")
+    pub bundle: &'static str,
+    /// macOS bundle identifier, used for Spotlight lookup
+    pub bundle_id: &'static str,
+    /// Executable name on Linux, None for macOS-only apps
+    pub binary: Option<&'static str>,
+}
+
+/// Terminal emulators Capacitor knows about, in preference order.
+pub const KNOWN_TERMINALS: &[KnownTerminal] = &[
+    KnownTerminal {
+        name: "Ghostty",
+        bundle: "Ghostty.app",
+        bundle_id: "com.mitchellh.ghostty",
+        binary: Some("ghostty"),
+    },
+    KnownTerminal {
+        name: "iTerm2",
+        bundle: "iTerm.app",
+        bundle_id: "com.googlecode.iterm2",
+        binary: None,
+    },
+    KnownTerminal {
+        name: "Terminal",
+        bundle: "Terminal.app",
+        bundle_id: "com.apple.Terminal",
+        binary: None,
+    },
+    KnownTerminal {
+        name: "Warp",
+        bundle: "Warp.app",
+        bundle_id: "dev.warp.Warp-Stable",
+        binary: Some("warp-terminal"),
+    },
+    KnownTerminal {
+        name: "kitty",
+        bundle: "kitty.app",
+        bundle_id: "net.kovidgoyal.kitty",
+        binary: Some("kitty"),
+    },
+    KnownTerminal {
+        name: "Alacritty",
+        bundle: "Alacritty.app",
+        bundle_id: "org.alacritty",
+        binary: Some("alacritty"),
+    },
+    KnownTerminal {
+        name: "WezTerm",
+        bundle: "WezTerm.app",
+        bundle_id: "com.github.wez.wezterm",
+        binary: Some("wezterm"),
+    },
+];
+
+/// An installed terminal app.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct DetectedTerminal {
+    /// Display name from `KNOWN_TERMINALS`
+    pub name: String,
+    /// Location of the app bundle (macOS) or executable (Linux)
+    pub path: String,
+}
+
+/// Returns the known terminal apps installed on this system, in `KNOWN_TERMINALS` order.
+pub fn detect_terminals() -> Vec<DetectedTerminal> {
+    detect_terminals_with(find_installed_app)
+}
+
+/// Detects terminals using a custom probe that returns the install location
+/// of an app, or None when it isn't installed.
+pub fn detect_terminals_with<F>(probe: F) -> Vec<DetectedTerminal>
+where
+    F: Fn(&KnownTerminal) -> Option<PathBuf>,
+{
+    KNOWN_TERMINALS
+        .iter()
+        .filter_map(|app| {
+            probe(app).map(|path| DetectedTerminal {
+                name: app.name.to_string(),
+                path: path.to_string_lossy().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Looks up a known terminal by display name (case-insensitive).
+pub fn find_known_terminal(name: &str) -> Option<&'static KnownTerminal> {
+    KNOWN_TERMINALS
+        .iter()
+        .find(|app| app.name.eq_ignore_ascii_case(name))
+}
+
+/// Placeholder in `HudConfig.launch_commands` templates for the project path.
+pub const LAUNCH_PATH_PLACEHOLDER: &str = "{path}";
+
+/// Builds the shell command a new terminal runs to start Claude in a project.
+///
+/// Uses the project's `HudConfig.launch_commands` template when set (with
+/// `{path}` replaced by the shell-quoted path), else `cd <path> && <claude>`.
+/// For a resume, `--resume <id>` is appended, so templates should end with
+/// the Claude invocation.
+pub fn launch_command(
+    config: &HudConfig,
+    project_path: &str,
+    claude_path: &str,
+    resume_session_id: Option<&str>,
+) -> String {
+    let quoted_path = shell_quote(project_path);
+    let mut command = match launch_template(config, project_path) {
+        Some(template) => template.replace(LAUNCH_PATH_PLACEHOLDER, &quoted_path),
+        None => format!("cd {} && {}", quoted_path, shell_quote(claude_path)),
+    };
+    if let Some(session_id) = resume_session_id {
+        command.push_str(" --resume ");
+        command.push_str(&shell_quote(session_id));
+    }
+    command
+}
+
+/// The `cmd` counterpart of [`launch_command`], for Windows.
+///
+/// The terminal is already opened in the project directory, so the default is
+/// plain `claude`. In templates `{path}` is replaced with the double-quoted path.
+pub fn windows_launch_command(
+    config: &HudConfig,
+    project_path: &str,
+    resume_session_id: Option<&str>,
+) -> String {
+    let mut command = match launch_template(config, project_path) {
+        Some(template) => {
+            template.replace(LAUNCH_PATH_PLACEHOLDER, &format!("\"{}\"", project_path))
+        }
+        None => "claude".to_string(),
+    };
+    if let Some(session_id) = resume_session_id {
+        command.push_str(" --resume ");
+        command.push_str(session_id);
+    }
+    command
+}
+
+fn launch_template<'a>(config: &'a HudConfig, project_path: &str) -> Option<&'a str> {
+    config
+        .launch_commands
+        .iter()
+        .find(|(path, _)| path.trim_end_matches('/') == project_path.trim_end_matches('/'))
+        .map(|(_, template)| template.trim())
+        .filter(|template| !template.is_empty())
+}
+
+/// Quotes a string for POSIX shells, leaving plain paths readable.
+pub fn shell_quote(value: &str) -> String {
+    let is_plain = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "/._-+:@%,".contains(c));
+    if is_plain {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
+/// Login shell used to run the launch command in apps that take a program to
+/// execute, so Claude sees the user's `PATH`.
+const LAUNCH_SHELL: &str = "/bin/zsh";
+
+/// A process to spawn that opens a terminal, for the app to run as-is.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct TerminalLaunch {
+    pub program: String,
+    pub args: Vec<String>,
+    /// Directory to spawn the program in, when the path can't safely be an argument
+    pub cwd: Option<String>,
+}
+
+impl TerminalLaunch {
+    fn new(program: &str, args: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            program: program.to_string(),
+            args: args.into_iter().collect(),
+            cwd: None,
+        }
+    }
+
+    fn open(args: impl IntoIterator<Item = String>) -> Self {
+        Self::new("open", args)
+    }
+
+    fn osascript(script: String) -> Self {
+        Self::new("osascript", ["-e".to_string(), script])
+    }
+}
+
+/// How to open `app` at `path`, starting Claude there when `run_claude` is set.
+///
+/// `app` is a `HudConfig.terminal_app` value. Unknown apps are opened at the
+/// path without running anything.
+pub fn launch_terminal_for_app(app: &str, path: &str, run_claude: bool) -> TerminalLaunch {
+    let command = run_claude.then(|| format!("cd {} && claude", shell_quote(path)));
+    terminal_launch(app, path, command.as_deref())
+}
+
+/// Like [`launch_terminal_for_app`], with the shell command to run (e.g. from
+/// [`launch_command`]) in place of the default `cd <path> && claude`.
+pub fn terminal_launch(app: &str, path: &str, command: Option<&str>) -> TerminalLaunch {
+    let Some(known) = find_known_terminal(app) else {
+        return TerminalLaunch::open(["-a".to_string(), app.to_string(), path.to_string()]);
+    };
+    let run_in_shell = |command: &str| {
+        vec![
+            LAUNCH_SHELL.to_string(),
+            "-l".to_string(),
+            "-c".to_string(),
+            command.to_string(),
+        ]
+    };
+
+    match (known.name, command) {
+        // Apps that take a working directory and a program to run
+        ("Ghostty", Some(command)) => {
+            let working_directory = format!("--working-directory={}", path);
+            TerminalLaunch::open(
+                ["-na", known.bundle, "--args", &working_directory, "-e"]
+                    .map(String::from)
+                    .into_iter()
+                    .chain(run_in_shell(command)),
+            )
+        }
+        ("Alacritty", Some(command)) => TerminalLaunch::open(
+            [
+                "-na",
+                known.bundle,
+                "--args",
+                "--working-directory",
+                path,
+                "-e",
+            ]
+            .map(String::from)
+            .into_iter()
+            .chain(run_in_shell(command)),
+        ),
+        ("kitty", Some(command)) => TerminalLaunch::open(
+            ["-na", known.bundle, "--args", "--directory", path]
+                .map(String::from)
+                .into_iter()
+                .chain(run_in_shell(command)),
+        ),
+        ("WezTerm", Some(command)) => TerminalLaunch::open(
+            ["-na", known.bundle, "--args", "start", "--cwd", path, "--"]
+                .map(String::from)
+                .into_iter()
+                .chain(run_in_shell(command)),
+        ),
+        // Apps driven over AppleScript, which type the command into a new window
+        ("iTerm2", _) => TerminalLaunch::osascript(format!(
+            "tell application \"iTerm\"\n\
+             activate\n\
+             set newWindow to (create window with default profile)\n\
+             tell current session of newWindow to write text \"{}\"\n\
+             end tell",
+            applescript_escape(&typed_command(path, command))
+        )),
+        ("Terminal", _) => TerminalLaunch::osascript(format!(
+            "tell application \"Terminal\"\n\
+             activate\n\
+             do script \"{}\"\n\
+             end tell",
+            applescript_escape(&typed_command(path, command))
+        )),
+        // Warp opens a tab at a path through its URL scheme but can't be given
+        // a command, so the command is typed once the tab is up
+        ("Warp", Some(command)) => TerminalLaunch::osascript(format!(
+            "open location \"warp://action/new_tab?path={}\"\n\
+             delay 1\n\
+             tell application \"System Events\"\n\
+             keystroke \"{}\"\n\
+             key code 36\n\
+             end tell",
+            percent_encode(path),
+            applescript_escape(command)
+        )),
+        ("Warp", None) => TerminalLaunch::open([format!(
+            "warp://action/new_tab?path={}",
+            percent_encode(path)
+        )]),
+        _ => TerminalLaunch::open(["-a".to_string(), known.bundle.to_string(), path.to_string()]),
+    }
+}
+
+/// The command typed into AppleScript-driven terminals: the launch command, or
+/// just a `cd` when not running Claude.
+fn typed_command(path: &str, command: Option<&str>) -> String {
+    command.map_or_else(|| format!("cd {}", shell_quote(path)), String::from)
+}
+
+/// Escapes a string for an AppleScript double-quoted literal.
+fn applescript_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Percent-encodes a path for a URL query value.
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Linux terminals probed, in order, when `$TERMINAL` and the configured app
+/// aren't installed.
+pub const LINUX_TERMINALS: &[&str] = &[
+    "gnome-terminal",
+    "konsole",
+    "ghostty",
+    "kitty",
+    "alacritty",
+    "wezterm",
+    "xfce4-terminal",
+    "xterm",
+];
+
+/// How to open a terminal at `path` on this platform, running `command` if given.
+///
+/// - macOS: the configured `app`, per [`terminal_launch`]
+/// - Linux: `$TERMINAL`, then the configured app, then [`LINUX_TERMINALS`]
+/// - Windows: Windows Terminal, else `cmd`, running `command` (see
+///   [`windows_launch_command`]) in `cmd`
+///
+/// Fails with the list of probed terminals when none is installed.
+pub fn launch_terminal(
+    app: &str,
+    path: &str,
+    command: Option<&str>,
+) -> Result<TerminalLaunch, String> {
+    if cfg!(target_os = "macos") {
+        Ok(terminal_launch(app, path, command))
+    } else if cfg!(windows) {
+        Ok(windows_terminal_launch(
+            path,
+            command,
+            find_on_path("wt.exe").is_some(),
+        ))
+    } else {
+        let env_terminal = std::env::var("TERMINAL").ok();
+        let configured = find_known_terminal(app).and_then(|known| known.binary);
+        linux_terminal_launch(
+            path,
+            command,
+            env_terminal.as_deref().into_iter().chain(configured),
+            |binary| find_on_path(binary).is_some(),
+        )
+    }
+}
+
+/// Picks the first installed Linux terminal from `preferred`, then
+/// [`LINUX_TERMINALS`], using `is_installed` to probe.
+pub fn linux_terminal_launch<'a, F>(
+    path: &str,
+    command: Option<&str>,
+    preferred: impl IntoIterator<Item = &'a str>,
+    is_installed: F,
+) -> Result<TerminalLaunch, String>
+where
+    F: Fn(&str) -> bool,
+{
+    let candidates: Vec<&str> = preferred
+        .into_iter()
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .chain(LINUX_TERMINALS.iter().copied())
+        .collect();
+    let Some(terminal) = candidates.iter().find(|c| is_installed(c)) else {
+        return Err(format!(
+            "No terminal found. Checked $TERMINAL and: {}",
+            candidates.join(", ")
+        ));
+    };
+
+    let run_in_shell = |flag: Option<&str>, command: &str| {
+        flag.into_iter()
+            .chain(["sh", "-lc", command])
+            .map(String::from)
+            .collect::<Vec<_>>()
+    };
+    let name = Path::new(terminal)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(terminal);
+    let (mut args, exec_flag) = match name {
+        "gnome-terminal" => (vec![format!("--working-directory={}", path)], Some("--")),
+        "konsole" => (vec!["--workdir".to_string(), path.to_string()], Some("-e")),
+        "ghostty" => (vec![format!("--working-directory={}", path)], Some("-e")),
+        "kitty" => (vec!["--directory".to_string(), path.to_string()], None),
+        "alacritty" => (
+            vec!["--working-directory".to_string(), path.to_string()],
+            Some("-e"),
+        ),
+        "wezterm" => (
+            vec!["start".to_string(), "--cwd".to_string(), path.to_string()],
+            Some("--"),
+        ),
+        "xfce4-terminal" => (vec![format!("--working-directory={}", path)], Some("-x")),
+        // No working-directory flag to rely on, so cd in the shell instead
+        _ => {
+            let script = match command {
+                Some(command) => format!("cd {} && {}", shell_quote(path), command),
+                None => format!("cd {} && exec \"${{SHELL:-sh}}\"", shell_quote(path)),
+            };
+            return Ok(TerminalLaunch::new(
+                terminal,
+                run_in_shell(Some("-e"), &script),
+            ));
+        }
+    };
+    if let Some(command) = command {
+        args.extend(run_in_shell(exec_flag, command));
+    }
+    Ok(TerminalLaunch::new(terminal, args))
+}
+
+/// Windows Terminal at `path` when installed, else a `cmd` window, running
+/// `command` in `cmd` if given.
+///
+/// The path is never spliced into a `cmd` command line, where characters like
+/// `&` would be parsed: `wt.exe` takes it as its own `-d` argument (with `;`,
+/// its command separator, escaped), and plain `cmd` is spawned in it.
+pub fn windows_terminal_launch(
+    path: &str,
+    command: Option<&str>,
+    has_windows_terminal: bool,
+) -> TerminalLaunch {
+    let run_in_cmd = command.map(|command| ["/K".to_string(), command.to_string()]);
+    if has_windows_terminal {
+        let escape = |value: &str| value.replace(';', "\\;");
+        let mut args = vec!["-d".to_string(), escape(path)];
+        if let Some([flag, command]) = run_in_cmd {
+            args.extend(["cmd".to_string(), flag, escape(&command)]);
+        }
+        TerminalLaunch::new("wt.exe", args)
+    } else {
+        let mut launch = TerminalLaunch::new("cmd", run_in_cmd.into_iter().flatten());
+        launch.cwd = Some(path.to_string());
+        launch
+    }
+}
+
+/// Finds an executable on `PATH`, like `which`.
+fn find_on_path(binary: &str) -> Option<PathBuf> {
+    let path = Path::new(binary);
+    if path.is_absolute() {
+        return path.exists().then(|| path.to_path_buf());
+    }
+    let path_var = std::env::var_os("PATH")?;
+    let dirs: Vec<PathBuf> = std::env::split_paths(&path_var).collect();
+    find_bundle_in(&dirs, binary)
+}
+
+#[cfg(target_os = "macos")]
+fn find_installed_app(app: &KnownTerminal) -> Option<PathBuf> {
+    let mut roots = vec![
+        PathBuf::from("/Applications"),
+        PathBuf::from("/System/Applications/Utilities"),
+        PathBuf::from("/Applications/Utilities"),
+    ];
+    if let Some(home) = dirs::home_dir() {
+        roots.push(home.join("Applications"));
+    }
+
+    find_bundle_in(&roots, app.bundle).or_else(|| find_bundle_with_spotlight(app.bundle_id))
+}
+
+#[cfg(not(target_os = "macos"))]
+fn find_installed_app(app: &KnownTerminal) -> Option<PathBuf> {
+    find_on_path(app.binary?)
+}
+
+/// Returns the first `root/name` that exists.
+fn find_bundle_in(roots: &[PathBuf], name: &str) -> Option<PathBuf> {
+    roots
+        .iter()
+        .map(|root| root.join(name))
+        .find(|candidate| Path::new(candidate).exists())
+}
+
+#[cfg(target_os = "macos")]
+fn find_bundle_with_spotlight(bundle_id: &str) -> Option<PathBuf> {
+    let output = std::process::Command::new("mdfind")
+        .arg(format!("kMDItemCFBundleIdentifier == '{}'", bundle_id))
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn launch_command_defaults_to_cd_and_claude() {
+        let config = HudConfig::default();
+        assert_eq!(
+            launch_command(&config, "/Users/me/My App", "claude", None),
+            "cd '/Users/me/My App' && claude"
+        );
+        assert_eq!(
+            launch_command(&config, "/p", "/opt/homebrew/bin/claude", Some("abc-123")),
+            "cd /p && /opt/homebrew/bin/claude --resume abc-123"
+        );
+    }
+
+    #[test]
+    fn launch_command_uses_project_template() {
+        let mut config = HudConfig::default();
+        config.launch_commands.insert(
+            "/Users/me/api/".to_string(),
+            "cd {path} && source .venv/bin/activate && claude".to_string(),
+        );
+
+        assert_eq!(
+            launch_command(&config, "/Users/me/api", "claude", Some("s1")),
+            "cd /Users/me/api && source .venv/bin/activate && claude --resume s1"
+        );
+        assert_eq!(
+            launch_command(&config, "/Users/me/other", "claude", None),
+            "cd /Users/me/other && claude"
+        );
+    }
+
+    #[test]
+    fn shell_quote_escapes_single_quotes() {
+        assert_eq!(shell_quote("/tmp/it's"), "'/tmp/it'\\''s'");
+        assert_eq!(shell_quote(""), "''");
+        assert_eq!(shell_quote("/tmp/$(rm -rf ~)"), "'/tmp/$(rm -rf ~)'");
+    }
+
+    #[test]
+    fn ghostty_runs_claude_in_a_login_shell_at_the_path() {
+        let launch = launch_terminal_for_app("Ghostty", "/Users/me/My App", true);
+        assert_eq!(launch.program, "open");
+        assert_eq!(
+            launch.args,
+            vec![
+                "-na",
+                "Ghostty.app",
+                "--args",
+                "--working-directory=/Users/me/My App",
+                "-e",
+                "/bin/zsh",
+                "-l",
+                "-c",
+                "cd '/Users/me/My App' && claude",
+            ]
+        );
+
+        let launch = launch_terminal_for_app("ghostty", "/p", false);
+        assert_eq!(launch.args, vec!["-a", "Ghostty.app", "/p"]);
+    }
+
+    #[test]
+    fn terminal_app_types_the_command_over_applescript() {
+        let launch = terminal_launch("Terminal", "/p", Some(r#"cd /p && echo "hi" && claude"#));
+        assert_eq!(launch.program, "osascript");
+        assert_eq!(launch.args[0], "-e");
+        assert!(launch.args[1].contains(r#"do script "cd /p && echo \"hi\" && claude""#));
+
+        let launch = launch_terminal_for_app("iTerm2", "/p", false);
+        assert!(launch.args[1].contains(r#"write text "cd /p""#));
+    }
+
+    #[test]
+    fn warp_opens_a_tab_at_the_encoded_path() {
+        let launch = launch_terminal_for_app("Warp", "/Users/me/My App", false);
+        assert_eq!(
+            launch,
+            TerminalLaunch {
+                program: "open".to_string(),
+                args: vec!["warp://action/new_tab?path=/Users/me/My%20App".to_string()],
+                cwd: None,
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_app_is_opened_at_the_path() {
+        let launch = launch_terminal_for_app("Hyper", "/p", true);
+        assert_eq!(launch.program, "open");
+        assert_eq!(launch.args, vec!["-a", "Hyper", "/p"]);
+    }
+
+    #[test]
+    fn linux_prefers_terminal_env_then_probes() {
+        let launch = linux_terminal_launch(
+            "/home/me/proj",
+            Some("cd /home/me/proj && claude"),
+            ["kitty"],
+            |binary| binary == "kitty",
+        )
+        .unwrap();
+        assert_eq!(launch.program, "kitty");
+        assert_eq!(
+            launch.args,
+            vec![
+                "--directory",
+                "/home/me/proj",
+                "sh",
+                "-lc",
+                "cd /home/me/proj && claude"
+            ]
+        );
+
+        let launch =
+            linux_terminal_launch("/p", None, ["missing-term"], |binary| binary == "konsole")
+                .unwrap();
+        assert_eq!(launch.program, "konsole");
+        assert_eq!(launch.args, vec!["--workdir", "/p"]);
+    }
+
+    #[test]
+    fn linux_unknown_terminal_changes_directory_in_the_shell() {
+        let launch = linux_terminal_launch("/my dir", None, ["/usr/bin/foot"], |_| true).unwrap();
+        assert_eq!(launch.program, "/usr/bin/foot");
+        assert_eq!(
+            launch.args,
+            vec!["-e", "sh", "-lc", "cd '/my dir' && exec \"${SHELL:-sh}\""]
+        );
+    }
+
+    #[test]
+    fn linux_without_any_terminal_lists_what_was_probed() {
+        let err = linux_terminal_launch("/p", None, ["foot"], |_| false).unwrap_err();
+        assert!(err.contains("foot, gnome-terminal, konsole"));
+        assert!(err.contains("xterm"));
+    }
+
+    #[test]
+    fn windows_uses_windows_terminal_or_cmd() {
+        let launch = windows_terminal_launch(r"C:\src\app", Some("claude"), true);
+        assert_eq!(launch.program, "wt.exe");
+        assert_eq!(
+            launch.args,
+            vec!["-d", r"C:\src\app", "cmd", "/K", "claude"]
+        );
+
+        let launch = windows_terminal_launch(r"C:\R&D\app", Some("claude"), false);
+        assert_eq!(launch.program, "cmd");
+        assert_eq!(launch.args, vec!["/K", "claude"]);
+        assert_eq!(launch.cwd.as_deref(), Some(r"C:\R&D\app"));
+
+        let launch = windows_terminal_launch(r"C:\a;b", None, true);
+        assert_eq!(launch.args, vec!["-d", r"C:\a\;b"]);
+    }
+
+    #[test]
+    fn windows_launch_command_uses_template_with_cmd_quoting() {
+        let mut config = HudConfig::default();
+        assert_eq!(
+            windows_launch_command(&config, r"C:\src\app", Some("s1")),
+            "claude --resume s1"
+        );
+        config.launch_commands.insert(
+            r"C:\R&D\app".to_string(),
+            r"{path}\.venv\Scripts\activate && claude".to_string(),
+        );
+        assert_eq!(
+            windows_launch_command(&config, r"C:\R&D\app", None),
+            r#""C:\R&D\app"\.venv\Scripts\activate && claude"#
+        );
+    }
+
+    #[test]
+    fn detects_only_installed_apps_in_known_order() {
+        let installed = ["WezTerm", "Ghostty", "kitty"];
+        let detected = detect_terminals_with(|app| {
+            installed
+                .contains(&app.name)
+                .then(|| PathBuf::from("/Applications").join(app.bundle))
+        });
+
+        let names: Vec<&str> = detected.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["Ghostty", "kitty", "WezTerm"]);
+        assert_eq!(detected[0].path, "/Applications/Ghostty.app");
+    }
+
+    #[test]
+    fn detects_nothing_when_nothing_installed() {
+        assert!(detect_terminals_with(|_| None).is_empty());
+    }
+
+    #[test]
+    fn find_bundle_in_checks_each_root() {
+        let temp = TempDir::new().unwrap();
+        let apps = temp.path().join("Applications");
+        std::fs::create_dir_all(apps.join("Warp.app")).unwrap();
+        let roots = vec![temp.path().join("missing"), apps.clone()];
+
+        assert_eq!(
+            find_bundle_in(&roots, "Warp.app"),
+            Some(apps.join("Warp.app"))
+        );
+        assert_eq!(find_bundle_in(&roots, "iTerm.app"), None);
+    }
+
+    #[test]
+    fn find_known_terminal_is_case_insensitive() {
+        assert_eq!(find_known_terminal("iterm2").unwrap().bundle, "iTerm.app");
+        assert!(find_known_terminal("Hyper").is_none());
+    }
+}
@@ -0,0 +1,258 @@
+//! Session timeline export in an OpenTelemetry-style trace format.
+//!
+//! Composes the state transitions log and the file-activity feed into a single
+//! trace per session so power users can load it into their own trace viewers.
+//!
+//! ## Schema (v1, stable)
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "trace_id": "<session_id>",
+//!   "project_path": "/path/to/project",
+//!   "spans": [
+//!     { "span_id": "session", "parent_span_id": null, "name": "session",
+//!       "kind": "session", "start_time": "...", "end_time": "...", "attributes": {} },
+//!     { "span_id": "state-0", "parent_span_id": "session", "name": "state.working",
+//!       "kind": "state", ... },
+//!     { "span_id": "tool-0", "parent_span_id": "session", "name": "tool.Edit",
+//!       "kind": "tool", "attributes": { "file.path": "..." } }
+//!   ]
+//! }
+//! ```
+//!
+//! State spans end where the next transition starts; the last one ends at the
+//! newest timestamp known for the session. Tool spans are instants (start == end)
+//! because the hook only observes tool completion. Sessions with sparse data yield
+//! fewer spans rather than an error.
+
+use crate::activity::ActivityStore;
+use crate::state::{load_session_transitions, normalize_path_for_matching, StateStore};
+use crate::storage::StorageConfig;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Current trace schema version. Bump only for breaking changes.
+pub const TRACE_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TraceSpanKind {
+    Session,
+    State,
+    Tool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceSpan {
+    pub span_id: String,
+    pub parent_span_id: Option<String>,
+    pub name: String,
+    pub kind: TraceSpanKind,
+    /// RFC 3339 timestamp
+    pub start_time: String,
+    /// RFC 3339 timestamp
+    pub end_time: String,
+    pub attributes: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionTrace {
+    pub schema_version: u32,
+    /// The session ID
+    pub trace_id: String,
+    pub project_path: String,
+    pub spans: Vec<TraceSpan>,
+}
+
+/// Builds a trace for one session from the transitions log and activity feed.
+pub fn export_session_trace(session_id: &str, project_path: &str) -> SessionTrace {
+    export_session_trace_with_storage(&StorageConfig::default(), session_id, project_path)
+}
+
+pub fn export_session_trace_with_storage(
+    storage: &StorageConfig,
+    session_id: &str,
+    project_path: &str,
+) -> SessionTrace {
+    let transitions = load_session_transitions(&storage.transitions_file(), session_id);
+    let record_updated_at = StateStore::load(&storage.sessions_file())
+        .ok()
+        .and_then(|store| store.get_by_session_id(session_id).map(|r| r.updated_at));
+
+    let project_prefix = format!("{}/", normalize_path_for_matching(project_path));
+    let activity_store = ActivityStore::load(&storage.file_activity_file());
+    let mut tool_uses: Vec<(DateTime<Utc>, String, String)> = activity_store
+        .sessions
+        .get(session_id)
+        .map(|session| {
+            session
+                .activity
+                .iter()
+                .filter(|a| normalize_path_for_matching(&a.file_path).starts_with(&project_prefix))
+                .filter_map(|a| {
+                    let at = DateTime::parse_from_rfc3339(&a.timestamp).ok()?;
+                    Some((at.with_timezone(&Utc), a.tool.clone(), a.file_path.clone()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    // Activity is stored newest first
+    tool_uses.sort_by_key(|(at, _, _)| *at);
+
+    let trace_end = transitions
+        .iter()
+        .map(|t| t.at)
+        .chain(tool_uses.iter().map(|(at, _, _)| *at))
+        .chain(record_updated_at)
+        .max();
+    let trace_start = transitions
+        .iter()
+        .map(|t| t.at)
+        .chain(tool_uses.iter().map(|(at, _, _)| *at))
+        .min();
+
+    let mut spans = Vec::new();
+    if let (Some(start), Some(end)) = (trace_start, trace_end) {
+        spans.push(TraceSpan {
+            span_id: "session".to_string(),
+            parent_span_id: None,
+            name: "session".to_string(),
+            kind: TraceSpanKind::Session,
+            start_time: start.to_rfc3339(),
+            end_time: end.to_rfc3339(),
+            attributes: BTreeMap::from([("session.id".to_string(), session_id.to_string())]),
+        });
+
+        for (i, transition) in transitions.iter().enumerate() {
+            let state_end = transitions.get(i + 1).map(|next| next.at).unwrap_or(end);
+            let state = state_name(&transition.to);
+            let mut attributes = BTreeMap::from([
+                ("state".to_string(), state.clone()),
+                ("cwd".to_string(), transition.cwd.clone()),
+            ]);
+            if let Some(event) = &transition.event {
+                attributes.insert("hook.event".to_string(), event.clone());
+            }
+            spans.push(TraceSpan {
+                span_id: format!("state-{}", i),
+                parent_span_id: Some("session".to_string()),
+                name: format!("state.{}", state),
+                kind: TraceSpanKind::State,
+                start_time: transition.at.to_rfc3339(),
+                end_time: state_end.to_rfc3339(),
+                attributes,
+            });
+        }
+
+        for (i, (at, tool, file_path)) in tool_uses.iter().enumerate() {
+            spans.push(TraceSpan {
+                span_id: format!("tool-{}", i),
+                parent_span_id: Some("session".to_string()),
+                name: format!("tool.{}", tool),
+                kind: TraceSpanKind::Tool,
+                start_time: at.to_rfc3339(),
+                end_time: at.to_rfc3339(),
+                attributes: BTreeMap::from([
+                    ("tool.name".to_string(), tool.clone()),
+                    ("file.path".to_string(), file_path.clone()),
+                ]),
+            });
+        }
+    }
+
+    SessionTrace {
+        schema_version: TRACE_SCHEMA_VERSION,
+        trace_id: session_id.to_string(),
+        project_path: project_path.to_string(),
+        spans,
+    }
+}
+
+/// Lowercase state name, matching the state file serialization.
+fn state_name(state: &crate::types::SessionState) -> String {
+    serde_json::to_value(state)
+        .ok()
+        .and_then(|v| v.as_str().map(String::from))
+        .unwrap_or_else(|| format!("{:?}", state).to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activity::FileActivity;
+    use crate::state::{append_transition, TransitionRecord};
+    use crate::types::SessionState;
+    use chrono::Duration;
+    use fs_err as fs;
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, StorageConfig) {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        fs::create_dir_all(storage.root()).unwrap();
+        (temp, storage)
+    }
+
+    #[test]
+    fn builds_spans_from_transitions_and_edits() {
+        let (_temp, storage) = setup();
+        let t0 = Utc::now() - Duration::minutes(10);
+
+        for (offset, from, to) in [
+            (0, None, SessionState::Working),
+            (5, Some(SessionState::Working), SessionState::Ready),
+        ] {
+            append_transition(
+                &storage.transitions_file(),
+                &TransitionRecord {
+                    session_id: "s1".to_string(),
+                    cwd: "/project".to_string(),
+                    from,
+                    to,
+                    event: None,
+                    at: t0 + Duration::minutes(offset),
+                },
+            )
+            .unwrap();
+        }
+
+        let mut activity = ActivityStore::new();
+        let session = activity.sessions.entry("s1".to_string()).or_default();
+        for (offset, file) in [(3, "c.rs"), (2, "b.rs"), (1, "a.rs")] {
+            session.activity.push(FileActivity {
+                project_path: "/project".to_string(),
+                file_path: format!("/project/src/{}", file),
+                tool: "Edit".to_string(),
+                timestamp: (t0 + Duration::minutes(offset)).to_rfc3339(),
+            });
+        }
+        activity.save(&storage.file_activity_file()).unwrap();
+
+        let trace = export_session_trace_with_storage(&storage, "s1", "/project");
+        assert_eq!(trace.schema_version, TRACE_SCHEMA_VERSION);
+        assert_eq!(trace.trace_id, "s1");
+
+        let count = |kind| trace.spans.iter().filter(|s| s.kind == kind).count();
+        assert_eq!(count(TraceSpanKind::Session), 1);
+        assert_eq!(count(TraceSpanKind::State), 2);
+        assert_eq!(count(TraceSpanKind::Tool), 3);
+
+        let working = trace.spans.iter().find(|s| s.span_id == "state-0").unwrap();
+        assert_eq!(working.name, "state.working");
+        assert_eq!(working.end_time, (t0 + Duration::minutes(5)).to_rfc3339());
+
+        // Tool spans come out oldest first
+        let first_tool = trace.spans.iter().find(|s| s.span_id == "tool-0").unwrap();
+        assert_eq!(first_tool.attributes["file.path"], "/project/src/a.rs");
+    }
+
+    #[test]
+    fn unknown_session_yields_empty_trace() {
+        let (_temp, storage) = setup();
+        let trace = export_session_trace_with_storage(&storage, "missing", "/project");
+        assert!(trace.spans.is_empty());
+    }
+}
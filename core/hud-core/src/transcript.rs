@@ -0,0 +1,173 @@
+//! Recent conversation messages from a session's transcript, for peeking at
+//! what a running session is doing without opening the raw JSONL.
+
+use crate::artifacts::strip_markdown;
+use crate::projects::session_transcript_path;
+use crate::state::StateStore;
+use crate::storage::StorageConfig;
+use fs_err as fs;
+use std::path::PathBuf;
+
+/// One user or assistant message, as plain text.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct TranscriptMessage {
+    /// "user" or "assistant"
+    pub role: String,
+    pub text: String,
+    pub timestamp: Option<String>,
+}
+
+/// Returns the last `limit` user/assistant messages of a session, oldest first.
+///
+/// The transcript is found via the session record's `transcript_path`, falling
+/// back to the encoded project directory. Empty when the session or its
+/// transcript can't be found.
+pub fn session_transcript(session_id: &str, limit: usize) -> Vec<TranscriptMessage> {
+    session_transcript_with_storage(&StorageConfig::default(), session_id, limit)
+}
+
+pub fn session_transcript_with_storage(
+    storage: &StorageConfig,
+    session_id: &str,
+    limit: usize,
+) -> Vec<TranscriptMessage> {
+    let Some(path) = locate_transcript(storage, session_id) else {
+        return vec![];
+    };
+    let Ok(content) = fs::read_to_string(path) else {
+        return vec![];
+    };
+    let mut messages = messages_from_content(&content);
+    let skip = messages.len().saturating_sub(limit);
+    messages.drain(..skip);
+    messages
+}
+
+pub(crate) fn locate_transcript(storage: &StorageConfig, session_id: &str) -> Option<PathBuf> {
+    let state_file = storage.sessions_file();
+    let store = StateStore::load(&state_file).unwrap_or_else(|_| StateStore::new(&state_file));
+    let record = store.get_by_session_id(session_id)?;
+
+    if let Some(path) = record.transcript_path.as_deref().map(PathBuf::from) {
+        if path.is_file() {
+            return Some(path);
+        }
+    }
+    let project_path = record.project_dir.as_deref().unwrap_or(&record.cwd);
+    session_transcript_path(storage, project_path, session_id).filter(|p| p.is_file())
+}
+
+/// Parses transcript content into plain-text messages.
+///
+/// Lines are filtered as in [`line_message`].
+pub fn messages_from_content(content: &str) -> Vec<TranscriptMessage> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let value: serde_json::Value = serde_json::from_str(line).ok()?;
+            let (role, text) = line_message(&value)?;
+
+            Some(TranscriptMessage {
+                role: role.to_string(),
+                text: strip_markdown(&text),
+                timestamp: value
+                    .get("timestamp")
+                    .and_then(|t| t.as_str())
+                    .map(String::from),
+            })
+        })
+        .collect()
+}
+
+/// The role and trimmed text of a user or assistant transcript line.
+///
+/// Meta lines (injected context), slash-command scaffolding, tool calls and
+/// results, and thinking are skipped; None when no text is left.
+pub(crate) fn line_message(value: &serde_json::Value) -> Option<(&str, String)> {
+    if value.get("isMeta").and_then(|m| m.as_bool()) == Some(true) {
+        return None;
+    }
+    let role = value.get("type")?.as_str()?;
+    if role != "user" && role != "assistant" {
+        return None;
+    }
+
+    let text = match value.get("message")?.get("content")? {
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Array(blocks) => blocks
+            .iter()
+            .filter(|b| b.get("type").and_then(|t| t.as_str()) == Some("text"))
+            .filter_map(|b| b.get("text").and_then(|t| t.as_str()))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => return None,
+    };
+    let text = text.trim();
+    if text.is_empty() || is_command_scaffolding(text) {
+        return None;
+    }
+    Some((role, text.to_string()))
+}
+
+/// Slash-command invocations and their captured output, which Claude Code
+/// records as user messages wrapped in `<command-*>` / `<local-command-*>` tags.
+fn is_command_scaffolding(text: &str) -> bool {
+    text.starts_with("<command-") || text.starts_with("<local-command-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects::encode_project_path;
+    use crate::types::SessionState;
+    use tempfile::TempDir;
+
+    #[test]
+    fn session_transcript_returns_last_messages_as_plain_text() {
+        let temp = TempDir::new().unwrap();
+        let storage =
+            StorageConfig::with_roots(temp.path().join("capacitor"), temp.path().join("claude"));
+        fs::create_dir_all(storage.root()).unwrap();
+        assert!(session_transcript_with_storage(&storage, "s1", 10).is_empty());
+
+        let mut store = StateStore::new(&storage.sessions_file());
+        store.update("s1", SessionState::Working, "/work/app");
+        store.save().unwrap();
+
+        let dir = storage
+            .claude_projects_dir()
+            .join(encode_project_path("/work/app"));
+        fs::create_dir_all(&dir).unwrap();
+        let content = [
+            r#"{"type":"user","isMeta":true,"message":{"content":"Caveat: injected"}}"#,
+            r#"{"type":"user","timestamp":"2026-01-01T10:00:00Z","message":{"content":"<command-name>/clear</command-name>"}}"#,
+            r#"{"type":"user","timestamp":"2026-01-01T10:00:01Z","message":{"content":"Fix the **login** bug"}}"#,
+            r#"{"type":"assistant","timestamp":"2026-01-01T10:00:02Z","message":{"content":[{"type":"thinking","thinking":"hmm"},{"type":"text","text":"Looking at `auth.rs`"},{"type":"tool_use","id":"t1","name":"Read","input":{}}]}}"#,
+            r#"{"type":"user","timestamp":"2026-01-01T10:00:03Z","message":{"content":[{"type":"tool_result","tool_use_id":"t1","content":"..."}]}}"#,
+            r#"{"type":"assistant","timestamp":"2026-01-01T10:00:04Z","message":{"content":[{"type":"text","text":"Fixed."}]}}"#,
+        ]
+        .join("\n");
+        fs::write(dir.join("s1.jsonl"), content).unwrap();
+
+        let messages = session_transcript_with_storage(&storage, "s1", 2);
+        assert_eq!(
+            messages,
+            vec![
+                TranscriptMessage {
+                    role: "assistant".to_string(),
+                    text: "Looking at auth.rs".to_string(),
+                    timestamp: Some("2026-01-01T10:00:02Z".to_string()),
+                },
+                TranscriptMessage {
+                    role: "assistant".to_string(),
+                    text: "Fixed.".to_string(),
+                    timestamp: Some("2026-01-01T10:00:04Z".to_string()),
+                },
+            ]
+        );
+
+        let all = session_transcript_with_storage(&storage, "s1", 10);
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].text, "Fix the login bug");
+    }
+}
@@ -72,6 +72,170 @@ pub struct ProjectStats {
     pub latest_summary: Option<String>,
     pub first_activity: Option<String>,
     pub last_activity: Option<String>,
+    /// Messages per day, keyed by `YYYY-MM-DD`, for activity heatmaps
+    #[serde(default)]
+    pub daily_activity: HashMap<String, u32>,
+    /// Messages per UTC hour, keyed by `YYYY-MM-DDTHH`, so activity can be
+    /// regrouped into local days without re-reading transcripts
+    #[serde(default)]
+    pub hourly_activity: HashMap<String, u32>,
+    /// Token usage from Opus messages, so each family is priced at its own rates
+    #[serde(default)]
+    pub opus_usage: TokenCounts,
+    #[serde(default)]
+    pub sonnet_usage: TokenCounts,
+    #[serde(default)]
+    pub haiku_usage: TokenCounts,
+    /// Usage from other models, or from lines with no model id; priced as Sonnet
+    #[serde(default)]
+    pub other_usage: TokenCounts,
+    /// Estimated dollars saved by serving input from the prompt cache.
+    /// Derived from the per-family usage; see `stats::cache_savings_usd`.
+    #[serde(default)]
+    pub cache_savings_usd: f64,
+    /// Estimated dollar cost of the token usage, rounded to cents.
+    /// Derived from the per-family usage; see `stats::estimate_cost`.
+    #[serde(default)]
+    pub estimated_cost_usd: f64,
+    /// True when some session files were skipped for exceeding `HudConfig.max_parse_bytes`
+    #[serde(default)]
+    pub partial: bool,
+    /// Names of the skipped session files, sorted
+    #[serde(default)]
+    pub skipped_files: Vec<String>,
+}
+
+/// Token counts by type, for one model family.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, uniffi::Record)]
+pub struct TokenCounts {
+    pub input: u64,
+    pub output: u64,
+    pub cache_read: u64,
+    pub cache_creation: u64,
+}
+
+/// One session file's share of a project's stats.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, uniffi::Record)]
+pub struct FileStatsContribution {
+    pub file_name: String,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_read_tokens: u64,
+    pub cache_creation_tokens: u64,
+    /// Assistant messages from a known model family
+    pub message_count: u32,
+    /// Fraction of the project's input + output tokens (0.0–1.0)
+    pub token_share: f64,
+}
+
+/// How much text the user wrote versus Claude across a project's sessions.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, uniffi::Record)]
+pub struct AuthorshipRatio {
+    /// Characters in typed prompts
+    pub human_chars: u64,
+    /// Characters in Claude's text replies
+    pub assistant_chars: u64,
+    /// Human characters per assistant character (0.0 when Claude wrote nothing)
+    pub ratio: f64,
+}
+
+/// One session's token usage and estimated cost.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, uniffi::Record)]
+pub struct SessionCost {
+    pub project_path: String,
+    pub session_id: String,
+    /// Latest summary line in the transcript, if Claude wrote one
+    pub summary: Option<String>,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_read_tokens: u64,
+    pub cache_creation_tokens: u64,
+    /// Estimated cost at list prices
+    pub cost_usd: f64,
+}
+
+/// Model family, as inferred from a transcript's model id.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, uniffi::Enum)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelFamily {
+    Opus,
+    Sonnet,
+    Haiku,
+    Other,
+}
+
+/// Token usage and estimated cost for one model family within a period.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, uniffi::Record)]
+pub struct ModelPeriodUsage {
+    pub family: ModelFamily,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_read_tokens: u64,
+    pub cache_creation_tokens: u64,
+    pub cost_usd: f64,
+}
+
+/// Usage across all projects between two dates, for billing-period views.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, uniffi::Record)]
+pub struct PeriodUsage {
+    /// First day included (YYYY-MM-DD, UTC)
+    pub start: String,
+    /// Last day included (YYYY-MM-DD, UTC)
+    pub end: String,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_read_tokens: u64,
+    pub cache_creation_tokens: u64,
+    pub cost_usd: f64,
+    /// Families with any usage in the period, largest cost first
+    pub models: Vec<ModelPeriodUsage>,
+}
+
+/// Where this month's spend stands against `HudConfig.monthly_budget_usd`.
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, uniffi::Enum,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetLevel {
+    /// Under 80% of the budget, or no budget set
+    Ok,
+    /// At or above 80% of the budget
+    Warning,
+    /// At or above the budget
+    Over,
+}
+
+/// This month's estimated spend compared with the configured budget.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, uniffi::Record)]
+pub struct BudgetStatus {
+    /// Month covered (YYYY-MM, UTC), from the 1st through today
+    pub month: String,
+    pub spent_usd: f64,
+    pub budget_usd: Option<f64>,
+    /// Spend as a percentage of the budget (100.0 = fully used), None without a budget
+    pub percent_used: Option<f64>,
+    pub level: BudgetLevel,
+}
+
+/// Time bucket size for usage time series.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageBucket {
+    Day,
+    /// ISO weeks, starting Monday
+    Week,
+}
+
+/// Token usage (input + output) in one time bucket, split by model family.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, uniffi::Record)]
+pub struct ModelUsagePoint {
+    /// First day of the bucket (YYYY-MM-DD, UTC)
+    pub bucket_start: String,
+    pub opus_tokens: u64,
+    pub sonnet_tokens: u64,
+    pub haiku_tokens: u64,
+    /// Models outside the known families
+    pub other_tokens: u64,
 }
 
 /// Cached file metadata for cache invalidation.
@@ -79,6 +243,10 @@ pub struct ProjectStats {
 pub struct CachedFileInfo {
     pub size: u64,
     pub mtime: u64,
+    /// Stats parsed from this file alone. None for skipped files and for caches
+    /// written before per-file subtotals, which forces a re-parse.
+    #[serde(default)]
+    pub subtotal: Option<ProjectStats>,
 }
 
 /// Cached statistics for a single project.
@@ -88,12 +256,43 @@ pub struct CachedProjectStats {
     pub stats: ProjectStats,
 }
 
+/// Current version of the stats cache format.
+///
+/// Bump it whenever parsing changes what a session file contributes, so
+/// per-file subtotals cached by the old parser are not summed as-is.
+/// Version 1: line-by-line JSON parsing replaced whole-file regex scans.
+/// Version 2: per-family token usage.
+/// Version 3: hourly activity.
+pub const STATS_CACHE_VERSION: u32 = 3;
+
 /// The full stats cache, persisted to disk.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, uniffi::Record)]
+#[derive(Debug, Serialize, Deserialize, Clone, uniffi::Record)]
 pub struct StatsCache {
+    /// Format version; caches written before versioning read as 0. Declared
+    /// first so it is serialized ahead of `projects` and survives truncation.
+    #[serde(default)]
+    pub version: u32,
     pub projects: HashMap<String, CachedProjectStats>,
 }
 
+impl Default for StatsCache {
+    fn default() -> Self {
+        Self {
+            version: STATS_CACHE_VERSION,
+            projects: HashMap::new(),
+        }
+    }
+}
+
+/// Outcome of salvaging a partially-corrupt stats cache.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, uniffi::Record)]
+pub struct StatsCacheRepair {
+    /// Project entries kept
+    pub salvaged: u32,
+    /// Project entries that could not be parsed and were discarded
+    pub dropped: u32,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Project Types
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -105,6 +304,7 @@ pub struct Project {
     pub path: String,
     pub display_path: String,
     pub last_active: Option<String>,
+    /// Path to the instructions file in use (CLAUDE.md, or AGENTS.md as a fallback).
     pub claude_md_path: Option<String>,
     pub claude_md_preview: Option<String>,
     pub has_local_settings: bool,
@@ -113,6 +313,12 @@ pub struct Project {
     /// True if the project directory no longer exists on disk.
     #[serde(default)]
     pub is_missing: bool,
+    /// File name of the instructions file in use ("CLAUDE.md" or "AGENTS.md").
+    #[serde(default)]
+    pub instructions_file: Option<String>,
+    /// True if the project has an AGENTS.md, even when CLAUDE.md takes precedence.
+    #[serde(default)]
+    pub has_agents_md: bool,
 }
 
 /// A task/session from a project (represents Claude Code sessions).
@@ -133,7 +339,22 @@ pub struct ProjectDetails {
     pub claude_md_content: Option<String>,
     pub tasks: Vec<Task>,
     pub git_branch: Option<String>,
+    /// Full SHA HEAD points at, None for unborn branches and non-git projects
+    #[serde(default)]
+    pub git_head_commit: Option<String>,
     pub git_dirty: bool,
+    /// Number of stash entries, None for non-git projects
+    #[serde(default)]
+    pub git_stash_count: Option<u32>,
+    /// Commits on HEAD not on its upstream; 0 without an upstream
+    #[serde(default)]
+    pub git_ahead: u32,
+    /// Commits on the upstream not on HEAD; 0 without an upstream
+    #[serde(default)]
+    pub git_behind: u32,
+    /// Whether the branch tracks an upstream, so 0/0 means "in sync"
+    #[serde(default)]
+    pub git_has_upstream: bool,
 }
 
 /// A project discovered in `~/.claude/projects/` but not yet pinned.
@@ -145,6 +366,9 @@ pub struct SuggestedProject {
     pub task_count: u32,
     pub has_claude_md: bool,
     pub has_project_indicators: bool,
+    /// How project-like the folder looks (0–100); suggestions are ranked by it
+    #[serde(default)]
+    pub confidence: u32,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -159,6 +383,70 @@ pub struct Artifact {
     pub description: String,
     pub source: String,
     pub path: String,
+    /// False when the user switched this plugin artifact off with
+    /// `toggle_plugin_artifact`
+    #[serde(default = "default_artifact_enabled")]
+    pub enabled: bool,
+}
+
+fn default_artifact_enabled() -> bool {
+    true
+}
+
+/// A parsed artifact file plus the metadata used to tell whether it changed.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CachedArtifact {
+    pub size: u64,
+    pub mtime: u64,
+    pub artifact: Artifact,
+}
+
+/// Parsed artifacts keyed by file path, persisted to disk so unchanged
+/// SKILL.md and command/agent files aren't re-read on every listing.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ArtifactCache {
+    pub files: HashMap<String, CachedArtifact>,
+    /// Set when entries were added or dropped since loading, so unchanged
+    /// caches aren't rewritten.
+    #[serde(skip)]
+    pub dirty: bool,
+}
+
+impl ArtifactCache {
+    /// Records a freshly parsed artifact file.
+    pub fn insert(&mut self, path: String, entry: CachedArtifact) {
+        self.files.insert(path, entry);
+        self.dirty = true;
+    }
+
+    /// Drops entries whose files no longer exist.
+    pub fn prune_missing(&mut self) {
+        let before = self.files.len();
+        self.files
+            .retain(|path, _| std::path::Path::new(path).is_file());
+        self.dirty |= self.files.len() != before;
+    }
+}
+
+/// Where an artifact comes from, for navigating from an artifact to its provider.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, uniffi::Record)]
+pub struct ArtifactOrigin {
+    /// "Global", "Project", or the providing plugin's name (matches `Artifact.source`)
+    pub source: String,
+    /// Plugin id when the artifact lives under a plugin's install path
+    pub plugin_id: Option<String>,
+}
+
+/// An artifact as a specific project sees it, after shadowing and plugin overrides.
+#[derive(Debug, Serialize, Deserialize, Clone, uniffi::Record)]
+pub struct EffectiveArtifact {
+    pub artifact: Artifact,
+    /// True when the project actually gets this artifact
+    pub active: bool,
+    /// Source of the same-named artifact that takes precedence, when shadowed
+    pub shadowed_by: Option<String>,
+    /// True when the providing plugin is disabled for this project
+    pub disabled: bool,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -171,6 +459,9 @@ pub struct DashboardData {
     pub global: GlobalConfig,
     pub plugins: Vec<Plugin>,
     pub projects: Vec<Project>,
+    /// Non-fatal load failures (e.g. corrupt plugin registry) for the UI to surface
+    #[serde(default)]
+    pub warnings: Vec<String>,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -187,6 +478,53 @@ pub struct HudConfig {
     pub pinned_projects: Vec<String>,
     #[serde(default = "default_terminal_app")]
     pub terminal_app: String,
+    /// When true, `hud-hook status-gen-log` appends the status generation hook's
+    /// `claude -p` output to `~/.capacitor/status-gen.log`. Off by default.
+    #[serde(default)]
+    pub status_gen_logging: bool,
+    /// Session files larger than this are skipped when computing stats, so huge
+    /// transcripts can't stall the dashboard. None (the default) means no limit.
+    #[serde(default)]
+    pub max_parse_bytes: Option<u64>,
+    /// When set, only the N most recently modified `~/.claude/projects` folders
+    /// are scanned for suggestions. None (the default) scans all of them.
+    #[serde(default)]
+    pub max_scan_folders: Option<u32>,
+    /// Store the stats cache as MessagePack (`stats-cache.bin`) instead of JSON.
+    /// Faster to load for users with hundreds of cached projects.
+    #[serde(default)]
+    pub binary_stats_cache: bool,
+    /// Seconds a locked session may sit in Ready before it shows as Idle.
+    /// None (the default) keeps locked sessions Ready until they end.
+    #[serde(default)]
+    pub idle_threshold_secs: Option<u64>,
+    /// Seconds without a hook update before a session record is considered stale.
+    /// None uses the default of 300.
+    #[serde(default)]
+    pub stale_threshold_secs: Option<u64>,
+    /// Seconds a lockless Working/Waiting record is trusted before it falls back
+    /// to Ready. None uses the default of 30.
+    #[serde(default)]
+    pub active_state_stale_secs: Option<u64>,
+    /// Monthly spend limit in USD for `budget_status`. None disables budget warnings.
+    #[serde(default)]
+    pub monthly_budget_usd: Option<f64>,
+    /// Per-project shell command used instead of `cd <path> && claude` when
+    /// launching, keyed by project path. `{path}` is replaced with the quoted path.
+    #[serde(default)]
+    pub launch_commands: HashMap<String, String>,
+    /// Keep pinned projects in `pinned_projects` order instead of sorting them
+    /// by most recent activity. Off by default.
+    #[serde(default)]
+    pub manual_order: bool,
+    /// User-chosen display names keyed by project path, used in place of the
+    /// folder name (e.g. to tell apart several `web` directories).
+    #[serde(default)]
+    pub project_aliases: HashMap<String, String>,
+    /// Plugin artifacts the user switched off, keyed by plugin id. Entries are
+    /// `<type>:<name>`, e.g. `skill:deploy`.
+    #[serde(default)]
+    pub disabled_artifacts: HashMap<String, Vec<String>>,
 }
 
 impl Default for HudConfig {
@@ -194,6 +532,18 @@ impl Default for HudConfig {
         Self {
             pinned_projects: Vec::new(),
             terminal_app: default_terminal_app(),
+            status_gen_logging: false,
+            max_parse_bytes: None,
+            max_scan_folders: None,
+            binary_stats_cache: false,
+            idle_threshold_secs: None,
+            stale_threshold_secs: None,
+            active_state_stale_secs: None,
+            monthly_budget_usd: None,
+            launch_commands: HashMap::new(),
+            manual_order: false,
+            project_aliases: HashMap::new(),
+            disabled_artifacts: HashMap::new(),
         }
     }
 }
@@ -203,7 +553,10 @@ impl Default for HudConfig {
 // ═══════════════════════════════════════════════════════════════════════════════
 
 /// The current state of a Claude Code session.
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, uniffi::Enum)]
+///
+/// States this build doesn't know (written by a newer hook) read as Idle, so one
+/// unfamiliar record can't make the whole state file fail to load.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, Default, uniffi::Enum)]
 #[serde(rename_all = "lowercase")]
 pub enum SessionState {
     Working,
@@ -212,6 +565,22 @@ pub enum SessionState {
     Idle,
     Compacting,
     Waiting,
+    /// Claude is reasoning before its next tool call or reply: set when a prompt
+    /// is submitted or a tool returns, until the next tool call or stop.
+    Thinking,
+}
+
+impl<'de> Deserialize<'de> for SessionState {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match String::deserialize(deserializer)?.as_str() {
+            "working" => Self::Working,
+            "ready" => Self::Ready,
+            "compacting" => Self::Compacting,
+            "waiting" => Self::Waiting,
+            "thinking" => Self::Thinking,
+            _ => Self::Idle,
+        })
+    }
 }
 
 impl SessionState {
@@ -224,7 +593,7 @@ impl SessionState {
     /// Whether this state indicates Claude is busy.
     #[must_use]
     pub fn is_busy(&self) -> bool {
-        matches!(self, Self::Working | Self::Compacting)
+        matches!(self, Self::Working | Self::Thinking | Self::Compacting)
     }
 }
 
@@ -237,6 +606,104 @@ pub struct ContextInfo {
     pub updated_at: Option<String>,
 }
 
+/// Estimated rate at which a session is filling its context window.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, uniffi::Record)]
+pub struct ContextBurnRate {
+    pub tokens_per_minute: f64,
+    /// Context tokens in use as of the latest usage block
+    pub tokens_used: u64,
+    pub context_size: u64,
+    /// Minutes until the context window fills, None when usage isn't growing
+    pub minutes_remaining: Option<f64>,
+}
+
+/// Consecutive days with transcript activity for a project.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct ActivityStreak {
+    /// Run of active days ending today, or yesterday if today has no activity yet
+    pub current_days: u32,
+    pub longest_days: u32,
+    /// Most recent active day as YYYY-MM-DD in local time
+    pub last_active_date: Option<String>,
+}
+
+/// Session and edit rates for a project over a trailing window.
+///
+/// Rates are given both per calendar day and per active day (a day with any
+/// session or edit), so the client can choose which to show.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, uniffi::Record)]
+pub struct ProjectVelocity {
+    pub window_days: u32,
+    pub active_days: u32,
+    /// Sessions with any transcript activity in the window
+    pub session_count: u32,
+    /// File writes from the activity feed in the window
+    pub edit_count: u32,
+    pub sessions_per_calendar_day: f64,
+    pub sessions_per_active_day: f64,
+    pub edits_per_calendar_day: f64,
+    pub edits_per_active_day: f64,
+}
+
+/// Which way a metric moved over a window.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+#[serde(rename_all = "lowercase")]
+pub enum TrendDirection {
+    Up,
+    Down,
+    /// Within a few percent, or too few active days to tell
+    Flat,
+}
+
+/// Output produced per input token on one day.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, uniffi::Record)]
+pub struct EfficiencyPoint {
+    /// YYYY-MM-DD, UTC
+    pub day: String,
+    /// Uncached input tokens; cache reads are excluded so better caching shows up
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    /// None on days without input
+    pub output_per_input: Option<f64>,
+}
+
+/// Daily token efficiency for a project and the direction it is heading.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, uniffi::Record)]
+pub struct EfficiencyTrend {
+    /// One point per calendar day in the window, oldest first
+    pub points: Vec<EfficiencyPoint>,
+    pub direction: TrendDirection,
+}
+
+/// A project's recomputed stats after its transcripts changed (`stats-changed`).
+#[derive(Debug, Serialize, Deserialize, Clone, uniffi::Record)]
+pub struct StatsChangedEvent {
+    pub project_path: String,
+    pub stats: ProjectStats,
+}
+
+/// Response timing for a session, from prompt/response timestamps in its transcript.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, uniffi::Record)]
+pub struct SessionLatency {
+    /// Prompts that had a timed response
+    pub prompt_count: u32,
+    pub min_response_secs: f64,
+    pub median_response_secs: f64,
+    pub max_response_secs: f64,
+    /// Sum of time from each prompt to the last assistant message of its turn
+    pub total_assistant_secs: f64,
+}
+
+/// How long a project's sessions run, from first to last transcript timestamp.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, uniffi::Record)]
+pub struct SessionDurationStats {
+    /// Sessions with at least two timestamps
+    pub session_count: u32,
+    pub average_secs: f64,
+    pub median_secs: f64,
+    pub longest_secs: f64,
+}
+
 /// Full session state with context information.
 #[derive(Debug, Serialize, Deserialize, Clone, uniffi::Record)]
 pub struct ProjectSessionState {
@@ -255,6 +722,10 @@ pub struct ProjectSessionState {
     /// This is checked via advisory file locks and is more reliable than state file alone.
     #[serde(default)]
     pub is_locked: bool,
+    /// Whether a locked session's record is just past staleness, within the grace band.
+    /// The state is still reported; clients can show it as "maybe idle".
+    #[serde(default)]
+    pub possibly_stale: bool,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -345,6 +816,27 @@ pub struct HookHealthReport {
     pub last_heartbeat_age_secs: Option<u64>,
 }
 
+/// Count of session records by state.
+#[derive(Debug, Clone, Default, PartialEq, Eq, uniffi::Record)]
+pub struct SessionSummary {
+    pub working: u32,
+    pub waiting: u32,
+    pub ready: u32,
+    pub idle: u32,
+    pub compacting: u32,
+    pub thinking: u32,
+}
+
+/// App and session context attached to feedback reports and shown in the debug panel.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct TelemetryContext {
+    pub app_version: String,
+    /// Hook heartbeat is fresh (or a session lock explains its absence)
+    pub hooks_healthy: bool,
+    pub pinned_project_count: u32,
+    pub session_summary: SessionSummary,
+}
+
 /// Result of running a comprehensive hook system test.
 ///
 /// This verifies both the heartbeat (hooks are firing) and state file I/O
@@ -407,6 +899,8 @@ pub struct HookDiagnosticReport {
     pub symlink_target: Option<String>,
     /// Age of last heartbeat in seconds (for "last seen X ago" display)
     pub last_heartbeat_age_secs: Option<u64>,
+    /// Set when session timestamps indicate a skewed clock
+    pub clock_skew_warning: Option<String>,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
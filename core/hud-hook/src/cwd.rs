@@ -385,6 +385,35 @@ fn detect_parent_app(pid: u32) -> ParentApp {
     ParentApp::Unknown
 }
 
+/// Returns the process chain from `pid` up toward init as `(pid, name)` pairs.
+///
+/// Unlike [`detect_parent_app`], which stops at the first known app, this keeps
+/// the whole chain for diagnosing misattribution. Capped at
+/// `MAX_PARENT_CHAIN_DEPTH` entries; names that can't be read are `"?"`.
+pub fn process_ancestry(pid: u32) -> Vec<(u32, String)> {
+    let mut chain = Vec::new();
+    let mut current_pid = pid;
+
+    while chain.len() < MAX_PARENT_CHAIN_DEPTH {
+        let name = get_process_name(current_pid).unwrap_or_else(|_| "?".to_string());
+        chain.push((current_pid, name));
+
+        match get_parent_pid(current_pid) {
+            Ok(ppid) if ppid > 0 && ppid != current_pid => current_pid = ppid,
+            _ => break,
+        }
+    }
+
+    chain
+}
+
+/// Prints the ancestry of `pid`, one `pid<TAB>name` line per process.
+pub fn run_ancestry(pid: u32) {
+    for (pid, name) in process_ancestry(pid) {
+        println!("{}\t{}", pid, name);
+    }
+}
+
 fn identify_app_from_pid(pid: u32) -> Option<ParentApp> {
     let name = get_process_name(pid).ok()?;
 
@@ -583,6 +612,16 @@ mod tests {
         assert!(process_exists(pid));
     }
 
+    #[test]
+    fn test_process_ancestry_walks_from_self_toward_init() {
+        let pid = std::process::id();
+        let chain = process_ancestry(pid);
+        assert!(!chain.is_empty());
+        assert!(chain.len() <= MAX_PARENT_CHAIN_DEPTH);
+        assert_eq!(chain[0].0, pid);
+        assert!(chain.last().unwrap().0 <= 1 || chain.len() == MAX_PARENT_CHAIN_DEPTH);
+    }
+
     #[test]
     fn test_process_exists_returns_false_for_invalid_pid() {
         assert!(!process_exists(999999999));
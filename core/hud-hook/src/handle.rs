@@ -6,12 +6,15 @@
 //!
 //! ```text
 //! SessionStart           → ready    (+ creates lock)
-//! UserPromptSubmit       → working  (+ creates lock if missing)
-//! PreToolUse/PostToolUse → working  (heartbeat if already working)
+//! UserPromptSubmit       → thinking (+ creates lock if missing)
+//! PreToolUse             → working  (heartbeat if already working)
+//! PostToolUse            → thinking (heartbeat if already thinking)
 //! PermissionRequest      → waiting
-//! Notification           → ready    (only idle_prompt type)
+//! Notification           → ready    (idle_prompt type)
 //! PreCompact             → compacting
 //! Stop                   → ready    (unless stop_hook_active=true)
+//! SubagentStart/Stop     → unchanged (adjusts active subagent count; Stop and
+//!                          UserPromptSubmit reset it)
 //! SessionEnd             → removes session record
 //! ```
 
@@ -19,8 +22,8 @@ use chrono::Utc;
 use fs_err as fs;
 use hud_core::boundaries::find_project_boundary;
 use hud_core::state::{
-    count_other_session_locks, create_session_lock, release_lock_by_session, HookEvent, HookInput,
-    StateStore,
+    append_transition, count_other_session_locks, create_session_lock, release_lock_by_session,
+    HookEvent, HookInput, StateStore, TransitionRecord,
 };
 use hud_core::types::SessionState;
 use std::env;
@@ -34,6 +37,7 @@ const LOCK_DIR: &str = ".capacitor/sessions";
 const ACTIVITY_FILE: &str = ".capacitor/file-activity.json";
 const TOMBSTONES_DIR: &str = ".capacitor/ended-sessions";
 const HEARTBEAT_FILE: &str = ".capacitor/hud-hook-heartbeat";
+const TRANSITIONS_FILE: &str = ".capacitor/transitions.jsonl";
 
 pub fn run() -> Result<(), String> {
     // Skip if this is a summary generation subprocess
@@ -117,6 +121,7 @@ fn handle_hook_input_with_home(hook_input: HookInput, home: &Path) -> Result<(),
     let state_file = home.join(STATE_FILE);
     let lock_base = home.join(LOCK_DIR);
     let activity_file = home.join(ACTIVITY_FILE);
+    let transitions_file = home.join(TRANSITIONS_FILE);
 
     // Ensure directories exist
     if let Some(parent) = state_file.parent() {
@@ -186,6 +191,10 @@ fn handle_hook_input_with_home(hook_input: HookInput, home: &Path) -> Result<(),
                     other_locks = other_locks,
                     "Session has other active locks, preserving session record"
                 );
+                store.reset_subagent_count(&session_id);
+                store
+                    .save()
+                    .map_err(|e| format!("Failed to save state: {}", e))?;
             } else {
                 // No other locks - clean up completely
                 // Order matters: remove record BEFORE lock to prevent race condition
@@ -202,6 +211,15 @@ fn handle_hook_input_with_home(hook_input: HookInput, home: &Path) -> Result<(),
 
                 // 3. Remove from activity file
                 remove_session_activity(&activity_file, &session_id);
+
+                record_transition(
+                    &transitions_file,
+                    &session_id,
+                    &cwd,
+                    current_state,
+                    SessionState::Idle,
+                    &hook_input.hook_event_name,
+                );
             }
 
             // 4. Release lock LAST - UI will see no record AND no lock atomically
@@ -219,11 +237,29 @@ fn handle_hook_input_with_home(hook_input: HookInput, home: &Path) -> Result<(),
             let state = new_state
                 .unwrap_or_else(|| existing.map(|r| r.state).unwrap_or(SessionState::Ready));
 
+            let previous = existing.map(|r| r.state);
+
             // Update the store (this handles state_changed_at internally)
             store.update(&session_id, state, &cwd);
+            if let Some(delta) = subagent_delta(&event) {
+                store.adjust_subagent_count(&session_id, delta);
+            } else if ends_subagents(&event) {
+                store.reset_subagent_count(&session_id);
+            }
             store
                 .save()
                 .map_err(|e| format!("Failed to save state: {}", e))?;
+
+            if previous != Some(state) {
+                record_transition(
+                    &transitions_file,
+                    &session_id,
+                    &cwd,
+                    previous,
+                    state,
+                    &hook_input.hook_event_name,
+                );
+            }
         }
         Action::Skip => {
             // Nothing to do for state, but lock may still need spawning
@@ -245,6 +281,34 @@ fn handle_hook_input_with_home(hook_input: HookInput, home: &Path) -> Result<(),
     Ok(())
 }
 
+/// Appends a state change to the transitions log.
+///
+/// Best-effort: the log is a secondary record, so failures are logged, not returned.
+fn record_transition(
+    transitions_file: &Path,
+    session_id: &str,
+    cwd: &str,
+    from: Option<SessionState>,
+    to: SessionState,
+    event: &Option<String>,
+) {
+    let record = TransitionRecord {
+        session_id: session_id.to_string(),
+        cwd: cwd.to_string(),
+        from,
+        to,
+        event: event.clone(),
+        at: Utc::now(),
+    };
+    if let Err(e) = append_transition(transitions_file, &record) {
+        tracing::warn!(
+            session = %session_id,
+            error = %e,
+            "Failed to record state transition"
+        );
+    }
+}
+
 #[derive(Debug, PartialEq)]
 enum Action {
     Upsert,
@@ -257,7 +321,10 @@ enum Action {
 fn is_active_state(state: Option<SessionState>) -> bool {
     matches!(
         state,
-        Some(SessionState::Working) | Some(SessionState::Waiting) | Some(SessionState::Compacting)
+        Some(SessionState::Working)
+            | Some(SessionState::Thinking)
+            | Some(SessionState::Waiting)
+            | Some(SessionState::Compacting)
     )
 }
 
@@ -293,12 +360,35 @@ fn extract_file_activity(
 /// Returns the appropriate action for a tool use event.
 fn tool_use_action(
     current_state: Option<SessionState>,
+    next_state: SessionState,
     file_activity: Option<(String, String)>,
 ) -> (Action, Option<SessionState>, Option<(String, String)>) {
-    if current_state == Some(SessionState::Working) {
+    if current_state == Some(next_state) {
         (Action::Heartbeat, None, file_activity)
     } else {
-        (Action::Upsert, Some(SessionState::Working), file_activity)
+        (Action::Upsert, Some(next_state), file_activity)
+    }
+}
+
+/// Change to the session's running subagent count for this event, if any.
+fn subagent_delta(event: &HookEvent) -> Option<i32> {
+    match event {
+        HookEvent::SubagentStart => Some(1),
+        HookEvent::SubagentStop => Some(-1),
+        _ => None,
+    }
+}
+
+/// Whether this event ends the turn, so none of the session's subagents can
+/// still be running.
+///
+/// Resetting the count here keeps a missed SubagentStop from leaving it stuck.
+/// Interrupts fire no event, so the next UserPromptSubmit covers them.
+fn ends_subagents(event: &HookEvent) -> bool {
+    match event {
+        HookEvent::Stop { .. } | HookEvent::UserPromptSubmit => true,
+        HookEvent::Notification { notification_type } => notification_type == "idle_prompt",
+        _ => false,
     }
 }
 
@@ -316,26 +406,28 @@ fn process_event(
             }
         }
 
-        HookEvent::UserPromptSubmit => (Action::Upsert, Some(SessionState::Working), None),
+        // Between a prompt or tool result and the next tool call, Claude is reasoning
+        HookEvent::UserPromptSubmit => (Action::Upsert, Some(SessionState::Thinking), None),
 
-        HookEvent::PreToolUse { .. } => tool_use_action(current_state, None),
+        HookEvent::PreToolUse { .. } => tool_use_action(current_state, SessionState::Working, None),
 
         HookEvent::PostToolUse {
             tool_name,
             file_path,
-        } => tool_use_action(current_state, extract_file_activity(tool_name, file_path)),
+        } => tool_use_action(
+            current_state,
+            SessionState::Thinking,
+            extract_file_activity(tool_name, file_path),
+        ),
 
         HookEvent::PermissionRequest => (Action::Upsert, Some(SessionState::Waiting), None),
 
         HookEvent::PreCompact => (Action::Upsert, Some(SessionState::Compacting), None),
 
-        HookEvent::Notification { notification_type } => {
-            if notification_type == "idle_prompt" {
-                (Action::Upsert, Some(SessionState::Ready), None)
-            } else {
-                (Action::Skip, None, None)
-            }
-        }
+        HookEvent::Notification { notification_type } => match notification_type.as_str() {
+            "idle_prompt" => (Action::Upsert, Some(SessionState::Ready), None),
+            _ => (Action::Skip, None, None),
+        },
 
         HookEvent::Stop { stop_hook_active } => {
             if *stop_hook_active {
@@ -345,6 +437,14 @@ fn process_event(
             }
         }
 
+        HookEvent::SubagentStart | HookEvent::SubagentStop => {
+            if current_state.is_some() {
+                (Action::Heartbeat, None, None)
+            } else {
+                (Action::Skip, None, None)
+            }
+        }
+
         HookEvent::SessionEnd => (Action::Delete, None, None),
 
         HookEvent::Unknown { event_name } => {
@@ -736,6 +836,84 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_handle_hook_input_records_only_actual_transitions() {
+        let temp = tempdir().unwrap();
+        let session_id = "session-transitions";
+
+        for event in ["PermissionRequest", "Stop", "Stop"] {
+            let hook_input = make_hook_input(event, Some(session_id), Some("/tmp/test"));
+            handle_hook_input_with_home(hook_input, temp.path()).unwrap();
+        }
+
+        let transitions = hud_core::state::load_session_transitions(
+            &temp.path().join(TRANSITIONS_FILE),
+            session_id,
+        );
+        let states: Vec<_> = transitions.iter().map(|t| (t.from, t.to)).collect();
+        assert_eq!(
+            states,
+            vec![
+                (None, SessionState::Waiting),
+                (Some(SessionState::Waiting), SessionState::Ready),
+            ]
+        );
+        assert_eq!(transitions[1].event.as_deref(), Some("Stop"));
+    }
+
+    #[test]
+    fn test_handle_hook_input_tracks_subagent_count() {
+        let temp = tempdir().unwrap();
+        let session_id = "session-subagents";
+        let count = || {
+            StateStore::load(&temp.path().join(STATE_FILE))
+                .unwrap()
+                .get_by_session_id(session_id)
+                .map(|r| r.active_subagent_count)
+        };
+
+        // Subagent events before the session exists don't create a record
+        let hook_input = make_hook_input("SubagentStart", Some(session_id), Some("/tmp/test"));
+        handle_hook_input_with_home(hook_input, temp.path()).unwrap();
+        assert_eq!(count(), None);
+
+        for (event, expected) in [
+            ("PermissionRequest", 0),
+            ("SubagentStart", 1),
+            ("SubagentStart", 2),
+            ("SubagentStop", 1),
+            ("SubagentStop", 0),
+            ("SubagentStop", 0),
+            ("SubagentStart", 1),
+        ] {
+            let hook_input = make_hook_input(event, Some(session_id), Some("/tmp/test"));
+            handle_hook_input_with_home(hook_input, temp.path()).unwrap();
+            assert_eq!(count(), Some(expected), "after {}", event);
+        }
+
+        let state = StateStore::load(&temp.path().join(STATE_FILE))
+            .unwrap()
+            .get_by_session_id(session_id)
+            .map(|r| r.state);
+        assert_eq!(state, Some(SessionState::Waiting));
+
+        // A missed SubagentStop doesn't outlive the turn
+        for (event, expected) in [
+            ("SubagentStart", 2),
+            ("Stop", 0),
+            ("SubagentStart", 1),
+            ("UserPromptSubmit", 0),
+        ] {
+            let hook_input = make_hook_input(event, Some(session_id), Some("/tmp/test"));
+            handle_hook_input_with_home(hook_input, temp.path()).unwrap();
+            assert_eq!(count(), Some(expected), "after {}", event);
+        }
+
+        let hook_input = make_hook_input("SessionEnd", Some(session_id), Some("/tmp/test"));
+        handle_hook_input_with_home(hook_input, temp.path()).unwrap();
+        assert_eq!(count(), None, "SessionEnd drops the record and its count");
+    }
+
     #[test]
     fn test_process_event_session_start() {
         let input = HookInput {
@@ -810,8 +988,38 @@ mod tests {
         let event = HookEvent::UserPromptSubmit;
         let (action, state, _) = process_event(&event, None, &input);
 
+        assert_eq!(action, Action::Upsert);
+        assert_eq!(state, Some(SessionState::Thinking));
+    }
+
+    #[test]
+    fn test_process_event_thinking_between_tool_calls() {
+        let input = make_hook_input("PostToolUse", Some("test"), Some("/test"));
+        let pre = HookEvent::PreToolUse {
+            tool_name: Some("Bash".to_string()),
+        };
+        let post = HookEvent::PostToolUse {
+            tool_name: Some("Bash".to_string()),
+            file_path: None,
+        };
+
+        let (action, state, _) = process_event(&pre, Some(SessionState::Thinking), &input);
         assert_eq!(action, Action::Upsert);
         assert_eq!(state, Some(SessionState::Working));
+
+        let (action, state, _) = process_event(&post, Some(SessionState::Working), &input);
+        assert_eq!(action, Action::Upsert);
+        assert_eq!(state, Some(SessionState::Thinking));
+
+        let (action, state, _) = process_event(&post, Some(SessionState::Thinking), &input);
+        assert_eq!(action, Action::Heartbeat);
+        assert_eq!(state, None);
+
+        let stop = HookEvent::Stop {
+            stop_hook_active: false,
+        };
+        let (_, state, _) = process_event(&stop, Some(SessionState::Thinking), &input);
+        assert_eq!(state, Some(SessionState::Ready));
     }
 
     #[test]
@@ -8,14 +8,20 @@
 //! - `handle`: Main hook handler, reads JSON from stdin
 //! - `cwd`: Shell CWD tracking (called by shell precmd hooks)
 //! - `lock-holder`: Background daemon for lock management (spawned internally)
+//! - `state`: Print the resolved session state for a path (for scripting)
+//! - `ancestry`: Print the parent process chain for a PID (for debugging app detection)
+//! - `status-gen-log`: Append status generation output from stdin to the debug log
 
 mod cwd;
 mod handle;
 mod lock_holder;
 mod logging;
+mod state;
 
 use clap::{Parser, Subcommand};
+use std::io::Read;
 use std::path::PathBuf;
+use std::time::Instant;
 
 #[derive(Parser)]
 #[command(name = "hud-hook")]
@@ -64,6 +70,23 @@ enum Commands {
         #[arg(long)]
         lock_dir: PathBuf,
     },
+
+    /// Print resolved session state for a path as `state<TAB>session_id<TAB>secs`
+    State {
+        /// Project path to resolve
+        #[arg(value_name = "PATH")]
+        path: String,
+    },
+
+    /// Print the parent process chain for a PID as `pid<TAB>name` lines
+    Ancestry {
+        /// Process ID to start from (typically a shell PID)
+        #[arg(value_name = "PID")]
+        pid: u32,
+    },
+
+    /// Append stdin to ~/.capacitor/status-gen.log when status_gen_logging is on
+    StatusGenLog,
 }
 
 fn main() {
@@ -72,16 +95,21 @@ fn main() {
 
     match cli.command {
         Commands::Handle => {
-            if let Err(e) = handle::run() {
+            let started = Instant::now();
+            let result = handle::run();
+            log_duration("handle", started);
+            if let Err(e) = result {
                 tracing::error!(error = %e, "hud-hook handle failed");
                 std::process::exit(1);
             }
         }
         Commands::Cwd { path, pid, tty } => {
+            let started = Instant::now();
             // CWD tracking is non-critical - log errors but exit 0 to not disrupt shell
             if let Err(e) = cwd::run(&path, pid, &tty) {
                 tracing::warn!(error = %e, "hud-hook cwd failed");
             }
+            log_duration("cwd", started);
         }
         Commands::LockHolder {
             session_id,
@@ -91,5 +119,33 @@ fn main() {
         } => {
             lock_holder::run(&session_id, &cwd_path, pid, &lock_dir);
         }
+        Commands::State { path } => {
+            if let Err(e) = state::run(&path) {
+                eprintln!("hud-hook state failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Ancestry { pid } => cwd::run_ancestry(pid),
+        Commands::StatusGenLog => {
+            // Logging is best-effort - never fail the status hook over it
+            let mut output = String::new();
+            if let Err(e) = std::io::stdin().read_to_string(&mut output) {
+                tracing::warn!(error = %e, "hud-hook status-gen-log failed to read stdin");
+                return;
+            }
+            if let Err(e) = hud_core::sessions::append_status_gen_log(&output) {
+                tracing::warn!(error = %e, "hud-hook status-gen-log failed");
+            }
+        }
     }
 }
+
+/// Records a run's duration for `hud_core::hook_latency_stats`, which parses
+/// this exact message and field layout back out of the debug log.
+fn log_duration(command: &str, started: Instant) {
+    tracing::debug!(
+        command = %command,
+        duration_us = started.elapsed().as_micros() as u64,
+        "hook finished"
+    );
+}
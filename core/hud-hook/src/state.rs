@@ -0,0 +1,129 @@
+//! Prints the resolved session state for a path, for shell scripts and status bars.
+//!
+//! Output is a single tab-separated line:
+//!
+//! ```text
+//! <state>\t<session_id>\t<seconds_in_state>
+//! ```
+//!
+//! When no session is running for the path, the line is `idle\t\t` and the exit
+//! code is still 0, so callers can `cut -f1` without special-casing errors.
+
+use chrono::Utc;
+use hud_core::config::load_hud_config_with_storage;
+use hud_core::sessions::ready_or_idle;
+use hud_core::state::{resolve_state_with_config, ResolverConfig, StateStore};
+use hud_core::storage::StorageConfig;
+use hud_core::types::SessionState;
+use std::path::Path;
+
+const CAPACITOR_DIR: &str = ".capacitor";
+
+pub fn run(path: &str) -> Result<(), String> {
+    let home = dirs::home_dir().ok_or("Cannot determine home directory")?;
+    println!("{}", format_state_line(&home, path));
+    Ok(())
+}
+
+fn format_state_line(home: &Path, path: &str) -> String {
+    let storage = StorageConfig::with_root(home.join(CAPACITOR_DIR));
+    let state_file = storage.sessions_file();
+    let store = StateStore::load(&state_file).unwrap_or_else(|_| StateStore::new(&state_file));
+
+    // Same thresholds and Ready→Idle rules as the app, so scripts never disagree with it
+    let Some(resolved) = resolve_state_with_config(
+        &storage.sessions_dir(),
+        &store,
+        path,
+        &ResolverConfig::load(&storage),
+    ) else {
+        return "idle\t\t".to_string();
+    };
+
+    let record = resolved
+        .session_id
+        .as_deref()
+        .and_then(|sid| store.get_by_session_id(sid));
+    let secs_in_state = record.map(|r| {
+        Utc::now()
+            .signed_duration_since(r.state_changed_at)
+            .num_seconds()
+            .max(0)
+    });
+    let state = ready_or_idle(
+        &resolved,
+        record,
+        load_hud_config_with_storage(&storage).idle_threshold_secs,
+    );
+
+    format!(
+        "{}\t{}\t{}",
+        state_name(state),
+        resolved.session_id.unwrap_or_default(),
+        secs_in_state.map(|s| s.to_string()).unwrap_or_default()
+    )
+}
+
+fn state_name(state: SessionState) -> &'static str {
+    match state {
+        SessionState::Working => "working",
+        SessionState::Ready => "ready",
+        SessionState::Idle => "idle",
+        SessionState::Compacting => "compacting",
+        SessionState::Waiting => "waiting",
+        SessionState::Thinking => "thinking",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn storage(home: &Path) -> StorageConfig {
+        StorageConfig::with_root(home.join(CAPACITOR_DIR))
+    }
+
+    #[test]
+    fn test_format_state_line_running_session() {
+        let temp = tempdir().unwrap();
+        let state_file = storage(temp.path()).sessions_file();
+        let mut store = StateStore::new(&state_file);
+        store.update("session-running", SessionState::Working, "/tmp/project");
+        store.save().unwrap();
+
+        let line = format_state_line(temp.path(), "/tmp/project");
+        let fields: Vec<&str> = line.split('\t').collect();
+        assert_eq!(fields[0], "working");
+        assert_eq!(fields[1], "session-running");
+        assert!(fields[2].parse::<i64>().unwrap() >= 0);
+    }
+
+    #[test]
+    fn test_format_state_line_nothing_running() {
+        let temp = tempdir().unwrap();
+        assert_eq!(format_state_line(temp.path(), "/tmp/project"), "idle\t\t");
+    }
+
+    #[test]
+    fn test_format_state_line_uses_configured_active_state_threshold() {
+        let temp = tempdir().unwrap();
+        let storage = storage(temp.path());
+        let mut store = StateStore::new(&storage.sessions_file());
+        store.update("session-quiet", SessionState::Working, "/tmp/project");
+        store.set_timestamp_for_test("session-quiet", Utc::now() - chrono::Duration::seconds(60));
+        store.save().unwrap();
+
+        // Default: a lockless Working record quiet for 60s has fallen back to Ready
+        let line = format_state_line(temp.path(), "/tmp/project");
+        assert!(line.starts_with("ready\t"), "{}", line);
+
+        let config = hud_core::types::HudConfig {
+            active_state_stale_secs: Some(120),
+            ..Default::default()
+        };
+        hud_core::config::save_hud_config_with_storage(&storage, &config).unwrap();
+        let line = format_state_line(temp.path(), "/tmp/project");
+        assert!(line.starts_with("working\t"), "{}", line);
+    }
+}